@@ -60,9 +60,12 @@ impl DerivationPath {
         }
     }
 
-    /// Format as string
-    pub fn to_string(&self) -> String {
-        format!(
+}
+
+impl std::fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
             "m/44'/{}'/{}'/{}/{}",
             self.coin_type, self.account, self.change, self.address_index
         )
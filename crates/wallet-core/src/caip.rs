@@ -27,8 +27,8 @@ pub fn caip2_to_chain_id(caip2: &str) -> Option<ChainId> {
 }
 
 /// Convert internal ChainId to CAIP-2 identifier
-pub fn chain_id_to_caip2(chain_id: &ChainId) -> &'static str {
-    match chain_id {
+pub fn chain_id_to_caip2(chain_id: &ChainId) -> String {
+    let caip2 = match chain_id {
         ChainId::Ethereum => "eip155:1",
         ChainId::Polygon => "eip155:137",
         ChainId::Bsc => "eip155:56",
@@ -45,14 +45,28 @@ pub fn chain_id_to_caip2(chain_id: &ChainId) -> &'static str {
         ChainId::Ripple => "xrpl:0",
         ChainId::Dogecoin => "bip122:1a91e3dace36e2be3bf030a65679fe821aa1d6ef92e7c9902eb318182c355691",
         ChainId::Tron => "tron:mainnet",
-    }
+        ChainId::CustomEvm(id) => return format!("eip155:{}", id),
+    };
+    caip2.to_string()
+}
+
+/// Whether a Solana chain id (CAIP-2 genesis hash or Wallet Standard `solana:devnet`)
+/// names a test cluster rather than mainnet
+pub fn is_solana_test_cluster(chain: &str) -> bool {
+    matches!(
+        chain,
+        "solana:devnet" | "solana:testnet" | "solana:localnet"
+            | "solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1"
+            | "solana:4uhcVJyU9pJkvQyS88uRDiswHXSCkY3z"
+    )
 }
 
 /// Get CAIP-2 namespace for a ChainId
 pub fn chain_id_to_namespace(chain_id: &ChainId) -> &'static str {
     match chain_id {
         ChainId::Ethereum | ChainId::Polygon | ChainId::Bsc |
-        ChainId::Optimism | ChainId::Base | ChainId::Arbitrum => "eip155",
+        ChainId::Optimism | ChainId::Base | ChainId::Arbitrum |
+        ChainId::CustomEvm(_) => "eip155",
         ChainId::Solana => "solana",
         ChainId::CosmosHub | ChainId::Osmosis => "cosmos",
         ChainId::Bitcoin | ChainId::Litecoin | ChainId::Dogecoin => "bip122",
@@ -85,7 +99,7 @@ pub fn supported_namespaces(
                 accounts: Vec::new(),
             }
         });
-        entry.chains.insert(caip2.to_string());
+        entry.chains.insert(caip2);
         entry.accounts.push(account);
     }
 
@@ -110,6 +124,8 @@ fn namespace_methods(ns: &str) -> Vec<String> {
         ],
         "solana" => vec![
             "solana_signTransaction".into(),
+            "solana_signAllTransactions".into(),
+            "solana_signAndSendTransaction".into(),
             "solana_signMessage".into(),
         ],
         "cosmos" => vec![
@@ -143,7 +159,7 @@ mod tests {
     #[test]
     fn test_caip2_roundtrip_solana() {
         let caip = chain_id_to_caip2(&ChainId::Solana);
-        assert_eq!(caip2_to_chain_id(caip), Some(ChainId::Solana));
+        assert_eq!(caip2_to_chain_id(&caip), Some(ChainId::Solana));
     }
 
     #[test]
@@ -158,16 +174,31 @@ mod tests {
         ];
         for id in all {
             let caip = chain_id_to_caip2(&id);
-            assert_eq!(caip2_to_chain_id(caip), Some(id));
+            assert_eq!(caip2_to_chain_id(&caip), Some(id));
         }
     }
 
+    #[test]
+    fn test_caip2_custom_evm() {
+        let id = ChainId::CustomEvm(43114);
+        assert_eq!(chain_id_to_caip2(&id), "eip155:43114");
+        assert_eq!(chain_id_to_namespace(&id), "eip155");
+    }
+
     #[test]
     fn test_caip2_unknown() {
         assert_eq!(caip2_to_chain_id("eip155:999999"), None);
         assert_eq!(caip2_to_chain_id(""), None);
     }
 
+    #[test]
+    fn test_solana_clusters() {
+        assert!(!is_solana_test_cluster(&chain_id_to_caip2(&ChainId::Solana)));
+        assert!(!is_solana_test_cluster("solana:mainnet"));
+        assert!(is_solana_test_cluster("solana:devnet"));
+        assert!(is_solana_test_cluster("solana:EtWTRABZaYq6iMfeYKouRu166VU2xqa1"));
+    }
+
     #[test]
     fn test_namespace() {
         assert_eq!(chain_id_to_namespace(&ChainId::Ethereum), "eip155");
@@ -198,4 +229,11 @@ mod tests {
         assert!(methods.contains(&"personal_sign".to_string()));
         assert!(methods.contains(&"eth_sendTransaction".to_string()));
     }
+
+    #[test]
+    fn test_solana_methods() {
+        let methods = namespace_methods("solana");
+        assert!(methods.contains(&"solana_signAllTransactions".to_string()));
+        assert!(methods.contains(&"solana_signAndSendTransaction".to_string()));
+    }
 }
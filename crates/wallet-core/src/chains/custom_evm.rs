@@ -0,0 +1,332 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// chains/custom_evm: User-defined EVM networks (manual entry or EIP-3085 wallet_addEthereumChain)
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{get_chains, ChainConfig, ChainId};
+
+/// Prefix of the UI chain key for custom networks ("evm:43114")
+pub const CUSTOM_CHAIN_PREFIX: &str = "evm:";
+
+/// A user-defined EVM network. Addresses are shared with Ethereum (coin type 60).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomEvmNetwork {
+    pub chain_id: u64,
+    pub name: String,
+    pub ticker: String,
+    pub rpc_urls: Vec<String>,
+    #[serde(default)]
+    pub explorer_url: Option<String>,
+    /// Etherscan-compatible API endpoint for history; defaults to `{explorer_url}/api`
+    #[serde(default)]
+    pub explorer_api_url: Option<String>,
+}
+
+impl CustomEvmNetwork {
+    /// UI chain key used in `WalletState::active_chain` and storage maps
+    pub fn chain_key(&self) -> String {
+        chain_key(self.chain_id)
+    }
+
+    pub fn to_config(&self) -> ChainConfig {
+        ChainConfig {
+            id: ChainId::CustomEvm(self.chain_id),
+            name: self.name.clone(),
+            ticker: self.ticker.clone(),
+            evm_chain_id: Some(self.chain_id),
+            coin_type: 60,
+            rpc_urls: self.rpc_urls.clone(),
+        }
+    }
+
+    /// Explorer link for a transaction hash
+    pub fn tx_url(&self, hash: &str) -> Option<String> {
+        self.explorer_url.as_ref()
+            .map(|base| format!("{}/tx/{}", base.trim_end_matches('/'), hash))
+    }
+
+    /// Etherscan-style API base used for account history
+    pub fn history_api_url(&self) -> Option<String> {
+        self.explorer_api_url.clone().or_else(|| {
+            self.explorer_url.as_ref()
+                .map(|base| format!("{}/api", base.trim_end_matches('/')))
+        })
+    }
+
+    /// Check fields and reject chain ids already served by a built-in network
+    pub fn validate(&self) -> Result<(), String> {
+        if self.chain_id == 0 {
+            return Err("Chain ID must be greater than zero".into());
+        }
+        if is_builtin_evm_chain(self.chain_id) {
+            return Err(format!("Chain ID {} is already a built-in network", self.chain_id));
+        }
+        let name = self.name.trim();
+        if name.is_empty() || name.len() > 64 {
+            return Err("Network name must be 1-64 characters".into());
+        }
+        let ticker = self.ticker.trim();
+        if ticker.len() < 2 || ticker.len() > 6 || !ticker.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err("Currency symbol must be 2-6 letters or digits".into());
+        }
+        if self.rpc_urls.is_empty() {
+            return Err("At least one RPC URL is required".into());
+        }
+        for url in &self.rpc_urls {
+            validate_rpc_url(url)?;
+        }
+        for url in self.explorer_url.iter().chain(self.explorer_api_url.iter()) {
+            if !url.starts_with("https://") {
+                return Err(format!("Explorer URL must use https: {}", url));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// UI chain key for a custom EVM chain id
+pub fn chain_key(chain_id: u64) -> String {
+    format!("{}{}", CUSTOM_CHAIN_PREFIX, chain_id)
+}
+
+/// Parse a UI chain key ("evm:43114") back to the numeric chain id
+pub fn parse_chain_key(key: &str) -> Option<u64> {
+    key.strip_prefix(CUSTOM_CHAIN_PREFIX)?.parse().ok()
+}
+
+/// True when the chain id belongs to a built-in mainnet or testnet EVM network
+pub fn is_builtin_evm_chain(chain_id: u64) -> bool {
+    [false, true].into_iter()
+        .flat_map(get_chains)
+        .any(|c| c.evm_chain_id == Some(chain_id))
+}
+
+/// RPC endpoints must be https; plain http is accepted only for local nodes
+fn validate_rpc_url(url: &str) -> Result<(), String> {
+    if url.starts_with("https://") {
+        return Ok(());
+    }
+    let local = ["http://localhost", "http://127.0.0.1", "http://[::1]"];
+    if local.iter().any(|prefix| {
+        url.strip_prefix(prefix)
+            .map(|rest| rest.is_empty() || rest.starts_with(':') || rest.starts_with('/'))
+            .unwrap_or(false)
+    }) {
+        return Ok(());
+    }
+    Err(format!("RPC URL must use https (http only for localhost): {}", url))
+}
+
+/// Parse EIP-3085 `wallet_addEthereumChain` params: `[AddEthereumChainParameter]`
+pub fn from_add_chain_params(params: &Value) -> Result<CustomEvmNetwork, String> {
+    let p = params.get(0).unwrap_or(params);
+    let chain_id = parse_hex_chain_id(p.get("chainId"))?;
+
+    let name = p.get("chainName")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing chainName")?
+        .trim()
+        .to_string();
+
+    let currency = p.get("nativeCurrency").ok_or("Missing nativeCurrency")?;
+    let ticker = currency.get("symbol")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing nativeCurrency.symbol")?
+        .trim()
+        .to_string();
+    let decimals = currency.get("decimals").and_then(|v| v.as_u64()).unwrap_or(18);
+    if decimals != 18 {
+        return Err("nativeCurrency.decimals must be 18".into());
+    }
+
+    let rpc_urls: Vec<String> = p.get("rpcUrls")
+        .and_then(|v| v.as_array())
+        .ok_or("Missing rpcUrls")?
+        .iter()
+        .filter_map(|u| u.as_str())
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+
+    let explorer_url = p.get("blockExplorerUrls")
+        .and_then(|v| v.as_array())
+        .and_then(|urls| urls.first())
+        .and_then(|u| u.as_str())
+        .map(|u| u.trim().trim_end_matches('/').to_string());
+
+    let network = CustomEvmNetwork {
+        chain_id,
+        name,
+        ticker,
+        rpc_urls,
+        explorer_url,
+        explorer_api_url: None,
+    };
+    network.validate()?;
+    Ok(network)
+}
+
+/// Parse EIP-3326 `wallet_switchEthereumChain` params: `[{ chainId }]`
+pub fn parse_switch_chain_params(params: &Value) -> Result<u64, String> {
+    let p = params.get(0).unwrap_or(params);
+    parse_hex_chain_id(p.get("chainId"))
+}
+
+/// EIP-3085/3326 chain ids are 0x-prefixed hex without leading zeros
+fn parse_hex_chain_id(value: Option<&Value>) -> Result<u64, String> {
+    let s = value.and_then(|v| v.as_str()).ok_or("Missing chainId")?;
+    let hex = s.strip_prefix("0x").ok_or("chainId must be 0x-prefixed hex")?;
+    if hex.is_empty() || (hex.len() > 1 && hex.starts_with('0')) {
+        return Err("chainId must not have leading zeros".into());
+    }
+    u64::from_str_radix(hex, 16).map_err(|_| format!("Invalid chainId: {}", s))
+}
+
+/// Insert or replace a network by chain id. Returns true if it was new.
+pub fn upsert_network(list: &mut Vec<CustomEvmNetwork>, network: CustomEvmNetwork) -> bool {
+    match list.iter_mut().find(|n| n.chain_id == network.chain_id) {
+        Some(existing) => {
+            *existing = network;
+            false
+        }
+        None => {
+            list.push(network);
+            true
+        }
+    }
+}
+
+/// Built-in chains followed by the user's custom networks.
+/// Custom networks are listed in both mainnet and testnet mode.
+pub fn merge_chains(testnet: bool, custom: &[CustomEvmNetwork]) -> Vec<ChainConfig> {
+    let mut chains = get_chains(testnet);
+    chains.extend(
+        custom.iter()
+            .filter(|n| !is_builtin_evm_chain(n.chain_id))
+            .map(CustomEvmNetwork::to_config),
+    );
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn avalanche() -> CustomEvmNetwork {
+        CustomEvmNetwork {
+            chain_id: 43114,
+            name: "Avalanche C-Chain".into(),
+            ticker: "AVAX".into(),
+            rpc_urls: vec!["https://api.avax.network/ext/bc/C/rpc".into()],
+            explorer_url: Some("https://snowtrace.io".into()),
+            explorer_api_url: None,
+        }
+    }
+
+    #[test]
+    fn test_chain_key_roundtrip() {
+        assert_eq!(chain_key(43114), "evm:43114");
+        assert_eq!(parse_chain_key("evm:43114"), Some(43114));
+        assert_eq!(parse_chain_key("ethereum"), None);
+        assert_eq!(parse_chain_key("evm:abc"), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(avalanche().validate().is_ok());
+
+        let mut builtin = avalanche();
+        builtin.chain_id = 137;
+        assert!(builtin.validate().is_err());
+
+        let mut http = avalanche();
+        http.rpc_urls = vec!["http://rpc.example.com".into()];
+        assert!(http.validate().is_err());
+
+        let mut local = avalanche();
+        local.rpc_urls = vec!["http://localhost:8545".into(), "http://127.0.0.1/rpc".into()];
+        assert!(local.validate().is_ok());
+
+        let mut sneaky = avalanche();
+        sneaky.rpc_urls = vec!["http://localhost.evil.com".into()];
+        assert!(sneaky.validate().is_err());
+
+        let mut bad_ticker = avalanche();
+        bad_ticker.ticker = "A V".into();
+        assert!(bad_ticker.validate().is_err());
+    }
+
+    #[test]
+    fn test_from_add_chain_params() {
+        let params = json!([{
+            "chainId": "0xa86a",
+            "chainName": "Avalanche C-Chain",
+            "nativeCurrency": {"name": "Avalanche", "symbol": "AVAX", "decimals": 18},
+            "rpcUrls": ["https://api.avax.network/ext/bc/C/rpc"],
+            "blockExplorerUrls": ["https://snowtrace.io/"]
+        }]);
+        let net = from_add_chain_params(&params).unwrap();
+        assert_eq!(net, avalanche());
+        assert_eq!(net.tx_url("0xabc").unwrap(), "https://snowtrace.io/tx/0xabc");
+        assert_eq!(net.history_api_url().unwrap(), "https://snowtrace.io/api");
+    }
+
+    #[test]
+    fn test_from_add_chain_params_rejects() {
+        let leading_zero = json!([{
+            "chainId": "0x0a86a", "chainName": "X",
+            "nativeCurrency": {"symbol": "AVAX", "decimals": 18},
+            "rpcUrls": ["https://rpc.example.com"]
+        }]);
+        assert!(from_add_chain_params(&leading_zero).is_err());
+
+        let decimal_id = json!([{
+            "chainId": "43114", "chainName": "X",
+            "nativeCurrency": {"symbol": "AVAX", "decimals": 18},
+            "rpcUrls": ["https://rpc.example.com"]
+        }]);
+        assert!(from_add_chain_params(&decimal_id).is_err());
+
+        let bad_decimals = json!([{
+            "chainId": "0xa86a", "chainName": "X",
+            "nativeCurrency": {"symbol": "AVAX", "decimals": 9},
+            "rpcUrls": ["https://rpc.example.com"]
+        }]);
+        assert!(from_add_chain_params(&bad_decimals).is_err());
+
+        let no_rpc = json!([{
+            "chainId": "0xa86a", "chainName": "X",
+            "nativeCurrency": {"symbol": "AVAX", "decimals": 18},
+            "rpcUrls": []
+        }]);
+        assert!(from_add_chain_params(&no_rpc).is_err());
+    }
+
+    #[test]
+    fn test_parse_switch_chain_params() {
+        assert_eq!(parse_switch_chain_params(&json!([{"chainId": "0x89"}])).unwrap(), 137);
+        assert!(parse_switch_chain_params(&json!([{}])).is_err());
+    }
+
+    #[test]
+    fn test_upsert_and_merge() {
+        let mut list = Vec::new();
+        assert!(upsert_network(&mut list, avalanche()));
+        let mut renamed = avalanche();
+        renamed.name = "Avalanche".into();
+        assert!(!upsert_network(&mut list, renamed));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].name, "Avalanche");
+
+        let chains = merge_chains(false, &list);
+        let last = chains.last().unwrap();
+        assert_eq!(last.id, ChainId::CustomEvm(43114));
+        assert_eq!(last.evm_chain_id, Some(43114));
+        assert_eq!(last.coin_type, 60);
+        assert_eq!(chains.len(), get_chains(false).len() + 1);
+    }
+}
@@ -134,7 +134,7 @@ pub fn get_private_key(seed: &[u8; 64]) -> Result<[u8; 32], String> {
 }
 
 /// EIP-55 mixed-case checksum encoding
-fn eip55_checksum(address: &[u8]) -> String {
+pub fn eip55_checksum(address: &[u8]) -> String {
     let hex_addr = hex::encode(address);
 
     let mut hasher = Keccak::v256();
@@ -4,13 +4,14 @@
 //
 // chains: Address derivation for all supported blockchains (16 chains)
 //
-// Submodules: evm, solana, ton, cosmos, bitcoin, litecoin, stellar, ripple, dogecoin, tron
+// Submodules: evm, solana, ton, ton_cell, cosmos, bitcoin, litecoin, stellar, ripple, dogecoin, tron, custom_evm
 // Trait: Chain — derive_address(), name(), ticker(), chain_id()
 // Functions: supported_chains() — Config for all chains with RPC URLs
 
 pub mod evm;
 pub mod solana;
 pub mod ton;
+pub mod ton_cell;
 pub mod cosmos;
 pub mod bitcoin;
 pub mod litecoin;
@@ -18,6 +19,7 @@ pub mod stellar;
 pub mod ripple;
 pub mod dogecoin;
 pub mod tron;
+pub mod custom_evm;
 
 use serde::{Deserialize, Serialize};
 
@@ -55,6 +57,8 @@ pub enum ChainId {
     Ripple,
     Dogecoin,
     Tron,
+    /// User-defined EVM network, identified by its EIP-155 chain id
+    CustomEvm(u64),
 }
 
 /// Supported chain configuration
@@ -103,7 +103,7 @@ pub fn get_public_key(seed: &[u8; 64]) -> Result<[u8; 33], String> {
 /// Get the 20-byte account ID (Hash160 of compressed pubkey)
 pub fn get_account_id(seed: &[u8; 64]) -> Result<[u8; 20], String> {
     let pubkey = get_public_key(seed)?;
-    let sha = Sha256::digest(&pubkey);
+    let sha = Sha256::digest(pubkey);
     let hash = Ripemd160::digest(sha);
     let mut result = [0u8; 20];
     result.copy_from_slice(&hash);
@@ -2,21 +2,27 @@
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// chains/ton: TON address derivation (Ed25519 + wallet v4r2)
+// chains/ton: TON address derivation (Ed25519 + wallet v4r2 / v5r1)
 //
 // Functions:
 //   derive_ton_address()            — seed → SLIP-10 m/44'/607'/0' → Ed25519 → v4r2 address
+//   derive_ton_address_for_version() — same key, address of the chosen wallet contract
+//   preferred_version()             — wallet version to use for an imported key, by balance
+//   wallet_v4r2_state_init()        — StateInit cell (code + initial data) of a v4r2 wallet
+//   wallet_v5r1_id()                — network-specific wallet id of a v5r1 (W5) wallet
+//   wallet_v5r1_state_init()        — StateInit cell of a v5r1 wallet
 //   decode_ton_friendly_address()   — base64url address → raw bytes (tag + workchain + hash)
+//   TonAddress                      — workchain + account hash, friendly and raw forms
 //   crc16_xmodem()                  — CRC16-XMODEM for TON address checksum
 //
-// NOTE: This implementation uses the proper wallet v4r2 state_init hash for
-// address derivation. The code BOC is parsed at derivation time to compute
-// the code cell hash.
+// The address is the hash of the wallet's StateInit cell, built with `ton_cell`
+// from the published v4r2 or v5r1 code BOC and the initial data cell. The same
+// key owns one wallet per version; the user picks which one the app uses.
 
 use crate::bip32_utils::{self, DerivationPath};
 use ed25519_dalek::SigningKey;
-use sha2::{Digest, Sha256};
 
+use super::ton_cell::{Cell, CellBuilder};
 use super::{Chain, ChainId};
 
 /// Standard wallet v4r2 code BOC (base64, standard TON format without CRC)
@@ -36,8 +42,86 @@ yMoHFcv/ydB3dIAYyMsFywIizxZQBfoCFMtrEszMyXP7AMhAFIEBCPRR8qcCAHCBAQjXGPoA0z/I\
 VCBHgQEI9FHyp4IQbm90ZXB0gBjIywXLAlAGzxZQBPoCFMtqEssfyz/Jc/sAAgBsgQEI1xj6ANM/\
 MFIkgQEI9Fnyp4IQZHN0cnB0gBjIywXLAlAFzxZQA/oCE8tqyx8Syz/Jc/sAAAr0AMntVA==";
 
+/// Wallet v5r1 (W5) code BOC (base64, standard TON format without CRC)
+/// Source: ton-blockchain/wallet-contract-v5 build, as shipped by tonlib-rs
+const WALLET_V5R1_CODE_BOC_B64: &str = "\
+te6ccgECFAEAAoEAART/APSkE/S88sgLAQIBIAIDAgFIBAUBAvIOAtzQINdJwSCRW49jINcLHyCC\
+EGV4dG69IYIQc2ludL2wkl8D4IIQZXh0brqOtIAg1yEB0HTXIfpAMPpE+Cj6RDBYvZFb4O1E0IEB\
+Qdch9AWDB/QOb6ExkTDhgEDXIXB/2zzgMSDXSYECgLmRMOBw4hAPAgEgBgcCASAICQAZvl8PaiaE\
+CAoOuQ+gLAIBbgoLAgFIDA0AGa3OdqJoQCDrkOuF/8AAGa8d9qJoQBDrkOuFj8AAF7Ml+1E0HHXI\
+dcLH4AARsmL7UTQ1woAgAR4g1wsfghBzaWduuvLgin8PAeaO8O2i7fshgwjXIgKDCNcjIIAg1yHT\
+H9Mf0x/tRNDSANMfINMf0//XCgAK+QFAzPkQmiiUXwrbMeHywIffArNQB7Dy0IRRJbry4IVQNrry\
+4Ib4I7vy0IgikvgA3gGkf8jKAMsfAc8Wye1UIJL4D95w2zzYEAP27aLt+wL0BCFukmwhjkwCIdc5\
+MHCUIccAs44tAdcoIHYeQ2wg10nACPLgkyDXSsAC8uCTINcdBscSwgBSMLDy0InXTNc5MAGk6GwS\
+hAe78uCT10rAAPLgk+1V4tIAAcAAkVvg69csCBQgkXCWAdcsCBwS4lIQseMPINdKERITAJYB+kAB\
++kT4KPpEMFi68uCR7UTQgQFB1xj0BQSdf8jKAEAEgwf0U/Lgi44UA4MH9Fvy4Iwi1woAIW4Bs7Dy\
+0JDiyFADzxYS9ADJ7VQAcjDXLAgkji0h8uCS0gDtRNDSAFETuvLQj1RQMJExnAGBAUDXIdcKAPLg\
+juLIygBYzxbJ7VST8sCN4gAQk1vbMeHXTNA=";
+
 /// Default wallet v4r2 sub-wallet ID
-const WALLET_V4R2_ID: u32 = 698983191; // 0x29A9A317
+pub const WALLET_V4R2_ID: u32 = 698983191; // 0x29A9A317
+
+/// Published wallet v5r1 code hash
+pub const WALLET_V5R1_CODE_HASH: &str = "20834b7b72b112147e1b2fb457b84e74d1a30f04f737d4f62a668e9552d2b72f";
+
+/// Network global ids, mixed into the v5r1 wallet id
+pub const GLOBAL_ID_MAINNET: i32 = -239;
+pub const GLOBAL_ID_TESTNET: i32 = -3;
+
+/// Wallet contract versions the wallet can derive and sign for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WalletVersion {
+    #[default]
+    V4R2,
+    V5R1,
+}
+
+impl WalletVersion {
+    pub fn all() -> &'static [WalletVersion] {
+        &[WalletVersion::V4R2, WalletVersion::V5R1]
+    }
+
+    /// Stable id, used in storage
+    pub fn id(&self) -> &'static str {
+        match self {
+            WalletVersion::V4R2 => "v4r2",
+            WalletVersion::V5R1 => "v5r1",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|v| v.id() == id)
+    }
+
+    /// Key of this version's address in the derived address map
+    pub fn address_key(&self) -> &'static str {
+        match self {
+            WalletVersion::V4R2 => "ton",
+            WalletVersion::V5R1 => "ton_v5r1",
+        }
+    }
+
+    /// StateInit of this version's wallet for `pubkey` (v5r1 wallet ids depend on the network)
+    pub fn state_init(&self, pubkey: &[u8; 32], testnet: bool) -> Result<Cell, String> {
+        match self {
+            WalletVersion::V4R2 => wallet_v4r2_state_init(pubkey),
+            WalletVersion::V5R1 => wallet_v5r1_state_init(pubkey, wallet_v5r1_id(testnet, 0, 0)),
+        }
+    }
+
+    /// Basechain address of this version's wallet for `pubkey`
+    pub fn address(&self, pubkey: &[u8; 32], testnet: bool) -> Result<TonAddress, String> {
+        match self {
+            WalletVersion::V4R2 => wallet_v4r2_address(pubkey),
+            WalletVersion::V5R1 => wallet_v5r1_address(pubkey, testnet),
+        }
+    }
+}
+
+/// Friendly address tags
+const TAG_BOUNCEABLE: u8 = 0x11;
+const TAG_NON_BOUNCEABLE: u8 = 0x51;
+const TAG_TESTNET: u8 = 0x80;
 
 pub struct TonChain;
 
@@ -59,10 +143,25 @@ impl Chain for TonChain {
     }
 }
 
+/// Wallet version to use for an imported key, given the balance (nanoton) of
+/// each version's wallet: v4r2 unless only other versions hold funds
+pub fn preferred_version(balances: &[(WalletVersion, u64)]) -> WalletVersion {
+    let funded = |v: WalletVersion| balances.iter().any(|(version, balance)| *version == v && *balance > 0);
+    if funded(WalletVersion::V4R2) {
+        return WalletVersion::V4R2;
+    }
+    WalletVersion::all().iter().copied().find(|v| funded(*v)).unwrap_or_default()
+}
+
 /// Derive TON address from seed
 /// Path: m/44'/607'/0' (SLIP-10 Ed25519)
 /// Address: wallet v4r2 bounceable base64url
 pub fn derive_ton_address(seed: &[u8; 64]) -> Result<String, String> {
+    derive_ton_address_for_version(seed, WalletVersion::V4R2, false)
+}
+
+/// Derive the address of the `version` wallet owned by the seed's TON key
+pub fn derive_ton_address_for_version(seed: &[u8; 64], version: WalletVersion, testnet: bool) -> Result<String, String> {
     let path = DerivationPath::bip44(607);
     let (private_key, _) = bip32_utils::derive_ed25519_key_from_seed(seed, &path)?;
 
@@ -70,253 +169,165 @@ pub fn derive_ton_address(seed: &[u8; 64]) -> Result<String, String> {
     let public_key = signing_key.verifying_key();
     let pubkey_bytes = public_key.as_bytes();
 
-    build_wallet_v4r2_address(pubkey_bytes)
+    Ok(version.address(pubkey_bytes, testnet)?.to_friendly())
 }
 
-/// Build TON wallet v4r2 address from public key using proper state_init hash
-fn build_wallet_v4r2_address(pubkey: &[u8; 32]) -> Result<String, String> {
-    // 1. Parse wallet v4r2 code BOC to get code cell hash and depth
-    let boc_bytes = base64_decode(WALLET_V4R2_CODE_BOC_B64)?;
-    let (cells, roots) = parse_boc(&boc_bytes)?;
-    if roots.is_empty() {
-        return Err("BOC has no root cells".into());
-    }
-    let hashed = compute_cell_hashes(&cells);
-    let root_idx = roots[0];
-    let code = &hashed[root_idx];
-
-    // 2. Compute data cell hash
-    let data = build_v4r2_data_cell_hash(pubkey);
-
-    // 3. Compute state_init hash
-    let state_init_hash = compute_state_init_hash(
-        &code.hash, code.depth,
-        &data.hash, data.depth,
-    );
-
-    // 4. Build user-friendly address: tag(1) + workchain(1) + hash(32) + crc16(2) = 36 bytes
-    let tag: u8 = 0x11; // bounceable
-    let workchain: u8 = 0x00; // basechain
-
-    let mut addr_data = Vec::with_capacity(36);
-    addr_data.push(tag);
-    addr_data.push(workchain);
-    addr_data.extend_from_slice(&state_init_hash);
-
-    let crc = crc16_xmodem(&addr_data);
-    addr_data.push((crc >> 8) as u8);
-    addr_data.push((crc & 0xff) as u8);
-
-    Ok(base64url_encode(&addr_data))
+/// Wallet v4r2 code cell
+pub fn wallet_v4r2_code() -> Result<Cell, String> {
+    Cell::from_boc(&base64_decode(WALLET_V4R2_CODE_BOC_B64)?)
 }
 
-/// Decode a TON user-friendly address (base64url) to raw 34 bytes (tag + workchain + hash)
-/// Verifies CRC16 checksum
-pub fn decode_ton_friendly_address(addr: &str) -> Result<Vec<u8>, String> {
-    let bytes = base64url_decode(addr)?;
-    if bytes.len() != 36 {
-        return Err(format!(
-            "Invalid TON address length: {} (expected 36 bytes from 48 base64 chars)",
-            bytes.len()
-        ));
-    }
-
-    // Verify CRC16
-    let crc_data = &bytes[..34];
-    let expected_crc = ((bytes[34] as u16) << 8) | (bytes[35] as u16);
-    let actual_crc = crc16_xmodem(crc_data);
-    if expected_crc != actual_crc {
-        return Err("Invalid TON address checksum".into());
-    }
-
-    Ok(bytes[..34].to_vec())
+/// StateInit of a fresh v4r2 wallet:
+/// split_depth(0) special(0) code(1) data(1) library(0), code and data as refs.
+/// Data: seqno(32) + wallet_id(32) + pubkey(256) + empty plugins dict(1)
+pub fn wallet_v4r2_state_init(pubkey: &[u8; 32]) -> Result<Cell, String> {
+    let mut data = CellBuilder::new();
+    data.store_uint(0, 32)?
+        .store_uint(WALLET_V4R2_ID as u64, 32)?
+        .store_bytes(pubkey)?
+        .store_bit(false)?;
+    state_init(wallet_v4r2_code()?, data.build())
 }
 
-// ======== TON Cell hashing ========
-
-struct CellHashInfo {
-    hash: [u8; 32],
-    depth: u16,
+/// `StateInit` with code and data only
+pub fn state_init(code: Cell, data: Cell) -> Result<Cell, String> {
+    let mut init = CellBuilder::new();
+    init.store_uint(0b00, 2)?
+        .store_maybe_ref(Some(code))?
+        .store_maybe_ref(Some(data))?
+        .store_bit(false)?;
+    Ok(init.build())
 }
 
-struct BocCell {
-    d1: u8,
-    d2: u8,
-    data: Vec<u8>,
-    refs: Vec<usize>,
+/// Address of the v4r2 wallet owned by `pubkey` on the basechain
+pub fn wallet_v4r2_address(pubkey: &[u8; 32]) -> Result<TonAddress, String> {
+    Ok(TonAddress { workchain: 0, hash: wallet_v4r2_state_init(pubkey)?.hash(), bounceable: true, testnet: false })
 }
 
-/// Build data cell hash for wallet v4r2 initial state
-/// Data: seqno(32bit) + wallet_id(32bit) + pubkey(256bit) + empty_plugins(1bit) = 321 bits
-fn build_v4r2_data_cell_hash(pubkey: &[u8; 32]) -> CellHashInfo {
-    let mut data = Vec::with_capacity(41);
-    data.extend_from_slice(&0u32.to_be_bytes());           // seqno = 0
-    data.extend_from_slice(&WALLET_V4R2_ID.to_be_bytes()); // wallet_id v4r2
-    data.extend_from_slice(pubkey);                         // public key (256 bits)
-    // empty plugins dict (0 bit) + padding marker (1 bit) + zeros = 0b01000000
-    data.push(0x40);
-
-    // Cell representation for hashing: d1(1) + d2(1) + data(41)
-    // d1 = 0x00 (0 refs, ordinary cell)
-    // d2 = floor(321/8) + ceil(321/8) = 40 + 41 = 81 = 0x51
-    let mut repr = Vec::with_capacity(43);
-    repr.push(0x00); // d1
-    repr.push(0x51); // d2
-    repr.extend_from_slice(&data);
-
-    CellHashInfo { hash: sha256_hash(&repr), depth: 0 }
+/// v5r1 wallet id: `global_id XOR context`, where the client context is
+/// `1 (client) | workchain:int8 | version:uint8 (0 = v5r1) | subwallet:uint15`.
+/// Mainnet basechain subwallet 0 is 2147483409, testnet 2147483645.
+pub fn wallet_v5r1_id(testnet: bool, workchain: i8, subwallet: u16) -> u32 {
+    let global_id = if testnet { GLOBAL_ID_TESTNET } else { GLOBAL_ID_MAINNET };
+    let context = (1u32 << 31) | ((workchain as u8 as u32) << 23) | (subwallet as u32 & 0x7fff);
+    (global_id as u32) ^ context
 }
 
-/// Compute state_init cell hash from code and data cell hashes
-/// State init (TL-B): split_depth(0) special(0) code(1) data(1) library(0) = 5 bits
-fn compute_state_init_hash(
-    code_hash: &[u8; 32], code_depth: u16,
-    data_hash: &[u8; 32], data_depth: u16,
-) -> [u8; 32] {
-    // d1 = 0x02 (2 refs: code + data, ordinary cell)
-    // d2 = floor(5/8) + ceil(5/8) = 0 + 1 = 1 = 0x01
-    // data = 0b00110100 = 0x34 (bits: 0 0 1 1 0 + padding 1 0 0)
-    let mut repr = Vec::with_capacity(71);
-    repr.push(0x02); // d1
-    repr.push(0x01); // d2
-    repr.push(0x34); // data
-    // Depths (2 bytes each, big-endian): code then data
-    repr.push((code_depth >> 8) as u8);
-    repr.push((code_depth & 0xff) as u8);
-    repr.push((data_depth >> 8) as u8);
-    repr.push((data_depth & 0xff) as u8);
-    // Hashes: code then data
-    repr.extend_from_slice(code_hash);
-    repr.extend_from_slice(data_hash);
-
-    sha256_hash(&repr)
+/// Initial v5r1 data: is_signature_allowed(1) + seqno(32) + wallet_id(32)
+/// + pubkey(256) + empty extensions dict(1)
+pub fn wallet_v5r1_data(pubkey: &[u8; 32], wallet_id: u32) -> Result<Cell, String> {
+    let mut data = CellBuilder::new();
+    data.store_bit(true)?
+        .store_uint(0, 32)?
+        .store_uint(wallet_id as u64, 32)?
+        .store_bytes(pubkey)?
+        .store_bit(false)?;
+    Ok(data.build())
 }
 
-fn sha256_hash(data: &[u8]) -> [u8; 32] {
-    let mut hasher = Sha256::new();
-    hasher.update(data);
-    let result = hasher.finalize();
-    let mut h = [0u8; 32];
-    h.copy_from_slice(&result);
-    h
+/// Wallet v5r1 code cell
+pub fn wallet_v5r1_code() -> Result<Cell, String> {
+    Cell::from_boc(&base64_decode(WALLET_V5R1_CODE_BOC_B64)?)
 }
 
-// ======== Minimal BOC parser ========
-
-fn read_be(data: &[u8], n: usize) -> usize {
-    let mut result: usize = 0;
-    for &byte in data.iter().take(n) {
-        result = (result << 8) | byte as usize;
-    }
-    result
+/// StateInit of a fresh v5r1 wallet
+pub fn wallet_v5r1_state_init(pubkey: &[u8; 32], wallet_id: u32) -> Result<Cell, String> {
+    state_init(wallet_v5r1_code()?, wallet_v5r1_data(pubkey, wallet_id)?)
 }
 
-/// Parse a BOC (Bag of Cells) and return cells + root indices
-fn parse_boc(boc: &[u8]) -> Result<(Vec<BocCell>, Vec<usize>), String> {
-    if boc.len() < 6 {
-        return Err("BOC too short".into());
-    }
-    if boc[0..4] != [0xb5, 0xee, 0x9c, 0x72] {
-        return Err("Invalid BOC magic".into());
-    }
-
-    let flags_byte = boc[4];
-    let has_idx = (flags_byte >> 7) & 1 == 1;
-    let ref_sz = (flags_byte & 0x07) as usize;
-    let off_sz = boc[5] as usize;
-
-    let mut pos = 6;
-    let cells_count = read_be(&boc[pos..], ref_sz); pos += ref_sz;
-    let roots_count = read_be(&boc[pos..], ref_sz); pos += ref_sz;
-    let _absent     = read_be(&boc[pos..], ref_sz); pos += ref_sz;
-    let _total_size = read_be(&boc[pos..], off_sz); pos += off_sz;
+/// Address of the v5r1 wallet owned by `pubkey` on the basechain of the given network
+pub fn wallet_v5r1_address(pubkey: &[u8; 32], testnet: bool) -> Result<TonAddress, String> {
+    let init = wallet_v5r1_state_init(pubkey, wallet_v5r1_id(testnet, 0, 0))?;
+    Ok(TonAddress { workchain: 0, hash: init.hash(), bounceable: true, testnet })
+}
 
-    let mut root_indices = Vec::with_capacity(roots_count);
-    for _ in 0..roots_count {
-        root_indices.push(read_be(&boc[pos..], ref_sz));
-        pos += ref_sz;
-    }
+/// A TON account address with the flags of its friendly form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TonAddress {
+    pub workchain: i8,
+    pub hash: [u8; 32],
+    pub bounceable: bool,
+    pub testnet: bool,
+}
 
-    // Skip index table if present
-    if has_idx {
-        pos += cells_count * off_sz;
+impl TonAddress {
+    /// Parse a friendly (base64url or base64) or raw (`workchain:hex`) address.
+    /// Raw addresses carry no flags and are treated as bounceable.
+    pub fn parse(address: &str) -> Result<Self, String> {
+        let address = address.trim();
+        if let Some((workchain, hash)) = address.split_once(':') {
+            let workchain: i8 = workchain.parse().map_err(|_| "Invalid TON workchain".to_string())?;
+            let hash: [u8; 32] = hex::decode(hash)
+                .map_err(|_| "Invalid TON address hash".to_string())?
+                .try_into()
+                .map_err(|_| "Invalid TON address hash length".to_string())?;
+            return Ok(TonAddress { workchain, hash, bounceable: true, testnet: false });
+        }
+        Self::from_raw_bytes(&decode_ton_friendly_address(address)?)
     }
 
-    let mut cells = Vec::with_capacity(cells_count);
-    for _ in 0..cells_count {
-        if pos + 2 > boc.len() {
-            return Err("BOC truncated at cell descriptor".into());
+    /// From the 34 bytes returned by `decode_ton_friendly_address`
+    pub fn from_raw_bytes(raw: &[u8]) -> Result<Self, String> {
+        if raw.len() != 34 {
+            return Err("Invalid TON address length".into());
         }
-        let d1 = boc[pos];
-        let d2 = boc[pos + 1];
-        pos += 2;
-
-        let data_len = ((d2 as usize) + 1) / 2;
-        let refs_count = (d1 & 0x07) as usize;
+        let bounceable = match raw[0] & !TAG_TESTNET {
+            TAG_BOUNCEABLE => true,
+            TAG_NON_BOUNCEABLE => false,
+            _ => return Err("Invalid TON address tag".into()),
+        };
+        Ok(TonAddress {
+            workchain: raw[1] as i8,
+            hash: raw[2..].try_into().unwrap(),
+            bounceable,
+            testnet: raw[0] & TAG_TESTNET != 0,
+        })
+    }
 
-        if pos + data_len + refs_count * ref_sz > boc.len() {
-            return Err("BOC truncated at cell data/refs".into());
+    /// User-friendly base64url form, checksummed
+    pub fn to_friendly(&self) -> String {
+        let mut tag = if self.bounceable { TAG_BOUNCEABLE } else { TAG_NON_BOUNCEABLE };
+        if self.testnet {
+            tag |= TAG_TESTNET;
         }
+        let mut addr_data = Vec::with_capacity(36);
+        addr_data.push(tag);
+        addr_data.push(self.workchain as u8);
+        addr_data.extend_from_slice(&self.hash);
 
-        let data = boc[pos..pos + data_len].to_vec();
-        pos += data_len;
-
-        let mut refs = Vec::with_capacity(refs_count);
-        for _ in 0..refs_count {
-            refs.push(read_be(&boc[pos..], ref_sz));
-            pos += ref_sz;
-        }
+        let crc = crc16_xmodem(&addr_data);
+        addr_data.push((crc >> 8) as u8);
+        addr_data.push((crc & 0xff) as u8);
 
-        cells.push(BocCell { d1, d2, data, refs });
+        base64url_encode(&addr_data)
     }
 
-    Ok((cells, root_indices))
+    /// Raw `workchain:hex` form
+    pub fn to_raw_string(&self) -> String {
+        format!("{}:{}", self.workchain, hex::encode(self.hash))
+    }
 }
 
-/// Compute hashes for all cells in a BOC (bottom-up: leaves first)
-/// Cells in BOC are stored with children having higher indices than parents.
-fn compute_cell_hashes(cells: &[BocCell]) -> Vec<CellHashInfo> {
-    let n = cells.len();
-    let mut result: Vec<Option<CellHashInfo>> = (0..n).map(|_| None).collect();
-
-    for i in (0..n).rev() {
-        let cell = &cells[i];
-        let data_len = ((cell.d2 as usize) + 1) / 2;
-
-        // Depth: 0 for leaves, 1 + max(children) for internal nodes
-        let mut depth: u16 = 0;
-        for r in &cell.refs {
-            if let Some(ref info) = result[*r] {
-                depth = depth.max(info.depth + 1);
-            }
-        }
-
-        // Cell representation: d1 + d2 + data + depth(refs)... + hash(refs)...
-        let mut repr = Vec::new();
-        repr.push(cell.d1);
-        repr.push(cell.d2);
-        repr.extend_from_slice(&cell.data[..data_len.min(cell.data.len())]);
-
-        // Append child depths (2 bytes each, big-endian)
-        for r in &cell.refs {
-            if let Some(ref info) = result[*r] {
-                repr.push((info.depth >> 8) as u8);
-                repr.push((info.depth & 0xff) as u8);
-            }
-        }
-        // Append child hashes (32 bytes each)
-        for r in &cell.refs {
-            if let Some(ref info) = result[*r] {
-                repr.extend_from_slice(&info.hash);
-            }
-        }
+/// Decode a TON user-friendly address (base64url) to raw 34 bytes (tag + workchain + hash)
+/// Verifies CRC16 checksum
+pub fn decode_ton_friendly_address(addr: &str) -> Result<Vec<u8>, String> {
+    let bytes = base64url_decode(addr)?;
+    if bytes.len() != 36 {
+        return Err(format!(
+            "Invalid TON address length: {} (expected 36 bytes from 48 base64 chars)",
+            bytes.len()
+        ));
+    }
 
-        result[i] = Some(CellHashInfo { hash: sha256_hash(&repr), depth });
+    // Verify CRC16
+    let crc_data = &bytes[..34];
+    let expected_crc = ((bytes[34] as u16) << 8) | (bytes[35] as u16);
+    let actual_crc = crc16_xmodem(crc_data);
+    if expected_crc != actual_crc {
+        return Err("Invalid TON address checksum".into());
     }
 
-    result.into_iter()
-        .map(|o| o.unwrap_or(CellHashInfo { hash: [0; 32], depth: 0 }))
-        .collect()
+    Ok(bytes[..34].to_vec())
 }
 
 // ======== Base64 encoding/decoding ========
@@ -466,10 +477,97 @@ mod tests {
     #[test]
     fn test_boc_parse() {
         let boc_bytes = base64_decode(WALLET_V4R2_CODE_BOC_B64).unwrap();
-        let (cells, roots) = parse_boc(&boc_bytes).unwrap();
-        assert!(!cells.is_empty(), "BOC should contain cells");
+        let roots = super::super::ton_cell::parse_boc(&boc_bytes).unwrap();
         assert_eq!(roots.len(), 1, "BOC should have exactly 1 root");
-        assert_eq!(roots[0], 0, "Root should be cell 0");
+        assert!(!roots[0].refs().is_empty(), "Code root should reference other cells");
+        // Published wallet v4r2 code hash
+        assert_eq!(hex::encode(roots[0].hash()), "feb5ff6820e2ff0d9483e7e0d62c817d846789fb4ae580c878866d959dabd5c0");
+        assert_eq!(roots[0].depth(), 7);
+        // Re-serialized code parses back to the same tree
+        assert_eq!(Cell::from_boc(&roots[0].to_boc()).unwrap(), roots[0]);
+    }
+
+    #[test]
+    fn test_v4r2_address_matches_state_init() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = bip39_utils::mnemonic_to_seed(phrase, "").unwrap();
+        assert_eq!(derive_ton_address(&seed).unwrap(), "EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v");
+
+        let init = wallet_v4r2_state_init(&[7u8; 32]).unwrap();
+        assert_eq!(init.bit_len(), 5);
+        assert_eq!(init.data(), &[0b0011_0000]);
+        assert_eq!(init.refs()[1].bit_len(), 321);
+        assert_eq!(wallet_v4r2_address(&[7u8; 32]).unwrap().hash, init.hash());
+    }
+
+    #[test]
+    fn test_v5r1_wallet_id_and_data() {
+        assert_eq!(wallet_v5r1_id(false, 0, 0), 2147483409);
+        assert_eq!(wallet_v5r1_id(true, 0, 0), 2147483645);
+        assert_eq!(wallet_v5r1_id(false, 0, 1), 2147483408);
+        assert_eq!(wallet_v5r1_id(false, -1, 0), 0x7fffff11 ^ (0xff << 23));
+
+        let data = wallet_v5r1_data(&[7u8; 32], wallet_v5r1_id(false, 0, 0)).unwrap();
+        assert_eq!(data.bit_len(), 322);
+        let mut s = data.parser();
+        assert!(s.load_bit().unwrap());
+        assert_eq!(s.load_uint(32).unwrap(), 0);
+        assert_eq!(s.load_uint(32).unwrap(), 2147483409);
+        assert_eq!(s.load_bytes(32).unwrap(), vec![7u8; 32]);
+        assert!(!s.load_bit().unwrap());
+
+    }
+
+    #[test]
+    fn test_v5r1_code_and_address() {
+        let code = wallet_v5r1_code().unwrap();
+        assert_eq!(hex::encode(code.hash()), WALLET_V5R1_CODE_HASH);
+        assert_eq!(Cell::from_boc(&code.to_boc()).unwrap(), code);
+
+        // Same key as tonlib-rs `TonWallet::new(V5R1, ..)` with secret key [5; 32]
+        let pubkey = SigningKey::from_bytes(&[5u8; 32]).verifying_key().to_bytes();
+        let address = WalletVersion::V5R1.address(&pubkey, false).unwrap();
+        assert_eq!(address.to_friendly(), "EQB_0hWkC4Hare8I0yrshB9zfD3ZKQjQuSMc9wWBWJkQlleQ");
+        assert_eq!(WalletVersion::V5R1.state_init(&pubkey, false).unwrap().hash(), address.hash);
+        assert_ne!(WalletVersion::V5R1.address(&pubkey, true).unwrap().hash, address.hash);
+        assert_eq!(WalletVersion::V4R2.address(&pubkey, true).unwrap().hash, wallet_v4r2_address(&pubkey).unwrap().hash);
+
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = bip39_utils::mnemonic_to_seed(phrase, "").unwrap();
+        assert_eq!(derive_ton_address_for_version(&seed, WalletVersion::V4R2, false).unwrap(), derive_ton_address(&seed).unwrap());
+        assert_ne!(derive_ton_address_for_version(&seed, WalletVersion::V5R1, false).unwrap(), derive_ton_address(&seed).unwrap());
+
+        assert_eq!(WalletVersion::from_id("v5r1"), Some(WalletVersion::V5R1));
+        assert_eq!(WalletVersion::from_id(WalletVersion::V4R2.id()), Some(WalletVersion::V4R2));
+        assert_eq!(WalletVersion::from_id("v3r2"), None);
+    }
+
+    #[test]
+    fn test_preferred_version() {
+        assert_eq!(preferred_version(&[]), WalletVersion::V4R2);
+        assert_eq!(preferred_version(&[(WalletVersion::V4R2, 0), (WalletVersion::V5R1, 0)]), WalletVersion::V4R2);
+        assert_eq!(preferred_version(&[(WalletVersion::V4R2, 0), (WalletVersion::V5R1, 1)]), WalletVersion::V5R1);
+        assert_eq!(preferred_version(&[(WalletVersion::V4R2, 5), (WalletVersion::V5R1, 9)]), WalletVersion::V4R2);
+    }
+
+    #[test]
+    fn test_ton_address_forms() {
+        let address = TonAddress::parse("EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v").unwrap();
+        assert!(address.bounceable && !address.testnet);
+        assert_eq!(address.workchain, 0);
+        let raw = address.to_raw_string();
+        assert!(raw.starts_with("0:36aaab6f"));
+        assert_eq!(TonAddress::parse(&raw).unwrap(), address);
+
+        let non_bounceable = TonAddress { bounceable: false, ..address };
+        let friendly = non_bounceable.to_friendly();
+        assert!(friendly.starts_with('U'));
+        assert_eq!(TonAddress::parse(&friendly).unwrap(), non_bounceable);
+        let master = TonAddress::parse(&format!("-1:{}", "ab".repeat(32))).unwrap();
+        assert_eq!(master.workchain, -1);
+        // tag 0x11, workchain 0xff
+        assert!(master.to_friendly().starts_with("Ef-r"));
+        assert!(TonAddress::parse("0:abcd").is_err());
     }
 
     #[test]
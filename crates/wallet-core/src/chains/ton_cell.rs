@@ -0,0 +1,723 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// chains/ton_cell: TON cells (TL-B building blocks) and Bag of Cells serialization
+//
+// Types:
+//   Cell         — up to 1023 bits and 4 references, with its representation hash and depth
+//   CellBuilder  — append bits, integers, coins, addresses and references
+//   CellSlice    — read a cell back in the same order
+// Functions:
+//   Cell::to_boc() / Cell::from_boc() — single-root BOC with CRC32C
+//   crc32c()                         — Castagnoli CRC used by BOCs
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+
+use super::ton::TonAddress;
+
+/// Maximum data bits in a cell
+pub const MAX_CELL_BITS: usize = 1023;
+/// Maximum references in a cell
+pub const MAX_CELL_REFS: usize = 4;
+/// Maximum cell depth accepted by the TON virtual machine
+pub const MAX_CELL_DEPTH: u16 = 1024;
+/// Cells a parsed BOC may expand to when shared references are counted once per use.
+/// Shared refs are stored once in memory, but code that walks the tree visits every use.
+pub const MAX_EXPANDED_CELLS: u64 = 1 << 16;
+
+const BOC_MAGIC: [u8; 4] = [0xb5, 0xee, 0x9c, 0x72];
+
+/// An ordinary (non-exotic) cell. Bits past `bit_len` in `data` are always zero.
+/// References are shared, so a child used several times is stored once.
+#[derive(Clone)]
+pub struct Cell {
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<Arc<Cell>>,
+    hash: [u8; 32],
+    depth: u16,
+}
+
+/// Cells are equal when their representation hashes are, without walking shared subtrees
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl Eq for Cell {}
+
+impl fmt::Debug for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cell")
+            .field("bit_len", &self.bit_len)
+            .field("data", &hex::encode(&self.data))
+            .field("refs", &self.refs.len())
+            .field("hash", &hex::encode(self.hash))
+            .finish()
+    }
+}
+
+impl Cell {
+    /// The cell with no bits and no references
+    pub fn empty() -> Cell {
+        CellBuilder::new().build()
+    }
+
+    fn new(data: Vec<u8>, bit_len: usize, refs: Vec<Arc<Cell>>) -> Cell {
+        let depth = refs.iter().map(|r| r.depth.saturating_add(1)).max().unwrap_or(0);
+        let mut repr = descriptors(bit_len, refs.len()).to_vec();
+        repr.extend_from_slice(&padded_data(&data, bit_len));
+        for r in &refs {
+            repr.extend_from_slice(&r.depth.to_be_bytes());
+        }
+        for r in &refs {
+            repr.extend_from_slice(&r.hash);
+        }
+        let hash: [u8; 32] = Sha256::digest(&repr).into();
+        Cell { data, bit_len, refs, hash, depth }
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn refs(&self) -> &[Arc<Cell>] {
+        &self.refs
+    }
+
+    /// Representation hash: what addresses, signatures and message hashes commit to
+    pub fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    pub fn parser(&self) -> CellSlice<'_> {
+        CellSlice { cell: self, bit: 0, next_ref: 0 }
+    }
+
+    /// Serialize as a single-root BOC with a CRC32C trailer (no index)
+    pub fn to_boc(&self) -> Vec<u8> {
+        serialize_boc(self, true)
+    }
+
+    /// Parse a BOC that holds exactly one root
+    pub fn from_boc(boc: &[u8]) -> Result<Cell, String> {
+        let mut roots = parse_boc(boc)?;
+        if roots.len() != 1 {
+            return Err(format!("Expected one BOC root, found {}", roots.len()));
+        }
+        Ok(roots.remove(0))
+    }
+}
+
+/// `d1` (refs count; ordinary, level 0) and `d2` (data length descriptor)
+fn descriptors(bit_len: usize, refs: usize) -> [u8; 2] {
+    [refs as u8, (bit_len / 8 + bit_len.div_ceil(8)) as u8]
+}
+
+/// Data bytes with the completion tag: a single 1 bit after the last data bit
+fn padded_data(data: &[u8], bit_len: usize) -> Vec<u8> {
+    let mut out = data[..bit_len.div_ceil(8)].to_vec();
+    if !bit_len.is_multiple_of(8) {
+        out[bit_len / 8] |= 0x80 >> (bit_len % 8);
+    }
+    out
+}
+
+/// Builder for a `Cell`; every store fails instead of exceeding the cell limits
+#[derive(Debug, Clone, Default)]
+pub struct CellBuilder {
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<Arc<Cell>>,
+}
+
+impl CellBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    /// Bits still free in the cell
+    pub fn remaining_bits(&self) -> usize {
+        MAX_CELL_BITS - self.bit_len
+    }
+
+    /// References still free in the cell
+    pub fn remaining_refs(&self) -> usize {
+        MAX_CELL_REFS - self.refs.len()
+    }
+
+    fn ensure_bits(&self, bits: usize) -> Result<(), String> {
+        if bits > self.remaining_bits() {
+            return Err("Cell overflow: more than 1023 bits".into());
+        }
+        Ok(())
+    }
+
+    fn push_bit(&mut self, bit: bool) {
+        if self.bit_len.is_multiple_of(8) {
+            self.data.push(0);
+        }
+        if bit {
+            self.data[self.bit_len / 8] |= 0x80 >> (self.bit_len % 8);
+        }
+        self.bit_len += 1;
+    }
+
+    pub fn store_bit(&mut self, bit: bool) -> Result<&mut Self, String> {
+        self.ensure_bits(1)?;
+        self.push_bit(bit);
+        Ok(self)
+    }
+
+    /// Unsigned big-endian integer of `bits` bits (at most 64)
+    pub fn store_uint(&mut self, value: u64, bits: usize) -> Result<&mut Self, String> {
+        if bits > 64 || (bits < 64 && value >> bits != 0) {
+            return Err(format!("Value {} does not fit in {} bits", value, bits));
+        }
+        self.ensure_bits(bits)?;
+        for i in (0..bits).rev() {
+            self.push_bit((value >> i) & 1 == 1);
+        }
+        Ok(self)
+    }
+
+    /// Two's complement integer of `bits` bits (at most 64)
+    pub fn store_int(&mut self, value: i64, bits: usize) -> Result<&mut Self, String> {
+        if bits == 0 || bits > 64 {
+            return Err(format!("Invalid integer width {}", bits));
+        }
+        if bits < 64 {
+            let limit = 1i64 << (bits - 1);
+            if value < -limit || value >= limit {
+                return Err(format!("Value {} does not fit in {} bits", value, bits));
+            }
+        }
+        let mask = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+        self.store_uint(value as u64 & mask, bits)
+    }
+
+    pub fn store_bytes(&mut self, bytes: &[u8]) -> Result<&mut Self, String> {
+        self.ensure_bits(bytes.len() * 8)?;
+        for byte in bytes {
+            for i in (0..8).rev() {
+                self.push_bit((byte >> i) & 1 == 1);
+            }
+        }
+        Ok(self)
+    }
+
+    /// `Coins` (VarUInteger 16): 4-bit byte length, then the amount big-endian
+    pub fn store_coins(&mut self, amount: u128) -> Result<&mut Self, String> {
+        let len = 16 - amount.leading_zeros() as usize / 8;
+        if len > 15 {
+            return Err("Coin amount exceeds 120 bits".into());
+        }
+        self.ensure_bits(4 + len * 8)?;
+        self.store_uint(len as u64, 4)?;
+        self.store_bytes(&amount.to_be_bytes()[16 - len..])
+    }
+
+    /// `MsgAddress`: `addr_std` without anycast, or `addr_none` for `None`
+    pub fn store_address(&mut self, address: Option<&TonAddress>) -> Result<&mut Self, String> {
+        match address {
+            None => self.store_uint(0, 2),
+            Some(address) => {
+                self.ensure_bits(267)?;
+                self.store_uint(0b100, 3)?;
+                self.store_int(address.workchain as i64, 8)?;
+                self.store_bytes(&address.hash)
+            }
+        }
+    }
+
+    pub fn store_ref(&mut self, cell: impl Into<Arc<Cell>>) -> Result<&mut Self, String> {
+        if self.refs.len() >= MAX_CELL_REFS {
+            return Err("Cell overflow: more than 4 references".into());
+        }
+        self.refs.push(cell.into());
+        Ok(self)
+    }
+
+    /// `Maybe ^Cell`: a presence bit, then the reference
+    pub fn store_maybe_ref(&mut self, cell: Option<Cell>) -> Result<&mut Self, String> {
+        match cell {
+            Some(cell) => {
+                if self.refs.len() >= MAX_CELL_REFS {
+                    return Err("Cell overflow: more than 4 references".into());
+                }
+                self.store_bit(true)?.store_ref(cell)
+            }
+            None => self.store_bit(false),
+        }
+    }
+
+    /// Append the bits and references of `cell` inline
+    pub fn store_cell(&mut self, cell: &Cell) -> Result<&mut Self, String> {
+        self.ensure_bits(cell.bit_len)?;
+        if self.refs.len() + cell.refs.len() > MAX_CELL_REFS {
+            return Err("Cell overflow: more than 4 references".into());
+        }
+        let mut slice = cell.parser();
+        while slice.remaining_bits() > 0 {
+            let bit = slice.load_bit()?;
+            self.push_bit(bit);
+        }
+        self.refs.extend(cell.refs.iter().cloned());
+        Ok(self)
+    }
+
+    pub fn build(&self) -> Cell {
+        Cell::new(self.data.clone(), self.bit_len, self.refs.clone())
+    }
+}
+
+/// Sequential reader over a cell's bits and references
+#[derive(Debug, Clone)]
+pub struct CellSlice<'a> {
+    cell: &'a Cell,
+    bit: usize,
+    next_ref: usize,
+}
+
+impl<'a> CellSlice<'a> {
+    pub fn remaining_bits(&self) -> usize {
+        self.cell.bit_len - self.bit
+    }
+
+    pub fn remaining_refs(&self) -> usize {
+        self.cell.refs.len() - self.next_ref
+    }
+
+    pub fn load_bit(&mut self) -> Result<bool, String> {
+        if self.remaining_bits() == 0 {
+            return Err("Cell underflow".into());
+        }
+        let bit = self.cell.data[self.bit / 8] & (0x80 >> (self.bit % 8)) != 0;
+        self.bit += 1;
+        Ok(bit)
+    }
+
+    pub fn load_uint(&mut self, bits: usize) -> Result<u64, String> {
+        if bits > 64 {
+            return Err(format!("Invalid integer width {}", bits));
+        }
+        if bits > self.remaining_bits() {
+            return Err("Cell underflow".into());
+        }
+        let mut value = 0u64;
+        for _ in 0..bits {
+            value = (value << 1) | self.load_bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    pub fn load_int(&mut self, bits: usize) -> Result<i64, String> {
+        let raw = self.load_uint(bits)?;
+        if bits == 0 || bits == 64 {
+            return Ok(raw as i64);
+        }
+        let shift = 64 - bits as u32;
+        Ok(((raw << shift) as i64) >> shift)
+    }
+
+    pub fn load_bytes(&mut self, len: usize) -> Result<Vec<u8>, String> {
+        (0..len).map(|_| self.load_uint(8).map(|b| b as u8)).collect()
+    }
+
+    pub fn load_coins(&mut self) -> Result<u128, String> {
+        let len = self.load_uint(4)? as usize;
+        Ok(self.load_bytes(len)?.iter().fold(0u128, |acc, b| (acc << 8) | *b as u128))
+    }
+
+    /// `addr_std` (without anycast) or `addr_none`
+    pub fn load_address(&mut self) -> Result<Option<TonAddress>, String> {
+        match self.load_uint(2)? {
+            0b00 => Ok(None),
+            0b10 => {
+                if self.load_bit()? {
+                    return Err("Anycast addresses are not supported".into());
+                }
+                let workchain = self.load_int(8)? as i8;
+                let hash: [u8; 32] = self.load_bytes(32)?.try_into().unwrap();
+                Ok(Some(TonAddress { workchain, hash, bounceable: true, testnet: false }))
+            }
+            _ => Err("Unsupported address type".into()),
+        }
+    }
+
+    pub fn load_ref(&mut self) -> Result<&'a Cell, String> {
+        let cell = self.cell.refs.get(self.next_ref).ok_or("No more cell references")?;
+        self.next_ref += 1;
+        Ok(&**cell)
+    }
+
+    pub fn load_maybe_ref(&mut self) -> Result<Option<&'a Cell>, String> {
+        if self.load_bit()? { self.load_ref().map(Some) } else { Ok(None) }
+    }
+}
+
+/// CRC32C (Castagnoli), as appended to BOCs in little-endian order
+pub fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0x82f6_3b78 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Bytes needed to hold `value` (at least one)
+fn byte_len(value: usize) -> usize {
+    (usize::BITS as usize - value.leading_zeros() as usize).div_ceil(8).max(1)
+}
+
+/// Unique cells reachable from `cell`, each before all the cells it references.
+/// Refs are visited last to first so that, once reversed, the order is the
+/// depth-first pre-order the reference serializers (@ton/core, tonweb) write.
+fn topological_order<'a>(cell: &'a Cell, order: &mut Vec<&'a Cell>, seen: &mut HashSet<[u8; 32]>) {
+    if !seen.insert(cell.hash) {
+        return;
+    }
+    for r in cell.refs.iter().rev() {
+        topological_order(r, order, seen);
+    }
+    order.push(cell);
+}
+
+fn write_be(out: &mut Vec<u8>, value: usize, len: usize) {
+    out.extend_from_slice(&value.to_be_bytes()[usize::BITS as usize / 8 - len..]);
+}
+
+/// Serialize `root` and its descendants, identical subtrees stored once
+pub fn serialize_boc(root: &Cell, with_crc: bool) -> Vec<u8> {
+    let mut order = Vec::new();
+    topological_order(root, &mut order, &mut HashSet::new());
+    // Post-order puts children first; reversed, every parent precedes its refs
+    order.reverse();
+    let index: HashMap<[u8; 32], usize> = order.iter().enumerate().map(|(i, c)| (c.hash, i)).collect();
+
+    let ref_size = byte_len(order.len());
+    let mut cells = Vec::new();
+    for cell in &order {
+        cells.extend_from_slice(&descriptors(cell.bit_len, cell.refs.len()));
+        cells.extend_from_slice(&padded_data(&cell.data, cell.bit_len));
+        for r in &cell.refs {
+            write_be(&mut cells, index[&r.hash], ref_size);
+        }
+    }
+    let offset_size = byte_len(cells.len());
+
+    let mut out = BOC_MAGIC.to_vec();
+    // has_idx = 0, has_crc32c, has_cache_bits = 0, flags = 0, size
+    out.push(((with_crc as u8) << 6) | ref_size as u8);
+    out.push(offset_size as u8);
+    write_be(&mut out, order.len(), ref_size);
+    write_be(&mut out, 1, ref_size); // roots
+    write_be(&mut out, 0, ref_size); // absent
+    write_be(&mut out, cells.len(), offset_size);
+    write_be(&mut out, 0, ref_size); // root index
+    out.extend_from_slice(&cells);
+    if with_crc {
+        let crc = crc32c(&out);
+        out.extend_from_slice(&crc.to_le_bytes());
+    }
+    out
+}
+
+/// Parse a BOC into its root cells
+pub fn parse_boc(boc: &[u8]) -> Result<Vec<Cell>, String> {
+    if boc.len() < 6 || boc[..4] != BOC_MAGIC {
+        return Err("Invalid BOC magic".into());
+    }
+    let flags = boc[4];
+    let has_idx = flags & 0x80 != 0;
+    let has_crc = flags & 0x40 != 0;
+    let ref_size = (flags & 0x07) as usize;
+    let offset_size = boc[5] as usize;
+    if !(1..=4).contains(&ref_size) || !(1..=8).contains(&offset_size) {
+        return Err("Invalid BOC size fields".into());
+    }
+    let body = if has_crc {
+        if boc.len() < 10 {
+            return Err("BOC truncated".into());
+        }
+        let (body, crc) = boc.split_at(boc.len() - 4);
+        if crc32c(body).to_le_bytes() != crc {
+            return Err("BOC checksum mismatch".into());
+        }
+        body
+    } else {
+        boc
+    };
+
+    let mut pos = 6;
+    let mut read = |len: usize| -> Result<usize, String> {
+        let bytes = body.get(pos..pos + len).ok_or("BOC truncated")?;
+        pos += len;
+        Ok(bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize))
+    };
+    let cell_count = read(ref_size)?;
+    let root_count = read(ref_size)?;
+    let absent = read(ref_size)?;
+    let _total_size = read(offset_size)?;
+    // Every cell takes at least its 2 descriptor bytes: bound the counts by the
+    // payload before allocating anything from them
+    let payload = body.len().saturating_sub(6 + 3 * ref_size + offset_size);
+    if cell_count > payload / 2 || root_count > cell_count || absent > cell_count {
+        return Err("Invalid BOC cell count".into());
+    }
+    let roots = (0..root_count).map(|_| read(ref_size)).collect::<Result<Vec<_>, _>>()?;
+    if has_idx {
+        for _ in 0..cell_count {
+            read(offset_size)?;
+        }
+    }
+
+    // (bit length, data, ref indices) per cell
+    let mut raw = Vec::with_capacity(cell_count);
+    for _ in 0..cell_count {
+        let d1 = read(1)? as u8;
+        let d2 = read(1)? as u8;
+        if d1 & 0x08 != 0 || d1 >> 5 != 0 {
+            return Err("Exotic and higher-level cells are not supported".into());
+        }
+        if d1 & 0x10 != 0 {
+            return Err("Cells with stored hashes are not supported".into());
+        }
+        let ref_count = (d1 & 0x07) as usize;
+        if ref_count > MAX_CELL_REFS {
+            return Err("Invalid cell reference count".into());
+        }
+        let data_len = (d2 as usize).div_ceil(2);
+        let mut data = (0..data_len).map(|_| read(1).map(|b| b as u8)).collect::<Result<Vec<_>, _>>()?;
+        let bit_len = if d2 % 2 == 1 {
+            // Strip the completion tag
+            let last = data.last_mut().ok_or("Invalid cell data")?;
+            if *last == 0 {
+                return Err("Missing cell completion tag".into());
+            }
+            let tag = last.trailing_zeros() as usize;
+            *last &= !(1u8 << tag);
+            data_len * 8 - tag - 1
+        } else {
+            data_len * 8
+        };
+        let refs = (0..ref_count).map(|_| read(ref_size)).collect::<Result<Vec<_>, _>>()?;
+        raw.push((bit_len, data, refs));
+    }
+
+    // References always point forward, so build from the last cell backwards. A cell
+    // referenced several times is built once and shared; the expanded size (every use
+    // counted) is tracked so that a small DAG cannot stand for a huge tree.
+    let mut built: Vec<Option<(Arc<Cell>, u64)>> = vec![None; cell_count];
+    for (i, (bit_len, data, refs)) in raw.into_iter().enumerate().rev() {
+        let mut children = Vec::with_capacity(refs.len());
+        let mut expanded = 1u64;
+        let mut depth = 0u16;
+        for r in refs {
+            let (child, child_expanded) = match built.get(r) {
+                Some(Some(child)) if r > i => child,
+                _ => return Err("Invalid BOC cell reference".into()),
+            };
+            expanded = expanded.checked_add(*child_expanded).ok_or("BOC expands to too many cells")?;
+            depth = depth.max(child.depth.checked_add(1).ok_or("BOC cell too deep")?);
+            children.push(child.clone());
+        }
+        if expanded > MAX_EXPANDED_CELLS {
+            return Err("BOC expands to too many cells".into());
+        }
+        if depth > MAX_CELL_DEPTH {
+            return Err("BOC cell too deep".into());
+        }
+        built[i] = Some((Arc::new(Cell::new(data, bit_len, children)), expanded));
+    }
+    roots.iter()
+        .map(|r| match built.get(*r) {
+            Some(Some((cell, _))) => Ok(Cell::clone(cell)),
+            _ => Err("Invalid BOC root index".to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32c() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn test_empty_cell_boc() {
+        // ton-core: beginCell().endCell().toBoc() = te6cckEBAQEAAgAAAEysuc0=
+        let expected = [0xb5, 0xee, 0x9c, 0x72, 0x41, 0x01, 0x01, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x4c, 0xac, 0xb9, 0xcd];
+        assert_eq!(Cell::empty().to_boc(), expected);
+        assert_eq!(Cell::from_boc(&expected).unwrap(), Cell::empty());
+        // Hash of the empty cell's representation [0, 0]
+        assert_eq!(hex::encode(Cell::empty().hash()), "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7");
+    }
+
+    #[test]
+    fn test_boc_counts_bounded_by_payload() {
+        // 2^32 - 1 cells claimed in a 23-byte BOC: rejected before allocating
+        let huge = hex::decode("b5ee9c720401ffffffff00000001000000000000000000").unwrap();
+        assert_eq!(huge.len(), 23);
+        assert!(parse_boc(&huge).is_err());
+
+        // More roots than cells
+        let mut roots = serialize_boc(&Cell::empty(), false);
+        roots[7] = 2;
+        assert!(parse_boc(&roots).is_err());
+        // Cell descriptor with the has_hashes bit
+        let mut hashes = serialize_boc(&Cell::empty(), false);
+        hashes[11] = 0x10;
+        assert!(parse_boc(&hashes).is_err());
+    }
+
+    /// `levels` + 1 cells, each referencing the next one 4 times: 4^levels leaves when expanded
+    fn shared_ref_chain(levels: usize) -> Cell {
+        let mut cell = Cell::empty();
+        for level in 0..levels {
+            let mut b = CellBuilder::new();
+            b.store_uint(level as u64, 8).unwrap();
+            let child = Arc::new(cell);
+            for _ in 0..MAX_CELL_REFS {
+                b.store_ref(child.clone()).unwrap();
+            }
+            cell = b.build();
+        }
+        cell
+    }
+
+    #[test]
+    fn test_boc_shared_refs_are_not_expanded() {
+        // 7 levels: 5461 cells when expanded, parsed from 8 stored cells
+        let dag = shared_ref_chain(7);
+        let boc = dag.to_boc();
+        assert_eq!(boc[6], 8);
+        let parsed = Cell::from_boc(&boc).unwrap();
+        assert_eq!(parsed.hash(), dag.hash());
+        assert!(Arc::ptr_eq(&parsed.refs()[0], &parsed.refs()[3]));
+
+        // 40 levels in a few hundred bytes would be 4^40 cells: rejected quickly
+        let bomb = shared_ref_chain(40).to_boc();
+        assert!(bomb.len() < 512);
+        let start = std::time::Instant::now();
+        assert!(Cell::from_boc(&bomb).unwrap_err().contains("too many cells"));
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_boc_depth_limit() {
+        // Every level references a different cell, so the chain is stored in full
+        let chain = |depth: u16| {
+            let mut cell = Cell::empty();
+            for _ in 0..depth {
+                let mut b = CellBuilder::new();
+                b.store_ref(cell).unwrap();
+                cell = b.build();
+            }
+            cell
+        };
+        assert_eq!(Cell::from_boc(&chain(MAX_CELL_DEPTH).to_boc()).unwrap().depth(), MAX_CELL_DEPTH);
+        assert!(Cell::from_boc(&chain(MAX_CELL_DEPTH + 1).to_boc()).unwrap_err().contains("too deep"));
+    }
+
+    #[test]
+    fn test_builder_and_slice() {
+        let child = {
+            let mut b = CellBuilder::new();
+            b.store_uint(0xdead, 16).unwrap();
+            b.build()
+        };
+        let address = TonAddress { workchain: -1, hash: [0xab; 32], bounceable: true, testnet: false };
+        let mut b = CellBuilder::new();
+        b.store_bit(true).unwrap()
+            .store_uint(5, 3).unwrap()
+            .store_int(-2, 8).unwrap()
+            .store_coins(1_000_000_000).unwrap()
+            .store_coins(0).unwrap()
+            .store_address(Some(&address)).unwrap()
+            .store_address(None).unwrap()
+            .store_maybe_ref(Some(child.clone())).unwrap();
+        let cell = b.build();
+        assert_eq!(cell.bit_len(), 1 + 3 + 8 + (4 + 32) + 4 + 267 + 2 + 1);
+        assert_eq!(cell.depth(), 1);
+
+        let mut s = cell.parser();
+        assert!(s.load_bit().unwrap());
+        assert_eq!(s.load_uint(3).unwrap(), 5);
+        assert_eq!(s.load_int(8).unwrap(), -2);
+        assert_eq!(s.load_coins().unwrap(), 1_000_000_000);
+        assert_eq!(s.load_coins().unwrap(), 0);
+        assert_eq!(s.load_address().unwrap().unwrap().workchain, -1);
+        assert_eq!(s.load_address().unwrap(), None);
+        assert_eq!(s.load_maybe_ref().unwrap(), Some(&child));
+        assert_eq!(s.remaining_bits(), 0);
+        assert!(s.load_bit().is_err());
+
+        assert!(CellBuilder::new().store_uint(4, 2).is_err());
+        assert!(CellBuilder::new().store_int(128, 8).is_err());
+        assert!(CellBuilder::new().store_bytes(&[0u8; 128]).is_err());
+        let mut full = CellBuilder::new();
+        for _ in 0..4 {
+            full.store_ref(Cell::empty()).unwrap();
+        }
+        assert!(full.store_ref(Cell::empty()).is_err());
+    }
+
+    #[test]
+    fn test_boc_roundtrip_shares_cells() {
+        let leaf = {
+            let mut b = CellBuilder::new();
+            b.store_uint(7, 5).unwrap();
+            b.build()
+        };
+        let mut b = CellBuilder::new();
+        b.store_bytes(b"root").unwrap().store_ref(leaf.clone()).unwrap().store_ref(leaf).unwrap();
+        let root = b.build();
+        let boc = root.to_boc();
+        // Two cells only: the shared leaf is stored once
+        assert_eq!(boc[6], 2);
+        assert_eq!(Cell::from_boc(&boc).unwrap(), root);
+        assert_eq!(Cell::from_boc(&serialize_boc(&root, false)).unwrap().hash(), root.hash());
+
+        let mut corrupted = boc.clone();
+        corrupted[12] ^= 1;
+        assert!(Cell::from_boc(&corrupted).is_err());
+        assert!(Cell::from_boc(&boc[..boc.len() - 5]).is_err());
+    }
+
+    #[test]
+    fn test_inline_cell() {
+        let mut inner = CellBuilder::new();
+        inner.store_uint(0b101, 3).unwrap().store_ref(Cell::empty()).unwrap();
+        let inner = inner.build();
+        let mut outer = CellBuilder::new();
+        outer.store_bit(false).unwrap().store_cell(&inner).unwrap();
+        let outer = outer.build();
+        assert_eq!(outer.bit_len(), 4);
+        assert_eq!(outer.data(), &[0b0101_0000]);
+        assert_eq!(outer.refs().len(), 1);
+    }
+}
@@ -0,0 +1,324 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// ens: ENS name normalisation, namehash and registry/resolver calldata (eth_call based)
+//
+// Forward lookup: registry.resolver(node) -> resolver.addr(node) / addr(node, coinType)
+// Reverse lookup: "<addr>.addr.reverse" -> resolver.name(node), then forward-verified
+
+use crate::chains::evm::eip55_checksum;
+use crate::signing::keccak256;
+
+/// ENS registry (same address on mainnet and testnets)
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// SLIP-44 coin type of Ether; resolved with the legacy `addr(bytes32)`
+pub const ETH_COIN_TYPE: u64 = 60;
+
+/// ENSIP-19 default EVM coin type (chain id 0): the address for every EVM chain
+/// without its own record
+pub const DEFAULT_EVM_COIN_TYPE: u64 = 0x8000_0000;
+
+/// resolver(bytes32)
+const SELECTOR_RESOLVER: [u8; 4] = [0x01, 0x78, 0xb8, 0xbf];
+/// addr(bytes32)
+const SELECTOR_ADDR: [u8; 4] = [0x3b, 0x3b, 0x57, 0xde];
+/// addr(bytes32,uint256) — ENSIP-9 multichain
+const SELECTOR_ADDR_COIN: [u8; 4] = [0xf1, 0xcb, 0x7e, 0x06];
+/// name(bytes32)
+const SELECTOR_NAME: [u8; 4] = [0x69, 0x1f, 0x34, 0x31];
+
+/// True when `input` should be resolved as an ENS name rather than used as an address
+pub fn looks_like_ens_name(input: &str) -> bool {
+    let s = input.trim();
+    !s.is_empty()
+        && !s.starts_with("0x")
+        && !s.chars().any(char::is_whitespace)
+        && s.contains('.')
+        && !s.ends_with('.')
+}
+
+/// Normalise a name with UTS-46 (non-transitional) mapping and STD3 ASCII rules.
+/// Rejects empty labels and invisible characters that make look-alike names.
+pub fn normalize(name: &str) -> Result<String, String> {
+    // UTS-46 silently drops these, so "fo\u{200B}o.eth" would display differently than it hashes
+    if name.chars().any(is_invisible) {
+        return Err("ENS name contains invisible characters".into());
+    }
+    let (mapped, result) = idna::domain_to_unicode(name.trim());
+    result.map_err(|_| format!("Invalid ENS name: {}", name))?;
+    if mapped.is_empty() {
+        return Err("ENS name is empty".into());
+    }
+    for label in mapped.split('.') {
+        if label.is_empty() {
+            return Err(format!("Invalid ENS name (empty label): {}", name));
+        }
+        for c in label.chars() {
+            let allowed = if c.is_ascii() {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'
+            } else {
+                !c.is_control() && !c.is_whitespace() && !is_invisible(c)
+            };
+            if !allowed {
+                return Err(format!("Invalid character {:?} in ENS name", c));
+            }
+        }
+    }
+    Ok(mapped)
+}
+
+/// Zero-width and format characters that hide inside look-alike names
+fn is_invisible(c: char) -> bool {
+    matches!(c, '\u{200B}' | '\u{2060}' | '\u{FEFF}' | '\u{00AD}')
+}
+
+/// EIP-137 namehash of an already normalised name
+pub fn namehash(name: &str) -> [u8; 32] {
+    let mut node = [0u8; 32];
+    if name.is_empty() {
+        return node;
+    }
+    for label in name.rsplit('.') {
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(&node);
+        buf[32..].copy_from_slice(&keccak256(label.as_bytes()));
+        node = keccak256(&buf);
+    }
+    node
+}
+
+/// Node of the reverse record for an address ("<hex>.addr.reverse")
+pub fn reverse_node(address: &str) -> Result<[u8; 32], String> {
+    let hex_addr = address.strip_prefix("0x").unwrap_or(address).to_lowercase();
+    if hex_addr.len() != 40 || hex::decode(&hex_addr).is_err() {
+        return Err(format!("Invalid address: {}", address));
+    }
+    Ok(namehash(&format!("{}.addr.reverse", hex_addr)))
+}
+
+/// ENSIP-11 coin type for an EVM chain id (mainnet keeps SLIP-44 60)
+pub fn evm_coin_type(chain_id: u64) -> u64 {
+    if chain_id == 1 {
+        ETH_COIN_TYPE
+    } else {
+        DEFAULT_EVM_COIN_TYPE | chain_id
+    }
+}
+
+/// Record to read when `coin_type` has none (ENSIP-19): the default EVM record for
+/// chain-specific EVM coin types. Ether and the default record have no fallback.
+pub fn fallback_coin_type(coin_type: u64) -> Option<u64> {
+    let is_chain_specific = coin_type & DEFAULT_EVM_COIN_TYPE != 0 && coin_type != DEFAULT_EVM_COIN_TYPE;
+    is_chain_specific.then_some(DEFAULT_EVM_COIN_TYPE)
+}
+
+fn call_with_node(selector: [u8; 4], node: &[u8; 32]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(36);
+    data.extend_from_slice(&selector);
+    data.extend_from_slice(node);
+    data
+}
+
+/// Registry calldata: resolver(node)
+pub fn encode_resolver_call(node: &[u8; 32]) -> Vec<u8> {
+    call_with_node(SELECTOR_RESOLVER, node)
+}
+
+/// Resolver calldata for a coin type: addr(node) for Ether, addr(node, coinType) otherwise
+pub fn encode_addr_call(node: &[u8; 32], coin_type: u64) -> Vec<u8> {
+    if coin_type == ETH_COIN_TYPE {
+        return call_with_node(SELECTOR_ADDR, node);
+    }
+    let mut data = call_with_node(SELECTOR_ADDR_COIN, node);
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&coin_type.to_be_bytes());
+    data.extend_from_slice(&word);
+    data
+}
+
+/// Resolver calldata: name(node), used on reverse nodes
+pub fn encode_name_call(node: &[u8; 32]) -> Vec<u8> {
+    call_with_node(SELECTOR_NAME, node)
+}
+
+fn decode_result_hex(result: &str) -> Result<Vec<u8>, String> {
+    hex::decode(result.strip_prefix("0x").unwrap_or(result))
+        .map_err(|e| format!("Invalid eth_call result: {}", e))
+}
+
+/// Decode an `address` return value. The zero address means "not set".
+pub fn decode_address(result: &str) -> Result<Option<String>, String> {
+    let bytes = decode_result_hex(result)?;
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    if bytes.len() < 32 {
+        return Err("Short address result".into());
+    }
+    let addr = &bytes[12..32];
+    if addr.iter().all(|b| *b == 0) {
+        return Ok(None);
+    }
+    Ok(Some(eip55_checksum(addr)))
+}
+
+/// Decode a dynamic `bytes` or `string` return value
+pub fn decode_bytes(result: &str) -> Result<Vec<u8>, String> {
+    let bytes = decode_result_hex(result)?;
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let word = |at: usize| -> Result<usize, String> {
+        let w = bytes.get(at..at.saturating_add(32)).ok_or("Truncated ABI result")?;
+        if w[..28].iter().any(|b| *b != 0) {
+            return Err("ABI offset out of range".into());
+        }
+        Ok(u32::from_be_bytes(w[28..].try_into().unwrap()) as usize)
+    };
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset.saturating_add(32);
+    bytes.get(start..start.saturating_add(len))
+        .map(|b| b.to_vec())
+        .ok_or_else(|| "Truncated ABI result".into())
+}
+
+/// Decode the result of addr(node, coinType) into an address for EVM coin types.
+/// Non-EVM coin types use chain-specific binary formats and are not supported.
+pub fn decode_coin_address(result: &str, coin_type: u64) -> Result<Option<String>, String> {
+    if coin_type != ETH_COIN_TYPE && coin_type & DEFAULT_EVM_COIN_TYPE == 0 {
+        return Err(format!("Unsupported ENS coin type: {}", coin_type));
+    }
+    let raw = decode_bytes(result)?;
+    match raw.len() {
+        0 => Ok(None),
+        20 => Ok(Some(eip55_checksum(&raw))),
+        n => Err(format!("Unexpected address length {} for coin type {}", n, coin_type)),
+    }
+}
+
+/// A reverse record is only trusted when the name resolves back to the same address
+pub fn verified_primary_name(reverse_name: &str, forward_address: Option<&str>, address: &str) -> Option<String> {
+    let name = normalize(reverse_name).ok()?;
+    let forward = forward_address?;
+    forward.eq_ignore_ascii_case(address).then_some(name)
+}
+
+/// Fail if a name now resolves to a different address than the one shown in the preview
+pub fn ensure_unchanged(name: &str, previewed: &str, current: &str) -> Result<(), String> {
+    if previewed.eq_ignore_ascii_case(current) {
+        Ok(())
+    } else {
+        Err(format!("{} now resolves to {} (was {}); review the recipient again", name, current, previewed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(sig: &str) -> [u8; 4] {
+        keccak256(sig.as_bytes())[..4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(SELECTOR_RESOLVER, selector("resolver(bytes32)"));
+        assert_eq!(SELECTOR_ADDR, selector("addr(bytes32)"));
+        assert_eq!(SELECTOR_ADDR_COIN, selector("addr(bytes32,uint256)"));
+        assert_eq!(SELECTOR_NAME, selector("name(bytes32)"));
+    }
+
+    #[test]
+    fn test_namehash_vectors() {
+        assert_eq!(namehash(""), [0u8; 32]);
+        assert_eq!(
+            hex::encode(namehash("eth")),
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+        );
+        assert_eq!(
+            hex::encode(namehash("foo.eth")),
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Vitalik.ETH").unwrap(), "vitalik.eth");
+        assert_eq!(normalize("  nick.eth ").unwrap(), "nick.eth");
+        assert!(normalize("foo..eth").is_err());
+        assert!(normalize("foo bar.eth").is_err());
+        assert!(normalize("foo$.eth").is_err());
+        assert!(normalize("fo\u{200B}o.eth").is_err());
+        assert!(looks_like_ens_name("vitalik.eth"));
+        assert!(!looks_like_ens_name("0xd8da6bf26964af9d7eed9e03e53415d37aa96045"));
+        assert!(!looks_like_ens_name("vitalik"));
+    }
+
+    #[test]
+    fn test_addr_calldata() {
+        let node = namehash("foo.eth");
+        let eth = encode_addr_call(&node, ETH_COIN_TYPE);
+        assert_eq!(eth.len(), 36);
+        assert_eq!(&eth[..4], &SELECTOR_ADDR);
+
+        let op = encode_addr_call(&node, evm_coin_type(10));
+        assert_eq!(op.len(), 68);
+        assert_eq!(&op[..4], &SELECTOR_ADDR_COIN);
+        assert_eq!(u64::from_be_bytes(op[60..68].try_into().unwrap()), 0x8000_000a);
+        assert_eq!(evm_coin_type(1), 60);
+
+        let default = encode_addr_call(&node, DEFAULT_EVM_COIN_TYPE);
+        assert_eq!(hex::encode(&default[36..]), format!("{:064x}", 0x8000_0000u64));
+    }
+
+    #[test]
+    fn test_fallback_coin_type() {
+        // L2 records fall back to the ENSIP-19 default, never to the mainnet Ether record
+        assert_eq!(fallback_coin_type(evm_coin_type(10)), Some(DEFAULT_EVM_COIN_TYPE));
+        assert_eq!(fallback_coin_type(evm_coin_type(8453)), Some(DEFAULT_EVM_COIN_TYPE));
+        assert_eq!(fallback_coin_type(ETH_COIN_TYPE), None);
+        assert_eq!(fallback_coin_type(DEFAULT_EVM_COIN_TYPE), None);
+        assert_eq!(fallback_coin_type(0), None);
+    }
+
+    #[test]
+    fn test_decode_results() {
+        let word = format!("0x{:0>64}", "d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(
+            decode_address(&word).unwrap().unwrap(),
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+        );
+        assert_eq!(decode_address(&format!("0x{}", "0".repeat(64))).unwrap(), None);
+        assert_eq!(decode_address("0x").unwrap(), None);
+
+        // string "vitalik.eth" ABI-encoded
+        let name_hex = hex::encode("vitalik.eth");
+        let encoded = format!("0x{:064x}{:064x}{:0<64}", 32, 11, name_hex);
+        assert_eq!(decode_bytes(&encoded).unwrap(), b"vitalik.eth");
+
+        // bytes holding a 20-byte address for addr(node, coinType)
+        let addr_bytes = format!("0x{:064x}{:064x}{:0<64}", 32, 20, "d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(
+            decode_coin_address(&addr_bytes, evm_coin_type(10)).unwrap().unwrap(),
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+        );
+        assert!(decode_coin_address(&addr_bytes, 0).is_err());
+    }
+
+    #[test]
+    fn test_reverse_and_guards() {
+        let node = reverse_node("0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045").unwrap();
+        assert_eq!(node, namehash("d8da6bf26964af9d7eed9e03e53415d37aa96045.addr.reverse"));
+        assert!(reverse_node("0x1234").is_err());
+
+        let addr = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+        assert_eq!(verified_primary_name("vitalik.eth", Some(addr), addr).as_deref(), Some("vitalik.eth"));
+        assert_eq!(verified_primary_name("vitalik.eth", Some("0x0000000000000000000000000000000000000001"), addr), None);
+        assert_eq!(verified_primary_name("vitalik.eth", None, addr), None);
+
+        assert!(ensure_unchanged("vitalik.eth", addr, &addr.to_lowercase()).is_ok());
+        assert!(ensure_unchanged("vitalik.eth", addr, "0x0000000000000000000000000000000000000001").is_err());
+    }
+}
@@ -10,6 +10,8 @@
 //   chains       — Address derivation per chain (EVM, Solana, TON, Cosmos)
 //   crypto       — AES-256-GCM encrypt/decrypt with PBKDF2
 //   wallet       — Multi-wallet manager (create, unlock, store)
+//   ens          — ENS namehash, normalisation and resolver calldata
+//   tonconnect   — TON Connect 2 wallet protocol (sessions, ton_proof, bridge)
 
 pub mod bip39_utils;
 pub mod bip32_utils;
@@ -25,3 +27,5 @@ pub mod security;
 pub mod backup;
 pub mod nft;
 pub mod swap;
+pub mod ens;
+pub mod tonconnect;
@@ -1,8 +1,12 @@
-// Rusby Wallet — NFT types and helpers
+// Rusby Wallet — NFT types, helpers and transfer encoding
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::tokens::spl::{self, program_key};
+use crate::tx::solana::{find_program_address, AccountMeta, Instruction};
 
 /// Single NFT item
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +90,293 @@ pub fn group_by_collection(items: &[NftItem]) -> Vec<NftCollection> {
     result
 }
 
+/// ERC-721 safeTransferFrom(address,address,uint256)
+const SELECTOR_ERC721_SAFE_TRANSFER: [u8; 4] = [0x42, 0x84, 0x2e, 0x0e];
+/// ERC-1155 safeTransferFrom(address,address,uint256,uint256,bytes)
+const SELECTOR_ERC1155_SAFE_TRANSFER: [u8; 4] = [0xf2, 0x42, 0x43, 0x2a];
+
+/// Metaplex Token Metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+/// Metaplex Token Auth Rules program (pNFT rule sets)
+pub const TOKEN_AUTH_RULES_PROGRAM_ID: &str = "auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg";
+/// Instructions sysvar, read by Token Metadata to inspect the calling transaction
+pub const SYSVAR_INSTRUCTIONS_ID: &str = "Sysvar1nstructions1111111111111111111111111";
+
+/// Token Metadata `Transfer` instruction discriminator
+const IX_METADATA_TRANSFER: u8 = 49;
+/// MetadataV1 account key
+const METADATA_V1_KEY: u8 = 4;
+
+/// How an NFT is transferred
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftStandard {
+    Erc721,
+    Erc1155,
+    /// Solana Metaplex NFT (plain SPL or programmable)
+    Metaplex,
+}
+
+impl NftStandard {
+    /// Map `NftItem::token_standard` ("ERC721", "ERC-1155", "Metaplex", ...)
+    pub fn from_item(item: &NftItem) -> Option<Self> {
+        match item.token_standard.to_uppercase().replace('-', "").as_str() {
+            "ERC721" => Some(NftStandard::Erc721),
+            "ERC1155" => Some(NftStandard::Erc1155),
+            "METAPLEX" => Some(NftStandard::Metaplex),
+            _ => None,
+        }
+    }
+}
+
+/// Token id as a uint256 word; accepts decimal (indexer format) or 0x-hex
+pub fn parse_token_id(token_id: &str) -> Result<[u8; 32], String> {
+    let token_id = token_id.trim();
+    let mut word = [0u8; 32];
+    if let Some(hex_id) = token_id.strip_prefix("0x") {
+        if hex_id.is_empty() || hex_id.len() > 64 {
+            return Err("Invalid token id".into());
+        }
+        let bytes = hex::decode(format!("{:0>64}", hex_id)).map_err(|_| "Invalid token id")?;
+        word.copy_from_slice(&bytes);
+        return Ok(word);
+    }
+    if token_id.is_empty() || !token_id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("Invalid token id".into());
+    }
+    for digit in token_id.bytes() {
+        let mut carry = (digit - b'0') as u32;
+        for byte in word.iter_mut().rev() {
+            let v = *byte as u32 * 10 + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err("Token id exceeds uint256".into());
+        }
+    }
+    Ok(word)
+}
+
+fn address_word(address: &str) -> Result<[u8; 32], String> {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(&crate::tx::evm::parse_address(address)?);
+    Ok(word)
+}
+
+/// `safeTransferFrom(from, to, tokenId)` calldata for an ERC-721
+pub fn encode_erc721_safe_transfer_from(from: &str, to: &str, token_id: &str) -> Result<Vec<u8>, String> {
+    let mut data = SELECTOR_ERC721_SAFE_TRANSFER.to_vec();
+    data.extend_from_slice(&address_word(from)?);
+    data.extend_from_slice(&address_word(to)?);
+    data.extend_from_slice(&parse_token_id(token_id)?);
+    Ok(data)
+}
+
+/// `safeTransferFrom(from, to, id, amount, "")` calldata for an ERC-1155
+pub fn encode_erc1155_safe_transfer_from(from: &str, to: &str, token_id: &str, amount: u128) -> Result<Vec<u8>, String> {
+    if amount == 0 {
+        return Err("Amount must be greater than zero".into());
+    }
+    let mut data = SELECTOR_ERC1155_SAFE_TRANSFER.to_vec();
+    data.extend_from_slice(&address_word(from)?);
+    data.extend_from_slice(&address_word(to)?);
+    data.extend_from_slice(&parse_token_id(token_id)?);
+    let mut amount_word = [0u8; 32];
+    amount_word[16..].copy_from_slice(&amount.to_be_bytes());
+    data.extend_from_slice(&amount_word);
+    // Offset of the empty `bytes data` argument, then its zero length
+    let mut offset = [0u8; 32];
+    offset[31] = 0xa0;
+    data.extend_from_slice(&offset);
+    data.extend_from_slice(&[0u8; 32]);
+    Ok(data)
+}
+
+/// Fields of a Metaplex metadata account that decide how the NFT moves
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetaplexMetadata {
+    /// On-chain name (NUL padding stripped); also used for fungible SPL mints
+    pub name: String,
+    pub symbol: String,
+    /// ProgrammableNonFungible (or its edition): transfers must go through Token Metadata
+    pub programmable: bool,
+    /// Authorization rule set enforced on programmable transfers
+    pub rule_set: Option<[u8; 32]>,
+}
+
+fn metadata_seeds_address(mint: &[u8; 32], extra: &[&[u8]]) -> Result<[u8; 32], String> {
+    let program = program_key(TOKEN_METADATA_PROGRAM_ID);
+    let mut seeds: Vec<&[u8]> = vec![b"metadata", &program, mint];
+    seeds.extend_from_slice(extra);
+    Ok(find_program_address(&seeds, &program)?.0)
+}
+
+pub fn metadata_address(mint: &[u8; 32]) -> Result<[u8; 32], String> {
+    metadata_seeds_address(mint, &[])
+}
+
+pub fn master_edition_address(mint: &[u8; 32]) -> Result<[u8; 32], String> {
+    metadata_seeds_address(mint, &[b"edition"])
+}
+
+pub fn token_record_address(mint: &[u8; 32], token_account: &[u8; 32]) -> Result<[u8; 32], String> {
+    metadata_seeds_address(mint, &[b"token_record", token_account])
+}
+
+/// Borsh reader over a metadata account; running past the end reads as zeros,
+/// which matches the zero padding of older, shorter accounts.
+struct BorshReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl BorshReader<'_> {
+    fn skip(&mut self, n: usize) {
+        self.pos = self.pos.saturating_add(n);
+    }
+
+    fn u8(&mut self) -> u8 {
+        let b = self.data.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        b
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes([self.u8(), self.u8(), self.u8(), self.u8()])
+    }
+
+    fn pubkey(&mut self) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        for b in key.iter_mut() {
+            *b = self.u8();
+        }
+        key
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.u32() as usize;
+        if self.pos + len > self.data.len() {
+            return Err("Truncated metadata string".into());
+        }
+        let value = String::from_utf8_lossy(&self.data[self.pos..self.pos + len])
+            .trim_end_matches('\0')
+            .to_string();
+        self.skip(len);
+        Ok(value)
+    }
+}
+
+/// Parse the raw bytes of a Token Metadata `MetadataV1` account
+pub fn parse_metadata_account(data: &[u8]) -> Result<MetaplexMetadata, String> {
+    let mut r = BorshReader { data, pos: 0 };
+    if r.u8() != METADATA_V1_KEY {
+        return Err("Not a Metaplex metadata account".into());
+    }
+    r.skip(64); // update authority, mint
+    let name = r.string()?;
+    let symbol = r.string()?;
+    r.string()?; // uri
+    r.skip(2); // seller fee basis points
+    if r.u8() == 1 {
+        let creators = r.u32() as usize;
+        r.skip(creators.saturating_mul(34));
+    }
+    r.skip(2); // primary sale happened, is mutable
+    if r.u8() == 1 {
+        r.skip(1); // edition nonce
+    }
+    let token_standard = if r.u8() == 1 { Some(r.u8()) } else { None };
+    if r.u8() == 1 {
+        r.skip(33); // collection
+    }
+    if r.u8() == 1 {
+        r.skip(17); // uses
+    }
+    if r.u8() == 1 {
+        r.skip(9); // collection details
+    }
+    let rule_set = if r.u8() == 1 {
+        r.skip(1); // ProgrammableConfig::V1
+        (r.u8() == 1).then(|| r.pubkey())
+    } else {
+        None
+    };
+    Ok(MetaplexMetadata {
+        name,
+        symbol,
+        // ProgrammableNonFungible = 4, ProgrammableNonFungibleEdition = 5
+        programmable: matches!(token_standard, Some(4) | Some(5)),
+        rule_set,
+    })
+}
+
+/// Parse a `getAccountInfo` value fetched with base64 encoding
+pub fn parse_metadata_account_info(value: &Value) -> Result<MetaplexMetadata, String> {
+    let encoded = value.get("data")
+        .and_then(|d| d.get(0))
+        .and_then(|d| d.as_str())
+        .ok_or("Metadata account not found")?;
+    parse_metadata_account(&crate::backup::base64_decode(encoded)?)
+}
+
+/// Instructions moving one Solana NFT from `owner` to `recipient`.
+/// Plain NFTs use SPL TransferChecked (creating the recipient's token account
+/// if needed); programmable NFTs go through Token Metadata TransferV1, which
+/// updates the token records and enforces the collection's rule set.
+pub fn solana_nft_transfer_instructions(
+    owner: &[u8; 32],
+    mint: &[u8; 32],
+    recipient: &[u8; 32],
+    metadata: &MetaplexMetadata,
+) -> Result<Vec<Instruction>, String> {
+    if owner == recipient {
+        return Err("Recipient is the current owner".into());
+    }
+    let token_program = program_key(spl::TOKEN_PROGRAM_ID);
+    let source = spl::associated_token_address(owner, mint, &token_program)?;
+    let destination = spl::associated_token_address(recipient, mint, &token_program)?;
+
+    if !metadata.programmable {
+        return Ok(vec![
+            spl::create_associated_token_account_idempotent(owner, recipient, mint, &token_program)?,
+            spl::transfer_checked(&source, mint, &destination, owner, 1, 0, &token_program),
+        ]);
+    }
+
+    // Unused optional accounts are passed as the Token Metadata program id
+    let metadata_program = program_key(TOKEN_METADATA_PROGRAM_ID);
+    let (rules_program, rules) = match metadata.rule_set {
+        Some(rule_set) => (program_key(TOKEN_AUTH_RULES_PROGRAM_ID), rule_set),
+        None => (metadata_program, metadata_program),
+    };
+    let mut data = vec![IX_METADATA_TRANSFER, 0]; // TransferArgs::V1
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.push(0); // authorization_data: None
+    Ok(vec![Instruction {
+        program_id: metadata_program,
+        accounts: vec![
+            AccountMeta::writable(source, false),
+            AccountMeta::readonly(*owner, false),
+            AccountMeta::writable(destination, false),
+            AccountMeta::readonly(*recipient, false),
+            AccountMeta::readonly(*mint, false),
+            AccountMeta::writable(metadata_address(mint)?, false),
+            AccountMeta::readonly(master_edition_address(mint)?, false),
+            AccountMeta::writable(token_record_address(mint, &source)?, false),
+            AccountMeta::writable(token_record_address(mint, &destination)?, false),
+            AccountMeta::readonly(*owner, true),
+            AccountMeta::writable(*owner, true),
+            AccountMeta::readonly(program_key(spl::SYSTEM_PROGRAM_ID), false),
+            AccountMeta::readonly(program_key(SYSVAR_INSTRUCTIONS_ID), false),
+            AccountMeta::readonly(token_program, false),
+            AccountMeta::readonly(program_key(spl::ATA_PROGRAM_ID), false),
+            AccountMeta::readonly(rules_program, false),
+            AccountMeta::readonly(rules, false),
+        ],
+        data,
+    }])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +479,117 @@ mod tests {
         let punks = groups.iter().find(|g| g.name == "CryptoPunks").unwrap();
         assert_eq!(punks.items.len(), 1);
     }
+    #[test]
+    fn test_nft_standard_from_item() {
+        let mut item = NftItem {
+            contract_address: "0xAAA".into(),
+            token_id: "1".into(),
+            name: String::new(),
+            description: String::new(),
+            image_url: String::new(),
+            collection_name: String::new(),
+            chain_id: "ethereum".into(),
+            token_standard: "ERC721".into(),
+        };
+        assert_eq!(NftStandard::from_item(&item), Some(NftStandard::Erc721));
+        item.token_standard = "ERC-1155".into();
+        assert_eq!(NftStandard::from_item(&item), Some(NftStandard::Erc1155));
+        item.token_standard = "Metaplex".into();
+        assert_eq!(NftStandard::from_item(&item), Some(NftStandard::Metaplex));
+        item.token_standard = "NO_SUPPORTED_NFT_STANDARD".into();
+        assert_eq!(NftStandard::from_item(&item), None);
+    }
+
+    #[test]
+    fn test_parse_token_id() {
+        let mut expected = [0u8; 32];
+        expected[30] = 0x01;
+        expected[31] = 0x00;
+        assert_eq!(parse_token_id("256").unwrap(), expected);
+        assert_eq!(parse_token_id("0x100").unwrap(), expected);
+        assert_eq!(
+            parse_token_id("115792089237316195423570985008687907853269984665640564039457584007913129639935").unwrap(),
+            [0xff; 32],
+        );
+        assert!(parse_token_id("115792089237316195423570985008687907853269984665640564039457584007913129639936").is_err());
+        assert!(parse_token_id("12a").is_err());
+    }
+
+    #[test]
+    fn test_encode_safe_transfers() {
+        use crate::signing::keccak256;
+        let from = "0x1111111111111111111111111111111111111111";
+        let to = "0x2222222222222222222222222222222222222222";
+
+        let erc721 = encode_erc721_safe_transfer_from(from, to, "7").unwrap();
+        assert_eq!(erc721[..4], keccak256(b"safeTransferFrom(address,address,uint256)")[..4]);
+        assert_eq!(erc721.len(), 4 + 3 * 32);
+        assert_eq!(erc721[4 + 31 - 19], 0x11);
+        assert_eq!(erc721[4 + 3 * 32 - 1], 7);
+
+        let erc1155 = encode_erc1155_safe_transfer_from(from, to, "7", 3).unwrap();
+        assert_eq!(erc1155[..4], keccak256(b"safeTransferFrom(address,address,uint256,uint256,bytes)")[..4]);
+        assert_eq!(erc1155.len(), 4 + 6 * 32);
+        assert_eq!(erc1155[4 + 4 * 32 - 1], 3);
+        assert_eq!(erc1155[4 + 5 * 32 - 1], 0xa0);
+        assert!(encode_erc1155_safe_transfer_from(from, to, "7", 0).is_err());
+    }
+
+    fn borsh_string(out: &mut Vec<u8>, s: &str) {
+        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        out.extend_from_slice(s.as_bytes());
+    }
+
+    #[test]
+    fn test_parse_metadata_account() {
+        let mut data = vec![METADATA_V1_KEY];
+        data.extend_from_slice(&[0u8; 64]);
+        borsh_string(&mut data, "Name");
+        borsh_string(&mut data, "SYM");
+        borsh_string(&mut data, "https://example.com");
+        data.extend_from_slice(&500u16.to_le_bytes());
+        data.push(1); // creators: Some
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[9u8; 34]);
+        data.extend_from_slice(&[1, 1]); // primary sale, mutable
+        data.extend_from_slice(&[1, 255]); // edition nonce
+        data.extend_from_slice(&[1, 4]); // ProgrammableNonFungible
+        data.push(1);
+        data.extend_from_slice(&[0u8; 33]); // collection
+        data.push(0); // uses
+        data.push(0); // collection details
+        data.extend_from_slice(&[1, 0, 1]); // ProgrammableConfig::V1 { rule_set: Some }
+        data.extend_from_slice(&[7u8; 32]);
+
+        let parsed = parse_metadata_account(&data).unwrap();
+        assert!(parsed.programmable);
+        assert_eq!(parsed.rule_set, Some([7u8; 32]));
+        assert_eq!(parsed.symbol, "SYM");
+
+        // A plain NFT whose account ends right after the creators is zero-padded
+        let mut plain = data[..1 + 64 + 4 + 4 + 4 + 3 + 4 + 19 + 2 + 1 + 4 + 34].to_vec();
+        plain.resize(plain.len() + 40, 0);
+        let parsed = parse_metadata_account(&plain).unwrap();
+        assert!(!parsed.programmable);
+        assert_eq!(parsed.rule_set, None);
+        assert!(parse_metadata_account(&[0u8; 10]).is_err());
+    }
+
+    #[test]
+    fn test_solana_nft_transfer_instructions() {
+        let owner = [1u8; 32];
+        let mint = [2u8; 32];
+        let recipient = [3u8; 32];
+        let plain = solana_nft_transfer_instructions(&owner, &mint, &recipient, &MetaplexMetadata::default()).unwrap();
+        assert_eq!(plain.len(), 2);
+        assert_eq!(plain[1].data, vec![12, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let pnft = MetaplexMetadata { programmable: true, ..Default::default() };
+        let ix = solana_nft_transfer_instructions(&owner, &mint, &recipient, &pnft).unwrap();
+        assert_eq!(ix.len(), 1);
+        assert_eq!(ix[0].data, vec![49, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ix[0].accounts.len(), 17);
+        assert_eq!(ix[0].accounts[15].pubkey, program_key(TOKEN_METADATA_PROGRAM_ID));
+        assert!(solana_nft_transfer_instructions(&owner, &mint, &owner, &pnft).is_err());
+    }
 }
@@ -2,7 +2,12 @@
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// Phishing detection, scam address warnings, security heuristics
+// Phishing detection, scam address warnings, security heuristics,
+// off-chain permit/order signature analysis, Sign-In with Ethereum and
+// Sign-In With Solana validation
 
 pub mod phishing;
 pub mod scam_addresses;
+pub mod permit;
+pub mod siwe;
+pub mod siws;
@@ -0,0 +1,367 @@
+// Rusby Wallet — Off-chain approval detection for EIP-712 signatures
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Recognises typed data that grants token access without an on-chain transaction:
+// ERC-2612 / DAI Permit, Uniswap Permit2 (allowance and signature transfers) and
+// Seaport orders. These are the signatures drainers ask for.
+
+use serde_json::Value;
+
+/// Canonical Permit2 deployment (same address on every chain)
+pub const PERMIT2_ADDRESS: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+
+/// Expiries further out than this are flagged (Uniswap uses 30 days for Permit2)
+pub const FAR_FUTURE_SECS: u64 = 30 * 24 * 3600;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermitKind {
+    /// ERC-2612 `Permit(owner,spender,value,nonce,deadline)`
+    Erc2612,
+    /// DAI-style `Permit(holder,spender,nonce,expiry,allowed)`
+    DaiPermit,
+    /// Permit2 `PermitSingle` / `PermitBatch` (standing allowance)
+    Permit2Allowance,
+    /// Permit2 `PermitTransferFrom` / `PermitBatchTransferFrom` (one-shot transfer)
+    Permit2Transfer,
+    /// Seaport `OrderComponents` (listing that hands over the offered items)
+    SeaportOrder,
+}
+
+/// One token the signature gives access to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermitToken {
+    pub token: String,
+    /// Raw amount in base units; `None` when unlimited
+    pub amount: Option<u128>,
+    /// NFT / ERC-1155 item (Seaport itemType 2-5)
+    pub is_nft: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermitRisk {
+    UnlimitedAmount,
+    UnknownSpender,
+    /// Expiry more than `FAR_FUTURE_SECS` away, or none at all
+    FarFutureExpiry,
+    /// Seaport order that gives items away for (almost) nothing back to the signer
+    NoPaymentToSigner,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermitAnalysis {
+    pub kind: PermitKind,
+    /// Who can move the tokens (for Seaport: whoever receives the consideration)
+    pub spender: String,
+    pub tokens: Vec<PermitToken>,
+    /// Unix seconds; `None` = never expires
+    pub expiry: Option<u64>,
+    pub risks: Vec<PermitRisk>,
+}
+
+impl PermitAnalysis {
+    /// Any of the drainer patterns present
+    pub fn is_high_risk(&self) -> bool {
+        !self.risks.is_empty()
+    }
+}
+
+/// Amounts at or above 2^128 are treated as unlimited: no real token supply gets near it,
+/// and it covers both `type(uint256).max` and Permit2's `type(uint160).max`
+fn parse_amount(value: Option<&Value>) -> Option<Option<u128>> {
+    match value? {
+        Value::Number(n) => Some(Some(n.as_u64()? as u128)),
+        Value::String(s) => {
+            let parsed = match s.strip_prefix("0x") {
+                Some(hex) => {
+                    let hex = hex.trim_start_matches('0');
+                    if hex.len() > 32 { return Some(None); }
+                    if hex.is_empty() { 0 } else { u128::from_str_radix(hex, 16).ok()? }
+                }
+                None => {
+                    if !s.bytes().all(|b| b.is_ascii_digit()) || s.is_empty() { return None; }
+                    match s.parse::<u128>() {
+                        Ok(v) => v,
+                        Err(_) => return Some(None),
+                    }
+                }
+            };
+            Some(Some(parsed))
+        }
+        _ => None,
+    }
+}
+
+/// Timestamps: anything that doesn't fit u64 is effectively "never"
+fn parse_time(value: Option<&Value>) -> Option<u64> {
+    match parse_amount(value)? {
+        Some(v) => Some(u64::try_from(v).unwrap_or(u64::MAX)),
+        None => Some(u64::MAX),
+    }
+}
+
+fn str_field(obj: &Value, field: &str) -> Option<String> {
+    obj.get(field)?.as_str().map(|s| s.to_string())
+}
+
+fn is_address(s: &str) -> bool {
+    s.len() == 42 && s.starts_with("0x") && hex::decode(&s[2..]).is_ok()
+}
+
+/// Decode eth_signTypedData_v4's second parameter (a JSON string, or an object from some dApps)
+pub fn parse_typed_data(param: &Value) -> Option<Value> {
+    match param {
+        Value::String(s) => serde_json::from_str(s).ok(),
+        Value::Object(_) => Some(param.clone()),
+        _ => None,
+    }
+}
+
+/// Detect a token-approval-like signature and assess it.
+/// `known_spenders` are trusted contract addresses (routers, marketplaces) for the chain.
+pub fn analyze_typed_data(typed_data: &Value, now: u64, known_spenders: &[String]) -> Option<PermitAnalysis> {
+    let primary = typed_data.get("primaryType")?.as_str()?;
+    let message = typed_data.get("message")?;
+    let domain = typed_data.get("domain");
+    let verifying = domain.and_then(|d| str_field(d, "verifyingContract")).unwrap_or_default();
+
+    let (kind, spender, tokens, expiry) = match primary {
+        "Permit" if message.get("allowed").is_some() => {
+            // DAI: all-or-nothing allowance; expiry 0 never expires
+            let allowed = message.get("allowed").and_then(|a| a.as_bool()).unwrap_or(false);
+            let expiry = parse_time(message.get("expiry"))?;
+            (
+                PermitKind::DaiPermit,
+                str_field(message, "spender")?,
+                vec![PermitToken { token: verifying, amount: if allowed { None } else { Some(0) }, is_nft: false }],
+                (expiry != 0).then_some(expiry),
+            )
+        }
+        "Permit" => (
+            PermitKind::Erc2612,
+            str_field(message, "spender")?,
+            vec![PermitToken { token: verifying, amount: parse_amount(message.get("value"))?, is_nft: false }],
+            Some(parse_time(message.get("deadline"))?),
+        ),
+        "PermitSingle" | "PermitBatch" => {
+            let details: Vec<&Value> = match message.get("details")? {
+                Value::Array(list) => list.iter().collect(),
+                single => vec![single],
+            };
+            let tokens = details.iter()
+                .map(|d| Some(PermitToken { token: str_field(d, "token")?, amount: parse_amount(d.get("amount"))?, is_nft: false }))
+                .collect::<Option<Vec<_>>>()?;
+            // Permit2 treats expiration 0 as "this block"; the latest expiration is what matters
+            let expiry = details.iter()
+                .filter_map(|d| parse_time(d.get("expiration")))
+                .max()
+                .unwrap_or(0);
+            (PermitKind::Permit2Allowance, str_field(message, "spender")?, tokens, Some(expiry.max(now)))
+        }
+        p if p.starts_with("PermitTransferFrom") || p.starts_with("PermitBatchTransferFrom")
+            || p.starts_with("PermitWitnessTransferFrom") || p.starts_with("PermitBatchWitnessTransferFrom") =>
+        {
+            let permitted: Vec<&Value> = match message.get("permitted")? {
+                Value::Array(list) => list.iter().collect(),
+                single => vec![single],
+            };
+            let tokens = permitted.iter()
+                .map(|d| Some(PermitToken { token: str_field(d, "token")?, amount: parse_amount(d.get("amount"))?, is_nft: false }))
+                .collect::<Option<Vec<_>>>()?;
+            (PermitKind::Permit2Transfer, str_field(message, "spender")?, tokens, Some(parse_time(message.get("deadline"))?))
+        }
+        "OrderComponents" => return analyze_seaport(message, now, known_spenders),
+        _ => return None,
+    };
+
+    let mut risks = Vec::new();
+    if tokens.iter().any(|t| t.amount.is_none()) {
+        risks.push(PermitRisk::UnlimitedAmount);
+    }
+    if !is_address(&spender) || !known_spenders.iter().any(|k| k.eq_ignore_ascii_case(&spender)) {
+        risks.push(PermitRisk::UnknownSpender);
+    }
+    if expiry.map(|e| e.saturating_sub(now) > FAR_FUTURE_SECS).unwrap_or(true) {
+        risks.push(PermitRisk::FarFutureExpiry);
+    }
+    Some(PermitAnalysis { kind, spender, tokens, expiry, risks })
+}
+
+/// Seaport: the signer (offerer) gives `offer` items and receives whatever `consideration`
+/// entries name them as recipient. Drainer listings route all consideration elsewhere.
+fn analyze_seaport(message: &Value, now: u64, known_spenders: &[String]) -> Option<PermitAnalysis> {
+    let offerer = str_field(message, "offerer")?;
+    let offer = message.get("offer")?.as_array()?;
+    let consideration = message.get("consideration")?.as_array()?;
+
+    let tokens = offer.iter().map(|item| {
+        let item_type = parse_amount(item.get("itemType")).flatten().unwrap_or(0);
+        let amount = parse_amount(item.get("endAmount").or_else(|| item.get("startAmount")))?;
+        Some(PermitToken { token: str_field(item, "token")?, amount, is_nft: (2..=5).contains(&item_type) })
+    }).collect::<Option<Vec<_>>>()?;
+
+    let paid_to_signer: u128 = consideration.iter()
+        .filter(|c| str_field(c, "recipient").is_some_and(|r| r.eq_ignore_ascii_case(&offerer)))
+        .filter_map(|c| parse_amount(c.get("endAmount").or_else(|| c.get("startAmount"))))
+        .map(|a| a.unwrap_or(u128::MAX))
+        .fold(0u128, |acc, a| acc.saturating_add(a));
+    // First recipient other than the signer is who actually profits
+    let spender = consideration.iter()
+        .filter_map(|c| str_field(c, "recipient"))
+        .find(|r| !r.eq_ignore_ascii_case(&offerer))
+        .unwrap_or_else(|| offerer.clone());
+    let expiry = parse_time(message.get("endTime"))?;
+
+    let mut risks = Vec::new();
+    if tokens.iter().any(|t| t.amount.is_none()) {
+        risks.push(PermitRisk::UnlimitedAmount);
+    }
+    if !offer.is_empty() && paid_to_signer == 0 {
+        risks.push(PermitRisk::NoPaymentToSigner);
+        if !known_spenders.iter().any(|k| k.eq_ignore_ascii_case(&spender)) {
+            risks.push(PermitRisk::UnknownSpender);
+        }
+    }
+    if expiry.saturating_sub(now) > FAR_FUTURE_SECS {
+        risks.push(PermitRisk::FarFutureExpiry);
+    }
+    Some(PermitAnalysis { kind: PermitKind::SeaportOrder, spender, tokens, expiry: Some(expiry), risks })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const NOW: u64 = 1_750_000_000;
+    const ROUTER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const OWNER: &str = "0x1111111111111111111111111111111111111111";
+    const ATTACKER: &str = "0x9999999999999999999999999999999999999999";
+
+    fn known() -> Vec<String> {
+        vec![ROUTER.to_lowercase()]
+    }
+
+    #[test]
+    fn test_erc2612_permit() {
+        let typed = json!({
+            "primaryType": "Permit",
+            "domain": {"name": "USD Coin", "version": "2", "chainId": 1, "verifyingContract": USDC},
+            "message": {"owner": OWNER, "spender": ROUTER, "value": "1000000", "nonce": 0, "deadline": NOW + 1800},
+        });
+        let a = analyze_typed_data(&typed, NOW, &known()).unwrap();
+        assert_eq!(a.kind, PermitKind::Erc2612);
+        assert_eq!(a.tokens, vec![PermitToken { token: USDC.into(), amount: Some(1_000_000), is_nft: false }]);
+        assert_eq!(a.expiry, Some(NOW + 1800));
+        assert!(!a.is_high_risk());
+
+        let mut drain = typed.clone();
+        drain["message"]["spender"] = json!(ATTACKER);
+        drain["message"]["value"] = json!("115792089237316195423570985008687907853269984665640564039457584007913129639935");
+        drain["message"]["deadline"] = json!("115792089237316195423570985008687907853269984665640564039457584007913129639935");
+        let a = analyze_typed_data(&drain, NOW, &known()).unwrap();
+        assert_eq!(a.risks, vec![PermitRisk::UnlimitedAmount, PermitRisk::UnknownSpender, PermitRisk::FarFutureExpiry]);
+        assert_eq!(a.expiry, Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_dai_permit_never_expires() {
+        let typed = json!({
+            "primaryType": "Permit",
+            "domain": {"name": "Dai Stablecoin", "version": "1", "chainId": 1, "verifyingContract": "0x6B175474E89094C44Da98b954EedeAC495271d0F"},
+            "message": {"holder": OWNER, "spender": ROUTER, "nonce": 3, "expiry": 0, "allowed": true},
+        });
+        let a = analyze_typed_data(&typed, NOW, &known()).unwrap();
+        assert_eq!(a.kind, PermitKind::DaiPermit);
+        assert_eq!(a.expiry, None);
+        assert_eq!(a.risks, vec![PermitRisk::UnlimitedAmount, PermitRisk::FarFutureExpiry]);
+    }
+
+    #[test]
+    fn test_permit2_single_and_batch() {
+        let single = json!({
+            "primaryType": "PermitSingle",
+            "domain": {"name": "Permit2", "chainId": 1, "verifyingContract": PERMIT2_ADDRESS},
+            "message": {
+                "details": {"token": USDC, "amount": "1461501637330902918203684832716283019655932542975", "expiration": NOW + 29 * 86400, "nonce": 0},
+                "spender": ROUTER,
+                "sigDeadline": NOW + 1800,
+            },
+        });
+        let a = analyze_typed_data(&single, NOW, &known()).unwrap();
+        assert_eq!(a.kind, PermitKind::Permit2Allowance);
+        assert_eq!(a.tokens[0].amount, None);
+        assert_eq!(a.risks, vec![PermitRisk::UnlimitedAmount]);
+
+        let batch = json!({
+            "primaryType": "PermitBatch",
+            "message": {
+                "details": [
+                    {"token": USDC, "amount": "5", "expiration": "0", "nonce": 0},
+                    {"token": OWNER, "amount": "0x10", "expiration": NOW + 365 * 86400, "nonce": 0},
+                ],
+                "spender": ATTACKER,
+                "sigDeadline": NOW + 60,
+            },
+        });
+        let a = analyze_typed_data(&batch, NOW, &known()).unwrap();
+        assert_eq!(a.tokens.len(), 2);
+        assert_eq!(a.tokens[1].amount, Some(16));
+        assert_eq!(a.risks, vec![PermitRisk::UnknownSpender, PermitRisk::FarFutureExpiry]);
+    }
+
+    #[test]
+    fn test_permit2_signature_transfer() {
+        let typed = json!({
+            "primaryType": "PermitBatchTransferFrom",
+            "message": {
+                "permitted": [{"token": USDC, "amount": "250"}],
+                "spender": ROUTER,
+                "nonce": "1",
+                "deadline": NOW + 600,
+            },
+        });
+        let a = analyze_typed_data(&typed, NOW, &known()).unwrap();
+        assert_eq!(a.kind, PermitKind::Permit2Transfer);
+        assert_eq!(a.tokens[0].amount, Some(250));
+        assert!(!a.is_high_risk());
+    }
+
+    #[test]
+    fn test_seaport_zero_consideration_listing() {
+        let order = |recipient: &str| json!({
+            "primaryType": "OrderComponents",
+            "domain": {"name": "Seaport", "version": "1.6"},
+            "message": {
+                "offerer": OWNER,
+                "offer": [{"itemType": 2, "token": "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D", "identifierOrCriteria": "1", "startAmount": "1", "endAmount": "1"}],
+                "consideration": [
+                    {"itemType": 0, "token": "0x0000000000000000000000000000000000000000", "identifierOrCriteria": "0", "startAmount": "1", "endAmount": "1", "recipient": recipient},
+                ],
+                "startTime": NOW,
+                "endTime": NOW + 86400,
+            },
+        });
+        let fair = analyze_typed_data(&order(OWNER), NOW, &known()).unwrap();
+        assert_eq!(fair.kind, PermitKind::SeaportOrder);
+        assert!(fair.tokens[0].is_nft);
+        assert!(!fair.is_high_risk());
+
+        let drain = analyze_typed_data(&order(ATTACKER), NOW, &known()).unwrap();
+        assert_eq!(drain.spender, ATTACKER);
+        assert_eq!(drain.risks, vec![PermitRisk::NoPaymentToSigner, PermitRisk::UnknownSpender]);
+    }
+
+    #[test]
+    fn test_other_typed_data_is_ignored() {
+        let mail = json!({"primaryType": "Mail", "message": {"contents": "hi"}});
+        assert_eq!(analyze_typed_data(&mail, NOW, &known()), None);
+        // No spender: not a recognisable permit
+        let broken = json!({"primaryType": "Permit", "message": {"value": "1"}});
+        assert_eq!(analyze_typed_data(&broken, NOW, &known()), None);
+
+        let param = json!(r#"{"primaryType":"Mail","message":{}}"#);
+        assert_eq!(parse_typed_data(&param).unwrap()["primaryType"], "Mail");
+    }
+}
@@ -145,8 +145,8 @@ fn levenshtein(a: &str, b: &str) -> usize {
     let lb = b.len();
 
     let mut matrix = vec![vec![0usize; lb + 1]; la + 1];
-    for i in 0..=la { matrix[i][0] = i; }
-    for j in 0..=lb { matrix[0][j] = j; }
+    for (i, row) in matrix.iter_mut().enumerate() { row[0] = i; }
+    for (j, cell) in matrix[0].iter_mut().enumerate() { *cell = j; }
 
     for i in 1..=la {
         for j in 1..=lb {
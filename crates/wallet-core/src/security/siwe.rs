@@ -0,0 +1,459 @@
+// Rusby Wallet — Sign-In with Ethereum (EIP-4361) parsing and validation
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// A SIWE message is a personal_sign payload with a fixed layout. Signing one
+// for a domain other than the requesting site lets that site log in as the
+// user elsewhere, so the domain is bound to the dApp origin before signing.
+
+use super::phishing;
+
+const HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+
+/// Parsed EIP-4361 message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiweMessage {
+    /// Optional URI scheme before the domain (`https://example.com wants you...`)
+    pub scheme: Option<String>,
+    /// Authority (host and optional port) asking for the sign-in
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub nonce: String,
+    /// RFC 3339 timestamps, kept as written for display
+    pub issued_at: String,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SiweIssue {
+    /// Message domain differs from the site that sent the request
+    DomainMismatch { domain: String, origin: String },
+    /// Message scheme differs from the origin scheme
+    SchemeMismatch,
+    /// Message domain flagged by the phishing heuristics
+    SuspiciousDomain(String),
+    ChainMismatch { message: u64, active: u64 },
+    /// The request did not say which EVM chain the dApp is on, so the chain was not checked
+    ChainUnknown,
+    AddressMismatch,
+    /// Fewer than 8 alphanumeric characters
+    InvalidNonce,
+    Expired,
+    NotYetValid,
+    /// Issued At / Expiration Time / Not Before is not a valid RFC 3339 time
+    InvalidTimestamp,
+}
+
+impl SiweIssue {
+    /// Issues that mean the signature could be replayed against another site
+    pub fn is_high_risk(&self) -> bool {
+        matches!(
+            self,
+            SiweIssue::DomainMismatch { .. } | SiweIssue::SchemeMismatch | SiweIssue::SuspiciousDomain(_) | SiweIssue::AddressMismatch
+        )
+    }
+}
+
+/// True if `message` starts with a SIWE header line
+pub fn is_siwe_message(message: &str) -> bool {
+    message.lines().next().is_some_and(|l| l.ends_with(HEADER_SUFFIX))
+}
+
+/// Parse an EIP-4361 message. Fields must appear in the order defined by the spec.
+pub fn parse_siwe(message: &str) -> Result<SiweMessage, String> {
+    let mut lines = message.lines().peekable();
+
+    let header = lines.next().ok_or("Empty message")?;
+    let origin = header.strip_suffix(HEADER_SUFFIX).ok_or("Not a SIWE message")?;
+    let (scheme, domain) = match origin.split_once("://") {
+        Some((s, d)) => (Some(s.to_string()), d.to_string()),
+        None => (None, origin.to_string()),
+    };
+    if domain.is_empty() || domain.contains(|c: char| c.is_whitespace() || c == '/') {
+        return Err("Invalid domain".into());
+    }
+
+    let address = lines.next().ok_or("Missing address")?.to_string();
+    let valid_address = address.strip_prefix("0x")
+        .is_some_and(|h| h.len() == 40 && h.chars().all(|c| c.is_ascii_hexdigit()));
+    if !valid_address {
+        return Err("Invalid address".into());
+    }
+
+    // Blank line, optional statement, blank line
+    let mut statement = None;
+    while let Some(line) = lines.peek() {
+        if line.starts_with("URI: ") {
+            break;
+        }
+        if !line.is_empty() {
+            if statement.is_some() {
+                return Err("Statement must be a single line".into());
+            }
+            statement = Some(line.to_string());
+        }
+        lines.next();
+    }
+
+    let mut field = |name: &str, required: bool| -> Result<Option<String>, String> {
+        let prefix = format!("{}: ", name);
+        match lines.peek().and_then(|l| l.strip_prefix(prefix.as_str())) {
+            Some(value) => {
+                let value = value.to_string();
+                lines.next();
+                Ok(Some(value))
+            }
+            None if required => Err(format!("Missing {}", name)),
+            None => Ok(None),
+        }
+    };
+
+    let uri = field("URI", true)?.unwrap_or_default();
+    let version = field("Version", true)?.unwrap_or_default();
+    if version != "1" {
+        return Err(format!("Unsupported version: {}", version));
+    }
+    let chain_id = field("Chain ID", true)?.unwrap_or_default()
+        .parse::<u64>()
+        .map_err(|_| "Invalid Chain ID")?;
+    let nonce = field("Nonce", true)?.unwrap_or_default();
+    let issued_at = field("Issued At", true)?.unwrap_or_default();
+    let expiration_time = field("Expiration Time", false)?;
+    let not_before = field("Not Before", false)?;
+    let request_id = field("Request ID", false)?;
+
+    let mut resources = Vec::new();
+    if lines.peek() == Some(&"Resources:") {
+        lines.next();
+        while let Some(resource) = lines.peek().and_then(|l| l.strip_prefix("- ")) {
+            resources.push(resource.to_string());
+            lines.next();
+        }
+    }
+    if lines.any(|l| !l.is_empty()) {
+        return Err("Unexpected content after message".into());
+    }
+
+    Ok(SiweMessage {
+        scheme, domain, address, statement, uri, version, chain_id, nonce,
+        issued_at, expiration_time, not_before, request_id, resources,
+    })
+}
+
+/// Parse an RFC 3339 timestamp (`2025-01-02T03:04:05.678Z`, `...+02:00`) into unix seconds
+pub fn parse_rfc3339(s: &str) -> Option<u64> {
+    let b = s.as_bytes();
+    if b.len() < 20 || b[4] != b'-' || b[7] != b'-' || !matches!(b[10], b'T' | b't') || b[13] != b':' || b[16] != b':' {
+        return None;
+    }
+    let num = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = s.get(range)?;
+        part.bytes().all(|c| c.is_ascii_digit()).then(|| part.parse().ok())?
+    };
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    if let Some(frac) = rest.strip_prefix('.') {
+        let digits = frac.bytes().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return None;
+        }
+        rest = &frac[digits..];
+    }
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            if rest.len() != 6 || rest.as_bytes()[3] != b':' {
+                return None;
+            }
+            let (h, m): (i64, i64) = (rest.get(1..3)?.parse().ok()?, rest.get(4..6)?.parse().ok()?);
+            if h > 23 || m > 59 {
+                return None;
+            }
+            sign * (h * 3600 + m * 60)
+        }
+    };
+
+    let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+    u64::try_from(secs).ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 (proleptic Gregorian calendar)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Split a request origin (`https://app.example.com:8443/path`) into scheme and authority
+fn split_origin(origin: &str) -> (Option<String>, String) {
+    let origin = origin.trim().to_lowercase();
+    let (scheme, rest) = match origin.split_once("://") {
+        Some((s, r)) => (Some(s.to_string()), r.to_string()),
+        None => (None, origin),
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    (scheme, authority.to_string())
+}
+
+/// Drop the default port of `scheme` so `example.com:443` matches `https://example.com`
+fn strip_default_port(authority: &str, scheme: Option<&str>) -> String {
+    let default = match scheme {
+        Some("https") | None => ":443",
+        Some("http") => ":80",
+        _ => return authority.to_string(),
+    };
+    authority.strip_suffix(default).unwrap_or(authority).to_string()
+}
+
+/// Check a parsed message against the request context.
+///
+/// `origin` is the page that sent the request (web origin or bare host), or None when it
+/// is unknown (WalletConnect sessions) and the domain cannot be bound. `active_chain_id`
+/// is the EVM chain the dApp is connected to, None if the request did not carry one, and
+/// `our_address` the account that would sign.
+pub fn validate(
+    msg: &SiweMessage,
+    origin: Option<&str>,
+    active_chain_id: Option<u64>,
+    our_address: &str,
+    now: u64,
+) -> Vec<SiweIssue> {
+    let mut issues = check_domain(msg.scheme.as_deref(), &msg.domain, origin);
+
+    match active_chain_id {
+        Some(active) if active != msg.chain_id => {
+            issues.push(SiweIssue::ChainMismatch { message: msg.chain_id, active });
+        }
+        Some(_) => {}
+        None => issues.push(SiweIssue::ChainUnknown),
+    }
+    if !msg.address.eq_ignore_ascii_case(our_address) {
+        issues.push(SiweIssue::AddressMismatch);
+    }
+    if !is_valid_nonce(&msg.nonce) {
+        issues.push(SiweIssue::InvalidNonce);
+    }
+    issues.extend(check_times(
+        Some(&msg.issued_at),
+        msg.expiration_time.as_deref(),
+        msg.not_before.as_deref(),
+        now,
+    ));
+    issues
+}
+
+/// Bind a sign-in domain (and scheme, if written) to the requesting origin and run the
+/// phishing heuristics on it. Shared with Sign-In With Solana.
+pub(crate) fn check_domain(scheme: Option<&str>, domain: &str, origin: Option<&str>) -> Vec<SiweIssue> {
+    let mut issues = Vec::new();
+
+    let msg_scheme = scheme.map(str::to_lowercase);
+    let (origin_scheme, origin_authority) = origin.map(split_origin).unwrap_or_default();
+    let scheme = msg_scheme.as_deref().or(origin_scheme.as_deref());
+    let host = strip_default_port(&domain.to_lowercase(), scheme);
+    if origin.is_some() {
+        let origin_host = strip_default_port(&origin_authority, origin_scheme.as_deref());
+        if host != origin_host {
+            issues.push(SiweIssue::DomainMismatch { domain: domain.to_string(), origin: origin_authority });
+        } else if let (Some(m), Some(o)) = (msg_scheme.as_deref(), origin_scheme.as_deref()) {
+            if m != o {
+                issues.push(SiweIssue::SchemeMismatch);
+            }
+        }
+    }
+    if let Some(reason) = phishing::check_suspicious_domain(&host) {
+        issues.push(SiweIssue::SuspiciousDomain(reason));
+    }
+    issues
+}
+
+/// At least 8 alphanumeric characters
+pub(crate) fn is_valid_nonce(nonce: &str) -> bool {
+    nonce.len() >= 8 && nonce.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Timestamp format and validity window; absent fields are not checked
+pub(crate) fn check_times(
+    issued_at: Option<&str>,
+    expiration_time: Option<&str>,
+    not_before: Option<&str>,
+    now: u64,
+) -> Vec<SiweIssue> {
+    let mut issues = Vec::new();
+    let issued_at = issued_at.map(parse_rfc3339);
+    let expiration = expiration_time.map(parse_rfc3339);
+    let not_before = not_before.map(parse_rfc3339);
+    if [issued_at, expiration, not_before].contains(&Some(None)) {
+        issues.push(SiweIssue::InvalidTimestamp);
+    }
+    if let Some(Some(exp)) = expiration {
+        if exp <= now {
+            issues.push(SiweIssue::Expired);
+        }
+    }
+    if let Some(Some(nbf)) = not_before {
+        if nbf > now {
+            issues.push(SiweIssue::NotYetValid);
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2";
+
+    fn sample() -> String {
+        format!(
+            "app.example.com wants you to sign in with your Ethereum account:\n\
+             {}\n\
+             \n\
+             Sign in to Example.\n\
+             \n\
+             URI: https://app.example.com/login\n\
+             Version: 1\n\
+             Chain ID: 1\n\
+             Nonce: 32891756abcd\n\
+             Issued At: 2025-03-01T12:00:00.000Z\n\
+             Expiration Time: 2025-03-01T12:10:00Z\n\
+             Resources:\n\
+             - ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/\n\
+             - https://example.com/my-web2-claim.json",
+            ADDRESS
+        )
+    }
+
+    // 2025-03-01T12:05:00Z
+    const NOW: u64 = 1_740_830_700;
+
+    #[test]
+    fn test_parse_full_message() {
+        let text = sample();
+        assert!(is_siwe_message(&text));
+        let msg = parse_siwe(&text).unwrap();
+        assert_eq!(msg.scheme, None);
+        assert_eq!(msg.domain, "app.example.com");
+        assert_eq!(msg.address, ADDRESS);
+        assert_eq!(msg.statement.as_deref(), Some("Sign in to Example."));
+        assert_eq!(msg.uri, "https://app.example.com/login");
+        assert_eq!(msg.chain_id, 1);
+        assert_eq!(msg.nonce, "32891756abcd");
+        assert_eq!(msg.expiration_time.as_deref(), Some("2025-03-01T12:10:00Z"));
+        assert_eq!(msg.not_before, None);
+        assert_eq!(msg.resources.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_minimal_and_invalid() {
+        let minimal = format!(
+            "https://localhost:3000 wants you to sign in with your Ethereum account:\n{}\n\n\nURI: http://localhost:3000\nVersion: 1\nChain ID: 8453\nNonce: abcdefgh1\nIssued At: 2025-03-01T12:00:00+01:00",
+            ADDRESS
+        );
+        let msg = parse_siwe(&minimal).unwrap();
+        assert_eq!(msg.scheme.as_deref(), Some("https"));
+        assert_eq!(msg.domain, "localhost:3000");
+        assert_eq!(msg.statement, None);
+        assert_eq!(msg.chain_id, 8453);
+
+        assert!(!is_siwe_message("hello world"));
+        assert!(parse_siwe(&sample().replace("Version: 1", "Version: 2")).is_err());
+        assert!(parse_siwe(&sample().replace("Chain ID: 1\n", "")).is_err());
+        assert!(parse_siwe(&sample().replace(ADDRESS, "0x1234")).is_err());
+        assert!(parse_siwe(&format!("{}\nextra", sample())).is_err());
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_rfc3339("2025-03-01T12:05:00Z"), Some(NOW));
+        assert_eq!(parse_rfc3339("2025-03-01T13:05:00.123+01:00"), Some(NOW));
+        assert_eq!(parse_rfc3339("2024-02-29T00:00:00Z"), Some(1_709_164_800));
+        assert_eq!(parse_rfc3339("2025-02-29T00:00:00Z"), None);
+        assert_eq!(parse_rfc3339("2025-03-01 12:05:00Z"), None);
+        assert_eq!(parse_rfc3339("2025-03-01T12:05:00"), None);
+    }
+
+    #[test]
+    fn test_validate_matching_request() {
+        let msg = parse_siwe(&sample()).unwrap();
+        let issues = validate(&msg, Some("https://app.example.com"), Some(1), &ADDRESS.to_lowercase(), NOW);
+        assert!(issues.is_empty(), "{:?}", issues);
+        // Default port on either side still matches
+        assert!(validate(&msg, Some("https://app.example.com:443/path"), Some(1), ADDRESS, NOW).is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_issues() {
+        let msg = parse_siwe(&sample()).unwrap();
+        let other = "0x0000000000000000000000000000000000000001";
+        let issues = validate(&msg, Some("https://evil.example.net"), Some(10), other, NOW + 3600);
+        assert!(issues.contains(&SiweIssue::DomainMismatch {
+            domain: "app.example.com".into(),
+            origin: "evil.example.net".into(),
+        }));
+        assert!(issues.contains(&SiweIssue::ChainMismatch { message: 1, active: 10 }));
+        assert!(issues.contains(&SiweIssue::AddressMismatch));
+        assert!(issues.contains(&SiweIssue::Expired));
+        assert!(issues.iter().any(|i| i.is_high_risk()));
+
+        let mut msg = msg;
+        msg.nonce = "abc".into();
+        msg.not_before = Some("2030-01-01T00:00:00Z".into());
+        msg.domain = "uniswap.rog".into();
+        let issues = validate(&msg, Some("https://uniswap.rog"), Some(1), ADDRESS, NOW);
+        assert!(issues.contains(&SiweIssue::InvalidNonce));
+        assert!(issues.contains(&SiweIssue::NotYetValid));
+        assert!(issues.iter().any(|i| matches!(i, SiweIssue::SuspiciousDomain(_))));
+        assert!(!issues.iter().any(|i| matches!(i, SiweIssue::DomainMismatch { .. })));
+    }
+
+    #[test]
+    fn test_validate_scheme_mismatch() {
+        let mut msg = parse_siwe(&sample()).unwrap();
+        msg.scheme = Some("http".into());
+        let issues = validate(&msg, Some("https://app.example.com"), Some(1), ADDRESS, NOW);
+        assert_eq!(issues, vec![SiweIssue::SchemeMismatch]);
+        // Without a known origin only the phishing heuristics apply to the domain
+        assert!(validate(&msg, None, Some(1), ADDRESS, NOW).is_empty());
+    }
+
+    #[test]
+    fn test_validate_unknown_chain() {
+        let msg = parse_siwe(&sample()).unwrap();
+        let issues = validate(&msg, Some("https://app.example.com"), None, ADDRESS, NOW);
+        assert_eq!(issues, vec![SiweIssue::ChainUnknown]);
+        assert!(!issues[0].is_high_risk());
+    }
+}
@@ -0,0 +1,287 @@
+// Rusby Wallet — Sign-In With Solana message building, parsing and validation
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// SIWS follows the EIP-4361 text layout with a Solana header, base58 address
+// and every field after the address optional. Wallet Standard `solana:signIn`
+// hands the wallet the fields and lets it write the message; dApps that sign in
+// through `signMessage` send the finished text instead.
+
+use super::siwe::{self, SiweIssue};
+
+const HEADER_SUFFIX: &str = " wants you to sign in with your Solana account:";
+
+/// Parsed or wallet-built Sign-In With Solana message
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SiwsMessage {
+    pub scheme: Option<String>,
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: Option<String>,
+    pub version: Option<String>,
+    /// Cluster as written by the dApp (`mainnet`, `devnet`, `solana:mainnet`...)
+    pub chain_id: Option<String>,
+    pub nonce: Option<String>,
+    pub issued_at: Option<String>,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+/// True if `message` starts with a SIWS header line
+pub fn is_siws_message(message: &str) -> bool {
+    message.lines().next().is_some_and(|l| l.ends_with(HEADER_SUFFIX))
+}
+
+fn is_base58_pubkey(s: &str) -> bool {
+    bs58::decode(s).into_vec().is_ok_and(|b| b.len() == 32)
+}
+
+/// Field labels in message order
+const FIELDS: [&str; 8] = ["URI", "Version", "Chain ID", "Nonce", "Issued At", "Expiration Time", "Not Before", "Request ID"];
+
+impl SiwsMessage {
+    /// Build the message for a `solana:signIn` input. Missing `domain` and `address`
+    /// are filled with the requesting host and our account, as the Wallet Standard asks.
+    pub fn from_sign_in_input(input: &serde_json::Value, origin_host: &str, our_address: &str) -> Result<Self, String> {
+        let text = |key: &str| -> Result<Option<String>, String> {
+            match input.get(key) {
+                None | Some(serde_json::Value::Null) => Ok(None),
+                Some(serde_json::Value::String(s)) if s.contains(['\n', '\r']) => Err(format!("Invalid {}", key)),
+                Some(serde_json::Value::String(s)) => Ok(Some(s.clone())),
+                Some(_) => Err(format!("Invalid {}", key)),
+            }
+        };
+        let resources = match input.get("resources") {
+            None | Some(serde_json::Value::Null) => Vec::new(),
+            Some(serde_json::Value::Array(items)) => items.iter()
+                .map(|r| r.as_str().filter(|s| !s.contains(['\n', '\r'])).map(str::to_string).ok_or("Invalid resources"))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err("Invalid resources".into()),
+        };
+        let msg = SiwsMessage {
+            scheme: None,
+            domain: text("domain")?.unwrap_or_else(|| origin_host.to_string()),
+            address: text("address")?.unwrap_or_else(|| our_address.to_string()),
+            statement: text("statement")?,
+            uri: text("uri")?,
+            version: text("version")?,
+            chain_id: text("chainId")?,
+            nonce: text("nonce")?,
+            issued_at: text("issuedAt")?,
+            expiration_time: text("expirationTime")?,
+            not_before: text("notBefore")?,
+            request_id: text("requestId")?,
+            resources,
+        };
+        if msg.domain.is_empty() || msg.domain.contains(char::is_whitespace) {
+            return Err("Invalid domain".into());
+        }
+        if !is_base58_pubkey(&msg.address) {
+            return Err("Invalid address".into());
+        }
+        Ok(msg)
+    }
+
+    /// Message text, byte for byte what gets signed
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        if let Some(scheme) = &self.scheme {
+            out.push_str(scheme);
+            out.push_str("://");
+        }
+        out.push_str(&self.domain);
+        out.push_str(HEADER_SUFFIX);
+        out.push('\n');
+        out.push_str(&self.address);
+        if let Some(statement) = &self.statement {
+            out.push_str("\n\n");
+            out.push_str(statement);
+        }
+
+        let values = [
+            &self.uri, &self.version, &self.chain_id, &self.nonce,
+            &self.issued_at, &self.expiration_time, &self.not_before, &self.request_id,
+        ];
+        let mut fields: Vec<String> = FIELDS.iter().zip(values)
+            .filter_map(|(name, value)| value.as_ref().map(|v| format!("{}: {}", name, v)))
+            .collect();
+        if !self.resources.is_empty() {
+            fields.push("Resources:".into());
+            fields.extend(self.resources.iter().map(|r| format!("- {}", r)));
+        }
+        if !fields.is_empty() {
+            out.push_str("\n\n");
+            out.push_str(&fields.join("\n"));
+        }
+        out
+    }
+}
+
+/// Parse a SIWS message. Fields are optional but must keep their order.
+pub fn parse_siws(message: &str) -> Result<SiwsMessage, String> {
+    let mut lines = message.lines().peekable();
+
+    let header = lines.next().ok_or("Empty message")?;
+    let origin = header.strip_suffix(HEADER_SUFFIX).ok_or("Not a SIWS message")?;
+    let (scheme, domain) = match origin.split_once("://") {
+        Some((s, d)) => (Some(s.to_string()), d.to_string()),
+        None => (None, origin.to_string()),
+    };
+    if domain.is_empty() || domain.contains(|c: char| c.is_whitespace() || c == '/') {
+        return Err("Invalid domain".into());
+    }
+
+    let address = lines.next().ok_or("Missing address")?.to_string();
+    if !is_base58_pubkey(&address) {
+        return Err("Invalid address".into());
+    }
+
+    let is_field = |line: &str| line == "Resources:" || FIELDS.iter().any(|f| line.starts_with(&format!("{}: ", f)));
+    let mut statement = None;
+    while let Some(line) = lines.peek() {
+        if is_field(line) {
+            break;
+        }
+        if !line.is_empty() {
+            if statement.is_some() {
+                return Err("Statement must be a single line".into());
+            }
+            statement = Some(line.to_string());
+        }
+        lines.next();
+    }
+
+    let mut values: [Option<String>; 8] = Default::default();
+    for (name, value) in FIELDS.iter().zip(values.iter_mut()) {
+        let prefix = format!("{}: ", name);
+        if let Some(v) = lines.peek().and_then(|l| l.strip_prefix(prefix.as_str())) {
+            *value = Some(v.to_string());
+            lines.next();
+        }
+    }
+    let [uri, version, chain_id, nonce, issued_at, expiration_time, not_before, request_id] = values;
+
+    let mut resources = Vec::new();
+    if lines.peek() == Some(&"Resources:") {
+        lines.next();
+        while let Some(resource) = lines.peek().and_then(|l| l.strip_prefix("- ")) {
+            resources.push(resource.to_string());
+            lines.next();
+        }
+    }
+    if lines.any(|l| !l.is_empty()) {
+        return Err("Unexpected content after message".into());
+    }
+
+    Ok(SiwsMessage {
+        scheme, domain, address, statement, uri, version, chain_id, nonce,
+        issued_at, expiration_time, not_before, request_id, resources,
+    })
+}
+
+/// Check a message against the request context. `origin` is None for WalletConnect
+/// sessions, where the domain cannot be bound. The nonce and timestamps are only
+/// checked when the message carries them.
+pub fn validate(msg: &SiwsMessage, origin: Option<&str>, our_address: &str, now: u64) -> Vec<SiweIssue> {
+    let mut issues = siwe::check_domain(msg.scheme.as_deref(), &msg.domain, origin);
+    // Base58 is case-sensitive
+    if msg.address != our_address {
+        issues.push(SiweIssue::AddressMismatch);
+    }
+    if msg.nonce.as_deref().is_some_and(|n| !siwe::is_valid_nonce(n)) {
+        issues.push(SiweIssue::InvalidNonce);
+    }
+    issues.extend(siwe::check_times(
+        msg.issued_at.as_deref(),
+        msg.expiration_time.as_deref(),
+        msg.not_before.as_deref(),
+        now,
+    ));
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T";
+    // 2025-03-01T12:05:00Z
+    const NOW: u64 = 1_740_830_700;
+
+    #[test]
+    fn test_build_from_sign_in_input() {
+        let input = serde_json::json!({
+            "statement": "Sign in to Example.",
+            "uri": "https://app.example.com/login",
+            "version": "1",
+            "chainId": "mainnet",
+            "nonce": "32891756abcd",
+            "issuedAt": "2025-03-01T12:00:00Z",
+            "resources": ["https://example.com/terms"],
+        });
+        let msg = SiwsMessage::from_sign_in_input(&input, "app.example.com", ADDRESS).unwrap();
+        let expected = format!(
+            "app.example.com wants you to sign in with your Solana account:\n\
+             {}\n\
+             \n\
+             Sign in to Example.\n\
+             \n\
+             URI: https://app.example.com/login\n\
+             Version: 1\n\
+             Chain ID: mainnet\n\
+             Nonce: 32891756abcd\n\
+             Issued At: 2025-03-01T12:00:00Z\n\
+             Resources:\n\
+             - https://example.com/terms",
+            ADDRESS
+        );
+        assert_eq!(msg.to_text(), expected);
+        assert!(is_siws_message(&expected));
+        assert_eq!(parse_siws(&expected).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_minimal_message() {
+        let msg = SiwsMessage::from_sign_in_input(&serde_json::json!({}), "localhost:3000", ADDRESS).unwrap();
+        let text = msg.to_text();
+        assert_eq!(text, format!("localhost:3000 wants you to sign in with your Solana account:\n{}", ADDRESS));
+        assert_eq!(parse_siws(&text).unwrap(), msg);
+        assert!(validate(&msg, Some("http://localhost:3000"), ADDRESS, NOW).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        let bad_address = serde_json::json!({"address": "0x1234"});
+        assert!(SiwsMessage::from_sign_in_input(&bad_address, "example.com", ADDRESS).is_err());
+        let injected = serde_json::json!({"statement": "hi\nURI: https://evil.example"});
+        assert!(SiwsMessage::from_sign_in_input(&injected, "example.com", ADDRESS).is_err());
+        assert!(parse_siws("example.com wants you to sign in with your Ethereum account:\n0x00").is_err());
+        let text = format!("example.com wants you to sign in with your Solana account:\n{}\n\nNonce: abcdefgh1\nURI: https://example.com", ADDRESS);
+        assert!(parse_siws(&text).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_issues() {
+        let input = serde_json::json!({
+            "domain": "app.example.com",
+            "nonce": "abc",
+            "expirationTime": "2025-03-01T12:00:00Z",
+        });
+        let msg = SiwsMessage::from_sign_in_input(&input, "evil.example.net", ADDRESS).unwrap();
+        let other = "11111111111111111111111111111111";
+        let issues = validate(&msg, Some("https://evil.example.net"), other, NOW);
+        assert!(issues.contains(&SiweIssue::DomainMismatch {
+            domain: "app.example.com".into(),
+            origin: "evil.example.net".into(),
+        }));
+        assert!(issues.contains(&SiweIssue::AddressMismatch));
+        assert!(issues.contains(&SiweIssue::InvalidNonce));
+        assert!(issues.contains(&SiweIssue::Expired));
+        // Unknown origin: only the phishing heuristics apply to the domain
+        assert!(validate(&msg, None, ADDRESS, NOW - 600).contains(&SiweIssue::InvalidNonce));
+        assert_eq!(validate(&msg, None, ADDRESS, NOW - 600).len(), 1);
+    }
+}
@@ -4,12 +4,13 @@
 // EIP-712: Typed structured data hashing and signing
 
 use k256::ecdsa::{SigningKey, signature::hazmat::PrehashSigner};
+use serde_json::Value;
 use super::keccak256;
 
 /// Sign pre-computed EIP-712 hashes.
 ///
-/// The caller is responsible for computing domain_separator and struct_hash
-/// from the typed data JSON (done in WASM/JS layer).
+/// The caller is responsible for computing domain_separator and struct_hash;
+/// `sign_typed_data` does both from the typed data JSON.
 ///
 /// Computes: keccak256("\x19\x01" + domainSeparator + structHash)
 /// Returns 65 bytes: r (32) + s (32) + v (1, value 27 or 28)
@@ -18,12 +19,19 @@ pub fn sign_typed_data_hash(
     struct_hash: &[u8; 32],
     private_key: &[u8; 32],
 ) -> Result<[u8; 65], String> {
-    let hash = eip712_hash(domain_separator, struct_hash);
+    sign_digest(&eip712_hash(domain_separator, struct_hash), private_key)
+}
+
+/// Sign a full `eth_signTypedData_v4` payload (see `hash_typed_data`)
+pub fn sign_typed_data(typed: &Value, private_key: &[u8; 32]) -> Result<[u8; 65], String> {
+    sign_digest(&hash_typed_data(typed)?, private_key)
+}
 
+fn sign_digest(hash: &[u8; 32], private_key: &[u8; 32]) -> Result<[u8; 65], String> {
     let signing_key = SigningKey::from_bytes(private_key.into())
         .map_err(|e| format!("Chiave non valida: {}", e))?;
     let (signature, recovery_id) = signing_key
-        .sign_prehash(&hash)
+        .sign_prehash(hash)
         .map_err(|e| format!("Errore firma: {}", e))?;
 
     let sig_bytes = signature.to_bytes();
@@ -74,7 +82,7 @@ pub fn hash_eip712_domain(
 }
 
 /// Hash a simple struct type for EIP-712.
-/// For complex types, the WASM/JS layer handles recursive hashing.
+/// Nested and array types are handled by `hash_typed_data`.
 pub fn hash_struct(type_hash: &[u8; 32], encoded_data: &[u8]) -> [u8; 32] {
     let mut data = Vec::with_capacity(32 + encoded_data.len());
     data.extend_from_slice(type_hash);
@@ -82,6 +90,200 @@ pub fn hash_struct(type_hash: &[u8; 32], encoded_data: &[u8]) -> [u8; 32] {
     keccak256(&data)
 }
 
+/// Full EIP-712 digest of an `eth_signTypedData_v4` payload:
+/// keccak256("\x19\x01" + hashStruct(domain) + hashStruct(message)).
+///
+/// Handles nested structs, arrays and any subset of the domain fields.
+pub fn hash_typed_data(typed: &Value) -> Result<[u8; 32], String> {
+    let types = typed.get("types").and_then(|t| t.as_object()).ok_or("Missing types")?;
+    let domain = typed.get("domain").ok_or("Missing domain")?;
+    let primary_type = typed.get("primaryType").and_then(|p| p.as_str()).ok_or("Missing primaryType")?;
+
+    // dApps often leave EIP712Domain out of `types`; derive it from the fields present
+    let mut types = types.clone();
+    if !types.contains_key("EIP712Domain") {
+        let fields: Vec<Value> = [
+            ("name", "string"),
+            ("version", "string"),
+            ("chainId", "uint256"),
+            ("verifyingContract", "address"),
+            ("salt", "bytes32"),
+        ].iter()
+            .filter(|(name, _)| domain.get(*name).is_some_and(|v| !v.is_null()))
+            .map(|(name, ty)| serde_json::json!({ "name": name, "type": ty }))
+            .collect();
+        types.insert("EIP712Domain".into(), Value::Array(fields));
+    }
+
+    let mut data = vec![0x19, 0x01];
+    data.extend_from_slice(&hash_struct_value("EIP712Domain", domain, &types)?);
+    if primary_type != "EIP712Domain" {
+        let message = typed.get("message").ok_or("Missing message")?;
+        data.extend_from_slice(&hash_struct_value(primary_type, message, &types)?);
+    }
+    Ok(keccak256(&data))
+}
+
+type Types = serde_json::Map<String, Value>;
+
+fn fields<'a>(type_name: &str, types: &'a Types) -> Result<&'a Vec<Value>, String> {
+    types.get(type_name).and_then(|f| f.as_array()).ok_or_else(|| format!("Unknown type: {}", type_name))
+}
+
+fn field_parts(field: &Value) -> Result<(&str, &str), String> {
+    let name = field.get("name").and_then(|n| n.as_str()).ok_or("Field without name")?;
+    let ty = field.get("type").and_then(|t| t.as_str()).ok_or("Field without type")?;
+    Ok((name, ty))
+}
+
+/// `Type[]` / `Type[3]` -> `Type`
+fn base_type(ty: &str) -> &str {
+    ty.split('[').next().unwrap_or(ty)
+}
+
+/// Struct types referenced (transitively) by `type_name`, including itself
+fn collect_dependencies(type_name: &str, types: &Types, found: &mut Vec<String>) -> Result<(), String> {
+    if found.iter().any(|t| t == type_name) || !types.contains_key(type_name) {
+        return Ok(());
+    }
+    found.push(type_name.to_string());
+    for field in fields(type_name, types)? {
+        let (_, ty) = field_parts(field)?;
+        collect_dependencies(base_type(ty), types, found)?;
+    }
+    Ok(())
+}
+
+/// encodeType: primary type followed by its dependencies sorted by name
+pub fn encode_type(type_name: &str, types: &Types) -> Result<String, String> {
+    let mut deps = Vec::new();
+    collect_dependencies(type_name, types, &mut deps)?;
+    if deps.is_empty() {
+        return Err(format!("Unknown type: {}", type_name));
+    }
+    deps[1..].sort();
+    let mut out = String::new();
+    for dep in &deps {
+        let members: Vec<String> = fields(dep, types)?.iter()
+            .map(|f| field_parts(f).map(|(name, ty)| format!("{} {}", ty, name)))
+            .collect::<Result<_, _>>()?;
+        out.push_str(&format!("{}({})", dep, members.join(",")));
+    }
+    Ok(out)
+}
+
+fn hash_struct_value(type_name: &str, data: &Value, types: &Types) -> Result<[u8; 32], String> {
+    let mut encoded = keccak256(encode_type(type_name, types)?.as_bytes()).to_vec();
+    for field in fields(type_name, types)? {
+        let (name, ty) = field_parts(field)?;
+        encoded.extend_from_slice(&encode_value(ty, data.get(name), types)?);
+    }
+    Ok(keccak256(&encoded))
+}
+
+fn encode_value(ty: &str, value: Option<&Value>, types: &Types) -> Result<[u8; 32], String> {
+    // Arrays: keccak256 of the concatenated encodings of the elements
+    if let Some(inner) = ty.strip_suffix(']') {
+        let elem_ty = &inner[..inner.rfind('[').ok_or_else(|| format!("Invalid type: {}", ty))?];
+        let items = value.and_then(|v| v.as_array()).ok_or_else(|| format!("Expected array for {}", ty))?;
+        let mut concat = Vec::with_capacity(items.len() * 32);
+        for item in items {
+            concat.extend_from_slice(&encode_value(elem_ty, Some(item), types)?);
+        }
+        return Ok(keccak256(&concat));
+    }
+    if types.contains_key(ty) {
+        return match value {
+            None | Some(Value::Null) => Ok([0u8; 32]),
+            Some(v) => hash_struct_value(ty, v, types),
+        };
+    }
+
+    let value = value.ok_or_else(|| format!("Missing value for {}", ty))?;
+    let mut word = [0u8; 32];
+    match ty {
+        "address" => {
+            let addr = crate::tx::evm::parse_address(value.as_str().ok_or("Address must be a string")?)?;
+            word[12..].copy_from_slice(&addr);
+        }
+        "bool" => {
+            let b = match value {
+                Value::Bool(b) => *b,
+                Value::String(s) => s == "true",
+                Value::Number(n) => n.as_u64() != Some(0),
+                _ => return Err("Invalid bool".into()),
+            };
+            word[31] = b as u8;
+        }
+        "string" => word = keccak256(value.as_str().ok_or("String expected")?.as_bytes()),
+        "bytes" => word = keccak256(&decode_hex_value(value)?),
+        t if t.starts_with("bytes") => {
+            let bytes = decode_hex_value(value)?;
+            if bytes.len() > 32 {
+                return Err(format!("Too many bytes for {}", t));
+            }
+            word[..bytes.len()].copy_from_slice(&bytes);
+        }
+        t if t.starts_with("uint") || t.starts_with("int") => word = encode_integer(value)?,
+        other => return Err(format!("Unsupported type: {}", other)),
+    }
+    Ok(word)
+}
+
+fn decode_hex_value(value: &Value) -> Result<Vec<u8>, String> {
+    let s = value.as_str().ok_or("Hex string expected")?;
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| format!("Invalid hex: {}", e))
+}
+
+/// uint/int as a 256-bit big-endian word (two's complement for negatives).
+/// Accepts JSON numbers, decimal strings and 0x-prefixed hex strings.
+fn encode_integer(value: &Value) -> Result<[u8; 32], String> {
+    let text = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.trim().to_string(),
+        _ => return Err("Integer expected".into()),
+    };
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.as_str()),
+    };
+    let mut word = [0u8; 32];
+    if let Some(hex_digits) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        let padded = if hex_digits.len() % 2 == 1 { format!("0{}", hex_digits) } else { hex_digits.to_string() };
+        let bytes = hex::decode(padded).map_err(|_| format!("Invalid integer: {}", text))?;
+        if bytes.len() > 32 {
+            return Err(format!("Integer too large: {}", text));
+        }
+        word[32 - bytes.len()..].copy_from_slice(&bytes);
+    } else {
+        if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid integer: {}", text));
+        }
+        for d in digits.bytes() {
+            // word = word * 10 + d
+            let mut carry = (d - b'0') as u32;
+            for byte in word.iter_mut().rev() {
+                let v = *byte as u32 * 10 + carry;
+                *byte = v as u8;
+                carry = v >> 8;
+            }
+            if carry != 0 {
+                return Err(format!("Integer too large: {}", text));
+            }
+        }
+    }
+    if negative {
+        // Two's complement: invert and add one
+        let mut carry = 1u16;
+        for byte in word.iter_mut().rev() {
+            let v = (!*byte) as u16 + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+    }
+    Ok(word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +348,99 @@ mod tests {
         let h = hash_struct(&type_hash, &encoded);
         assert_ne!(h, [0u8; 32]);
     }
+
+    /// The "Mail" example from the EIP-712 specification
+    fn mail_example() -> Value {
+        serde_json::json!({
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"}
+                ],
+                "Person": [
+                    {"name": "name", "type": "string"},
+                    {"name": "wallet", "type": "address"}
+                ],
+                "Mail": [
+                    {"name": "from", "type": "Person"},
+                    {"name": "to", "type": "Person"},
+                    {"name": "contents", "type": "string"}
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+                "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+                "contents": "Hello, Bob!"
+            }
+        })
+    }
+
+    #[test]
+    fn test_hash_typed_data_spec_vector() {
+        let typed = mail_example();
+        let types = typed["types"].as_object().unwrap();
+        assert_eq!(
+            encode_type("Mail", types).unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+        let hash = hash_typed_data(&typed).unwrap();
+        assert_eq!(hex::encode(hash), "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+        // The four-field domain is also what hash_eip712_domain produces
+        let contract = crate::tx::evm::parse_address("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap();
+        let sep = hash_eip712_domain("Ether Mail", "1", 1, &contract);
+        assert_eq!(hex::encode(sep), "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+
+        // Omitting EIP712Domain from types derives the same domain
+        let mut implicit = typed.clone();
+        implicit["types"].as_object_mut().unwrap().remove("EIP712Domain");
+        assert_eq!(hash_typed_data(&implicit).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_sign_typed_data_matches_hash_signing() {
+        let typed = mail_example();
+        let types = typed["types"].as_object().unwrap();
+        let domain = hash_struct_value("EIP712Domain", &typed["domain"], types).unwrap();
+        let message = hash_struct_value("Mail", &typed["message"], types).unwrap();
+        // RFC 6979 signatures are deterministic
+        assert_eq!(
+            sign_typed_data(&typed, &TEST_KEY).unwrap(),
+            sign_typed_data_hash(&domain, &message, &TEST_KEY).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_typed_data_arrays_and_integers() {
+        let typed = serde_json::json!({
+            "types": {
+                "Batch": [
+                    {"name": "ids", "type": "uint256[]"},
+                    {"name": "delta", "type": "int256"},
+                    {"name": "tag", "type": "bytes4"}
+                ]
+            },
+            "primaryType": "Batch",
+            "domain": {"name": "Test", "chainId": "0x1"},
+            "message": {"ids": [1, "2", "0x03"], "delta": "-1", "tag": "0xdeadbeef"}
+        });
+        assert!(hash_typed_data(&typed).is_ok());
+        assert_eq!(encode_integer(&serde_json::json!("-1")).unwrap(), [0xff; 32]);
+        assert_eq!(encode_integer(&serde_json::json!(258)).unwrap()[30..], [1, 2]);
+        assert_eq!(encode_integer(&serde_json::json!("0x0102")).unwrap()[30..], [1, 2]);
+        assert!(encode_integer(&serde_json::json!("1e5")).is_err());
+
+        let mut missing = typed.clone();
+        missing["message"].as_object_mut().unwrap().remove("delta");
+        assert!(hash_typed_data(&missing).is_err());
+    }
 }
@@ -1,10 +1,11 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// signing: EIP-191 personal_sign and EIP-712 typed data signing
+// signing: EIP-191 personal_sign and EIP-712 typed data signing, signature verification
 
 pub mod personal_sign;
 pub mod eip712;
+pub mod verify;
 
 /// Keccak-256 hash helper
 pub fn keccak256(data: &[u8]) -> [u8; 32] {
@@ -38,9 +38,14 @@ pub fn personal_sign_hash(message: &[u8]) -> [u8; 32] {
 
 /// Recover the signer address from a personal_sign signature
 pub fn recover_address(message: &[u8], signature: &[u8; 65]) -> Result<[u8; 20], String> {
+    recover_prehash_address(&personal_sign_hash(message), signature)
+}
+
+/// Recover the signer address of a signature over an already-computed hash
+/// (e.g. an EIP-712 digest); `v` is 27 or 28
+pub fn recover_prehash_address(hash: &[u8; 32], signature: &[u8; 65]) -> Result<[u8; 20], String> {
     use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 
-    let hash = personal_sign_hash(message);
     let v = signature[64];
     let recovery_id = RecoveryId::try_from(v.wrapping_sub(27))
         .map_err(|e| format!("Recovery ID non valido: {}", e))?;
@@ -48,7 +53,7 @@ pub fn recover_address(message: &[u8], signature: &[u8; 65]) -> Result<[u8; 20],
     let sig = Signature::from_slice(&signature[..64])
         .map_err(|e| format!("Firma non valida: {}", e))?;
 
-    let verifying_key = VerifyingKey::recover_from_prehash(&hash, &sig, recovery_id)
+    let verifying_key = VerifyingKey::recover_from_prehash(hash, &sig, recovery_id)
         .map_err(|e| format!("Recovery fallito: {}", e))?;
 
     // Public key → keccak256 → last 20 bytes = address
@@ -0,0 +1,366 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// Signature verification: ecrecover for EOAs, ERC-1271 for deployed smart
+// accounts and ERC-6492 for accounts that have not been deployed yet
+
+use serde_json::{json, Value};
+
+use super::personal_sign::recover_prehash_address;
+
+/// `isValidSignature(bytes32,bytes)` selector, also the value returned on success
+pub const ERC1271_MAGIC: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Trailing marker of an ERC-6492 wrapped signature
+pub const ERC6492_SUFFIX: [u8; 32] = [
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+];
+
+/// How a signature was checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyMethod {
+    /// Recovered from the signature (externally owned account)
+    Ecrecover,
+    /// `isValidSignature` on the deployed account
+    Erc1271,
+    /// Account deployed by its factory inside a simulation, then ERC-1271
+    Erc6492,
+}
+
+impl VerifyMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            VerifyMethod::Ecrecover => "ecrecover",
+            VerifyMethod::Erc1271 => "ERC-1271",
+            VerifyMethod::Erc6492 => "ERC-6492",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub valid: bool,
+    pub method: VerifyMethod,
+    /// Address recovered by ecrecover (EOA checks only)
+    pub recovered: Option<[u8; 20]>,
+}
+
+/// ERC-6492 wrapper: `abi.encode(factory, factoryCalldata, signature) ++ magic`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erc6492Signature {
+    pub factory: [u8; 20],
+    pub factory_calldata: Vec<u8>,
+    /// Signature to pass to `isValidSignature` once the account exists
+    pub signature: Vec<u8>,
+}
+
+/// What the signature is over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    /// EIP-191 personal message: 0x-prefixed hex is taken as raw bytes (as in
+    /// `personal_sign` params), anything else as UTF-8 text
+    Personal,
+    /// EIP-712 typed data JSON
+    TypedData,
+    /// Precomputed 32-byte digest
+    Hash,
+}
+
+/// Digest that was signed for `input` interpreted as `kind`
+pub fn message_hash(kind: MessageKind, input: &str) -> Result<[u8; 32], String> {
+    match kind {
+        MessageKind::Personal => {
+            let bytes = input.strip_prefix("0x")
+                .and_then(|h| hex::decode(h).ok())
+                .unwrap_or_else(|| input.as_bytes().to_vec());
+            Ok(super::personal_sign::personal_sign_hash(&bytes))
+        }
+        MessageKind::TypedData => {
+            let typed: Value = serde_json::from_str(input).map_err(|e| format!("Invalid typed data JSON: {}", e))?;
+            super::eip712::hash_typed_data(&typed)
+        }
+        MessageKind::Hash => {
+            let input = input.trim();
+            let bytes = hex::decode(input.strip_prefix("0x").unwrap_or(input)).map_err(|e| format!("Invalid hash: {}", e))?;
+            bytes.try_into().map_err(|_| "Hash must be 32 bytes".into())
+        }
+    }
+}
+
+/// Parse a hex signature (with or without 0x)
+pub fn parse_signature_hex(s: &str) -> Result<Vec<u8>, String> {
+    let s = s.trim();
+    let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| format!("Invalid signature hex: {}", e))?;
+    if bytes.is_empty() {
+        return Err("Empty signature".into());
+    }
+    Ok(bytes)
+}
+
+/// Recover the signer of `hash` from a 65-byte (`v` = 0/1/27/28) or
+/// 64-byte EIP-2098 compact signature
+pub fn recover_signer(hash: &[u8; 32], signature: &[u8]) -> Result<[u8; 20], String> {
+    let mut sig = [0u8; 65];
+    match signature.len() {
+        65 => {
+            sig.copy_from_slice(signature);
+            if sig[64] < 27 {
+                sig[64] += 27;
+            }
+        }
+        64 => {
+            // yParityAndS: the top bit of s carries the recovery id
+            sig[..64].copy_from_slice(signature);
+            sig[64] = 27 + (sig[32] >> 7);
+            sig[32] &= 0x7f;
+        }
+        n => return Err(format!("Invalid signature length: {} bytes", n)),
+    }
+    if sig[64] != 27 && sig[64] != 28 {
+        return Err(format!("Invalid v value: {}", sig[64]));
+    }
+    recover_prehash_address(hash, &sig)
+}
+
+/// Unwrap an ERC-6492 signature; `None` if the magic suffix is absent
+pub fn decode_erc6492(signature: &[u8]) -> Result<Option<Erc6492Signature>, String> {
+    let Some(body) = signature.strip_suffix(&ERC6492_SUFFIX[..]) else {
+        return Ok(None);
+    };
+    let word = |i: usize| body.get(i * 32..(i + 1) * 32).ok_or("Truncated ERC-6492 signature");
+    let factory_word = word(0)?;
+    if factory_word[..12].iter().any(|&b| b != 0) {
+        return Err("Invalid ERC-6492 factory address".into());
+    }
+    let mut factory = [0u8; 20];
+    factory.copy_from_slice(&factory_word[12..]);
+
+    let dynamic = |head: usize| -> Result<Vec<u8>, String> {
+        let offset = abi_usize(word(head)?)?;
+        let len = abi_usize(body.get(offset..offset + 32).ok_or("Truncated ERC-6492 signature")?)?;
+        body.get(offset + 32..offset + 32 + len)
+            .map(|b| b.to_vec())
+            .ok_or_else(|| "Truncated ERC-6492 signature".into())
+    };
+    Ok(Some(Erc6492Signature {
+        factory,
+        factory_calldata: dynamic(1)?,
+        signature: dynamic(2)?,
+    }))
+}
+
+fn abi_usize(word: &[u8]) -> Result<usize, String> {
+    if word[..24].iter().any(|&b| b != 0) {
+        return Err("ABI offset out of range".into());
+    }
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&word[24..32]);
+    usize::try_from(u64::from_be_bytes(buf)).map_err(|_| "ABI offset out of range".into())
+}
+
+/// Calldata for `isValidSignature(bytes32 hash, bytes signature)`
+pub fn encode_is_valid_signature(hash: &[u8; 32], signature: &[u8]) -> Vec<u8> {
+    let padded = signature.len().div_ceil(32) * 32;
+    let mut data = Vec::with_capacity(4 + 32 * 3 + padded);
+    data.extend_from_slice(&ERC1271_MAGIC);
+    data.extend_from_slice(hash);
+    let mut word = [0u8; 32];
+    word[31] = 0x40;
+    data.extend_from_slice(&word);
+    word = [0u8; 32];
+    word[24..].copy_from_slice(&(signature.len() as u64).to_be_bytes());
+    data.extend_from_slice(&word);
+    data.extend_from_slice(signature);
+    data.resize(4 + 32 * 3 + padded, 0);
+    data
+}
+
+/// True if the `isValidSignature` return data carries the ERC-1271 magic value
+pub fn is_erc1271_success(return_data: &[u8]) -> bool {
+    return_data.len() >= 4 && return_data[..4] == ERC1271_MAGIC
+}
+
+/// eth_simulateV1 request that runs the ERC-6492 factory call and then
+/// `isValidSignature` on the (now deployed) account in the same block
+pub fn erc6492_simulate_body(account: &[u8; 20], hash: &[u8; 32], wrapped: &Erc6492Signature) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "eth_simulateV1",
+        "params": [{
+            "blockStateCalls": [{
+                "calls": [
+                    {
+                        "to": format!("0x{}", hex::encode(wrapped.factory)),
+                        "data": format!("0x{}", hex::encode(&wrapped.factory_calldata)),
+                    },
+                    {
+                        "to": format!("0x{}", hex::encode(account)),
+                        "data": format!("0x{}", hex::encode(encode_is_valid_signature(hash, &wrapped.signature))),
+                    },
+                ],
+            }],
+            "validation": false,
+        }, "latest"],
+        "id": 1,
+    })
+}
+
+/// Result of the `isValidSignature` call in an `erc6492_simulate_body` response
+pub fn parse_erc6492_simulation(result: &Value) -> Result<bool, String> {
+    let call = result.get(0)
+        .and_then(|block| block.get("calls"))
+        .and_then(|calls| calls.get(1))
+        .ok_or("Unexpected eth_simulateV1 response")?;
+    if call.get("status").and_then(|s| s.as_str()) != Some("0x1") {
+        return Ok(false);
+    }
+    let data = call.get("returnData").and_then(|d| d.as_str()).unwrap_or("0x");
+    let bytes = hex::decode(data.strip_prefix("0x").unwrap_or(data)).map_err(|e| format!("Invalid returnData: {}", e))?;
+    Ok(is_erc1271_success(&bytes))
+}
+
+/// EOA check: recover the signer and compare with `expected`
+pub fn verify_ecrecover(hash: &[u8; 32], signature: &[u8], expected: &[u8; 20]) -> Verification {
+    let recovered = recover_signer(hash, signature).ok();
+    Verification {
+        valid: recovered.as_ref() == Some(expected),
+        method: VerifyMethod::Ecrecover,
+        recovered,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::{keccak256, personal_sign};
+
+    const TEST_KEY: [u8; 32] = [
+        0xac, 0x09, 0x74, 0xbe, 0xc3, 0x9a, 0x17, 0xe3,
+        0x6b, 0xa4, 0xa6, 0xb4, 0xd2, 0x38, 0xff, 0x94,
+        0x4b, 0xac, 0xb3, 0x78, 0x50, 0x0f, 0xa5, 0x5e,
+        0x4c, 0x4d, 0x47, 0x1b, 0x52, 0x6a, 0x98, 0x52,
+    ];
+
+    fn signer() -> [u8; 20] {
+        let key = k256::ecdsa::SigningKey::from_bytes((&TEST_KEY).into()).unwrap();
+        let point = key.verifying_key().to_encoded_point(false);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&keccak256(&point.as_bytes()[1..])[12..]);
+        address
+    }
+
+    #[test]
+    fn test_ecrecover_formats() {
+        let message = b"support ticket #42";
+        let sig = personal_sign::personal_sign(message, &TEST_KEY).unwrap();
+        let hash = personal_sign::personal_sign_hash(message);
+        let expected = signer();
+
+        let result = verify_ecrecover(&hash, &sig, &expected);
+        assert!(result.valid);
+        assert_eq!(result.method, VerifyMethod::Ecrecover);
+
+        // v as 0/1
+        let mut raw_v = sig;
+        raw_v[64] -= 27;
+        assert_eq!(recover_signer(&hash, &raw_v).unwrap(), expected);
+
+        // EIP-2098 compact form
+        let mut compact = [0u8; 64];
+        compact.copy_from_slice(&sig[..64]);
+        compact[32] |= (sig[64] - 27) << 7;
+        assert_eq!(recover_signer(&hash, &compact).unwrap(), expected);
+
+        // Different message → different signer
+        let other = personal_sign::personal_sign_hash(b"other");
+        assert!(!verify_ecrecover(&other, &sig, &expected).valid);
+        assert!(recover_signer(&hash, &sig[..10]).is_err());
+    }
+
+    #[test]
+    fn test_message_hash() {
+        let text = message_hash(MessageKind::Personal, "hi").unwrap();
+        assert_eq!(text, personal_sign::personal_sign_hash(b"hi"));
+        assert_eq!(message_hash(MessageKind::Personal, "0x6869").unwrap(), text);
+        // Not valid hex: signed as text
+        assert_eq!(message_hash(MessageKind::Personal, "0xzz").unwrap(), personal_sign::personal_sign_hash(b"0xzz"));
+
+        let digest = format!("0x{}", "ab".repeat(32));
+        assert_eq!(message_hash(MessageKind::Hash, &digest).unwrap(), [0xab; 32]);
+        assert!(message_hash(MessageKind::Hash, "0xabcd").is_err());
+        assert!(message_hash(MessageKind::TypedData, "{}").is_err());
+    }
+
+    #[test]
+    fn test_is_valid_signature_calldata() {
+        let hash = keccak256(b"hash");
+        let data = encode_is_valid_signature(&hash, &[0xab; 65]);
+        assert_eq!(&data[..4], &ERC1271_MAGIC);
+        assert_eq!(&data[4..36], &hash);
+        assert_eq!(data[67], 0x40);
+        assert_eq!(data[99], 65);
+        assert_eq!(data.len(), 4 + 96 + 96);
+        assert_eq!(data[100 + 64], 0xab);
+        assert_eq!(data[100 + 65], 0);
+
+        let mut ret = [0u8; 32];
+        ret[..4].copy_from_slice(&ERC1271_MAGIC);
+        assert!(is_erc1271_success(&ret));
+        assert!(!is_erc1271_success(&[0xff; 32]));
+    }
+
+    fn wrap_6492(factory: [u8; 20], calldata: &[u8], inner: &[u8]) -> Vec<u8> {
+        fn word(n: usize) -> [u8; 32] {
+            let mut w = [0u8; 32];
+            w[24..].copy_from_slice(&(n as u64).to_be_bytes());
+            w
+        }
+        fn tail(bytes: &[u8]) -> Vec<u8> {
+            let mut out = word(bytes.len()).to_vec();
+            out.extend_from_slice(bytes);
+            out.resize(32 + bytes.len().div_ceil(32) * 32, 0);
+            out
+        }
+        let calldata_tail = tail(calldata);
+        let mut out = [0u8; 12].to_vec();
+        out.extend_from_slice(&factory);
+        out.extend_from_slice(&word(96));
+        out.extend_from_slice(&word(96 + calldata_tail.len()));
+        out.extend_from_slice(&calldata_tail);
+        out.extend_from_slice(&tail(inner));
+        out.extend_from_slice(&ERC6492_SUFFIX);
+        out
+    }
+
+    #[test]
+    fn test_decode_erc6492() {
+        let wrapped = wrap_6492([0x11; 20], &[0xde, 0xad, 0xbe, 0xef, 0x01], &[0x22; 65]);
+        let decoded = decode_erc6492(&wrapped).unwrap().unwrap();
+        assert_eq!(decoded.factory, [0x11; 20]);
+        assert_eq!(decoded.factory_calldata, vec![0xde, 0xad, 0xbe, 0xef, 0x01]);
+        assert_eq!(decoded.signature, vec![0x22; 65]);
+
+        assert_eq!(decode_erc6492(&[0x22; 65]).unwrap(), None);
+        assert!(decode_erc6492(&wrapped[wrapped.len() - 40..]).is_err());
+    }
+
+    #[test]
+    fn test_erc6492_simulation() {
+        let wrapped = decode_erc6492(&wrap_6492([0x11; 20], &[1, 2], &[3; 65])).unwrap().unwrap();
+        let body = erc6492_simulate_body(&[0x33; 20], &[0x44; 32], &wrapped);
+        let calls = &body["params"][0]["blockStateCalls"][0]["calls"];
+        assert_eq!(calls[0]["to"], format!("0x{}", "11".repeat(20)));
+        assert_eq!(calls[1]["to"], format!("0x{}", "33".repeat(20)));
+        assert!(calls[1]["data"].as_str().unwrap().starts_with("0x1626ba7e"));
+
+        let ok = json!([{ "calls": [
+            { "status": "0x1", "returnData": "0x" },
+            { "status": "0x1", "returnData": format!("0x1626ba7e{}", "0".repeat(56)) },
+        ]}]);
+        assert!(parse_erc6492_simulation(&ok).unwrap());
+        let reverted = json!([{ "calls": [{ "status": "0x1" }, { "status": "0x0", "returnData": "0x" }] }]);
+        assert!(!parse_erc6492_simulation(&reverted).unwrap());
+        assert!(parse_erc6492_simulation(&json!([])).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+// Rusby Wallet — Pure Rust multi-chain crypto wallet
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tokens/approvals: Discovery of ERC-20 allowances and ERC-721/1155 operator
+// approvals from Approval / ApprovalForAll logs, scanned in block ranges
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::chains::evm::eip55_checksum;
+use crate::signing::keccak256;
+use crate::tx::evm::parse_address;
+
+pub const APPROVAL_EVENT: &str = "Approval(address,address,uint256)";
+pub const APPROVAL_FOR_ALL_EVENT: &str = "ApprovalForAll(address,address,bool)";
+
+/// First eth_getLogs window; most providers cap ranges somewhere between 2k and 100k blocks
+pub const INITIAL_STEP: u64 = 50_000;
+/// Ranges never grow beyond this after successful queries
+pub const MAX_STEP: u64 = 2_000_000;
+/// Smallest range before a provider error is treated as fatal
+pub const MIN_STEP: u64 = 500;
+
+/// What a discovered approval lets the spender move
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApprovalKind {
+    /// ERC-20 `approve(spender, amount)`
+    Allowance,
+    /// ERC-721 / ERC-1155 `setApprovalForAll(operator, true)`
+    Operator,
+}
+
+/// Contract/spender pair seen in the logs; its current state still has to be read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalCandidate {
+    pub contract: String,
+    pub spender: String,
+    pub kind: ApprovalKind,
+}
+
+/// Progress of the log scan for one owner on one chain, persisted between visits.
+/// History is scanned from the newest block backwards, new blocks forwards.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanState {
+    /// Highest block already scanned
+    pub newest: Option<u64>,
+    /// Lowest block already scanned
+    pub oldest: Option<u64>,
+    /// Current eth_getLogs window (learned from provider limits)
+    pub step: u64,
+    pub candidates: Vec<ApprovalCandidate>,
+}
+
+impl Default for ScanState {
+    fn default() -> Self {
+        Self { newest: None, oldest: None, step: INITIAL_STEP, candidates: Vec::new() }
+    }
+}
+
+impl ScanState {
+    /// Next inclusive block range to query: new blocks first, then older history
+    pub fn next_range(&self, latest: u64) -> Option<(u64, u64)> {
+        let step = self.step.max(1);
+        match (self.newest, self.oldest) {
+            (None, _) | (_, None) => Some((latest.saturating_sub(step - 1), latest)),
+            (Some(newest), _) if newest < latest => Some((newest + 1, latest.min(newest + step))),
+            (_, Some(oldest)) if oldest > 0 => Some((oldest.saturating_sub(step), oldest - 1)),
+            _ => None,
+        }
+    }
+
+    /// True once every block up to `latest` has been scanned
+    pub fn is_complete(&self, latest: u64) -> bool {
+        self.next_range(latest).is_none()
+    }
+
+    /// Record a successful query of `range` and widen the window for the next one
+    pub fn record(&mut self, range: (u64, u64), logs: &[ApprovalLog]) {
+        let (from, to) = range;
+        self.newest = Some(self.newest.map_or(to, |n| n.max(to)));
+        self.oldest = Some(self.oldest.map_or(from, |o| o.min(from)));
+        for log in logs {
+            let candidate = log.candidate();
+            if !self.candidates.contains(&candidate) {
+                self.candidates.push(candidate);
+            }
+        }
+        self.step = (self.step * 2).min(MAX_STEP);
+    }
+
+    /// Halve the window after a provider rejected the range. False if it cannot shrink further.
+    pub fn shrink(&mut self) -> bool {
+        if self.step <= MIN_STEP {
+            return false;
+        }
+        self.step = (self.step / 2).max(MIN_STEP);
+        true
+    }
+
+    /// Forget a candidate after it was revoked or found inactive
+    pub fn remove(&mut self, contract: &str, spender: &str, kind: ApprovalKind) {
+        self.candidates.retain(|c| {
+            !(c.kind == kind && c.contract.eq_ignore_ascii_case(contract) && c.spender.eq_ignore_ascii_case(spender))
+        });
+    }
+}
+
+/// An Approval / ApprovalForAll log emitted for the owner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalLog {
+    pub contract: [u8; 20],
+    pub spender: [u8; 20],
+    pub kind: ApprovalKind,
+}
+
+impl ApprovalLog {
+    pub fn candidate(&self) -> ApprovalCandidate {
+        ApprovalCandidate {
+            contract: eip55_checksum(&self.contract),
+            spender: eip55_checksum(&self.spender),
+            kind: self.kind,
+        }
+    }
+}
+
+fn topic_hex(bytes: &[u8; 32]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn address_topic(address: &[u8; 20]) -> String {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    topic_hex(&word)
+}
+
+/// eth_getLogs request for both events with the owner as first indexed argument
+pub fn get_logs_body(owner: &str, from_block: u64, to_block: u64) -> Result<Value, String> {
+    let owner = parse_address(owner)?;
+    Ok(json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getLogs",
+        "params": [{
+            "fromBlock": format!("0x{:x}", from_block),
+            "toBlock": format!("0x{:x}", to_block),
+            "topics": [
+                [
+                    topic_hex(&keccak256(APPROVAL_EVENT.as_bytes())),
+                    topic_hex(&keccak256(APPROVAL_FOR_ALL_EVENT.as_bytes())),
+                ],
+                address_topic(&owner),
+            ],
+        }],
+        "id": 1,
+    }))
+}
+
+/// Parse the `result` array of eth_getLogs.
+///
+/// ERC-721 single-token approvals (Approval with an indexed token id) are skipped:
+/// they are cleared on every transfer and cannot move anything else.
+pub fn parse_logs(result: &Value) -> Result<Vec<ApprovalLog>, String> {
+    let entries = result.as_array().ok_or("eth_getLogs result is not an array")?;
+    let approval = keccak256(APPROVAL_EVENT.as_bytes());
+    let approval_for_all = keccak256(APPROVAL_FOR_ALL_EVENT.as_bytes());
+
+    let mut logs = Vec::new();
+    for entry in entries {
+        if entry.get("removed").and_then(|r| r.as_bool()) == Some(true) {
+            continue;
+        }
+        let topics: Vec<[u8; 32]> = entry.get("topics")
+            .and_then(|t| t.as_array())
+            .map(|t| t.iter().filter_map(|v| decode_word(v.as_str()?)).collect())
+            .unwrap_or_default();
+        let Some(contract) = entry.get("address").and_then(|a| a.as_str()).and_then(|a| parse_address(a).ok()) else {
+            continue;
+        };
+        let kind = match topics.first() {
+            Some(t) if *t == approval && topics.len() == 3 => ApprovalKind::Allowance,
+            Some(t) if *t == approval_for_all && topics.len() == 3 => ApprovalKind::Operator,
+            _ => continue,
+        };
+        let mut spender = [0u8; 20];
+        spender.copy_from_slice(&topics[2][12..]);
+        logs.push(ApprovalLog { contract, spender, kind });
+    }
+    Ok(logs)
+}
+
+fn decode_word(hex_str: &str) -> Option<[u8; 32]> {
+    hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str)).ok()?.try_into().ok()
+}
+
+/// Provider errors that mean "ask for a smaller block range"
+pub fn is_range_error(message: &str) -> bool {
+    let m = message.to_lowercase();
+    ["range", "too many", "limit", "exceed", "more than", "too large", "timeout", "timed out"]
+        .iter()
+        .any(|needle| m.contains(needle))
+}
+
+/// Current allowance as read from `allowance(owner, spender)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Allowance {
+    Amount(u128),
+    /// Larger than any realistic balance (typically 2^256 - 1)
+    Unlimited,
+}
+
+/// Decode an `allowance()` / `isApprovedForAll()` eth_call result.
+/// Returns `None` for a zero value (nothing to revoke).
+pub fn decode_allowance(hex_result: &str) -> Option<Allowance> {
+    let word = decode_word(hex_result)?;
+    if word.iter().all(|b| *b == 0) {
+        return None;
+    }
+    if word[..16].iter().any(|b| *b != 0) {
+        return Some(Allowance::Unlimited);
+    }
+    let value = u128::from_be_bytes(word[16..].try_into().unwrap());
+    Some(if value >= u128::MAX / 2 { Allowance::Unlimited } else { Allowance::Amount(value) })
+}
+
+/// `isApprovedForAll(address owner, address operator)` calldata
+pub fn encode_is_approved_for_all(owner: &str, operator: &str) -> Result<Vec<u8>, String> {
+    let selector = &keccak256(b"isApprovedForAll(address,address)")[..4];
+    let mut data = selector.to_vec();
+    for address in [owner, operator] {
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(&parse_address(address)?);
+    }
+    Ok(data)
+}
+
+/// `setApprovalForAll(operator, false)` calldata
+pub fn encode_revoke_operator(operator: &str) -> Result<Vec<u8>, String> {
+    let selector = &keccak256(b"setApprovalForAll(address,bool)")[..4];
+    let mut data = selector.to_vec();
+    data.extend_from_slice(&[0u8; 12]);
+    data.extend_from_slice(&parse_address(operator)?);
+    data.extend_from_slice(&[0u8; 32]);
+    Ok(data)
+}
+
+/// Calldata that removes the approval of `spender` on a contract
+pub fn encode_revoke(kind: ApprovalKind, spender: &str) -> Result<Vec<u8>, String> {
+    match kind {
+        ApprovalKind::Allowance => super::erc20::encode_revoke(spender),
+        ApprovalKind::Operator => encode_revoke_operator(spender),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: &str = "0x1111111111111111111111111111111111111111";
+    const TOKEN: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const SPENDER: &str = "0x2222222222222222222222222222222222222222";
+
+    fn log(event: &str, contract: &str, extra_topic: bool) -> Value {
+        let owner = address_topic(&parse_address(OWNER).unwrap());
+        let spender = address_topic(&parse_address(SPENDER).unwrap());
+        let mut topics = vec![topic_hex(&keccak256(event.as_bytes())), owner, spender];
+        if extra_topic {
+            topics.push(topic_hex(&[7u8; 32]));
+        }
+        json!({ "address": contract, "topics": topics, "data": "0x", "removed": false })
+    }
+
+    #[test]
+    fn test_get_logs_body() {
+        let body = get_logs_body(OWNER, 100, 0x1ff).unwrap();
+        let filter = &body["params"][0];
+        assert_eq!(filter["fromBlock"], "0x64");
+        assert_eq!(filter["toBlock"], "0x1ff");
+        assert_eq!(filter["topics"][0].as_array().unwrap().len(), 2);
+        assert_eq!(filter["topics"][1], format!("0x{}{}", "0".repeat(24), "1".repeat(40)));
+        assert!(get_logs_body("nope", 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_parse_logs() {
+        let result = json!([
+            log(APPROVAL_EVENT, TOKEN, false),
+            // ERC-721 per-token approval: skipped
+            log(APPROVAL_EVENT, TOKEN, true),
+            log(APPROVAL_FOR_ALL_EVENT, "0x3333333333333333333333333333333333333333", false),
+            log("Transfer(address,address,uint256)", TOKEN, false),
+        ]);
+        let logs = parse_logs(&result).unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].kind, ApprovalKind::Allowance);
+        assert_eq!(logs[0].candidate().contract, "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        assert_eq!(logs[1].kind, ApprovalKind::Operator);
+        assert!(parse_logs(&json!({})).is_err());
+    }
+
+    #[test]
+    fn test_scan_ranges() {
+        let mut state = ScanState { step: 1000, ..Default::default() };
+        assert_eq!(state.next_range(5000), Some((4001, 5000)));
+        state.record((4001, 5000), &[]);
+        assert_eq!(state.step, 2000);
+        // History next, walking backwards
+        assert_eq!(state.next_range(5000), Some((2001, 4000)));
+        assert!(state.shrink());
+        assert_eq!(state.next_range(5000), Some((3001, 4000)));
+        state.record((3001, 4000), &[]);
+        // New blocks take priority
+        assert_eq!(state.next_range(5100), Some((5001, 5100)));
+        state.record((5001, 5100), &[]);
+        state.step = MAX_STEP;
+        assert_eq!(state.next_range(5100), Some((0, 3000)));
+        state.record((0, 3000), &[]);
+        assert!(state.is_complete(5100));
+        assert!(!state.is_complete(5101));
+
+        state.step = MIN_STEP;
+        assert!(!state.shrink());
+    }
+
+    #[test]
+    fn test_candidates_are_deduplicated() {
+        let logs = parse_logs(&json!([log(APPROVAL_EVENT, TOKEN, false), log(APPROVAL_EVENT, TOKEN, false)])).unwrap();
+        let mut state = ScanState::default();
+        state.record((0, 10), &logs);
+        state.record((11, 20), &logs);
+        assert_eq!(state.candidates.len(), 1);
+        state.remove(&TOKEN.to_uppercase().replace("0X", "0x"), SPENDER, ApprovalKind::Allowance);
+        assert!(state.candidates.is_empty());
+    }
+
+    #[test]
+    fn test_decode_allowance() {
+        assert_eq!(decode_allowance(&format!("0x{}", "0".repeat(64))), None);
+        assert_eq!(decode_allowance(&format!("0x{}", "f".repeat(64))), Some(Allowance::Unlimited));
+        assert_eq!(decode_allowance(&format!("0x{}{}", "0".repeat(62), "64")), Some(Allowance::Amount(100)));
+        assert_eq!(decode_allowance("0x"), None);
+    }
+
+    #[test]
+    fn test_is_range_error() {
+        assert!(is_range_error("query returned more than 10000 results"));
+        assert!(is_range_error("eth_getLogs block range is too wide"));
+        assert!(is_range_error("Block range limit exceeded"));
+        assert!(!is_range_error("invalid params: address"));
+    }
+
+    #[test]
+    fn test_revoke_calldata() {
+        let operator = encode_revoke(ApprovalKind::Operator, SPENDER).unwrap();
+        assert_eq!(hex::encode(&operator[..4]), "a22cb465");
+        assert_eq!(operator.len(), 68);
+        assert!(operator[36..].iter().all(|b| *b == 0));
+        let allowance = encode_revoke(ApprovalKind::Allowance, SPENDER).unwrap();
+        assert_eq!(hex::encode(&allowance[..4]), "095ea7b3");
+        let query = encode_is_approved_for_all(OWNER, SPENDER).unwrap();
+        assert_eq!(hex::encode(&query[..4]), "e985e9c5");
+    }
+}
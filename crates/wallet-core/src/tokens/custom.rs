@@ -0,0 +1,243 @@
+// Rusby Wallet — Pure Rust multi-chain crypto wallet
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tokens/custom: User-imported tokens (ERC-20, SPL, CW-20, Jetton) merged with the defaults
+
+use super::{cw20, erc20, jetton, spl, Token, TokenMetadata};
+use crate::chains::custom_evm;
+
+/// Longest symbol kept from on-chain metadata
+const MAX_SYMBOL_LEN: usize = 16;
+/// Longest name kept from on-chain metadata
+const MAX_NAME_LEN: usize = 64;
+/// `parse_token_amount` scales by `10^decimals` in a u128
+const MAX_DECIMALS: u8 = 38;
+
+/// Token standard used on a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Erc20,
+    Spl,
+    Cw20,
+    Jetton,
+}
+
+impl TokenKind {
+    /// Token standard of `chain_id`, or None for chains without token support
+    pub fn for_chain(chain_id: &str) -> Option<Self> {
+        match chain_id {
+            c if crate::wallet::EVM_CHAIN_IDS.contains(&c) => Some(TokenKind::Erc20),
+            c if custom_evm::parse_chain_key(c).is_some() => Some(TokenKind::Erc20),
+            "solana" => Some(TokenKind::Spl),
+            "cosmos" | "osmosis" => Some(TokenKind::Cw20),
+            "ton" => Some(TokenKind::Jetton),
+            _ => None,
+        }
+    }
+
+    pub fn standard(&self) -> &'static str {
+        match self {
+            TokenKind::Erc20 => "ERC-20",
+            TokenKind::Spl => "SPL",
+            TokenKind::Cw20 => "CW-20",
+            TokenKind::Jetton => "Jetton",
+        }
+    }
+}
+
+/// Built-in tokens of `chain_id`
+pub fn default_tokens_for_chain(chain_id: &str) -> Vec<Token> {
+    let all = match TokenKind::for_chain(chain_id) {
+        Some(TokenKind::Erc20) => erc20::default_tokens(),
+        Some(TokenKind::Spl) => spl::default_tokens(),
+        Some(TokenKind::Cw20) => cw20::default_tokens(),
+        Some(TokenKind::Jetton) => jetton::default_tokens(),
+        None => return Vec::new(),
+    };
+    all.into_iter().filter(|t| t.chain_id == chain_id).collect()
+}
+
+/// Compare two token addresses on `chain_id` (hex addresses are case-insensitive)
+pub fn same_address(chain_id: &str, a: &str, b: &str) -> bool {
+    match TokenKind::for_chain(chain_id) {
+        Some(TokenKind::Erc20) => a.eq_ignore_ascii_case(b),
+        _ => a == b,
+    }
+}
+
+/// Default tokens of `chain_id` followed by the user's imported ones
+pub fn tokens_for_chain(chain_id: &str, custom: &[Token]) -> Vec<Token> {
+    let mut tokens = default_tokens_for_chain(chain_id);
+    for token in custom.iter().filter(|t| t.chain_id == chain_id) {
+        if !tokens.iter().any(|t| same_address(chain_id, &t.address, &token.address)) {
+            tokens.push(token.clone());
+        }
+    }
+    tokens
+}
+
+/// Validate a contract/mint address for `chain_id` and return its canonical form
+pub fn normalize_address(chain_id: &str, address: &str) -> Result<String, String> {
+    let address = address.trim();
+    match TokenKind::for_chain(chain_id).ok_or("Tokens are not supported on this chain")? {
+        TokenKind::Erc20 => {
+            let hex_part = address.strip_prefix("0x").ok_or("Address must start with 0x")?;
+            let bytes = hex::decode(hex_part).map_err(|_| "Invalid hex address")?;
+            if bytes.len() != 20 {
+                return Err("Invalid address length".into());
+            }
+            if bytes.iter().all(|&b| b == 0) {
+                return Err("Zero address is not a token".into());
+            }
+            Ok(crate::chains::evm::eip55_checksum(&bytes))
+        }
+        TokenKind::Spl => {
+            crate::tx::solana::parse_pubkey(address)?;
+            Ok(address.to_string())
+        }
+        TokenKind::Cw20 => {
+            let (hrp, data) = bech32::decode(address).map_err(|_| "Invalid bech32 address")?;
+            let expected = if chain_id == "cosmos" { "cosmos" } else { "osmo" };
+            if hrp.as_str() != expected {
+                return Err(format!("Address must start with {}1", expected));
+            }
+            if data.len() != 32 {
+                return Err("Not a CosmWasm contract address".into());
+            }
+            Ok(address.to_string())
+        }
+        TokenKind::Jetton => {
+            crate::chains::ton::decode_ton_friendly_address(address)?;
+            Ok(address.to_string())
+        }
+    }
+}
+
+/// Strip control and bidi/zero-width characters (used to spoof symbols) and cap the length
+fn clean(text: &str, max: usize) -> String {
+    text.chars()
+        .filter(|c| !c.is_control() && !matches!(c, '\u{200b}'..='\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}' | '\u{feff}'))
+        .collect::<String>()
+        .trim()
+        .chars()
+        .take(max)
+        .collect()
+}
+
+/// Build a token from on-chain metadata, validating the address and sanitizing the text
+pub fn new_token(chain_id: &str, address: &str, metadata: TokenMetadata) -> Result<Token, String> {
+    let address = normalize_address(chain_id, address)?;
+    let symbol = clean(&metadata.symbol, MAX_SYMBOL_LEN);
+    if symbol.is_empty() {
+        return Err("Token has no symbol".into());
+    }
+    if metadata.decimals > MAX_DECIMALS {
+        return Err(format!("Unsupported decimals: {}", metadata.decimals));
+    }
+    let name = match clean(&metadata.name, MAX_NAME_LEN) {
+        n if n.is_empty() => symbol.clone(),
+        n => n,
+    };
+    Ok(Token { address, symbol, name, decimals: metadata.decimals, chain_id: chain_id.to_string() })
+}
+
+/// True if `address` is already in the built-in list of `chain_id`
+pub fn is_default(chain_id: &str, address: &str) -> bool {
+    default_tokens_for_chain(chain_id).iter().any(|t| same_address(chain_id, &t.address, address))
+}
+
+/// Insert or replace a token by chain and address. Returns true if it was new.
+pub fn upsert_token(list: &mut Vec<Token>, token: Token) -> bool {
+    match list.iter_mut().find(|t| t.chain_id == token.chain_id && same_address(&token.chain_id, &t.address, &token.address)) {
+        Some(existing) => {
+            *existing = token;
+            false
+        }
+        None => {
+            list.push(token);
+            true
+        }
+    }
+}
+
+/// Remove a token by chain and address. Returns true if it was present.
+pub fn remove_token(list: &mut Vec<Token>, chain_id: &str, address: &str) -> bool {
+    let before = list.len();
+    list.retain(|t| !(t.chain_id == chain_id && same_address(chain_id, &t.address, address)));
+    list.len() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(symbol: &str, decimals: u8) -> TokenMetadata {
+        TokenMetadata { name: format!("{} Token", symbol), symbol: symbol.into(), decimals }
+    }
+
+    #[test]
+    fn test_token_kind() {
+        assert_eq!(TokenKind::for_chain("ethereum"), Some(TokenKind::Erc20));
+        assert_eq!(TokenKind::for_chain("evm:43114"), Some(TokenKind::Erc20));
+        assert_eq!(TokenKind::for_chain("solana"), Some(TokenKind::Spl));
+        assert_eq!(TokenKind::for_chain("osmosis"), Some(TokenKind::Cw20));
+        assert_eq!(TokenKind::for_chain("ton"), Some(TokenKind::Jetton));
+        assert_eq!(TokenKind::for_chain("bitcoin"), None);
+    }
+
+    #[test]
+    fn test_normalize_address() {
+        let lower = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        assert_eq!(normalize_address("ethereum", lower).unwrap(), "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        assert!(normalize_address("ethereum", "0x1234").is_err());
+        assert!(normalize_address("ethereum", "0x0000000000000000000000000000000000000000").is_err());
+        assert!(normalize_address("solana", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").is_ok());
+        assert!(normalize_address("solana", "not-a-key").is_err());
+        let osmo = bech32::Hrp::parse("osmo").unwrap();
+        let contract = bech32::encode::<bech32::Bech32>(osmo, &[7u8; 32]).unwrap();
+        let account = bech32::encode::<bech32::Bech32>(osmo, &[7u8; 20]).unwrap();
+        assert!(normalize_address("osmosis", &contract).is_ok());
+        assert!(normalize_address("osmosis", &account).is_err());
+        assert!(normalize_address("cosmos", &contract).is_err());
+        assert!(normalize_address("ton", "EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs").is_ok());
+        assert!(normalize_address("bitcoin", lower).is_err());
+    }
+
+    #[test]
+    fn test_new_token_sanitizes_metadata() {
+        let address = "0x1111111111111111111111111111111111111111";
+        let token = new_token("base", address, TokenMetadata {
+            name: "  Evil\u{202e}\nToken  ".into(),
+            symbol: "AVERYLONGSYMBOLNAMEHERE".into(),
+            decimals: 18,
+        }).unwrap();
+        assert_eq!(token.symbol.chars().count(), MAX_SYMBOL_LEN);
+        assert_eq!(token.name, "EvilToken");
+        assert_eq!(token.chain_id, "base");
+        assert!(new_token("base", address, meta("", 18)).is_err());
+        assert!(new_token("base", address, meta("X", 39)).is_err());
+    }
+
+    #[test]
+    fn test_merge_and_upsert() {
+        let usdc = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+        let custom_addr = "0x1111111111111111111111111111111111111111";
+        let mut custom = Vec::new();
+        assert!(upsert_token(&mut custom, new_token("ethereum", custom_addr, meta("ABC", 18)).unwrap()));
+        assert!(!upsert_token(&mut custom, new_token("ethereum", custom_addr, meta("ABC2", 18)).unwrap()));
+        assert_eq!(custom.len(), 1);
+        assert_eq!(custom[0].symbol, "ABC2");
+        // A re-import of a default token does not duplicate it
+        custom.push(new_token("ethereum", &usdc.to_lowercase(), meta("USDC", 6)).unwrap());
+
+        let merged = tokens_for_chain("ethereum", &custom);
+        assert_eq!(merged.len(), default_tokens_for_chain("ethereum").len() + 1);
+        assert!(merged.iter().any(|t| t.symbol == "ABC2"));
+        assert!(tokens_for_chain("polygon", &custom).iter().all(|t| t.chain_id == "polygon"));
+        assert!(is_default("ethereum", &usdc.to_lowercase()));
+
+        assert!(remove_token(&mut custom, "ethereum", &custom_addr.to_uppercase().replace("0X", "0x")));
+        assert!(!remove_token(&mut custom, "ethereum", custom_addr));
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::Token;
+use super::{Token, TokenMetadata};
 
 /// Default CW-20 tokens for Cosmos Hub and Osmosis
 pub fn default_tokens() -> Vec<Token> {
@@ -48,6 +48,22 @@ pub fn encode_balance_query(owner_address: &str) -> Result<String, String> {
     Ok(base64_encode(query.as_bytes()))
 }
 
+/// Encode the CW-20 `token_info` query as base64
+/// Query: {"token_info":{}}
+pub fn encode_token_info_query() -> String {
+    base64_encode(br#"{"token_info":{}}"#)
+}
+
+/// Parse the `data` of a `token_info` smart query response
+pub fn parse_token_info(data: &serde_json::Value) -> Result<TokenMetadata, String> {
+    let name = data["name"].as_str().ok_or("Missing name in token_info")?;
+    let symbol = data["symbol"].as_str().ok_or("Missing symbol in token_info")?;
+    let decimals = data["decimals"].as_u64()
+        .and_then(|d| u8::try_from(d).ok())
+        .ok_or("Invalid decimals in token_info")?;
+    Ok(TokenMetadata { name: name.to_string(), symbol: symbol.to_string(), decimals })
+}
+
 /// Encode a CW-20 transfer message as JSON string
 /// Returns: {"transfer":{"recipient":"cosmos1...","amount":"1000000"}}
 pub fn encode_transfer_msg(recipient: &str, amount: &str, decimals: u8) -> Result<String, String> {
@@ -161,6 +177,16 @@ mod tests {
         assert!(msg.contains("\"amount\":\"1500000\""));
     }
 
+    #[test]
+    fn test_token_info() {
+        assert_eq!(encode_token_info_query(), "eyJ0b2tlbl9pbmZvIjp7fX0=");
+        let data = serde_json::json!({"name": "Mars Protocol", "symbol": "MARS", "decimals": 6, "total_supply": "1"});
+        let meta = parse_token_info(&data).unwrap();
+        assert_eq!(meta.symbol, "MARS");
+        assert_eq!(meta.decimals, 6);
+        assert!(parse_token_info(&serde_json::json!({"name": "X", "symbol": "X", "decimals": 300})).is_err());
+    }
+
     #[test]
     fn test_tokens_for_chain() {
         let osmosis = tokens_for_chain("osmosis");
@@ -89,6 +89,60 @@ pub fn encode_revoke(spender: &str) -> Result<Vec<u8>, String> {
     Ok(data)
 }
 
+/// `name()` calldata (selector 0x06fdde03)
+pub fn encode_name() -> Vec<u8> {
+    vec![0x06, 0xfd, 0xde, 0x03]
+}
+
+/// `symbol()` calldata (selector 0x95d89b41)
+pub fn encode_symbol() -> Vec<u8> {
+    vec![0x95, 0xd8, 0x9b, 0x41]
+}
+
+/// `decimals()` calldata (selector 0x313ce567)
+pub fn encode_decimals() -> Vec<u8> {
+    vec![0x31, 0x3c, 0xe5, 0x67]
+}
+
+/// Decode the result of `name()` / `symbol()`.
+/// Accepts an ABI-encoded `string` and the legacy `bytes32` form (e.g. MKR, SAI).
+pub fn decode_string_result(hex_result: &str) -> Result<String, String> {
+    let bytes = hex::decode(hex_result.trim_start_matches("0x"))
+        .map_err(|_| "Invalid hex result")?;
+    if bytes.len() == 32 {
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(32);
+        return String::from_utf8(bytes[..end].to_vec()).map_err(|_| "Invalid UTF-8 in bytes32 string".into());
+    }
+    if bytes.len() < 64 {
+        return Err("Empty or short string result".into());
+    }
+    let word = |at: usize| -> Result<usize, String> {
+        let w = bytes.get(at..at + 32).ok_or("Truncated string result")?;
+        if w[..24].iter().any(|&b| b != 0) {
+            return Err("String offset out of range".into());
+        }
+        Ok(u64::from_be_bytes(w[24..].try_into().unwrap()) as usize)
+    };
+    let offset = word(0)?;
+    let len = word(offset)?;
+    let start = offset + 32;
+    let data = bytes.get(start..start.saturating_add(len)).ok_or("Truncated string result")?;
+    String::from_utf8(data.to_vec()).map_err(|_| "Invalid UTF-8 in string result".into())
+}
+
+/// Decode the result of `decimals()` (uint8 padded to 32 bytes)
+pub fn decode_decimals(hex_result: &str) -> Result<u8, String> {
+    let bytes = hex::decode(hex_result.trim_start_matches("0x"))
+        .map_err(|_| "Invalid hex result")?;
+    if bytes.len() < 32 {
+        return Err("Empty decimals result".into());
+    }
+    if bytes[..31].iter().any(|&b| b != 0) {
+        return Err("decimals() out of range".into());
+    }
+    Ok(bytes[31])
+}
+
 /// Decode a uint256 hex result to a formatted balance string
 pub fn decode_balance(hex_result: &str, decimals: u8) -> String {
     let hex = hex_result.trim_start_matches("0x");
@@ -115,7 +169,7 @@ pub fn parse_token_amount(amount: &str, decimals: u8) -> Result<u128, String> {
 }
 
 /// Format raw token units to decimal string
-fn format_token_amount(raw: u128, decimals: u8) -> String {
+pub fn format_token_amount(raw: u128, decimals: u8) -> String {
     let multiplier = 10u128.pow(decimals as u32);
     let integer = raw / multiplier;
     let fraction = raw % multiplier;
@@ -125,7 +179,7 @@ fn format_token_amount(raw: u128, decimals: u8) -> String {
     format!("{}.{}", integer, &dec_str[..show])
 }
 
-fn parse_address(addr: &str) -> Result<[u8; 20], String> {
+pub(crate) fn parse_address(addr: &str) -> Result<[u8; 20], String> {
     let hex = addr.trim_start_matches("0x");
     if hex.len() != 40 {
         return Err("Invalid address length".into());
@@ -180,6 +234,27 @@ mod tests {
         assert!(data[36..68].iter().all(|&b| b == 0));
     }
 
+    #[test]
+    fn test_decode_string_result() {
+        // ABI string "USDC"
+        let abi = format!("0x{:064x}{:064x}{:0<64}", 32, 4, hex::encode("USDC"));
+        assert_eq!(decode_string_result(&abi).unwrap(), "USDC");
+        // bytes32 "MKR"
+        let b32 = format!("0x{:0<64}", hex::encode("MKR"));
+        assert_eq!(decode_string_result(&b32).unwrap(), "MKR");
+        assert!(decode_string_result("0x").is_err());
+        let bad_offset = format!("0x{:064x}{:064x}", 4096, 4);
+        assert!(decode_string_result(&bad_offset).is_err());
+    }
+
+    #[test]
+    fn test_decode_decimals() {
+        assert_eq!(decode_decimals(&format!("0x{:064x}", 18)).unwrap(), 18);
+        assert_eq!(decode_decimals(&format!("0x{:064x}", 0)).unwrap(), 0);
+        assert!(decode_decimals(&format!("0x{:064x}", 256)).is_err());
+        assert!(decode_decimals("0x").is_err());
+    }
+
     #[test]
     fn test_decode_balance() {
         // 1 USDC = 1000000 (6 decimals) = 0xF4240
@@ -1,8 +1,13 @@
-// Rusby Wallet — Jetton token definitions for TON
+// Rusby Wallet — Jetton token definitions and TEP-74 transfers for TON
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::Token;
+use serde_json::Value;
+
+use super::{Token, TokenMetadata};
+use crate::chains::ton::TonAddress;
+use crate::chains::ton_cell::{Cell, CellBuilder};
+use crate::tx::ton::{text_comment, TonMessage};
 
 /// Jetton transfer operation code
 pub const JETTON_TRANSFER_OP: u32 = 0x0f8a7ea5;
@@ -47,6 +52,117 @@ pub fn default_tokens() -> Vec<Token> {
     ]
 }
 
+/// Decimals assumed by TEP-64 when the metadata omits them
+pub const DEFAULT_JETTON_DECIMALS: u8 = 9;
+
+fn decimals_field(v: &Value) -> Option<u8> {
+    match v {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_u64().and_then(|d| u8::try_from(d).ok()),
+        _ => None,
+    }
+}
+
+/// Parse a toncenter v3 `/jetton/masters?address=` response.
+/// On-chain content wins; off-chain metadata resolved by the indexer fills the gaps.
+pub fn parse_jetton_master(json: &Value) -> Result<TokenMetadata, String> {
+    let master = json["jetton_masters"].as_array()
+        .and_then(|m| m.first())
+        .ok_or("Address is not a jetton master")?;
+    let content = &master["jetton_content"];
+    let info = master["address"].as_str()
+        .and_then(|raw| json["metadata"][raw]["token_info"].as_array())
+        .and_then(|infos| infos.first())
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    let text = |key: &str| content[key].as_str()
+        .or_else(|| info[key].as_str())
+        .map(str::to_string);
+    let symbol = text("symbol").ok_or("Jetton has no symbol in its metadata")?;
+    let name = text("name").unwrap_or_else(|| symbol.clone());
+    let decimals = match decimals_field(&content["decimals"]).or_else(|| decimals_field(&info["extra"]["decimals"])) {
+        Some(d) => d,
+        None if content["decimals"].is_null() && info["extra"]["decimals"].is_null() => DEFAULT_JETTON_DECIMALS,
+        None => return Err("Invalid decimals in jetton metadata".into()),
+    };
+    Ok(TokenMetadata { name, symbol, decimals })
+}
+
+/// TEP-74 `transfer` from our jetton wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JettonTransfer {
+    pub query_id: u64,
+    /// Jetton amount in base units
+    pub amount: u128,
+    /// Owner of the receiving jetton wallet (not the jetton wallet itself)
+    pub destination: TonAddress,
+    /// Receives the unspent TON (`excesses`)
+    pub response_destination: TonAddress,
+    /// TON forwarded with the `transfer_notification` to `destination`
+    pub forward_ton_amount: u64,
+    pub comment: Option<String>,
+}
+
+impl JettonTransfer {
+    /// `transfer#0f8a7ea5 query_id:uint64 amount:Coins destination:MsgAddress
+    /// response_destination:MsgAddress custom_payload:(Maybe ^Cell)
+    /// forward_ton_amount:Coins forward_payload:(Either Cell ^Cell)`.
+    /// The comment becomes the forward payload, in a reference.
+    pub fn body(&self) -> Result<Cell, String> {
+        let mut b = CellBuilder::new();
+        b.store_uint(JETTON_TRANSFER_OP as u64, 32)?
+            .store_uint(self.query_id, 64)?
+            .store_coins(self.amount)?
+            .store_address(Some(&self.destination))?
+            .store_address(Some(&self.response_destination))?
+            .store_maybe_ref(None)?
+            .store_coins(self.forward_ton_amount as u128)?;
+        match &self.comment {
+            Some(comment) => b.store_bit(true)?.store_ref(text_comment(comment)?)?,
+            None => b.store_bit(false)?,
+        };
+        Ok(b.build())
+    }
+
+    /// Internal message to the sender's `jetton_wallet` carrying the transfer and
+    /// `JETTON_GAS_AMOUNT` of TON for fees; the excess comes back to `response_destination`
+    pub fn message(&self, jetton_wallet: TonAddress) -> Result<TonMessage, String> {
+        if self.amount == 0 {
+            return Err("Amount must be greater than zero".into());
+        }
+        if self.forward_ton_amount >= JETTON_GAS_AMOUNT {
+            return Err("Forward amount exceeds the attached TON".into());
+        }
+        Ok(TonMessage {
+            destination: jetton_wallet,
+            amount_nanoton: JETTON_GAS_AMOUNT,
+            bounce: true,
+            state_init: None,
+            body: Some(self.body()?),
+        })
+    }
+}
+
+/// `get_wallet_address` argument: the owner address as a slice, base64 BOC
+pub fn wallet_address_stack_arg(owner: &TonAddress) -> Result<String, String> {
+    let mut b = CellBuilder::new();
+    b.store_address(Some(owner))?;
+    Ok(crate::backup::base64_encode(&b.build().to_boc()))
+}
+
+/// Jetton wallet address from a toncenter `runGetMethod get_wallet_address` response,
+/// whose stack holds `["cell", {"bytes": <base64 BOC>}]`
+pub fn parse_wallet_address_result(json: &Value) -> Result<TonAddress, String> {
+    if json["result"]["exit_code"].as_i64().is_some_and(|code| code != 0) {
+        return Err(format!("get_wallet_address failed with exit code {}", json["result"]["exit_code"]));
+    }
+    let boc = json["result"]["stack"][0][1]["bytes"].as_str()
+        .ok_or("Missing cell in get_wallet_address response")?;
+    let cell = Cell::from_boc(&crate::backup::base64_decode(boc)?)?;
+    cell.parser().load_address()?.ok_or_else(|| "Jetton master returned no wallet address".to_string())
+}
+
 /// Find a jetton token by its master contract address
 pub fn find_by_address(address: &str) -> Option<Token> {
     default_tokens().into_iter().find(|t| t.address == address)
@@ -75,6 +191,76 @@ mod tests {
         assert!(unknown.is_none());
     }
 
+    #[test]
+    fn test_parse_jetton_master() {
+        let onchain = serde_json::json!({
+            "jetton_masters": [{ "address": "0:AB", "jetton_content": { "name": "Tether USD", "symbol": "USD₮", "decimals": "6" } }],
+        });
+        let meta = parse_jetton_master(&onchain).unwrap();
+        assert_eq!(meta.symbol, "USD₮");
+        assert_eq!(meta.decimals, 6);
+
+        // Off-chain content: only the uri is on-chain, the indexer resolved the rest
+        let offchain = serde_json::json!({
+            "jetton_masters": [{ "address": "0:CD", "jetton_content": { "uri": "https://example.com/meta.json" } }],
+            "metadata": { "0:CD": { "token_info": [{ "name": "Notcoin", "symbol": "NOT" }] } },
+        });
+        let meta = parse_jetton_master(&offchain).unwrap();
+        assert_eq!(meta.name, "Notcoin");
+        assert_eq!(meta.decimals, DEFAULT_JETTON_DECIMALS);
+
+        assert!(parse_jetton_master(&serde_json::json!({ "jetton_masters": [] })).is_err());
+    }
+
+    #[test]
+    fn test_jetton_transfer_body() {
+        let transfer = JettonTransfer {
+            query_id: 42,
+            amount: 1_500_000,
+            destination: TonAddress { workchain: 0, hash: [0x11; 32], bounceable: false, testnet: false },
+            response_destination: TonAddress { workchain: 0, hash: [0x22; 32], bounceable: true, testnet: false },
+            forward_ton_amount: FORWARD_TON_AMOUNT,
+            comment: Some("Invoice 7".into()),
+        };
+        let body = transfer.body().unwrap();
+        // Reference serialization assembled bit by bit from the TL-B schema
+        assert_eq!(body.bit_len(), 696);
+        assert_eq!(
+            hex::encode(body.data()),
+            "0f8a7ea5000000000000002a316e36080022222222222222222222222222222222222222222222222222222222222222230008888888888888888888888888888888888888888888888888888888888888888805f5e101",
+        );
+        assert_eq!(hex::encode(body.refs()[0].hash()), "b1c222c8c7b80ff22c5b2c659c1f443b481086d20f63a268ed41ee13caa469a3");
+        assert_eq!(hex::encode(body.hash()), "56fbc032c8216677aa1177d19af275ded2a27a959a2509dd70f467e7f7b075ae");
+
+        let jetton_wallet = TonAddress { workchain: 0, hash: [0x33; 32], bounceable: true, testnet: false };
+        let message = transfer.message(jetton_wallet).unwrap();
+        assert_eq!(message.amount_nanoton, JETTON_GAS_AMOUNT);
+        assert!(message.bounce);
+        assert_eq!(message.body, Some(body));
+
+        let plain = JettonTransfer { comment: None, ..transfer.clone() }.body().unwrap();
+        assert!(plain.refs().is_empty());
+        assert_eq!(plain.bit_len(), 696);
+        assert!(JettonTransfer { amount: 0, ..transfer }.message(jetton_wallet).is_err());
+    }
+
+    #[test]
+    fn test_wallet_address_get_method() {
+        let owner = TonAddress { workchain: 0, hash: [0x44; 32], bounceable: true, testnet: false };
+        let arg = wallet_address_stack_arg(&owner).unwrap();
+        let slice = Cell::from_boc(&crate::backup::base64_decode(&arg).unwrap()).unwrap();
+        assert_eq!(slice.bit_len(), 267);
+
+        // The master answers with the same encoding: a cell holding one address
+        let response = serde_json::json!({
+            "ok": true,
+            "result": { "exit_code": 0, "stack": [["cell", { "bytes": arg, "object": {} }]] },
+        });
+        assert_eq!(parse_wallet_address_result(&response).unwrap(), owner);
+        let failed = serde_json::json!({ "result": { "exit_code": 11, "stack": [] } });
+        assert!(parse_wallet_address_result(&failed).is_err());
+    }
+
     #[test]
     fn test_constants() {
         assert_eq!(JETTON_TRANSFER_OP, 0x0f8a7ea5);
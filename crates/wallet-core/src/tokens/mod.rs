@@ -6,8 +6,12 @@
 
 pub mod erc20;
 pub mod spl;
+pub mod token_2022;
 pub mod cw20;
 pub mod jetton;
+pub mod custom;
+pub mod approvals;
+pub mod multicall;
 
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +25,14 @@ pub struct Token {
     pub chain_id: String,
 }
 
+/// Name, symbol and decimals as read from the token contract or mint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
 /// Token balance (token + formatted balance)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenBalance {
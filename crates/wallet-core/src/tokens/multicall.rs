@@ -0,0 +1,202 @@
+// Rusby Wallet — Pure Rust multi-chain crypto wallet
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tokens/multicall: Multicall3 aggregate3 encoding for batched eth_call reads
+
+use super::erc20::parse_address;
+
+/// Multicall3, deployed at the same address on almost every EVM chain
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+/// Sub-calls per aggregate3 request; keeps calldata and gas within public RPC limits
+pub const MAX_BATCH: usize = 100;
+
+/// `aggregate3((address,bool,bytes)[])` selector
+const AGGREGATE3_SELECTOR: [u8; 4] = [0x82, 0xad, 0x56, 0xcb];
+
+/// One read in a batch
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call3 {
+    pub target: [u8; 20],
+    pub call_data: Vec<u8>,
+}
+
+impl Call3 {
+    pub fn new(target: &str, call_data: Vec<u8>) -> Result<Self, String> {
+        Ok(Self { target: parse_address(target)?, call_data })
+    }
+}
+
+/// Outcome of one sub-call
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallResult {
+    pub success: bool,
+    pub return_data: Vec<u8>,
+}
+
+impl CallResult {
+    /// Return data as an eth_call-style hex string, so existing decoders apply unchanged
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(&self.return_data))
+    }
+}
+
+fn word_usize(n: usize) -> [u8; 32] {
+    let mut w = [0u8; 32];
+    w[24..].copy_from_slice(&(n as u64).to_be_bytes());
+    w
+}
+
+fn padded_len(n: usize) -> usize {
+    n.div_ceil(32) * 32
+}
+
+/// Build `aggregate3` calldata. Every call sets `allowFailure`, so one reverting
+/// token does not fail the whole batch.
+pub fn encode_aggregate3(calls: &[Call3]) -> Vec<u8> {
+    // Each tuple: target, allowFailure, bytes offset, bytes length, padded data
+    let tuple_sizes: Vec<usize> = calls.iter().map(|c| 4 * 32 + padded_len(c.call_data.len())).collect();
+
+    let mut data = AGGREGATE3_SELECTOR.to_vec();
+    data.extend_from_slice(&word_usize(0x20));
+    data.extend_from_slice(&word_usize(calls.len()));
+
+    let mut offset = 32 * calls.len();
+    for size in &tuple_sizes {
+        data.extend_from_slice(&word_usize(offset));
+        offset += size;
+    }
+
+    for call in calls {
+        data.extend_from_slice(&[0u8; 12]);
+        data.extend_from_slice(&call.target);
+        data.extend_from_slice(&word_usize(1));
+        data.extend_from_slice(&word_usize(0x60));
+        data.extend_from_slice(&word_usize(call.call_data.len()));
+        data.extend_from_slice(&call.call_data);
+        data.resize(data.len() + padded_len(call.call_data.len()) - call.call_data.len(), 0);
+    }
+    data
+}
+
+/// Decode the `(bool success, bytes returnData)[]` returned by `aggregate3`
+pub fn decode_aggregate3(hex_result: &str, expected: usize) -> Result<Vec<CallResult>, String> {
+    let bytes = hex::decode(hex_result.trim_start_matches("0x"))
+        .map_err(|_| "Invalid hex result")?;
+    if bytes.is_empty() {
+        return Err("Multicall3 is not deployed on this chain".into());
+    }
+
+    let word = |at: usize| -> Result<usize, String> {
+        let w = bytes.get(at..at.checked_add(32).ok_or("Offset overflow")?)
+            .ok_or("Truncated multicall result")?;
+        if w[..24].iter().any(|&b| b != 0) {
+            return Err("Multicall offset out of range".into());
+        }
+        Ok(u64::from_be_bytes(w[24..].try_into().unwrap()) as usize)
+    };
+
+    let array = word(0)?;
+    let len = word(array)?;
+    if len != expected {
+        return Err(format!("Multicall returned {} results, expected {}", len, expected));
+    }
+    let base = array + 32;
+
+    (0..len).map(|i| {
+        let tuple = base.checked_add(word(base + 32 * i)?).ok_or("Offset overflow")?;
+        let success = word(tuple)? == 1;
+        let data_at = tuple.checked_add(word(tuple + 32)?).ok_or("Offset overflow")?;
+        let data_len = word(data_at)?;
+        let start = data_at + 32;
+        let return_data = bytes.get(start..start.checked_add(data_len).ok_or("Offset overflow")?)
+            .ok_or("Truncated multicall return data")?
+            .to_vec();
+        Ok(CallResult { success, return_data })
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::erc20;
+
+    #[test]
+    fn test_selector() {
+        let hash = crate::signing::keccak256(b"aggregate3((address,bool,bytes)[])");
+        assert_eq!(hash[..4], AGGREGATE3_SELECTOR);
+    }
+
+    #[test]
+    fn test_encode_layout() {
+        let token = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+        let calls = vec![
+            Call3::new(token, erc20::encode_decimals()).unwrap(),
+            Call3::new(token, erc20::encode_balance_of("0x0000000000000000000000000000000000000001").unwrap()).unwrap(),
+        ];
+        let data = encode_aggregate3(&calls);
+        let body = &data[4..];
+
+        // array offset, length, then two tuple offsets relative to the element area
+        assert_eq!(body[31], 0x20);
+        assert_eq!(body[63], 2);
+        assert_eq!(body[95], 0x40);
+        // first tuple: 4 head words + 4 bytes of calldata padded to 32
+        assert_eq!(body[127], 0x40 + 0xa0);
+        // first tuple content starts after the two offsets
+        let t0 = 96 + 32;
+        assert_eq!(&body[t0 + 12..t0 + 32], &calls[0].target);
+        assert_eq!(body[t0 + 63], 1);
+        assert_eq!(body[t0 + 95], 0x60);
+        assert_eq!(body[t0 + 127], 4);
+        assert_eq!(&body[t0 + 128..t0 + 132], &erc20::encode_decimals()[..]);
+        // second calldata is 36 bytes, padded to 64
+        assert_eq!(body.len(), 128 + 0xa0 + 4 * 32 + 64);
+    }
+
+    fn encode_results(results: &[(bool, Vec<u8>)]) -> String {
+        let mut tuples = Vec::new();
+        for (ok, data) in results {
+            let mut t = word_usize(*ok as usize).to_vec();
+            t.extend_from_slice(&word_usize(0x40));
+            t.extend_from_slice(&word_usize(data.len()));
+            t.extend_from_slice(data);
+            t.resize(t.len() + padded_len(data.len()) - data.len(), 0);
+            tuples.push(t);
+        }
+        let mut out = word_usize(0x20).to_vec();
+        out.extend_from_slice(&word_usize(results.len()));
+        let mut offset = 32 * results.len();
+        for t in &tuples {
+            out.extend_from_slice(&word_usize(offset));
+            offset += t.len();
+        }
+        for t in tuples {
+            out.extend_from_slice(&t);
+        }
+        format!("0x{}", hex::encode(out))
+    }
+
+    #[test]
+    fn test_decode_results() {
+        let decimals = word_usize(6).to_vec();
+        let hex_result = encode_results(&[(true, decimals.clone()), (false, vec![])]);
+        let results = decode_aggregate3(&hex_result, 2).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].success);
+        assert_eq!(erc20::decode_decimals(&results[0].to_hex()).unwrap(), 6);
+        assert!(!results[1].success);
+        assert_eq!(results[1].to_hex(), "0x");
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        // No contract at the address: eth_call returns empty data
+        assert!(decode_aggregate3("0x", 1).is_err());
+        let hex_result = encode_results(&[(true, vec![1, 2, 3])]);
+        assert!(decode_aggregate3(&hex_result, 2).is_err());
+        let truncated = &hex_result[..hex_result.len() - 64];
+        assert!(decode_aggregate3(truncated, 1).is_err());
+    }
+}
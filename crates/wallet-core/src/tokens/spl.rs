@@ -2,9 +2,12 @@
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// tokens/spl: SPL Token definitions and encoding for Solana
+// tokens/spl: SPL Token definitions, associated token accounts and transfer instructions
 
 use super::Token;
+use super::token_2022::{self, MintExtensions};
+use crate::tx::solana_dapp::MEMO_PROGRAM_ID;
+use crate::tx::solana::{find_program_address, is_on_curve, parse_pubkey, AccountMeta, Instruction};
 
 /// Default SPL tokens
 pub fn default_tokens() -> Vec<Token> {
@@ -13,11 +16,370 @@ pub fn default_tokens() -> Vec<Token> {
         Token { address: "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB".into(), symbol: "USDT".into(), name: "Tether USD".into(), decimals: 6, chain_id: "solana".into() },
         Token { address: "So11111111111111111111111111111111111111112".into(), symbol: "WSOL".into(), name: "Wrapped SOL".into(), decimals: 9, chain_id: "solana".into() },
         Token { address: "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".into(), symbol: "JUP".into(), name: "Jupiter".into(), decimals: 6, chain_id: "solana".into() },
+        Token { address: "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo".into(), symbol: "PYUSD".into(), name: "PayPal USD".into(), decimals: 6, chain_id: "solana".into() },
     ]
 }
 
 /// SPL Token Program ID
 pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 
+/// Token-2022 (Token Extensions) Program ID
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
 /// Associated Token Account Program ID
 pub const ATA_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+/// System Program ID
+pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+
+/// Size of the base `Mint` layout shared by both token programs
+const MINT_LEN: usize = 82;
+
+/// Rent-exempt minimum of a 165-byte token account, paid by the sender when it creates
+/// the recipient's associated token account
+pub const TOKEN_ACCOUNT_RENT_LAMPORTS: u64 = 2_039_280;
+
+/// SPL Token `TransferChecked` instruction index
+const IX_TRANSFER_CHECKED: u8 = 12;
+/// Associated Token Account `CreateIdempotent` instruction index
+const IX_CREATE_IDEMPOTENT: u8 = 1;
+
+pub(crate) fn program_key(id: &str) -> [u8; 32] {
+    parse_pubkey(id).expect("valid program id constant")
+}
+
+/// Associated token account of `owner` for `mint` under `token_program`
+pub fn associated_token_address(owner: &[u8; 32], mint: &[u8; 32], token_program: &[u8; 32]) -> Result<[u8; 32], String> {
+    let (address, _) = find_program_address(&[owner, token_program, mint], &program_key(ATA_PROGRAM_ID))?;
+    Ok(address)
+}
+
+/// Create `owner`'s associated token account if missing; a no-op when it already exists
+pub fn create_associated_token_account_idempotent(
+    payer: &[u8; 32],
+    owner: &[u8; 32],
+    mint: &[u8; 32],
+    token_program: &[u8; 32],
+) -> Result<Instruction, String> {
+    let ata = associated_token_address(owner, mint, token_program)?;
+    Ok(Instruction {
+        program_id: program_key(ATA_PROGRAM_ID),
+        accounts: vec![
+            AccountMeta::writable(*payer, true),
+            AccountMeta::writable(ata, false),
+            AccountMeta::readonly(*owner, false),
+            AccountMeta::readonly(*mint, false),
+            AccountMeta::readonly(program_key(SYSTEM_PROGRAM_ID), false),
+            AccountMeta::readonly(*token_program, false),
+        ],
+        data: vec![IX_CREATE_IDEMPOTENT],
+    })
+}
+
+/// `TransferChecked`: the program verifies `mint` and `decimals` against the accounts
+pub fn transfer_checked(
+    source: &[u8; 32],
+    mint: &[u8; 32],
+    destination: &[u8; 32],
+    authority: &[u8; 32],
+    amount: u64,
+    decimals: u8,
+    token_program: &[u8; 32],
+) -> Instruction {
+    let mut data = vec![IX_TRANSFER_CHECKED];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::writable(*source, false),
+            AccountMeta::readonly(*mint, false),
+            AccountMeta::writable(*destination, false),
+            AccountMeta::readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+/// Instructions sending `amount` base units of `mint` from `owner` to `recipient`'s
+/// associated token account, created first if it does not exist. An off-curve
+/// recipient (a PDA, e.g. a multisig vault) is only accepted when `destination_exists`:
+/// a program that already holds that ATA can move the tokens, while a fresh ATA for a
+/// mistyped or pasted address would leave them stuck.
+pub fn transfer_instructions(
+    owner: &[u8; 32],
+    mint: &[u8; 32],
+    recipient: &[u8; 32],
+    amount: u64,
+    decimals: u8,
+    token_program: &[u8; 32],
+    destination_exists: bool,
+) -> Result<Vec<Instruction>, String> {
+    if amount == 0 {
+        return Err("Amount must be greater than zero".into());
+    }
+    if !destination_exists && !is_on_curve(recipient) {
+        return Err("Recipient is a program-derived address without a token account for this mint".into());
+    }
+    let source = associated_token_address(owner, mint, token_program)?;
+    let destination = associated_token_address(recipient, mint, token_program)?;
+    Ok(vec![
+        create_associated_token_account_idempotent(owner, recipient, mint, token_program)?,
+        transfer_checked(&source, mint, &destination, owner, amount, decimals, token_program),
+    ])
+}
+
+/// Memo program instruction signed by `signer`. Placed right before a transfer, it
+/// satisfies Token-2022 accounts that require memos on incoming transfers.
+pub fn memo_instruction(signer: &[u8; 32], memo: &str) -> Instruction {
+    Instruction {
+        program_id: program_key(MEMO_PROGRAM_ID),
+        accounts: vec![AccountMeta::readonly(*signer, true)],
+        data: memo.as_bytes().to_vec(),
+    }
+}
+
+/// `transfer_instructions` honouring the mint's Token-2022 extensions: transfer-fee
+/// mints use `TransferCheckedWithFee` with the fee for `epoch`, and `memo` goes
+/// immediately before the transfer. Mints that can't be sent are rejected.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_transfer_instructions(
+    owner: &[u8; 32],
+    mint: &[u8; 32],
+    recipient: &[u8; 32],
+    amount: u64,
+    mint_info: &MintInfo,
+    epoch: u64,
+    memo: Option<&str>,
+    destination_exists: bool,
+) -> Result<Vec<Instruction>, String> {
+    mint_info.extensions.check_transferable()?;
+    let program = &mint_info.token_program;
+    let mut instructions = transfer_instructions(owner, mint, recipient, amount, mint_info.decimals, program, destination_exists)?;
+    if let Some(config) = &mint_info.extensions.transfer_fee {
+        let source = associated_token_address(owner, mint, program)?;
+        let destination = associated_token_address(recipient, mint, program)?;
+        let fee = config.calculate_fee(epoch, amount);
+        instructions[1] = token_2022::transfer_checked_with_fee(&source, mint, &destination, owner, amount, mint_info.decimals, fee, program);
+    }
+    if let Some(memo) = memo {
+        instructions.insert(1, memo_instruction(owner, memo));
+    }
+    Ok(instructions)
+}
+
+/// Parse a decimal token amount to base units (SPL amounts are u64)
+pub fn parse_amount(amount: &str, decimals: u8) -> Result<u64, String> {
+    let raw = super::erc20::parse_token_amount(amount.trim(), decimals)?;
+    u64::try_from(raw).map_err(|_| "Amount exceeds the SPL token limit".into())
+}
+
+/// Mint details needed to build a transfer
+#[derive(Debug, Clone, PartialEq)]
+pub struct MintInfo {
+    /// Program owning the mint (SPL Token or Token-2022)
+    pub token_program: [u8; 32],
+    pub decimals: u8,
+    /// Token-2022 extensions; empty for SPL Token mints
+    pub extensions: MintExtensions,
+}
+
+/// Decimals of a mint account: `COption<Pubkey>` authority (36) + supply (8), then
+/// `decimals` and `is_initialized`. Token-2022 mints append extensions after the base layout.
+pub fn parse_mint_decimals(data: &[u8]) -> Result<u8, String> {
+    if data.len() < MINT_LEN {
+        return Err("Not a token mint account".into());
+    }
+    if data[45] != 1 {
+        return Err("Mint is not initialized".into());
+    }
+    Ok(data[44])
+}
+
+/// Parse a base64 `getAccountInfo` value, checking the mint is owned by a token program
+pub fn parse_mint_account_info(value: &serde_json::Value) -> Result<u8, String> {
+    parse_mint_info(value).map(|m| m.decimals)
+}
+
+/// Like `parse_mint_account_info`, also returning the owning token program
+pub fn parse_mint_info(value: &serde_json::Value) -> Result<MintInfo, String> {
+    let owner = value.get("owner").and_then(|o| o.as_str()).ok_or("Mint account not found")?;
+    if owner != TOKEN_PROGRAM_ID && owner != TOKEN_2022_PROGRAM_ID {
+        return Err("Address is not an SPL token mint".into());
+    }
+    let encoded = value.get("data")
+        .and_then(|d| d.get(0))
+        .and_then(|d| d.as_str())
+        .ok_or("Mint account has no data")?;
+    let data = crate::backup::base64_decode(encoded)?;
+    let extensions = if owner == TOKEN_2022_PROGRAM_ID {
+        token_2022::parse_mint_extensions(&data)?
+    } else {
+        MintExtensions::default()
+    };
+    Ok(MintInfo { token_program: program_key(owner), decimals: parse_mint_decimals(&data)?, extensions })
+}
+
+/// Whether a `getAccountInfo` value belongs to a token program, i.e. the address is
+/// a token account or mint rather than a wallet
+pub fn is_token_program_account(value: &serde_json::Value) -> bool {
+    matches!(value.get("owner").and_then(|o| o.as_str()), Some(TOKEN_PROGRAM_ID) | Some(TOKEN_2022_PROGRAM_ID))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compressed ed25519 base point: a valid wallet-style key
+    const ON_CURVE: [u8; 32] = {
+        let mut key = [0x66u8; 32];
+        key[0] = 0x58;
+        key
+    };
+
+    #[test]
+    fn test_program_ids_parse() {
+        assert_eq!(program_key(SYSTEM_PROGRAM_ID), [0u8; 32]);
+        program_key(TOKEN_PROGRAM_ID);
+        program_key(TOKEN_2022_PROGRAM_ID);
+        program_key(ATA_PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_associated_token_address() {
+        let token_program = program_key(TOKEN_PROGRAM_ID);
+        let mint = program_key("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let a = associated_token_address(&[1u8; 32], &mint, &token_program).unwrap();
+        let b = associated_token_address(&[2u8; 32], &mint, &token_program).unwrap();
+        assert_ne!(a, b);
+        assert!(!is_on_curve(&a));
+        assert_eq!(a, associated_token_address(&[1u8; 32], &mint, &token_program).unwrap());
+    }
+
+    #[test]
+    fn test_associated_token_address_mainnet() {
+        // Accounts of a mainnet pump.fun `buy` (carbon-pumpfun-decoder test fixture): the
+        // buyer's and the bonding curve's token accounts for the traded mint
+        let token_program = program_key(TOKEN_PROGRAM_ID);
+        let mint = program_key("9p1PMtto471A7GvnRJVmDcuqUz3xDd1Lhu8vzrmpump");
+        let user = program_key("5ztadiszGPmBeGVcvmtPyqiHRA8SpU8mqNzPV1WeV88F");
+        let bonding_curve = program_key("HWxwYxr4AV5ytUyT8pvjCEiUrXhwpbx365VpvQ6Bd6MZ");
+        assert_eq!(
+            associated_token_address(&user, &mint, &token_program).unwrap(),
+            program_key("4FLYmjhLuUb5ofNBo1PA9enF7HrPUSYUA1t55tUSFYa5")
+        );
+        assert_eq!(
+            associated_token_address(&bonding_curve, &mint, &token_program).unwrap(),
+            program_key("AUfg9aTAix7YarkHXSBMUyQPCTq55Gg1Z2NTe6utwwzG")
+        );
+    }
+
+    #[test]
+    fn test_transfer_checked_layout() {
+        let token_program = program_key(TOKEN_PROGRAM_ID);
+        let ix = transfer_checked(&[1u8; 32], &[2u8; 32], &[3u8; 32], &[4u8; 32], 1, 0, &token_program);
+        assert_eq!(ix.data, vec![12, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ix.accounts.len(), 4);
+        assert!(ix.accounts[3].is_signer && !ix.accounts[3].is_writable);
+
+        let create = create_associated_token_account_idempotent(&[4u8; 32], &[5u8; 32], &[2u8; 32], &token_program).unwrap();
+        assert_eq!(create.data, vec![1]);
+        assert_eq!(create.accounts[1].pubkey, associated_token_address(&[5u8; 32], &[2u8; 32], &token_program).unwrap());
+    }
+
+    #[test]
+    fn test_parse_mint_account() {
+        let mut mint = vec![0u8; MINT_LEN];
+        mint[44] = 6;
+        mint[45] = 1;
+        assert_eq!(parse_mint_decimals(&mint).unwrap(), 6);
+
+        let value = serde_json::json!({
+            "owner": TOKEN_PROGRAM_ID,
+            "data": [crate::backup::base64_encode(&mint), "base64"],
+        });
+        assert_eq!(parse_mint_account_info(&value).unwrap(), 6);
+        let wrong_owner = serde_json::json!({ "owner": SYSTEM_PROGRAM_ID, "data": value["data"] });
+        assert!(parse_mint_account_info(&wrong_owner).is_err());
+
+        mint[45] = 0;
+        assert!(parse_mint_decimals(&mint).is_err());
+        assert!(parse_mint_decimals(&[0u8; 40]).is_err());
+    }
+
+    #[test]
+    fn test_transfer_instructions() {
+        let token_program = program_key(TOKEN_PROGRAM_ID);
+        let owner = [9u8; 32];
+        let mint = program_key("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        assert!(is_on_curve(&ON_CURVE));
+
+        let ixs = transfer_instructions(&owner, &mint, &ON_CURVE, 1_500_000, 6, &token_program, false).unwrap();
+        assert_eq!(ixs.len(), 2);
+        assert_eq!(ixs[0].program_id, program_key(ATA_PROGRAM_ID));
+        let destination = associated_token_address(&ON_CURVE, &mint, &token_program).unwrap();
+        assert_eq!(ixs[0].accounts[1].pubkey, destination);
+        assert_eq!(ixs[1].accounts[0].pubkey, associated_token_address(&owner, &mint, &token_program).unwrap());
+        assert_eq!(ixs[1].accounts[2].pubkey, destination);
+        assert_eq!(ixs[1].data[1..9], 1_500_000u64.to_le_bytes());
+        assert_eq!(ixs[1].data[9], 6);
+
+        // An ATA is itself off-curve: pasting it as recipient must fail
+        assert!(transfer_instructions(&owner, &mint, &destination, 1, 6, &token_program, false).is_err());
+        assert!(transfer_instructions(&owner, &mint, &ON_CURVE, 0, 6, &token_program, false).is_err());
+
+        // A PDA owner (vault, multisig) is fine once its token account exists
+        let pda = destination;
+        let ixs = transfer_instructions(&owner, &mint, &pda, 1, 6, &token_program, true).unwrap();
+        assert_eq!(ixs[1].accounts[2].pubkey, associated_token_address(&pda, &mint, &token_program).unwrap());
+    }
+
+    #[test]
+    fn test_mint_transfer_instructions() {
+        let token_program = program_key(TOKEN_2022_PROGRAM_ID);
+        let owner = [9u8; 32];
+        let mint = [8u8; 32];
+        let fee = token_2022::TransferFee { epoch: 0, maximum_fee: 1_000, basis_points: 100 };
+        let mut info = MintInfo { token_program, decimals: 6, extensions: MintExtensions::default() };
+
+        let plain = mint_transfer_instructions(&owner, &mint, &ON_CURVE, 5_000, &info, 0, None, false).unwrap();
+        assert_eq!(plain, transfer_instructions(&owner, &mint, &ON_CURVE, 5_000, 6, &token_program, false).unwrap());
+
+        info.extensions.transfer_fee = Some(token_2022::TransferFeeConfig { older: fee, newer: fee });
+        let ixs = mint_transfer_instructions(&owner, &mint, &ON_CURVE, 5_000, &info, 0, Some("invoice 7"), false).unwrap();
+        assert_eq!(ixs.len(), 3);
+        assert_eq!(ixs[1], memo_instruction(&owner, "invoice 7"));
+        assert_eq!(ixs[1].program_id, program_key(MEMO_PROGRAM_ID));
+        assert_eq!(ixs[2].program_id, token_program);
+        assert_eq!(ixs[2].data[..2], [26, 1]);
+        // 1% of 5000 withheld from the recipient
+        assert_eq!(ixs[2].data[11..19], 50u64.to_le_bytes());
+        assert_eq!(ixs[2].accounts[2].pubkey, associated_token_address(&ON_CURVE, &mint, &token_program).unwrap());
+
+        info.extensions.non_transferable = true;
+        assert!(mint_transfer_instructions(&owner, &mint, &ON_CURVE, 5_000, &info, 0, None, false).is_err());
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("1.5", 6).unwrap(), 1_500_000);
+        assert_eq!(parse_amount(" 2 ", 9).unwrap(), 2_000_000_000);
+        assert!(parse_amount("18446744073709551616", 0).is_err());
+        assert!(parse_amount("abc", 6).is_err());
+    }
+
+    #[test]
+    fn test_parse_mint_info_program() {
+        let mut mint = vec![0u8; MINT_LEN];
+        mint[44] = 9;
+        mint[45] = 1;
+        let data = serde_json::json!([crate::backup::base64_encode(&mint), "base64"]);
+        let info = parse_mint_info(&serde_json::json!({ "owner": TOKEN_2022_PROGRAM_ID, "data": data })).unwrap();
+        assert_eq!(info.token_program, program_key(TOKEN_2022_PROGRAM_ID));
+        assert_eq!(info.decimals, 9);
+
+        assert!(is_token_program_account(&serde_json::json!({ "owner": TOKEN_PROGRAM_ID })));
+        assert!(!is_token_program_account(&serde_json::json!({ "owner": SYSTEM_PROGRAM_ID })));
+        assert!(!is_token_program_account(&serde_json::Value::Null));
+    }
+}
@@ -0,0 +1,430 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tokens/token_2022: Token-2022 extensions (TLV after the base layout): transfer
+// fees, metadata pointer and embedded token metadata, memo-required accounts
+
+use crate::tx::solana::{AccountMeta, Instruction};
+
+/// Base token account length; mints are padded to it when they carry extensions
+const BASE_ACCOUNT_LEN: usize = 165;
+/// Multisig length: an account of exactly this size gets two bytes of padding
+const MULTISIG_LEN: usize = 355;
+
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+pub const EXT_TRANSFER_FEE_CONFIG: u16 = 1;
+pub const EXT_IMMUTABLE_OWNER: u16 = 7;
+pub const EXT_MEMO_TRANSFER: u16 = 8;
+pub const EXT_NON_TRANSFERABLE: u16 = 9;
+pub const EXT_TRANSFER_HOOK: u16 = 14;
+pub const EXT_METADATA_POINTER: u16 = 18;
+pub const EXT_TOKEN_METADATA: u16 = 19;
+pub const EXT_PAUSABLE: u16 = 26;
+
+/// `TransferFeeExtension` instruction index and its `TransferCheckedWithFee` sub-instruction
+const IX_TRANSFER_FEE_EXTENSION: u8 = 26;
+const IX_TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+
+const MAX_FEE_BASIS_POINTS: u128 = 10_000;
+
+/// Rent-exempt cost per byte over two years, and the account storage overhead
+const LAMPORTS_PER_BYTE_YEAR: u64 = 3_480;
+const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+/// Fee charged on transfers from `epoch` onwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub basis_points: u16,
+}
+
+impl TransferFee {
+    /// Fee withheld from `amount`: basis points rounded up, capped at `maximum_fee`
+    pub fn calculate(&self, amount: u64) -> u64 {
+        if self.basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let raw = (amount as u128 * self.basis_points as u128).div_ceil(MAX_FEE_BASIS_POINTS);
+        raw.min(self.maximum_fee as u128) as u64
+    }
+}
+
+/// `TransferFeeConfig`: the fee in force and the one scheduled to replace it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFeeConfig {
+    pub older: TransferFee,
+    pub newer: TransferFee,
+}
+
+impl TransferFeeConfig {
+    pub fn fee_for_epoch(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer.epoch { &self.newer } else { &self.older }
+    }
+
+    pub fn calculate_fee(&self, epoch: u64, amount: u64) -> u64 {
+        self.fee_for_epoch(epoch).calculate(amount)
+    }
+}
+
+/// `TokenMetadata` stored in the mint itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataExtension {
+    pub update_authority: Option<[u8; 32]>,
+    pub mint: [u8; 32],
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+/// Extensions of a Token-2022 mint that matter to a wallet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MintExtensions {
+    /// Every extension type present, in TLV order
+    pub types: Vec<u16>,
+    pub transfer_fee: Option<TransferFeeConfig>,
+    /// Account holding the token metadata (often the mint itself)
+    pub metadata_pointer: Option<[u8; 32]>,
+    pub metadata: Option<MetadataExtension>,
+    pub non_transferable: bool,
+    /// Program invoked on every transfer, which needs extra accounts we don't resolve
+    pub transfer_hook: Option<[u8; 32]>,
+    pub paused: bool,
+}
+
+impl MintExtensions {
+    /// Reject mints whose transfers this wallet can't build or that would fail on-chain
+    pub fn check_transferable(&self) -> Result<(), String> {
+        if self.non_transferable {
+            return Err("Token is non-transferable".into());
+        }
+        if self.paused {
+            return Err("Token transfers are paused by the issuer".into());
+        }
+        if self.transfer_hook.is_some() {
+            return Err("Tokens with a transfer hook are not supported yet".into());
+        }
+        Ok(())
+    }
+
+    /// Size of a new associated token account: ImmutableOwner plus the account
+    /// extensions the mint's extensions require
+    pub fn token_account_len(&self) -> usize {
+        if self.types.is_empty() {
+            return BASE_ACCOUNT_LEN;
+        }
+        let mut len = BASE_ACCOUNT_LEN + 1 + tlv_len(0);
+        for ty in &self.types {
+            // TransferFeeAmount, NonTransferableAccount, TransferHookAccount, PausableAccount
+            len += match *ty {
+                EXT_TRANSFER_FEE_CONFIG => tlv_len(8),
+                EXT_NON_TRANSFERABLE => tlv_len(0),
+                EXT_TRANSFER_HOOK => tlv_len(1),
+                EXT_PAUSABLE => tlv_len(0),
+                _ => 0,
+            };
+        }
+        if len == MULTISIG_LEN { len + 2 } else { len }
+    }
+
+    /// Rent-exempt minimum of that account
+    pub fn token_account_rent(&self) -> u64 {
+        (ACCOUNT_STORAGE_OVERHEAD + self.token_account_len() as u64) * LAMPORTS_PER_BYTE_YEAR * 2
+    }
+}
+
+fn tlv_len(value_len: usize) -> usize {
+    4 + value_len
+}
+
+/// `(type, value)` entries after the account type byte; empty without extensions
+fn tlv_entries(data: &[u8], account_type: u8) -> Result<Vec<(u16, &[u8])>, String> {
+    if data.len() <= BASE_ACCOUNT_LEN {
+        return Ok(Vec::new());
+    }
+    if data[BASE_ACCOUNT_LEN] != account_type {
+        return Err("Unexpected Token-2022 account type".into());
+    }
+    let mut entries = Vec::new();
+    let mut offset = BASE_ACCOUNT_LEN + 1;
+    while offset + 4 <= data.len() {
+        let ty = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        // Uninitialized: the rest is unused space
+        if ty == 0 {
+            break;
+        }
+        let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value = data.get(offset + 4..offset + 4 + len).ok_or("Truncated Token-2022 extension")?;
+        entries.push((ty, value));
+        offset += 4 + len;
+    }
+    Ok(entries)
+}
+
+/// Cursor over a Borsh / Pod encoded extension value
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let bytes = self.data.get(self.offset..self.offset + len).ok_or("Truncated Token-2022 extension")?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn key(&mut self) -> Result<[u8; 32], String> {
+        Ok(self.bytes(32)?.try_into().unwrap())
+    }
+
+    /// `OptionalNonZeroPubkey`: all zeros means none
+    fn optional_key(&mut self) -> Result<Option<[u8; 32]>, String> {
+        let key = self.key()?;
+        Ok((key != [0u8; 32]).then_some(key))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.bytes(len)?.to_vec()).map_err(|_| "Invalid UTF-8 in token metadata".to_string())
+    }
+
+    fn transfer_fee(&mut self) -> Result<TransferFee, String> {
+        Ok(TransferFee { epoch: self.u64()?, maximum_fee: self.u64()?, basis_points: self.u16()? })
+    }
+}
+
+/// Parse the extensions of a mint account's raw data
+pub fn parse_mint_extensions(data: &[u8]) -> Result<MintExtensions, String> {
+    let mut ext = MintExtensions::default();
+    for (ty, value) in tlv_entries(data, ACCOUNT_TYPE_MINT)? {
+        ext.types.push(ty);
+        let mut r = Reader { data: value, offset: 0 };
+        match ty {
+            EXT_TRANSFER_FEE_CONFIG => {
+                // config authority, withdraw authority, withheld amount
+                r.bytes(72)?;
+                ext.transfer_fee = Some(TransferFeeConfig { older: r.transfer_fee()?, newer: r.transfer_fee()? });
+            }
+            EXT_METADATA_POINTER => {
+                r.bytes(32)?;
+                ext.metadata_pointer = r.optional_key()?;
+            }
+            EXT_TOKEN_METADATA => {
+                let update_authority = r.optional_key()?;
+                let mint = r.key()?;
+                let (name, symbol, uri) = (r.string()?, r.string()?, r.string()?);
+                let count = r.u32()?;
+                let additional_metadata = (0..count)
+                    .map(|_| Ok((r.string()?, r.string()?)))
+                    .collect::<Result<_, String>>()?;
+                ext.metadata = Some(MetadataExtension { update_authority, mint, name, symbol, uri, additional_metadata });
+            }
+            EXT_NON_TRANSFERABLE => ext.non_transferable = true,
+            EXT_TRANSFER_HOOK => {
+                r.bytes(32)?;
+                ext.transfer_hook = r.optional_key()?;
+            }
+            EXT_PAUSABLE => {
+                r.bytes(32)?;
+                ext.paused = r.bytes(1)?[0] != 0;
+            }
+            _ => {}
+        }
+    }
+    Ok(ext)
+}
+
+/// Whether a token account's `MemoTransfer` extension requires a memo on incoming transfers
+pub fn requires_incoming_memo(data: &[u8]) -> Result<bool, String> {
+    Ok(tlv_entries(data, ACCOUNT_TYPE_ACCOUNT)?
+        .iter()
+        .any(|(ty, value)| *ty == EXT_MEMO_TRANSFER && value.first().is_some_and(|b| *b != 0)))
+}
+
+/// Check a destination token account (base64 `getAccountInfo` value, null if it
+/// does not exist yet) accepts a transfer with or without `memo`
+pub fn check_memo_requirement(destination: &serde_json::Value, memo: Option<&str>) -> Result<(), String> {
+    if destination.is_null() || memo.is_some() {
+        return Ok(());
+    }
+    let encoded = destination.get("data")
+        .and_then(|d| d.get(0))
+        .and_then(|d| d.as_str())
+        .ok_or("Token account has no data")?;
+    if requires_incoming_memo(&crate::backup::base64_decode(encoded)?)? {
+        return Err("Recipient requires a memo on incoming transfers".into());
+    }
+    Ok(())
+}
+
+/// `TransferCheckedWithFee`: fails unless `fee` matches the mint's fee for the current epoch
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_fee(
+    source: &[u8; 32],
+    mint: &[u8; 32],
+    destination: &[u8; 32],
+    authority: &[u8; 32],
+    amount: u64,
+    decimals: u8,
+    fee: u64,
+    token_program: &[u8; 32],
+) -> Instruction {
+    let mut data = vec![IX_TRANSFER_FEE_EXTENSION, IX_TRANSFER_CHECKED_WITH_FEE];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+    data.extend_from_slice(&fee.to_le_bytes());
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::writable(*source, false),
+            AccountMeta::readonly(*mint, false),
+            AccountMeta::writable(*destination, false),
+            AccountMeta::readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tlv(ty: u16, value: &[u8]) -> Vec<u8> {
+        let mut out = ty.to_le_bytes().to_vec();
+        out.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        out.extend_from_slice(value);
+        out
+    }
+
+    fn borsh_str(s: &str) -> Vec<u8> {
+        let mut out = (s.len() as u32).to_le_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out
+    }
+
+    fn with_extensions(account_type: u8, entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = vec![0u8; BASE_ACCOUNT_LEN];
+        data.push(account_type);
+        for e in entries {
+            data.extend_from_slice(e);
+        }
+        data
+    }
+
+    fn fee_config(older: (u64, u64, u16), newer: (u64, u64, u16)) -> Vec<u8> {
+        let mut value = vec![0u8; 72];
+        for (epoch, max, bps) in [older, newer] {
+            value.extend_from_slice(&epoch.to_le_bytes());
+            value.extend_from_slice(&max.to_le_bytes());
+            value.extend_from_slice(&bps.to_le_bytes());
+        }
+        tlv(EXT_TRANSFER_FEE_CONFIG, &value)
+    }
+
+    #[test]
+    fn test_transfer_fee_calculation() {
+        let fee = TransferFee { epoch: 0, maximum_fee: 5_000, basis_points: 50 };
+        // 0.5% of 1001 = 5.005, rounded up
+        assert_eq!(fee.calculate(1_001), 6);
+        assert_eq!(fee.calculate(10_000_000), 5_000);
+        assert_eq!(fee.calculate(0), 0);
+        assert_eq!(TransferFee { basis_points: 0, ..fee }.calculate(1_000), 0);
+
+        let config = TransferFeeConfig { older: fee, newer: TransferFee { epoch: 600, maximum_fee: u64::MAX, basis_points: 100 } };
+        assert_eq!(config.calculate_fee(599, 10_000), 50);
+        assert_eq!(config.calculate_fee(600, 10_000), 100);
+    }
+
+    #[test]
+    fn test_parse_mint_extensions() {
+        let mint = [7u8; 32];
+        let mut metadata = [0u8; 32].to_vec();
+        metadata.extend_from_slice(&mint);
+        for s in ["PayPal USD", "PYUSD", "https://example.com/pyusd.json"] {
+            metadata.extend_from_slice(&borsh_str(s));
+        }
+        metadata.extend_from_slice(&1u32.to_le_bytes());
+        metadata.extend_from_slice(&borsh_str("issuer"));
+        metadata.extend_from_slice(&borsh_str("Paxos"));
+        let mut pointer = [1u8; 32].to_vec();
+        pointer.extend_from_slice(&mint);
+
+        let mut data = with_extensions(ACCOUNT_TYPE_MINT, &[
+            fee_config((0, 0, 0), (512, 1_000_000, 25)),
+            tlv(EXT_METADATA_POINTER, &pointer),
+            tlv(EXT_TOKEN_METADATA, &metadata),
+        ]);
+        data.extend_from_slice(&[0u8; 8]);
+        let ext = parse_mint_extensions(&data).unwrap();
+        assert_eq!(ext.types, vec![EXT_TRANSFER_FEE_CONFIG, EXT_METADATA_POINTER, EXT_TOKEN_METADATA]);
+        let fees = ext.transfer_fee.unwrap();
+        assert_eq!(fees.newer, TransferFee { epoch: 512, maximum_fee: 1_000_000, basis_points: 25 });
+        assert_eq!(ext.metadata_pointer, Some(mint));
+        let meta = ext.metadata.as_ref().unwrap();
+        assert_eq!(meta.update_authority, None);
+        assert_eq!((meta.name.as_str(), meta.symbol.as_str()), ("PayPal USD", "PYUSD"));
+        assert_eq!(meta.additional_metadata, vec![("issuer".to_string(), "Paxos".to_string())]);
+        assert!(ext.check_transferable().is_ok());
+
+        // Plain 82-byte mint: no extensions
+        assert_eq!(parse_mint_extensions(&[0u8; 82]).unwrap(), MintExtensions::default());
+        // A token account is not a mint
+        assert!(parse_mint_extensions(&with_extensions(ACCOUNT_TYPE_ACCOUNT, &[])).is_err());
+        let truncated = with_extensions(ACCOUNT_TYPE_MINT, &[tlv(EXT_TOKEN_METADATA, &metadata[..40])]);
+        assert!(parse_mint_extensions(&truncated).is_err());
+    }
+
+    #[test]
+    fn test_blocked_mints_and_account_size() {
+        let plain = MintExtensions::default();
+        assert_eq!(plain.token_account_rent(), crate::tokens::spl::TOKEN_ACCOUNT_RENT_LAMPORTS);
+
+        let data = with_extensions(ACCOUNT_TYPE_MINT, &[tlv(EXT_NON_TRANSFERABLE, &[])]);
+        assert!(parse_mint_extensions(&data).unwrap().check_transferable().is_err());
+        let mut paused = [0u8; 32].to_vec();
+        paused.push(1);
+        let data = with_extensions(ACCOUNT_TYPE_MINT, &[tlv(EXT_PAUSABLE, &paused)]);
+        assert!(parse_mint_extensions(&data).unwrap().check_transferable().is_err());
+
+        // ImmutableOwner (4) + TransferFeeAmount (12) after the account type byte
+        let data = with_extensions(ACCOUNT_TYPE_MINT, &[fee_config((0, 10, 10), (0, 10, 10))]);
+        let ext = parse_mint_extensions(&data).unwrap();
+        assert_eq!(ext.token_account_len(), 182);
+        assert_eq!(ext.token_account_rent(), 2_157_600);
+    }
+
+    #[test]
+    fn test_memo_required_and_fee_transfer() {
+        let required = with_extensions(ACCOUNT_TYPE_ACCOUNT, &[tlv(EXT_IMMUTABLE_OWNER, &[]), tlv(EXT_MEMO_TRANSFER, &[1])]);
+        assert!(requires_incoming_memo(&required).unwrap());
+        let optional = with_extensions(ACCOUNT_TYPE_ACCOUNT, &[tlv(EXT_MEMO_TRANSFER, &[0])]);
+        assert!(!requires_incoming_memo(&optional).unwrap());
+        assert!(!requires_incoming_memo(&[0u8; BASE_ACCOUNT_LEN]).unwrap());
+
+        let account = serde_json::json!({ "data": [crate::backup::base64_encode(&required), "base64"] });
+        assert!(check_memo_requirement(&account, None).is_err());
+        assert!(check_memo_requirement(&account, Some("invoice 7")).is_ok());
+        assert!(check_memo_requirement(&serde_json::Value::Null, None).is_ok());
+
+        let ix = transfer_checked_with_fee(&[1u8; 32], &[2u8; 32], &[3u8; 32], &[4u8; 32], 1_000, 6, 3, &[5u8; 32]);
+        assert_eq!(ix.program_id, [5u8; 32]);
+        assert_eq!(ix.data, vec![26, 1, 0xe8, 3, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(ix.accounts[3].is_signer);
+    }
+}
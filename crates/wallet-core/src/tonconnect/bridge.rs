@@ -0,0 +1,384 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tonconnect/bridge: HTTP bridge client, independent of the HTTP stack
+//
+// The wallet listens on `GET {bridge}/events?client_id=<ids>&last_event_id=<id>`,
+// a Server-Sent Events stream of `{"from", "message"}` objects, and sends with
+// `POST {bridge}/message?client_id=<ours>&to=<dApp>&ttl=300&topic=<method>`
+// with the base64 ciphertext as body. `BridgeClient` builds the URLs, parses
+// the stream chunks as they arrive, keeps the last event id for reconnects and
+// paces them with an exponential backoff.
+
+use serde_json::Value;
+
+use super::session::Session;
+
+/// Bridge used by Tonkeeper and most dApps
+pub const DEFAULT_BRIDGE_URL: &str = "https://bridge.tonapi.io/bridge";
+
+/// Seconds the bridge keeps an undelivered message
+pub const MESSAGE_TTL_SECS: u32 = 300;
+
+/// Wait before the first reconnect; doubles on each failure up to the maximum
+pub const RECONNECT_MIN_MS: u32 = 1_000;
+pub const RECONNECT_MAX_MS: u32 = 30_000;
+
+/// What the stream reader does after a read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamControl {
+    /// Read the next chunk
+    Continue,
+    /// The stream ended: connect again after `reconnect_delay_ms`
+    Reconnect,
+    /// The listener was stopped
+    Stop,
+}
+
+/// One Server-Sent Event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    pub id: Option<String>,
+    /// Event type, `message` when the stream does not name one
+    pub event: String,
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` parser: feed raw chunks, get complete events
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    id: Option<String>,
+    event: Option<String>,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    /// Parse a chunk; lines split across chunks are kept until they complete
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches('\n').trim_end_matches('\r');
+            if line.is_empty() {
+                if let Some(event) = self.dispatch() {
+                    events.push(event);
+                }
+                continue;
+            }
+            if line.starts_with(':') {
+                continue; // comment / keep-alive
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "id" => self.id = Some(value.to_string()),
+                "event" => self.event = Some(value.to_string()),
+                "data" => self.data.push(value.to_string()),
+                _ => {}
+            }
+        }
+        events
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        let id = self.id.take();
+        if self.data.is_empty() {
+            return None;
+        }
+        Some(SseEvent {
+            id,
+            event: event.unwrap_or_else(|| "message".into()),
+            data: std::mem::take(&mut self.data).join("\n"),
+        })
+    }
+}
+
+/// Encrypted message received from a dApp
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncomingMessage {
+    /// Client id of the sending dApp
+    pub from: String,
+    pub message: Vec<u8>,
+}
+
+impl IncomingMessage {
+    /// Decrypt with the session of the sender and parse the JSON request
+    pub fn open(&self, session: &Session) -> Result<Value, String> {
+        let plaintext = session.decrypt(&self.message)?;
+        serde_json::from_slice(&plaintext).map_err(|e| format!("Invalid request: {}", e))
+    }
+}
+
+/// HTTP request the caller sends to deliver a message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingMessage {
+    pub url: String,
+    /// Base64 ciphertext, sent as `text/plain`
+    pub body: String,
+}
+
+/// Bridge client state for the wallet's sessions
+#[derive(Debug)]
+pub struct BridgeClient {
+    bridge_url: String,
+    last_event_id: Option<String>,
+    parser: SseParser,
+    failures: u32,
+}
+
+impl BridgeClient {
+    pub fn new(bridge_url: &str, last_event_id: Option<String>) -> Self {
+        BridgeClient {
+            bridge_url: bridge_url.trim_end_matches('/').to_string(),
+            last_event_id,
+            parser: SseParser::default(),
+            failures: 0,
+        }
+    }
+
+    /// Id of the last event received, to resume after a reconnect
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Events URL for our session client ids. Starts a new stream: any
+    /// partial event from the previous connection is dropped.
+    pub fn events_url(&mut self, client_ids: &[String]) -> String {
+        self.parser = SseParser::default();
+        let mut url = format!("{}/events?client_id={}", self.bridge_url, client_ids.join(","));
+        if let Some(id) = &self.last_event_id {
+            url.push_str(&format!("&last_event_id={}", id));
+        }
+        url
+    }
+
+    /// Feed a chunk of the events stream. Heartbeats and malformed events are skipped.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<IncomingMessage> {
+        let mut messages = Vec::new();
+        for event in self.parser.feed(chunk) {
+            if event.event != "message" {
+                continue;
+            }
+            if let Some(id) = event.id {
+                self.last_event_id = Some(id);
+            }
+            if let Some(message) = parse_bridge_message(&event.data) {
+                messages.push(message);
+            }
+        }
+        messages
+    }
+
+    /// Handle one read of the events stream: `Some(chunk)` with data, `None`
+    /// when the stream closed or failed. Complete messages go to `on_message`;
+    /// any data received resets the reconnect backoff.
+    pub fn on_read(
+        &mut self,
+        chunk: Option<&[u8]>,
+        keep_running: bool,
+        mut on_message: impl FnMut(IncomingMessage),
+    ) -> StreamControl {
+        if let Some(chunk) = chunk {
+            self.failures = 0;
+            for message in self.feed(chunk) {
+                on_message(message);
+            }
+        }
+        match (keep_running, chunk) {
+            (false, _) => StreamControl::Stop,
+            (true, Some(_)) => StreamControl::Continue,
+            (true, None) => StreamControl::Reconnect,
+        }
+    }
+
+    /// Delay before the next reconnect, doubling after each consecutive failure
+    pub fn reconnect_delay_ms(&mut self) -> u32 {
+        let delay = RECONNECT_MIN_MS.saturating_mul(1 << self.failures.min(5)).min(RECONNECT_MAX_MS);
+        self.failures += 1;
+        delay
+    }
+
+    /// Encrypt `payload` for the session's dApp and build the send request
+    pub fn outgoing(&self, session: &Session, payload: &Value, topic: Option<&str>) -> Result<OutgoingMessage, String> {
+        let ciphertext = session.encrypt(payload.to_string().as_bytes())?;
+        let mut url = format!(
+            "{}/message?client_id={}&to={}&ttl={}",
+            self.bridge_url,
+            session.client_id(),
+            session.app_client_id(),
+            MESSAGE_TTL_SECS
+        );
+        if let Some(topic) = topic {
+            url.push_str(&format!("&topic={}", topic));
+        }
+        Ok(OutgoingMessage { url, body: crate::backup::base64_encode(&ciphertext) })
+    }
+}
+
+fn parse_bridge_message(data: &str) -> Option<IncomingMessage> {
+    let value: Value = serde_json::from_str(data).ok()?;
+    let from = value.get("from")?.as_str()?.to_lowercase();
+    let message = super::decode_base64(value.get("message")?.as_str()?).ok()?;
+    Some(IncomingMessage { from, message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_box::SecretKey;
+    use serde_json::json;
+
+    /// In-memory stand-in for a bridge server, answering the same HTTP requests
+    #[derive(Default)]
+    struct LocalBridge {
+        next_id: u64,
+        /// (event id, recipient, sender, base64 body)
+        stored: Vec<(u64, String, String, String)>,
+    }
+
+    impl LocalBridge {
+        fn query(url: &str) -> Vec<(String, String)> {
+            url.split_once('?').map(|(_, q)| q).unwrap_or_default().split('&')
+                .filter_map(|p| p.split_once('='))
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        }
+
+        fn param(url: &str, key: &str) -> Option<String> {
+            Self::query(url).into_iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        }
+
+        /// `POST /message`
+        fn post(&mut self, url: &str, body: &str) {
+            assert!(url.starts_with("http://127.0.0.1:8081/bridge/message?"));
+            assert_eq!(Self::param(url, "ttl").as_deref(), Some("300"));
+            self.next_id += 1;
+            self.stored.push((self.next_id, Self::param(url, "to").unwrap(), Self::param(url, "client_id").unwrap(), body.to_string()));
+        }
+
+        /// `GET /events`: the stream body the bridge writes for this request
+        fn events(&self, url: &str) -> String {
+            assert!(url.starts_with("http://127.0.0.1:8081/bridge/events?"));
+            let clients = Self::param(url, "client_id").unwrap();
+            let after: u64 = Self::param(url, "last_event_id").map(|id| id.parse().unwrap()).unwrap_or(0);
+            let mut stream = String::from(": connected\r\n\r\nevent: heartbeat\r\ndata: heartbeat\r\n\r\n");
+            for (id, to, from, body) in &self.stored {
+                if *id > after && clients.split(',').any(|c| c == to) {
+                    let data = json!({"from": from, "message": body}).to_string();
+                    stream.push_str(&format!("event: message\r\nid: {}\r\ndata: {}\r\n\r\n", id, data));
+                }
+            }
+            stream
+        }
+    }
+
+    const BRIDGE: &str = "http://127.0.0.1:8081/bridge/";
+
+    #[test]
+    fn test_sse_parser() {
+        let mut parser = SseParser::default();
+        assert!(parser.feed(b"id: 1\ndata: {\"a\"").is_empty());
+        let events = parser.feed(b":1}\n\n: ping\n\nevent: heartbeat\n\ndata: one\ndata:two\n\n");
+        assert_eq!(events, vec![
+            SseEvent { id: Some("1".into()), event: "message".into(), data: "{\"a\":1}".into() },
+            SseEvent { id: None, event: "message".into(), data: "one\ntwo".into() },
+        ]);
+    }
+
+    #[test]
+    fn test_round_trip_through_local_bridge() {
+        let mut bridge = LocalBridge::default();
+        let app_secret = SecretKey::from([4u8; 32]);
+        let app_id = hex::encode(app_secret.public_key().as_bytes());
+        let wallet_session = Session::new(&app_id).unwrap();
+        let app_session = Session::restore(&hex::encode(app_secret.to_bytes()), &wallet_session.client_id()).unwrap();
+        let mut wallet = BridgeClient::new(BRIDGE, None);
+        let app = BridgeClient::new(BRIDGE, None);
+
+        // Wallet announces the connection
+        let connect = json!({"event": "connect", "id": 1, "payload": {"items": []}});
+        let sent = wallet.outgoing(&wallet_session, &connect, None).unwrap();
+        bridge.post(&sent.url, &sent.body);
+        let mut app_listener = BridgeClient::new(BRIDGE, None);
+        let url = app_listener.events_url(&[app_session.client_id()]);
+        let received = app_listener.feed(bridge.events(&url).as_bytes());
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].from, wallet_session.client_id());
+        assert_eq!(received[0].open(&app_session).unwrap(), connect);
+
+        // dApp sends two requests; the wallet reads them in one stream, split at odd places
+        for id in ["1", "2"] {
+            let request = json!({"method": "sendTransaction", "params": ["{}"], "id": id});
+            let sent = app.outgoing(&app_session, &request, Some("sendTransaction")).unwrap();
+            assert!(sent.url.ends_with("&topic=sendTransaction"));
+            bridge.post(&sent.url, &sent.body);
+        }
+        let url = wallet.events_url(&[wallet_session.client_id(), "00".repeat(32)]);
+        let stream = bridge.events(&url);
+        let mut received = Vec::new();
+        for chunk in stream.as_bytes().chunks(7) {
+            received.extend(wallet.feed(chunk));
+        }
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[1].from, app_id);
+        assert_eq!(received[1].open(&wallet_session).unwrap()["id"], "2");
+        assert_eq!(wallet.last_event_id(), Some("3"));
+
+        // Reconnecting resumes after the last event
+        let url = wallet.events_url(&[wallet_session.client_id()]);
+        assert!(url.ends_with("&last_event_id=3"));
+        assert!(wallet.feed(bridge.events(&url).as_bytes()).is_empty());
+
+        // Messages from another dApp do not open with this session
+        let stranger = Session::new(&wallet_session.client_id()).unwrap();
+        let sent = app.outgoing(&stranger, &json!({"id": "x"}), None).unwrap();
+        let forged = IncomingMessage { from: app_id, message: crate::backup::base64_decode(&sent.body).unwrap() };
+        assert!(forged.open(&wallet_session).is_err());
+    }
+
+    #[test]
+    fn test_stream_reads_and_reconnects() {
+        let mut bridge = LocalBridge::default();
+        let app_secret = SecretKey::from([6u8; 32]);
+        let wallet_session = Session::new(&hex::encode(app_secret.public_key().as_bytes())).unwrap();
+        let app_session = Session::restore(&hex::encode(app_secret.to_bytes()), &wallet_session.client_id()).unwrap();
+        let app = BridgeClient::new(BRIDGE, None);
+        for id in ["1", "2"] {
+            let sent = app.outgoing(&app_session, &json!({"method": "disconnect", "params": [], "id": id}), None).unwrap();
+            bridge.post(&sent.url, &sent.body);
+        }
+        let mut wallet = BridgeClient::new(BRIDGE, None);
+        let mut received = Vec::new();
+
+        // The connection drops in the middle of the second event
+        let url = wallet.events_url(&[wallet_session.client_id()]);
+        let stream = bridge.events(&url);
+        let cut = stream.rfind("event: message").unwrap() + 20;
+        assert_eq!(wallet.on_read(Some(&stream.as_bytes()[..cut]), true, |m| received.push(m)), StreamControl::Continue);
+        assert_eq!(wallet.on_read(None, true, |m| received.push(m)), StreamControl::Reconnect);
+        assert_eq!(received.len(), 1);
+        assert_eq!(wallet.last_event_id(), Some("1"));
+
+        // Failed reconnects back off up to the maximum
+        let delays: Vec<u32> = (0..8).map(|_| wallet.reconnect_delay_ms()).collect();
+        assert_eq!(delays, vec![1_000, 2_000, 4_000, 8_000, 16_000, 30_000, 30_000, 30_000]);
+
+        // The new stream resumes after event 1 and delivers event 2 exactly once
+        let url = wallet.events_url(&[wallet_session.client_id()]);
+        assert!(url.ends_with("&last_event_id=1"));
+        for chunk in bridge.events(&url).as_bytes().chunks(5) {
+            assert_eq!(wallet.on_read(Some(chunk), true, |m| received.push(m)), StreamControl::Continue);
+        }
+        assert_eq!(received.len(), 2);
+        assert_eq!(received[1].open(&wallet_session).unwrap()["id"], "2");
+        assert_eq!(wallet.reconnect_delay_ms(), 1_000);
+
+        // Stopping wins over both data and a closed stream
+        assert_eq!(wallet.on_read(Some(b": ping\n\n"), false, |m| received.push(m)), StreamControl::Stop);
+        assert_eq!(wallet.on_read(None, false, |m| received.push(m)), StreamControl::Stop);
+    }
+}
@@ -0,0 +1,615 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tonconnect: wallet side of the TON Connect 2 protocol
+//
+// Submodules:
+//   session — X25519 session keys and NaCl box encryption of bridge messages
+//   proof   — `ton_proof` message hashing and Ed25519 signature
+//   bridge  — HTTP bridge URLs and the SSE event stream parser
+//
+// A dApp shows a universal link `tc://?v=2&id=<client id>&r=<ConnectRequest>`.
+// The wallet answers with a `connect` event carrying the `ton_addr` item (and
+// `ton_proof` when asked), then receives `sendTransaction` and `disconnect`
+// requests through the bridge and replies with the signed external message BOC.
+
+pub mod bridge;
+pub mod proof;
+pub mod session;
+
+use serde_json::{json, Value};
+
+use crate::chains::ton::{TonAddress, WalletVersion};
+use crate::chains::ton_cell::Cell;
+use crate::tx::ton::{TonMessage, MAX_WALLET_MESSAGES};
+
+/// Protocol version implemented by the wallet
+pub const PROTOCOL_VERSION: u8 = 2;
+
+/// `network` values of the protocol (global ids of mainnet and testnet)
+pub const NETWORK_MAINNET: &str = "-239";
+pub const NETWORK_TESTNET: &str = "-3";
+
+/// Error codes of `connect_error` events and request responses
+pub const ERROR_UNKNOWN: u16 = 0;
+pub const ERROR_BAD_REQUEST: u16 = 1;
+pub const ERROR_MANIFEST_NOT_FOUND: u16 = 2;
+pub const ERROR_MANIFEST_CONTENT: u16 = 3;
+pub const ERROR_UNKNOWN_APP: u16 = 100;
+pub const ERROR_USER_DECLINED: u16 = 300;
+pub const ERROR_METHOD_NOT_SUPPORTED: u16 = 400;
+
+/// Longest base64 `payload` / `stateInit` accepted: 64 KiB of BOC, the
+/// external message limit of the network
+const MAX_BOC_BASE64_LEN: usize = 65536 / 3 * 4 + 4;
+
+/// Item the dApp asks for in its connect request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectItem {
+    TonAddr,
+    TonProof { payload: String },
+}
+
+/// Connect request carried by a universal link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectRequest {
+    pub manifest_url: String,
+    pub items: Vec<ConnectItem>,
+}
+
+impl ConnectRequest {
+    /// `ton_proof` payload, if the dApp asked for a proof
+    pub fn proof_payload(&self) -> Option<&str> {
+        self.items.iter().find_map(|item| match item {
+            ConnectItem::TonProof { payload } => Some(payload.as_str()),
+            ConnectItem::TonAddr => None,
+        })
+    }
+}
+
+/// Parsed universal link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectLink {
+    /// dApp client id: hex of its X25519 session public key
+    pub client_id: String,
+    pub request: ConnectRequest,
+    /// Return strategy: `back`, `none` or a URL
+    pub ret: Option<String>,
+}
+
+/// Parse a `tc://` link or a wallet universal link (`https://…?v=2&id=…&r=…`).
+/// Items other than `ton_addr` and `ton_proof` are ignored; `ton_addr` is required.
+pub fn parse_connect_link(link: &str) -> Result<ConnectLink, String> {
+    let query = link.trim().split_once('?').map(|(_, q)| q).ok_or("Not a TON Connect link")?;
+    let mut version = None;
+    let mut client_id = None;
+    let mut request = None;
+    let mut ret = None;
+    for pair in query.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = percent_decode(value)?;
+        match key {
+            "v" => version = Some(value),
+            "id" => client_id = Some(value),
+            "r" => request = Some(value),
+            "ret" => ret = Some(value),
+            _ => {}
+        }
+    }
+    if version.as_deref() != Some("2") {
+        return Err("Unsupported TON Connect version".into());
+    }
+    let client_id = client_id.ok_or("Missing client id")?.to_lowercase();
+    if hex::decode(&client_id).map(|k| k.len()) != Ok(32) {
+        return Err("Invalid client id".into());
+    }
+    let request: Value = serde_json::from_str(&request.ok_or("Missing connect request")?)
+        .map_err(|e| format!("Invalid connect request: {}", e))?;
+    Ok(ConnectLink { client_id, request: parse_connect_request(&request)?, ret })
+}
+
+fn parse_connect_request(request: &Value) -> Result<ConnectRequest, String> {
+    let manifest_url = request.get("manifestUrl").and_then(|u| u.as_str())
+        .filter(|u| u.starts_with("https://") || u.starts_with("http://"))
+        .ok_or("Invalid manifestUrl")?
+        .to_string();
+    let mut items = Vec::new();
+    for item in request.get("items").and_then(|i| i.as_array()).ok_or("Missing items")? {
+        match item.get("name").and_then(|n| n.as_str()) {
+            Some("ton_addr") => items.push(ConnectItem::TonAddr),
+            Some("ton_proof") => {
+                let payload = item.get("payload").and_then(|p| p.as_str()).ok_or("Missing ton_proof payload")?;
+                items.push(ConnectItem::TonProof { payload: payload.to_string() });
+            }
+            _ => {}
+        }
+    }
+    if !items.contains(&ConnectItem::TonAddr) {
+        return Err("Connect request without ton_addr".into());
+    }
+    Ok(ConnectRequest { manifest_url, items })
+}
+
+/// dApp manifest (`tonconnect-manifest.json`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppManifest {
+    pub url: String,
+    pub name: String,
+    pub icon_url: String,
+}
+
+impl AppManifest {
+    pub fn parse(manifest: &Value) -> Result<Self, String> {
+        let field = |key: &str| manifest.get(key).and_then(|v| v.as_str()).filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| format!("Manifest without {}", key));
+        let url = field("url")?;
+        url_host(&url)?;
+        Ok(AppManifest { url, name: field("name")?, icon_url: field("iconUrl")? })
+    }
+
+    /// Domain signed in `ton_proof`: the host of the app URL
+    pub fn domain(&self) -> String {
+        url_host(&self.url).unwrap_or_default()
+    }
+}
+
+/// Host (with port) of an http(s) URL
+fn url_host(url: &str) -> Result<String, String> {
+    let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| format!("Invalid app URL: {}", url))?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() {
+        return Err(format!("Invalid app URL: {}", url));
+    }
+    Ok(host.to_lowercase())
+}
+
+/// `network` value for the selected network
+pub fn network_id(testnet: bool) -> &'static str {
+    if testnet { NETWORK_TESTNET } else { NETWORK_MAINNET }
+}
+
+/// `ton_addr` reply item: raw address, network, public key and wallet StateInit
+pub fn ton_addr_item(address: &TonAddress, public_key: &[u8; 32], state_init: &Cell, testnet: bool) -> Value {
+    json!({
+        "name": "ton_addr",
+        "address": address.to_raw_string(),
+        "network": network_id(testnet),
+        "publicKey": hex::encode(public_key),
+        "walletStateInit": crate::backup::base64_encode(&state_init.to_boc()),
+    })
+}
+
+/// Reply items for a connect request from the `version` wallet of `private_key`:
+/// `ton_addr`, plus `ton_proof` for the manifest's domain when asked
+pub fn connect_items(
+    private_key: &[u8; 32],
+    version: WalletVersion,
+    request: &ConnectRequest,
+    manifest: &AppManifest,
+    testnet: bool,
+    timestamp: u64,
+) -> Result<Vec<Value>, String> {
+    let public_key = ed25519_dalek::SigningKey::from_bytes(private_key).verifying_key().to_bytes();
+    let address = version.address(&public_key, testnet)?;
+    let state_init = version.state_init(&public_key, testnet)?;
+
+    let mut items = vec![ton_addr_item(&address, &public_key, &state_init, testnet)];
+    if let Some(payload) = request.proof_payload() {
+        let proof = proof::TonProof { timestamp, domain: manifest.domain(), payload: payload.to_string() };
+        items.push(proof.item(&proof.sign(private_key, &address)));
+    }
+    Ok(items)
+}
+
+/// Wallet description sent with the connect event
+pub fn device_info(app_version: &str) -> Value {
+    json!({
+        "platform": "browser",
+        "appName": "rusby-wallet",
+        "appVersion": app_version,
+        "maxProtocolVersion": PROTOCOL_VERSION,
+        "features": ["SendTransaction", {"name": "SendTransaction", "maxMessages": MAX_WALLET_MESSAGES}],
+    })
+}
+
+/// `connect` event with the reply items
+pub fn connect_event(id: u64, items: Vec<Value>, device: Value) -> Value {
+    json!({"event": "connect", "id": id, "payload": {"items": items, "device": device}})
+}
+
+/// `connect_error` event, e.g. when the user declines
+pub fn connect_error_event(id: u64, code: u16, message: &str) -> Value {
+    json!({"event": "connect_error", "id": id, "payload": {"code": code, "message": message}})
+}
+
+/// `disconnect` event sent when the user ends the session from the wallet
+pub fn disconnect_event(id: u64) -> Value {
+    json!({"event": "disconnect", "id": id, "payload": {}})
+}
+
+/// Request received from the dApp
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppRequest {
+    SendTransaction { id: String, request: TransactionRequest },
+    Disconnect { id: String },
+    /// Well-formed request for a method the wallet does not implement
+    Unsupported { id: String, method: String },
+}
+
+impl AppRequest {
+    pub fn id(&self) -> &str {
+        match self {
+            AppRequest::SendTransaction { id, .. } | AppRequest::Disconnect { id } | AppRequest::Unsupported { id, .. } => id,
+        }
+    }
+}
+
+/// Id of a decrypted request, to answer it even when the rest does not parse
+pub fn request_id(request: &Value) -> Option<String> {
+    match request.get("id") {
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Parse a decrypted `{"method", "params", "id"}` request
+pub fn parse_app_request(request: &Value) -> Result<AppRequest, String> {
+    let id = request_id(request).ok_or("Request without id")?;
+    let method = request.get("method").and_then(|m| m.as_str()).ok_or("Request without method")?;
+    match method {
+        "sendTransaction" => {
+            let param = request.get("params").and_then(|p| p.get(0)).and_then(|p| p.as_str())
+                .ok_or("sendTransaction without params")?;
+            let param: Value = serde_json::from_str(param).map_err(|e| format!("Invalid sendTransaction params: {}", e))?;
+            Ok(AppRequest::SendTransaction { id, request: TransactionRequest::parse(&param)? })
+        }
+        "disconnect" => Ok(AppRequest::Disconnect { id }),
+        _ => Ok(AppRequest::Unsupported { id, method: method.to_string() }),
+    }
+}
+
+/// `sendTransaction` request: up to 4 messages with optional payload and StateInit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionRequest {
+    /// Unix time after which the wallet must not sign
+    pub valid_until: Option<u64>,
+    pub network: Option<String>,
+    /// Raw or friendly address the dApp expects to sign with
+    pub from: Option<String>,
+    pub messages: Vec<RequestMessage>,
+}
+
+/// One message of a `sendTransaction` request. The payload and StateInit stay
+/// base64 until the request is signed, so nothing the dApp sends is decoded
+/// before the user has approved it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMessage {
+    pub destination: TonAddress,
+    pub amount_nanoton: u64,
+    pub bounce: bool,
+    /// Base64 BOC of the message body
+    pub payload: Option<String>,
+    /// Base64 BOC of the StateInit to deploy
+    pub state_init: Option<String>,
+}
+
+impl RequestMessage {
+    /// Decode the payload and StateInit into the message to sign
+    pub fn to_ton_message(&self) -> Result<TonMessage, String> {
+        let boc = |key: &str, b64: &Option<String>| -> Result<Option<Cell>, String> {
+            b64.as_deref()
+                .map(|b64| Cell::from_boc(&decode_base64(b64)?).map_err(|e| format!("Invalid {}: {}", key, e)))
+                .transpose()
+        };
+        Ok(TonMessage {
+            destination: self.destination,
+            amount_nanoton: self.amount_nanoton,
+            bounce: self.bounce,
+            state_init: boc("stateInit", &self.state_init)?,
+            body: boc("payload", &self.payload)?,
+        })
+    }
+}
+
+impl TransactionRequest {
+    /// Parse the request. Messages bounce when the destination is given in
+    /// bounceable (or raw) form, as the address flags say.
+    pub fn parse(request: &Value) -> Result<Self, String> {
+        let valid_until = match request.get("valid_until").or_else(|| request.get("validUntil")) {
+            None | Some(Value::Null) => None,
+            Some(v) => Some(v.as_u64().ok_or("Invalid valid_until")?),
+        };
+        let text = |key: &str| request.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        let messages = request.get("messages").and_then(|m| m.as_array()).ok_or("Missing messages")?
+            .iter()
+            .map(parse_message)
+            .collect::<Result<Vec<_>, _>>()?;
+        if messages.is_empty() || messages.len() > MAX_WALLET_MESSAGES {
+            return Err(format!("A transaction carries 1 to {} messages", MAX_WALLET_MESSAGES));
+        }
+        Ok(TransactionRequest { valid_until, network: text("network"), from: text("from"), messages })
+    }
+
+    /// Check the request targets our account and network and has not expired
+    pub fn validate(&self, account: &TonAddress, testnet: bool, now: u64) -> Result<(), String> {
+        if let Some(network) = &self.network {
+            if network != network_id(testnet) {
+                return Err("Transaction for another network".into());
+            }
+        }
+        if let Some(from) = &self.from {
+            let from = TonAddress::parse(from)?;
+            if from.workchain != account.workchain || from.hash != account.hash {
+                return Err("Transaction for another account".into());
+            }
+        }
+        if self.is_expired(now) {
+            return Err("Transaction request expired".into());
+        }
+        Ok(())
+    }
+
+    /// Whether `valid_until` has passed at `now`
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.valid_until.is_some_and(|until| until <= now)
+    }
+
+    /// Expiry to sign with: never later than the dApp's `valid_until`, nor
+    /// than `ttl` seconds from `now`
+    pub fn signing_valid_until(&self, now: u64, ttl: u32) -> u32 {
+        let cap = now.saturating_add(ttl as u64);
+        self.valid_until.map_or(cap, |until| until.min(cap)).min(u32::MAX as u64) as u32
+    }
+
+    /// Total nanoton sent by the messages
+    pub fn total_nanoton(&self) -> u128 {
+        self.messages.iter().map(|m| m.amount_nanoton as u128).sum()
+    }
+
+    /// Decode the messages to sign; call after `validate`
+    pub fn ton_messages(&self) -> Result<Vec<TonMessage>, String> {
+        self.messages.iter().map(RequestMessage::to_ton_message).collect()
+    }
+}
+
+fn parse_message(message: &Value) -> Result<RequestMessage, String> {
+    let destination = TonAddress::parse(message.get("address").and_then(|a| a.as_str()).ok_or("Message without address")?)?;
+    let amount_nanoton = match message.get("amount") {
+        Some(Value::String(s)) => s.parse().map_err(|_| format!("Invalid amount: {}", s))?,
+        Some(Value::Number(n)) => n.as_u64().ok_or("Invalid amount")?,
+        _ => return Err("Message without amount".into()),
+    };
+    let boc = |key: &str| -> Result<Option<String>, String> {
+        match message.get(key).and_then(|v| v.as_str()).filter(|v| !v.is_empty()) {
+            Some(b64) if b64.len() > MAX_BOC_BASE64_LEN => Err(format!("{} too large", key)),
+            b64 => Ok(b64.map(str::to_string)),
+        }
+    };
+    Ok(RequestMessage {
+        destination,
+        amount_nanoton,
+        bounce: destination.bounceable,
+        state_init: boc("stateInit")?,
+        payload: boc("payload")?,
+    })
+}
+
+/// Successful reply to a request
+pub fn response_result(id: &str, result: Value) -> Value {
+    json!({"result": result, "id": id})
+}
+
+/// Error reply to a request
+pub fn response_error(id: &str, code: u16, message: &str) -> Value {
+    json!({"error": {"code": code, "message": message}, "id": id})
+}
+
+/// Standard or URL-safe base64, with or without padding
+pub(crate) fn decode_base64(input: &str) -> Result<Vec<u8>, String> {
+    let mut normalized: String = input.trim().chars()
+        .map(|c| match c { '-' => '+', '_' => '/', c => c })
+        .collect();
+    while !normalized.len().is_multiple_of(4) {
+        normalized.push('=');
+    }
+    crate::backup::base64_decode(&normalized)
+}
+
+/// Decode `%XX` escapes and `+` (space) in a query string value
+fn percent_decode(input: &str) -> Result<String, String> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = input.get(i + 1..i + 3).ok_or("Truncated escape in link")?;
+                out.push(u8::from_str_radix(hex, 16).map_err(|_| "Invalid escape in link")?);
+                i += 3;
+            }
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|_| "Link is not valid UTF-8".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT_ID: &str = "230f1e4df32364888a5dbd92a410266fcb974b73e30ff3e546a654fc8ee2c953";
+
+    #[test]
+    fn test_parse_connect_link() {
+        let link = format!(
+            "tc://?v=2&id={}&r=%7B%22manifestUrl%22%3A%22https%3A%2F%2Fapp.example%2Ftonconnect-manifest.json%22%2C%22items%22%3A%5B%7B%22name%22%3A%22ton_addr%22%7D%2C%7B%22name%22%3A%22ton_proof%22%2C%22payload%22%3A%22nonce+42%22%7D%5D%7D&ret=none",
+            CLIENT_ID
+        );
+        let parsed = parse_connect_link(&link).unwrap();
+        assert_eq!(parsed.client_id, CLIENT_ID);
+        assert_eq!(parsed.request.manifest_url, "https://app.example/tonconnect-manifest.json");
+        assert_eq!(parsed.request.proof_payload(), Some("nonce 42"));
+        assert_eq!(parsed.ret.as_deref(), Some("none"));
+
+        // Wallet universal links carry the same query
+        let universal = link.replacen("tc://", "https://app.tonkeeper.com/ton-connect", 1);
+        assert_eq!(parse_connect_link(&universal).unwrap(), parsed);
+
+        assert!(parse_connect_link(&link.replace("v=2", "v=1")).is_err());
+        assert!(parse_connect_link(&link.replace(CLIENT_ID, "abcd")).is_err());
+        assert!(parse_connect_link("tc://?v=2&id=00&r=%7B%7D").is_err());
+        let no_addr = format!("tc://?v=2&id={}&r={{\"manifestUrl\":\"https://a.b/m.json\",\"items\":[]}}", CLIENT_ID);
+        assert!(parse_connect_link(&no_addr).is_err());
+    }
+
+    #[test]
+    fn test_manifest() {
+        let manifest = AppManifest::parse(&json!({
+            "url": "https://App.Example:8443/path",
+            "name": "Example",
+            "iconUrl": "https://app.example/icon.png",
+        })).unwrap();
+        assert_eq!(manifest.domain(), "app.example:8443");
+        assert!(AppManifest::parse(&json!({"url": "ftp://x", "name": "X", "iconUrl": "i"})).is_err());
+        assert!(AppManifest::parse(&json!({"url": "https://x.y", "iconUrl": "i"})).is_err());
+    }
+
+    #[test]
+    fn test_send_transaction_request() {
+        let payload = crate::tx::ton::text_comment("hi").unwrap();
+        let init = crate::chains::ton::wallet_v4r2_state_init(&[7u8; 32]).unwrap();
+        let account = TonAddress { workchain: 0, hash: [9; 32], bounceable: true, testnet: false };
+        let params = json!({
+            "valid_until": 1_700_000_600,
+            "network": "-239",
+            "from": account.to_raw_string(),
+            "messages": [
+                {"address": "EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v", "amount": "1500000000",
+                 "payload": crate::backup::base64_encode(&payload.to_boc())},
+                {"address": TonAddress { bounceable: false, ..account }.to_friendly(), "amount": "20",
+                 "stateInit": crate::backup::base64_encode(&init.to_boc()).trim_end_matches('=')},
+            ],
+        });
+        let request = json!({"method": "sendTransaction", "params": [params.to_string()], "id": "7"});
+        let AppRequest::SendTransaction { id, request } = parse_app_request(&request).unwrap() else {
+            panic!("expected sendTransaction");
+        };
+        assert_eq!(id, "7");
+        assert_eq!(request.messages.len(), 2);
+        let messages = request.ton_messages().unwrap();
+        assert!(messages[0].bounce);
+        assert_eq!(messages[0].body, Some(payload));
+        assert!(!messages[1].bounce);
+        assert_eq!(messages[1].state_init, Some(init));
+        assert_eq!(request.total_nanoton(), 1_500_000_020);
+
+        assert!(request.validate(&account, false, 1_700_000_000).is_ok());
+        assert!(request.validate(&account, true, 1_700_000_000).is_err());
+        assert!(request.validate(&account, false, 1_700_000_600).is_err());
+        assert!(request.is_expired(1_700_000_600) && !request.is_expired(1_700_000_599));
+        assert_eq!(request.signing_valid_until(1_700_000_000, 60), 1_700_000_060);
+        assert_eq!(request.signing_valid_until(1_700_000_580, 60), 1_700_000_600);
+        let other = TonAddress { hash: [8; 32], ..account };
+        assert!(request.validate(&other, false, 1_700_000_000).is_err());
+
+        let too_many = json!({"messages": vec![json!({"address": account.to_raw_string(), "amount": "1"}); 5]});
+        assert!(TransactionRequest::parse(&too_many).is_err());
+        assert!(TransactionRequest::parse(&json!({"messages": [{"address": "0:00", "amount": "1"}]})).is_err());
+
+        // Oversized input is refused at parse time; BOCs are only decoded when signing,
+        // where a tiny BOC claiming 2^32 cells is rejected
+        let huge = json!({"messages": [{"address": account.to_raw_string(), "amount": "1", "payload": "A".repeat(MAX_BOC_BASE64_LEN + 4)}]});
+        assert!(TransactionRequest::parse(&huge).unwrap_err().contains("too large"));
+        let bomb = hex::decode("b5ee9c720401ffffffff00000001000000000000000000").unwrap();
+        let bomb = json!({"messages": [{"address": account.to_raw_string(), "amount": "1", "stateInit": crate::backup::base64_encode(&bomb)}]});
+        assert!(TransactionRequest::parse(&bomb).unwrap().ton_messages().is_err());
+    }
+
+    #[test]
+    fn test_send_transaction_shared_ref_payload() {
+        // A few hundred bytes of cells that each reference the next one 4 times: 4^40
+        // cells if expanded. Parsing the request does not touch it and decoding it for
+        // signing fails fast instead of exhausting memory.
+        let mut cell = Cell::empty();
+        for _ in 0..40 {
+            let mut b = crate::chains::ton_cell::CellBuilder::new();
+            let child = std::sync::Arc::new(cell);
+            for _ in 0..4 {
+                b.store_ref(child.clone()).unwrap();
+            }
+            cell = b.build();
+        }
+        let payload = crate::backup::base64_encode(&cell.to_boc());
+        let params = json!({"messages": [{"address": "EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v", "amount": "1", "payload": payload}]});
+        let request = json!({"method": "sendTransaction", "params": [params.to_string()], "id": "9"});
+
+        let start = std::time::Instant::now();
+        let AppRequest::SendTransaction { request, .. } = parse_app_request(&request).unwrap() else {
+            panic!("expected sendTransaction");
+        };
+        assert_eq!(request.messages[0].payload.as_deref(), Some(payload.as_str()));
+        assert!(request.ton_messages().unwrap_err().contains("too many cells"));
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_other_requests_and_replies() {
+        assert_eq!(parse_app_request(&json!({"method": "disconnect", "params": [], "id": 3})).unwrap(),
+            AppRequest::Disconnect { id: "3".into() });
+        let sign_data = parse_app_request(&json!({"method": "signData", "params": [], "id": "4"})).unwrap();
+        assert_eq!(sign_data.id(), "4");
+        assert!(matches!(sign_data, AppRequest::Unsupported { .. }));
+        assert!(parse_app_request(&json!({"method": "disconnect"})).is_err());
+        let malformed = json!({"method": "sendTransaction", "params": ["{"], "id": 5});
+        assert!(parse_app_request(&malformed).is_err());
+        assert_eq!(request_id(&malformed).as_deref(), Some("5"));
+        assert_eq!(request_id(&json!({"method": "disconnect"})), None);
+
+        assert_eq!(response_error("4", ERROR_METHOD_NOT_SUPPORTED, "Method not supported"),
+            json!({"error": {"code": 400, "message": "Method not supported"}, "id": "4"}));
+        assert_eq!(connect_error_event(2, ERROR_USER_DECLINED, "User declined")["payload"]["code"], 300);
+
+        let address = crate::chains::ton::wallet_v4r2_address(&[7u8; 32]).unwrap();
+        let init = crate::chains::ton::wallet_v4r2_state_init(&[7u8; 32]).unwrap();
+        let item = ton_addr_item(&address, &[7u8; 32], &init, false);
+        assert_eq!(item["network"], "-239");
+        assert_eq!(item["address"], address.to_raw_string());
+        let state_init = decode_base64(item["walletStateInit"].as_str().unwrap()).unwrap();
+        assert_eq!(Cell::from_boc(&state_init).unwrap().hash(), address.hash);
+        assert_eq!(connect_event(1, vec![item], device_info("0.1.0"))["payload"]["device"]["maxProtocolVersion"], 2);
+    }
+
+    #[test]
+    fn test_connect_items() {
+        let manifest = AppManifest { url: "https://app.example".into(), name: "App".into(), icon_url: "https://app.example/i.png".into() };
+        let mut request = ConnectRequest { manifest_url: "https://app.example/m.json".into(), items: vec![ConnectItem::TonAddr] };
+        let items = connect_items(&[5u8; 32], WalletVersion::V4R2, &request, &manifest, true, 1_700_000_000).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["network"], NETWORK_TESTNET);
+
+        // The reply describes the chosen wallet contract
+        let public_key = ed25519_dalek::SigningKey::from_bytes(&[5u8; 32]).verifying_key().to_bytes();
+        let items = connect_items(&[5u8; 32], WalletVersion::V5R1, &request, &manifest, false, 1_700_000_000).unwrap();
+        let v5r1 = WalletVersion::V5R1.address(&public_key, false).unwrap();
+        assert_eq!(items[0]["address"], v5r1.to_raw_string());
+        let state_init = decode_base64(items[0]["walletStateInit"].as_str().unwrap()).unwrap();
+        assert_eq!(Cell::from_boc(&state_init).unwrap().hash(), v5r1.hash);
+
+        request.items.push(ConnectItem::TonProof { payload: "abc".into() });
+        let items = connect_items(&[5u8; 32], WalletVersion::V4R2, &request, &manifest, false, 1_700_000_000).unwrap();
+        let proof = &items[1]["proof"];
+        assert_eq!(proof["domain"]["value"], "app.example");
+        assert_eq!(proof["payload"], "abc");
+        assert_eq!(proof["timestamp"], 1_700_000_000u64);
+    }
+}
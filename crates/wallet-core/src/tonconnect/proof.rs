@@ -0,0 +1,101 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tonconnect/proof: `ton_proof` signature
+//
+// message = "ton-proof-item-v2/" ++ workchain (u32 BE) ++ address hash
+//           ++ domain length (u32 LE) ++ domain ++ timestamp (u64 LE) ++ payload
+// signature = Ed25519(sha256(0xffff ++ "ton-connect" ++ sha256(message)))
+
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::chains::ton::TonAddress;
+
+const PROOF_PREFIX: &[u8] = b"ton-proof-item-v2/";
+const CONNECT_PREFIX: &[u8] = b"ton-connect";
+
+/// What the wallet proves: control of its address for `domain` at `timestamp`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TonProof {
+    pub timestamp: u64,
+    pub domain: String,
+    pub payload: String,
+}
+
+impl TonProof {
+    /// Proof message for `address`
+    pub fn message(&self, address: &TonAddress) -> Vec<u8> {
+        let mut message = PROOF_PREFIX.to_vec();
+        message.extend_from_slice(&(address.workchain as i32).to_be_bytes());
+        message.extend_from_slice(&address.hash);
+        message.extend_from_slice(&(self.domain.len() as u32).to_le_bytes());
+        message.extend_from_slice(self.domain.as_bytes());
+        message.extend_from_slice(&self.timestamp.to_le_bytes());
+        message.extend_from_slice(self.payload.as_bytes());
+        message
+    }
+
+    /// Hash the wallet signs
+    pub fn signing_hash(&self, address: &TonAddress) -> [u8; 32] {
+        let mut full = vec![0xff, 0xff];
+        full.extend_from_slice(CONNECT_PREFIX);
+        full.extend_from_slice(&Sha256::digest(self.message(address)));
+        Sha256::digest(&full).into()
+    }
+
+    /// Sign with the wallet key
+    pub fn sign(&self, private_key: &[u8; 32], address: &TonAddress) -> [u8; 64] {
+        SigningKey::from_bytes(private_key).sign(&self.signing_hash(address)).to_bytes()
+    }
+
+    /// `ton_proof` reply item with the signature
+    pub fn item(&self, signature: &[u8; 64]) -> Value {
+        json!({
+            "name": "ton_proof",
+            "proof": {
+                "timestamp": self.timestamp,
+                "domain": {"lengthBytes": self.domain.len(), "value": self.domain},
+                "signature": crate::backup::base64_encode(signature),
+                "payload": self.payload,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Verifier;
+
+    #[test]
+    fn test_ton_proof() {
+        let address = TonAddress { workchain: -1, hash: [0xab; 32], bounceable: true, testnet: false };
+        let proof = TonProof { timestamp: 0x0102030405, domain: "app.example".into(), payload: "nonce".into() };
+
+        let message = proof.message(&address);
+        assert_eq!(&message[..18], PROOF_PREFIX);
+        assert_eq!(&message[18..22], &[0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(&message[22..54], &[0xab; 32]);
+        assert_eq!(&message[54..58], &[11, 0, 0, 0]);
+        assert_eq!(&message[58..69], b"app.example");
+        assert_eq!(&message[69..77], &[5, 4, 3, 2, 1, 0, 0, 0]);
+        assert_eq!(&message[77..], b"nonce");
+
+        let mut full = vec![0xff, 0xff];
+        full.extend_from_slice(b"ton-connect");
+        full.extend_from_slice(&Sha256::digest(&message));
+        assert_eq!(proof.signing_hash(&address), <[u8; 32]>::from(Sha256::digest(&full)));
+
+        let key = [5u8; 32];
+        let signature = proof.sign(&key, &address);
+        let verifying = SigningKey::from_bytes(&key).verifying_key();
+        assert!(verifying.verify(&proof.signing_hash(&address), &ed25519_dalek::Signature::from_bytes(&signature)).is_ok());
+
+        let item = proof.item(&signature);
+        assert_eq!(item["proof"]["domain"]["lengthBytes"], 11);
+        assert_eq!(item["proof"]["timestamp"], 0x0102030405u64);
+        assert_eq!(crate::backup::base64_decode(item["proof"]["signature"].as_str().unwrap()).unwrap(), signature.to_vec());
+    }
+}
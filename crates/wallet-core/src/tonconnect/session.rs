@@ -0,0 +1,137 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tonconnect/session: per-dApp session keys and message encryption
+//
+// Each side of a session holds an X25519 key pair; its client id is the hex
+// public key. Bridge messages are NaCl `box` ciphertexts (X25519 +
+// XSalsa20-Poly1305) prefixed with their random 24-byte nonce.
+
+use crypto_box::aead::{Aead, AeadCore};
+use crypto_box::{Nonce, PublicKey, SalsaBox, SecretKey};
+
+const NONCE_LEN: usize = 24;
+
+/// Wallet side of a session with one dApp
+#[derive(Clone)]
+pub struct Session {
+    secret: SecretKey,
+    app_public_key: PublicKey,
+}
+
+impl Session {
+    /// New session with a fresh key pair for the dApp with `app_client_id`
+    pub fn new(app_client_id: &str) -> Result<Self, String> {
+        let secret = SecretKey::generate(&mut rand::thread_rng());
+        Ok(Session { secret, app_public_key: parse_client_id(app_client_id)? })
+    }
+
+    /// Session restored from its stored secret key (hex)
+    pub fn restore(secret_hex: &str, app_client_id: &str) -> Result<Self, String> {
+        let secret: [u8; 32] = hex::decode(secret_hex).ok()
+            .and_then(|s| s.try_into().ok())
+            .ok_or("Invalid session key")?;
+        Ok(Session { secret: SecretKey::from(secret), app_public_key: parse_client_id(app_client_id)? })
+    }
+
+    /// Secret key (hex) to persist the session
+    pub fn secret_hex(&self) -> String {
+        hex::encode(self.secret.to_bytes())
+    }
+
+    /// Our client id: hex of the session public key
+    pub fn client_id(&self) -> String {
+        hex::encode(self.secret.public_key().as_bytes())
+    }
+
+    /// The dApp's client id
+    pub fn app_client_id(&self) -> String {
+        hex::encode(self.app_public_key.as_bytes())
+    }
+
+    /// Encrypt for the dApp: nonce || box(plaintext)
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        let nonce = SalsaBox::generate_nonce(&mut rand::thread_rng());
+        let ciphertext = SalsaBox::new(&self.app_public_key, &self.secret)
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| "Encryption failed")?;
+        let mut out = nonce.to_vec();
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// Decrypt a message from the dApp
+    pub fn decrypt(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        if message.len() < NONCE_LEN {
+            return Err("Message too short".into());
+        }
+        let (nonce, ciphertext) = message.split_at(NONCE_LEN);
+        SalsaBox::new(&self.app_public_key, &self.secret)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "Cannot decrypt message".into())
+    }
+}
+
+fn parse_client_id(client_id: &str) -> Result<PublicKey, String> {
+    let key: [u8; 32] = hex::decode(client_id).ok()
+        .and_then(|k| k.try_into().ok())
+        .ok_or("Invalid client id")?;
+    Ok(PublicKey::from(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_roundtrip() {
+        let app_secret = SecretKey::from([3u8; 32]);
+        let app_id = hex::encode(app_secret.public_key().as_bytes());
+        let wallet = Session::new(&app_id).unwrap();
+        assert_eq!(wallet.app_client_id(), app_id);
+
+        // The dApp sees the wallet as a session in the other direction
+        let app = Session { secret: app_secret, app_public_key: parse_client_id(&wallet.client_id()).unwrap() };
+        let sealed = wallet.encrypt(b"{\"event\":\"connect\"}").unwrap();
+        assert_eq!(sealed.len(), NONCE_LEN + 19 + 16);
+        assert_eq!(app.decrypt(&sealed).unwrap(), b"{\"event\":\"connect\"}");
+        assert_eq!(wallet.decrypt(&app.encrypt(b"reply").unwrap()).unwrap(), b"reply");
+
+        // Restored sessions keep their keys; tampered messages are rejected
+        let restored = Session::restore(&wallet.secret_hex(), &app_id).unwrap();
+        assert_eq!(restored.client_id(), wallet.client_id());
+        let mut tampered = app.encrypt(b"reply").unwrap();
+        tampered[30] ^= 1;
+        assert!(restored.decrypt(&tampered).is_err());
+        assert!(restored.decrypt(&[0; 10]).is_err());
+        assert!(Session::new("zz").is_err());
+    }
+
+    #[test]
+    fn test_nacl_box_vector() {
+        // crypto_box test vector from "Cryptography in NaCl" (Bernstein), section 10
+        let alice_sk: [u8; 32] = hex::decode("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a").unwrap().try_into().unwrap();
+        let bob_pk: [u8; 32] = hex::decode("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f").unwrap().try_into().unwrap();
+        let nonce = hex::decode("69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37").unwrap();
+        let message = hex::decode(concat!(
+            "be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffce5ecbaaf33bd751a1ac728d45e6c6129",
+            "6cdc3c01233561f41db66cce314adb310e3be8250c46f06dceea3a7fa1348057e2f6556ad6b1318a024a838f21af1fde",
+            "048977eb48f59ffd4924ca1c60902e52f0a089bc76897040e082f937763848645e0705",
+        )).unwrap();
+        let expected = hex::decode(concat!(
+            "f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce48332ea7164d96a4476fb8c531a1186a",
+            "c0dfc17c98dce87b4da7f011ec48c97271d2c20f9b928fe2270d6fb863d51738b48eeee314a7cc8ab932164548e526ae",
+            "90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de56244a9e88d5f9b37973f622a43d14a6599b1f654cb45a74",
+            "e355a5",
+        )).unwrap();
+        let session = Session { secret: SecretKey::from(alice_sk), app_public_key: PublicKey::from(bob_pk) };
+        let ciphertext = SalsaBox::new(&session.app_public_key, &session.secret)
+            .encrypt(Nonce::from_slice(&nonce), message.as_slice())
+            .unwrap();
+        assert_eq!(hex::encode(&ciphertext), hex::encode(&expected));
+
+        let mut sealed = nonce.clone();
+        sealed.extend_from_slice(&ciphertext);
+        assert_eq!(session.decrypt(&sealed).unwrap(), message);
+    }
+}
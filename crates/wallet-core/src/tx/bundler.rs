@@ -0,0 +1,239 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/bundler: ERC-4337 bundler JSON-RPC — request bodies and response parsing.
+// No transport here: callers POST the request bodies to their bundler endpoint,
+// which keeps the client testable against an in-process bundler stand-in.
+
+use serde_json::{json, Value};
+
+use super::erc4337::{UserOpGasEstimate, UserOperation, DUMMY_SIGNATURE};
+
+/// Outcome of a mined UserOperation (eth_getUserOperationReceipt)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserOpReceipt {
+    pub user_op_hash: String,
+    pub success: bool,
+    /// Hash of the bundle transaction that included the operation
+    pub transaction_hash: String,
+    pub actual_gas_cost: u128,
+}
+
+fn request(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params })
+}
+
+/// eth_sendUserOperation(op, entryPoint)
+pub fn send_user_operation_request(op: &UserOperation) -> Value {
+    request("eth_sendUserOperation", json!([op.to_rpc_json(), op.version().entry_point()]))
+}
+
+/// eth_estimateUserOperationGas(op, entryPoint); unsigned ops get the dummy signature
+pub fn estimate_user_operation_gas_request(op: &UserOperation) -> Value {
+    let mut op_json = op.to_rpc_json();
+    if op.signature().is_empty() {
+        op_json["signature"] = json!(DUMMY_SIGNATURE);
+    }
+    request("eth_estimateUserOperationGas", json!([op_json, op.version().entry_point()]))
+}
+
+/// eth_getUserOperationReceipt(userOpHash)
+pub fn get_user_operation_receipt_request(user_op_hash: &str) -> Value {
+    request("eth_getUserOperationReceipt", json!([user_op_hash]))
+}
+
+/// eth_supportedEntryPoints()
+pub fn supported_entry_points_request() -> Value {
+    request("eth_supportedEntryPoints", json!([]))
+}
+
+/// The `result` of a JSON-RPC response, or the bundler's error message
+pub fn response_result(response: &Value) -> Result<&Value, String> {
+    if let Some(err) = response.get("error") {
+        let message = err.get("message").and_then(|m| m.as_str()).unwrap_or("unknown error");
+        return Err(match err.get("code").and_then(|c| c.as_i64()) {
+            Some(code) => format!("Bundler error {}: {}", code, message),
+            None => format!("Bundler error: {}", message),
+        });
+    }
+    response.get("result").ok_or_else(|| "Missing result in bundler response".into())
+}
+
+/// Quantities come back as hex strings from most bundlers, as JSON numbers from some
+fn parse_quantity(value: Option<&Value>, field: &str) -> Result<u128, String> {
+    match value {
+        Some(Value::String(s)) => match s.strip_prefix("0x") {
+            Some(hex) => u128::from_str_radix(hex, 16),
+            None => s.parse(),
+        }.map_err(|_| format!("Invalid {}: {}", field, s)),
+        Some(Value::Number(n)) => n.as_u64().map(u128::from).ok_or_else(|| format!("Invalid {}", field)),
+        _ => Err(format!("Missing {}", field)),
+    }
+}
+
+/// userOpHash returned by eth_sendUserOperation
+pub fn parse_send_response(response: &Value) -> Result<String, String> {
+    let hash = response_result(response)?.as_str().ok_or("userOpHash is not a string")?;
+    let hex = hash.strip_prefix("0x").ok_or("userOpHash must be 0x-prefixed")?;
+    if hex.len() != 64 || hex::decode(hex).is_err() {
+        return Err(format!("Invalid userOpHash: {}", hash));
+    }
+    Ok(hash.to_string())
+}
+
+pub fn parse_gas_estimate(response: &Value) -> Result<UserOpGasEstimate, String> {
+    let r = response_result(response)?;
+    let optional = |field: &str| r.get(field).map(|v| parse_quantity(Some(v), field)).transpose();
+    Ok(UserOpGasEstimate {
+        pre_verification_gas: parse_quantity(r.get("preVerificationGas"), "preVerificationGas")?,
+        verification_gas_limit: parse_quantity(r.get("verificationGasLimit"), "verificationGasLimit")?,
+        call_gas_limit: parse_quantity(r.get("callGasLimit"), "callGasLimit")?,
+        paymaster_verification_gas_limit: optional("paymasterVerificationGasLimit")?,
+        paymaster_post_op_gas_limit: optional("paymasterPostOpGasLimit")?,
+    })
+}
+
+/// `None` while the operation is still pending
+pub fn parse_receipt(response: &Value) -> Result<Option<UserOpReceipt>, String> {
+    let r = response_result(response)?;
+    if r.is_null() {
+        return Ok(None);
+    }
+    let transaction_hash = r.get("receipt")
+        .and_then(|rc| rc.get("transactionHash"))
+        .and_then(|h| h.as_str())
+        .ok_or("Missing receipt.transactionHash")?
+        .to_string();
+    Ok(Some(UserOpReceipt {
+        user_op_hash: r.get("userOpHash").and_then(|h| h.as_str()).unwrap_or_default().to_string(),
+        success: r.get("success").and_then(|s| s.as_bool()).ok_or("Missing success")?,
+        transaction_hash,
+        actual_gas_cost: parse_quantity(r.get("actualGasCost"), "actualGasCost")?,
+    }))
+}
+
+pub fn parse_supported_entry_points(response: &Value) -> Result<Vec<String>, String> {
+    response_result(response)?
+        .as_array()
+        .ok_or("eth_supportedEntryPoints result is not an array")?
+        .iter()
+        .map(|v| v.as_str().map(|s| s.to_string()).ok_or_else(|| "Invalid entry point".to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::personal_sign::recover_address;
+    use crate::tx::erc4337::{encode_execute, EntryPointVersion};
+    use crate::tx::evm::parse_address;
+    use crate::tx::evm_fees::Eip1559Fees;
+
+    // Hardhat account #0 (DO NOT use in production)
+    const TEST_KEY: [u8; 32] = [
+        0xac, 0x09, 0x74, 0xbe, 0xc3, 0x9a, 0x17, 0xe3,
+        0x6b, 0xa4, 0xa6, 0xb4, 0xd2, 0x38, 0xff, 0x94,
+        0x4b, 0xac, 0xb4, 0x78, 0xcb, 0xed, 0x5e, 0xfc,
+        0xae, 0x78, 0x4d, 0x7b, 0xf4, 0xf2, 0xff, 0x80,
+    ];
+    const TEST_OWNER: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+    const CHAIN_ID: u64 = 31337;
+
+    /// In-process bundler stand-in: validates requests like a real bundler would
+    /// (entry point, owner signature over the userOpHash) and answers in its wire format.
+    struct LocalBundler {
+        owner: [u8; 20],
+        sent: Vec<Value>,
+    }
+
+    impl LocalBundler {
+        fn handle(&mut self, req: &Value, op: &UserOperation) -> Value {
+            let params = req["params"].as_array().unwrap();
+            let entry_point = params.get(1).and_then(|v| v.as_str()).unwrap_or_default();
+            if !entry_point.eq_ignore_ascii_case(op.version().entry_point()) {
+                return json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32602, "message": "unsupported entrypoint"}});
+            }
+            match req["method"].as_str().unwrap() {
+                "eth_estimateUserOperationGas" => {
+                    assert_eq!(params[0]["signature"], DUMMY_SIGNATURE);
+                    json!({"jsonrpc": "2.0", "id": 1, "result": {
+                        "preVerificationGas": "0xb708",
+                        "verificationGasLimit": "0x186a0",
+                        "callGasLimit": 21000,
+                    }})
+                }
+                "eth_sendUserOperation" => {
+                    let ep = parse_address(entry_point).unwrap();
+                    let hash = op.hash(&ep, CHAIN_ID);
+                    let sig: [u8; 65] = match op.signature().try_into() {
+                        Ok(s) => s,
+                        Err(_) => return json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32507, "message": "invalid signature length"}}),
+                    };
+                    if recover_address(&hash, &sig).ok() != Some(self.owner) {
+                        return json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32507, "message": "AA24 signature error"}});
+                    }
+                    self.sent.push(params[0].clone());
+                    json!({"jsonrpc": "2.0", "id": 1, "result": format!("0x{}", hex::encode(hash))})
+                }
+                other => panic!("unexpected method {}", other),
+            }
+        }
+    }
+
+    fn unsigned_op(version: EntryPointVersion) -> UserOperation {
+        let mut op = UserOperation::new(version, [0x42; 20], [0; 32], encode_execute(&[0x11; 20], 1, &[]));
+        op.set_fees(Eip1559Fees { max_fee_per_gas: 2_000_000_000, max_priority_fee_per_gas: 1_000_000_000 });
+        op
+    }
+
+    #[test]
+    fn test_estimate_sign_send_against_local_bundler() {
+        for version in [EntryPointVersion::V06, EntryPointVersion::V07] {
+            let mut bundler = LocalBundler { owner: parse_address(TEST_OWNER).unwrap(), sent: vec![] };
+            let mut op = unsigned_op(version);
+
+            let est_resp = bundler.handle(&estimate_user_operation_gas_request(&op), &op);
+            let est = parse_gas_estimate(&est_resp).unwrap();
+            assert_eq!(est.pre_verification_gas, 0xb708);
+            assert_eq!(est.call_gas_limit, 21000);
+            op.apply_gas_estimate(&est);
+
+            let hash = op.sign(&TEST_KEY, CHAIN_ID).unwrap();
+            let send_resp = bundler.handle(&send_user_operation_request(&op), &op);
+            assert_eq!(parse_send_response(&send_resp).unwrap(), format!("0x{}", hex::encode(hash)));
+            assert_eq!(bundler.sent.len(), 1);
+            assert_eq!(bundler.sent[0]["callGasLimit"], "0x5208");
+        }
+    }
+
+    #[test]
+    fn test_bundler_rejects_wrong_signer() {
+        let mut bundler = LocalBundler { owner: [0x01; 20], sent: vec![] };
+        let mut op = unsigned_op(EntryPointVersion::V07);
+        op.sign(&TEST_KEY, CHAIN_ID).unwrap();
+        let resp = bundler.handle(&send_user_operation_request(&op), &op);
+        let err = parse_send_response(&resp).unwrap_err();
+        assert!(err.contains("-32507"));
+        assert!(err.contains("AA24"));
+    }
+
+    #[test]
+    fn test_parse_receipt_and_entry_points() {
+        assert_eq!(parse_receipt(&json!({"result": null})).unwrap(), None);
+        let receipt = parse_receipt(&json!({"result": {
+            "userOpHash": "0xabc",
+            "success": true,
+            "actualGasCost": "0x2386f26fc10000",
+            "receipt": {"transactionHash": "0xdef"}
+        }})).unwrap().unwrap();
+        assert!(receipt.success);
+        assert_eq!(receipt.transaction_hash, "0xdef");
+        assert_eq!(receipt.actual_gas_cost, 10_000_000_000_000_000);
+
+        let eps = parse_supported_entry_points(&json!({"result": [crate::tx::erc4337::ENTRY_POINT_V07]})).unwrap();
+        assert_eq!(EntryPointVersion::from_entry_point(&eps[0]), Some(EntryPointVersion::V07));
+
+        assert!(parse_send_response(&json!({"result": "0x1234"})).is_err());
+        assert!(response_result(&json!({"error": {"message": "boom"}})).unwrap_err().contains("boom"));
+    }
+}
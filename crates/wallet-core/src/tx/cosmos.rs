@@ -113,7 +113,7 @@ impl CosmosMsgSend {
         let tx_bytes = tx_json.to_string().into_bytes();
 
         // Tx hash = SHA256 of the sign doc
-        let tx_hash = format!("0x{}", hex::encode(&hash_bytes));
+        let tx_hash = format!("0x{}", hex::encode(hash_bytes));
 
         Ok(SignedTransaction {
             chain_id,
@@ -224,7 +224,7 @@ impl CosmosMsgExecuteContract {
         });
 
         let tx_bytes = tx_json.to_string().into_bytes();
-        let tx_hash = format!("0x{}", hex::encode(&hash_bytes));
+        let tx_hash = format!("0x{}", hex::encode(hash_bytes));
 
         Ok(SignedTransaction {
             chain_id,
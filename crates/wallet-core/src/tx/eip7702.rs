@@ -0,0 +1,331 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/eip7702: EIP-7702 set-code authorizations — signing, RLP encoding and delegate review
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tiny_keccak::{Hasher, Keccak};
+
+use super::evm::{parse_address, rlp_encode_bytes, rlp_encode_signature, rlp_encode_u64, rlp_wrap_list_payload, sign_prehash};
+use crate::chains::evm::eip55_checksum;
+
+/// EIP-2718 type of set-code transactions
+pub const SET_CODE_TX_TYPE: u8 = 0x04;
+
+/// Domain byte of the authorization hash: keccak256(0x05 || rlp([chain_id, address, nonce]))
+pub const AUTHORIZATION_MAGIC: u8 = 0x05;
+
+/// Intrinsic gas charged per authorization (PER_EMPTY_ACCOUNT_COST).
+/// eth_estimateGas on a plain call does not include it.
+pub const PER_AUTHORIZATION_GAS: u64 = 25_000;
+
+/// Audited delegation targets (same address on every chain they are deployed to)
+pub const KNOWN_DELEGATES: &[(&str, &str)] = &[
+    ("0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B", "MetaMask Delegator"),
+    ("0x4Cd241E8d1510e30b2076397afc7508Ae59C66c9", "Simple7702Account (ERC-4337 v0.8)"),
+    ("0x000000009B1D0aF20D8C6d0A44e162d11F9b8f00", "Uniswap Calibur"),
+    ("0x69007702764179f14F51cdce752f4f775d74E139", "Alchemy Modular Account"),
+];
+
+/// Unsigned authorization tuple: let `address`'s code run for the signing account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Authorization {
+    /// 0 = valid on every chain
+    pub chain_id: u64,
+    pub address: [u8; 20],
+    pub nonce: u64,
+}
+
+impl Authorization {
+    fn rlp_fields(&self) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.chain_id)));
+        payload.extend_from_slice(&rlp_encode_bytes(&self.address));
+        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.nonce)));
+        payload
+    }
+
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut hasher = Keccak::v256();
+        let mut hash = [0u8; 32];
+        hasher.update(&[AUTHORIZATION_MAGIC]);
+        hasher.update(&rlp_wrap_list_payload(&self.rlp_fields()));
+        hasher.finalize(&mut hash);
+        hash
+    }
+
+    pub fn sign(&self, private_key: &[u8; 32]) -> Result<SignedAuthorization, String> {
+        let (y_parity, r, s) = sign_prehash(&self.signing_hash(), private_key)?;
+        Ok(SignedAuthorization { authorization: self.clone(), y_parity, r, s })
+    }
+}
+
+/// Authorization tuple with the authority's signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedAuthorization {
+    pub authorization: Authorization,
+    pub y_parity: u8,
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+}
+
+impl SignedAuthorization {
+    /// rlp([chain_id, address, nonce, y_parity, r, s])
+    pub fn rlp(&self) -> Vec<u8> {
+        let mut payload = self.authorization.rlp_fields();
+        payload.extend_from_slice(&rlp_encode_signature(self.y_parity, &self.r, &self.s));
+        rlp_wrap_list_payload(&payload)
+    }
+}
+
+/// RLP list of signed authorizations, as placed after the access list of a type-4 transaction
+pub fn encode_authorization_list(list: &[SignedAuthorization]) -> Vec<u8> {
+    let payload: Vec<u8> = list.iter().flat_map(|a| a.rlp()).collect();
+    rlp_wrap_list_payload(&payload)
+}
+
+/// An `authorizationList` entry from a dApp request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthorizationEntry {
+    /// To be signed by the wallet; a missing nonce is filled in at signing time
+    Unsigned { chain_id: u64, address: [u8; 20], nonce: Option<u64> },
+    /// Already signed by another account (sponsored delegation)
+    Signed(SignedAuthorization),
+}
+
+impl AuthorizationEntry {
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            AuthorizationEntry::Unsigned { chain_id, .. } => *chain_id,
+            AuthorizationEntry::Signed(s) => s.authorization.chain_id,
+        }
+    }
+
+    pub fn address(&self) -> [u8; 20] {
+        match self {
+            AuthorizationEntry::Unsigned { address, .. } => *address,
+            AuthorizationEntry::Signed(s) => s.authorization.address,
+        }
+    }
+}
+
+fn quantity(v: &Value) -> Option<u64> {
+    match v {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => match s.strip_prefix("0x") {
+            Some("") => Some(0),
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => s.parse().ok(),
+        },
+        _ => None,
+    }
+}
+
+fn word(v: &Value) -> Option<[u8; 32]> {
+    let hex = v.as_str()?.strip_prefix("0x")?;
+    let bytes = hex::decode(format!("{:0>64}", hex)).ok()?;
+    bytes.try_into().ok()
+}
+
+/// Parse the `authorizationList` of an eth_sendTransaction object (viem/ethers field names).
+/// Returns an empty list when the field is absent.
+pub fn parse_authorization_list(tx: &Value) -> Result<Vec<AuthorizationEntry>, String> {
+    let Some(list) = tx.get("authorizationList") else { return Ok(Vec::new()) };
+    let list = list.as_array().ok_or("authorizationList must be an array")?;
+    list.iter().map(|item| {
+        let chain_id = quantity(&item["chainId"]).ok_or("Invalid authorization chainId")?;
+        let address = item.get("address")
+            .or_else(|| item.get("contractAddress"))
+            .and_then(|a| a.as_str())
+            .ok_or("Missing authorization address")?;
+        let address = parse_address(address)?;
+        let nonce = match item.get("nonce") {
+            None | Some(Value::Null) => None,
+            Some(n) => Some(quantity(n).ok_or("Invalid authorization nonce")?),
+        };
+        let (Some(r), Some(s)) = (word(&item["r"]), word(&item["s"])) else {
+            return Ok(AuthorizationEntry::Unsigned { chain_id, address, nonce });
+        };
+        let y_parity = quantity(&item["yParity"])
+            .or_else(|| quantity(&item["v"]).map(|v| if v >= 27 { v - 27 } else { v }))
+            .filter(|y| *y <= 1)
+            .ok_or("Invalid authorization yParity")? as u8;
+        let nonce = nonce.ok_or("Signed authorization without nonce")?;
+        Ok(AuthorizationEntry::Signed(SignedAuthorization {
+            authorization: Authorization { chain_id, address, nonce },
+            y_parity,
+            r,
+            s,
+        }))
+    }).collect()
+}
+
+/// Sign the wallet's own entries and pass pre-signed ones through.
+/// The sender's nonce is bumped before authorizations are applied, so a
+/// self-authorization sent in the same transaction must use `tx_nonce + 1`.
+pub fn sign_entries(
+    entries: &[AuthorizationEntry],
+    tx_chain_id: u64,
+    tx_nonce: u64,
+    private_key: &[u8; 32],
+) -> Result<Vec<SignedAuthorization>, String> {
+    entries.iter().map(|entry| {
+        if entry.chain_id() != 0 && entry.chain_id() != tx_chain_id {
+            return Err(format!("Authorization is for chain {}, not {}", entry.chain_id(), tx_chain_id));
+        }
+        match entry {
+            AuthorizationEntry::Unsigned { chain_id, address, nonce } => Authorization {
+                chain_id: *chain_id,
+                address: *address,
+                nonce: nonce.unwrap_or(tx_nonce + 1),
+            }.sign(private_key),
+            AuthorizationEntry::Signed(signed) => Ok(signed.clone()),
+        }
+    }).collect()
+}
+
+/// What an authorization would do, for display before signing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelegationReview {
+    /// EIP-55 delegation target
+    pub delegate: String,
+    pub delegate_name: Option<&'static str>,
+    /// Delegating to the zero address clears an existing delegation
+    pub revokes: bool,
+    /// chain_id 0: the signature is replayable on every EVM chain
+    pub any_chain: bool,
+    /// Signed by another account; the wallet only relays it
+    pub presigned: bool,
+}
+
+impl DelegationReview {
+    /// Unknown delegate code, or an all-chain authorization, can drain the account
+    pub fn is_high_risk(&self) -> bool {
+        !self.revokes && (self.delegate_name.is_none() || self.any_chain)
+    }
+}
+
+pub fn known_delegate(address: &[u8; 20]) -> Option<&'static str> {
+    let checksummed = eip55_checksum(address);
+    KNOWN_DELEGATES.iter()
+        .find(|(a, _)| a.eq_ignore_ascii_case(&checksummed))
+        .map(|(_, name)| *name)
+}
+
+pub fn review(entry: &AuthorizationEntry) -> DelegationReview {
+    let address = entry.address();
+    DelegationReview {
+        delegate: eip55_checksum(&address),
+        delegate_name: known_delegate(&address),
+        revokes: address == [0u8; 20],
+        any_chain: entry.chain_id() == 0,
+        presigned: matches!(entry, AuthorizationEntry::Signed(_)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::evm::EvmTransaction;
+    use crate::chains::ChainId;
+    use serde_json::json;
+
+    const METAMASK: &str = "0x63c0c19a282a1B52b07dD5a65b58948A07DAE32B";
+
+    #[test]
+    fn test_authorization_hash_layout() {
+        let auth = Authorization { chain_id: 1, address: [0x11; 20], nonce: 0 };
+        let mut preimage = vec![AUTHORIZATION_MAGIC];
+        // rlp([1, 0x11.., 0x80]) = 0xd7 01 94 <20 bytes> 80
+        preimage.extend_from_slice(&[0xd7, 0x01, 0x94]);
+        preimage.extend_from_slice(&[0x11; 20]);
+        preimage.push(0x80);
+        let mut hasher = Keccak::v256();
+        let mut expected = [0u8; 32];
+        hasher.update(&preimage);
+        hasher.finalize(&mut expected);
+        assert_eq!(auth.signing_hash(), expected);
+    }
+
+    #[test]
+    fn test_sign_and_recover_authority() {
+        use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+        let key = [7u8; 32];
+        let signed = Authorization { chain_id: 8453, address: parse_address(METAMASK).unwrap(), nonce: 3 }
+            .sign(&key)
+            .unwrap();
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&signed.r);
+        sig[32..].copy_from_slice(&signed.s);
+        let recovered = VerifyingKey::recover_from_prehash(
+            &signed.authorization.signing_hash(),
+            &Signature::from_slice(&sig).unwrap(),
+            RecoveryId::from_byte(signed.y_parity).unwrap(),
+        ).unwrap();
+        let expected = k256::ecdsa::SigningKey::from_bytes((&key).into()).unwrap();
+        assert_eq!(&recovered, expected.verifying_key());
+    }
+
+    #[test]
+    fn test_parse_and_sign_entries() {
+        let tx = json!({
+            "authorizationList": [
+                { "chainId": "0x1", "address": METAMASK },
+                { "chainId": 1, "contractAddress": METAMASK, "nonce": "0x9",
+                  "r": "0x01", "s": "0x02", "yParity": "0x1" },
+            ]
+        });
+        let entries = parse_authorization_list(&tx).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(entries[0], AuthorizationEntry::Unsigned { nonce: None, .. }));
+        assert!(matches!(&entries[1], AuthorizationEntry::Signed(s) if s.y_parity == 1 && s.r[31] == 1));
+        assert!(parse_authorization_list(&json!({})).unwrap().is_empty());
+
+        let signed = sign_entries(&entries, 1, 4, &[7u8; 32]).unwrap();
+        assert_eq!(signed[0].authorization.nonce, 5);
+        assert_eq!(signed[1].authorization.nonce, 9);
+        assert!(sign_entries(&entries, 10, 4, &[7u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_review() {
+        let known = AuthorizationEntry::Unsigned { chain_id: 1, address: parse_address(METAMASK).unwrap(), nonce: None };
+        let r = review(&known);
+        assert_eq!(r.delegate_name, Some("MetaMask Delegator"));
+        assert!(!r.is_high_risk());
+
+        let unknown = AuthorizationEntry::Unsigned { chain_id: 1, address: [0x42; 20], nonce: None };
+        assert!(review(&unknown).is_high_risk());
+
+        let any_chain = AuthorizationEntry::Unsigned { chain_id: 0, address: parse_address(METAMASK).unwrap(), nonce: None };
+        assert!(review(&any_chain).is_high_risk());
+
+        let revoke = AuthorizationEntry::Unsigned { chain_id: 1, address: [0u8; 20], nonce: None };
+        assert!(review(&revoke).revokes);
+        assert!(!review(&revoke).is_high_risk());
+    }
+
+    #[test]
+    fn test_type4_transaction() {
+        let auth = Authorization { chain_id: 1, address: [0x11; 20], nonce: 1 }.sign(&[7u8; 32]).unwrap();
+        let tx = EvmTransaction {
+            chain_id_num: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: 1,
+            max_fee_per_gas: 2,
+            gas_limit: 100_000,
+            to: [0x22; 20],
+            value: 0,
+            data: vec![],
+            authorization_list: vec![auth.clone()],
+        };
+        let signed = tx.sign(&[7u8; 32], ChainId::Ethereum).unwrap();
+        assert_eq!(signed.raw_bytes[0], SET_CODE_TX_TYPE);
+        let list = encode_authorization_list(&[auth]);
+        assert!(signed.raw_bytes.windows(list.len()).any(|w| w == list.as_slice()));
+
+        let plain = EvmTransaction { authorization_list: vec![], ..tx };
+        assert_eq!(plain.sign(&[7u8; 32], ChainId::Ethereum).unwrap().raw_bytes[0], 0x02);
+    }
+}
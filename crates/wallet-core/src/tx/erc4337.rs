@@ -0,0 +1,693 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/erc4337: ERC-4337 UserOperations (EntryPoint v0.6 and v0.7), userOpHash,
+// owner signatures and counterfactual smart-account addresses (CREATE2)
+
+use serde_json::{json, Value};
+use zeroize::Zeroize;
+
+use super::evm::parse_address;
+use super::evm_fees::Eip1559Fees;
+use crate::signing::keccak256;
+use crate::signing::personal_sign::personal_sign;
+
+/// EntryPoint v0.6 (same address on every chain)
+pub const ENTRY_POINT_V06: &str = "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789";
+/// EntryPoint v0.7 (same address on every chain)
+pub const ENTRY_POINT_V07: &str = "0x0000000071727De22E5E9d8BAf0edAc6f37da032";
+
+/// Well-formed placeholder signature for gas estimation.
+/// ECDSA recovery must not revert during simulation, so an all-zero value won't do.
+pub const DUMMY_SIGNATURE: &str = "0xfffffffffffffffffffffffffffffff0000000000000000000000000000000007aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa1c";
+
+/// SimpleAccount.execute(address,uint256,bytes)
+const SELECTOR_EXECUTE: [u8; 4] = [0xb6, 0x1d, 0x27, 0xf6];
+/// SimpleAccountFactory.createAccount(address,uint256)
+const SELECTOR_CREATE_ACCOUNT: [u8; 4] = [0x5f, 0xbf, 0xb9, 0xcf];
+/// SimpleAccount.initialize(address)
+const SELECTOR_INITIALIZE: [u8; 4] = [0xc4, 0xd6, 0x6d, 0xe8];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPointVersion {
+    V06,
+    V07,
+}
+
+impl EntryPointVersion {
+    pub fn entry_point(&self) -> &'static str {
+        match self {
+            EntryPointVersion::V06 => ENTRY_POINT_V06,
+            EntryPointVersion::V07 => ENTRY_POINT_V07,
+        }
+    }
+
+    /// Version served by a known EntryPoint address
+    pub fn from_entry_point(address: &str) -> Option<Self> {
+        [EntryPointVersion::V06, EntryPointVersion::V07].into_iter()
+            .find(|v| v.entry_point().eq_ignore_ascii_case(address))
+    }
+}
+
+/// UserOperation as accepted by EntryPoint v0.6
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UserOperationV06 {
+    pub sender: [u8; 20],
+    /// uint256 `key << 64 | sequence` (192-bit key), big-endian; see `user_op_nonce`
+    pub nonce: [u8; 32],
+    /// factory address ++ factory calldata, empty once the account is deployed
+    pub init_code: Vec<u8>,
+    pub call_data: Vec<u8>,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub paymaster_and_data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// UserOperation as accepted by EntryPoint v0.7 (unpacked RPC form)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UserOperationV07 {
+    pub sender: [u8; 20],
+    /// uint256 `key << 64 | sequence` (192-bit key), big-endian; see `user_op_nonce`
+    pub nonce: [u8; 32],
+    pub factory: Option<[u8; 20]>,
+    pub factory_data: Vec<u8>,
+    pub call_data: Vec<u8>,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub paymaster: Option<[u8; 20]>,
+    pub paymaster_verification_gas_limit: u128,
+    pub paymaster_post_op_gas_limit: u128,
+    pub paymaster_data: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl UserOperationV07 {
+    /// factory ++ factoryData (empty without a factory)
+    pub fn init_code(&self) -> Vec<u8> {
+        match self.factory {
+            Some(factory) => [factory.as_slice(), &self.factory_data].concat(),
+            None => Vec::new(),
+        }
+    }
+
+    /// paymaster ++ uint128 verification gas ++ uint128 postOp gas ++ paymasterData
+    pub fn paymaster_and_data(&self) -> Vec<u8> {
+        match self.paymaster {
+            Some(paymaster) => [
+                paymaster.as_slice(),
+                &self.paymaster_verification_gas_limit.to_be_bytes(),
+                &self.paymaster_post_op_gas_limit.to_be_bytes(),
+                &self.paymaster_data,
+            ].concat(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Gas fields returned by eth_estimateUserOperationGas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UserOpGasEstimate {
+    pub pre_verification_gas: u128,
+    pub verification_gas_limit: u128,
+    pub call_gas_limit: u128,
+    /// v0.7 only
+    pub paymaster_verification_gas_limit: Option<u128>,
+    /// v0.7 only
+    pub paymaster_post_op_gas_limit: Option<u128>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserOperation {
+    V06(UserOperationV06),
+    V07(UserOperationV07),
+}
+
+impl UserOperation {
+    /// Unsigned operation with zero gas; fill gas and fees before signing
+    pub fn new(version: EntryPointVersion, sender: [u8; 20], nonce: [u8; 32], call_data: Vec<u8>) -> Self {
+        match version {
+            EntryPointVersion::V06 => UserOperation::V06(UserOperationV06 {
+                sender,
+                nonce,
+                call_data,
+                ..Default::default()
+            }),
+            EntryPointVersion::V07 => UserOperation::V07(UserOperationV07 {
+                sender,
+                nonce,
+                call_data,
+                ..Default::default()
+            }),
+        }
+    }
+
+    pub fn version(&self) -> EntryPointVersion {
+        match self {
+            UserOperation::V06(_) => EntryPointVersion::V06,
+            UserOperation::V07(_) => EntryPointVersion::V07,
+        }
+    }
+
+    pub fn sender(&self) -> [u8; 20] {
+        match self {
+            UserOperation::V06(op) => op.sender,
+            UserOperation::V07(op) => op.sender,
+        }
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        match self {
+            UserOperation::V06(op) => &op.signature,
+            UserOperation::V07(op) => &op.signature,
+        }
+    }
+
+    pub fn set_signature(&mut self, signature: Vec<u8>) {
+        match self {
+            UserOperation::V06(op) => op.signature = signature,
+            UserOperation::V07(op) => op.signature = signature,
+        }
+    }
+
+    /// Deploy the account in the same operation (first use of a counterfactual account)
+    pub fn set_factory(&mut self, factory: [u8; 20], factory_data: Vec<u8>) {
+        match self {
+            UserOperation::V06(op) => op.init_code = [factory.as_slice(), &factory_data].concat(),
+            UserOperation::V07(op) => {
+                op.factory = Some(factory);
+                op.factory_data = factory_data;
+            }
+        }
+    }
+
+    pub fn set_fees(&mut self, fees: Eip1559Fees) {
+        match self {
+            UserOperation::V06(op) => {
+                op.max_fee_per_gas = fees.max_fee_per_gas;
+                op.max_priority_fee_per_gas = fees.max_priority_fee_per_gas;
+            }
+            UserOperation::V07(op) => {
+                op.max_fee_per_gas = fees.max_fee_per_gas;
+                op.max_priority_fee_per_gas = fees.max_priority_fee_per_gas;
+            }
+        }
+    }
+
+    pub fn apply_gas_estimate(&mut self, est: &UserOpGasEstimate) {
+        match self {
+            UserOperation::V06(op) => {
+                op.pre_verification_gas = est.pre_verification_gas;
+                op.verification_gas_limit = est.verification_gas_limit;
+                op.call_gas_limit = est.call_gas_limit;
+            }
+            UserOperation::V07(op) => {
+                op.pre_verification_gas = est.pre_verification_gas;
+                op.verification_gas_limit = est.verification_gas_limit;
+                op.call_gas_limit = est.call_gas_limit;
+                if let Some(g) = est.paymaster_verification_gas_limit {
+                    op.paymaster_verification_gas_limit = g;
+                }
+                if let Some(g) = est.paymaster_post_op_gas_limit {
+                    op.paymaster_post_op_gas_limit = g;
+                }
+            }
+        }
+    }
+
+    /// abi.encode of the operation with dynamic fields hashed (signature excluded)
+    fn pack(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 * 10);
+        match self {
+            UserOperation::V06(op) => {
+                out.extend_from_slice(&address_word(&op.sender));
+                out.extend_from_slice(&op.nonce);
+                out.extend_from_slice(&keccak256(&op.init_code));
+                out.extend_from_slice(&keccak256(&op.call_data));
+                out.extend_from_slice(&uint_word(op.call_gas_limit));
+                out.extend_from_slice(&uint_word(op.verification_gas_limit));
+                out.extend_from_slice(&uint_word(op.pre_verification_gas));
+                out.extend_from_slice(&uint_word(op.max_fee_per_gas));
+                out.extend_from_slice(&uint_word(op.max_priority_fee_per_gas));
+                out.extend_from_slice(&keccak256(&op.paymaster_and_data));
+            }
+            UserOperation::V07(op) => {
+                out.extend_from_slice(&address_word(&op.sender));
+                out.extend_from_slice(&op.nonce);
+                out.extend_from_slice(&keccak256(&op.init_code()));
+                out.extend_from_slice(&keccak256(&op.call_data));
+                out.extend_from_slice(&packed_u128_pair(op.verification_gas_limit, op.call_gas_limit));
+                out.extend_from_slice(&uint_word(op.pre_verification_gas));
+                out.extend_from_slice(&packed_u128_pair(op.max_priority_fee_per_gas, op.max_fee_per_gas));
+                out.extend_from_slice(&keccak256(&op.paymaster_and_data()));
+            }
+        }
+        out
+    }
+
+    /// userOpHash = keccak256(abi.encode(keccak256(pack(op)), entryPoint, chainId))
+    pub fn hash(&self, entry_point: &[u8; 20], chain_id: u64) -> [u8; 32] {
+        let mut data = Vec::with_capacity(96);
+        data.extend_from_slice(&keccak256(&self.pack()));
+        data.extend_from_slice(&address_word(entry_point));
+        data.extend_from_slice(&uint_word(chain_id as u128));
+        keccak256(&data)
+    }
+
+    /// Sign as the account owner (SimpleAccount checks an EIP-191 signature over userOpHash).
+    /// Stores the 65-byte signature and returns the userOpHash.
+    pub fn sign(&mut self, private_key: &[u8; 32], chain_id: u64) -> Result<[u8; 32], String> {
+        let entry_point = parse_address(self.version().entry_point())?;
+        let hash = self.hash(&entry_point, chain_id);
+        let signature = personal_sign(&hash, private_key)?;
+        self.set_signature(signature.to_vec());
+        Ok(hash)
+    }
+
+    /// Sign with the wallet's EVM owner key (m/44'/60'/0'/0/0)
+    pub fn sign_with_seed(&mut self, seed: &[u8; 64], chain_id: u64) -> Result<[u8; 32], String> {
+        let mut private_key = crate::chains::evm::get_private_key(seed)?;
+        let result = self.sign(&private_key, chain_id);
+        private_key.zeroize();
+        result
+    }
+
+    /// JSON object for bundler RPC calls (hex quantities, v0.7 uses unpacked fields)
+    pub fn to_rpc_json(&self) -> Value {
+        match self {
+            UserOperation::V06(op) => json!({
+                "sender": hex_bytes(&op.sender),
+                "nonce": hex_word_quantity(&op.nonce),
+                "initCode": hex_bytes(&op.init_code),
+                "callData": hex_bytes(&op.call_data),
+                "callGasLimit": hex_quantity(op.call_gas_limit),
+                "verificationGasLimit": hex_quantity(op.verification_gas_limit),
+                "preVerificationGas": hex_quantity(op.pre_verification_gas),
+                "maxFeePerGas": hex_quantity(op.max_fee_per_gas),
+                "maxPriorityFeePerGas": hex_quantity(op.max_priority_fee_per_gas),
+                "paymasterAndData": hex_bytes(&op.paymaster_and_data),
+                "signature": hex_bytes(&op.signature),
+            }),
+            UserOperation::V07(op) => {
+                let mut v = json!({
+                    "sender": hex_bytes(&op.sender),
+                    "nonce": hex_word_quantity(&op.nonce),
+                    "callData": hex_bytes(&op.call_data),
+                    "callGasLimit": hex_quantity(op.call_gas_limit),
+                    "verificationGasLimit": hex_quantity(op.verification_gas_limit),
+                    "preVerificationGas": hex_quantity(op.pre_verification_gas),
+                    "maxFeePerGas": hex_quantity(op.max_fee_per_gas),
+                    "maxPriorityFeePerGas": hex_quantity(op.max_priority_fee_per_gas),
+                    "signature": hex_bytes(&op.signature),
+                });
+                if let Some(factory) = op.factory {
+                    v["factory"] = json!(hex_bytes(&factory));
+                    v["factoryData"] = json!(hex_bytes(&op.factory_data));
+                }
+                if let Some(paymaster) = op.paymaster {
+                    v["paymaster"] = json!(hex_bytes(&paymaster));
+                    v["paymasterVerificationGasLimit"] = json!(hex_quantity(op.paymaster_verification_gas_limit));
+                    v["paymasterPostOpGasLimit"] = json!(hex_quantity(op.paymaster_post_op_gas_limit));
+                    v["paymasterData"] = json!(hex_bytes(&op.paymaster_data));
+                }
+                v
+            }
+        }
+    }
+}
+
+pub(super) fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+pub(super) fn uint_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// bytes32(uint256(high) << 128 | low), the v0.7 packed gas/fee layout
+fn packed_u128_pair(high: u128, low: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[..16].copy_from_slice(&high.to_be_bytes());
+    word[16..].copy_from_slice(&low.to_be_bytes());
+    word
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn hex_quantity(value: u128) -> String {
+    format!("0x{:x}", value)
+}
+
+/// Hex quantity of a big-endian uint256, without leading zeros
+fn hex_word_quantity(word: &[u8; 32]) -> String {
+    let digits = hex::encode(word);
+    let digits = digits.trim_start_matches('0');
+    format!("0x{}", if digits.is_empty() { "0" } else { digits })
+}
+
+/// EntryPoint nonce for a 192-bit `key` and the 64-bit `sequence` within it
+pub fn user_op_nonce(key: &[u8; 24], sequence: u64) -> [u8; 32] {
+    let mut nonce = [0u8; 32];
+    nonce[..24].copy_from_slice(key);
+    nonce[24..].copy_from_slice(&sequence.to_be_bytes());
+    nonce
+}
+
+/// abi.encode(bytes) tail: length word + right-padded data
+pub(super) fn abi_bytes_tail(data: &[u8]) -> Vec<u8> {
+    let mut out = uint_word(data.len() as u128).to_vec();
+    out.extend_from_slice(data);
+    out.resize(32 + data.len().div_ceil(32) * 32, 0);
+    out
+}
+
+/// SimpleAccount.execute(dest, value, func) calldata for a single call
+pub fn encode_execute(to: &[u8; 20], value: u128, data: &[u8]) -> Vec<u8> {
+    let mut out = SELECTOR_EXECUTE.to_vec();
+    out.extend_from_slice(&address_word(to));
+    out.extend_from_slice(&uint_word(value));
+    out.extend_from_slice(&uint_word(0x60));
+    out.extend_from_slice(&abi_bytes_tail(data));
+    out
+}
+
+/// SimpleAccountFactory.createAccount(owner, salt) calldata (the factoryData of the first op)
+pub fn encode_create_account(owner: &[u8; 20], salt: u128) -> Vec<u8> {
+    let mut out = SELECTOR_CREATE_ACCOUNT.to_vec();
+    out.extend_from_slice(&address_word(owner));
+    out.extend_from_slice(&uint_word(salt));
+    out
+}
+
+/// EIP-1014: keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]
+pub fn create2_address(deployer: &[u8; 20], salt: &[u8; 32], init_code_hash: &[u8; 32]) -> [u8; 20] {
+    let mut data = Vec::with_capacity(85);
+    data.push(0xff);
+    data.extend_from_slice(deployer);
+    data.extend_from_slice(salt);
+    data.extend_from_slice(init_code_hash);
+    let hash = keccak256(&data);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Counterfactual SimpleAccount: the factory deploys
+/// `ERC1967Proxy(implementation, initialize(owner))` with CREATE2 salt = `salt`.
+/// `proxy_creation_code` is ERC1967Proxy's creation bytecode as compiled for that factory.
+pub fn simple_account_address(
+    factory: &[u8; 20],
+    proxy_creation_code: &[u8],
+    implementation: &[u8; 20],
+    owner: &[u8; 20],
+    salt: u128,
+) -> [u8; 20] {
+    let mut init_call = SELECTOR_INITIALIZE.to_vec();
+    init_call.extend_from_slice(&address_word(owner));
+
+    let mut init_code = proxy_creation_code.to_vec();
+    init_code.extend_from_slice(&address_word(implementation));
+    init_code.extend_from_slice(&uint_word(0x40));
+    init_code.extend_from_slice(&abi_bytes_tail(&init_call));
+
+    create2_address(factory, &uint_word(salt), &keccak256(&init_code))
+}
+
+/// Counterfactual Safe proxy from `SafeProxyFactory.createProxyWithNonce(singleton, initializer, saltNonce)`.
+/// `proxy_creation_code` is what the factory's `proxyCreationCode()` returns.
+pub fn safe_proxy_address(
+    proxy_factory: &[u8; 20],
+    proxy_creation_code: &[u8],
+    singleton: &[u8; 20],
+    initializer: &[u8],
+    salt_nonce: u128,
+) -> [u8; 20] {
+    let mut salt_input = keccak256(initializer).to_vec();
+    salt_input.extend_from_slice(&uint_word(salt_nonce));
+    let salt = keccak256(&salt_input);
+
+    let mut init_code = proxy_creation_code.to_vec();
+    init_code.extend_from_slice(&address_word(singleton));
+
+    create2_address(proxy_factory, &salt, &keccak256(&init_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::personal_sign::recover_address;
+
+    // Hardhat account #0 (DO NOT use in production)
+    const TEST_KEY: [u8; 32] = [
+        0xac, 0x09, 0x74, 0xbe, 0xc3, 0x9a, 0x17, 0xe3,
+        0x6b, 0xa4, 0xa6, 0xb4, 0xd2, 0x38, 0xff, 0x94,
+        0x4b, 0xac, 0xb4, 0x78, 0xcb, 0xed, 0x5e, 0xfc,
+        0xae, 0x78, 0x4d, 0x7b, 0xf4, 0xf2, 0xff, 0x80,
+    ];
+    const TEST_OWNER: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+    fn selector(sig: &str) -> [u8; 4] {
+        keccak256(sig.as_bytes())[..4].try_into().unwrap()
+    }
+
+    fn sample(version: EntryPointVersion) -> UserOperation {
+        let mut op = UserOperation::new(version, [0x42; 20], user_op_nonce(&[0; 24], 3), encode_execute(&[0x11; 20], 1000, &[0xde, 0xad]));
+        op.apply_gas_estimate(&UserOpGasEstimate {
+            pre_verification_gas: 50_000,
+            verification_gas_limit: 100_000,
+            call_gas_limit: 80_000,
+            ..Default::default()
+        });
+        op.set_fees(Eip1559Fees { max_fee_per_gas: 30_000_000_000, max_priority_fee_per_gas: 1_000_000_000 });
+        op
+    }
+
+    #[test]
+    fn test_selectors() {
+        assert_eq!(SELECTOR_EXECUTE, selector("execute(address,uint256,bytes)"));
+        assert_eq!(SELECTOR_CREATE_ACCOUNT, selector("createAccount(address,uint256)"));
+        assert_eq!(SELECTOR_INITIALIZE, selector("initialize(address)"));
+    }
+
+    #[test]
+    fn test_create2_eip1014_vectors() {
+        let init_hash = keccak256(&[0x00]);
+        assert_eq!(
+            hex::encode(create2_address(&[0u8; 20], &[0u8; 32], &init_hash)),
+            "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+        );
+        let deployer = parse_address("0xdeadbeef00000000000000000000000000000000").unwrap();
+        assert_eq!(
+            hex::encode(create2_address(&deployer, &[0u8; 32], &init_hash)),
+            "b928f69bb1d91cd65274e3c79d8986362984fda3"
+        );
+        let deployer = parse_address("0x00000000000000000000000000000000deadbeef").unwrap();
+        let salt = uint_word(0xcafebabe);
+        let init = hex::decode("deadbeef".repeat(11)).unwrap();
+        assert_eq!(
+            hex::encode(create2_address(&deployer, &salt, &keccak256(&init))),
+            "1d8bfdc5d46dc4f61d6b6115972536ebe6a8854c"
+        );
+    }
+
+    #[test]
+    fn test_counterfactual_addresses_depend_on_owner_and_salt() {
+        let factory = [0x9a; 20];
+        let implementation = [0x77; 20];
+        let code = [0x60, 0x80, 0x60, 0x40];
+        let owner = parse_address(TEST_OWNER).unwrap();
+        let a = simple_account_address(&factory, &code, &implementation, &owner, 0);
+        assert_eq!(a, simple_account_address(&factory, &code, &implementation, &owner, 0));
+        assert_ne!(a, simple_account_address(&factory, &code, &implementation, &owner, 1));
+        assert_ne!(a, simple_account_address(&factory, &code, &implementation, &[0x01; 20], 0));
+
+        let s = safe_proxy_address(&factory, &code, &implementation, &[0xab; 36], 7);
+        assert_ne!(s, safe_proxy_address(&factory, &code, &implementation, &[0xab; 36], 8));
+        assert_ne!(s, safe_proxy_address(&factory, &code, &implementation, &[0xac; 36], 7));
+    }
+
+    // Known-answer vectors below were computed independently of this module, from the
+    // EntryPoint `getUserOpHash` / SimpleAccountFactory `getAddress` definitions, with
+    // a separate Keccak-256 implementation. The proxy creation code is a fixed stand-in.
+    const SIMPLE_ACCOUNT_FACTORY_V06: &str = "0x9406Cc6185a346906296840746125a0E44976454";
+    const SIMPLE_ACCOUNT_IMPL_V06: &str = "0x8ABB13360b87Be5EEb1B98647A016adD927a136c";
+    const PROXY_CODE: [u8; 9] = [0x60, 0x80, 0x60, 0x40, 0x52, 0x60, 0x40, 0x51, 0x61];
+
+    fn known_account(salt: u128) -> [u8; 20] {
+        simple_account_address(
+            &parse_address(SIMPLE_ACCOUNT_FACTORY_V06).unwrap(),
+            &PROXY_CODE,
+            &parse_address(SIMPLE_ACCOUNT_IMPL_V06).unwrap(),
+            &parse_address(TEST_OWNER).unwrap(),
+            salt,
+        )
+    }
+
+    #[test]
+    fn test_simple_account_known_address() {
+        assert_eq!(hex::encode(known_account(0)), "45d55abaabc249460dbd72564c98485a5fac3de8");
+        assert_eq!(hex::encode(known_account(7)), "8bf5d9e04fd3c8e92153969fb432e5c50165b597");
+    }
+
+    #[test]
+    fn test_known_user_op_hashes() {
+        // v0.6 on Sepolia: first op of a counterfactual account, sending 0.001 ETH
+        let owner = parse_address(TEST_OWNER).unwrap();
+        let mut v6 = UserOperation::new(
+            EntryPointVersion::V06,
+            known_account(0),
+            [0; 32],
+            encode_execute(&parse_address("0x70997970C51812dc3A010C7d01b50e0d17dc79C8").unwrap(), 1_000_000_000_000_000, &[]),
+        );
+        v6.set_factory(parse_address(SIMPLE_ACCOUNT_FACTORY_V06).unwrap(), encode_create_account(&owner, 0));
+        v6.apply_gas_estimate(&UserOpGasEstimate {
+            pre_verification_gas: 50_000,
+            verification_gas_limit: 300_000,
+            call_gas_limit: 80_000,
+            ..Default::default()
+        });
+        v6.set_fees(Eip1559Fees { max_fee_per_gas: 30_000_000_000, max_priority_fee_per_gas: 1_000_000_000 });
+        let ep = parse_address(ENTRY_POINT_V06).unwrap();
+        assert_eq!(hex::encode(v6.hash(&ep, 11_155_111)), "347fb073d17c963f6950fb51ea42a2ce572e124c015e1dabc75bbafa2cec94c5");
+
+        // v0.7 on Base: deployed account, nonce key 1, USDC transfer through a paymaster
+        let mut transfer = keccak256(b"transfer(address,uint256)")[..4].to_vec();
+        transfer.extend_from_slice(&address_word(&parse_address("0x90F79bf6EB2c4f870365E785982E1f101E93b906").unwrap()));
+        transfer.extend_from_slice(&uint_word(25_000_000));
+        let mut key_one = [0u8; 24];
+        key_one[23] = 1;
+        let mut v7 = UserOperationV07 {
+            sender: known_account(7),
+            nonce: user_op_nonce(&key_one, 5),
+            call_data: encode_execute(&parse_address("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913").unwrap(), 0, &transfer),
+            call_gas_limit: 120_000,
+            verification_gas_limit: 90_000,
+            pre_verification_gas: 48_000,
+            max_fee_per_gas: 2_000_000_000,
+            max_priority_fee_per_gas: 100_000_000,
+            paymaster: Some(parse_address("0x0000000000000039cd5e8aE05257CE51C473ddd1").unwrap()),
+            paymaster_verification_gas_limit: 40_000,
+            paymaster_post_op_gas_limit: 20_000,
+            paymaster_data: vec![0xde, 0xad, 0xbe, 0xef],
+            ..Default::default()
+        };
+        let ep = parse_address(ENTRY_POINT_V07).unwrap();
+        let op = UserOperation::V07(v7.clone());
+        assert_eq!(hex::encode(op.hash(&ep, 8453)), "2a9b663f1c7be081e44a78286c0803af06000d383bf3070d2ec7f9ea79550325");
+
+        // Same op under a key that uses all 192 bits
+        let wide_key: [u8; 24] = std::array::from_fn(|i| i as u8 + 1);
+        v7.nonce = user_op_nonce(&wide_key, 5);
+        let op = UserOperation::V07(v7);
+        assert_eq!(op.to_rpc_json()["nonce"], "0x102030405060708090a0b0c0d0e0f1011121314151617180000000000000005");
+        assert_eq!(hex::encode(op.hash(&ep, 8453)), "65791f94d1fca545c7cef402d91860e9d8c03c3ed4342b3accb8c90a4761b741");
+    }
+
+    #[test]
+    fn test_v07_packing() {
+        let mut v7 = UserOperationV07 {
+            factory: Some([0xfa; 20]),
+            factory_data: vec![1, 2, 3],
+            paymaster: Some([0xbb; 20]),
+            paymaster_verification_gas_limit: 0x1234,
+            paymaster_post_op_gas_limit: 0x56,
+            paymaster_data: vec![9],
+            ..Default::default()
+        };
+        assert_eq!(v7.init_code(), [[0xfa; 20].as_slice(), &[1, 2, 3]].concat());
+        let pmd = v7.paymaster_and_data();
+        assert_eq!(pmd.len(), 20 + 16 + 16 + 1);
+        assert_eq!(u128::from_be_bytes(pmd[20..36].try_into().unwrap()), 0x1234);
+        assert_eq!(u128::from_be_bytes(pmd[36..52].try_into().unwrap()), 0x56);
+
+        v7.paymaster = None;
+        assert!(v7.paymaster_and_data().is_empty());
+
+        let word = packed_u128_pair(100_000, 80_000);
+        assert_eq!(u128::from_be_bytes(word[..16].try_into().unwrap()), 100_000);
+        assert_eq!(u128::from_be_bytes(word[16..].try_into().unwrap()), 80_000);
+    }
+
+    #[test]
+    fn test_hash_binds_chain_entry_point_and_fields() {
+        for version in [EntryPointVersion::V06, EntryPointVersion::V07] {
+            let op = sample(version);
+            let ep = parse_address(version.entry_point()).unwrap();
+            let h = op.hash(&ep, 1);
+            assert_ne!(h, op.hash(&ep, 10));
+            assert_ne!(h, op.hash(&[0x01; 20], 1));
+
+            // Signature is not part of the hash
+            let mut signed = op.clone();
+            signed.set_signature(vec![1; 65]);
+            assert_eq!(h, signed.hash(&ep, 1));
+
+            let mut changed = op.clone();
+            changed.set_fees(Eip1559Fees { max_fee_per_gas: 31_000_000_000, max_priority_fee_per_gas: 1_000_000_000 });
+            assert_ne!(h, changed.hash(&ep, 1));
+        }
+    }
+
+    #[test]
+    fn test_sign_recovers_owner() {
+        let mut op = sample(EntryPointVersion::V07);
+        let hash = op.sign(&TEST_KEY, 11155111).unwrap();
+        let sig: [u8; 65] = op.signature().try_into().unwrap();
+        let signer = recover_address(&hash, &sig).unwrap();
+        assert_eq!(signer, parse_address(TEST_OWNER).unwrap());
+    }
+
+    #[test]
+    fn test_sign_with_seed_uses_evm_owner() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = crate::bip39_utils::mnemonic_to_seed(phrase, "").unwrap();
+        let mut op = sample(EntryPointVersion::V06);
+        let hash = op.sign_with_seed(&seed, 1).unwrap();
+        let sig: [u8; 65] = op.signature().try_into().unwrap();
+        let owner = format!("0x{}", hex::encode(recover_address(&hash, &sig).unwrap()));
+        assert_eq!(owner.to_lowercase(), crate::chains::evm::derive_evm_address(&seed).unwrap().to_lowercase());
+    }
+
+    #[test]
+    fn test_rpc_json_shapes() {
+        let mut v6 = sample(EntryPointVersion::V06);
+        v6.set_factory([0xfa; 20], encode_create_account(&[0x01; 20], 0));
+        let j = v6.to_rpc_json();
+        assert_eq!(j["nonce"], "0x3");
+        assert_eq!(j["callGasLimit"], "0x13880");
+        assert!(j["initCode"].as_str().unwrap().starts_with("0xfafafa"));
+        assert!(j.get("factory").is_none());
+
+        let mut v7 = sample(EntryPointVersion::V07);
+        assert!(v7.to_rpc_json().get("factory").is_none());
+        v7.set_factory([0xfa; 20], vec![0xaa]);
+        let j = v7.to_rpc_json();
+        assert_eq!(j["factory"], format!("0x{}", "fa".repeat(20)));
+        assert_eq!(j["factoryData"], "0xaa");
+        assert!(j.get("initCode").is_none());
+        assert!(j.get("paymaster").is_none());
+    }
+
+    #[test]
+    fn test_execute_calldata_layout() {
+        let data = encode_execute(&[0x11; 20], 5, &[0xde, 0xad]);
+        assert_eq!(data.len(), 4 + 32 * 5);
+        assert_eq!(&data[4 + 12..4 + 32], &[0x11; 20]);
+        assert_eq!(data[4 + 32 * 2 + 31], 0x60);
+        assert_eq!(data[4 + 32 * 3 + 31], 2);
+        assert_eq!(&data[4 + 32 * 4..4 + 32 * 4 + 2], &[0xde, 0xad]);
+        assert_eq!(EntryPointVersion::from_entry_point(&ENTRY_POINT_V07.to_lowercase()), Some(EntryPointVersion::V07));
+    }
+}
@@ -1,15 +1,16 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// tx/evm: EIP-1559 transaction construction, RLP encoding, secp256k1 signing
+// tx/evm: EIP-1559 / EIP-7702 transaction construction, RLP encoding, secp256k1 signing
 
 use k256::ecdsa::{SigningKey, signature::hazmat::PrehashSigner};
 use tiny_keccak::{Hasher, Keccak};
 
+use super::eip7702::{self, SignedAuthorization};
 use super::SignedTransaction;
 use crate::chains::ChainId;
 
-/// EIP-1559 (Type 2) transaction
+/// EIP-1559 (Type 2) transaction; EIP-7702 (Type 4) when `authorization_list` is non-empty
 #[derive(Debug, Clone)]
 pub struct EvmTransaction {
     pub chain_id_num: u64,
@@ -20,79 +21,60 @@ pub struct EvmTransaction {
     pub to: [u8; 20],
     pub value: u128,
     pub data: Vec<u8>,
+    pub authorization_list: Vec<SignedAuthorization>,
 }
 
 impl EvmTransaction {
-    /// Encode for signing (without signature fields)
-    fn encode_unsigned(&self) -> Vec<u8> {
-        // Build RLP payload manually to correctly encode access_list as empty list (0xc0)
-        // instead of empty byte string (0x80)
+    /// EIP-2718 transaction type byte
+    pub fn tx_type(&self) -> u8 {
+        if self.authorization_list.is_empty() { 0x02 } else { eip7702::SET_CODE_TX_TYPE }
+    }
+
+    /// RLP items shared by the unsigned and signed encodings
+    fn encode_fields(&self) -> Vec<u8> {
+        // Built manually to encode access_list as an empty list (0xc0)
+        // instead of an empty byte string (0x80)
         let mut payload = Vec::new();
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.chain_id_num)));
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.nonce)));
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.max_priority_fee_per_gas)));
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.max_fee_per_gas)));
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.gas_limit)));
-        payload.extend_from_slice(&rlp_encode_bytes(&self.to.to_vec()));
+        payload.extend_from_slice(&rlp_encode_bytes(&self.to));
         payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.value)));
         payload.extend_from_slice(&rlp_encode_bytes(&self.data));
         payload.push(0xc0); // access_list: RLP empty list (NOT byte string 0x80)
+        if !self.authorization_list.is_empty() {
+            payload.extend_from_slice(&eip7702::encode_authorization_list(&self.authorization_list));
+        }
+        payload
+    }
 
-        let rlp = rlp_wrap_list_payload(&payload);
-        // EIP-1559: 0x02 || RLP(...)
+    /// Encode for signing (without signature fields): type || RLP(fields)
+    fn encode_unsigned(&self) -> Vec<u8> {
+        let rlp = rlp_wrap_list_payload(&self.encode_fields());
         let mut result = Vec::with_capacity(1 + rlp.len());
-        result.push(0x02);
+        result.push(self.tx_type());
         result.extend_from_slice(&rlp);
         result
     }
 
     /// Sign the transaction with a private key
     pub fn sign(&self, private_key: &[u8; 32], chain_id: ChainId) -> Result<SignedTransaction, String> {
-        let unsigned = self.encode_unsigned();
-
-        // Hash: keccak256(0x02 || RLP(unsigned))
-        let mut hasher = Keccak::v256();
-        let mut hash = [0u8; 32];
-        hasher.update(&unsigned);
-        hasher.finalize(&mut hash);
-
-        // Sign with secp256k1
-        let signing_key = SigningKey::from_bytes(private_key.into())
-            .map_err(|e| format!("Invalid key: {}", e))?;
-        let (signature, recovery_id) = signing_key
-            .sign_prehash(&hash)
-            .map_err(|e| format!("Signing error: {}", e))?;
-
-        let sig_bytes = signature.to_bytes();
-        let r = &sig_bytes[..32];
-        let s = &sig_bytes[32..];
-        let v = recovery_id.to_byte();
-
-        // Encode signed: 0x02 || RLP([chain_id, nonce, max_priority_fee, max_fee, gas_limit, to, value, data, access_list, v, r, s])
-        let mut payload = Vec::new();
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.chain_id_num)));
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.nonce)));
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.max_priority_fee_per_gas)));
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.max_fee_per_gas)));
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u64(self.gas_limit)));
-        payload.extend_from_slice(&rlp_encode_bytes(&self.to.to_vec()));
-        payload.extend_from_slice(&rlp_encode_bytes(&rlp_encode_u128(self.value)));
-        payload.extend_from_slice(&rlp_encode_bytes(&self.data));
-        payload.push(0xc0); // access_list: RLP empty list
-        payload.extend_from_slice(&rlp_encode_bytes(&vec![v]));
-        payload.extend_from_slice(&rlp_encode_bytes(&r.to_vec()));
-        payload.extend_from_slice(&rlp_encode_bytes(&s.to_vec()));
+        // Hash: keccak256(type || RLP(unsigned))
+        let (v, r, s) = sign_prehash(&keccak256(&self.encode_unsigned()), private_key)?;
+
+        // Encode signed: type || RLP([...fields, y_parity, r, s])
+        let mut payload = self.encode_fields();
+        payload.extend_from_slice(&rlp_encode_signature(v, &r, &s));
 
         let rlp_signed = rlp_wrap_list_payload(&payload);
         let mut raw = Vec::with_capacity(1 + rlp_signed.len());
-        raw.push(0x02);
+        raw.push(self.tx_type());
         raw.extend_from_slice(&rlp_signed);
 
         // Tx hash = keccak256(signed raw)
-        let mut tx_hasher = Keccak::v256();
-        let mut tx_hash = [0u8; 32];
-        tx_hasher.update(&raw);
-        tx_hasher.finalize(&mut tx_hash);
+        let tx_hash = keccak256(&raw);
 
         Ok(SignedTransaction {
             chain_id,
@@ -102,6 +84,41 @@ impl EvmTransaction {
     }
 }
 
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Sign a 32-byte hash; returns (y_parity, r, s)
+pub(crate) fn sign_prehash(hash: &[u8; 32], private_key: &[u8; 32]) -> Result<(u8, [u8; 32], [u8; 32]), String> {
+    let signing_key = SigningKey::from_bytes(private_key.into())
+        .map_err(|e| format!("Invalid key: {}", e))?;
+    let (signature, recovery_id) = signing_key
+        .sign_prehash(hash)
+        .map_err(|e| format!("Signing error: {}", e))?;
+    let sig_bytes = signature.to_bytes();
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&sig_bytes[..32]);
+    s.copy_from_slice(&sig_bytes[32..]);
+    Ok((recovery_id.to_byte(), r, s))
+}
+
+/// y_parity, r and s as canonical RLP integers (no leading zero bytes, 0 as 0x80)
+pub(crate) fn rlp_encode_signature(y_parity: u8, r: &[u8; 32], s: &[u8; 32]) -> Vec<u8> {
+    let trim = |b: &[u8; 32]| -> Vec<u8> {
+        let start = b.iter().position(|&x| x != 0).unwrap_or(32);
+        rlp_encode_bytes(&b[start..])
+    };
+    let mut out = rlp_encode_bytes(&rlp_encode_u64(y_parity as u64));
+    out.extend_from_slice(&trim(r));
+    out.extend_from_slice(&trim(s));
+    out
+}
+
 /// Parse an EVM address string (0x...) to 20 bytes
 pub fn parse_address(addr: &str) -> Result<[u8; 20], String> {
     let hex_str = addr.strip_prefix("0x").unwrap_or(addr);
@@ -144,7 +161,7 @@ pub fn parse_ether_to_wei(amount: &str) -> Result<u128, String> {
 
 // --- RLP encoding helpers ---
 
-fn rlp_encode_u64(val: u64) -> Vec<u8> {
+pub(crate) fn rlp_encode_u64(val: u64) -> Vec<u8> {
     if val == 0 {
         return vec![];
     }
@@ -162,7 +179,7 @@ fn rlp_encode_u128(val: u128) -> Vec<u8> {
     bytes[start..].to_vec()
 }
 
-fn rlp_encode_bytes(data: &[u8]) -> Vec<u8> {
+pub(crate) fn rlp_encode_bytes(data: &[u8]) -> Vec<u8> {
     if data.len() == 1 && data[0] < 0x80 {
         return data.to_vec();
     }
@@ -184,7 +201,7 @@ fn rlp_encode_bytes(data: &[u8]) -> Vec<u8> {
 
 /// Wrap pre-encoded RLP payload bytes in a list envelope
 /// Used when payload contains pre-encoded items (e.g., access_list as 0xc0)
-fn rlp_wrap_list_payload(payload: &[u8]) -> Vec<u8> {
+pub(crate) fn rlp_wrap_list_payload(payload: &[u8]) -> Vec<u8> {
     if payload.len() <= 55 {
         let mut result = vec![0xc0 + payload.len() as u8];
         result.extend_from_slice(payload);
@@ -234,6 +251,7 @@ mod tests {
             to: [0u8; 20],
             value: 1_000_000_000_000_000_000, // 1 ETH
             data: vec![],
+            authorization_list: vec![],
         };
         // Use a test private key
         let key = [1u8; 32];
@@ -241,4 +259,18 @@ mod tests {
         assert!(signed.tx_hash.starts_with("0x"));
         assert!(!signed.raw_bytes.is_empty());
     }
+
+    #[test]
+    fn test_signature_rlp_is_canonical() {
+        let mut r = [0u8; 32];
+        r[1] = 0x80;
+        let s = [0x11u8; 32];
+        let encoded = rlp_encode_signature(0, &r, &s);
+        // y_parity 0 is the empty string, r drops its leading zero byte
+        assert_eq!(encoded[0], 0x80);
+        assert_eq!(encoded[1], 0x80 + 31);
+        assert_eq!(encoded[2], 0x80);
+        assert_eq!(encoded.len(), 1 + 32 + 33);
+        assert_eq!(rlp_encode_signature(1, &s, &s)[0], 0x01);
+    }
 }
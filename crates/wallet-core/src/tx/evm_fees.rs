@@ -0,0 +1,435 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/evm_fees: EIP-1559 fee tiers (eth_feeHistory) and gas limit estimation
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Number of past blocks requested from eth_feeHistory
+pub const FEE_HISTORY_BLOCKS: u64 = 10;
+
+/// Reward percentiles requested from eth_feeHistory (slow, normal, fast)
+pub const FEE_HISTORY_PERCENTILES: [u8; 3] = [10, 50, 90];
+
+/// Safety margin added on top of eth_estimateGas (percent)
+pub const GAS_MARGIN_PERCENT: u64 = 20;
+
+/// Gas used by a plain value transfer to an EOA
+pub const NATIVE_TRANSFER_GAS: u64 = 21_000;
+
+/// Gas limit used for ERC-20 transfers when estimation fails
+pub const ERC20_TRANSFER_GAS_FALLBACK: u64 = 65_000;
+
+/// Gas limit used for arbitrary contract calls when estimation fails
+pub const CONTRACT_CALL_GAS_FALLBACK: u64 = 300_000;
+
+/// Priority fee used when the node offers no suggestion (1.5 Gwei)
+pub const DEFAULT_PRIORITY_FEE: u128 = 1_500_000_000;
+
+/// Max fee headroom over the next base fee per tier (percent)
+const BASE_FEE_HEADROOM: [u128; 3] = [150, 200, 250];
+
+/// Priority fee scaling per tier when derived from eth_maxPriorityFeePerGas (percent)
+const FALLBACK_PRIORITY_SCALE: [u128; 3] = [80, 100, 150];
+
+const WEI_PER_GWEI: u128 = 1_000_000_000;
+
+/// User-selectable fee speed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeeTier {
+    Slow,
+    Normal,
+    Fast,
+}
+
+impl FeeTier {
+    pub fn all() -> [FeeTier; 3] {
+        [FeeTier::Slow, FeeTier::Normal, FeeTier::Fast]
+    }
+
+    pub(crate) fn index(self) -> usize {
+        match self {
+            FeeTier::Slow => 0,
+            FeeTier::Normal => 1,
+            FeeTier::Fast => 2,
+        }
+    }
+}
+
+/// EIP-1559 fee parameters for a single transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip1559Fees {
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+impl Eip1559Fees {
+    /// Reject fee pairs a node would refuse (tip above cap, zero cap)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_fee_per_gas == 0 {
+            return Err("Max fee must be greater than zero".into());
+        }
+        if self.max_priority_fee_per_gas > self.max_fee_per_gas {
+            return Err("Priority fee cannot exceed max fee".into());
+        }
+        Ok(())
+    }
+
+    /// Worst-case fee in wei for the given gas limit
+    pub fn max_cost(&self, gas_limit: u64) -> u128 {
+        self.max_fee_per_gas.saturating_mul(gas_limit as u128)
+    }
+
+    /// Expected fee in wei, assuming the base fee stays at `base_fee_per_gas`
+    pub fn expected_cost(&self, gas_limit: u64, base_fee_per_gas: u128) -> u128 {
+        let per_gas = base_fee_per_gas
+            .saturating_add(self.max_priority_fee_per_gas)
+            .min(self.max_fee_per_gas);
+        per_gas.saturating_mul(gas_limit as u128)
+    }
+}
+
+/// Slow / normal / fast fee suggestions for the next block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeEstimate {
+    pub base_fee_per_gas: u128,
+    pub tiers: [Eip1559Fees; 3],
+    /// False when derived from eth_gasPrice because eth_feeHistory was unavailable
+    pub from_fee_history: bool,
+}
+
+impl FeeEstimate {
+    pub fn tier(&self, tier: FeeTier) -> Eip1559Fees {
+        self.tiers[tier.index()]
+    }
+}
+
+/// Add the safety margin to an eth_estimateGas result.
+/// Exact 21000 estimates are plain transfers and never need headroom.
+pub fn with_gas_margin(estimate: u64) -> u64 {
+    if estimate == NATIVE_TRANSFER_GAS {
+        return estimate;
+    }
+    estimate.saturating_add(estimate.saturating_mul(GAS_MARGIN_PERCENT) / 100)
+}
+
+/// Gas limit from an eth_estimateGas JSON-RPC response, falling back on errors.
+/// `None` means the request itself failed (network, timeout).
+pub fn resolve_gas_limit(response: Option<&Value>, fallback: u64) -> u64 {
+    response
+        .filter(|json| json.get("error").is_none())
+        .and_then(|json| json.get("result"))
+        .and_then(parse_quantity)
+        .and_then(|gas| u64::try_from(gas).ok())
+        .filter(|gas| *gas > 0)
+        .map(with_gas_margin)
+        .unwrap_or(fallback)
+}
+
+/// Build fee tiers from an eth_feeHistory JSON-RPC response
+/// requested with `FEE_HISTORY_PERCENTILES`.
+pub fn fees_from_fee_history(response: &Value) -> Result<FeeEstimate, String> {
+    if let Some(error) = response.get("error") {
+        return Err(format!("eth_feeHistory error: {}", error));
+    }
+    let result = response.get("result").ok_or("Missing result in eth_feeHistory")?;
+
+    // baseFeePerGas has blockCount + 1 entries; the last one is the next block's base fee
+    let base_fee = result.get("baseFeePerGas")
+        .and_then(|v| v.as_array())
+        .and_then(|fees| fees.last())
+        .and_then(parse_quantity)
+        .ok_or("Missing baseFeePerGas in eth_feeHistory")?;
+
+    let rewards = result.get("reward")
+        .and_then(|v| v.as_array())
+        .filter(|r| !r.is_empty())
+        .ok_or("Missing reward in eth_feeHistory")?;
+
+    let mut tiers = [Eip1559Fees { max_fee_per_gas: 0, max_priority_fee_per_gas: 0 }; 3];
+    for tier in FeeTier::all() {
+        let i = tier.index();
+        let mut samples: Vec<u128> = rewards.iter()
+            .filter_map(|block| block.get(i).and_then(parse_quantity))
+            .collect();
+        if samples.is_empty() {
+            return Err("Empty reward percentiles in eth_feeHistory".into());
+        }
+        samples.sort_unstable();
+        let priority = samples[samples.len() / 2];
+        tiers[i] = Eip1559Fees {
+            max_fee_per_gas: base_fee.saturating_mul(BASE_FEE_HEADROOM[i]) / 100 + priority,
+            max_priority_fee_per_gas: priority,
+        };
+    }
+
+    // Percentile medians are not monotonic across noisy blocks; keep tiers ordered
+    for i in 1..3 {
+        if tiers[i].max_priority_fee_per_gas < tiers[i - 1].max_priority_fee_per_gas {
+            tiers[i].max_priority_fee_per_gas = tiers[i - 1].max_priority_fee_per_gas;
+            tiers[i].max_fee_per_gas = base_fee.saturating_mul(BASE_FEE_HEADROOM[i]) / 100
+                + tiers[i].max_priority_fee_per_gas;
+        }
+    }
+
+    Ok(FeeEstimate { base_fee_per_gas: base_fee, tiers, from_fee_history: true })
+}
+
+/// Build fee tiers from eth_gasPrice (and eth_maxPriorityFeePerGas when available).
+/// On EIP-1559 nodes gasPrice is base fee + suggested tip, so the tip is subtracted back out.
+pub fn fees_from_gas_price(gas_price: u128, priority_fee: Option<u128>) -> FeeEstimate {
+    let priority = priority_fee.unwrap_or(DEFAULT_PRIORITY_FEE).min(gas_price);
+    let base_fee = gas_price - priority;
+
+    let mut tiers = [Eip1559Fees { max_fee_per_gas: 0, max_priority_fee_per_gas: 0 }; 3];
+    for tier in FeeTier::all() {
+        let i = tier.index();
+        let tip = priority.saturating_mul(FALLBACK_PRIORITY_SCALE[i]) / 100;
+        tiers[i] = Eip1559Fees {
+            max_fee_per_gas: (base_fee.saturating_mul(BASE_FEE_HEADROOM[i]) / 100 + tip).max(gas_price),
+            max_priority_fee_per_gas: tip,
+        };
+    }
+
+    FeeEstimate { base_fee_per_gas: base_fee, tiers, from_fee_history: false }
+}
+
+/// Pick the best available fee source: eth_feeHistory first, then eth_gasPrice.
+pub fn resolve_fee_estimate(
+    fee_history: Option<&Value>,
+    gas_price: Option<u128>,
+    priority_fee: Option<u128>,
+) -> Result<FeeEstimate, String> {
+    if let Some(estimate) = fee_history.and_then(|json| fees_from_fee_history(json).ok()) {
+        return Ok(estimate);
+    }
+    gas_price
+        .map(|price| fees_from_gas_price(price, priority_fee))
+        .ok_or_else(|| "No fee data available from RPC".to_string())
+}
+
+/// Parse a user-entered Gwei amount (e.g. "1.5") to wei
+pub fn parse_gwei(input: &str) -> Result<u128, String> {
+    let input = input.trim();
+    let (integer_part, decimal_part) = input.split_once('.').unwrap_or((input, ""));
+    if decimal_part.len() > 9 {
+        return Err("Too many decimals for Gwei (max 9)".into());
+    }
+    let integer: u128 = if integer_part.is_empty() {
+        0
+    } else {
+        integer_part.parse().map_err(|_| "Invalid Gwei amount")?
+    };
+    let fraction: u128 = if decimal_part.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", decimal_part).parse().map_err(|_| "Invalid Gwei amount")?
+    };
+    integer.checked_mul(WEI_PER_GWEI)
+        .and_then(|v| v.checked_add(fraction))
+        .ok_or_else(|| "Gwei amount overflow".to_string())
+}
+
+/// Format wei as Gwei with up to 3 decimals (e.g. 1_500_000_000 → "1.5")
+pub fn format_gwei(wei: u128) -> String {
+    let integer = wei / WEI_PER_GWEI;
+    let milli = (wei % WEI_PER_GWEI) / 1_000_000;
+    if milli == 0 {
+        return integer.to_string();
+    }
+    let frac = format!("{:03}", milli);
+    format!("{}.{}", integer, frac.trim_end_matches('0'))
+}
+
+/// Format a wei fee as native units with 6 decimals (e.g. "0.000441")
+pub fn format_fee(wei: u128) -> String {
+    let unit = 1_000_000_000_000_000_000u128;
+    format!("{}.{:06}", wei / unit, (wei % unit) / 1_000_000_000_000)
+}
+
+fn parse_quantity(value: &Value) -> Option<u128> {
+    let hex = value.as_str()?;
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.is_empty() {
+        return None;
+    }
+    u128::from_str_radix(hex, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn fee_history_response() -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "oldestBlock": "0x1300000",
+                // 20 Gwei ... 30 Gwei next block
+                "baseFeePerGas": ["0x4a817c800", "0x5d21dba00", "0x6fc23ac00"],
+                "gasUsedRatio": [0.5, 0.9],
+                "reward": [
+                    ["0x3b9aca00", "0x77359400", "0xb2d05e00"],
+                    ["0x5f5e100", "0x59682f00", "0x12a05f200"]
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn test_gas_margin() {
+        assert_eq!(with_gas_margin(21_000), 21_000);
+        assert_eq!(with_gas_margin(50_000), 60_000);
+        assert_eq!(with_gas_margin(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_resolve_gas_limit_success() {
+        let resp = json!({"jsonrpc": "2.0", "id": 1, "result": "0xc350"}); // 50000
+        assert_eq!(resolve_gas_limit(Some(&resp), ERC20_TRANSFER_GAS_FALLBACK), 60_000);
+    }
+
+    #[test]
+    fn test_resolve_gas_limit_fallbacks() {
+        let reverted = json!({
+            "jsonrpc": "2.0", "id": 1,
+            "error": {"code": 3, "message": "execution reverted"}
+        });
+        assert_eq!(resolve_gas_limit(Some(&reverted), 65_000), 65_000);
+
+        let garbage = json!({"jsonrpc": "2.0", "id": 1, "result": "0xzz"});
+        assert_eq!(resolve_gas_limit(Some(&garbage), 65_000), 65_000);
+
+        let zero = json!({"jsonrpc": "2.0", "id": 1, "result": "0x0"});
+        assert_eq!(resolve_gas_limit(Some(&zero), 21_000), 21_000);
+
+        assert_eq!(resolve_gas_limit(None, CONTRACT_CALL_GAS_FALLBACK), 300_000);
+    }
+
+    #[test]
+    fn test_fees_from_fee_history() {
+        let est = fees_from_fee_history(&fee_history_response()).unwrap();
+        assert!(est.from_fee_history);
+        assert_eq!(est.base_fee_per_gas, 30 * GWEI);
+
+        // Median of two samples picks the upper one
+        let slow = est.tier(FeeTier::Slow);
+        assert_eq!(slow.max_priority_fee_per_gas, GWEI);
+        assert_eq!(slow.max_fee_per_gas, 45 * GWEI + GWEI);
+
+        let normal = est.tier(FeeTier::Normal);
+        assert_eq!(normal.max_priority_fee_per_gas, 2 * GWEI);
+        assert_eq!(normal.max_fee_per_gas, 60 * GWEI + 2 * GWEI);
+
+        let fast = est.tier(FeeTier::Fast);
+        assert_eq!(fast.max_priority_fee_per_gas, 5 * GWEI);
+        assert_eq!(fast.max_fee_per_gas, 75 * GWEI + 5 * GWEI);
+
+        for tier in FeeTier::all() {
+            est.tier(tier).validate().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_fee_history_tiers_stay_ordered() {
+        let resp = json!({
+            "result": {
+                "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00"],
+                "reward": [["0x77359400", "0x3b9aca00", "0x3b9aca00"]]
+            }
+        });
+        let est = fees_from_fee_history(&resp).unwrap();
+        let slow = est.tier(FeeTier::Slow).max_priority_fee_per_gas;
+        let normal = est.tier(FeeTier::Normal).max_priority_fee_per_gas;
+        let fast = est.tier(FeeTier::Fast).max_priority_fee_per_gas;
+        assert!(slow <= normal && normal <= fast);
+    }
+
+    #[test]
+    fn test_fee_history_errors() {
+        let unsupported = json!({
+            "jsonrpc": "2.0", "id": 1,
+            "error": {"code": -32601, "message": "the method eth_feeHistory does not exist"}
+        });
+        assert!(fees_from_fee_history(&unsupported).is_err());
+
+        let no_rewards = json!({"result": {"baseFeePerGas": ["0x1"], "reward": []}});
+        assert!(fees_from_fee_history(&no_rewards).is_err());
+
+        let no_base = json!({"result": {"reward": [["0x1", "0x2", "0x3"]]}});
+        assert!(fees_from_fee_history(&no_base).is_err());
+    }
+
+    #[test]
+    fn test_fees_from_gas_price() {
+        let est = fees_from_gas_price(22 * GWEI, Some(2 * GWEI));
+        assert!(!est.from_fee_history);
+        assert_eq!(est.base_fee_per_gas, 20 * GWEI);
+        let normal = est.tier(FeeTier::Normal);
+        assert_eq!(normal.max_priority_fee_per_gas, 2 * GWEI);
+        assert_eq!(normal.max_fee_per_gas, 42 * GWEI);
+
+        // Legacy chain with a gas price below the default tip
+        let est = fees_from_gas_price(GWEI / 2, None);
+        assert_eq!(est.base_fee_per_gas, 0);
+        for tier in FeeTier::all() {
+            let fees = est.tier(tier);
+            fees.validate().unwrap();
+            assert!(fees.max_fee_per_gas >= GWEI / 2);
+        }
+    }
+
+    #[test]
+    fn test_resolve_fee_estimate_fallback_chain() {
+        let history = fee_history_response();
+        let est = resolve_fee_estimate(Some(&history), Some(GWEI), None).unwrap();
+        assert!(est.from_fee_history);
+
+        let broken = json!({"jsonrpc": "2.0", "id": 1, "error": {"code": -32000, "message": "boom"}});
+        let est = resolve_fee_estimate(Some(&broken), Some(22 * GWEI), Some(2 * GWEI)).unwrap();
+        assert!(!est.from_fee_history);
+        assert_eq!(est.base_fee_per_gas, 20 * GWEI);
+
+        let est = resolve_fee_estimate(None, Some(22 * GWEI), None).unwrap();
+        assert!(!est.from_fee_history);
+
+        assert!(resolve_fee_estimate(Some(&broken), None, None).is_err());
+    }
+
+    #[test]
+    fn test_validate_fees() {
+        let ok = Eip1559Fees { max_fee_per_gas: 30 * GWEI, max_priority_fee_per_gas: 2 * GWEI };
+        assert!(ok.validate().is_ok());
+        let tip_too_high = Eip1559Fees { max_fee_per_gas: GWEI, max_priority_fee_per_gas: 2 * GWEI };
+        assert!(tip_too_high.validate().is_err());
+        let zero = Eip1559Fees { max_fee_per_gas: 0, max_priority_fee_per_gas: 0 };
+        assert!(zero.validate().is_err());
+    }
+
+    #[test]
+    fn test_costs() {
+        let fees = Eip1559Fees { max_fee_per_gas: 40 * GWEI, max_priority_fee_per_gas: 2 * GWEI };
+        assert_eq!(fees.max_cost(21_000), 840_000 * GWEI);
+        assert_eq!(fees.expected_cost(21_000, 20 * GWEI), 462_000 * GWEI);
+        // Capped by max fee when base fee spikes
+        assert_eq!(fees.expected_cost(21_000, 50 * GWEI), 840_000 * GWEI);
+    }
+
+    #[test]
+    fn test_gwei_parse_format() {
+        assert_eq!(parse_gwei("1.5").unwrap(), 1_500_000_000);
+        assert_eq!(parse_gwei("30").unwrap(), 30 * GWEI);
+        assert_eq!(parse_gwei(".1").unwrap(), 100_000_000);
+        assert!(parse_gwei("1.0000000001").is_err());
+        assert!(parse_gwei("abc").is_err());
+        assert_eq!(format_gwei(1_500_000_000), "1.5");
+        assert_eq!(format_gwei(30 * GWEI), "30");
+        assert_eq!(format_gwei(1_234_567_890), "1.234");
+        assert_eq!(format_fee(462_000 * GWEI), "0.000462");
+    }
+}
@@ -0,0 +1,269 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/evm_pending: Pending EVM transactions by nonce, speed-up and cancel replacements
+
+use serde::{Deserialize, Serialize};
+
+use super::eip7702::SignedAuthorization;
+use super::evm::{parse_address, EvmTransaction};
+use super::evm_fees::{Eip1559Fees, NATIVE_TRANSFER_GAS};
+
+/// Minimum fee bump (percent) nodes require to accept a same-nonce replacement.
+/// geth and most forks reject replacements below +10% on both fee fields.
+pub const REPLACEMENT_BUMP_PERCENT: u128 = 10;
+
+/// Why a pending transaction was broadcast
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingKind {
+    Original,
+    SpeedUp,
+    Cancel,
+}
+
+/// A broadcast EVM transaction that has not been mined yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingEvmTx {
+    pub hash: String,
+    /// UI chain id string (e.g. "ethereum")
+    pub chain: String,
+    pub evm_chain_id: u64,
+    pub from: String,
+    pub nonce: u64,
+    pub to: String,
+    pub value: u128,
+    /// Calldata as 0x-prefixed hex
+    pub data: String,
+    pub gas_limit: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub kind: PendingKind,
+    /// Unix timestamp (seconds) of the broadcast
+    pub submitted_at: u64,
+    /// EIP-7702 authorizations, re-sent unchanged by speed-up
+    #[serde(default)]
+    pub authorization_list: Vec<SignedAuthorization>,
+}
+
+impl PendingEvmTx {
+    pub fn from_transaction(
+        tx: &EvmTransaction,
+        hash: &str,
+        chain: &str,
+        from: &str,
+        kind: PendingKind,
+        submitted_at: u64,
+    ) -> Self {
+        Self {
+            hash: hash.to_string(),
+            chain: chain.to_string(),
+            evm_chain_id: tx.chain_id_num,
+            from: from.to_string(),
+            nonce: tx.nonce,
+            to: format!("0x{}", hex::encode(tx.to)),
+            value: tx.value,
+            data: format!("0x{}", hex::encode(&tx.data)),
+            gas_limit: tx.gas_limit,
+            max_fee_per_gas: tx.max_fee_per_gas,
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+            kind,
+            submitted_at,
+            authorization_list: tx.authorization_list.clone(),
+        }
+    }
+
+    pub fn fees(&self) -> Eip1559Fees {
+        Eip1559Fees {
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+        }
+    }
+
+    /// Rebuild the unsigned transaction that was broadcast
+    pub fn to_transaction(&self) -> Result<EvmTransaction, String> {
+        let data_hex = self.data.strip_prefix("0x").unwrap_or(&self.data);
+        Ok(EvmTransaction {
+            chain_id_num: self.evm_chain_id,
+            nonce: self.nonce,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            max_fee_per_gas: self.max_fee_per_gas,
+            gas_limit: self.gas_limit,
+            to: parse_address(&self.to)?,
+            value: self.value,
+            data: hex::decode(data_hex).map_err(|e| format!("Invalid calldata hex: {}", e))?,
+            authorization_list: self.authorization_list.clone(),
+        })
+    }
+
+    /// Same payload and nonce, fees bumped to satisfy replacement rules
+    pub fn speed_up(&self, market: Option<Eip1559Fees>) -> Result<EvmTransaction, String> {
+        let fees = replacement_fees(self.fees(), market);
+        let mut tx = self.to_transaction()?;
+        tx.max_fee_per_gas = fees.max_fee_per_gas;
+        tx.max_priority_fee_per_gas = fees.max_priority_fee_per_gas;
+        Ok(tx)
+    }
+
+    /// Zero-value self-send at the same nonce, fees bumped to satisfy replacement rules
+    pub fn cancel(&self, market: Option<Eip1559Fees>) -> Result<EvmTransaction, String> {
+        let fees = replacement_fees(self.fees(), market);
+        Ok(EvmTransaction {
+            chain_id_num: self.evm_chain_id,
+            nonce: self.nonce,
+            max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+            max_fee_per_gas: fees.max_fee_per_gas,
+            gas_limit: NATIVE_TRANSFER_GAS,
+            to: parse_address(&self.from)?,
+            value: 0,
+            data: vec![],
+            authorization_list: vec![],
+        })
+    }
+}
+
+/// Smallest fees a node will accept as a replacement for `old` (rounded up)
+pub fn min_replacement_fees(old: Eip1559Fees) -> Eip1559Fees {
+    let bump = |v: u128| v.saturating_add((v.saturating_mul(REPLACEMENT_BUMP_PERCENT)).div_ceil(100));
+    Eip1559Fees {
+        max_fee_per_gas: bump(old.max_fee_per_gas),
+        max_priority_fee_per_gas: bump(old.max_priority_fee_per_gas),
+    }
+}
+
+/// Replacement fees: at least the required bump, or current market fees when higher
+pub fn replacement_fees(old: Eip1559Fees, market: Option<Eip1559Fees>) -> Eip1559Fees {
+    let min = min_replacement_fees(old);
+    let (max_fee, tip) = match market {
+        Some(m) => (
+            min.max_fee_per_gas.max(m.max_fee_per_gas),
+            min.max_priority_fee_per_gas.max(m.max_priority_fee_per_gas),
+        ),
+        None => (min.max_fee_per_gas, min.max_priority_fee_per_gas),
+    };
+    Eip1559Fees {
+        max_fee_per_gas: max_fee.max(tip),
+        max_priority_fee_per_gas: tip,
+    }
+}
+
+/// Latest broadcast per nonce for an account, ordered by nonce
+pub fn latest_by_nonce<'a>(list: &'a [PendingEvmTx], chain: &str, from: &str) -> Vec<&'a PendingEvmTx> {
+    let mut latest: Vec<&PendingEvmTx> = Vec::new();
+    for tx in list.iter().filter(|t| t.chain == chain && t.from.eq_ignore_ascii_case(from)) {
+        match latest.iter_mut().find(|l| l.nonce == tx.nonce) {
+            Some(existing) if existing.submitted_at <= tx.submitted_at => *existing = tx,
+            Some(_) => {}
+            None => latest.push(tx),
+        }
+    }
+    latest.sort_by_key(|t| t.nonce);
+    latest
+}
+
+/// Drop entries whose nonce is below the account's mined transaction count.
+/// Returns true when anything was removed.
+pub fn prune_mined(list: &mut Vec<PendingEvmTx>, chain: &str, from: &str, mined_nonce: u64) -> bool {
+    let before = list.len();
+    list.retain(|t| !(t.chain == chain && t.from.eq_ignore_ascii_case(from) && t.nonce < mined_nonce));
+    list.len() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GWEI: u128 = 1_000_000_000;
+    const FROM: &str = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+    fn pending(nonce: u64, submitted_at: u64, kind: PendingKind) -> PendingEvmTx {
+        let tx = EvmTransaction {
+            chain_id_num: 1,
+            nonce,
+            max_priority_fee_per_gas: 2 * GWEI,
+            max_fee_per_gas: 30 * GWEI,
+            gas_limit: 60_000,
+            to: [0x11; 20],
+            value: 5,
+            data: vec![0xa9, 0x05, 0x9c, 0xbb],
+            authorization_list: vec![],
+        };
+        PendingEvmTx::from_transaction(&tx, &format!("0x{:064x}", submitted_at), "ethereum", FROM, kind, submitted_at)
+    }
+
+    #[test]
+    fn test_min_replacement_bump() {
+        let old = Eip1559Fees { max_fee_per_gas: 30 * GWEI, max_priority_fee_per_gas: 2 * GWEI };
+        let new = min_replacement_fees(old);
+        assert_eq!(new.max_fee_per_gas, 33 * GWEI);
+        assert_eq!(new.max_priority_fee_per_gas, 2_200_000_000);
+
+        // Rounds up so tiny values still increase
+        let tiny = min_replacement_fees(Eip1559Fees { max_fee_per_gas: 1, max_priority_fee_per_gas: 0 });
+        assert_eq!(tiny.max_fee_per_gas, 2);
+        assert_eq!(tiny.max_priority_fee_per_gas, 0);
+    }
+
+    #[test]
+    fn test_replacement_uses_market_when_higher() {
+        let old = Eip1559Fees { max_fee_per_gas: 30 * GWEI, max_priority_fee_per_gas: 2 * GWEI };
+        let market = Eip1559Fees { max_fee_per_gas: 80 * GWEI, max_priority_fee_per_gas: GWEI };
+        let new = replacement_fees(old, Some(market));
+        assert_eq!(new.max_fee_per_gas, 80 * GWEI);
+        assert_eq!(new.max_priority_fee_per_gas, 2_200_000_000);
+        new.validate().unwrap();
+    }
+
+    #[test]
+    fn test_speed_up_keeps_payload() {
+        let p = pending(7, 100, PendingKind::Original);
+        let original = p.to_transaction().unwrap();
+        let sped = p.speed_up(None).unwrap();
+        assert_eq!(sped.nonce, 7);
+        assert_eq!(sped.to, original.to);
+        assert_eq!(sped.value, original.value);
+        assert_eq!(sped.data, original.data);
+        assert_eq!(sped.gas_limit, original.gas_limit);
+        assert!(sped.max_fee_per_gas >= original.max_fee_per_gas * 110 / 100);
+        assert!(sped.max_priority_fee_per_gas >= original.max_priority_fee_per_gas * 110 / 100);
+    }
+
+    #[test]
+    fn test_cancel_is_zero_value_self_send() {
+        let p = pending(7, 100, PendingKind::Original);
+        let cancel = p.cancel(None).unwrap();
+        assert_eq!(cancel.nonce, 7);
+        assert_eq!(cancel.to, parse_address(FROM).unwrap());
+        assert_eq!(cancel.value, 0);
+        assert!(cancel.data.is_empty());
+        assert_eq!(cancel.gas_limit, NATIVE_TRANSFER_GAS);
+        assert_eq!(cancel.max_fee_per_gas, 33 * GWEI);
+    }
+
+    #[test]
+    fn test_latest_by_nonce_and_prune() {
+        let mut list = vec![
+            pending(3, 100, PendingKind::Original),
+            pending(4, 110, PendingKind::Original),
+            pending(3, 200, PendingKind::SpeedUp),
+        ];
+        let latest = latest_by_nonce(&list, "ethereum", FROM);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[0].nonce, 3);
+        assert_eq!(latest[0].kind, PendingKind::SpeedUp);
+        assert_eq!(latest[1].nonce, 4);
+        assert!(latest_by_nonce(&list, "polygon", FROM).is_empty());
+
+        assert!(prune_mined(&mut list, "ethereum", FROM, 4));
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].nonce, 4);
+        assert!(!prune_mined(&mut list, "ethereum", FROM, 4));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let p = pending(1, 100, PendingKind::Cancel);
+        let json = serde_json::to_string(&p).unwrap();
+        let back: PendingEvmTx = serde_json::from_str(&json).unwrap();
+        assert_eq!(p, back);
+    }
+}
@@ -0,0 +1,628 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/evm_simulate: Pre-sign simulation of EVM calls (eth_simulateV1, debug_traceCall)
+// and extraction of the balance deltas, approvals and NFT moves affecting the sender
+
+use serde_json::{json, Value};
+
+use crate::chains::evm::eip55_checksum;
+use crate::signing::keccak256;
+
+/// Emitter of the synthetic Transfer logs that eth_simulateV1 adds for native-coin
+/// movements when `traceTransfers` is enabled (0xEeee…EEeE)
+pub const NATIVE_TRANSFER_EMITTER: [u8; 20] = [0xee; 20];
+
+const EVENT_TRANSFER: &str = "Transfer(address,address,uint256)";
+const EVENT_APPROVAL: &str = "Approval(address,address,uint256)";
+const EVENT_APPROVAL_FOR_ALL: &str = "ApprovalForAll(address,address,bool)";
+const EVENT_TRANSFER_SINGLE: &str = "TransferSingle(address,address,address,uint256,uint256)";
+const EVENT_TRANSFER_BATCH: &str = "TransferBatch(address,address,address,uint256[],uint256[])";
+
+/// Error(string) selector
+const SELECTOR_ERROR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Panic(uint256) selector
+const SELECTOR_PANIC: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Custom errors worth naming: OpenZeppelin v5 token/access errors and Permit2
+const KNOWN_ERRORS: &[&str] = &[
+    "ERC20InsufficientBalance(address,uint256,uint256)",
+    "ERC20InsufficientAllowance(address,uint256,uint256)",
+    "ERC20InvalidSender(address)",
+    "ERC20InvalidReceiver(address)",
+    "ERC20InvalidApprover(address)",
+    "ERC20InvalidSpender(address)",
+    "ERC721NonexistentToken(uint256)",
+    "ERC721IncorrectOwner(address,uint256,address)",
+    "ERC721InsufficientApproval(address,uint256)",
+    "ERC1155InsufficientBalance(address,uint256,uint256,uint256)",
+    "ERC1155MissingApprovalForAll(address,address)",
+    "OwnableUnauthorizedAccount(address)",
+    "AccessControlUnauthorizedAccount(address,bytes32)",
+    "EnforcedPause()",
+    "ReentrancyGuardReentrantCall()",
+    "SafeERC20FailedOperation(address)",
+    "AllowanceExpired(uint256)",
+    "InsufficientAllowance(uint256)",
+    "SignatureExpired(uint256)",
+    "InvalidNonce()",
+];
+
+/// A call to simulate from the user's account
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimCall {
+    pub from: [u8; 20],
+    pub to: [u8; 20],
+    pub value: u128,
+    pub data: Vec<u8>,
+}
+
+impl SimCall {
+    fn call_object(&self) -> Value {
+        json!({
+            "from": format!("0x{}", hex::encode(self.from)),
+            "to": format!("0x{}", hex::encode(self.to)),
+            "value": format!("0x{:x}", self.value),
+            "data": format!("0x{}", hex::encode(&self.data)),
+        })
+    }
+
+    /// eth_simulateV1 request with native transfers traced as logs
+    pub fn simulate_v1_body(&self) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "eth_simulateV1",
+            "params": [{
+                "blockStateCalls": [{ "calls": [self.call_object()] }],
+                "traceTransfers": true,
+                "validation": false,
+            }, "latest"],
+            "id": 1,
+        })
+    }
+
+    /// debug_traceCall request using the callTracer with logs
+    pub fn trace_call_body(&self) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "debug_traceCall",
+            "params": [self.call_object(), "latest", {
+                "tracer": "callTracer",
+                "tracerConfig": { "withLog": true },
+            }],
+            "id": 1,
+        })
+    }
+
+    /// Plain eth_call, used when the node supports neither simulation method
+    pub fn eth_call_body(&self) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "eth_call",
+            "params": [self.call_object(), "latest"],
+            "id": 1,
+        })
+    }
+}
+
+/// Net change of one ERC-20 balance (raw units)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenDelta {
+    pub token: String,
+    pub delta: i128,
+}
+
+/// An ERC-721 or ERC-1155 token entering or leaving the account
+#[derive(Debug, Clone, PartialEq)]
+pub struct NftTransfer {
+    pub collection: String,
+    pub token_id: String,
+    pub amount: u128,
+    pub incoming: bool,
+}
+
+/// Allowance granted (or revoked) by the account during the call
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApprovalChange {
+    /// ERC-20 allowance; `None` means unlimited
+    Token { token: String, spender: String, amount: Option<u128> },
+    /// ERC-721 approval for a single token id
+    Nft { collection: String, spender: String, token_id: String },
+    /// setApprovalForAll on a whole collection
+    All { collection: String, operator: String, approved: bool },
+}
+
+/// Everything the call would move in or out of the account
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetChanges {
+    /// Native coin delta in wei (gas not included)
+    pub native: i128,
+    pub tokens: Vec<TokenDelta>,
+    pub nfts: Vec<NftTransfer>,
+    pub approvals: Vec<ApprovalChange>,
+}
+
+impl AssetChanges {
+    pub fn is_empty(&self) -> bool {
+        self.native == 0 && self.tokens.is_empty() && self.nfts.is_empty() && self.approvals.is_empty()
+    }
+
+    fn add_token(&mut self, token: &[u8; 20], delta: i128) {
+        let token = eip55_checksum(token);
+        match self.tokens.iter_mut().find(|t| t.token == token) {
+            Some(t) => t.delta = t.delta.saturating_add(delta),
+            None => self.tokens.push(TokenDelta { token, delta }),
+        }
+    }
+
+    fn add_nft(&mut self, collection: &[u8; 20], id: &[u8], amount: u128, incoming: bool) {
+        self.nfts.push(NftTransfer {
+            collection: eip55_checksum(collection),
+            token_id: word_to_decimal(id),
+            amount,
+            incoming,
+        });
+    }
+
+    /// Fold one emitted log into the changes, keeping only events involving `owner`
+    fn apply_log(&mut self, owner: &[u8; 20], log: &Log) {
+        let Some(sig) = log.topics.first() else { return };
+        let topic_addr = |i: usize| log.topics.get(i).map(word_address);
+        let mine = |i: usize| topic_addr(i).as_ref() == Some(owner);
+
+        if *sig == keccak256(EVENT_TRANSFER.as_bytes()) {
+            let (from_me, to_me) = (mine(1), mine(2));
+            if from_me == to_me {
+                return;
+            }
+            let sign = if to_me { 1 } else { -1 };
+            if log.address == NATIVE_TRANSFER_EMITTER && log.topics.len() == 3 {
+                self.native = self.native.saturating_add(sign * word_to_i128(&log.data));
+            } else if log.topics.len() == 3 && log.data.len() >= 32 {
+                self.add_token(&log.address, sign * word_to_i128(&log.data));
+            } else if log.topics.len() == 4 {
+                self.add_nft(&log.address, &log.topics[3], 1, to_me);
+            }
+        } else if *sig == keccak256(EVENT_TRANSFER_SINGLE.as_bytes()) && log.topics.len() == 4 {
+            let (from_me, to_me) = (mine(2), mine(3));
+            if from_me != to_me && log.data.len() >= 64 {
+                self.add_nft(&log.address, &log.data[..32], word_to_u128(&log.data[32..64]).unwrap_or(u128::MAX), to_me);
+            }
+        } else if *sig == keccak256(EVENT_TRANSFER_BATCH.as_bytes()) && log.topics.len() == 4 {
+            let (from_me, to_me) = (mine(2), mine(3));
+            if from_me == to_me {
+                return;
+            }
+            let (Some(ids), Some(amounts)) = (abi_word_array(&log.data, 0), abi_word_array(&log.data, 1)) else { return };
+            for (id, amount) in ids.iter().zip(amounts.iter()) {
+                self.add_nft(&log.address, id, word_to_u128(amount).unwrap_or(u128::MAX), to_me);
+            }
+        } else if *sig == keccak256(EVENT_APPROVAL.as_bytes()) && mine(1) {
+            let Some(spender) = topic_addr(2) else { return };
+            if log.topics.len() == 4 {
+                self.approvals.push(ApprovalChange::Nft {
+                    collection: eip55_checksum(&log.address),
+                    spender: eip55_checksum(&spender),
+                    token_id: word_to_decimal(&log.topics[3]),
+                });
+            } else if log.data.len() >= 32 {
+                self.approvals.push(ApprovalChange::Token {
+                    token: eip55_checksum(&log.address),
+                    spender: eip55_checksum(&spender),
+                    amount: word_to_u128(&log.data[..32]),
+                });
+            }
+        } else if *sig == keccak256(EVENT_APPROVAL_FOR_ALL.as_bytes()) && mine(1) {
+            let Some(operator) = topic_addr(2) else { return };
+            self.approvals.push(ApprovalChange::All {
+                collection: eip55_checksum(&log.address),
+                operator: eip55_checksum(&operator),
+                approved: log.data.iter().any(|b| *b != 0),
+            });
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        self.tokens.retain(|t| t.delta != 0);
+        self
+    }
+}
+
+/// Result of a pre-sign simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationOutcome {
+    pub success: bool,
+    /// Decoded revert reason when the call fails
+    pub revert_reason: Option<String>,
+    /// `None` when the node could only run a plain eth_call
+    pub changes: Option<AssetChanges>,
+}
+
+impl SimulationOutcome {
+    fn reverted(reason: String) -> Self {
+        SimulationOutcome { success: false, revert_reason: Some(reason), changes: None }
+    }
+}
+
+struct Log {
+    address: [u8; 20],
+    topics: Vec<[u8; 32]>,
+    data: Vec<u8>,
+}
+
+fn parse_log(value: &Value) -> Option<Log> {
+    let address = hex_field(value.get("address")?)?;
+    let topics = value.get("topics")?.as_array()?.iter()
+        .map(|t| hex_field(t).and_then(|b| <[u8; 32]>::try_from(b.as_slice()).ok()))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Log {
+        address: address.as_slice().try_into().ok()?,
+        topics,
+        data: value.get("data").and_then(hex_field).unwrap_or_default(),
+    })
+}
+
+fn hex_field(value: &Value) -> Option<Vec<u8>> {
+    let s = value.as_str()?;
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.len() % 2 == 1 {
+        hex::decode(format!("0{}", s)).ok()
+    } else {
+        hex::decode(s).ok()
+    }
+}
+
+fn quantity_field(value: Option<&Value>) -> u128 {
+    value.and_then(hex_field)
+        .filter(|b| b.len() <= 16)
+        .map(|b| b.iter().fold(0u128, |acc, x| (acc << 8) | *x as u128))
+        .unwrap_or(0)
+}
+
+fn word_address(word: &[u8; 32]) -> [u8; 20] {
+    word[12..].try_into().unwrap()
+}
+
+/// uint256 word to u128, `None` if it does not fit (treated as unlimited for allowances)
+fn word_to_u128(word: &[u8]) -> Option<u128> {
+    if word.len() < 32 || word[..16].iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(u128::from_be_bytes(word[16..32].try_into().unwrap()))
+}
+
+fn word_to_i128(word: &[u8]) -> i128 {
+    word_to_u128(word).map(|v| v.min(i128::MAX as u128) as i128).unwrap_or(i128::MAX)
+}
+
+/// Big-endian uint256 word as a decimal string
+fn word_to_decimal(word: &[u8]) -> String {
+    let mut digits = word.to_vec();
+    let mut out = Vec::new();
+    while digits.iter().any(|b| *b != 0) {
+        let mut rem = 0u32;
+        for d in digits.iter_mut() {
+            let cur = (rem << 8) | *d as u32;
+            *d = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        out.push(b'0' + rem as u8);
+    }
+    if out.is_empty() {
+        return "0".into();
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
+
+/// Dynamic `uint256[]` argument number `index` of ABI-encoded data
+fn abi_word_array(data: &[u8], index: usize) -> Option<Vec<&[u8]>> {
+    let offset = word_to_u128(data.get(index * 32..index * 32 + 32)?)? as usize;
+    let len = word_to_u128(data.get(offset..offset.checked_add(32)?)?)? as usize;
+    let start = offset + 32;
+    let body = data.get(start..start.checked_add(len.checked_mul(32)?)?)?;
+    Some(body.chunks(32).collect())
+}
+
+/// Human-readable reason from revert data: Error(string), Panic(uint256)
+/// or a known custom error; unknown selectors are reported as such.
+pub fn decode_revert(data: &[u8]) -> Option<String> {
+    if data.len() < 4 {
+        return None;
+    }
+    let (selector, args) = data.split_at(4);
+    if selector == SELECTOR_ERROR {
+        let offset = word_to_u128(args.get(..32)?)? as usize;
+        let len = word_to_u128(args.get(offset..offset.checked_add(32)?)?)? as usize;
+        let text = args.get(offset + 32..(offset + 32).checked_add(len)?)?;
+        return Some(String::from_utf8_lossy(text).into_owned());
+    }
+    if selector == SELECTOR_PANIC {
+        let code = args.get(..32).and_then(word_to_u128).unwrap_or(u128::MAX);
+        let meaning = match code {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "corrupted storage byte array",
+            0x31 => "pop on empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to zero-initialized function",
+            _ => "unknown panic code",
+        };
+        return Some(format!("Panic(0x{:02x}): {}", code, meaning));
+    }
+    for signature in KNOWN_ERRORS {
+        if keccak256(signature.as_bytes())[..4] == *selector {
+            return Some(format_custom_error(signature, args));
+        }
+    }
+    Some(format!("custom error 0x{}", hex::encode(selector)))
+}
+
+fn format_custom_error(signature: &str, args: &[u8]) -> String {
+    let (name, params) = signature.split_once('(').unwrap_or((signature, ")"));
+    let types: Vec<&str> = params.trim_end_matches(')').split(',').filter(|t| !t.is_empty()).collect();
+    let values: Vec<String> = types.iter().enumerate().map(|(i, ty)| {
+        let Some(word) = args.get(i * 32..i * 32 + 32) else { return "?".to_string() };
+        match *ty {
+            "address" => eip55_checksum(&word[12..]),
+            "bool" => (word[31] != 0).to_string(),
+            "bytes32" => format!("0x{}", hex::encode(word)),
+            _ => word_to_decimal(word),
+        }
+    }).collect();
+    format!("{}({})", name, values.join(", "))
+}
+
+fn rpc_revert(error: &Value) -> String {
+    error.get("data")
+        .and_then(hex_field)
+        .and_then(|d| decode_revert(&d))
+        .or_else(|| error.get("message").and_then(|m| m.as_str()).map(String::from))
+        .unwrap_or_else(|| "execution reverted".into())
+}
+
+/// Parse the `result` of eth_simulateV1 for a single call
+pub fn parse_simulate_v1(result: &Value, owner: &[u8; 20]) -> Result<SimulationOutcome, String> {
+    let call = result.get(0)
+        .and_then(|block| block.get("calls"))
+        .and_then(|calls| calls.get(0))
+        .ok_or("Empty eth_simulateV1 result")?;
+    if call.get("status").and_then(|s| s.as_str()) != Some("0x1") {
+        let reason = call.get("returnData")
+            .and_then(hex_field)
+            .and_then(|d| decode_revert(&d))
+            .or_else(|| call.get("error").map(rpc_revert))
+            .unwrap_or_else(|| "execution reverted".into());
+        return Ok(SimulationOutcome::reverted(reason));
+    }
+    let mut changes = AssetChanges::default();
+    for log in call.get("logs").and_then(|l| l.as_array()).into_iter().flatten() {
+        if let Some(log) = parse_log(log) {
+            changes.apply_log(owner, &log);
+        }
+    }
+    Ok(SimulationOutcome { success: true, revert_reason: None, changes: Some(changes.finish()) })
+}
+
+/// Parse the callTracer frame tree returned by debug_traceCall
+pub fn parse_call_trace(result: &Value, owner: &[u8; 20]) -> Result<SimulationOutcome, String> {
+    if !result.is_object() {
+        return Err("Invalid debug_traceCall result".into());
+    }
+    if result.get("error").is_some() {
+        let reason = result.get("output")
+            .and_then(hex_field)
+            .and_then(|d| decode_revert(&d))
+            .or_else(|| result.get("revertReason").and_then(|r| r.as_str()).map(String::from))
+            .or_else(|| result.get("error").and_then(|e| e.as_str()).map(String::from))
+            .unwrap_or_else(|| "execution reverted".into());
+        return Ok(SimulationOutcome::reverted(reason));
+    }
+    let mut changes = AssetChanges::default();
+    walk_frame(result, owner, &mut changes);
+    Ok(SimulationOutcome { success: true, revert_reason: None, changes: Some(changes.finish()) })
+}
+
+fn walk_frame(frame: &Value, owner: &[u8; 20], changes: &mut AssetChanges) {
+    // State changes of a reverted subcall are discarded, including its logs
+    if frame.get("error").is_some() {
+        return;
+    }
+    let moves_value = !matches!(frame.get("type").and_then(|t| t.as_str()), Some("DELEGATECALL" | "STATICCALL"));
+    if moves_value {
+        let value = quantity_field(frame.get("value")).min(i128::MAX as u128) as i128;
+        let addr = |key: &str| frame.get(key).and_then(hex_field);
+        let from_me = addr("from").as_deref() == Some(owner.as_slice());
+        let to_me = addr("to").as_deref() == Some(owner.as_slice());
+        if from_me && !to_me {
+            changes.native = changes.native.saturating_sub(value);
+        } else if to_me && !from_me {
+            changes.native = changes.native.saturating_add(value);
+        }
+    }
+    for log in frame.get("logs").and_then(|l| l.as_array()).into_iter().flatten() {
+        if let Some(log) = parse_log(log) {
+            changes.apply_log(owner, &log);
+        }
+    }
+    for child in frame.get("calls").and_then(|c| c.as_array()).into_iter().flatten() {
+        walk_frame(child, owner, changes);
+    }
+}
+
+/// Interpret a full eth_call JSON-RPC response. Only success or the revert
+/// reason is known; asset changes stay `None`.
+pub fn parse_eth_call_response(response: &Value) -> Result<SimulationOutcome, String> {
+    if let Some(error) = response.get("error") {
+        return Ok(SimulationOutcome::reverted(rpc_revert(error)));
+    }
+    if response.get("result").is_none() {
+        return Err("Invalid RPC response".into());
+    }
+    Ok(SimulationOutcome { success: true, revert_reason: None, changes: None })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: [u8; 20] = [0x11; 20];
+    const OTHER: [u8; 20] = [0x22; 20];
+    const TOKEN: [u8; 20] = [0x33; 20];
+
+    fn topic(sig: &str) -> String {
+        format!("0x{}", hex::encode(keccak256(sig.as_bytes())))
+    }
+
+    fn addr_topic(addr: &[u8; 20]) -> String {
+        format!("0x{:0>64}", hex::encode(addr))
+    }
+
+    fn word(value: u128) -> String {
+        format!("{:064x}", value)
+    }
+
+    fn log(address: &[u8; 20], topics: Vec<String>, data: String) -> Value {
+        json!({ "address": format!("0x{}", hex::encode(address)), "topics": topics, "data": format!("0x{}", data) })
+    }
+
+    #[test]
+    fn test_request_bodies() {
+        let call = SimCall { from: OWNER, to: TOKEN, value: 255, data: vec![0xab] };
+        let sim = call.simulate_v1_body();
+        assert_eq!(sim["method"], "eth_simulateV1");
+        assert_eq!(sim["params"][0]["traceTransfers"], true);
+        assert_eq!(sim["params"][0]["blockStateCalls"][0]["calls"][0]["value"], "0xff");
+        let trace = call.trace_call_body();
+        assert_eq!(trace["params"][2]["tracer"], "callTracer");
+        assert_eq!(trace["params"][2]["tracerConfig"]["withLog"], true);
+        assert_eq!(call.eth_call_body()["params"][0]["data"], "0xab");
+    }
+
+    #[test]
+    fn test_decode_error_string_and_panic() {
+        let mut data = SELECTOR_ERROR.to_vec();
+        data.extend(hex::decode(format!("{}{}{:0<64}", word(32), word(5), hex::encode("oops!"))).unwrap());
+        assert_eq!(decode_revert(&data).unwrap(), "oops!");
+
+        let mut panic = SELECTOR_PANIC.to_vec();
+        panic.extend(hex::decode(word(0x11)).unwrap());
+        assert_eq!(decode_revert(&panic).unwrap(), "Panic(0x11): arithmetic overflow or underflow");
+        assert_eq!(decode_revert(&[0x01, 0x02]), None);
+    }
+
+    #[test]
+    fn test_decode_custom_errors() {
+        let mut data = keccak256(b"ERC20InsufficientBalance(address,uint256,uint256)")[..4].to_vec();
+        data.extend(hex::decode(format!("{:0>64}{}{}", hex::encode(OWNER), word(5), word(100))).unwrap());
+        assert_eq!(
+            decode_revert(&data).unwrap(),
+            format!("ERC20InsufficientBalance({}, 5, 100)", eip55_checksum(&OWNER)),
+        );
+        assert_eq!(decode_revert(&[0xde, 0xad, 0xbe, 0xef]).unwrap(), "custom error 0xdeadbeef");
+    }
+
+    #[test]
+    fn test_word_to_decimal() {
+        assert_eq!(word_to_decimal(&[0u8; 32]), "0");
+        assert_eq!(word_to_decimal(&hex::decode(word(1234567890)).unwrap()), "1234567890");
+        assert_eq!(
+            word_to_decimal(&[0xff; 32]),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        );
+    }
+
+    #[test]
+    fn test_parse_simulate_v1_changes() {
+        let result = json!([{ "calls": [{
+            "status": "0x1",
+            "returnData": "0x",
+            "logs": [
+                // 1 ETH out (synthetic native transfer log)
+                log(&NATIVE_TRANSFER_EMITTER, vec![topic(EVENT_TRANSFER), addr_topic(&OWNER), addr_topic(&OTHER)], word(10u128.pow(18))),
+                // 500 tokens in, 200 back out
+                log(&TOKEN, vec![topic(EVENT_TRANSFER), addr_topic(&OTHER), addr_topic(&OWNER)], word(500)),
+                log(&TOKEN, vec![topic(EVENT_TRANSFER), addr_topic(&OWNER), addr_topic(&OTHER)], word(200)),
+                // ERC-721 #7 leaves the account
+                log(&OTHER, vec![topic(EVENT_TRANSFER), addr_topic(&OWNER), addr_topic(&OTHER), format!("0x{}", word(7))], String::new()),
+                // Unlimited approval
+                log(&TOKEN, vec![topic(EVENT_APPROVAL), addr_topic(&OWNER), addr_topic(&OTHER)], "ff".repeat(32)),
+                // Transfer between third parties is ignored
+                log(&TOKEN, vec![topic(EVENT_TRANSFER), addr_topic(&OTHER), addr_topic(&TOKEN)], word(1)),
+            ],
+        }]}]);
+        let outcome = parse_simulate_v1(&result, &OWNER).unwrap();
+        assert!(outcome.success);
+        let changes = outcome.changes.unwrap();
+        assert_eq!(changes.native, -(10i128.pow(18)));
+        assert_eq!(changes.tokens, vec![TokenDelta { token: eip55_checksum(&TOKEN), delta: 300 }]);
+        assert_eq!(changes.nfts.len(), 1);
+        assert_eq!(changes.nfts[0].token_id, "7");
+        assert!(!changes.nfts[0].incoming);
+        assert_eq!(changes.approvals, vec![ApprovalChange::Token {
+            token: eip55_checksum(&TOKEN),
+            spender: eip55_checksum(&OTHER),
+            amount: None,
+        }]);
+    }
+
+    #[test]
+    fn test_parse_simulate_v1_revert() {
+        let mut panic = SELECTOR_PANIC.to_vec();
+        panic.extend(hex::decode(word(0x12)).unwrap());
+        let result = json!([{ "calls": [{
+            "status": "0x0",
+            "returnData": format!("0x{}", hex::encode(&panic)),
+            "logs": [],
+            "error": { "code": 3, "message": "execution reverted" },
+        }]}]);
+        let outcome = parse_simulate_v1(&result, &OWNER).unwrap();
+        assert!(!outcome.success);
+        assert_eq!(outcome.revert_reason.unwrap(), "Panic(0x12): division or modulo by zero");
+        assert!(outcome.changes.is_none());
+    }
+
+    #[test]
+    fn test_parse_call_trace_skips_reverted_frames() {
+        let owner_hex = format!("0x{}", hex::encode(OWNER));
+        let other_hex = format!("0x{}", hex::encode(OTHER));
+        let batch_data = format!("{}{}{}{}{}{}{}{}", word(64), word(160), word(2), word(1), word(2), word(2), word(10), word(20));
+        let trace = json!({
+            "type": "CALL", "from": owner_hex, "to": other_hex, "value": "0x64",
+            "calls": [
+                {
+                    "type": "CALL", "from": other_hex, "to": owner_hex, "value": "0xa",
+                    "logs": [log(&TOKEN, vec![topic(EVENT_TRANSFER_BATCH), addr_topic(&OTHER), addr_topic(&OTHER), addr_topic(&OWNER)], batch_data)],
+                },
+                {
+                    "type": "CALL", "from": other_hex, "to": owner_hex, "value": "0x3e8", "error": "execution reverted",
+                    "logs": [log(&TOKEN, vec![topic(EVENT_TRANSFER), addr_topic(&OTHER), addr_topic(&OWNER)], word(9))],
+                },
+                { "type": "DELEGATECALL", "from": other_hex, "to": owner_hex, "value": "0x64" },
+            ],
+        });
+        let changes = parse_call_trace(&trace, &OWNER).unwrap().changes.unwrap();
+        assert_eq!(changes.native, -90);
+        assert!(changes.tokens.is_empty());
+        assert_eq!(changes.nfts.len(), 2);
+        assert_eq!((changes.nfts[1].token_id.as_str(), changes.nfts[1].amount, changes.nfts[1].incoming), ("2", 20, true));
+    }
+
+    #[test]
+    fn test_parse_call_trace_and_eth_call_reverts() {
+        let mut data = SELECTOR_ERROR.to_vec();
+        data.extend(hex::decode(format!("{}{}{:0<64}", word(32), word(3), hex::encode("bad"))).unwrap());
+        let trace = json!({ "type": "CALL", "error": "execution reverted", "output": format!("0x{}", hex::encode(&data)) });
+        assert_eq!(parse_call_trace(&trace, &OWNER).unwrap().revert_reason.unwrap(), "bad");
+
+        let response = json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": 3, "message": "execution reverted", "data": "0xdeadbeef" } });
+        let outcome = parse_eth_call_response(&response).unwrap();
+        assert_eq!(outcome.revert_reason.unwrap(), "custom error 0xdeadbeef");
+        let ok = parse_eth_call_response(&json!({ "result": "0x" })).unwrap();
+        assert!(ok.success && ok.changes.is_none());
+    }
+}
@@ -4,7 +4,18 @@
 // tx: Transaction construction and signing for all supported chains
 
 pub mod evm;
+pub mod evm_fees;
+pub mod evm_pending;
+pub mod evm_simulate;
+pub mod eip7702;
+pub mod erc4337;
+pub mod bundler;
+pub mod safe;
 pub mod solana;
+pub mod solana_message;
+pub mod solana_dapp;
+pub mod solana_fees;
+pub mod solana_stake;
 pub mod ton;
 pub mod cosmos;
 pub mod bitcoin;
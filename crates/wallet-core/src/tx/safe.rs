@@ -0,0 +1,573 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/safe: Safe (Gnosis Safe) multisig — SafeTx EIP-712 hash, owner signatures,
+// signature packing, execTransaction calldata and Safe Transaction Service payloads.
+// Targets Safe v1.3.0+ (domain = chainId + verifyingContract).
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use zeroize::Zeroize;
+
+use super::erc4337::{abi_bytes_tail, address_word, uint_word};
+use super::evm::parse_address;
+use crate::chains::evm::eip55_checksum;
+use crate::signing::eip712::{eip712_hash, hash_struct, sign_typed_data_hash};
+use crate::signing::keccak256;
+use crate::signing::personal_sign::{personal_sign_hash, recover_prehash_address};
+
+/// Safe.execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)
+const SELECTOR_EXEC_TRANSACTION: [u8; 4] = [0x6a, 0x76, 0x12, 0x02];
+/// Safe.nonce()
+const SELECTOR_NONCE: [u8; 4] = [0xaf, 0xfe, 0xd0, 0xe0];
+/// Safe.getOwners()
+const SELECTOR_GET_OWNERS: [u8; 4] = [0xa0, 0xe6, 0x7e, 0x2b];
+/// Safe.getThreshold()
+const SELECTOR_GET_THRESHOLD: [u8; 4] = [0xe7, 0x52, 0x35, 0xb8];
+
+const SAFE_TX_TYPE: &[u8] = b"SafeTx(address to,uint256 value,bytes data,uint8 operation,uint256 safeTxGas,uint256 baseGas,uint256 gasPrice,address gasToken,address refundReceiver,uint256 nonce)";
+const DOMAIN_TYPE: &[u8] = b"EIP712Domain(uint256 chainId,address verifyingContract)";
+
+/// A Safe the user co-signs for, tracked like a watch-only account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchedSafe {
+    /// Checksummed Safe address
+    pub address: String,
+    /// UI chain key ("ethereum", "base", "evm:100", ...)
+    pub chain: String,
+    pub evm_chain_id: u64,
+    #[serde(default)]
+    pub name: String,
+}
+
+impl WatchedSafe {
+    pub fn new(address: &str, chain: &str, evm_chain_id: u64, name: &str) -> Result<Self, String> {
+        let addr = parse_address(address)?;
+        Ok(WatchedSafe {
+            address: eip55_checksum(&addr),
+            chain: chain.to_string(),
+            evm_chain_id,
+            name: name.trim().to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SafeOperation {
+    #[default]
+    Call = 0,
+    DelegateCall = 1,
+}
+
+/// The SafeTx struct signed by owners
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SafeTransaction {
+    pub to: [u8; 20],
+    pub value: u128,
+    pub data: Vec<u8>,
+    pub operation: SafeOperation,
+    pub safe_tx_gas: u128,
+    pub base_gas: u128,
+    pub gas_price: u128,
+    pub gas_token: [u8; 20],
+    pub refund_receiver: [u8; 20],
+    pub nonce: u64,
+}
+
+/// How an owner's approval is encoded in the packed signatures blob
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureKind {
+    /// EIP-712 ECDSA over safeTxHash (v = 27/28)
+    Eip712,
+    /// eth_sign over safeTxHash (v = 31/32)
+    EthSign,
+    /// Pre-validated: the owner is the one calling execTransaction (v = 1)
+    ApprovedHash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafeSignature {
+    pub owner: [u8; 20],
+    pub kind: SignatureKind,
+    /// 65 bytes r ++ s ++ v
+    pub data: [u8; 65],
+}
+
+impl SafeSignature {
+    /// Signature valid when `owner` itself submits execTransaction
+    pub fn approved_hash(owner: [u8; 20]) -> Self {
+        let mut data = [0u8; 65];
+        data[12..32].copy_from_slice(&owner);
+        data[64] = 1;
+        SafeSignature { owner, kind: SignatureKind::ApprovedHash, data }
+    }
+
+    /// Parse a 65-byte ECDSA signature and recover its owner from `safe_tx_hash`
+    pub fn recover(safe_tx_hash: &[u8; 32], signature: &[u8]) -> Result<Self, String> {
+        let data: [u8; 65] = signature.try_into()
+            .map_err(|_| format!("Safe signature must be 65 bytes, got {}", signature.len()))?;
+        let (kind, owner) = match data[64] {
+            27 | 28 => (SignatureKind::Eip712, recover_prehash_address(safe_tx_hash, &data)?),
+            31 | 32 => {
+                let mut normalized = data;
+                normalized[64] -= 4;
+                (SignatureKind::EthSign, recover_prehash_address(&personal_sign_hash(safe_tx_hash), &normalized)?)
+            }
+            v => return Err(format!("Unsupported Safe signature type (v = {})", v)),
+        };
+        Ok(SafeSignature { owner, kind, data })
+    }
+}
+
+impl SafeTransaction {
+    /// Plain call with no gas refund (what the Safe web app proposes by default)
+    pub fn call(to: [u8; 20], value: u128, data: Vec<u8>, nonce: u64) -> Self {
+        SafeTransaction { to, value, data, nonce, ..Default::default() }
+    }
+
+    pub fn struct_hash(&self) -> [u8; 32] {
+        let mut encoded = Vec::with_capacity(10 * 32);
+        encoded.extend_from_slice(&address_word(&self.to));
+        encoded.extend_from_slice(&uint_word(self.value));
+        encoded.extend_from_slice(&keccak256(&self.data));
+        encoded.extend_from_slice(&uint_word(self.operation as u128));
+        encoded.extend_from_slice(&uint_word(self.safe_tx_gas));
+        encoded.extend_from_slice(&uint_word(self.base_gas));
+        encoded.extend_from_slice(&uint_word(self.gas_price));
+        encoded.extend_from_slice(&address_word(&self.gas_token));
+        encoded.extend_from_slice(&address_word(&self.refund_receiver));
+        encoded.extend_from_slice(&uint_word(self.nonce as u128));
+        hash_struct(&keccak256(SAFE_TX_TYPE), &encoded)
+    }
+
+    /// safeTxHash = keccak256(0x1901 ++ domainSeparator(safe, chainId) ++ structHash)
+    pub fn safe_tx_hash(&self, safe: &[u8; 20], chain_id: u64) -> [u8; 32] {
+        eip712_hash(&domain_separator(safe, chain_id), &self.struct_hash())
+    }
+
+    /// Owner's EIP-712 signature of this transaction
+    pub fn sign(&self, safe: &[u8; 20], chain_id: u64, private_key: &[u8; 32]) -> Result<SafeSignature, String> {
+        let hash = self.safe_tx_hash(safe, chain_id);
+        let data = sign_typed_data_hash(&domain_separator(safe, chain_id), &self.struct_hash(), private_key)?;
+        SafeSignature::recover(&hash, &data)
+    }
+
+    /// Sign with the wallet's EVM key, which is the Safe owner
+    pub fn sign_with_seed(&self, safe: &[u8; 20], chain_id: u64, seed: &[u8; 64]) -> Result<SafeSignature, String> {
+        let mut private_key = crate::chains::evm::get_private_key(seed)?;
+        let result = self.sign(safe, chain_id, &private_key);
+        private_key.zeroize();
+        result
+    }
+
+    /// Safe.execTransaction calldata with the given packed signatures
+    pub fn encode_exec_transaction(&self, signatures: &[u8]) -> Vec<u8> {
+        // 10 head words; `data` and `signatures` are dynamic
+        let data_tail = abi_bytes_tail(&self.data);
+        let data_offset = 10 * 32;
+        let signatures_offset = data_offset + data_tail.len();
+
+        let mut out = SELECTOR_EXEC_TRANSACTION.to_vec();
+        out.extend_from_slice(&address_word(&self.to));
+        out.extend_from_slice(&uint_word(self.value));
+        out.extend_from_slice(&uint_word(data_offset as u128));
+        out.extend_from_slice(&uint_word(self.operation as u128));
+        out.extend_from_slice(&uint_word(self.safe_tx_gas));
+        out.extend_from_slice(&uint_word(self.base_gas));
+        out.extend_from_slice(&uint_word(self.gas_price));
+        out.extend_from_slice(&address_word(&self.gas_token));
+        out.extend_from_slice(&address_word(&self.refund_receiver));
+        out.extend_from_slice(&uint_word(signatures_offset as u128));
+        out.extend_from_slice(&data_tail);
+        out.extend_from_slice(&abi_bytes_tail(signatures));
+        out
+    }
+
+    /// Body for POST /api/v1/safes/{safe}/multisig-transactions/ (proposal with the first signature)
+    pub fn proposal_body(&self, safe: &[u8; 20], chain_id: u64, signature: &SafeSignature) -> Value {
+        json!({
+            "to": eip55_checksum(&self.to),
+            "value": self.value.to_string(),
+            "data": if self.data.is_empty() { Value::Null } else { json!(format!("0x{}", hex::encode(&self.data))) },
+            "operation": self.operation as u8,
+            "safeTxGas": self.safe_tx_gas.to_string(),
+            "baseGas": self.base_gas.to_string(),
+            "gasPrice": self.gas_price.to_string(),
+            "gasToken": eip55_checksum(&self.gas_token),
+            "refundReceiver": eip55_checksum(&self.refund_receiver),
+            "nonce": self.nonce,
+            "contractTransactionHash": format!("0x{}", hex::encode(self.safe_tx_hash(safe, chain_id))),
+            "sender": eip55_checksum(&signature.owner),
+            "signature": format!("0x{}", hex::encode(signature.data)),
+            "origin": "Rusby Wallet",
+        })
+    }
+}
+
+/// EIP712Domain(uint256 chainId, address verifyingContract) separator of a Safe
+pub fn domain_separator(safe: &[u8; 20], chain_id: u64) -> [u8; 32] {
+    let mut encoded = Vec::with_capacity(64);
+    encoded.extend_from_slice(&uint_word(chain_id as u128));
+    encoded.extend_from_slice(&address_word(safe));
+    hash_struct(&keccak256(DOMAIN_TYPE), &encoded)
+}
+
+/// Concatenate signatures sorted by owner address, as Safe.checkNSignatures requires.
+/// Duplicate owners keep their first signature.
+pub fn pack_signatures(signatures: &[SafeSignature]) -> Vec<u8> {
+    let mut sorted: Vec<&SafeSignature> = signatures.iter().collect();
+    sorted.sort_by_key(|s| s.owner);
+    sorted.dedup_by_key(|s| s.owner);
+    sorted.iter().flat_map(|s| s.data).collect()
+}
+
+/// Body for POST /api/v1/multisig-transactions/{safeTxHash}/confirmations/
+pub fn confirmation_body(signature: &SafeSignature) -> Value {
+    json!({ "signature": format!("0x{}", hex::encode(signature.data)) })
+}
+
+/// Safe Transaction Service base URL for an EVM chain id
+pub fn service_url(chain_id: u64) -> Option<&'static str> {
+    Some(match chain_id {
+        1 => "https://safe-transaction-mainnet.safe.global",
+        10 => "https://safe-transaction-optimism.safe.global",
+        56 => "https://safe-transaction-bsc.safe.global",
+        100 => "https://safe-transaction-gnosis-chain.safe.global",
+        137 => "https://safe-transaction-polygon.safe.global",
+        8453 => "https://safe-transaction-base.safe.global",
+        42161 => "https://safe-transaction-arbitrum.safe.global",
+        84532 => "https://safe-transaction-base-sepolia.safe.global",
+        11155111 => "https://safe-transaction-sepolia.safe.global",
+        _ => return None,
+    })
+}
+
+pub fn encode_nonce_call() -> Vec<u8> {
+    SELECTOR_NONCE.to_vec()
+}
+
+pub fn encode_get_owners_call() -> Vec<u8> {
+    SELECTOR_GET_OWNERS.to_vec()
+}
+
+pub fn encode_get_threshold_call() -> Vec<u8> {
+    SELECTOR_GET_THRESHOLD.to_vec()
+}
+
+fn decode_hex(result: &str) -> Result<Vec<u8>, String> {
+    hex::decode(result.strip_prefix("0x").unwrap_or(result))
+        .map_err(|e| format!("Invalid eth_call result: {}", e))
+}
+
+/// Decode a single `uint256` return value (nonce, threshold)
+pub fn decode_uint(result: &str) -> Result<u64, String> {
+    let bytes = decode_hex(result)?;
+    if bytes.len() < 32 {
+        return Err("Short uint result (is this a Safe?)".into());
+    }
+    if bytes[..24].iter().any(|b| *b != 0) {
+        return Err("uint result out of range".into());
+    }
+    Ok(u64::from_be_bytes(bytes[24..32].try_into().unwrap()))
+}
+
+/// Decode the `address[]` returned by getOwners()
+pub fn decode_owners(result: &str) -> Result<Vec<[u8; 20]>, String> {
+    let bytes = decode_hex(result)?;
+    let word = |i: usize| bytes.get(i * 32..(i + 1) * 32).ok_or("Truncated owners result");
+    let offset = decode_uint(&hex::encode(word(0)?))? as usize;
+    if !offset.is_multiple_of(32) {
+        return Err("Malformed owners result".into());
+    }
+    let base = offset / 32;
+    let count = decode_uint(&hex::encode(word(base)?))? as usize;
+    (0..count)
+        .map(|i| {
+            let w = word(base + 1 + i)?;
+            let mut addr = [0u8; 20];
+            addr.copy_from_slice(&w[12..]);
+            Ok(addr)
+        })
+        .collect()
+}
+
+/// A queued Safe transaction from the Transaction Service, with verified confirmations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingSafeTx {
+    pub tx: SafeTransaction,
+    pub safe_tx_hash: [u8; 32],
+    pub confirmations: Vec<SafeSignature>,
+}
+
+impl PendingSafeTx {
+    pub fn is_signed_by(&self, owner: &[u8; 20]) -> bool {
+        self.confirmations.iter().any(|c| &c.owner == owner)
+    }
+
+    /// Confirmations that still count, i.e. from current owners
+    pub fn valid_confirmations(&self, owners: &[[u8; 20]]) -> usize {
+        self.confirmations.iter().filter(|c| owners.contains(&c.owner)).count()
+    }
+}
+
+/// Numbers come back as strings or JSON numbers depending on the field and service version
+fn service_uint(tx: &Value, field: &str) -> Result<u128, String> {
+    match tx.get(field) {
+        Some(Value::String(s)) => s.parse().map_err(|_| format!("Invalid {}: {}", field, s)),
+        Some(Value::Number(n)) => n.as_u64().map(u128::from).ok_or_else(|| format!("Invalid {}", field)),
+        None | Some(Value::Null) => Ok(0),
+        _ => Err(format!("Invalid {}", field)),
+    }
+}
+
+fn service_address(tx: &Value, field: &str) -> Result<[u8; 20], String> {
+    match tx.get(field).and_then(|v| v.as_str()) {
+        Some(a) => parse_address(a),
+        None => Ok([0u8; 20]),
+    }
+}
+
+/// Parse one multisig transaction from the service. The safeTxHash is recomputed
+/// locally and must match, and every confirmation must recover to its claimed owner;
+/// the service is not trusted for either.
+pub fn parse_service_tx(tx: &Value, safe: &[u8; 20], chain_id: u64) -> Result<PendingSafeTx, String> {
+    let data = match tx.get("data").and_then(|v| v.as_str()) {
+        Some(d) => hex::decode(d.trim_start_matches("0x")).map_err(|_| "Invalid data hex")?,
+        None => Vec::new(),
+    };
+    let operation = match service_uint(tx, "operation")? {
+        0 => SafeOperation::Call,
+        1 => SafeOperation::DelegateCall,
+        op => return Err(format!("Unknown Safe operation {}", op)),
+    };
+    let safe_tx = SafeTransaction {
+        to: service_address(tx, "to")?,
+        value: service_uint(tx, "value")?,
+        data,
+        operation,
+        safe_tx_gas: service_uint(tx, "safeTxGas")?,
+        base_gas: service_uint(tx, "baseGas")?,
+        gas_price: service_uint(tx, "gasPrice")?,
+        gas_token: service_address(tx, "gasToken")?,
+        refund_receiver: service_address(tx, "refundReceiver")?,
+        nonce: service_uint(tx, "nonce")? as u64,
+    };
+
+    let safe_tx_hash = safe_tx.safe_tx_hash(safe, chain_id);
+    let claimed = tx.get("safeTxHash").and_then(|v| v.as_str()).unwrap_or_default();
+    if !claimed.trim_start_matches("0x").eq_ignore_ascii_case(&hex::encode(safe_tx_hash)) {
+        return Err(format!("safeTxHash mismatch for nonce {}", safe_tx.nonce));
+    }
+
+    let confirmations = tx.get("confirmations")
+        .and_then(|c| c.as_array())
+        .map(|list| list.iter().filter_map(|c| {
+            let owner = parse_address(c.get("owner")?.as_str()?).ok()?;
+            let sig = hex::decode(c.get("signature")?.as_str()?.trim_start_matches("0x")).ok()?;
+            // Contract (EIP-1271) and on-chain approvals are not re-packed by us
+            let sig = SafeSignature::recover(&safe_tx_hash, &sig).ok()?;
+            (sig.owner == owner).then_some(sig)
+        }).collect())
+        .unwrap_or_default();
+
+    Ok(PendingSafeTx { tx: safe_tx, safe_tx_hash, confirmations })
+}
+
+/// Parse the `results` of GET /api/v1/safes/{safe}/multisig-transactions/?executed=false,
+/// dropping anything below the on-chain nonce. Sorted by nonce.
+/// Entries that fail to parse or verify are skipped; their errors are returned
+/// alongside so the caller can log them.
+pub fn parse_pending(response: &Value, safe: &[u8; 20], chain_id: u64, onchain_nonce: u64) -> Result<(Vec<PendingSafeTx>, Vec<String>), String> {
+    let results = response.get("results")
+        .and_then(|r| r.as_array())
+        .ok_or("Missing results in Safe service response")?;
+    let mut pending = Vec::new();
+    let mut skipped = Vec::new();
+    for tx in results.iter().filter(|tx| !tx.get("isExecuted").and_then(|e| e.as_bool()).unwrap_or(false)) {
+        match parse_service_tx(tx, safe, chain_id) {
+            Ok(p) if p.tx.nonce >= onchain_nonce => pending.push(p),
+            Ok(_) => {}
+            Err(e) => skipped.push(e),
+        }
+    }
+    pending.sort_by_key(|p| p.tx.nonce);
+    Ok((pending, skipped))
+}
+
+/// Nonce for a new proposal: after the on-chain nonce and every queued transaction
+pub fn next_nonce(onchain_nonce: u64, pending: &[PendingSafeTx]) -> u64 {
+    pending.iter()
+        .map(|p| p.tx.nonce + 1)
+        .max()
+        .unwrap_or(0)
+        .max(onchain_nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Hardhat account #0 (DO NOT use in production)
+    const TEST_KEY: [u8; 32] = [
+        0xac, 0x09, 0x74, 0xbe, 0xc3, 0x9a, 0x17, 0xe3,
+        0x6b, 0xa4, 0xa6, 0xb4, 0xd2, 0x38, 0xff, 0x94,
+        0x4b, 0xac, 0xb4, 0x78, 0xcb, 0xed, 0x5e, 0xfc,
+        0xae, 0x78, 0x4d, 0x7b, 0xf4, 0xf2, 0xff, 0x80,
+    ];
+    // Hardhat account #1
+    const TEST_KEY_2: [u8; 32] = [
+        0x59, 0xc6, 0x99, 0x5e, 0x99, 0x8f, 0x97, 0xa5,
+        0xa0, 0x04, 0x49, 0x66, 0xf0, 0x94, 0x53, 0x89,
+        0xdc, 0x9e, 0x86, 0xda, 0xe8, 0x8c, 0x7a, 0x84,
+        0x12, 0xf4, 0x60, 0x3b, 0x6b, 0x78, 0x69, 0x0d,
+    ];
+    const OWNER_1: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+    const OWNER_2: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    const SAFE: [u8; 20] = [0x5a; 20];
+
+    fn selector(sig: &str) -> [u8; 4] {
+        keccak256(sig.as_bytes())[..4].try_into().unwrap()
+    }
+
+    fn sample_tx() -> SafeTransaction {
+        SafeTransaction::call([0x11; 20], 1_000_000_000_000_000, vec![], 7)
+    }
+
+    #[test]
+    fn test_selectors_and_type_hashes() {
+        assert_eq!(
+            SELECTOR_EXEC_TRANSACTION,
+            selector("execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)")
+        );
+        assert_eq!(SELECTOR_NONCE, selector("nonce()"));
+        assert_eq!(SELECTOR_GET_OWNERS, selector("getOwners()"));
+        assert_eq!(SELECTOR_GET_THRESHOLD, selector("getThreshold()"));
+        // Constants from Safe.sol
+        assert_eq!(hex::encode(keccak256(SAFE_TX_TYPE)), "bb8310d486368db6bd6f849402fdd73ad53d316b5a4b2644ad6efe0f941286d8");
+        assert_eq!(hex::encode(keccak256(DOMAIN_TYPE)), "47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218");
+    }
+
+    #[test]
+    fn test_safe_tx_hash_binds_safe_chain_and_nonce() {
+        let tx = sample_tx();
+        let h = tx.safe_tx_hash(&SAFE, 1);
+        assert_ne!(h, tx.safe_tx_hash(&SAFE, 10));
+        assert_ne!(h, tx.safe_tx_hash(&[0x5b; 20], 1));
+        let mut next = tx.clone();
+        next.nonce = 8;
+        assert_ne!(h, next.safe_tx_hash(&SAFE, 1));
+    }
+
+    #[test]
+    fn test_sign_and_recover_signature_kinds() {
+        let tx = sample_tx();
+        let hash = tx.safe_tx_hash(&SAFE, 1);
+        let sig = tx.sign(&SAFE, 1, &TEST_KEY).unwrap();
+        assert_eq!(sig.kind, SignatureKind::Eip712);
+        assert_eq!(sig.owner, parse_address(OWNER_1).unwrap());
+
+        // eth_sign flavour: personal_sign over the hash with v + 4
+        let mut eth_sign = crate::signing::personal_sign::personal_sign(&hash, &TEST_KEY).unwrap();
+        eth_sign[64] += 4;
+        let recovered = SafeSignature::recover(&hash, &eth_sign).unwrap();
+        assert_eq!(recovered.kind, SignatureKind::EthSign);
+        assert_eq!(recovered.owner, sig.owner);
+        assert_eq!(recovered.data[64], eth_sign[64]);
+
+        let approved = SafeSignature::approved_hash(sig.owner);
+        assert_eq!(&approved.data[12..32], &sig.owner);
+        assert_eq!(approved.data[64], 1);
+        assert!(SafeSignature::recover(&hash, &approved.data).is_err());
+    }
+
+    #[test]
+    fn test_pack_signatures_sorted_by_owner() {
+        let tx = sample_tx();
+        let s1 = tx.sign(&SAFE, 1, &TEST_KEY).unwrap();
+        let s2 = tx.sign(&SAFE, 1, &TEST_KEY_2).unwrap();
+        // OWNER_2 (0x7099...) sorts before OWNER_1 (0xf39f...)
+        assert_eq!(s2.owner, parse_address(OWNER_2).unwrap());
+        let packed = pack_signatures(&[s1.clone(), s2.clone(), s1.clone()]);
+        assert_eq!(packed.len(), 130);
+        assert_eq!(&packed[..65], &s2.data);
+        assert_eq!(&packed[65..], &s1.data);
+    }
+
+    #[test]
+    fn test_exec_transaction_layout() {
+        let tx = SafeTransaction::call([0x11; 20], 5, vec![0xaa, 0xbb], 0);
+        let sigs = [0xcc; 65];
+        let data = tx.encode_exec_transaction(&sigs);
+        assert_eq!(&data[..4], &SELECTOR_EXEC_TRANSACTION);
+        let word = |i: usize| &data[4 + i * 32..4 + (i + 1) * 32];
+        assert_eq!(&word(0)[12..], &[0x11; 20]);
+        assert_eq!(word(1)[31], 5);
+        assert_eq!(word(2)[30..], [0x01, 0x40]); // data offset = 10 words
+        assert_eq!(word(9)[30..], [0x01, 0x80]); // 320 + 64 (len word + 1 padded word)
+        assert_eq!(word(10)[31], 2);
+        assert_eq!(&word(11)[..2], &[0xaa, 0xbb]);
+        assert_eq!(word(12)[31], 65);
+        assert_eq!(data.len(), 4 + 32 * 16);
+    }
+
+    #[test]
+    fn test_parse_service_tx_verifies_hash_and_confirmations() {
+        let tx = SafeTransaction::call([0x11; 20], 42, vec![0x01, 0x02], 3);
+        let hash = tx.safe_tx_hash(&SAFE, 11155111);
+        let s1 = tx.sign(&SAFE, 11155111, &TEST_KEY).unwrap();
+        let s2 = tx.sign(&SAFE, 11155111, &TEST_KEY_2).unwrap();
+        let mut body = tx.proposal_body(&SAFE, 11155111, &s1);
+        body["safeTxHash"] = body["contractTransactionHash"].clone();
+        body["safeTxGas"] = json!(0);
+        body["confirmations"] = json!([
+            {"owner": OWNER_1, "signature": format!("0x{}", hex::encode(s1.data)), "signatureType": "EOA"},
+            // Signature from OWNER_2 claimed by OWNER_1: dropped
+            {"owner": OWNER_1, "signature": format!("0x{}", hex::encode(s2.data)), "signatureType": "EOA"},
+            {"owner": OWNER_2, "signature": "0x", "signatureType": "APPROVED_HASH"},
+        ]);
+
+        let parsed = parse_service_tx(&body, &SAFE, 11155111).unwrap();
+        assert_eq!(parsed.tx, tx);
+        assert_eq!(parsed.safe_tx_hash, hash);
+        assert_eq!(parsed.confirmations, vec![s1.clone()]);
+        assert!(parsed.is_signed_by(&s1.owner));
+        assert_eq!(parsed.valid_confirmations(&[s2.owner]), 0);
+
+        // Tampered value: the service hash no longer matches
+        body["value"] = json!("43");
+        assert!(parse_service_tx(&body, &SAFE, 11155111).unwrap_err().contains("mismatch"));
+
+        // One bad entry is skipped and reported without hiding the rest of the queue
+        body["value"] = json!("42");
+        let response = json!({"results": [
+            {"to": OWNER_2, "value": "0", "nonce": 1, "safeTxHash": "0x00", "isExecuted": false},
+            body,
+        ]});
+        let (pending, skipped) = parse_pending(&response, &SAFE, 11155111, 0).unwrap();
+        assert_eq!(pending, vec![parsed]);
+        assert_eq!(skipped.len(), 1);
+        assert!(parse_pending(&response, &SAFE, 11155111, 4).unwrap().0.is_empty());
+        assert!(parse_pending(&json!({}), &SAFE, 1, 0).is_err());
+        assert_eq!(parse_pending(&json!({"results": []}), &SAFE, 1, 0).unwrap(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_next_nonce_and_decoders() {
+        let pending = |nonce| PendingSafeTx {
+            tx: SafeTransaction::call([0; 20], 0, vec![], nonce),
+            safe_tx_hash: [0; 32],
+            confirmations: vec![],
+        };
+        assert_eq!(next_nonce(4, &[]), 4);
+        assert_eq!(next_nonce(4, &[pending(4), pending(6)]), 7);
+
+        assert_eq!(decode_uint(&format!("0x{}", hex::encode(uint_word(2)))).unwrap(), 2);
+        let mut owners_result = uint_word(0x20).to_vec();
+        owners_result.extend_from_slice(&uint_word(2));
+        owners_result.extend_from_slice(&address_word(&[0xaa; 20]));
+        owners_result.extend_from_slice(&address_word(&[0xbb; 20]));
+        let owners = decode_owners(&format!("0x{}", hex::encode(&owners_result))).unwrap();
+        assert_eq!(owners, vec![[0xaa; 20], [0xbb; 20]]);
+        assert!(decode_owners("0x").is_err());
+    }
+}
@@ -1,10 +1,14 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// tx/solana: Solana SystemProgram transfer construction and Ed25519 signing
+// tx/solana: Solana SystemProgram transfer construction, instructions, legacy
+// message compilation, program-derived addresses and Ed25519 signing
 
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use ed25519_dalek::{Signer, SigningKey};
+use sha2::{Digest, Sha256};
 
+use super::solana_message::Message;
 use super::SignedTransaction;
 use crate::chains::ChainId;
 
@@ -18,74 +22,138 @@ pub struct SolanaTransfer {
 }
 
 impl SolanaTransfer {
-    /// Build the compact Solana transaction message (v0 legacy)
-    fn build_message(&self) -> Vec<u8> {
-        // SystemProgram ID = [0u8; 32]
-        let system_program = [0u8; 32];
-
-        // Message header: [num_required_signatures, num_readonly_signed, num_readonly_unsigned]
-        let header = [1u8, 0, 1]; // 1 signer (from), 0 readonly signed, 1 readonly unsigned (system program)
-
-        // Account keys: [from, to, system_program]
-        let num_accounts: u8 = 3;
-
-        // Instructions: 1 instruction
-        // SystemProgram.Transfer = instruction index 2
-        // Data: transfer instruction (index 2 as u32 LE + lamports as u64 LE)
-        let mut instruction_data = Vec::with_capacity(12);
-        instruction_data.extend_from_slice(&2u32.to_le_bytes()); // Transfer instruction
-        instruction_data.extend_from_slice(&self.lamports.to_le_bytes());
-
-        let mut message = Vec::new();
-        // Header
-        message.extend_from_slice(&header);
-        // Compact array: num accounts
-        message.push(num_accounts);
-        // Account keys
-        message.extend_from_slice(&self.from_pubkey);
-        message.extend_from_slice(&self.to_pubkey);
-        message.extend_from_slice(&system_program);
-        // Recent blockhash
-        message.extend_from_slice(&self.recent_blockhash);
-        // Compact array: num instructions = 1
-        message.push(1);
-        // Instruction: program_id_index, accounts, data
-        message.push(2); // program_id_index = 2 (system program)
-        message.push(2); // num account indices
-        message.push(0); // from account index
-        message.push(1); // to account index
-        // Instruction data length + data
-        message.push(instruction_data.len() as u8);
-        message.extend_from_slice(&instruction_data);
-
-        message
+    /// SystemProgram.Transfer (instruction index 2, lamports as u64 LE)
+    pub fn instruction(&self) -> Instruction {
+        let mut data = Vec::with_capacity(12);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&self.lamports.to_le_bytes());
+        Instruction {
+            program_id: [0u8; 32],
+            accounts: vec![AccountMeta::writable(self.from_pubkey, true), AccountMeta::writable(self.to_pubkey, false)],
+            data,
+        }
+    }
+
+    fn build_message(&self) -> Result<Vec<u8>, String> {
+        compile_legacy_message(&self.from_pubkey, &[self.instruction()], &self.recent_blockhash)
     }
 
     /// Sign the transfer with an Ed25519 keypair
     pub fn sign(&self, private_key: &[u8; 32]) -> Result<SignedTransaction, String> {
-        let signing_key = SigningKey::from_bytes(private_key);
-        let message = self.build_message();
+        Ok(sign_message(&self.build_message()?, private_key))
+    }
+}
 
-        let signature = signing_key.sign(&message);
-        let sig_bytes = signature.to_bytes();
+/// Account referenced by an instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountMeta {
+    pub pubkey: [u8; 32],
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
 
-        // Full transaction: [num_signatures(compact), signature(64), message]
-        let mut tx = Vec::new();
-        tx.push(1u8); // 1 signature
-        tx.extend_from_slice(&sig_bytes);
-        tx.extend_from_slice(&message);
+impl AccountMeta {
+    pub fn writable(pubkey: [u8; 32], is_signer: bool) -> Self {
+        AccountMeta { pubkey, is_signer, is_writable: true }
+    }
 
-        // Tx hash = first signature as base58
-        let tx_hash = bs58::encode(&sig_bytes).into_string();
+    pub fn readonly(pubkey: [u8; 32], is_signer: bool) -> Self {
+        AccountMeta { pubkey, is_signer, is_writable: false }
+    }
+}
+
+/// A single program invocation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    pub program_id: [u8; 32],
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+}
 
-        Ok(SignedTransaction {
-            chain_id: ChainId::Solana,
-            raw_bytes: tx,
-            tx_hash,
-        })
+/// Solana compact-u16 (shortvec) length prefix
+pub fn encode_compact_u16(value: usize, out: &mut Vec<u8>) {
+    let mut rem = value as u16;
+    loop {
+        let mut byte = (rem & 0x7f) as u8;
+        rem >>= 7;
+        if rem != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if rem == 0 {
+            break;
+        }
     }
 }
 
+/// Compile instructions into a serialized legacy message paid by `payer`
+/// (see `solana_message::Message` for v0 messages and parsing)
+pub fn compile_legacy_message(
+    payer: &[u8; 32],
+    instructions: &[Instruction],
+    recent_blockhash: &[u8; 32],
+) -> Result<Vec<u8>, String> {
+    Message::compile_legacy(payer, instructions, recent_blockhash).map(|m| m.serialize())
+}
+
+/// Sign a message whose only required signer is the fee payer
+pub fn sign_message(message: &[u8], private_key: &[u8; 32]) -> SignedTransaction {
+    let signature = SigningKey::from_bytes(private_key).sign(message).to_bytes();
+    let mut raw_bytes = vec![1u8];
+    raw_bytes.extend_from_slice(&signature);
+    raw_bytes.extend_from_slice(message);
+    SignedTransaction {
+        chain_id: ChainId::Solana,
+        raw_bytes,
+        tx_hash: bs58::encode(&signature).into_string(),
+    }
+}
+
+/// Transaction with an all-zero signature, for `simulateTransaction` with `sigVerify: false`
+pub fn unsigned_transaction(message: &[u8]) -> Vec<u8> {
+    let mut tx = vec![1u8];
+    tx.extend_from_slice(&[0u8; 64]);
+    tx.extend_from_slice(message);
+    tx
+}
+
+/// Whether 32 bytes decode to a point on the ed25519 curve (i.e. could have a private key)
+pub fn is_on_curve(pubkey: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*pubkey).decompress().is_some()
+}
+
+/// `create_program_address`: fails if the hash lands on the curve
+pub fn create_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Result<[u8; 32], String> {
+    if seeds.len() > 16 || seeds.iter().any(|s| s.len() > 32) {
+        return Err("Invalid PDA seeds".into());
+    }
+    let mut hasher = Sha256::new();
+    for seed in seeds {
+        hasher.update(seed);
+    }
+    hasher.update(program_id);
+    hasher.update(b"ProgramDerivedAddress");
+    let address: [u8; 32] = hasher.finalize().into();
+    if is_on_curve(&address) {
+        return Err("Seeds produce an on-curve address".into());
+    }
+    Ok(address)
+}
+
+/// `find_program_address`: first off-curve address searching bumps from 255 down to 1.
+/// Bump 0 is never tried, matching the runtime, so a PDA that only exists there is not found.
+pub fn find_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Result<([u8; 32], u8), String> {
+    for bump in (1..=255u8).rev() {
+        let bump_seed = [bump];
+        let mut with_bump = seeds.to_vec();
+        with_bump.push(&bump_seed);
+        if let Ok(address) = create_program_address(&with_bump, program_id) {
+            return Ok((address, bump));
+        }
+    }
+    Err("No valid program address found".into())
+}
+
 /// Parse a base58 Solana address to 32 bytes
 pub fn parse_pubkey(addr: &str) -> Result<[u8; 32], String> {
     let bytes = bs58::decode(addr)
@@ -153,4 +221,84 @@ mod tests {
         // First byte = 1 (num signatures)
         assert_eq!(signed.raw_bytes[0], 1);
     }
+
+    #[test]
+    fn test_compact_u16() {
+        let enc = |n| { let mut v = Vec::new(); encode_compact_u16(n, &mut v); v };
+        assert_eq!(enc(0), vec![0]);
+        assert_eq!(enc(0x7f), vec![0x7f]);
+        assert_eq!(enc(0x80), vec![0x80, 0x01]);
+        assert_eq!(enc(0x3fff), vec![0xff, 0x7f]);
+        assert_eq!(enc(0x4000), vec![0x80, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_transfer_message_layout() {
+        let transfer = SolanaTransfer {
+            from_pubkey: [1u8; 32],
+            to_pubkey: [2u8; 32],
+            lamports: 42,
+            recent_blockhash: [3u8; 32],
+        };
+        // header, 3 keys, blockhash, 1 instruction: program 2, accounts [0, 1], 12 bytes of data
+        let mut expected = vec![1u8, 0, 1, 3];
+        expected.extend_from_slice(&[1u8; 32]);
+        expected.extend_from_slice(&[2u8; 32]);
+        expected.extend_from_slice(&[0u8; 32]);
+        expected.extend_from_slice(&[3u8; 32]);
+        expected.extend_from_slice(&[1, 2, 2, 0, 1, 12]);
+        expected.extend_from_slice(&2u32.to_le_bytes());
+        expected.extend_from_slice(&42u64.to_le_bytes());
+        assert_eq!(transfer.build_message().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_compile_orders_and_dedups_accounts() {
+        let payer = [9u8; 32];
+        let ix = Instruction {
+            program_id: [7u8; 32],
+            accounts: vec![
+                AccountMeta::readonly([5u8; 32], false),
+                AccountMeta::writable([6u8; 32], false),
+                AccountMeta::readonly(payer, true),
+                AccountMeta::writable([5u8; 32], false),
+            ],
+            data: vec![1],
+        };
+        let msg = compile_legacy_message(&payer, &[ix], &[0u8; 32]).unwrap();
+        // header: 1 signer, 0 readonly signed, 1 readonly unsigned (the program)
+        assert_eq!(&msg[..4], &[1, 0, 1, 4]);
+        let keys: Vec<u8> = msg[4..4 + 4 * 32].chunks(32).map(|k| k[0]).collect();
+        assert_eq!(keys, vec![9, 5, 6, 7]);
+        // program index 3, 4 account indices [1, 2, 0, 1]
+        assert_eq!(&msg[4 + 128 + 32..], &[1, 3, 4, 1, 2, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_program_address_is_off_curve() {
+        let signing_key = SigningKey::from_bytes(&[4u8; 32]);
+        assert!(is_on_curve(&signing_key.verifying_key().to_bytes()));
+
+        let program = parse_pubkey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL").unwrap();
+        let (address, bump) = find_program_address(&[b"seed", &[1u8; 32]], &program).unwrap();
+        assert!(!is_on_curve(&address));
+        assert_eq!(create_program_address(&[b"seed", &[1u8; 32], &[bump]], &program).unwrap(), address);
+        assert!(create_program_address(&[&[0u8; 33]], &program).is_err());
+    }
+
+    #[test]
+    fn test_create_program_address_vectors() {
+        // From the solana-pubkey `create_program_address` tests
+        let program = parse_pubkey("BPFLoaderUpgradeab1e11111111111111111111111").unwrap();
+        let public_key = parse_pubkey("SeedPubey1111111111111111111111111111111111").unwrap();
+        let cases: [(&[&[u8]], &str); 4] = [
+            (&[b"", &[1]], "BwqrghZA2htAcqq8dzP1WDAhTXYTYWj7CHxF5j7TDBAe"),
+            (&["☉".as_bytes(), &[0]], "13yWmRpaTR4r5nAktwLqMpRNr28tnVUZw26rTvPSSB19"),
+            (&[b"Talking", b"Squirrels"], "2fnQrngrQT4SeLcdToJAD96phoEjNL2man2kfRLCASVk"),
+            (&[&public_key, &[1]], "976ymqVnfE32QFe6NfGDctSvVa36LWnvYxhU6G2232YL"),
+        ];
+        for (seeds, expected) in cases {
+            assert_eq!(create_program_address(seeds, &program).unwrap(), parse_pubkey(expected).unwrap());
+        }
+    }
 }
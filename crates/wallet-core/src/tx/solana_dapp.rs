@@ -0,0 +1,469 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/solana_dapp: Review of transactions proposed by Solana dApps (decoded actions
+// and risks) and off-chain message signing for Wallet Standard / WalletConnect
+
+use ed25519_dalek::{Signer, SigningKey};
+
+use super::solana::{parse_pubkey, Instruction};
+use super::solana_fees::{self, MAX_COMPUTE_UNITS};
+use super::solana_stake;
+use super::solana_message::{AddressLookupTable, Message, Transaction};
+use crate::tokens::spl;
+
+/// SPL Memo program (v2)
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// SPL Memo program (v1, still used by older dApps)
+pub const MEMO_V1_PROGRAM_ID: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+
+/// Compute Budget program (compute unit limit and priority fee)
+pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+
+/// Owner of address lookup table accounts
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: &str = "AddressLookupTab1e1111111111111111111111111";
+
+/// Fee charged per signature, in lamports
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute units the runtime grants each instruction when no limit is requested
+const DEFAULT_UNITS_PER_INSTRUCTION: u64 = 200_000;
+
+/// One decoded instruction
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolanaAction {
+    SolTransfer { from: [u8; 32], to: [u8; 32], lamports: u64 },
+    CreateAccount { funder: [u8; 32], account: [u8; 32], lamports: u64, owner_program: [u8; 32] },
+    /// System `Assign`: `account` becomes owned by `owner_program`
+    Assign { account: [u8; 32], owner_program: [u8; 32] },
+    /// SPL `Transfer` / `TransferChecked`; mint and decimals only come with the checked form
+    TokenTransfer {
+        source: [u8; 32],
+        destination: [u8; 32],
+        authority: [u8; 32],
+        mint: Option<[u8; 32]>,
+        amount: u64,
+        decimals: Option<u8>,
+    },
+    /// SPL `Approve` / `ApproveChecked`
+    TokenApprove {
+        source: [u8; 32],
+        delegate: [u8; 32],
+        owner: [u8; 32],
+        mint: Option<[u8; 32]>,
+        amount: u64,
+        decimals: Option<u8>,
+    },
+    /// SPL `SetAuthority`; `new_authority` None removes the authority
+    TokenSetAuthority { account: [u8; 32], current_authority: [u8; 32], new_authority: Option<[u8; 32]> },
+    TokenCloseAccount { account: [u8; 32], destination: [u8; 32], owner: [u8; 32] },
+    /// Associated token account creation (`Create` / `CreateIdempotent`)
+    CreateTokenAccount { payer: [u8; 32], owner: [u8; 32], mint: [u8; 32] },
+    Memo(String),
+    ComputeUnitLimit(u32),
+    /// Priority fee in micro-lamports per compute unit
+    ComputeUnitPrice(u64),
+    /// Instruction this wallet does not decode; `program` is set for known programs
+    Other { program_id: [u8; 32], program: Option<&'static str> },
+}
+
+/// Something in the transaction that affects our account beyond a plain transfer
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolanaRisk {
+    /// Our wallet account is assigned to another program, which then controls its SOL
+    AssignsWallet,
+    /// Authority over one of our token accounts is handed over or removed
+    TransfersAuthority,
+    /// A delegate may move our tokens later
+    DelegatesTokens { unlimited: bool },
+    /// One of our token accounts is closed with the rent sent elsewhere
+    ClosesAccount,
+    /// A program the wallet cannot decode is invoked
+    UnknownProgram([u8; 32]),
+}
+
+impl SolanaRisk {
+    /// Risks that can hand over control of funds rather than move a visible amount
+    pub fn is_high_risk(&self) -> bool {
+        matches!(self, SolanaRisk::AssignsWallet | SolanaRisk::TransfersAuthority | SolanaRisk::DelegatesTokens { .. })
+    }
+}
+
+/// What a dApp transaction does, from the point of view of `our_key`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionReview {
+    pub fee_payer: [u8; 32],
+    /// False when the dApp (or another signer) sponsors the fee
+    pub we_pay_fee: bool,
+    /// Whether our key is one of the required signers
+    pub we_sign: bool,
+    pub num_signatures: usize,
+    pub actions: Vec<SolanaAction>,
+    pub risks: Vec<SolanaRisk>,
+}
+
+impl TransactionReview {
+    /// SOL leaving our account through transfers and account creation (fees excluded)
+    pub fn outgoing_lamports(&self, our_key: &[u8; 32]) -> u64 {
+        self.actions.iter().map(|a| match a {
+            SolanaAction::SolTransfer { from, lamports, .. } if from == our_key => *lamports,
+            SolanaAction::CreateAccount { funder, lamports, .. } if funder == our_key => *lamports,
+            _ => 0,
+        }).fold(0u64, u64::saturating_add)
+    }
+
+    /// Signature fee plus the priority fee requested through the Compute Budget program
+    pub fn network_fee_lamports(&self) -> u64 {
+        let base = LAMPORTS_PER_SIGNATURE * self.num_signatures as u64;
+        let price = self.actions.iter().find_map(|a| match a {
+            SolanaAction::ComputeUnitPrice(p) => Some(*p),
+            _ => None,
+        });
+        let Some(price) = price else { return base };
+        let limit = self.actions.iter().find_map(|a| match a {
+            SolanaAction::ComputeUnitLimit(l) => Some(*l as u64),
+            _ => None,
+        }).unwrap_or_else(|| {
+            let counted = self.actions.iter().filter(|a| !matches!(a, SolanaAction::ComputeUnitLimit(_) | SolanaAction::ComputeUnitPrice(_))).count();
+            (DEFAULT_UNITS_PER_INSTRUCTION * counted as u64).min(MAX_COMPUTE_UNITS as u64)
+        });
+        base.saturating_add(solana_fees::priority_fee_lamports(limit, price))
+    }
+}
+
+/// Parse a base64 wire transaction as sent by dApps
+pub fn decode_transaction(encoded: &str) -> Result<Transaction, String> {
+    Transaction::deserialize(&crate::backup::base64_decode(encoded)?)
+}
+
+/// Base64 wire encoding, the format dApps expect back
+pub fn encode_transaction(tx: &Transaction) -> String {
+    crate::backup::base64_encode(&tx.serialize())
+}
+
+/// Parse a base64 `getAccountInfo` value of a lookup table, checking its owner
+pub fn lookup_table_from_account_info(key: &str, value: &serde_json::Value) -> Result<AddressLookupTable, String> {
+    let owner = value.get("owner").and_then(|o| o.as_str())
+        .ok_or_else(|| format!("Lookup table {} not found", key))?;
+    if owner != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+        return Err(format!("{} is not an address lookup table", key));
+    }
+    let encoded = value.get("data")
+        .and_then(|d| d.get(0))
+        .and_then(|d| d.as_str())
+        .ok_or("Lookup table account has no data")?;
+    AddressLookupTable::from_account_data(parse_pubkey(key)?, &crate::backup::base64_decode(encoded)?)
+}
+
+fn read_u64(data: &[u8], at: usize) -> Option<u64> {
+    data.get(at..at + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+fn read_key(data: &[u8], at: usize) -> Option<[u8; 32]> {
+    data.get(at..at + 32).map(|b| b.try_into().unwrap())
+}
+
+fn program_name(program_id: &[u8; 32]) -> Option<&'static str> {
+    let key = bs58::encode(program_id).into_string();
+    match key.as_str() {
+        spl::SYSTEM_PROGRAM_ID => Some("System"),
+        spl::TOKEN_PROGRAM_ID => Some("SPL Token"),
+        spl::TOKEN_2022_PROGRAM_ID => Some("Token-2022"),
+        spl::ATA_PROGRAM_ID => Some("Associated Token Account"),
+        MEMO_PROGRAM_ID | MEMO_V1_PROGRAM_ID => Some("Memo"),
+        COMPUTE_BUDGET_PROGRAM_ID => Some("Compute Budget"),
+        solana_stake::STAKE_PROGRAM_ID => Some("Stake"),
+        _ => None,
+    }
+}
+
+fn decode_system(ix: &Instruction) -> Option<SolanaAction> {
+    let key = |i: usize| ix.accounts.get(i).map(|a| a.pubkey);
+    let tag = u32::from_le_bytes(ix.data.get(..4)?.try_into().unwrap());
+    match tag {
+        0 => Some(SolanaAction::CreateAccount {
+            funder: key(0)?,
+            account: key(1)?,
+            lamports: read_u64(&ix.data, 4)?,
+            owner_program: read_key(&ix.data, 20)?,
+        }),
+        1 => Some(SolanaAction::Assign { account: key(0)?, owner_program: read_key(&ix.data, 4)? }),
+        2 => Some(SolanaAction::SolTransfer { from: key(0)?, to: key(1)?, lamports: read_u64(&ix.data, 4)? }),
+        // TransferWithSeed: [from, base, to]
+        11 => Some(SolanaAction::SolTransfer { from: key(0)?, to: key(2)?, lamports: read_u64(&ix.data, 4)? }),
+        _ => None,
+    }
+}
+
+fn decode_token(ix: &Instruction) -> Option<SolanaAction> {
+    let key = |i: usize| ix.accounts.get(i).map(|a| a.pubkey);
+    match *ix.data.first()? {
+        3 => Some(SolanaAction::TokenTransfer {
+            source: key(0)?, destination: key(1)?, authority: key(2)?,
+            mint: None, amount: read_u64(&ix.data, 1)?, decimals: None,
+        }),
+        12 => Some(SolanaAction::TokenTransfer {
+            source: key(0)?, destination: key(2)?, authority: key(3)?,
+            mint: key(1), amount: read_u64(&ix.data, 1)?, decimals: ix.data.get(9).copied(),
+        }),
+        // Token-2022 TransferFeeExtension / TransferCheckedWithFee
+        26 if ix.data.get(1) == Some(&1) => Some(SolanaAction::TokenTransfer {
+            source: key(0)?, destination: key(2)?, authority: key(3)?,
+            mint: key(1), amount: read_u64(&ix.data, 2)?, decimals: ix.data.get(10).copied(),
+        }),
+        4 => Some(SolanaAction::TokenApprove {
+            source: key(0)?, delegate: key(1)?, owner: key(2)?,
+            mint: None, amount: read_u64(&ix.data, 1)?, decimals: None,
+        }),
+        13 => Some(SolanaAction::TokenApprove {
+            source: key(0)?, delegate: key(2)?, owner: key(3)?,
+            mint: key(1), amount: read_u64(&ix.data, 1)?, decimals: ix.data.get(9).copied(),
+        }),
+        // SetAuthority: authority type, then COption<Pubkey>
+        6 => Some(SolanaAction::TokenSetAuthority {
+            account: key(0)?,
+            current_authority: key(1)?,
+            new_authority: match *ix.data.get(2)? {
+                0 => None,
+                _ => Some(read_key(&ix.data, 3)?),
+            },
+        }),
+        9 => Some(SolanaAction::TokenCloseAccount { account: key(0)?, destination: key(1)?, owner: key(2)? }),
+        _ => None,
+    }
+}
+
+/// `Create` / `CreateIdempotent`: [payer, account, owner, mint, system, token program]
+fn decode_ata(ix: &Instruction) -> Option<SolanaAction> {
+    if !matches!(ix.data.as_slice(), [] | [0] | [1]) {
+        return None;
+    }
+    let key = |i: usize| ix.accounts.get(i).map(|a| a.pubkey);
+    Some(SolanaAction::CreateTokenAccount { payer: key(0)?, owner: key(2)?, mint: key(3)? })
+}
+
+fn decode_instruction(ix: &Instruction) -> SolanaAction {
+    let program = program_name(&ix.program_id);
+    let decoded = match program {
+        Some("System") => decode_system(ix),
+        Some("SPL Token") | Some("Token-2022") => decode_token(ix),
+        Some("Associated Token Account") => decode_ata(ix),
+        Some("Memo") => Some(SolanaAction::Memo(String::from_utf8_lossy(&ix.data).into_owned())),
+        Some("Compute Budget") => match ix.data.first() {
+            Some(2) => ix.data.get(1..5).map(|b| SolanaAction::ComputeUnitLimit(u32::from_le_bytes(b.try_into().unwrap()))),
+            Some(3) => read_u64(&ix.data, 1).map(SolanaAction::ComputeUnitPrice),
+            _ => None,
+        },
+        _ => None,
+    };
+    decoded.unwrap_or(SolanaAction::Other { program_id: ix.program_id, program })
+}
+
+fn action_risk(action: &SolanaAction, our_key: &[u8; 32]) -> Option<SolanaRisk> {
+    match action {
+        SolanaAction::Assign { account, owner_program } if account == our_key && *owner_program != [0u8; 32] => {
+            Some(SolanaRisk::AssignsWallet)
+        }
+        SolanaAction::TokenSetAuthority { current_authority, new_authority, .. }
+            if current_authority == our_key && new_authority.as_ref() != Some(our_key) => Some(SolanaRisk::TransfersAuthority),
+        SolanaAction::TokenApprove { owner, amount, .. } if owner == our_key => {
+            Some(SolanaRisk::DelegatesTokens { unlimited: *amount == u64::MAX })
+        }
+        SolanaAction::TokenCloseAccount { destination, owner, .. } if owner == our_key && destination != our_key => {
+            Some(SolanaRisk::ClosesAccount)
+        }
+        SolanaAction::Other { program_id, program: None } => Some(SolanaRisk::UnknownProgram(*program_id)),
+        _ => None,
+    }
+}
+
+/// Decode every instruction of `message` and collect the risks for `our_key`.
+/// `tables` must hold every lookup table the message references.
+pub fn review_message(message: &Message, tables: &[AddressLookupTable], our_key: &[u8; 32]) -> Result<TransactionReview, String> {
+    let actions: Vec<SolanaAction> = message.decompile(tables)?.iter().map(decode_instruction).collect();
+    let mut risks: Vec<SolanaRisk> = Vec::new();
+    for risk in actions.iter().filter_map(|a| action_risk(a, our_key)) {
+        if !risks.contains(&risk) {
+            risks.push(risk);
+        }
+    }
+    let fee_payer = message.fee_payer();
+    Ok(TransactionReview {
+        fee_payer,
+        we_pay_fee: fee_payer == *our_key,
+        we_sign: message.signer_keys().contains(our_key),
+        num_signatures: message.signer_keys().len(),
+        actions,
+        risks,
+    })
+}
+
+/// Whether `bytes` parse as a Solana transaction or message. Such bytes must never be
+/// signed as an "off-chain message": the signature would authorize the transaction.
+pub fn is_transaction_bytes(bytes: &[u8]) -> bool {
+    Message::deserialize(bytes).is_ok() || Transaction::deserialize(bytes).is_ok()
+}
+
+/// Ed25519 signature over an off-chain message (`signMessage`)
+pub fn sign_off_chain_message(message: &[u8], private_key: &[u8; 32]) -> Result<[u8; 64], String> {
+    if message.is_empty() {
+        return Err("Empty message".into());
+    }
+    if is_transaction_bytes(message) {
+        return Err("Refusing to sign a transaction disguised as a message".into());
+    }
+    Ok(SigningKey::from_bytes(private_key).sign(message).to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tx::solana::{AccountMeta, SolanaTransfer};
+    use ed25519_dalek::{Signature, Verifier};
+
+    const PRIVATE_KEY: [u8; 32] = [7u8; 32];
+
+    fn our_key() -> [u8; 32] {
+        SigningKey::from_bytes(&PRIVATE_KEY).verifying_key().to_bytes()
+    }
+
+    fn token_ix(our: [u8; 32], data: Vec<u8>, accounts: Vec<[u8; 32]>) -> Instruction {
+        let mut metas: Vec<AccountMeta> = accounts.into_iter().map(|k| AccountMeta::writable(k, false)).collect();
+        metas.push(AccountMeta::readonly(our, true));
+        Instruction { program_id: spl::program_key(spl::TOKEN_PROGRAM_ID), accounts: metas, data }
+    }
+
+    #[test]
+    fn test_review_transfer_and_budget() {
+        let our = our_key();
+        let transfer = SolanaTransfer { from_pubkey: our, to_pubkey: [9u8; 32], lamports: 1_500_000, recent_blockhash: [1u8; 32] };
+        let mut limit = vec![2];
+        limit.extend_from_slice(&300_000u32.to_le_bytes());
+        let mut price = vec![3];
+        price.extend_from_slice(&10_000u64.to_le_bytes());
+        let budget = spl::program_key(COMPUTE_BUDGET_PROGRAM_ID);
+        let memo = Instruction { program_id: spl::program_key(MEMO_PROGRAM_ID), accounts: vec![], data: b"order 42".to_vec() };
+        let ixs = vec![
+            Instruction { program_id: budget, accounts: vec![], data: limit },
+            Instruction { program_id: budget, accounts: vec![], data: price },
+            transfer.instruction(),
+            memo,
+        ];
+        let message = Message::compile_legacy(&our, &ixs, &[1u8; 32]).unwrap();
+        let review = review_message(&message, &[], &our).unwrap();
+
+        assert!(review.we_pay_fee && review.we_sign);
+        assert!(review.risks.is_empty());
+        assert_eq!(review.actions[0], SolanaAction::ComputeUnitLimit(300_000));
+        assert_eq!(review.actions[1], SolanaAction::ComputeUnitPrice(10_000));
+        assert_eq!(review.actions[2], SolanaAction::SolTransfer { from: our, to: [9u8; 32], lamports: 1_500_000 });
+        assert_eq!(review.actions[3], SolanaAction::Memo("order 42".into()));
+        assert_eq!(review.outgoing_lamports(&our), 1_500_000);
+        // 5000 signature fee + 300k units at 0.01 lamports each
+        assert_eq!(review.network_fee_lamports(), 5_000 + 3_000);
+    }
+
+    #[test]
+    fn test_review_flags_risks() {
+        let our = our_key();
+        let mut assign = 1u32.to_le_bytes().to_vec();
+        assign.extend_from_slice(&[5u8; 32]);
+        let mut approve = vec![4];
+        approve.extend_from_slice(&u64::MAX.to_le_bytes());
+        let mut set_authority = vec![6, 2, 1];
+        set_authority.extend_from_slice(&[8u8; 32]);
+        let ixs = vec![
+            Instruction { program_id: [0u8; 32], accounts: vec![AccountMeta::writable(our, true)], data: assign },
+            token_ix(our, approve, vec![[2u8; 32], [3u8; 32]]),
+            token_ix(our, set_authority, vec![[2u8; 32]]),
+            token_ix(our, vec![9], vec![[2u8; 32], [4u8; 32]]),
+            Instruction { program_id: [6u8; 32], accounts: vec![AccountMeta::readonly(our, true)], data: vec![1, 2, 3] },
+        ];
+        let message = Message::compile_legacy(&[1u8; 32], &ixs, &[1u8; 32]).unwrap();
+        let review = review_message(&message, &[], &our).unwrap();
+
+        assert!(!review.we_pay_fee);
+        assert!(review.we_sign);
+        assert_eq!(review.num_signatures, 2);
+        assert_eq!(review.actions[2], SolanaAction::TokenSetAuthority {
+            account: [2u8; 32], current_authority: our, new_authority: Some([8u8; 32]),
+        });
+        assert_eq!(review.risks, vec![
+            SolanaRisk::AssignsWallet,
+            SolanaRisk::DelegatesTokens { unlimited: true },
+            SolanaRisk::TransfersAuthority,
+            SolanaRisk::ClosesAccount,
+            SolanaRisk::UnknownProgram([6u8; 32]),
+        ]);
+        assert!(review.risks.iter().any(|r| r.is_high_risk()));
+        assert_eq!(review.network_fee_lamports(), 10_000);
+    }
+
+    #[test]
+    fn test_review_spl_transfer_instructions() {
+        let our = our_key();
+        let mint = [3u8; 32];
+        let recipient = SigningKey::from_bytes(&[8u8; 32]).verifying_key().to_bytes();
+        let token_program = spl::program_key(spl::TOKEN_PROGRAM_ID);
+        let ixs = spl::transfer_instructions(&our, &mint, &recipient, 2_500_000, 6, &token_program, false).unwrap();
+        let message = Message::compile_legacy(&our, &ixs, &[1u8; 32]).unwrap();
+        let review = review_message(&message, &[], &our).unwrap();
+
+        assert!(review.risks.is_empty());
+        assert!(matches!(review.actions[0], SolanaAction::CreateTokenAccount { payer, owner, mint: m } if payer == our && owner == recipient && m == mint));
+        assert!(matches!(review.actions[1], SolanaAction::TokenTransfer { authority, mint: Some(m), amount: 2_500_000, decimals: Some(6), .. } if authority == our && m == mint));
+
+        let source = spl::associated_token_address(&our, &mint, &token_program).unwrap();
+        let with_fee = crate::tokens::token_2022::transfer_checked_with_fee(&source, &mint, &[4u8; 32], &our, 900, 6, 9, &spl::program_key(spl::TOKEN_2022_PROGRAM_ID));
+        assert!(matches!(decode_instruction(&with_fee), SolanaAction::TokenTransfer { amount: 900, decimals: Some(6), .. }));
+    }
+
+    #[test]
+    fn test_transaction_base64_roundtrip() {
+        let our = our_key();
+        let transfer = SolanaTransfer { from_pubkey: our, to_pubkey: [9u8; 32], lamports: 1, recent_blockhash: [1u8; 32] };
+        let message = Message::compile_legacy(&our, &[transfer.instruction()], &[1u8; 32]).unwrap();
+        let mut tx = Transaction::new_unsigned(message);
+        let encoded = encode_transaction(&tx);
+        assert_eq!(decode_transaction(&encoded).unwrap(), tx);
+        tx.sign(&PRIVATE_KEY).unwrap();
+        assert!(decode_transaction(&encode_transaction(&tx)).unwrap().is_fully_signed());
+        assert!(decode_transaction("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_lookup_table_account_info() {
+        let key = bs58::encode([4u8; 32]).into_string();
+        let mut data = vec![0u8; 56];
+        data[0] = 1;
+        data.extend_from_slice(&[5u8; 32]);
+        let value = serde_json::json!({
+            "owner": ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+            "data": [crate::backup::base64_encode(&data), "base64"],
+        });
+        let table = lookup_table_from_account_info(&key, &value).unwrap();
+        assert_eq!(table.key, [4u8; 32]);
+        assert_eq!(table.addresses, vec![[5u8; 32]]);
+
+        let wrong_owner = serde_json::json!({"owner": spl::SYSTEM_PROGRAM_ID, "data": value["data"].clone()});
+        assert!(lookup_table_from_account_info(&key, &wrong_owner).is_err());
+        assert!(lookup_table_from_account_info(&key, &serde_json::Value::Null).is_err());
+    }
+
+    #[test]
+    fn test_sign_off_chain_message() {
+        let text = b"Welcome! Sign to log in. Nonce: 8f2a";
+        let signature = sign_off_chain_message(text, &PRIVATE_KEY).unwrap();
+        let verifying = SigningKey::from_bytes(&PRIVATE_KEY).verifying_key();
+        assert!(verifying.verify(text, &Signature::from_bytes(&signature)).is_ok());
+
+        let our = our_key();
+        let transfer = SolanaTransfer { from_pubkey: our, to_pubkey: [9u8; 32], lamports: 1, recent_blockhash: [1u8; 32] };
+        let message = Message::compile_legacy(&our, &[transfer.instruction()], &[1u8; 32]).unwrap();
+        assert!(sign_off_chain_message(&message.serialize(), &PRIVATE_KEY).is_err());
+        let tx = Transaction::new_unsigned(message);
+        assert!(sign_off_chain_message(&tx.serialize(), &PRIVATE_KEY).is_err());
+        assert!(sign_off_chain_message(b"", &PRIVATE_KEY).is_err());
+    }
+}
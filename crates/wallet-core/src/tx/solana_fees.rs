@@ -0,0 +1,168 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/solana_fees: Compute Budget instructions, priority fee tiers
+// (getRecentPrioritizationFees) and compute unit limits from simulation
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::evm_fees::FeeTier;
+use super::solana::Instruction;
+use super::solana_dapp::{COMPUTE_BUDGET_PROGRAM_ID, LAMPORTS_PER_SIGNATURE};
+use crate::tokens::spl;
+
+/// Percentiles of recent per-slot prioritization fees (slow, normal, fast)
+pub const PRIORITY_FEE_PERCENTILES: [u8; 3] = [25, 50, 75];
+
+/// Safety margin added on top of the simulated compute units (percent)
+pub const COMPUTE_UNIT_MARGIN_PERCENT: u64 = 10;
+
+/// Highest compute unit limit a transaction can request
+pub const MAX_COMPUTE_UNITS: u32 = 1_400_000;
+
+const IX_SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const IX_SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Slow / normal / fast compute unit prices, in micro-lamports per CU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityFeeEstimate {
+    pub tiers: [u64; 3],
+}
+
+impl PriorityFeeEstimate {
+    pub fn tier(&self, tier: FeeTier) -> u64 {
+        self.tiers[tier.index()]
+    }
+}
+
+/// ComputeBudget `SetComputeUnitLimit`
+pub fn set_compute_unit_limit(units: u32) -> Instruction {
+    let mut data = vec![IX_SET_COMPUTE_UNIT_LIMIT];
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction { program_id: spl::program_key(COMPUTE_BUDGET_PROGRAM_ID), accounts: vec![], data }
+}
+
+/// ComputeBudget `SetComputeUnitPrice` (micro-lamports per compute unit)
+pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
+    let mut data = vec![IX_SET_COMPUTE_UNIT_PRICE];
+    data.extend_from_slice(&micro_lamports.to_le_bytes());
+    Instruction { program_id: spl::program_key(COMPUTE_BUDGET_PROGRAM_ID), accounts: vec![], data }
+}
+
+/// Prepend the compute budget to `instructions`. A zero price adds no
+/// `SetComputeUnitPrice`, since it would only cost compute units.
+pub fn with_compute_budget(instructions: &[Instruction], unit_limit: u32, micro_lamports: u64) -> Vec<Instruction> {
+    let mut out = Vec::with_capacity(instructions.len() + 2);
+    out.push(set_compute_unit_limit(unit_limit));
+    if micro_lamports > 0 {
+        out.push(set_compute_unit_price(micro_lamports));
+    }
+    out.extend_from_slice(instructions);
+    out
+}
+
+/// Unit limit to request for a transaction that consumed `units_consumed` in simulation
+pub fn compute_unit_limit(units_consumed: u64) -> u32 {
+    let with_margin = units_consumed.saturating_add(units_consumed.saturating_mul(COMPUTE_UNIT_MARGIN_PERCENT) / 100);
+    with_margin.clamp(1, MAX_COMPUTE_UNITS as u64) as u32
+}
+
+/// Priority fee in lamports: the price is charged on the requested limit, not on units used
+pub fn priority_fee_lamports(unit_limit: u64, micro_lamports: u64) -> u64 {
+    let micro = (unit_limit as u128) * (micro_lamports as u128);
+    micro.div_ceil(MICRO_LAMPORTS_PER_LAMPORT).min(u64::MAX as u128) as u64
+}
+
+/// Signature fees plus the priority fee, in lamports
+pub fn total_fee_lamports(num_signatures: u8, unit_limit: u64, micro_lamports: u64) -> u64 {
+    (LAMPORTS_PER_SIGNATURE * num_signatures as u64).saturating_add(priority_fee_lamports(unit_limit, micro_lamports))
+}
+
+/// Build fee tiers from a getRecentPrioritizationFees JSON-RPC response
+/// (one `prioritizationFee` per recent slot) using `PRIORITY_FEE_PERCENTILES`.
+pub fn fees_from_prioritization_fees(response: &Value) -> Result<PriorityFeeEstimate, String> {
+    if let Some(error) = response.get("error") {
+        return Err(format!("getRecentPrioritizationFees error: {}", error));
+    }
+    let entries = response.get("result")
+        .and_then(|v| v.as_array())
+        .ok_or("Missing result in getRecentPrioritizationFees")?;
+
+    let mut fees: Vec<u64> = entries.iter()
+        .filter_map(|e| e.get("prioritizationFee").and_then(|f| f.as_u64()))
+        .collect();
+    if fees.is_empty() {
+        return Ok(PriorityFeeEstimate { tiers: [0; 3] });
+    }
+    fees.sort_unstable();
+
+    // Nearest-rank percentile; sorting keeps the tiers non-decreasing
+    let mut tiers = [0u64; 3];
+    for (tier, pct) in tiers.iter_mut().zip(PRIORITY_FEE_PERCENTILES) {
+        let rank = (fees.len() * pct as usize).div_ceil(100).max(1);
+        *tier = fees[rank - 1];
+    }
+    Ok(PriorityFeeEstimate { tiers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compute_budget_instructions() {
+        let budget = spl::program_key(COMPUTE_BUDGET_PROGRAM_ID);
+        let limit = set_compute_unit_limit(300_000);
+        assert_eq!(limit.program_id, budget);
+        assert!(limit.accounts.is_empty());
+        assert_eq!(limit.data, vec![2, 0xe0, 0x93, 0x04, 0x00]);
+        let price = set_compute_unit_price(10_000);
+        assert_eq!(price.data, vec![3, 0x10, 0x27, 0, 0, 0, 0, 0, 0]);
+
+        let transfer = Instruction { program_id: [0u8; 32], accounts: vec![], data: vec![2] };
+        let ixs = with_compute_budget(std::slice::from_ref(&transfer), 450, 10_000);
+        assert_eq!(ixs, vec![set_compute_unit_limit(450), price, transfer.clone()]);
+        assert_eq!(with_compute_budget(std::slice::from_ref(&transfer), 450, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_unit_limit_and_fee() {
+        assert_eq!(compute_unit_limit(450), 495);
+        assert_eq!(compute_unit_limit(0), 1);
+        assert_eq!(compute_unit_limit(2_000_000), MAX_COMPUTE_UNITS);
+        // 495 CU at 10,000 µlamports = 4.95 lamports, rounded up
+        assert_eq!(priority_fee_lamports(495, 10_000), 5);
+        assert_eq!(priority_fee_lamports(200_000, 0), 0);
+        assert_eq!(total_fee_lamports(1, 495, 10_000), 5_005);
+    }
+
+    #[test]
+    fn test_fee_tiers_from_recent_fees() {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [
+                {"slot": 1, "prioritizationFee": 0},
+                {"slot": 2, "prioritizationFee": 5_000},
+                {"slot": 3, "prioritizationFee": 1_000},
+                {"slot": 4, "prioritizationFee": 20_000},
+                {"slot": 5, "prioritizationFee": 0},
+                {"slot": 6, "prioritizationFee": 100_000},
+                {"slot": 7, "prioritizationFee": 2_000},
+                {"slot": 8, "prioritizationFee": 50_000}
+            ]
+        });
+        let est = fees_from_prioritization_fees(&response).unwrap();
+        // sorted: 0 0 1000 2000 5000 20000 50000 100000
+        assert_eq!(est.tiers, [0, 2_000, 20_000]);
+        assert_eq!(est.tier(FeeTier::Fast), 20_000);
+
+        let quiet = json!({"jsonrpc": "2.0", "id": 1, "result": []});
+        assert_eq!(fees_from_prioritization_fees(&quiet).unwrap().tiers, [0; 3]);
+        assert!(fees_from_prioritization_fees(&json!({"error": {"code": -32601}})).is_err());
+    }
+}
@@ -0,0 +1,643 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/solana_message: Solana transaction model — legacy and v0 messages, address
+// lookup tables, (de)serialization and partial signing of multi-signer transactions
+
+use ed25519_dalek::{Signer, SigningKey};
+
+use super::solana::{encode_compact_u16, AccountMeta, Instruction};
+use super::SignedTransaction;
+use crate::chains::ChainId;
+
+/// High bit of the first message byte marks a versioned message
+const VERSION_PREFIX: u8 = 0x80;
+
+/// Size of the address lookup table account header before the address list
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Decode a compact-u16 (shortvec). Returns the value and the bytes consumed;
+/// non-canonical encodings are rejected like the runtime does.
+pub fn decode_compact_u16(bytes: &[u8]) -> Result<(usize, usize), String> {
+    let mut value = 0usize;
+    for i in 0..3 {
+        let byte = *bytes.get(i).ok_or("Truncated compact-u16")?;
+        if i == 2 && byte > 0x03 {
+            return Err("compact-u16 overflow".into());
+        }
+        value |= ((byte & 0x7f) as usize) << (7 * i);
+        if byte & 0x80 == 0 {
+            if i > 0 && byte == 0 {
+                return Err("Non-canonical compact-u16".into());
+            }
+            return Ok((value, i + 1));
+        }
+    }
+    Err("compact-u16 overflow".into())
+}
+
+/// Message format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageVersion {
+    Legacy,
+    V0,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageHeader {
+    pub num_required_signatures: u8,
+    pub num_readonly_signed: u8,
+    pub num_readonly_unsigned: u8,
+}
+
+/// Instruction with accounts replaced by indices into the message key list
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledInstruction {
+    pub program_id_index: u8,
+    pub accounts: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+/// Accounts a v0 message loads from one lookup table
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageAddressTableLookup {
+    pub account_key: [u8; 32],
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// Contents of an on-chain address lookup table
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressLookupTable {
+    pub key: [u8; 32],
+    pub addresses: Vec<[u8; 32]>,
+}
+
+impl AddressLookupTable {
+    /// Parse the raw account data of a lookup table owned by the
+    /// AddressLookupTab1e program: a 56-byte header, then 32-byte addresses
+    pub fn from_account_data(key: [u8; 32], data: &[u8]) -> Result<Self, String> {
+        if data.len() < LOOKUP_TABLE_META_SIZE || !(data.len() - LOOKUP_TABLE_META_SIZE).is_multiple_of(32) {
+            return Err("Invalid lookup table account size".into());
+        }
+        if u32::from_le_bytes(data[..4].try_into().unwrap()) != 1 {
+            return Err("Account is not an initialized lookup table".into());
+        }
+        let addresses = data[LOOKUP_TABLE_META_SIZE..].chunks(32)
+            .map(|c| c.try_into().unwrap())
+            .collect();
+        Ok(Self { key, addresses })
+    }
+}
+
+/// A legacy or v0 message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub version: MessageVersion,
+    pub header: MessageHeader,
+    /// Static keys: signers first, then writable and readonly non-signers
+    pub account_keys: Vec<[u8; 32]>,
+    pub recent_blockhash: [u8; 32],
+    pub instructions: Vec<CompiledInstruction>,
+    /// Always empty for legacy messages
+    pub address_table_lookups: Vec<MessageAddressTableLookup>,
+}
+
+struct KeyMeta {
+    pubkey: [u8; 32],
+    is_signer: bool,
+    is_writable: bool,
+    is_invoked: bool,
+}
+
+/// Deduplicated keys (flags merged), payer first, then ordered as the runtime expects:
+/// writable signers, readonly signers, writable non-signers, readonly non-signers.
+fn collect_keys(payer: &[u8; 32], instructions: &[Instruction]) -> Vec<KeyMeta> {
+    let mut keys = vec![KeyMeta { pubkey: *payer, is_signer: true, is_writable: true, is_invoked: false }];
+    let mut add = |meta: &AccountMeta, invoked: bool| match keys.iter_mut().find(|k| k.pubkey == meta.pubkey) {
+        Some(existing) => {
+            existing.is_signer |= meta.is_signer;
+            existing.is_writable |= meta.is_writable;
+            existing.is_invoked |= invoked;
+        }
+        None => keys.push(KeyMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+            is_invoked: invoked,
+        }),
+    };
+    for ix in instructions {
+        for meta in &ix.accounts {
+            add(meta, false);
+        }
+        add(&AccountMeta::readonly(ix.program_id, false), true);
+    }
+    // Stable sort keeps the payer first and otherwise preserves first-use order
+    let rank = |k: &KeyMeta| match (k.is_signer, k.is_writable) {
+        (true, true) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (false, false) => 3,
+    };
+    keys[1..].sort_by_key(rank);
+    keys
+}
+
+fn compile_instructions(instructions: &[Instruction], all_keys: &[[u8; 32]]) -> Result<Vec<CompiledInstruction>, String> {
+    if all_keys.len() > 256 {
+        return Err("Too many accounts for one message".into());
+    }
+    let index_of = |key: &[u8; 32]| all_keys.iter().position(|k| k == key).map(|i| i as u8)
+        .ok_or_else(|| "Account missing from message keys".to_string());
+    instructions.iter().map(|ix| Ok(CompiledInstruction {
+        program_id_index: index_of(&ix.program_id)?,
+        accounts: ix.accounts.iter().map(|m| index_of(&m.pubkey)).collect::<Result<_, _>>()?,
+        data: ix.data.clone(),
+    })).collect()
+}
+
+fn header_for(keys: &[KeyMeta]) -> MessageHeader {
+    MessageHeader {
+        num_required_signatures: keys.iter().filter(|k| k.is_signer).count() as u8,
+        num_readonly_signed: keys.iter().filter(|k| k.is_signer && !k.is_writable).count() as u8,
+        num_readonly_unsigned: keys.iter().filter(|k| !k.is_signer && !k.is_writable).count() as u8,
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(n).ok_or("Length overflow")?;
+        let out = self.bytes.get(self.pos..end).ok_or("Truncated Solana message")?;
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn len(&mut self) -> Result<usize, String> {
+        let (value, used) = decode_compact_u16(&self.bytes[self.pos.min(self.bytes.len())..])?;
+        self.pos += used;
+        Ok(value)
+    }
+
+    fn key(&mut self) -> Result<[u8; 32], String> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn vec(&mut self) -> Result<Vec<u8>, String> {
+        let n = self.len()?;
+        Ok(self.take(n)?.to_vec())
+    }
+}
+
+impl Message {
+    /// Legacy message: every account is listed in the message
+    pub fn compile_legacy(payer: &[u8; 32], instructions: &[Instruction], recent_blockhash: &[u8; 32]) -> Result<Self, String> {
+        let keys = collect_keys(payer, instructions);
+        let account_keys: Vec<[u8; 32]> = keys.iter().map(|k| k.pubkey).collect();
+        Ok(Message {
+            version: MessageVersion::Legacy,
+            header: header_for(&keys),
+            instructions: compile_instructions(instructions, &account_keys)?,
+            account_keys,
+            recent_blockhash: *recent_blockhash,
+            address_table_lookups: Vec::new(),
+        })
+    }
+
+    /// v0 message: non-signer accounts found in `tables` are loaded by index instead
+    /// of listed. Program ids stay static, as the runtime requires.
+    pub fn compile_v0(
+        payer: &[u8; 32],
+        instructions: &[Instruction],
+        recent_blockhash: &[u8; 32],
+        tables: &[AddressLookupTable],
+    ) -> Result<Self, String> {
+        let mut keys = collect_keys(payer, instructions);
+        let mut lookups = Vec::new();
+        let mut loaded_writable = Vec::new();
+        let mut loaded_readonly = Vec::new();
+
+        for table in tables {
+            let mut lookup = MessageAddressTableLookup {
+                account_key: table.key,
+                writable_indexes: Vec::new(),
+                readonly_indexes: Vec::new(),
+            };
+            keys.retain(|k| {
+                if k.is_signer || k.is_invoked {
+                    return true;
+                }
+                match table.addresses.iter().take(256).position(|a| *a == k.pubkey) {
+                    Some(i) if k.is_writable => {
+                        lookup.writable_indexes.push(i as u8);
+                        loaded_writable.push(k.pubkey);
+                        false
+                    }
+                    Some(i) => {
+                        lookup.readonly_indexes.push(i as u8);
+                        loaded_readonly.push(k.pubkey);
+                        false
+                    }
+                    None => true,
+                }
+            });
+            if !lookup.writable_indexes.is_empty() || !lookup.readonly_indexes.is_empty() {
+                lookups.push(lookup);
+            }
+        }
+
+        let account_keys: Vec<[u8; 32]> = keys.iter().map(|k| k.pubkey).collect();
+        let all_keys: Vec<[u8; 32]> = account_keys.iter()
+            .chain(&loaded_writable)
+            .chain(&loaded_readonly)
+            .copied()
+            .collect();
+        Ok(Message {
+            version: MessageVersion::V0,
+            header: header_for(&keys),
+            instructions: compile_instructions(instructions, &all_keys)?,
+            account_keys,
+            recent_blockhash: *recent_blockhash,
+            address_table_lookups: lookups,
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if self.version == MessageVersion::V0 {
+            out.push(VERSION_PREFIX);
+        }
+        out.extend_from_slice(&[
+            self.header.num_required_signatures,
+            self.header.num_readonly_signed,
+            self.header.num_readonly_unsigned,
+        ]);
+        encode_compact_u16(self.account_keys.len(), &mut out);
+        for key in &self.account_keys {
+            out.extend_from_slice(key);
+        }
+        out.extend_from_slice(&self.recent_blockhash);
+        encode_compact_u16(self.instructions.len(), &mut out);
+        for ix in &self.instructions {
+            out.push(ix.program_id_index);
+            encode_compact_u16(ix.accounts.len(), &mut out);
+            out.extend_from_slice(&ix.accounts);
+            encode_compact_u16(ix.data.len(), &mut out);
+            out.extend_from_slice(&ix.data);
+        }
+        if self.version == MessageVersion::V0 {
+            encode_compact_u16(self.address_table_lookups.len(), &mut out);
+            for lookup in &self.address_table_lookups {
+                out.extend_from_slice(&lookup.account_key);
+                encode_compact_u16(lookup.writable_indexes.len(), &mut out);
+                out.extend_from_slice(&lookup.writable_indexes);
+                encode_compact_u16(lookup.readonly_indexes.len(), &mut out);
+                out.extend_from_slice(&lookup.readonly_indexes);
+            }
+        }
+        out
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = Reader { bytes, pos: 0 };
+        let message = Self::read(&mut r)?;
+        if r.pos != bytes.len() {
+            return Err("Trailing bytes after Solana message".into());
+        }
+        Ok(message)
+    }
+
+    fn read(r: &mut Reader) -> Result<Self, String> {
+        let first = r.byte()?;
+        let (version, num_required_signatures) = if first & VERSION_PREFIX != 0 {
+            match first & !VERSION_PREFIX {
+                0 => (MessageVersion::V0, r.byte()?),
+                v => return Err(format!("Unsupported Solana message version {}", v)),
+            }
+        } else {
+            (MessageVersion::Legacy, first)
+        };
+        let header = MessageHeader {
+            num_required_signatures,
+            num_readonly_signed: r.byte()?,
+            num_readonly_unsigned: r.byte()?,
+        };
+
+        let account_keys = (0..r.len()?).map(|_| r.key()).collect::<Result<Vec<_>, _>>()?;
+        let recent_blockhash = r.key()?;
+        let instructions = (0..r.len()?).map(|_| Ok(CompiledInstruction {
+            program_id_index: r.byte()?,
+            accounts: r.vec()?,
+            data: r.vec()?,
+        })).collect::<Result<Vec<_>, String>>()?;
+        let address_table_lookups = match version {
+            MessageVersion::Legacy => Vec::new(),
+            MessageVersion::V0 => (0..r.len()?).map(|_| Ok(MessageAddressTableLookup {
+                account_key: r.key()?,
+                writable_indexes: r.vec()?,
+                readonly_indexes: r.vec()?,
+            })).collect::<Result<Vec<_>, String>>()?,
+        };
+
+        let message = Message { version, header, account_keys, recent_blockhash, instructions, address_table_lookups };
+        message.sanitize()?;
+        Ok(message)
+    }
+
+    /// Structural checks the runtime applies before loading accounts
+    fn sanitize(&self) -> Result<(), String> {
+        let h = &self.header;
+        let keys = self.account_keys.len();
+        if h.num_required_signatures == 0 || h.num_required_signatures as usize > keys {
+            return Err("Invalid number of required signatures".into());
+        }
+        if h.num_readonly_signed >= h.num_required_signatures {
+            return Err("Fee payer must be writable".into());
+        }
+        if h.num_readonly_unsigned as usize > keys - h.num_required_signatures as usize {
+            return Err("Invalid readonly account count".into());
+        }
+        let total = keys + self.loaded_len();
+        if total > 256 {
+            return Err("Too many accounts for one message".into());
+        }
+        for ix in &self.instructions {
+            // Programs must be static keys and can't be the fee payer
+            if ix.program_id_index == 0 || ix.program_id_index as usize >= keys {
+                return Err("Invalid program id index".into());
+            }
+            if ix.accounts.iter().any(|&i| i as usize >= total) {
+                return Err("Instruction account index out of range".into());
+            }
+        }
+        Ok(())
+    }
+
+    fn loaded_len(&self) -> usize {
+        self.address_table_lookups.iter()
+            .map(|l| l.writable_indexes.len() + l.readonly_indexes.len())
+            .sum()
+    }
+
+    /// Keys that must sign, in signature order
+    pub fn signer_keys(&self) -> &[[u8; 32]] {
+        &self.account_keys[..self.header.num_required_signatures as usize]
+    }
+
+    pub fn fee_payer(&self) -> [u8; 32] {
+        self.account_keys[0]
+    }
+
+    fn static_meta(&self, i: usize) -> AccountMeta {
+        let h = &self.header;
+        let signers = h.num_required_signatures as usize;
+        let is_signer = i < signers;
+        let is_writable = if is_signer {
+            i < signers - h.num_readonly_signed as usize
+        } else {
+            i < self.account_keys.len() - h.num_readonly_unsigned as usize
+        };
+        AccountMeta { pubkey: self.account_keys[i], is_signer, is_writable }
+    }
+
+    /// Full account list (static keys, then writable and readonly loaded addresses)
+    /// with signer/writable flags. `tables` must hold every table the message uses.
+    pub fn resolve_accounts(&self, tables: &[AddressLookupTable]) -> Result<Vec<AccountMeta>, String> {
+        let mut accounts: Vec<AccountMeta> = (0..self.account_keys.len()).map(|i| self.static_meta(i)).collect();
+        let mut readonly = Vec::new();
+        for lookup in &self.address_table_lookups {
+            let table = tables.iter().find(|t| t.key == lookup.account_key)
+                .ok_or_else(|| format!("Lookup table {} not provided", bs58::encode(lookup.account_key).into_string()))?;
+            let load = |i: &u8| table.addresses.get(*i as usize).copied()
+                .ok_or_else(|| "Lookup table index out of range".to_string());
+            for i in &lookup.writable_indexes {
+                accounts.push(AccountMeta::writable(load(i)?, false));
+            }
+            for i in &lookup.readonly_indexes {
+                readonly.push(AccountMeta::readonly(load(i)?, false));
+            }
+        }
+        accounts.extend(readonly);
+        Ok(accounts)
+    }
+
+    /// Expand compiled instructions back to program ids and account metas
+    pub fn decompile(&self, tables: &[AddressLookupTable]) -> Result<Vec<Instruction>, String> {
+        let accounts = self.resolve_accounts(tables)?;
+        let meta = |i: u8| accounts.get(i as usize).cloned().ok_or_else(|| "Account index out of range".to_string());
+        self.instructions.iter().map(|ix| Ok(Instruction {
+            program_id: meta(ix.program_id_index)?.pubkey,
+            accounts: ix.accounts.iter().map(|&i| meta(i)).collect::<Result<_, _>>()?,
+            data: ix.data.clone(),
+        })).collect()
+    }
+}
+
+/// A message with its signatures, one per required signer (all-zero when missing)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    pub signatures: Vec<[u8; 64]>,
+    pub message: Message,
+}
+
+impl Transaction {
+    pub fn new_unsigned(message: Message) -> Self {
+        let signatures = vec![[0u8; 64]; message.header.num_required_signatures as usize];
+        Transaction { signatures, message }
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_compact_u16(self.signatures.len(), &mut out);
+        for sig in &self.signatures {
+            out.extend_from_slice(sig);
+        }
+        out.extend_from_slice(&self.message.serialize());
+        out
+    }
+
+    /// Parse a wire transaction (as returned base64 by dApps and swap APIs)
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = Reader { bytes, pos: 0 };
+        let signatures = (0..r.len()?)
+            .map(|_| Ok(r.take(64)?.try_into().unwrap()))
+            .collect::<Result<Vec<[u8; 64]>, String>>()?;
+        let message = Message::read(&mut r)?;
+        if r.pos != bytes.len() {
+            return Err("Trailing bytes after Solana transaction".into());
+        }
+        if signatures.len() != message.header.num_required_signatures as usize {
+            return Err("Signature count does not match the message header".into());
+        }
+        Ok(Transaction { signatures, message })
+    }
+
+    /// Add our signature in the slot of our public key; other signatures are kept
+    pub fn sign(&mut self, private_key: &[u8; 32]) -> Result<(), String> {
+        let signing_key = SigningKey::from_bytes(private_key);
+        let pubkey = signing_key.verifying_key().to_bytes();
+        let slot = self.message.signer_keys().iter().position(|k| *k == pubkey)
+            .ok_or("This wallet is not a required signer of the transaction")?;
+        self.signatures[slot] = signing_key.sign(&self.message.serialize()).to_bytes();
+        Ok(())
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.signatures.iter().all(|s| s.iter().any(|&b| b != 0))
+    }
+
+    /// Wire bytes and the fee payer's signature as transaction id
+    pub fn into_signed(self) -> SignedTransaction {
+        SignedTransaction {
+            chain_id: ChainId::Solana,
+            tx_hash: bs58::encode(&self.signatures[0]).into_string(),
+            raw_bytes: self.serialize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, Verifier};
+
+    fn ix(program: u8, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction { program_id: [program; 32], accounts, data: vec![program, 0xaa] }
+    }
+
+    #[test]
+    fn test_decode_compact_u16() {
+        for n in [0usize, 1, 0x7f, 0x80, 0x3fff, 0x4000, 0xffff] {
+            let mut v = Vec::new();
+            encode_compact_u16(n, &mut v);
+            assert_eq!(decode_compact_u16(&v).unwrap(), (n, v.len()));
+        }
+        assert!(decode_compact_u16(&[0x80, 0x00]).is_err());
+        assert!(decode_compact_u16(&[0xff, 0xff, 0x04]).is_err());
+        assert!(decode_compact_u16(&[0x80]).is_err());
+    }
+
+    #[test]
+    fn test_legacy_roundtrip() {
+        let payer = [9u8; 32];
+        let ixs = vec![
+            ix(7, vec![AccountMeta::writable([5u8; 32], false), AccountMeta::readonly(payer, true)]),
+            ix(8, vec![AccountMeta::readonly([6u8; 32], false)]),
+        ];
+        let message = Message::compile_legacy(&payer, &ixs, &[1u8; 32]).unwrap();
+        let bytes = message.serialize();
+        assert_eq!(bytes[0], 1, "legacy messages start with the signer count");
+        let parsed = Message::deserialize(&bytes).unwrap();
+        assert_eq!(parsed, message);
+
+        let decompiled = parsed.decompile(&[]).unwrap();
+        assert_eq!(decompiled[0].program_id, [7u8; 32]);
+        assert_eq!(decompiled[0].accounts[0], AccountMeta::writable([5u8; 32], false));
+        // the payer's flags are merged to writable signer
+        assert_eq!(decompiled[0].accounts[1], AccountMeta::writable(payer, true));
+        assert_eq!(decompiled[1].data, vec![8, 0xaa]);
+    }
+
+    #[test]
+    fn test_v0_with_lookup_table() {
+        let payer = [9u8; 32];
+        let table = AddressLookupTable {
+            key: [0xee; 32],
+            addresses: vec![[1u8; 32], [5u8; 32], [6u8; 32], [7u8; 32], [4u8; 32]],
+        };
+        let ixs = vec![ix(7, vec![
+            AccountMeta::readonly([6u8; 32], false),
+            AccountMeta::writable([5u8; 32], false),
+            AccountMeta::writable([4u8; 32], true),
+            AccountMeta::writable([3u8; 32], false),
+        ])];
+        let message = Message::compile_v0(&payer, &ixs, &[2u8; 32], std::slice::from_ref(&table)).unwrap();
+
+        // signer [4] and program [7] stay static even though the table holds them
+        assert_eq!(message.account_keys, vec![payer, [4u8; 32], [3u8; 32], [7u8; 32]]);
+        assert_eq!(message.header, MessageHeader { num_required_signatures: 2, num_readonly_signed: 0, num_readonly_unsigned: 1 });
+        assert_eq!(message.address_table_lookups, vec![MessageAddressTableLookup {
+            account_key: [0xee; 32],
+            writable_indexes: vec![1],
+            readonly_indexes: vec![2],
+        }]);
+        // combined list: 4 static, [5] loaded writable (4), [6] loaded readonly (5)
+        assert_eq!(message.instructions[0].accounts, vec![5, 4, 1, 2]);
+        assert_eq!(message.instructions[0].program_id_index, 3);
+
+        let bytes = message.serialize();
+        assert_eq!(bytes[0], 0x80);
+        let parsed = Message::deserialize(&bytes).unwrap();
+        assert_eq!(parsed, message);
+
+        let decompiled = parsed.decompile(&[table]).unwrap();
+        assert_eq!(decompiled[0].accounts, vec![
+            AccountMeta::readonly([6u8; 32], false),
+            AccountMeta::writable([5u8; 32], false),
+            AccountMeta::writable([4u8; 32], true),
+            AccountMeta::writable([3u8; 32], false),
+        ]);
+        assert!(parsed.decompile(&[]).is_err(), "missing table must be reported");
+    }
+
+    #[test]
+    fn test_rejects_malformed_messages() {
+        let message = Message::compile_legacy(&[9u8; 32], &[ix(7, vec![])], &[0u8; 32]).unwrap();
+        let bytes = message.serialize();
+        assert!(Message::deserialize(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Message::deserialize(&trailing).is_err());
+        let mut v1 = vec![0x81];
+        v1.extend_from_slice(&bytes);
+        assert!(Message::deserialize(&v1).is_err());
+        // program index pointing at the fee payer
+        let mut bad = message.clone();
+        bad.instructions[0].program_id_index = 0;
+        assert!(Message::deserialize(&bad.serialize()).is_err());
+    }
+
+    #[test]
+    fn test_partial_signing() {
+        let ours = SigningKey::from_bytes(&[4u8; 32]);
+        let our_key = ours.verifying_key().to_bytes();
+        let payer = [9u8; 32];
+        let ixs = vec![ix(7, vec![AccountMeta::readonly(our_key, true)])];
+        let message = Message::compile_v0(&payer, &ixs, &[2u8; 32], &[]).unwrap();
+
+        let wire = Transaction::new_unsigned(message).serialize();
+        let mut tx = Transaction::deserialize(&wire).unwrap();
+        assert_eq!(tx.signatures.len(), 2);
+        tx.sign(&[4u8; 32]).unwrap();
+        assert_eq!(tx.signatures[0], [0u8; 64], "fee payer slot untouched");
+        let sig = Signature::from_bytes(&tx.signatures[1]);
+        assert!(ours.verifying_key().verify(&tx.message.serialize(), &sig).is_ok());
+        assert!(!tx.is_fully_signed());
+
+        assert!(tx.sign(&[5u8; 32]).is_err(), "not a signer");
+        assert_eq!(Transaction::deserialize(&tx.serialize()).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_lookup_table_account() {
+        let mut data = vec![0u8; LOOKUP_TABLE_META_SIZE];
+        data[0] = 1;
+        data.extend_from_slice(&[3u8; 32]);
+        data.extend_from_slice(&[4u8; 32]);
+        let table = AddressLookupTable::from_account_data([1u8; 32], &data).unwrap();
+        assert_eq!(table.addresses, vec![[3u8; 32], [4u8; 32]]);
+
+        assert!(AddressLookupTable::from_account_data([1u8; 32], &data[..70]).is_err());
+        data[0] = 0;
+        assert!(AddressLookupTable::from_account_data([1u8; 32], &data).is_err());
+    }
+}
@@ -0,0 +1,545 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// tx/solana_stake: Native SOL staking with the Stake program — seeded stake
+// accounts, delegate / deactivate / withdraw / split / merge instructions,
+// stake account and validator (vote account) parsing
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::solana::{parse_pubkey, AccountMeta, Instruction, SolanaTransfer};
+use crate::tokens::spl;
+
+/// Stake program
+pub const STAKE_PROGRAM_ID: &str = "Stake11111111111111111111111111111111111111";
+
+/// Stake config account, still expected by `DelegateStake`
+pub const STAKE_CONFIG_ID: &str = "StakeConfig11111111111111111111111111111111";
+
+pub const SYSVAR_CLOCK_ID: &str = "SysvarC1ock11111111111111111111111111111111";
+pub const SYSVAR_RENT_ID: &str = "SysvarRent111111111111111111111111111111111";
+pub const SYSVAR_STAKE_HISTORY_ID: &str = "SysvarStakeHistory1111111111111111111111111";
+
+/// Size of a stake account (`StakeStateV2`)
+pub const STAKE_ACCOUNT_SIZE: u64 = 200;
+
+/// Rent-exempt minimum of a stake account, kept in the account while it exists
+pub const STAKE_ACCOUNT_RENT_LAMPORTS: u64 = 2_282_880;
+
+/// Prefix of the seeds this wallet derives stake accounts with (`stake:0`, `stake:1`, ...)
+pub const STAKE_SEED_PREFIX: &str = "stake:";
+
+const MAX_SEED_LEN: usize = 32;
+
+// StakeInstruction tags
+const IX_INITIALIZE: u32 = 0;
+const IX_DELEGATE: u32 = 2;
+const IX_SPLIT: u32 = 3;
+const IX_WITHDRAW: u32 = 4;
+const IX_DEACTIVATE: u32 = 5;
+const IX_MERGE: u32 = 7;
+
+// SystemInstruction tags
+const SYSTEM_CREATE_WITH_SEED: u32 = 3;
+const SYSTEM_ALLOCATE_WITH_SEED: u32 = 9;
+
+// StakeStateV2 layout
+const STATE_INITIALIZED: u32 = 1;
+const STATE_STAKE: u32 = 2;
+const STAKER_OFFSET: usize = 12;
+
+/// Offset of the withdraw authority, for `getProgramAccounts` memcmp filters
+pub const WITHDRAWER_OFFSET: usize = 44;
+
+/// `create_with_seed`: address of an account derived from `base`, `seed` and its owner program
+pub fn create_with_seed(base: &[u8; 32], seed: &str, owner: &[u8; 32]) -> Result<[u8; 32], String> {
+    if seed.len() > MAX_SEED_LEN {
+        return Err("Seed too long".into());
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(base);
+    hasher.update(seed.as_bytes());
+    hasher.update(owner);
+    Ok(hasher.finalize().into())
+}
+
+/// Seed of this wallet's `index`-th stake account
+pub fn stake_seed(index: u32) -> String {
+    format!("{}{}", STAKE_SEED_PREFIX, index)
+}
+
+/// Address of the stake account derived from `owner` and `seed`
+pub fn stake_account_address(owner: &[u8; 32], seed: &str) -> Result<[u8; 32], String> {
+    create_with_seed(owner, seed, &spl::program_key(STAKE_PROGRAM_ID))
+}
+
+fn push_seed(data: &mut Vec<u8>, seed: &str) {
+    // bincode String: u64 length prefix
+    data.extend_from_slice(&(seed.len() as u64).to_le_bytes());
+    data.extend_from_slice(seed.as_bytes());
+}
+
+fn stake_instruction(tag: u32, payload: &[u8], accounts: Vec<AccountMeta>) -> Instruction {
+    let mut data = tag.to_le_bytes().to_vec();
+    data.extend_from_slice(payload);
+    Instruction { program_id: spl::program_key(STAKE_PROGRAM_ID), accounts, data }
+}
+
+fn sysvar(id: &str) -> AccountMeta {
+    AccountMeta::readonly(spl::program_key(id), false)
+}
+
+/// SystemProgram `CreateAccountWithSeed` for a stake account funded by `owner`
+fn create_stake_account(owner: &[u8; 32], seed: &str, lamports: u64) -> Result<Instruction, String> {
+    let stake = stake_account_address(owner, seed)?;
+    let mut data = SYSTEM_CREATE_WITH_SEED.to_le_bytes().to_vec();
+    data.extend_from_slice(owner);
+    push_seed(&mut data, seed);
+    data.extend_from_slice(&lamports.to_le_bytes());
+    data.extend_from_slice(&STAKE_ACCOUNT_SIZE.to_le_bytes());
+    data.extend_from_slice(&spl::program_key(STAKE_PROGRAM_ID));
+    Ok(Instruction {
+        program_id: spl::program_key(spl::SYSTEM_PROGRAM_ID),
+        accounts: vec![AccountMeta::writable(*owner, true), AccountMeta::writable(stake, false), AccountMeta::readonly(*owner, true)],
+        data,
+    })
+}
+
+/// Stake `Initialize` with `owner` as staker and withdrawer and no lockup
+fn initialize(stake: &[u8; 32], owner: &[u8; 32]) -> Instruction {
+    let mut payload = Vec::with_capacity(112);
+    payload.extend_from_slice(owner);
+    payload.extend_from_slice(owner);
+    // Lockup: unix_timestamp, epoch, custodian
+    payload.extend_from_slice(&[0u8; 48]);
+    stake_instruction(IX_INITIALIZE, &payload, vec![AccountMeta::writable(*stake, false), sysvar(SYSVAR_RENT_ID)])
+}
+
+/// `DelegateStake` of `stake` to the validator vote account `vote`
+pub fn delegate(stake: &[u8; 32], vote: &[u8; 32], staker: &[u8; 32]) -> Instruction {
+    stake_instruction(IX_DELEGATE, &[], vec![
+        AccountMeta::writable(*stake, false),
+        AccountMeta::readonly(*vote, false),
+        sysvar(SYSVAR_CLOCK_ID),
+        sysvar(SYSVAR_STAKE_HISTORY_ID),
+        AccountMeta::readonly(spl::program_key(STAKE_CONFIG_ID), false),
+        AccountMeta::readonly(*staker, true),
+    ])
+}
+
+/// `Deactivate`: the stake cools down and becomes withdrawable after the epoch ends
+pub fn deactivate(stake: &[u8; 32], staker: &[u8; 32]) -> Instruction {
+    stake_instruction(IX_DEACTIVATE, &[], vec![
+        AccountMeta::writable(*stake, false),
+        sysvar(SYSVAR_CLOCK_ID),
+        AccountMeta::readonly(*staker, true),
+    ])
+}
+
+/// `Withdraw` `lamports` from `stake` to `to`; withdrawing everything closes the account
+pub fn withdraw(stake: &[u8; 32], withdrawer: &[u8; 32], to: &[u8; 32], lamports: u64) -> Instruction {
+    stake_instruction(IX_WITHDRAW, &lamports.to_le_bytes(), vec![
+        AccountMeta::writable(*stake, false),
+        AccountMeta::writable(*to, false),
+        sysvar(SYSVAR_CLOCK_ID),
+        sysvar(SYSVAR_STAKE_HISTORY_ID),
+        AccountMeta::readonly(*withdrawer, true),
+    ])
+}
+
+/// `Merge` `source` into `destination`; the source account is closed
+pub fn merge(destination: &[u8; 32], source: &[u8; 32], staker: &[u8; 32]) -> Instruction {
+    stake_instruction(IX_MERGE, &[], vec![
+        AccountMeta::writable(*destination, false),
+        AccountMeta::writable(*source, false),
+        sysvar(SYSVAR_CLOCK_ID),
+        sysvar(SYSVAR_STAKE_HISTORY_ID),
+        AccountMeta::readonly(*staker, true),
+    ])
+}
+
+/// `Split` `lamports` of `stake` into the new seeded account `seed`. The new
+/// account is funded with its rent reserve first, as the Stake program requires.
+pub fn split(stake: &[u8; 32], owner: &[u8; 32], seed: &str, lamports: u64) -> Result<Vec<Instruction>, String> {
+    let destination = stake_account_address(owner, seed)?;
+    let mut data = SYSTEM_ALLOCATE_WITH_SEED.to_le_bytes().to_vec();
+    data.extend_from_slice(owner);
+    push_seed(&mut data, seed);
+    data.extend_from_slice(&STAKE_ACCOUNT_SIZE.to_le_bytes());
+    data.extend_from_slice(&spl::program_key(STAKE_PROGRAM_ID));
+    let allocate = Instruction {
+        program_id: spl::program_key(spl::SYSTEM_PROGRAM_ID),
+        accounts: vec![AccountMeta::writable(destination, false), AccountMeta::readonly(*owner, true)],
+        data,
+    };
+    let rent = SolanaTransfer {
+        from_pubkey: *owner,
+        to_pubkey: destination,
+        lamports: STAKE_ACCOUNT_RENT_LAMPORTS,
+        recent_blockhash: [0u8; 32],
+    }.instruction();
+    Ok(vec![
+        rent,
+        allocate,
+        stake_instruction(IX_SPLIT, &lamports.to_le_bytes(), vec![
+            AccountMeta::writable(*stake, false),
+            AccountMeta::writable(destination, false),
+            AccountMeta::readonly(*owner, true),
+        ]),
+    ])
+}
+
+/// A staking action of the wallet owner, who is staker and withdrawer of its accounts
+#[derive(Debug, Clone, PartialEq)]
+pub enum StakeOperation {
+    /// Create the seeded account `seed` holding `lamports` (rent included) and delegate it
+    Stake { seed: String, lamports: u64, vote_account: [u8; 32] },
+    /// Delegate an undelegated or deactivated account
+    Delegate { stake_account: [u8; 32], vote_account: [u8; 32] },
+    Deactivate { stake_account: [u8; 32] },
+    /// Withdraw to the owner's wallet
+    Withdraw { stake_account: [u8; 32], lamports: u64 },
+    /// Move `lamports` into the new seeded account `seed`
+    Split { stake_account: [u8; 32], seed: String, lamports: u64 },
+    Merge { destination: [u8; 32], source: [u8; 32] },
+}
+
+impl StakeOperation {
+    pub fn instructions(&self, owner: &[u8; 32]) -> Result<Vec<Instruction>, String> {
+        match self {
+            StakeOperation::Stake { seed, lamports, vote_account } => {
+                if *lamports <= STAKE_ACCOUNT_RENT_LAMPORTS {
+                    return Err("Stake amount must exceed the stake account rent reserve".into());
+                }
+                let stake = stake_account_address(owner, seed)?;
+                Ok(vec![
+                    create_stake_account(owner, seed, *lamports)?,
+                    initialize(&stake, owner),
+                    delegate(&stake, vote_account, owner),
+                ])
+            }
+            StakeOperation::Delegate { stake_account, vote_account } => Ok(vec![delegate(stake_account, vote_account, owner)]),
+            StakeOperation::Deactivate { stake_account } => Ok(vec![deactivate(stake_account, owner)]),
+            StakeOperation::Withdraw { stake_account, lamports } => {
+                if *lamports == 0 {
+                    return Err("Nothing to withdraw".into());
+                }
+                Ok(vec![withdraw(stake_account, owner, owner, *lamports)])
+            }
+            StakeOperation::Split { stake_account, seed, lamports } => {
+                if *lamports == 0 {
+                    return Err("Split amount must be greater than zero".into());
+                }
+                split(stake_account, owner, seed, *lamports)
+            }
+            StakeOperation::Merge { destination, source } => {
+                if destination == source {
+                    return Err("Cannot merge a stake account into itself".into());
+                }
+                Ok(vec![merge(destination, source, owner)])
+            }
+        }
+    }
+}
+
+/// Where a stake account is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivationState {
+    /// Initialized but never delegated
+    Undelegated,
+    Activating,
+    Active,
+    Deactivating,
+    /// Deactivated; the lamports can be withdrawn
+    Inactive,
+}
+
+/// Delegation part of a delegated stake account
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delegation {
+    pub vote_account: [u8; 32],
+    pub stake: u64,
+    pub activation_epoch: u64,
+    /// `u64::MAX` while not deactivated
+    pub deactivation_epoch: u64,
+}
+
+/// A parsed stake account
+#[derive(Debug, Clone, PartialEq)]
+pub struct StakeAccount {
+    pub address: [u8; 32],
+    pub lamports: u64,
+    pub rent_exempt_reserve: u64,
+    pub staker: [u8; 32],
+    pub withdrawer: [u8; 32],
+    pub lockup_unix_timestamp: i64,
+    pub lockup_epoch: u64,
+    pub custodian: [u8; 32],
+    pub delegation: Option<Delegation>,
+}
+
+fn read_u64(data: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(data[at..at + 8].try_into().unwrap())
+}
+
+fn read_key(data: &[u8], at: usize) -> [u8; 32] {
+    data[at..at + 32].try_into().unwrap()
+}
+
+impl StakeAccount {
+    /// Parse `StakeStateV2` account data
+    pub fn parse(address: [u8; 32], lamports: u64, data: &[u8]) -> Result<Self, String> {
+        if data.len() < STAKE_ACCOUNT_SIZE as usize - 4 {
+            return Err("Stake account data too short".into());
+        }
+        let state = u32::from_le_bytes(data[..4].try_into().unwrap());
+        if state != STATE_INITIALIZED && state != STATE_STAKE {
+            return Err("Stake account is not initialized".into());
+        }
+        let delegation = (state == STATE_STAKE).then(|| Delegation {
+            vote_account: read_key(data, 124),
+            stake: read_u64(data, 156),
+            activation_epoch: read_u64(data, 164),
+            deactivation_epoch: read_u64(data, 172),
+        });
+        Ok(StakeAccount {
+            address,
+            lamports,
+            rent_exempt_reserve: read_u64(data, 4),
+            staker: read_key(data, STAKER_OFFSET),
+            withdrawer: read_key(data, WITHDRAWER_OFFSET),
+            lockup_unix_timestamp: read_u64(data, 76) as i64,
+            lockup_epoch: read_u64(data, 84),
+            custodian: read_key(data, 92),
+            delegation,
+        })
+    }
+
+    /// State at `epoch`. Warmup and cooldown are assumed to complete in one
+    /// epoch, which holds unless a large share of all stake moves at once.
+    pub fn activation(&self, epoch: u64) -> ActivationState {
+        let Some(d) = &self.delegation else { return ActivationState::Undelegated };
+        if d.activation_epoch == d.deactivation_epoch {
+            // Deactivated in the epoch it was delegated: never became active
+            return ActivationState::Inactive;
+        }
+        if d.deactivation_epoch != u64::MAX {
+            return if epoch <= d.deactivation_epoch { ActivationState::Deactivating } else { ActivationState::Inactive };
+        }
+        if epoch <= d.activation_epoch { ActivationState::Activating } else { ActivationState::Active }
+    }
+
+    /// Whether a lockup still blocks withdrawals at `epoch` / `unix_time`
+    pub fn is_locked(&self, epoch: u64, unix_time: i64) -> bool {
+        self.lockup_epoch > epoch || self.lockup_unix_timestamp > unix_time
+    }
+
+    /// Lamports the withdrawer can take out at `epoch`. Delegated stake stays
+    /// until it is inactive; only the excess over stake and rent is free before.
+    pub fn withdrawable(&self, epoch: u64) -> u64 {
+        match self.activation(epoch) {
+            ActivationState::Undelegated | ActivationState::Inactive => self.lamports,
+            _ => {
+                let staked = self.delegation.as_ref().map_or(0, |d| d.stake);
+                self.lamports.saturating_sub(staked).saturating_sub(self.rent_exempt_reserve)
+            }
+        }
+    }
+
+    /// Whether `source` can be merged into this account at `epoch`: same
+    /// authorities and lockup, and both inactive or both active on one validator
+    pub fn can_merge(&self, source: &StakeAccount, epoch: u64) -> bool {
+        if self.address == source.address
+            || self.staker != source.staker
+            || self.withdrawer != source.withdrawer
+            || (self.lockup_unix_timestamp, self.lockup_epoch, self.custodian)
+                != (source.lockup_unix_timestamp, source.lockup_epoch, source.custodian)
+        {
+            return false;
+        }
+        let idle = |s: ActivationState| matches!(s, ActivationState::Undelegated | ActivationState::Inactive);
+        match (self.activation(epoch), source.activation(epoch)) {
+            (a, b) if idle(a) && idle(b) => true,
+            (ActivationState::Active, ActivationState::Active) => {
+                self.delegation.as_ref().map(|d| d.vote_account) == source.delegation.as_ref().map(|d| d.vote_account)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Stake accounts from a base64 `getProgramAccounts` response of the Stake program
+pub fn stake_accounts_from_response(response: &Value) -> Result<Vec<StakeAccount>, String> {
+    if let Some(error) = response.get("error") {
+        return Err(format!("getProgramAccounts error: {}", error));
+    }
+    let entries = response.get("result")
+        .and_then(|r| r.as_array())
+        .ok_or("Missing result in getProgramAccounts")?;
+    let mut accounts = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let address = parse_pubkey(entry["pubkey"].as_str().ok_or("Missing pubkey")?)?;
+        let account = &entry["account"];
+        let lamports = account["lamports"].as_u64().ok_or("Missing lamports")?;
+        let encoded = account["data"].get(0).and_then(|d| d.as_str()).ok_or("Missing account data")?;
+        // Uninitialized accounts (e.g. a failed split) have nothing to show
+        if let Ok(stake) = StakeAccount::parse(address, lamports, &crate::backup::base64_decode(encoded)?) {
+            accounts.push(stake);
+        }
+    }
+    accounts.sort_by_key(|a| std::cmp::Reverse(a.lamports));
+    Ok(accounts)
+}
+
+/// A validator from `getVoteAccounts`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validator {
+    pub vote_account: String,
+    pub node: String,
+    pub activated_stake: u64,
+    /// Percent of rewards the validator keeps
+    pub commission: u8,
+    pub delinquent: bool,
+}
+
+/// Validators from a `getVoteAccounts` response, current ones first, by stake
+pub fn validators_from_response(response: &Value) -> Result<Vec<Validator>, String> {
+    if let Some(error) = response.get("error") {
+        return Err(format!("getVoteAccounts error: {}", error));
+    }
+    let result = response.get("result").ok_or("Missing result in getVoteAccounts")?;
+    let mut validators = Vec::new();
+    for (key, delinquent) in [("current", false), ("delinquent", true)] {
+        let Some(list) = result.get(key).and_then(|l| l.as_array()) else { continue };
+        validators.extend(list.iter().filter_map(|v| Some(Validator {
+            vote_account: v["votePubkey"].as_str()?.to_string(),
+            node: v["nodePubkey"].as_str()?.to_string(),
+            activated_stake: v["activatedStake"].as_u64()?,
+            commission: v["commission"].as_u64()?.min(100) as u8,
+            delinquent,
+        })));
+    }
+    validators.sort_by_key(|v| (v.delinquent, std::cmp::Reverse(v.activated_stake)));
+    Ok(validators)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const OWNER: [u8; 32] = [7u8; 32];
+    const VOTE: [u8; 32] = [9u8; 32];
+
+    fn stake_data(delegated: Option<(u64, u64, u64)>) -> Vec<u8> {
+        let mut data = vec![0u8; STAKE_ACCOUNT_SIZE as usize];
+        data[..4].copy_from_slice(&(if delegated.is_some() { STATE_STAKE } else { STATE_INITIALIZED }).to_le_bytes());
+        data[4..12].copy_from_slice(&STAKE_ACCOUNT_RENT_LAMPORTS.to_le_bytes());
+        data[12..44].copy_from_slice(&OWNER);
+        data[44..76].copy_from_slice(&OWNER);
+        if let Some((stake, activation, deactivation)) = delegated {
+            data[124..156].copy_from_slice(&VOTE);
+            data[156..164].copy_from_slice(&stake.to_le_bytes());
+            data[164..172].copy_from_slice(&activation.to_le_bytes());
+            data[172..180].copy_from_slice(&deactivation.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_seeded_address() {
+        // solana `Pubkey::create_with_seed(&Pubkey::default(), "limber chicken: 4/45", &Pubkey::default())`
+        let address = create_with_seed(&[0u8; 32], "limber chicken: 4/45", &[0u8; 32]).unwrap();
+        assert_eq!(bs58::encode(address).into_string(), "9h1HyLCW5dZnBVap8C5egQ9Z6pHyjsh5MNy83iPqqRuq");
+        assert!(create_with_seed(&OWNER, &"x".repeat(33), &[0u8; 32]).is_err());
+        assert_eq!(stake_seed(3), "stake:3");
+        assert_ne!(stake_account_address(&OWNER, "stake:0").unwrap(), stake_account_address(&OWNER, "stake:1").unwrap());
+    }
+
+    #[test]
+    fn test_stake_instructions() {
+        let op = StakeOperation::Stake { seed: stake_seed(0), lamports: 1_000_000_000, vote_account: VOTE };
+        let ixs = op.instructions(&OWNER).unwrap();
+        let stake = stake_account_address(&OWNER, "stake:0").unwrap();
+        assert_eq!(ixs.len(), 3);
+        // CreateAccountWithSeed: tag, base, seed (u64 len + bytes), lamports, space, owner
+        assert_eq!(ixs[0].data.len(), 4 + 32 + 8 + 7 + 8 + 8 + 32);
+        assert_eq!(&ixs[0].data[36..44], &7u64.to_le_bytes());
+        assert_eq!(&ixs[0].data[44..51], b"stake:0");
+        assert_eq!(ixs[0].accounts[1].pubkey, stake);
+        assert_eq!(ixs[1].data.len(), 4 + 64 + 48);
+        assert_eq!(&ixs[1].data[4..36], &OWNER);
+        assert_eq!(ixs[2].data, 2u32.to_le_bytes().to_vec());
+        assert_eq!(ixs[2].accounts[1].pubkey, VOTE);
+        assert!(ixs[2].accounts[5].is_signer);
+
+        let too_small = StakeOperation::Stake { seed: stake_seed(0), lamports: STAKE_ACCOUNT_RENT_LAMPORTS, vote_account: VOTE };
+        assert!(too_small.instructions(&OWNER).is_err());
+
+        let split = StakeOperation::Split { stake_account: stake, seed: stake_seed(1), lamports: 500 };
+        let ixs = split.instructions(&OWNER).unwrap();
+        assert_eq!(ixs.len(), 3);
+        assert_eq!(&ixs[0].data[4..], &STAKE_ACCOUNT_RENT_LAMPORTS.to_le_bytes());
+        assert_eq!(&ixs[1].data[..4], &9u32.to_le_bytes());
+        assert_eq!(ixs[2].data, [3u32.to_le_bytes().as_slice(), &500u64.to_le_bytes()].concat());
+
+        let withdraw = StakeOperation::Withdraw { stake_account: stake, lamports: 42 }.instructions(&OWNER).unwrap();
+        assert_eq!(withdraw[0].accounts[1].pubkey, OWNER);
+        assert!(StakeOperation::Merge { destination: stake, source: stake }.instructions(&OWNER).is_err());
+    }
+
+    #[test]
+    fn test_activation_and_withdrawable() {
+        let lamports = 2_000_000_000;
+        let undelegated = StakeAccount::parse([1u8; 32], lamports, &stake_data(None)).unwrap();
+        assert_eq!(undelegated.activation(500), ActivationState::Undelegated);
+        assert_eq!(undelegated.withdrawable(500), lamports);
+
+        let stake = lamports - STAKE_ACCOUNT_RENT_LAMPORTS - 1_000;
+        let active = StakeAccount::parse([2u8; 32], lamports, &stake_data(Some((stake, 100, u64::MAX)))).unwrap();
+        assert_eq!(active.delegation.as_ref().unwrap().vote_account, VOTE);
+        assert_eq!(active.activation(100), ActivationState::Activating);
+        assert_eq!(active.activation(101), ActivationState::Active);
+        assert_eq!(active.withdrawable(101), 1_000);
+
+        let deactivated = StakeAccount::parse([3u8; 32], lamports, &stake_data(Some((stake, 100, 200)))).unwrap();
+        assert_eq!(deactivated.activation(200), ActivationState::Deactivating);
+        assert_eq!(deactivated.activation(201), ActivationState::Inactive);
+        assert_eq!(deactivated.withdrawable(201), lamports);
+        assert!(!deactivated.is_locked(201, 0));
+
+        assert!(undelegated.can_merge(&deactivated, 201));
+        assert!(!active.can_merge(&deactivated, 201));
+        let other_active = StakeAccount { address: [4u8; 32], ..active.clone() };
+        assert!(active.can_merge(&other_active, 150));
+        assert!(!active.can_merge(&other_active, 100));
+        assert!(StakeAccount::parse([5u8; 32], 0, &[0u8; 200]).is_err());
+    }
+
+    #[test]
+    fn test_parse_rpc_responses() {
+        let encoded = crate::backup::base64_encode(&stake_data(Some((1_000_000, 10, u64::MAX))));
+        let response = json!({"result": [
+            {"pubkey": bs58::encode([2u8; 32]).into_string(), "account": {"lamports": 3_282_880u64, "data": [encoded, "base64"]}},
+            {"pubkey": bs58::encode([3u8; 32]).into_string(), "account": {"lamports": 1u64, "data": [crate::backup::base64_encode(&[0u8; 200]), "base64"]}}
+        ]});
+        let accounts = stake_accounts_from_response(&response).unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].delegation.as_ref().unwrap().stake, 1_000_000);
+
+        let votes = json!({"result": {
+            "current": [
+                {"votePubkey": "V1", "nodePubkey": "N1", "activatedStake": 10u64, "commission": 5, "lastVote": 1},
+                {"votePubkey": "V2", "nodePubkey": "N2", "activatedStake": 99u64, "commission": 100, "lastVote": 1}
+            ],
+            "delinquent": [
+                {"votePubkey": "V3", "nodePubkey": "N3", "activatedStake": 500u64, "commission": 0, "lastVote": 0}
+            ]
+        }});
+        let validators = validators_from_response(&votes).unwrap();
+        let order: Vec<&str> = validators.iter().map(|v| v.vote_account.as_str()).collect();
+        assert_eq!(order, vec!["V2", "V1", "V3"]);
+        assert!(validators[2].delinquent);
+    }
+}
@@ -3,14 +3,277 @@
 //
 // tx/ton: TON internal message construction and Ed25519 signing
 //
-// Simplified TON transfer: builds a wallet v4r2 external message
-// containing an internal transfer message.
+// Wallet v4r2 transfer: the signing message (wallet id, valid_until, seqno,
+// op 0, then mode + ^internal message per transfer) is signed by its cell hash,
+// prefixed with the signature and wrapped in an external-in message. The first
+// transfer of an undeployed wallet (seqno 0) carries the wallet's StateInit.
+//
+// Wallet v5r1 transfer: op "sign", wallet id, valid_until, seqno, then the
+// out-action list (action_send_msg per transfer) in a reference. The
+// signature over the hash of that cell goes at the END of the body.
 
 use ed25519_dalek::{Signer, SigningKey};
-use sha2::{Digest, Sha256};
 
 use super::SignedTransaction;
 use crate::chains::ChainId;
+use crate::chains::ton::{self as ton_chain, TonAddress, WalletVersion, WALLET_V4R2_ID};
+use crate::chains::ton_cell::{Cell, CellBuilder};
+
+/// Send mode: pay transfer fees separately (1) + ignore errors (2)
+pub const SEND_MODE_PAY_FEES_SEPARATELY: u8 = 3;
+
+/// A wallet can send at most 4 internal messages per external message
+pub const MAX_WALLET_MESSAGES: usize = 4;
+
+/// v5r1 op of a request signed for an external message ("sign")
+pub const W5_SIGNED_EXTERNAL_OP: u32 = 0x7369_676e;
+
+/// `action_send_msg#0ec3c86d mode:uint8 out_msg:^MessageRelaxed`
+pub const ACTION_SEND_MSG: u32 = 0x0ec3_c86d;
+
+/// A v5r1 out-action list holds at most 255 actions
+pub const MAX_W5_ACTIONS: usize = 255;
+
+/// Internal message sent by the wallet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TonMessage {
+    pub destination: TonAddress,
+    pub amount_nanoton: u64,
+    /// Return the value if the destination fails (unsafe for undeployed wallets)
+    pub bounce: bool,
+    pub state_init: Option<Cell>,
+    pub body: Option<Cell>,
+}
+
+/// `int_msg_info` with zeroed fees and timestamps (the validator fills them in),
+/// then the StateInit and body as laid out by `store_init_and_body`.
+pub fn internal_message(message: &TonMessage) -> Result<Cell, String> {
+    let mut b = CellBuilder::new();
+    b.store_bit(false)? // int_msg_info$0
+        .store_bit(true)? // ihr_disabled
+        .store_bit(message.bounce)?
+        .store_bit(false)? // bounced
+        .store_address(None)? // src: filled in by the wallet contract
+        .store_address(Some(&message.destination))?
+        .store_coins(message.amount_nanoton as u128)?
+        .store_bit(false)? // no extra currencies
+        .store_coins(0)? // ihr_fee
+        .store_coins(0)? // fwd_fee
+        .store_uint(0, 64)? // created_lt
+        .store_uint(0, 32)?; // created_at
+    store_init_and_body(&mut b, message.state_init.clone(), message.body.clone())?;
+    Ok(b.build())
+}
+
+/// Op code of a text comment body
+pub const TEXT_COMMENT_OP: u32 = 0;
+
+/// Bytes of text after the 32-bit op in the first cell, and in each following cell
+const COMMENT_HEAD_BYTES: usize = 123;
+const COMMENT_TAIL_BYTES: usize = 127;
+
+/// Text comment body: op 0 followed by the UTF-8 text in snake format
+/// (continued in a chain of references when it outgrows a cell)
+pub fn text_comment(text: &str) -> Result<Cell, String> {
+    let bytes = text.as_bytes();
+    let head = bytes.len().min(COMMENT_HEAD_BYTES);
+    let mut tail: Option<Cell> = None;
+    for chunk in bytes[head..].chunks(COMMENT_TAIL_BYTES).rev() {
+        let mut b = CellBuilder::new();
+        b.store_bytes(chunk)?;
+        if let Some(next) = tail.take() {
+            b.store_ref(next)?;
+        }
+        tail = Some(b.build());
+    }
+    let mut b = CellBuilder::new();
+    b.store_uint(TEXT_COMMENT_OP as u64, 32)?.store_bytes(&bytes[..head])?;
+    if let Some(next) = tail {
+        b.store_ref(next)?;
+    }
+    Ok(b.build())
+}
+
+/// `Maybe (Either StateInit ^StateInit)` then `Either X ^X` for the body. Each
+/// goes inline when it fits and in a reference otherwise, with the same rules
+/// as @ton/core, so messages hash the same as those of the reference wallets.
+fn store_init_and_body(b: &mut CellBuilder, state_init: Option<Cell>, body: Option<Cell>) -> Result<(), String> {
+    let body_bits = body.as_ref().map_or(0, |c| c.bit_len());
+    match state_init {
+        // Room for both Either bits, the StateInit and the body
+        Some(init) if b.remaining_bits() >= 3 + init.bit_len() + body_bits => {
+            b.store_bit(true)?.store_bit(false)?.store_cell(&init)?
+        }
+        Some(init) => b.store_bit(true)?.store_bit(true)?.store_ref(init)?,
+        None => b.store_bit(false)?,
+    };
+    match body {
+        Some(body) if b.remaining_bits() > body.bit_len() && b.remaining_refs() >= body.refs().len() => {
+            b.store_bit(false)?.store_cell(&body)?
+        }
+        Some(body) => b.store_bit(true)?.store_ref(body)?,
+        None => b.store_bit(false)?,
+    };
+    Ok(())
+}
+
+/// Unsigned v4r2 request: `subwallet_id valid_until seqno op:uint8 (mode ^message)*`
+pub fn v4r2_signing_message(
+    subwallet_id: u32,
+    seqno: u32,
+    valid_until: u32,
+    messages: &[TonMessage],
+    mode: u8,
+) -> Result<Cell, String> {
+    if messages.is_empty() || messages.len() > MAX_WALLET_MESSAGES {
+        return Err(format!("A wallet transfer carries 1 to {} messages", MAX_WALLET_MESSAGES));
+    }
+    let mut b = CellBuilder::new();
+    b.store_uint(subwallet_id as u64, 32)?
+        .store_uint(valid_until as u64, 32)?
+        .store_uint(seqno as u64, 32)?
+        .store_uint(0, 8)?; // op: simple send
+    for message in messages {
+        b.store_uint(mode as u64, 8)?.store_ref(internal_message(message)?)?;
+    }
+    Ok(b.build())
+}
+
+/// Out-action list: each node is `^prev action`, starting from the empty cell,
+/// so the first message is the deepest node and is sent first
+pub fn out_action_list(messages: &[TonMessage], mode: u8) -> Result<Cell, String> {
+    let mut list = Cell::empty();
+    for message in messages {
+        let mut b = CellBuilder::new();
+        b.store_ref(list)?
+            .store_uint(ACTION_SEND_MSG as u64, 32)?
+            .store_uint(mode as u64, 8)?
+            .store_ref(internal_message(message)?)?;
+        list = b.build();
+    }
+    Ok(list)
+}
+
+/// Unsigned v5r1 external request:
+/// `"sign" wallet_id valid_until seqno (Maybe ^OutList) has_other_actions:Bool`
+pub fn v5r1_signing_message(
+    wallet_id: u32,
+    seqno: u32,
+    valid_until: u32,
+    messages: &[TonMessage],
+    mode: u8,
+) -> Result<Cell, String> {
+    if messages.is_empty() || messages.len() > MAX_W5_ACTIONS {
+        return Err(format!("A wallet transfer carries 1 to {} messages", MAX_W5_ACTIONS));
+    }
+    // External requests are rejected by the contract unless errors are ignored
+    if mode & 2 == 0 {
+        return Err("Wallet v5 external transfers need the ignore-errors send mode".into());
+    }
+    let mut b = CellBuilder::new();
+    b.store_uint(W5_SIGNED_EXTERNAL_OP as u64, 32)?
+        .store_uint(wallet_id as u64, 32)?
+        .store_uint(valid_until as u64, 32)?
+        .store_uint(seqno as u64, 32)?
+        .store_maybe_ref(Some(out_action_list(messages, mode)?))?
+        .store_bit(false)?; // no extended actions
+    Ok(b.build())
+}
+
+/// The signed cell inline, followed by the signature over its hash
+pub fn v5r1_signed_body(signing_message: &Cell, signing_key: &SigningKey) -> Result<Cell, String> {
+    let signature = signing_key.sign(&signing_message.hash());
+    let mut b = CellBuilder::new();
+    b.store_cell(signing_message)?.store_bytes(&signature.to_bytes())?;
+    Ok(b.build())
+}
+
+/// Signature over the cell hash, followed by the signed cell inline
+pub fn signed_body(signing_message: &Cell, signing_key: &SigningKey) -> Result<Cell, String> {
+    let signature = signing_key.sign(&signing_message.hash());
+    let mut b = CellBuilder::new();
+    b.store_bytes(&signature.to_bytes())?.store_cell(signing_message)?;
+    Ok(b.build())
+}
+
+/// `ext_in_msg_info` to `destination` with an optional StateInit and the body
+pub fn external_message(destination: &TonAddress, state_init: Option<Cell>, body: Cell) -> Result<Cell, String> {
+    let mut b = CellBuilder::new();
+    b.store_uint(0b10, 2)? // ext_in_msg_info$10
+        .store_address(None)? // src
+        .store_address(Some(destination))?
+        .store_coins(0)?; // import_fee
+    store_init_and_body(&mut b, state_init, Some(body))?;
+    Ok(b.build())
+}
+
+/// Sign `messages` from the v4r2 wallet of `private_key`. Returns the external
+/// message BOC and its hash; seqno 0 deploys the wallet with the same message.
+pub fn sign_v4r2(
+    private_key: &[u8; 32],
+    seqno: u32,
+    valid_until: u32,
+    messages: &[TonMessage],
+    mode: u8,
+) -> Result<SignedTransaction, String> {
+    let signing_key = SigningKey::from_bytes(private_key);
+    let pubkey = signing_key.verifying_key().to_bytes();
+    let wallet = ton_chain::wallet_v4r2_address(&pubkey)?;
+
+    let signing_message = v4r2_signing_message(WALLET_V4R2_ID, seqno, valid_until, messages, mode)?;
+    let body = signed_body(&signing_message, &signing_key)?;
+    let state_init = if seqno == 0 { Some(ton_chain::wallet_v4r2_state_init(&pubkey)?) } else { None };
+    let external = external_message(&wallet, state_init, body)?;
+
+    Ok(SignedTransaction {
+        chain_id: ChainId::Ton,
+        raw_bytes: external.to_boc(),
+        tx_hash: format!("0x{}", hex::encode(external.hash())),
+    })
+}
+
+/// Sign `messages` from the v5r1 wallet of `private_key` on the given network.
+/// seqno 0 deploys the wallet with the same message.
+pub fn sign_v5r1(
+    private_key: &[u8; 32],
+    testnet: bool,
+    seqno: u32,
+    valid_until: u32,
+    messages: &[TonMessage],
+    mode: u8,
+) -> Result<SignedTransaction, String> {
+    let signing_key = SigningKey::from_bytes(private_key);
+    let pubkey = signing_key.verifying_key().to_bytes();
+    let wallet_id = ton_chain::wallet_v5r1_id(testnet, 0, 0);
+    let wallet = ton_chain::wallet_v5r1_address(&pubkey, testnet)?;
+
+    let signing_message = v5r1_signing_message(wallet_id, seqno, valid_until, messages, mode)?;
+    let body = v5r1_signed_body(&signing_message, &signing_key)?;
+    let state_init = if seqno == 0 { Some(ton_chain::wallet_v5r1_state_init(&pubkey, wallet_id)?) } else { None };
+    let external = external_message(&wallet, state_init, body)?;
+
+    Ok(SignedTransaction {
+        chain_id: ChainId::Ton,
+        raw_bytes: external.to_boc(),
+        tx_hash: format!("0x{}", hex::encode(external.hash())),
+    })
+}
+
+/// Sign `messages` from the `version` wallet of `private_key`
+pub fn sign_for_version(
+    version: WalletVersion,
+    private_key: &[u8; 32],
+    testnet: bool,
+    seqno: u32,
+    valid_until: u32,
+    messages: &[TonMessage],
+    mode: u8,
+) -> Result<SignedTransaction, String> {
+    match version {
+        WalletVersion::V4R2 => sign_v4r2(private_key, seqno, valid_until, messages, mode),
+        WalletVersion::V5R1 => sign_v5r1(private_key, testnet, seqno, valid_until, messages, mode),
+    }
+}
 
 /// TON native transfer
 #[derive(Debug, Clone)]
@@ -22,54 +285,18 @@ pub struct TonTransfer {
 }
 
 impl TonTransfer {
-    /// Build and sign the external message for wallet v4r2
+    /// Build and sign the external message for wallet v4r2. The bounce flag
+    /// follows the recipient's address form (bounceable or not).
     pub fn sign(&self, private_key: &[u8; 32]) -> Result<SignedTransaction, String> {
-        let signing_key = SigningKey::from_bytes(private_key);
-
-        // Build the internal message body:
-        // wallet_id(4) + valid_until(4) + seqno(4) + op(1) + mode(1) + internal_msg
-        let mut body = Vec::new();
-
-        // Wallet ID (v4r2 default: 698983191 = 0x29A9A317)
-        body.extend_from_slice(&698983191u32.to_be_bytes());
-        // Valid until
-        body.extend_from_slice(&self.valid_until.to_be_bytes());
-        // Seqno
-        body.extend_from_slice(&self.seqno.to_be_bytes());
-        // Simple send op = 0
-        body.push(0);
-        // Send mode = 3 (pay fees separately + ignore errors)
-        body.push(3);
-
-        // Simplified internal message ref:
-        // flags(1) + destination(34) + amount(8) + empty state_init + empty body
-        let mut internal = Vec::new();
-        internal.push(0x00); // flags: no bounce
-        internal.extend_from_slice(&self.to_address_raw);
-        internal.extend_from_slice(&self.amount_nanoton.to_be_bytes());
-        // Extra currency, IHR fee, fwd fee, created_lt, created_at: all 0
-        internal.extend_from_slice(&[0u8; 4]); // zero fields
-        body.extend_from_slice(&internal);
-
-        // Sign the body
-        let signature = signing_key.sign(&body);
-        let sig_bytes = signature.to_bytes();
-
-        // External message = signature(64) + body
-        let mut external = Vec::with_capacity(64 + body.len());
-        external.extend_from_slice(&sig_bytes);
-        external.extend_from_slice(&body);
-
-        // Tx hash
-        let mut hasher = Sha256::new();
-        hasher.update(&external);
-        let hash = hasher.finalize();
-
-        Ok(SignedTransaction {
-            chain_id: ChainId::Ton,
-            raw_bytes: external,
-            tx_hash: format!("0x{}", hex::encode(&hash)),
-        })
+        let destination = TonAddress::from_raw_bytes(&self.to_address_raw)?;
+        let message = TonMessage {
+            destination,
+            amount_nanoton: self.amount_nanoton,
+            bounce: destination.bounceable,
+            state_init: None,
+            body: None,
+        };
+        sign_v4r2(private_key, self.seqno, self.valid_until, &[message], SEND_MODE_PAY_FEES_SEPARATELY)
     }
 }
 
@@ -127,5 +354,267 @@ mod tests {
         let signed = transfer.sign(&key).unwrap();
         assert!(!signed.raw_bytes.is_empty());
         assert!(signed.tx_hash.starts_with("0x"));
+        assert_eq!(signed.tx_hash, format!("0x{}", hex::encode(Cell::from_boc(&signed.raw_bytes).unwrap().hash())));
+    }
+
+    #[test]
+    fn test_v4r2_external_message_layout() {
+        use ed25519_dalek::Verifier;
+
+        let key = [5u8; 32];
+        let signing_key = SigningKey::from_bytes(&key);
+        let pubkey = signing_key.verifying_key().to_bytes();
+        let recipient = TonAddress { workchain: 0, hash: [0x42; 32], bounceable: false, testnet: false };
+        let mut raw = vec![0x51, 0x00];
+        raw.extend_from_slice(&recipient.hash);
+        let transfer = TonTransfer { to_address_raw: raw, amount_nanoton: 150_000_000, seqno: 0, valid_until: 1_700_000_060 };
+        let external = Cell::from_boc(&transfer.sign(&key).unwrap().raw_bytes).unwrap();
+
+        // ext_in_msg_info: src none, dest = our wallet, no import fee, then the
+        // StateInit and the body, both inline since they fit
+        let mut s = external.parser();
+        assert_eq!(s.load_uint(2).unwrap(), 0b10);
+        assert_eq!(s.load_address().unwrap(), None);
+        let wallet = ton_chain::wallet_v4r2_address(&pubkey).unwrap();
+        assert_eq!(s.load_address().unwrap().unwrap().hash, wallet.hash);
+        assert_eq!(s.load_coins().unwrap(), 0);
+        assert!(s.load_bit().unwrap() && !s.load_bit().unwrap());
+        let mut init = CellBuilder::new();
+        init.store_uint(s.load_uint(5).unwrap(), 5).unwrap()
+            .store_ref(s.load_ref().unwrap().clone()).unwrap()
+            .store_ref(s.load_ref().unwrap().clone()).unwrap();
+        assert_eq!(init.build().hash(), wallet.hash);
+        assert!(!s.load_bit().unwrap());
+
+        // Body: signature over the hash of the signing message that follows it
+        let signature: [u8; 64] = s.load_bytes(64).unwrap().try_into().unwrap();
+        let mut rest = CellBuilder::new();
+        while s.remaining_bits() > 0 {
+            rest.store_bit(s.load_bit().unwrap()).unwrap();
+        }
+        rest.store_ref(s.load_ref().unwrap().clone()).unwrap();
+        let signing_message = rest.build();
+        let message = v4r2_signing_message(WALLET_V4R2_ID, 0, 1_700_000_060, &[TonMessage {
+            destination: recipient,
+            amount_nanoton: 150_000_000,
+            bounce: false,
+            state_init: None,
+            body: None,
+        }], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_eq!(signing_message, message);
+        let verifying = signing_key.verifying_key();
+        assert!(verifying.verify(&message.hash(), &ed25519_dalek::Signature::from_bytes(&signature)).is_ok());
+
+        let mut m = message.parser();
+        assert_eq!(m.load_uint(32).unwrap(), WALLET_V4R2_ID as u64);
+        assert_eq!(m.load_uint(32).unwrap(), 1_700_000_060);
+        assert_eq!(m.load_uint(32).unwrap(), 0);
+        assert_eq!(m.load_uint(8).unwrap(), 0);
+        assert_eq!(m.load_uint(8).unwrap(), SEND_MODE_PAY_FEES_SEPARATELY as u64);
+
+        // Internal message: ihr_disabled, no bounce for a non-bounceable address
+        let mut i = m.load_ref().unwrap().parser();
+        assert!(!i.load_bit().unwrap());
+        assert!(i.load_bit().unwrap());
+        assert!(!i.load_bit().unwrap());
+        assert!(!i.load_bit().unwrap());
+        assert_eq!(i.load_address().unwrap(), None);
+        assert_eq!(i.load_address().unwrap().unwrap().hash, recipient.hash);
+        assert_eq!(i.load_coins().unwrap(), 150_000_000);
+
+        // Deployed wallets send no StateInit
+        let later = TonTransfer { seqno: 4, ..transfer };
+        let external = Cell::from_boc(&later.sign(&key).unwrap().raw_bytes).unwrap();
+        assert_eq!(external.refs().len(), 1);
+    }
+
+    #[test]
+    fn test_v4r2_known_external_messages() {
+        // Reference wallet output for key [5; 32], wallet EQDEBwR80iPMEG1K6m7ZtbCzGA_3XCNltg3oc3J_30w4NqcS.
+        // Cell hashes match tonlib-core 0.26.11 (`TonWallet::create_external_msg`);
+        // the BOC is the @ton/core `toBoc()` form: CRC32C, cells in depth-first order.
+        let comment = |to: &str, amount, bounce, text| TonMessage {
+            destination: TonAddress::parse(to).unwrap(),
+            amount_nanoton: amount,
+            bounce,
+            state_init: None,
+            body: Some(text_comment(text).unwrap()),
+        };
+        let pubkey = SigningKey::from_bytes(&[5u8; 32]).verifying_key().to_bytes();
+        assert_eq!(ton_chain::wallet_v4r2_address(&pubkey).unwrap().to_friendly(), "EQDEBwR80iPMEG1K6m7ZtbCzGA_3XCNltg3oc3J_30w4NqcS");
+
+        // seqno 0 deploys the wallet; @ton/core signs it with valid_until = 2^32 - 1
+        let deploy = sign_v4r2(&[5u8; 32], 0, u32::MAX, &[
+            comment("EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v", 1_500_000_000, true, "hello"),
+        ], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_eq!(hex::encode(&deploy.raw_bytes), concat!(
+            "b5ee9c72410217010003b50003e38801880e08f9a4479820da95d4ddb36b6166301feeb846cb6c1bd0e6e4ffbe98706c",
+            "118d6c9b361618144dee273693bacb59196d3f6163f89ebe5eb524d8465d84959a9416e36990d8812f85dcba317a1b88",
+            "b58cc0312c49b28d9e56118c5a32846221a5353462ffffffffe00000000000700115160114ff00f4a413f4bcf2c80b02",
+            "0201200310020148040702e6d001d0d3032171b0925f04e022d749c120925f04e002d31f218210706c7567bd22821064",
+            "737472bdb0925f05e003fa403020fa4401c8ca07cbffc9d0ed44d0810140d721f404305c810108f40a6fa131b3925f07",
+            "e005d33fc8258210706c7567ba923830e30d03821064737472ba925f06e30d0506007801fa00f40430f8276f2230500a",
+            "a121bef2e0508210706c7567831eb17080185004cb0526cf1658fa0219f400cb6917cb1f5260cb3f20c98040fb000600",
+            "8a5004810108f45930ed44d0810140d720c801cf16f400c9ed540172b08e23821064737472831eb17080185005cb0550",
+            "03cf1623fa0213cb6acb1fcb3fc98040fb00925f03e2020120080f020120090e0201580a0b003db29dfb513420405035",
+            "c87d010c00b23281f2fff274006040423d029be84c600201200c0d0019adce76a26840206b90eb85ffc00019af1df6a2",
+            "6840106b90eb858fc00011b8c97ed44d0d70b1f80059bd242b6f6a2684080a06b90fa0218470d4080847a4937d29910c",
+            "e6903e9ff9837812801b7810148987159f318404f8f28308d71820d31fd31fd31f02f823bbf264ed44d0d31fd31fd3ff",
+            "f404d15143baf2a15151baf2a205f901541064f910f2a3f80024a4c8cb1f5240cb1f5230cbff5210f400c9ed54f80f01",
+            "d30721c0009f6c519320d74a96d307d402fb00e830e021c001e30021c002e30001c0039130e30d03a4c8cb1f12cb1fcb",
+            "ff11121314006ed207fa00d4d422f90005c8ca0715cbffc9d077748018c8cb05cb0222cf165005fa0214cb6b12ccccc9",
+            "73fb00c84014810108f451f2a7020070810108d718fa00d33fc8542047810108f451f2a782106e6f746570748018c8cb",
+            "05cb025006cf165004fa0214cb6a12cb1fcb3fc973fb0002006c810108d718fa00d33f305224810108f459f2a7821064",
+            "73747270748018c8cb05cb025005cf165003fa0213cb6acb1f12cb3fc973fb00000af400c9ed5400510000000029a9a3",
+            "176e7a1cdd29b0b78fd13af4c5598feff4ef2a97166e3ca6f2e4fbfccd80505bf140007a62001b5555b7ec6009306e21",
+            "011a40f7c692e5b12217643cf572d2eb582f0fc46b1122cb417800000000000000000000000000000000000068656c6c",
+            "6fa3ce7c6d",
+        ));
+        assert_eq!(deploy.tx_hash, "0xae031683cad36a630e3dd36174470f35b70227ff76f2494185c93ca277f4c64b");
+
+        let transfer = sign_v4r2(&[5u8; 32], 7, 1_700_000_600, &[
+            comment("UQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIpOq", 20_000_000, false, "second"),
+        ], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_eq!(hex::encode(&transfer.raw_bytes), concat!(
+            "b5ee9c724101020100b40001e18801880e08f9a4479820da95d4ddb36b6166301feeb846cb6c1bd0e6e4ffbe98706c04",
+            "37c6a6872d2dfb0b4da36e4ffc277af623ee6dda1fcdae1c9547cabfcd28f276395d109aa73acfe680124d44bab81795",
+            "0c163cadb6116b7d9953967411dd48214d4d18bb2a9f9ac000000038001c01007c42001b5555b7ec6009306e21011a40",
+            "f7c692e5b12217643cf572d2eb582f0fc46b11200989680000000000000000000000000000000000007365636f6e64aa",
+            "51e303",
+        ));
+        assert_eq!(transfer.tx_hash, "0xcb9db12003ca2cd891e9f27ae0944e87911957f67e1310076ee6ad49920905fc");
+    }
+
+    #[test]
+    fn test_v5r1_known_external_messages() {
+        // Same transfers from the v5r1 wallet of key [5; 32]; hashes match tonlib-core 0.26.11
+        let comment = |to: &str, amount, bounce, text| TonMessage {
+            destination: TonAddress::parse(to).unwrap(),
+            amount_nanoton: amount,
+            bounce,
+            state_init: None,
+            body: Some(text_comment(text).unwrap()),
+        };
+        let deploy = sign_v5r1(&[5u8; 32], false, 0, u32::MAX, &[
+            comment("EQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIs5v", 1_500_000_000, true, "hello"),
+        ], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_eq!(hex::encode(&deploy.raw_bytes), concat!(
+            "b5ee9c724102190100036f0003e78800ffa42b481703b55bde11a655d9083ee6f87bb25211a1724639ee0b02b132212c",
+            "118e6d2cedcfffffe23fffffffe000000017d6f57bfca2dbe04d03c0821b127ef2267400a8ef5ab945424ac227daea1e",
+            "f35d3c24586091ee63a7c41226ddd9ebddb87b7f5f48ee353075f5f9cac03b21c0740115160114ff00f4a413f4bcf2c8",
+            "0b02020120030e020148040502dcd020d749c120915b8f6320d70b1f2082106578746ebd21821073696e74bdb0925f03",
+            "e082106578746eba8eb48020d72101d074d721fa4030fa44f828fa443058bd915be0ed44d0810141d721f4058307f40e",
+            "6fa1319130e18040d721707fdb3ce03120d749810280b99130e070e21110020120060d020120070a02016e08090019ad",
+            "ce76a2684020eb90eb85ffc00019af1df6a2684010eb90eb858fc00201480b0c0017b325fb51341c75c875c2c7e00011",
+            "b262fb513435c280200019be5f0f6a2684080a0eb90fa02c0102f20f011e20d70b1f82107369676ebaf2e08a7f1001e6",
+            "8ef0eda2edfb218308d722028308d723208020d721d31fd31fd31fed44d0d200d31f20d31fd3ffd70a000af90140ccf9",
+            "109a28945f0adb31e1f2c087df02b35007b0f2d0845125baf2e0855036baf2e086f823bbf2d0882292f800de01a47fc8",
+            "ca00cb1f01cf16c9ed542092f80fde70db3cd81103f6eda2edfb02f404216e926c218e4c0221d73930709421c700b38e",
+            "2d01d72820761e436c20d749c008f2e09320d74ac002f2e09320d71d06c712c2005230b0f2d089d74cd7393001a4e86c",
+            "128407bbf2e093d74ac000f2e093ed55e2d20001c000915be0ebd72c08142091709601d72c081c12e25210b1e30f20d7",
+            "4a121314009601fa4001fa44f828fa443058baf2e091ed44d0810141d718f405049d7fc8ca0040048307f453f2e08b8e",
+            "14038307f45bf2e08c22d70a00216e01b3b0f2d090e2c85003cf1612f400c9ed54007230d72c08248e2d21f2e092d200",
+            "ed44d0d2005113baf2d08f54503091319c01810140d721d70a00f2e08ee2c8ca0058cf16c9ed5493f2c08de20010935b",
+            "db31e1d74cd00051800000003fffff88b73d0e6e94d85bc7e89d7a62acc7f7fa77954b8b371e5379727dfe66c0282df8",
+            "a0020a0ec3c86d0317180000007a62001b5555b7ec6009306e21011a40f7c692e5b12217643cf572d2eb582f0fc46b11",
+            "22cb417800000000000000000000000000000000000068656c6c6fcd614cee",
+        ));
+        assert_eq!(deploy.tx_hash, "0xedbec27008a57b2c65dd550577bfca8831490d488f696a42f9220d87eee0f939");
+
+        let transfer = sign_for_version(WalletVersion::V5R1, &[5u8; 32], false, 7, 1_700_000_600, &[
+            comment("UQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIpOq", 20_000_000, false, "second"),
+        ], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_eq!(hex::encode(&transfer.raw_bytes), concat!(
+            "b5ee9c724101040100c10001e58800ffa42b481703b55bde11a655d9083ee6f87bb25211a1724639ee0b02b132212c03",
+            "9b4b3b73fffff88b2a9f9ac00000003df142f5ca4b75a43dee3dd60b7850e14acacea13479e8c9bdcd10a7f79ff619b6",
+            "d05125151102bb29663e20d9917dd798e1f44242bf18bac930a2e044cfb34c0501020a0ec3c86d0302030000007c4200",
+            "1b5555b7ec6009306e21011a40f7c692e5b12217643cf572d2eb582f0fc46b1120098968000000000000000000000000",
+            "0000000000007365636f6e64795cb688",
+        ));
+        assert_eq!(transfer.tx_hash, "0xf2898b4590c64ce9b91244ff7d0f88339bdc78581e8ed7cbd5e94c8a940d4f51");
+
+        // The testnet wallet has another wallet id, so another address and signature
+        let testnet = sign_v5r1(&[5u8; 32], true, 7, 1_700_000_600, &[
+            comment("UQA2qqtv2MASYNxCAjSB740ly2JELsh56uWl1rBeH4jWIpOq", 20_000_000, false, "second"),
+        ], SEND_MODE_PAY_FEES_SEPARATELY).unwrap();
+        assert_ne!(testnet.tx_hash, transfer.tx_hash);
+    }
+
+    #[test]
+    fn test_text_comment() {
+        let short = text_comment("Invoice 7").unwrap();
+        assert_eq!(hex::encode(short.data()), "00000000496e766f6963652037");
+        assert!(short.refs().is_empty());
+
+        let long = "x".repeat(COMMENT_HEAD_BYTES + COMMENT_TAIL_BYTES + 1);
+        let cell = text_comment(&long).unwrap();
+        assert_eq!(cell.bit_len(), 32 + COMMENT_HEAD_BYTES * 8);
+        let second = &cell.refs()[0];
+        assert_eq!(second.bit_len(), COMMENT_TAIL_BYTES * 8);
+        assert_eq!(second.refs()[0].bit_len(), 8);
+        assert_eq!(text_comment("").unwrap().bit_len(), 32);
+    }
+
+    #[test]
+    fn test_signing_message_limits() {
+        let message = TonMessage {
+            destination: TonAddress { workchain: 0, hash: [1; 32], bounceable: true, testnet: false },
+            amount_nanoton: 1,
+            bounce: true,
+            state_init: None,
+            body: Some(Cell::empty()),
+        };
+        assert!(v4r2_signing_message(WALLET_V4R2_ID, 1, 0, &[], 3).is_err());
+        assert!(v4r2_signing_message(WALLET_V4R2_ID, 1, 0, &vec![message.clone(); 5], 3).is_err());
+        assert_eq!(v4r2_signing_message(WALLET_V4R2_ID, 1, 0, &vec![message; 4], 3).unwrap().refs().len(), 4);
+    }
+
+    #[test]
+    fn test_v5r1_signed_body() {
+        use ed25519_dalek::Verifier;
+
+        let message = |n: u8| TonMessage {
+            destination: TonAddress { workchain: 0, hash: [n; 32], bounceable: true, testnet: false },
+            amount_nanoton: n as u64 * 1_000,
+            bounce: true,
+            state_init: None,
+            body: None,
+        };
+        let wallet_id = ton_chain::wallet_v5r1_id(false, 0, 0);
+        let request = v5r1_signing_message(wallet_id, 3, 1_700_000_060, &[message(1), message(2)], 3).unwrap();
+        let mut r = request.parser();
+        assert_eq!(r.load_uint(32).unwrap(), W5_SIGNED_EXTERNAL_OP as u64);
+        assert_eq!(r.load_uint(32).unwrap(), 2147483409);
+        assert_eq!(r.load_uint(32).unwrap(), 1_700_000_060);
+        assert_eq!(r.load_uint(32).unwrap(), 3);
+
+        // The last message is the outermost node of the out-action list
+        let list = r.load_maybe_ref().unwrap().unwrap();
+        assert!(!r.load_bit().unwrap());
+        assert_eq!(r.remaining_bits(), 0);
+        let mut node = list.parser();
+        let prev = node.load_ref().unwrap();
+        assert_eq!(node.load_uint(32).unwrap(), ACTION_SEND_MSG as u64);
+        assert_eq!(node.load_uint(8).unwrap(), 3);
+        assert_eq!(node.load_ref().unwrap(), &internal_message(&message(2)).unwrap());
+        assert_eq!(*prev.refs()[0], Cell::empty());
+        assert_eq!(*prev.refs()[1], internal_message(&message(1)).unwrap());
+
+        // Signature over the request hash, appended after the request
+        let signing_key = SigningKey::from_bytes(&[5u8; 32]);
+        let body = v5r1_signed_body(&request, &signing_key).unwrap();
+        assert_eq!(body.bit_len(), request.bit_len() + 512);
+        let mut b = body.parser();
+        let mut unsigned = CellBuilder::new();
+        for _ in 0..request.bit_len() {
+            unsigned.store_bit(b.load_bit().unwrap()).unwrap();
+        }
+        unsigned.store_ref(body.refs()[0].clone()).unwrap();
+        assert_eq!(unsigned.build(), request);
+        let signature: [u8; 64] = b.load_bytes(64).unwrap().try_into().unwrap();
+        assert!(signing_key.verifying_key().verify(&request.hash(), &ed25519_dalek::Signature::from_bytes(&signature)).is_ok());
+
+        assert!(v5r1_signing_message(wallet_id, 3, 0, &[], 3).is_err());
+        assert!(v5r1_signing_message(wallet_id, 3, 0, &[message(1)], 1).is_err());
     }
 }
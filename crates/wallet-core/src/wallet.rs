@@ -20,7 +20,7 @@ use zeroize::Zeroize;
 use crate::bip39_utils;
 use crate::chains::evm::derive_evm_address;
 use crate::chains::solana::derive_solana_address;
-use crate::chains::ton::derive_ton_address;
+use crate::chains::ton::{derive_ton_address, derive_ton_address_for_version, WalletVersion};
 use crate::chains::cosmos::derive_cosmos_address;
 use crate::chains::bitcoin::derive_bitcoin_address_for_network;
 use crate::chains::litecoin::derive_litecoin_address_for_network;
@@ -83,6 +83,12 @@ pub struct WalletEntry {
     pub created_at: u64,
 }
 
+impl Default for WalletStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WalletStore {
     pub fn new() -> Self {
         Self {
@@ -283,6 +289,11 @@ pub fn derive_addresses_filtered(
     // TON
     if is_enabled("ton") {
         addresses.insert("ton".to_string(), derive_ton_address(seed)?);
+        // Same key's v5r1 wallet, for the wallet version chooser
+        addresses.insert(
+            WalletVersion::V5R1.address_key().to_string(),
+            derive_ton_address_for_version(seed, WalletVersion::V5R1, testnet)?,
+        );
     }
 
     // Cosmos chains
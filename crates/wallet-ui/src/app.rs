@@ -17,10 +17,16 @@ use crate::pages::history::HistoryPage;
 use crate::pages::approve::ApprovePage;
 use crate::pages::walletconnect::WalletConnectPage;
 use crate::pages::wc_proposal::WcProposalPage;
+use crate::pages::ton_connect::TonConnectPage;
 use crate::pages::approvals::ApprovalsPage;
 use crate::pages::address_book::AddressBookPage;
 use crate::pages::nft::NftPage;
 use crate::pages::swap::SwapPage;
+use crate::pages::networks::NetworksPage;
+use crate::pages::safe::SafePage;
+use crate::pages::tokens::TokensPage;
+use crate::pages::verify::VerifyPage;
+use crate::pages::staking::StakingPage;
 use crate::components::navbar::BottomNav;
 use crate::components::top_nav::TopNav;
 use crate::components::chain_sidebar::ChainSidebar;
@@ -283,10 +289,16 @@ fn render_page(page: AppPage) -> impl IntoView {
         AppPage::DappApproval => view! { <ApprovePage /> }.into_any(),
         AppPage::WalletConnect => view! { <WalletConnectPage /> }.into_any(),
         AppPage::WcProposal => view! { <WcProposalPage /> }.into_any(),
+        AppPage::TonConnect => view! { <TonConnectPage /> }.into_any(),
         AppPage::Approvals => view! { <ApprovalsPage /> }.into_any(),
         AppPage::AddressBook => view! { <AddressBookPage /> }.into_any(),
         AppPage::Nft => view! { <NftPage /> }.into_any(),
         AppPage::Swap => view! { <SwapPage /> }.into_any(),
+        AppPage::Networks => view! { <NetworksPage /> }.into_any(),
+        AppPage::Safe => view! { <SafePage /> }.into_any(),
+        AppPage::Tokens => view! { <TokensPage /> }.into_any(),
+        AppPage::Verify => view! { <VerifyPage /> }.into_any(),
+        AppPage::Staking => view! { <StakingPage /> }.into_any(),
     }
 }
 
@@ -366,6 +378,7 @@ fn SettingsPanel() -> impl IntoView {
                 </select>
             </div>
             <crate::theme::ThemeSelector />
+            <crate::components::ton_wallet_version::TonWalletVersionSelector />
             <div class="card">
                 <p class="text-sm text-muted mb-2">{move || t("settings.wallet_version")}</p>
                 <p>"v0.5.0 - Rusby (Rust + Leptos)"</p>
@@ -464,6 +477,15 @@ fn SettingsPanel() -> impl IntoView {
                     {move || t("settings.manage_wc")}
                 </button>
             </div>
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("settings.ton_connect")}</p>
+                <button
+                    class="btn btn-primary btn-block"
+                    on:click=move |_| set_page.set(AppPage::TonConnect)
+                >
+                    {move || t("settings.manage_ton_connect")}
+                </button>
+            </div>
             // Testnet Toggle
             <div class="card">
                 <p class="text-sm text-muted mb-2">{move || t("settings.testnet_toggle")}</p>
@@ -488,6 +510,36 @@ fn SettingsPanel() -> impl IntoView {
                     }}
                 </div>
             </div>
+            // Custom EVM networks
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("networks.title")}</p>
+                <button
+                    class="btn btn-primary btn-block"
+                    on:click=move |_| set_page.set(AppPage::Networks)
+                >
+                    {move || t("networks.manage")}
+                </button>
+            </div>
+            // Safe multisig accounts
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("safe.title")}</p>
+                <button
+                    class="btn btn-primary btn-block"
+                    on:click=move |_| set_page.set(AppPage::Safe)
+                >
+                    {move || t("safe.manage")}
+                </button>
+            </div>
+            // Signature verification
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("verify.title")}</p>
+                <button
+                    class="btn btn-primary btn-block"
+                    on:click=move |_| set_page.set(AppPage::Verify)
+                >
+                    {move || t("verify.open")}
+                </button>
+            </div>
             // Address Book
             <div class="card">
                 <p class="text-sm text-muted mb-2">{move || t("addressbook.title")}</p>
@@ -0,0 +1,118 @@
+// Rusby Wallet — Simulated asset changes (balance deltas, approvals, NFTs)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::tokens::erc20;
+use wallet_core::tx::evm_simulate::{ApprovalChange, SimulationOutcome};
+
+use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::i18n::t;
+
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..8], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
+}
+
+fn signed(delta: i128, decimals: Option<u8>) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    let abs = delta.unsigned_abs();
+    match decimals {
+        Some(d) => format!("{}{}", sign, erc20::format_token_amount(abs, d)),
+        None => format!("{}{}", sign, abs),
+    }
+}
+
+fn delta_color(delta: i128) -> &'static str {
+    if delta < 0 { "var(--danger, #f44336)" } else { "var(--success, #4caf50)" }
+}
+
+/// Result of simulating the pending transaction from the user's account.
+/// `chain` selects the known-token list used to label ERC-20 deltas.
+#[component]
+pub fn AssetChangesCard(outcome: SimulationOutcome, chain: String, ticker: String) -> impl IntoView {
+    if !outcome.success {
+        let reason = outcome.revert_reason.unwrap_or_else(|| t("send.tx_would_fail"));
+        return view! {
+            <SecurityWarning severity=Severity::High title=t("sim.would_fail") message=reason dismissable=false />
+        }.into_any();
+    }
+    let Some(changes) = outcome.changes else {
+        return view! { <p class="text-sm text-muted mb-2">{t("sim.unavailable")}</p> }.into_any();
+    };
+    if changes.is_empty() {
+        return view! { <p class="text-sm text-muted mb-2">{t("sim.no_changes")}</p> }.into_any();
+    }
+
+    let tokens = crate::state::tokens_for_chain(&chain);
+    let label = |address: &str| tokens.iter()
+        .find(|tk| tk.address.eq_ignore_ascii_case(address))
+        .map(|tk| (tk.symbol.clone(), Some(tk.decimals)))
+        .unwrap_or_else(|| (short(address), None));
+
+    let mut rows: Vec<(String, String, &'static str)> = Vec::new();
+    if changes.native != 0 {
+        rows.push((ticker, signed(changes.native, Some(18)), delta_color(changes.native)));
+    }
+    for delta in &changes.tokens {
+        let (symbol, decimals) = label(&delta.token);
+        rows.push((symbol, signed(delta.delta, decimals), delta_color(delta.delta)));
+    }
+    for nft in &changes.nfts {
+        let amount = if nft.incoming { nft.amount as i128 } else { -(nft.amount.min(i128::MAX as u128) as i128) };
+        let name = format!("{} #{}", label(&nft.collection).0, nft.token_id);
+        rows.push((name, signed(amount, None), delta_color(amount)));
+    }
+
+    let mut risky = false;
+    let approval_rows: Vec<(String, String)> = changes.approvals.iter().map(|approval| match approval {
+        ApprovalChange::Token { token, spender, amount } => {
+            let (symbol, decimals) = label(token);
+            let value = match (amount, decimals) {
+                (None, _) => {
+                    risky = true;
+                    t("permit.unlimited")
+                }
+                (Some(a), Some(d)) => erc20::format_token_amount(*a, d),
+                (Some(a), None) => a.to_string(),
+            };
+            (format!("{} → {}", symbol, short(spender)), value)
+        }
+        ApprovalChange::Nft { collection, spender, token_id } => {
+            (format!("{} #{} → {}", label(collection).0, token_id, short(spender)), "NFT".into())
+        }
+        ApprovalChange::All { collection, operator, approved } => {
+            risky |= *approved;
+            let value = if *approved { t("sim.all_items") } else { t("sim.revoked") };
+            (format!("{} → {}", label(collection).0, short(operator)), value)
+        }
+    }).collect();
+
+    view! {
+        {risky.then(|| view! {
+            <SecurityWarning severity=Severity::High title=t("sim.risky_approval") message=t("sim.risky_approval_hint") dismissable=false />
+        })}
+        <div class="card text-sm">
+            <p class="text-muted mb-2">{t("sim.title")}</p>
+            {rows.into_iter().map(|(asset, amount, color)| view! {
+                <div class="flex justify-between mb-2" style="gap: 8px;">
+                    <span style="word-break: break-all;">{asset}</span>
+                    <span style=format!("font-weight: bold; color: {};", color)>{amount}</span>
+                </div>
+            }).collect::<Vec<_>>()}
+            {(!approval_rows.is_empty()).then(|| view! {
+                <p class="text-muted mb-2">{t("sim.approvals")}</p>
+            })}
+            {approval_rows.into_iter().map(|(target, value)| view! {
+                <div class="flex justify-between mb-2" style="gap: 8px;">
+                    <span style="word-break: break-all;">{target}</span>
+                    <span style="font-weight: bold; text-align: right;">{value}</span>
+                </div>
+            }).collect::<Vec<_>>()}
+            <p class="text-muted" style="font-size: 11px;">{t("sim.gas_excluded")}</p>
+        </div>
+    }.into_any()
+}
@@ -0,0 +1,231 @@
+// Rusby Wallet — Fee tier selectors (EVM EIP-1559 with custom fees, Solana priority fees)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::tx::evm_fees::{self, Eip1559Fees, FeeEstimate, FeeTier};
+use wallet_core::tx::solana_fees::PriorityFeeEstimate;
+
+use crate::i18n::t;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FeeChoice {
+    Tier(FeeTier),
+    Custom,
+}
+
+fn tier_label(tier: FeeTier) -> String {
+    match tier {
+        FeeTier::Slow => t("fee.slow"),
+        FeeTier::Normal => t("fee.normal"),
+        FeeTier::Fast => t("fee.fast"),
+    }
+}
+
+/// Lets the user pick a fee tier or enter custom EIP-1559 fees in Gwei.
+/// Writes the selected fees to `set_fees` (`None` while loading or invalid).
+#[component]
+pub fn EvmFeeSelector(
+    estimate: ReadSignal<Option<FeeEstimate>>,
+    gas_limit: ReadSignal<u64>,
+    ticker: String,
+    set_fees: WriteSignal<Option<Eip1559Fees>>,
+) -> impl IntoView {
+    let (choice, set_choice) = signal(FeeChoice::Tier(FeeTier::Normal));
+    let (custom_max, set_custom_max) = signal(String::new());
+    let (custom_tip, set_custom_tip) = signal(String::new());
+    let (error, set_error) = signal::<Option<String>>(None);
+
+    Effect::new(move |_| {
+        let selected = match choice.get() {
+            FeeChoice::Tier(tier) => {
+                set_error.set(None);
+                estimate.get().map(|e| e.tier(tier))
+            }
+            FeeChoice::Custom => {
+                let parsed = evm_fees::parse_gwei(&custom_max.get()).and_then(|max_fee| {
+                    let fees = Eip1559Fees {
+                        max_fee_per_gas: max_fee,
+                        max_priority_fee_per_gas: evm_fees::parse_gwei(&custom_tip.get())?,
+                    };
+                    fees.validate()?;
+                    Ok(fees)
+                });
+                match parsed {
+                    Ok(fees) => {
+                        set_error.set(None);
+                        Some(fees)
+                    }
+                    Err(e) => {
+                        set_error.set(Some(e));
+                        None
+                    }
+                }
+            }
+        };
+        set_fees.set(selected);
+    });
+
+    let select_custom = move |_| {
+        // Pre-fill with the normal tier so the user only tweaks what they need
+        if let Some(normal) = estimate.get_untracked().map(|e| e.tier(FeeTier::Normal)) {
+            set_custom_max.set(evm_fees::format_gwei(normal.max_fee_per_gas));
+            set_custom_tip.set(evm_fees::format_gwei(normal.max_priority_fee_per_gas));
+        }
+        set_choice.set(FeeChoice::Custom);
+    };
+
+    let cost_line = move || {
+        let est = estimate.get()?;
+        let fees = match choice.get() {
+            FeeChoice::Tier(tier) => est.tier(tier),
+            FeeChoice::Custom => {
+                let max_fee = evm_fees::parse_gwei(&custom_max.get()).ok()?;
+                let tip = evm_fees::parse_gwei(&custom_tip.get()).ok()?;
+                Eip1559Fees { max_fee_per_gas: max_fee, max_priority_fee_per_gas: tip }
+            }
+        };
+        let gas = gas_limit.get();
+        Some(format!(
+            "~{} ({} {}) {}",
+            evm_fees::format_fee(fees.expected_cost(gas, est.base_fee_per_gas)),
+            t("fee.max"),
+            evm_fees::format_fee(fees.max_cost(gas)),
+            ticker.clone(),
+        ))
+    };
+
+    let input_style = "width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border);";
+
+    view! {
+        <div class="card text-sm">
+            <div class="flex justify-between items-center mb-2">
+                <span class="text-muted">{move || t("fee.title")}</span>
+                <span class="text-muted" style="font-size: 11px;">
+                    {move || format!("{} {}", t("fee.gas_limit"), gas_limit.get())}
+                </span>
+            </div>
+            {move || {
+                if estimate.get().is_none() {
+                    return view! { <p class="text-muted">{t("fee.loading")}</p> }.into_any();
+                }
+                view! {
+                    <div style="display: flex; gap: 4px;">
+                        {FeeTier::all().into_iter().map(|tier| {
+                            let is_active = move || choice.get() == FeeChoice::Tier(tier);
+                            let gwei = move || estimate.get()
+                                .map(|e| evm_fees::format_gwei(e.tier(tier).max_priority_fee_per_gas))
+                                .unwrap_or_default();
+                            view! {
+                                <button
+                                    class="btn btn-sm"
+                                    class:btn-primary=is_active
+                                    class:btn-secondary=move || !is_active()
+                                    style="flex: 1; display: flex; flex-direction: column; align-items: center;"
+                                    on:click=move |_| set_choice.set(FeeChoice::Tier(tier))
+                                >
+                                    <span>{tier_label(tier)}</span>
+                                    <span style="font-size: 10px; opacity: 0.8;">{move || format!("+{} Gwei", gwei())}</span>
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()}
+                        <button
+                            class="btn btn-sm"
+                            class:btn-primary=move || choice.get() == FeeChoice::Custom
+                            class:btn-secondary=move || choice.get() != FeeChoice::Custom
+                            style="flex: 1;"
+                            on:click=select_custom
+                        >
+                            {t("fee.custom")}
+                        </button>
+                    </div>
+                }.into_any()
+            }}
+            {move || {
+                if choice.get() != FeeChoice::Custom {
+                    return None;
+                }
+                Some(view! {
+                    <div style="display: flex; gap: 8px; margin-top: 8px;">
+                        <div style="flex: 1;">
+                            <label class="text-muted" style="font-size: 11px;">{t("fee.max_fee")}</label>
+                            <input
+                                type="text"
+                                style=input_style
+                                prop:value=move || custom_max.get()
+                                on:input=move |ev| set_custom_max.set(event_target_value(&ev))
+                            />
+                        </div>
+                        <div style="flex: 1;">
+                            <label class="text-muted" style="font-size: 11px;">{t("fee.priority_fee")}</label>
+                            <input
+                                type="text"
+                                style=input_style
+                                prop:value=move || custom_tip.get()
+                                on:input=move |ev| set_custom_tip.set(event_target_value(&ev))
+                            />
+                        </div>
+                    </div>
+                })
+            }}
+            {move || error.get().map(|e| view! {
+                <p style="color: var(--danger, #f44336); margin-top: 6px;">{e}</p>
+            })}
+            {move || cost_line().map(|line| view! {
+                <div class="flex justify-between mt-2">
+                    <span class="text-muted">{t("fee.estimated")}</span>
+                    <span>{line}</span>
+                </div>
+            })}
+        </div>
+    }
+}
+
+/// Lets the user pick a Solana priority fee tier. Writes the compute unit price
+/// (micro-lamports) to `set_price`; `None` while loading.
+#[component]
+pub fn SolanaFeeSelector(
+    estimate: ReadSignal<Option<PriorityFeeEstimate>>,
+    set_price: WriteSignal<Option<u64>>,
+) -> impl IntoView {
+    let (choice, set_choice) = signal(FeeTier::Normal);
+
+    Effect::new(move |_| {
+        let tier = choice.get();
+        set_price.set(estimate.get().map(|e| e.tier(tier)));
+    });
+
+    view! {
+        <div class="card text-sm">
+            <div class="flex justify-between items-center mb-2">
+                <span class="text-muted">{move || t("fee.solana_priority")}</span>
+                <span class="text-muted" style="font-size: 11px;">{move || t("fee.per_compute_unit")}</span>
+            </div>
+            {move || {
+                if estimate.get().is_none() {
+                    return view! { <p class="text-muted">{t("fee.loading")}</p> }.into_any();
+                }
+                view! {
+                    <div style="display: flex; gap: 4px;">
+                        {FeeTier::all().into_iter().map(|tier| {
+                            let is_active = move || choice.get() == tier;
+                            let price = move || estimate.get().map(|e| e.tier(tier)).unwrap_or_default();
+                            view! {
+                                <button
+                                    class="btn btn-sm"
+                                    class:btn-primary=is_active
+                                    class:btn-secondary=move || !is_active()
+                                    style="flex: 1; display: flex; flex-direction: column; align-items: center;"
+                                    on:click=move |_| set_choice.set(tier)
+                                >
+                                    <span>{tier_label(tier)}</span>
+                                    <span style="font-size: 10px; opacity: 0.8;">{move || format!("{} µL", price())}</span>
+                                </button>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -76,7 +76,6 @@ pub fn SecurityWarning(
                             </p>
                         </div>
                         {if dismissable {
-                            let on_dismiss = on_dismiss.clone();
                             Some(view! {
                                 <button
                                     style="background: none; border: none; cursor: pointer; color: var(--text-muted); font-size: 1rem; padding: 0;"
@@ -0,0 +1,134 @@
+// Rusby Wallet — Decoded Solana dApp transaction (instructions, fee payer, risks)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::tokens::erc20::format_token_amount;
+use wallet_core::tx::solana_dapp::{SolanaAction, SolanaRisk, TransactionReview};
+
+use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::i18n::t;
+
+fn short(key: &[u8; 32]) -> String {
+    let addr = bs58::encode(key).into_string();
+    format!("{}...{}", &addr[..6], &addr[addr.len() - 4..])
+}
+
+fn sol(lamports: u64) -> String {
+    format!("{} SOL", format_token_amount(lamports as u128, 9))
+}
+
+/// One signing request's transaction. `title` distinguishes the entries of a
+/// signAllTransactions batch; `our_key` is the wallet's Solana public key.
+#[component]
+pub fn SolanaReviewCard(review: TransactionReview, our_key: [u8; 32], title: String) -> impl IntoView {
+    let tokens = crate::state::tokens_for_chain("solana");
+    let known = |mint: &[u8; 32]| {
+        let addr = bs58::encode(mint).into_string();
+        tokens.iter().find(|tk| tk.address == addr).cloned()
+    };
+    let mint_label = |mint: &[u8; 32]| known(mint).map(|tk| tk.symbol).unwrap_or_else(|| short(mint));
+    let token = |mint: &Option<[u8; 32]>, decimals: Option<u8>, amount: u64| -> String {
+        match (mint.as_ref().and_then(known), mint, decimals) {
+            (Some(tk), _, _) => format!("{} {}", format_token_amount(amount as u128, tk.decimals), tk.symbol),
+            (None, Some(m), Some(d)) => format!("{} {}", format_token_amount(amount as u128, d), short(m)),
+            _ => format!("{} {}", amount, t("solana_review.raw_units")),
+        }
+    };
+    let who = |key: &[u8; 32]| if key == &our_key { t("solana_review.you") } else { short(key) };
+
+    let actions: Vec<(String, String)> = review.actions.iter().map(|action| match action {
+        SolanaAction::SolTransfer { from, to, lamports } => (
+            t("solana_review.sol_transfer"),
+            format!("{} {} → {}", sol(*lamports), who(from), who(to)),
+        ),
+        SolanaAction::CreateAccount { funder, account, lamports, .. } => (
+            t("solana_review.create_account"),
+            format!("{} ({} {})", short(account), sol(*lamports), who(funder)),
+        ),
+        SolanaAction::Assign { account, owner_program } => (
+            t("solana_review.assign"),
+            format!("{} → {}", who(account), short(owner_program)),
+        ),
+        SolanaAction::TokenTransfer { authority, destination, mint, amount, decimals, .. } => (
+            t("solana_review.token_transfer"),
+            format!("{} {} → {}", token(mint, *decimals, *amount), who(authority), short(destination)),
+        ),
+        SolanaAction::TokenApprove { delegate, mint, amount, decimals, .. } => {
+            let value = if *amount == u64::MAX { t("permit.unlimited") } else { token(mint, *decimals, *amount) };
+            (t("solana_review.token_approve"), format!("{} → {}", value, short(delegate)))
+        }
+        SolanaAction::TokenSetAuthority { account, new_authority, .. } => (
+            t("solana_review.set_authority"),
+            format!("{} → {}", short(account), new_authority.map(|a| who(&a)).unwrap_or_else(|| t("solana_review.none"))),
+        ),
+        SolanaAction::TokenCloseAccount { account, destination, .. } => (
+            t("solana_review.close_account"),
+            format!("{} → {}", short(account), who(destination)),
+        ),
+        SolanaAction::CreateTokenAccount { owner, mint, .. } => (
+            t("solana_review.create_token_account"),
+            format!("{} / {}", mint_label(mint), who(owner)),
+        ),
+        SolanaAction::Memo(text) => (t("solana_review.memo"), text.clone()),
+        SolanaAction::ComputeUnitLimit(units) => (t("solana_review.compute_limit"), format!("{} CU", units)),
+        SolanaAction::ComputeUnitPrice(price) => (t("solana_review.priority_fee"), format!("{} µlamports/CU", price)),
+        SolanaAction::Other { program_id, program } => (
+            t("solana_review.program_call"),
+            program.map(str::to_string).unwrap_or_else(|| bs58::encode(program_id).into_string()),
+        ),
+    }).collect();
+
+    let mut warnings: Vec<(Severity, String, String)> = Vec::new();
+    if !review.we_sign {
+        warnings.push((Severity::High, t("solana_review.not_signer"), t("solana_review.not_signer_hint")));
+    }
+    for risk in &review.risks {
+        warnings.push(match risk {
+            SolanaRisk::AssignsWallet => (Severity::High, t("solana_review.risk_assign"), t("solana_review.risk_assign_hint")),
+            SolanaRisk::TransfersAuthority => (Severity::High, t("solana_review.risk_authority"), t("solana_review.risk_authority_hint")),
+            SolanaRisk::DelegatesTokens { unlimited } => {
+                let severity = if *unlimited { Severity::High } else { Severity::Medium };
+                (severity, t("solana_review.risk_delegate"), t("solana_review.risk_delegate_hint"))
+            }
+            SolanaRisk::ClosesAccount => (Severity::Medium, t("solana_review.risk_close"), t("solana_review.risk_close_hint")),
+            SolanaRisk::UnknownProgram(program) => (
+                Severity::Medium,
+                t("solana_review.risk_unknown_program"),
+                bs58::encode(program).into_string(),
+            ),
+        });
+    }
+
+    let fee = if review.we_pay_fee {
+        sol(review.network_fee_lamports())
+    } else {
+        format!("{} ({})", t("solana_review.sponsored"), short(&review.fee_payer))
+    };
+    let outgoing = review.outgoing_lamports(&our_key);
+
+    view! {
+        {warnings.into_iter().map(|(severity, title, message)| view! {
+            <SecurityWarning severity=severity title=title message=message dismissable=false />
+        }).collect::<Vec<_>>()}
+        <div class="card text-sm">
+            <p class="text-muted mb-2">{title}</p>
+            {actions.into_iter().map(|(label, detail)| view! {
+                <div class="mb-2">
+                    <p style="font-weight: bold;">{label}</p>
+                    <p style="word-break: break-all;">{detail}</p>
+                </div>
+            }).collect::<Vec<_>>()}
+            {(outgoing > 0).then(|| view! {
+                <div class="flex justify-between mb-2" style="gap: 8px;">
+                    <span class="text-muted">{t("solana_review.outgoing")}</span>
+                    <span style="font-weight: bold; color: var(--danger, #f44336);">{sol(outgoing)}</span>
+                </div>
+            })}
+            <div class="flex justify-between" style="gap: 8px;">
+                <span class="text-muted">{t("solana_review.network_fee")}</span>
+                <span style="text-align: right;">{fee}</span>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,85 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::chains::ton::WalletVersion;
+
+use crate::state::*;
+use crate::i18n::t;
+
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..8], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
+}
+
+fn label(version: WalletVersion) -> &'static str {
+    match version {
+        WalletVersion::V4R2 => "v4r2",
+        WalletVersion::V5R1 => "W5 (v5r1)",
+    }
+}
+
+/// Settings card choosing which TON wallet contract of the same key the app uses.
+/// Shows each version's address and balance.
+#[component]
+pub fn TonWalletVersionSelector() -> impl IntoView {
+    let wallet_state: ReadSignal<WalletState> = expect_context();
+    let set_wallet_state: WriteSignal<WalletState> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let (balances, set_balances) = signal::<Vec<(WalletVersion, u64)>>(Vec::new());
+
+    Effect::new(move |_| {
+        let testnet = testnet_mode.get();
+        let addresses = wallet_state.with_untracked(|s| s.addresses.clone());
+        if !addresses.contains_key("ton") { return; }
+        wasm_bindgen_futures::spawn_local(async move {
+            set_balances.set(crate::rpc::ton::get_version_balances(&addresses, testnet).await.unwrap_or_default());
+        });
+    });
+
+    let select = move |version: WalletVersion| {
+        let name = wallet_state.with_untracked(|s| s.wallet_name.clone());
+        save_ton_version(&name, version);
+        set_wallet_state.update(|s| {
+            s.ton_version = version;
+            s.balances.remove("ton");
+            s.token_balances.remove("ton");
+        });
+    };
+
+    move || {
+        wallet_state.with(|s| s.addresses.contains_key("ton")).then(|| view! {
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("settings.ton_wallet_version")}</p>
+                <p class="text-sm text-muted mb-2">{move || t("settings.ton_wallet_version_hint")}</p>
+                {WalletVersion::all().iter().map(|version| {
+                    let version = *version;
+                    let address = move || wallet_state.with(|s| s.addresses.get(version.address_key()).cloned().unwrap_or_default());
+                    let balance = move || balances.with(|b| {
+                        b.iter()
+                            .find(|(v, _)| *v == version)
+                            .map(|(_, nanoton)| format!("{} TON", crate::rpc::ton::format_nanoton(*nanoton)))
+                            .unwrap_or_else(|| "—".to_string())
+                    });
+                    view! {
+                        <button
+                            class="btn btn-secondary btn-block mb-2"
+                            style=move || format!(
+                                "display: flex; justify-content: space-between; border-color: {};",
+                                if wallet_state.with(|s| s.ton_version) == version { "var(--accent)" } else { "transparent" }
+                            )
+                            on:click=move |_| select(version)
+                        >
+                            <span>{label(version)}" · "{move || short(&address())}</span>
+                            <span class="text-sm">{balance}</span>
+                        </button>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+        })
+    }
+}
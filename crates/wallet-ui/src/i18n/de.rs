@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Wallet nicht gefunden"),
     ("approve.active_wallet_not_found", "Aktive Wallet nicht gefunden"),
     ("approve.seed_invalid", "Ungültiger Seed"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction über dApp wird noch nicht unterstützt \u{2014} nutze die Wallet direkt"),
     ("approve.method_unsupported", "Nicht unterstützte Methode:"),
     ("approve.error_parsing_wallet", "Fehler beim Parsen der Wallet:"),
     ("approve.error_parsing_params", "Fehler beim Parsen der Parameter:"),
     ("approve.missing_message_param", "Fehlender Nachrichtenparameter"),
     ("approve.invalid_hex", "Ungültiges Hexadezimal:"),
     ("approve.missing_typed_data", "Fehlende typisierte Daten"),
-    ("approve.missing_domain", "Fehlendes Feld domain"),
-    ("approve.missing_message_field", "Fehlendes Feld message"),
-    ("approve.missing_primary_type", "Fehlendes Feld primaryType"),
-    ("approve.missing_types", "Fehlendes Feld types"),
-    ("approve.type_not_found", "Typ nicht gefunden:"),
     ("wc.back", "Zurück"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "Konfiguration"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "Unbekannte dApp"),
     ("approvals.title", "Token-Genehmigungen"),
     ("approvals.management", "Genehmigungsverwaltung"),
-    ("approvals.management_desc", "Überprüfe und widerrufe ERC-20-Genehmigungen für DEX-Verträge. Unbegrenzte Genehmigungen können ein Sicherheitsrisiko darstellen."),
+    ("approvals.management_desc", "Freigaben werden aus den On-Chain-Approval-Events deiner Adresse ermittelt. Unbegrenzte Freigaben können ein Sicherheitsrisiko sein."),
     ("approvals.evm_only", "Token-Genehmigungen sind nur für EVM-Chains verfügbar."),
     ("approvals.scanning", "Genehmigungen werden gescannt..."),
-    ("approvals.no_approvals", "Keine aktiven Genehmigungen für bekannte Token gefunden."),
+    ("approvals.no_approvals", "Keine aktiven Freigaben gefunden."),
     ("approvals.no_rpc", "Keine RPC-URL verfügbar"),
     ("approvals.revoke", "Widerrufen"),
     ("approvals.revoke_error", "Fehler beim Kodieren des Widerrufs:"),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "AES-256-GCM Verschlüsselung"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600.000 Runden)"),
     ("security.local_only", "Schlüssel verlassen niemals Ihr Gerät"),
+    // EVM fees
+    ("fee.title", "Netzwerkgebühr"),
+    ("fee.slow", "Langsam"),
+    ("fee.normal", "Normal"),
+    ("fee.fast", "Schnell"),
+    ("fee.custom", "Eigene"),
+    ("fee.max_fee", "Max. Gebühr (Gwei)"),
+    ("fee.priority_fee", "Prioritätsgebühr (Gwei)"),
+    ("fee.gas_limit", "Gaslimit"),
+    ("fee.estimated", "Geschätzte Gebühr"),
+    ("fee.max", "max."),
+    ("fee.loading", "Gebühren werden geladen..."),
+    ("fee.unavailable", "Gebührendaten nicht verfügbar:"),
+    ("fee.invalid", "Wähle eine gültige Netzwerkgebühr"),
+    ("approve.unsupported_chain", "Die dApp hat ein nicht unterstütztes Netzwerk angefordert"),
+    ("approve.missing_chain", "Die dApp hat kein Netzwerk angegeben"),
+    // Pending EVM transactions
+    ("history.pending", "Ausstehend"),
+    ("history.pending_tx", "Ausstehend"),
+    ("history.sped_up", "Beschleunigt"),
+    ("history.cancelling", "Wird storniert"),
+    ("history.speed_up", "Beschleunigen"),
+    ("history.cancel_tx", "Stornieren"),
+    ("history.speed_up_hint", "Sendet dieselbe Transaktion mit höheren Gebühren erneut (mindestens +10 %)."),
+    ("history.cancel_hint", "Ersetzt die Transaktion durch eine 0-Wert-Überweisung an dich selbst. Funktioniert nur, wenn sie nicht vorher gemint wird."),
+    // Custom EVM networks
+    ("networks.title", "Eigene Netzwerke"),
+    ("networks.manage", "Netzwerke verwalten"),
+    ("networks.hint", "Füge jede EVM-kompatible Chain hinzu. Deine Ethereum-Adresse wird in allen EVM-Netzwerken verwendet."),
+    ("networks.add", "+ Hinzufügen"),
+    ("networks.chain_id", "Chain-ID"),
+    ("networks.name", "Netzwerkname"),
+    ("networks.ticker", "Währungssymbol"),
+    ("networks.rpc_url", "RPC-URLs"),
+    ("networks.explorer_url", "Block-Explorer (optional)"),
+    ("networks.save", "Netzwerk speichern"),
+    ("networks.delete", "Entfernen"),
+    ("networks.empty", "Noch keine eigenen Netzwerke"),
+    ("networks.invalid_chain_id", "Die Chain-ID muss eine positive Zahl sein"),
+    ("networks.checking_rpc", "RPC wird geprüft..."),
+    ("approve.add_network", "Netzwerk hinzufügen"),
+    ("approve.switch_network", "Netzwerk wechseln"),
+    ("approve.add_network_warning", "Ein bösartiges Netzwerk kann falsche Guthaben und Transaktionen anzeigen. Füge nur Netzwerke von vertrauenswürdigen Seiten hinzu."),
+    // ENS
+    ("ens.resolving", "ENS-Name wird aufgelöst..."),
+    ("ens.not_resolved", "Warte, bis der ENS-Name aufgelöst ist"),
+    ("approve.to", "An"),
+    // Safe multisig
+    ("safe.title", "Safe-Multisig"),
+    ("safe.manage", "Safes verwalten"),
+    ("safe.add", "+ Hinzufügen"),
+    ("safe.hint", "Beobachte einen Safe, den du mitverwaltest, um Transaktionen zu signieren, auszuführen und vorzuschlagen."),
+    ("safe.address", "Safe-Adresse"),
+    ("safe.name", "Name (optional)"),
+    ("safe.network", "Netzwerk"),
+    ("safe.save", "Safe hinzufügen"),
+    ("safe.empty", "Noch keine Safes"),
+    ("safe.checking", "Safe wird geprüft..."),
+    ("safe.already_added", "Dieser Safe ist bereits in der Liste"),
+    ("safe.not_a_safe", "Kein Safe in diesem Netzwerk:"),
+    ("safe.no_rpc", "Kein RPC für dieses Netzwerk"),
+    ("safe.info_error", "Safe konnte nicht gelesen werden:"),
+    ("safe.queue_error", "Warteschlange konnte nicht geladen werden:"),
+    ("safe.threshold", "Schwelle"),
+    ("safe.you_are_owner", "Diese Wallet ist Eigentümer"),
+    ("safe.not_owner", "Nur beobachten: diese Wallet ist kein Eigentümer"),
+    ("safe.queue", "Ausstehende Transaktionen"),
+    ("safe.queue_empty", "Nichts wartet auf Signaturen"),
+    ("safe.contract_call", "Vertragsaufruf"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: das Ziel führt Code mit voller Kontrolle über den Safe aus"),
+    ("safe.confirmations", "Signaturen"),
+    ("safe.sign", "Signieren"),
+    ("safe.execute", "Ausführen"),
+    ("safe.propose", "Neue Überweisung aus dem Safe"),
+    ("safe.propose_button", "Vorschlagen und signieren"),
+    ("safe.signed", "Signatur hinzugefügt"),
+    ("safe.proposed", "Vorgeschlagen mit Nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Token-Permit (Off-Chain-Freigabe)"),
+    ("permit.kind_permit2", "Permit2-Freigabe"),
+    ("permit.kind_permit2_transfer", "Permit2-Token-Übertragung"),
+    ("permit.kind_seaport", "Seaport-Angebot"),
+    ("permit.spender", "Spender"),
+    ("permit.expiry", "Läuft ab"),
+    ("permit.unlimited", "Unbegrenzt"),
+    ("permit.never", "Nie"),
+    ("permit.expired", "Bereits abgelaufen"),
+    ("permit.in", "in"),
+    ("permit.high_risk_title", "Diese Signatur kann deine Token abziehen"),
+    ("permit.review_hint", "Es wird keine Transaktion gesendet, aber nach der Signatur kann der Spender diese Token bewegen."),
+    ("permit.risk_unlimited", "Unbegrenzter Betrag."),
+    ("permit.risk_unknown_spender", "Der Spender ist kein bekannter Vertrag."),
+    ("permit.risk_far_expiry", "Sie bleibt sehr lange gültig."),
+    ("permit.risk_no_payment", "Du erhältst nichts für die angebotenen Objekte."),
+    // Transaction simulation
+    ("sim.title", "Geschätzte Saldoänderungen"),
+    ("sim.would_fail", "Diese Transaktion wird voraussichtlich fehlschlagen"),
+    ("sim.unavailable", "Änderungen nicht verfügbar: Dieser RPC unterstützt keine Simulation."),
+    ("sim.no_changes", "Keine Vermögensänderungen für dein Konto erkannt."),
+    ("sim.approvals", "Erteilte Freigaben"),
+    ("sim.all_items", "Alle Objekte"),
+    ("sim.revoked", "Widerrufen"),
+    ("sim.risky_approval", "Unbegrenzte Freigabe"),
+    ("sim.risky_approval_hint", "Der Spender kann diese Assets jederzeit bewegen, bis du die Freigabe widerrufst."),
+    ("sim.gas_excluded", "Netzwerkgebühr nicht enthalten."),
+    // NFT transfers
+    ("nft.send", "NFT senden"),
+    ("nft.review", "Übertragung prüfen"),
+    ("nft.simulating", "Übertragung wird simuliert..."),
+    ("nft.simulation_ok", "Simulation erfolgreich: Das NFT verlässt deine Wallet."),
+    // Custom tokens
+    ("tokens.title", "Token importieren"),
+    ("tokens.import", "+ Importieren"),
+    ("tokens.address", "Vertrags- / Mint-Adresse"),
+    ("tokens.lookup", "Abrufen"),
+    ("tokens.looking_up", "Token-Metadaten werden gelesen..."),
+    ("tokens.lookup_failed", "Token konnte nicht gelesen werden"),
+    ("tokens.already_listed", "Dieser Token ist bereits in der Standardliste"),
+    ("tokens.warning_title", "Adresse prüfen"),
+    ("tokens.warning", "Jeder kann einen Token mit beliebigem Namen und Symbol erstellen. Importiere nur Adressen aus offiziellen Quellen des Projekts."),
+    ("tokens.symbol", "Symbol"),
+    ("tokens.name", "Name"),
+    ("tokens.decimals", "Dezimalstellen"),
+    ("tokens.add", "Token hinzufügen"),
+    ("tokens.remove", "Entfernen"),
+    ("tokens.empty", "Keine importierten Token in diesem Netzwerk"),
+    ("tokens.unsupported_chain", "Dieses Netzwerk hat keinen vom Wallet unterstützten Token-Standard"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Konto-Delegation (EIP-7702)"),
+    ("approve.delegation_unknown", "Unbekanntes Delegationsziel"),
+    ("approve.delegation_unknown_hint", "Dieser Vertrag kontrolliert dein Konto und alle darin enthaltenen Assets. Er ist kein bekannter, geprüfter Delegat. Fahre nur fort, wenn du dieser dApp voll vertraust."),
+    ("approve.delegation_any_chain", "Delegation auf allen Chains gültig"),
+    ("approve.delegation_any_chain_hint", "Diese Autorisierung nutzt Chain-ID 0 und kann in jedem EVM-Netzwerk, in dem dein Konto existiert, erneut verwendet werden."),
+    ("approve.delegation_unknown_contract", "Unbekannter Vertrag"),
+    ("approve.delegation_revoke", "Aktuelle Delegation entfernen"),
+    ("approve.delegation_all_chains", "Alle Chains"),
+    ("approve.delegation_presigned", "Von einem anderen Konto signiert; mit dieser Transaktion weitergeleitet"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Anmeldeanfrage"),
+    ("siwe.address", "Konto"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Netzwerk"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Ausgestellt am"),
+    ("siwe.expires", "Läuft ab"),
+    ("siwe.not_before", "Gültig ab"),
+    ("siwe.request_id", "Anfrage-ID"),
+    ("siwe.resources", "Ressourcen"),
+    ("siwe.domain_mismatch", "Anmeldung für eine andere Website"),
+    ("siwe.domain_mismatch_hint", "Diese Nachricht meldet Sie bei {domain} an, wurde aber von {origin} angefordert. Wenn Sie sie signieren, kann {origin} auf Ihr {domain}-Konto zugreifen. Dies ist eine verbreitete Phishing-Methode."),
+    ("siwe.scheme_mismatch", "Protokoll stimmt nicht überein"),
+    ("siwe.scheme_mismatch_hint", "Die Nachricht nennt ein anderes Protokoll (http/https) als die anfragende Seite."),
+    ("siwe.address_mismatch", "Anderes Konto"),
+    ("siwe.address_mismatch_hint", "Die Nachricht gilt für eine Adresse, die nicht Ihr aktives Konto ist."),
+    ("siwe.chain_mismatch", "Anderes Netzwerk"),
+    ("siwe.chain_mismatch_hint", "Die Nachricht gilt für Chain {message}, die Website ist aber mit Chain {active} verbunden."),
+    ("siwe.invalid_nonce", "Schwache Nonce"),
+    ("siwe.invalid_nonce_hint", "Die Nonce sollte aus mindestens 8 zufälligen Buchstaben oder Ziffern bestehen. Eine vorhersehbare Nonce erlaubt das Wiederverwenden der Signatur."),
+    ("siwe.expired", "Anmeldeanfrage abgelaufen"),
+    ("siwe.expired_hint", "Die Ablaufzeit ist überschritten. Laden Sie die Website neu und melden Sie sich erneut an."),
+    ("siwe.not_yet_valid", "Anmeldeanfrage noch nicht gültig"),
+    ("siwe.not_yet_valid_hint", "Die Nachricht wird erst später gültig und kann daher verwendet werden, nachdem Sie die Website verlassen haben."),
+    ("siwe.invalid_time", "Ungültiges Datum"),
+    ("siwe.invalid_time_hint", "Ein Zeitstempel der Nachricht ist fehlerhaft, daher kann die Gültigkeit nicht geprüft werden."),
+    ("siwe.unbound", "Website nicht verifiziert"),
+    ("siwe.unbound_hint", "Diese Anfrage kam über WalletConnect, daher kann die Wallet nicht bestätigen, dass sie von der in der Nachricht genannten Website stammt."),
+    ("siwe.chain_unknown", "Netzwerk nicht geprüft"),
+    ("siwe.chain_unknown_hint", "Die Anfrage gibt nicht an, mit welchem Netzwerk die Seite verbunden ist, daher konnte die Chain der Nachricht nicht geprüft werden."),
+    ("siwe.malformed", "Fehlerhafte Anmeldenachricht"),
+    ("siwe.malformed_hint", "Dies sieht wie eine Sign-In-with-Ethereum-Nachricht aus, folgt aber nicht dem Standard ({error}), daher können Seite, Konto und Netzwerk, für die sie Zugriff gewährt, nicht geprüft werden. Seriöse Seiten senden keine fehlerhaften Anmeldenachrichten."),
+    // Signature verification tool
+    ("verify.title", "Signatur prüfen"),
+    ("verify.open", "Prüfwerkzeug öffnen"),
+    ("verify.hint", "Prüfen Sie, wer eine Nachricht signiert hat. Funktioniert mit normalen Konten und Smart-Contract-Wallets (ERC-1271), auch noch nicht deployten (ERC-6492)."),
+    ("verify.network", "Netzwerk"),
+    ("verify.signer", "Adresse des Unterzeichners"),
+    ("verify.message_type", "Nachrichtentyp"),
+    ("verify.kind_personal", "Nachricht (personal_sign)"),
+    ("verify.kind_typed", "Typisierte Daten (EIP-712 JSON)"),
+    ("verify.kind_hash", "Hash (32 Byte)"),
+    ("verify.message", "Nachricht"),
+    ("verify.signature", "Signatur"),
+    ("verify.check", "Prüfen"),
+    ("verify.checking", "Wird geprüft..."),
+    ("verify.valid", "Gültige Signatur"),
+    ("verify.invalid", "Ungültige Signatur"),
+    ("verify.method", "Geprüft mit"),
+    ("verify.recovered", "Tatsächlicher Unterzeichner"),
+    ("verify.no_rpc", "Kein RPC-Endpunkt für dieses Netzwerk"),
+    // Approval discovery
+    ("approvals.all_items", "Alle Objekte"),
+    ("approvals.scanned_to", "Verlauf bis Block {block} durchsucht"),
+    ("approvals.scan_more", "Ältere Blöcke durchsuchen"),
+    ("approvals.scan_error", "Log-Suche unterbrochen:"),
+    ("approvals.found", "{n} aktive Freigaben"),
+    ("approvals.select_all", "Alle auswählen"),
+    ("approvals.unknown_spender", "Unbekannter Vertrag"),
+    ("approvals.spender", "Spender"),
+    ("approvals.allowance", "Freigabe"),
+    ("approvals.revoke_selected", "Auswahl widerrufen ({n})"),
+    ("approvals.confirm_revoke", "{n} Freigaben widerrufen? Pro Freigabe wird eine Transaktion gesendet."),
+    ("approvals.revoked_count", "{n} Widerrufstransaktionen gesendet"),
+    // SPL token transfers
+    ("send.ata_rent", "inkl. Miete für das neue Token-Konto des Empfängers"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Solana-Wallet-Verbindung"),
+    ("approve.solana_sign_tx", "Solana-Transaktion signieren"),
+    ("approve.solana_sign_all", "Solana-Transaktionen signieren"),
+    ("approve.solana_send_tx", "Solana-Transaktion senden"),
+    ("approve.solana_sign_message", "Nachricht signieren (Solana)"),
+    ("approve.solana_sign_in", "Mit Solana anmelden"),
+    ("approve.solana_disguised_tx", "Diese Nachricht ist eine Transaktion"),
+    ("approve.solana_disguised_tx_hint", "Die Seite möchte Bytes signieren lassen, die eine Solana-Transaktion bilden. Eine Signatur würde diese Transaktion autorisieren, daher lehnt Rusby ab."),
+    ("approve.solana_tx", "Transaktion"),
+    ("approve.solana_tx_n", "Transaktion {n} von {count}"),
+    ("approve.solana_decode_failed", "Transaktion konnte nicht dekodiert werden"),
+    ("solana_review.raw_units", "Einheiten (unbekannter Token)"),
+    ("solana_review.you", "Sie"),
+    ("solana_review.sol_transfer", "SOL-Überweisung"),
+    ("solana_review.create_account", "Konto erstellen"),
+    ("solana_review.assign", "Konto einem Programm zuweisen"),
+    ("solana_review.token_transfer", "Token-Überweisung"),
+    ("solana_review.token_approve", "Token-Delegierung"),
+    ("solana_review.set_authority", "Autorität des Token-Kontos ändern"),
+    ("solana_review.none", "keine"),
+    ("solana_review.close_account", "Token-Konto schließen"),
+    ("solana_review.create_token_account", "Token-Konto erstellen"),
+    ("solana_review.memo", "Memo"),
+    ("solana_review.compute_limit", "Compute-Unit-Limit"),
+    ("solana_review.priority_fee", "Prioritätsgebühr"),
+    ("solana_review.program_call", "Programmaufruf"),
+    ("solana_review.not_signer", "Nicht Ihre Transaktion"),
+    ("solana_review.not_signer_hint", "Ihr Konto gehört nicht zu den Unterzeichnern dieser Transaktion, daher kann sie nicht signiert werden."),
+    ("solana_review.risk_assign", "Änderung des Kontoinhabers"),
+    ("solana_review.risk_assign_hint", "Ihr Wallet-Konto würde einem anderen Programm übergeben, das dann alle SOL bewegen könnte. Wallet-Drainer nutzen das."),
+    ("solana_review.risk_authority", "Übertragung der Token-Konto-Autorität"),
+    ("solana_review.risk_authority_hint", "Die Kontrolle über eines Ihrer Token-Konten ginge an eine andere Adresse über, samt allen Token, die es jetzt oder später hält."),
+    ("solana_review.risk_delegate", "Token-Ausgabefreigabe"),
+    ("solana_review.risk_delegate_hint", "Ein Delegierter dürfte Ihre Token später ohne erneute Nachfrage bewegen."),
+    ("solana_review.risk_close", "Token-Konto wird geschlossen"),
+    ("solana_review.risk_close_hint", "Eines Ihrer Token-Konten wird geschlossen und die Rent-Einlage geht an eine andere Adresse."),
+    ("solana_review.risk_unknown_program", "Unbekanntes Programm"),
+    ("solana_review.sponsored", "Bezahlt von"),
+    ("solana_review.outgoing", "Von Ihrem Konto gesendete SOL"),
+    ("solana_review.network_fee", "Netzwerkgebühr"),
+    // Solana priority fees
+    ("fee.solana_priority", "Prioritätsgebühr"),
+    ("fee.per_compute_unit", "Micro-Lamports pro Compute Unit"),
+    ("fee.compute_units", "Compute Units"),
+    // Native SOL staking
+    ("dashboard.stake", "Staken"),
+    ("staking.title", "SOL-Staking"),
+    ("staking.about", "Natives Staking"),
+    ("staking.about_desc", "Der Stake bleibt in Stake-Konten unter deiner Kontrolle. Neuer Stake wird in der nächsten Epoche aktiv; deaktivierter Stake kann nach Ende der Epoche abgehoben werden."),
+    ("staking.total_staked", "Gesamt gestaked"),
+    ("staking.epoch", "Epoche"),
+    ("staking.accounts", "Stake-Konten"),
+    ("staking.loading", "Stake-Konten werden geladen..."),
+    ("staking.no_accounts", "Noch keine Stake-Konten"),
+    ("staking.load_error", "Stake-Konten konnten nicht geladen werden:"),
+    ("staking.validators_error", "Validatoren konnten nicht geladen werden:"),
+    ("staking.undelegated", "Nicht delegiert"),
+    ("staking.activating", "Wird aktiviert"),
+    ("staking.active", "Aktiv"),
+    ("staking.deactivating", "Wird deaktiviert"),
+    ("staking.inactive", "Inaktiv"),
+    ("staking.validator", "Validator"),
+    ("staking.locked", "Sperrfrist aktiv: Abhebungen blockiert"),
+    ("staking.stake", "Staken"),
+    ("staking.delegate", "Delegieren"),
+    ("staking.deactivate", "Deaktivieren"),
+    ("staking.withdraw", "Abheben"),
+    ("staking.split", "Aufteilen"),
+    ("staking.merge", "Zusammenführen"),
+    ("staking.new_stake", "Neuer Stake"),
+    ("staking.rent_note", "Etwa 0,0023 SOL des Betrags bleiben als Rent-Reserve im Konto."),
+    ("staking.search_validator", "Vote- oder Identitätsadresse suchen"),
+    ("staking.loading_validators", "Validatoren werden geladen..."),
+    ("staking.delinquent", "säumig"),
+    ("staking.pick_validator", "Wähle zuerst einen Validator"),
+    ("staking.exceeds_withdrawable", "Betrag übersteigt das abhebbare Guthaben"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Memo (optional)"),
+    ("send.memo_placeholder", "Von manchen Börsen verlangt"),
+    ("send.token_transfer_fee", "Token-Transfergebühr (vom Betrag abgezogen)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "TON Connect verwalten"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Zurück"),
+    ("tc.connect_dapp", "TON-Connect-Link (tc://) einer dApp einfügen"),
+    ("tc.open_link", "Link öffnen"),
+    ("tc.invalid_link", "Ungültiger Link:"),
+    ("tc.loading_manifest", "App-Manifest wird geladen..."),
+    ("tc.manifest_error", "App-Manifest kann nicht geladen werden:"),
+    ("tc.connect_request", "möchte sich mit deiner TON-Wallet verbinden"),
+    ("tc.proof_requested", "Anmeldenachweis angefordert für"),
+    ("tc.password", "Passwort"),
+    ("tc.approve", "Genehmigen"),
+    ("tc.reject", "Ablehnen"),
+    ("tc.connected", "Verbunden mit"),
+    ("tc.error", "Fehler:"),
+    ("tc.tx_requests", "Transaktionsanfragen"),
+    ("tc.payload", "mit Payload"),
+    ("tc.state_init", "deployt einen Vertrag (StateInit)"),
+    ("tc.tx_sent", "Transaktion gesendet"),
+    ("tc.sessions", "Verbundene dApps"),
+    ("tc.no_sessions", "Keine verbundenen dApps"),
+    ("tc.disconnect", "Trennen"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "TON-Wallet-Vertrag"),
+    ("settings.ton_wallet_version_hint", "Derselbe Schlüssel besitzt pro Version ein TON-Wallet. Wählen Sie das anzuzeigende und zum Senden verwendete."),
 ];
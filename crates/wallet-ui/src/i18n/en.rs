@@ -124,18 +124,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Wallet not found"),
     ("approve.active_wallet_not_found", "Active wallet not found"),
     ("approve.seed_invalid", "Invalid seed"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction via dApp not yet supported — use the wallet directly"),
     ("approve.method_unsupported", "Unsupported method:"),
     ("approve.error_parsing_wallet", "Error parsing wallet:"),
     ("approve.error_parsing_params", "Error parsing params:"),
     ("approve.missing_message_param", "Missing message parameter"),
     ("approve.invalid_hex", "Invalid hex:"),
     ("approve.missing_typed_data", "Missing typed data parameter"),
-    ("approve.missing_domain", "Missing domain field"),
-    ("approve.missing_message_field", "Missing message field"),
-    ("approve.missing_primary_type", "Missing primaryType field"),
-    ("approve.missing_types", "Missing types field"),
-    ("approve.type_not_found", "Type not found:"),
 
     // WalletConnect
     ("wc.back", "Back"),
@@ -182,10 +176,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     // Token Approvals
     ("approvals.title", "Token Approvals"),
     ("approvals.management", "Approval Management"),
-    ("approvals.management_desc", "Check and revoke ERC-20 approvals to DEX contracts. Unlimited approvals can be a security risk."),
+    ("approvals.management_desc", "Approvals are discovered from on-chain Approval events for your address. Unlimited approvals can be a security risk."),
     ("approvals.evm_only", "Token approvals are only available for EVM chains."),
     ("approvals.scanning", "Scanning approvals..."),
-    ("approvals.no_approvals", "No active approvals found for known tokens."),
+    ("approvals.no_approvals", "No active approvals found."),
     ("approvals.no_rpc", "No RPC URL available"),
     ("approvals.revoke", "Revoke"),
     ("approvals.revoke_error", "Error encoding revoke:"),
@@ -359,4 +353,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "AES-256-GCM encryption"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600,000 rounds)"),
     ("security.local_only", "Keys never leave your device"),
+    // EVM fees
+    ("fee.title", "Network fee"),
+    ("fee.slow", "Slow"),
+    ("fee.normal", "Normal"),
+    ("fee.fast", "Fast"),
+    ("fee.custom", "Custom"),
+    ("fee.max_fee", "Max fee (Gwei)"),
+    ("fee.priority_fee", "Priority fee (Gwei)"),
+    ("fee.gas_limit", "Gas limit"),
+    ("fee.estimated", "Estimated fee"),
+    ("fee.max", "max"),
+    ("fee.loading", "Loading fees..."),
+    ("fee.unavailable", "Fee data unavailable:"),
+    ("fee.invalid", "Select a valid network fee"),
+    ("approve.unsupported_chain", "The dApp requested an unsupported network"),
+    ("approve.missing_chain", "The dApp did not say which network to use"),
+    // Pending EVM transactions
+    ("history.pending", "Pending"),
+    ("history.pending_tx", "Pending"),
+    ("history.sped_up", "Sped up"),
+    ("history.cancelling", "Cancelling"),
+    ("history.speed_up", "Speed up"),
+    ("history.cancel_tx", "Cancel"),
+    ("history.speed_up_hint", "Re-sends the same transaction with higher fees (at least +10%)."),
+    ("history.cancel_hint", "Replaces the transaction with a 0-value send to yourself. Only works if it is not mined first."),
+    // Custom EVM networks
+    ("networks.title", "Custom Networks"),
+    ("networks.manage", "Manage networks"),
+    ("networks.hint", "Add any EVM-compatible chain. Your Ethereum address is used on every EVM network."),
+    ("networks.add", "+ Add"),
+    ("networks.chain_id", "Chain ID"),
+    ("networks.name", "Network name"),
+    ("networks.ticker", "Currency symbol"),
+    ("networks.rpc_url", "RPC URLs"),
+    ("networks.explorer_url", "Block explorer (optional)"),
+    ("networks.save", "Save network"),
+    ("networks.delete", "Remove"),
+    ("networks.empty", "No custom networks yet"),
+    ("networks.invalid_chain_id", "Chain ID must be a positive number"),
+    ("networks.checking_rpc", "Checking RPC..."),
+    ("approve.add_network", "Add Network"),
+    ("approve.switch_network", "Switch Network"),
+    ("approve.add_network_warning", "A malicious network can report fake balances and transactions. Only add networks from sites you trust."),
+    // ENS
+    ("ens.resolving", "Resolving ENS name..."),
+    ("ens.not_resolved", "Wait for the ENS name to resolve before sending"),
+    ("approve.to", "To"),
+    // Safe multisig
+    ("safe.title", "Safe multisig"),
+    ("safe.manage", "Manage Safes"),
+    ("safe.add", "+ Add"),
+    ("safe.hint", "Watch a Safe you co-own to sign, execute and propose its transactions."),
+    ("safe.address", "Safe address"),
+    ("safe.name", "Name (optional)"),
+    ("safe.network", "Network"),
+    ("safe.save", "Add Safe"),
+    ("safe.empty", "No Safes yet"),
+    ("safe.checking", "Checking Safe..."),
+    ("safe.already_added", "This Safe is already in the list"),
+    ("safe.not_a_safe", "Not a Safe on this network:"),
+    ("safe.no_rpc", "No RPC for this network"),
+    ("safe.info_error", "Could not read the Safe:"),
+    ("safe.queue_error", "Could not load the queue:"),
+    ("safe.threshold", "Threshold"),
+    ("safe.you_are_owner", "This wallet is an owner"),
+    ("safe.not_owner", "Watch only: this wallet is not an owner"),
+    ("safe.queue", "Pending transactions"),
+    ("safe.queue_empty", "Nothing waiting for signatures"),
+    ("safe.contract_call", "Contract call"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: the target runs code with full control of the Safe"),
+    ("safe.confirmations", "Signatures"),
+    ("safe.sign", "Sign"),
+    ("safe.execute", "Execute"),
+    ("safe.propose", "New transfer from the Safe"),
+    ("safe.propose_button", "Propose and sign"),
+    ("safe.signed", "Signature added"),
+    ("safe.proposed", "Proposed with nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Token permit (off-chain approval)"),
+    ("permit.kind_permit2", "Permit2 allowance"),
+    ("permit.kind_permit2_transfer", "Permit2 token transfer"),
+    ("permit.kind_seaport", "Seaport listing"),
+    ("permit.spender", "Spender"),
+    ("permit.expiry", "Expires"),
+    ("permit.unlimited", "Unlimited"),
+    ("permit.never", "Never"),
+    ("permit.expired", "Already expired"),
+    ("permit.in", "in"),
+    ("permit.high_risk_title", "This signature can drain your tokens"),
+    ("permit.review_hint", "No transaction is sent, but the spender can move these tokens once you sign."),
+    ("permit.risk_unlimited", "Unlimited amount."),
+    ("permit.risk_unknown_spender", "The spender is not a known contract."),
+    ("permit.risk_far_expiry", "It stays valid for a very long time."),
+    ("permit.risk_no_payment", "You receive nothing in return for the listed items."),
+    // Transaction simulation
+    ("sim.title", "Estimated balance changes"),
+    ("sim.would_fail", "This transaction is expected to fail"),
+    ("sim.unavailable", "Asset changes unavailable: this RPC does not support simulation."),
+    ("sim.no_changes", "No asset changes detected for your account."),
+    ("sim.approvals", "Approvals granted"),
+    ("sim.all_items", "All items"),
+    ("sim.revoked", "Revoked"),
+    ("sim.risky_approval", "Unlimited approval"),
+    ("sim.risky_approval_hint", "The spender will be able to move these assets at any time until you revoke the approval."),
+    ("sim.gas_excluded", "Network fee not included."),
+    // NFT transfers
+    ("nft.send", "Send NFT"),
+    ("nft.review", "Review transfer"),
+    ("nft.simulating", "Simulating transfer..."),
+    ("nft.simulation_ok", "Simulation succeeded: the NFT will leave your wallet."),
+    // Custom tokens
+    ("tokens.title", "Import token"),
+    ("tokens.import", "+ Import"),
+    ("tokens.address", "Contract / mint address"),
+    ("tokens.lookup", "Look up"),
+    ("tokens.looking_up", "Reading token metadata..."),
+    ("tokens.lookup_failed", "Could not read token"),
+    ("tokens.already_listed", "This token is already in the default list"),
+    ("tokens.warning_title", "Verify the address"),
+    ("tokens.warning", "Anyone can create a token with any name and symbol. Only import addresses from the project's official sources."),
+    ("tokens.symbol", "Symbol"),
+    ("tokens.name", "Name"),
+    ("tokens.decimals", "Decimals"),
+    ("tokens.add", "Add token"),
+    ("tokens.remove", "Remove"),
+    ("tokens.empty", "No imported tokens on this network"),
+    ("tokens.unsupported_chain", "This network has no token standard supported by the wallet"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Account delegation (EIP-7702)"),
+    ("approve.delegation_unknown", "Unknown delegation target"),
+    ("approve.delegation_unknown_hint", "This contract will control your account and every asset in it. It is not a known, audited delegate. Only continue if you fully trust this dApp."),
+    ("approve.delegation_any_chain", "Delegation valid on every chain"),
+    ("approve.delegation_any_chain_hint", "This authorization uses chain id 0 and can be replayed on any EVM network where your account exists."),
+    ("approve.delegation_unknown_contract", "Unknown contract"),
+    ("approve.delegation_revoke", "Remove current delegation"),
+    ("approve.delegation_all_chains", "All chains"),
+    ("approve.delegation_presigned", "Signed by another account; relayed by this transaction"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Sign-in request"),
+    ("siwe.address", "Account"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Network"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Issued at"),
+    ("siwe.expires", "Expires"),
+    ("siwe.not_before", "Valid from"),
+    ("siwe.request_id", "Request ID"),
+    ("siwe.resources", "Resources"),
+    ("siwe.domain_mismatch", "Sign-in for a different site"),
+    ("siwe.domain_mismatch_hint", "This message logs you in to {domain}, but it was requested by {origin}. Signing it can let {origin} access your {domain} account. This is a common phishing technique."),
+    ("siwe.scheme_mismatch", "Protocol mismatch"),
+    ("siwe.scheme_mismatch_hint", "The message names a different protocol (http/https) than the page that requested it."),
+    ("siwe.address_mismatch", "Different account"),
+    ("siwe.address_mismatch_hint", "The message is for an address that is not your active account."),
+    ("siwe.chain_mismatch", "Different network"),
+    ("siwe.chain_mismatch_hint", "The message is for chain {message}, but the site is connected to chain {active}."),
+    ("siwe.invalid_nonce", "Weak nonce"),
+    ("siwe.invalid_nonce_hint", "The nonce should be at least 8 random letters or digits. A predictable nonce lets the signature be replayed."),
+    ("siwe.expired", "Sign-in request expired"),
+    ("siwe.expired_hint", "The expiration time has passed. Reload the site and sign in again."),
+    ("siwe.not_yet_valid", "Sign-in request not yet valid"),
+    ("siwe.not_yet_valid_hint", "The message only becomes valid at a later time, so it can be used after you have left the site."),
+    ("siwe.invalid_time", "Invalid date"),
+    ("siwe.invalid_time_hint", "One of the message timestamps is malformed, so its validity period cannot be checked."),
+    ("siwe.unbound", "Site not verified"),
+    ("siwe.unbound_hint", "This request came through WalletConnect, so the wallet cannot confirm it was sent by the site named in the message."),
+    ("siwe.chain_unknown", "Network not verified"),
+    ("siwe.chain_unknown_hint", "The request did not say which network the site is connected to, so the chain in the message could not be checked."),
+    ("siwe.malformed", "Malformed sign-in message"),
+    ("siwe.malformed_hint", "This looks like a Sign-In with Ethereum message but does not follow the standard ({error}), so the site, account and network it grants access to cannot be checked. Legitimate sites do not send malformed sign-in messages."),
+    // Signature verification tool
+    ("verify.title", "Verify signature"),
+    ("verify.open", "Open verification tool"),
+    ("verify.hint", "Check who signed a message. Works for regular accounts and smart-contract wallets (ERC-1271), including ones not deployed yet (ERC-6492)."),
+    ("verify.network", "Network"),
+    ("verify.signer", "Signer address"),
+    ("verify.message_type", "Message type"),
+    ("verify.kind_personal", "Message (personal_sign)"),
+    ("verify.kind_typed", "Typed data (EIP-712 JSON)"),
+    ("verify.kind_hash", "Hash (32 bytes)"),
+    ("verify.message", "Message"),
+    ("verify.signature", "Signature"),
+    ("verify.check", "Verify"),
+    ("verify.checking", "Verifying..."),
+    ("verify.valid", "Valid signature"),
+    ("verify.invalid", "Invalid signature"),
+    ("verify.method", "Checked with"),
+    ("verify.recovered", "Actual signer"),
+    ("verify.no_rpc", "No RPC endpoint for this network"),
+    // Approval discovery
+    ("approvals.all_items", "All items"),
+    ("approvals.scanned_to", "History scanned back to block {block}"),
+    ("approvals.scan_more", "Scan older blocks"),
+    ("approvals.scan_error", "Log scan interrupted:"),
+    ("approvals.found", "{n} active approvals"),
+    ("approvals.select_all", "Select all"),
+    ("approvals.unknown_spender", "Unknown contract"),
+    ("approvals.spender", "Spender"),
+    ("approvals.allowance", "Allowance"),
+    ("approvals.revoke_selected", "Revoke selected ({n})"),
+    ("approvals.confirm_revoke", "Revoke {n} approvals? One transaction is sent per approval."),
+    ("approvals.revoked_count", "{n} revoke transactions sent"),
+    // SPL token transfers
+    ("send.ata_rent", "includes rent for the recipient's new token account"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Solana Wallet Connection"),
+    ("approve.solana_sign_tx", "Sign Solana Transaction"),
+    ("approve.solana_sign_all", "Sign Solana Transactions"),
+    ("approve.solana_send_tx", "Send Solana Transaction"),
+    ("approve.solana_sign_message", "Sign Message (Solana)"),
+    ("approve.solana_sign_in", "Sign-In With Solana"),
+    ("approve.solana_disguised_tx", "This message is a transaction"),
+    ("approve.solana_disguised_tx_hint", "The site asked to sign bytes that form a Solana transaction. Signing them would authorize that transaction, so Rusby will refuse."),
+    ("approve.solana_tx", "Transaction"),
+    ("approve.solana_tx_n", "Transaction {n} of {count}"),
+    ("approve.solana_decode_failed", "Could not decode the transaction"),
+    ("solana_review.raw_units", "units (unknown token)"),
+    ("solana_review.you", "you"),
+    ("solana_review.sol_transfer", "SOL transfer"),
+    ("solana_review.create_account", "Create account"),
+    ("solana_review.assign", "Assign account to program"),
+    ("solana_review.token_transfer", "Token transfer"),
+    ("solana_review.token_approve", "Token delegate approval"),
+    ("solana_review.set_authority", "Change token account authority"),
+    ("solana_review.none", "none"),
+    ("solana_review.close_account", "Close token account"),
+    ("solana_review.create_token_account", "Create token account"),
+    ("solana_review.memo", "Memo"),
+    ("solana_review.compute_limit", "Compute unit limit"),
+    ("solana_review.priority_fee", "Priority fee"),
+    ("solana_review.program_call", "Program call"),
+    ("solana_review.not_signer", "Not your transaction"),
+    ("solana_review.not_signer_hint", "Your account is not one of the signers of this transaction, so it cannot be signed."),
+    ("solana_review.risk_assign", "Account ownership change"),
+    ("solana_review.risk_assign_hint", "Your wallet account would be handed to another program, which could then move all of its SOL. Wallet drainers use this."),
+    ("solana_review.risk_authority", "Token account authority transfer"),
+    ("solana_review.risk_authority_hint", "Control over one of your token accounts would pass to another address, together with every token it holds now or later."),
+    ("solana_review.risk_delegate", "Token spending approval"),
+    ("solana_review.risk_delegate_hint", "A delegate would be allowed to move your tokens later without asking again."),
+    ("solana_review.risk_close", "Token account closed"),
+    ("solana_review.risk_close_hint", "One of your token accounts is closed and its rent deposit goes to another address."),
+    ("solana_review.risk_unknown_program", "Unknown program"),
+    ("solana_review.sponsored", "Paid by"),
+    ("solana_review.outgoing", "SOL sent from your account"),
+    ("solana_review.network_fee", "Network fee"),
+    // Solana priority fees
+    ("fee.solana_priority", "Priority fee"),
+    ("fee.per_compute_unit", "micro-lamports per compute unit"),
+    ("fee.compute_units", "compute units"),
+    // Native SOL staking
+    ("dashboard.stake", "Stake"),
+    ("staking.title", "SOL Staking"),
+    ("staking.about", "Native staking"),
+    ("staking.about_desc", "Stake stays in stake accounts you control. New stake activates at the next epoch; deactivated stake can be withdrawn after the epoch ends."),
+    ("staking.total_staked", "Total staked"),
+    ("staking.epoch", "Epoch"),
+    ("staking.accounts", "Stake accounts"),
+    ("staking.loading", "Loading stake accounts..."),
+    ("staking.no_accounts", "No stake accounts yet"),
+    ("staking.load_error", "Could not load stake accounts:"),
+    ("staking.validators_error", "Could not load validators:"),
+    ("staking.undelegated", "Not delegated"),
+    ("staking.activating", "Activating"),
+    ("staking.active", "Active"),
+    ("staking.deactivating", "Deactivating"),
+    ("staking.inactive", "Inactive"),
+    ("staking.validator", "Validator"),
+    ("staking.locked", "Lockup in force: withdrawals are blocked"),
+    ("staking.stake", "Stake"),
+    ("staking.delegate", "Delegate"),
+    ("staking.deactivate", "Deactivate"),
+    ("staking.withdraw", "Withdraw"),
+    ("staking.split", "Split"),
+    ("staking.merge", "Merge"),
+    ("staking.new_stake", "New stake"),
+    ("staking.rent_note", "About 0.0023 SOL of the amount stays in the stake account as rent reserve."),
+    ("staking.search_validator", "Search vote or identity address"),
+    ("staking.loading_validators", "Loading validators..."),
+    ("staking.delinquent", "delinquent"),
+    ("staking.pick_validator", "Select a validator first"),
+    ("staking.exceeds_withdrawable", "Amount exceeds the withdrawable balance"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Memo (optional)"),
+    ("send.memo_placeholder", "Required by some exchanges"),
+    ("send.token_transfer_fee", "token transfer fee (deducted from the amount)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "Manage TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Back"),
+    ("tc.connect_dapp", "Paste a TON Connect link (tc://) from a dApp"),
+    ("tc.open_link", "Open link"),
+    ("tc.invalid_link", "Invalid link:"),
+    ("tc.loading_manifest", "Loading app manifest..."),
+    ("tc.manifest_error", "Cannot load the app manifest:"),
+    ("tc.connect_request", "wants to connect to your TON wallet"),
+    ("tc.proof_requested", "Sign-in proof requested for"),
+    ("tc.password", "Password"),
+    ("tc.approve", "Approve"),
+    ("tc.reject", "Reject"),
+    ("tc.connected", "Connected to"),
+    ("tc.error", "Error:"),
+    ("tc.tx_requests", "Transaction requests"),
+    ("tc.payload", "with payload"),
+    ("tc.state_init", "deploys a contract (StateInit)"),
+    ("tc.tx_sent", "Transaction sent"),
+    ("tc.sessions", "Connected dApps"),
+    ("tc.no_sessions", "No connected dApps"),
+    ("tc.disconnect", "Disconnect"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "TON Wallet Contract"),
+    ("settings.ton_wallet_version_hint", "The same key owns one TON wallet per version. Choose the one to show and send from."),
 ];
@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Wallet no encontrado"),
     ("approve.active_wallet_not_found", "Wallet activo no encontrado"),
     ("approve.seed_invalid", "Seed no válido"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction desde dApp aún no soportado \u{2014} usa el wallet directamente"),
     ("approve.method_unsupported", "Método no soportado:"),
     ("approve.error_parsing_wallet", "Error al analizar el wallet:"),
     ("approve.error_parsing_params", "Error al analizar los parámetros:"),
     ("approve.missing_message_param", "Falta el parámetro del mensaje"),
     ("approve.invalid_hex", "Hexadecimal no válido:"),
     ("approve.missing_typed_data", "Faltan los datos tipados"),
-    ("approve.missing_domain", "Falta el campo domain"),
-    ("approve.missing_message_field", "Falta el campo message"),
-    ("approve.missing_primary_type", "Falta el campo primaryType"),
-    ("approve.missing_types", "Falta el campo types"),
-    ("approve.type_not_found", "Tipo no encontrado:"),
     ("wc.back", "Atrás"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "Configuración"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "dApp desconocida"),
     ("approvals.title", "Aprobaciones de Tokens"),
     ("approvals.management", "Gestión de Aprobaciones"),
-    ("approvals.management_desc", "Consulta y revoca aprobaciones ERC-20 a contratos DEX. Las aprobaciones ilimitadas pueden suponer un riesgo de seguridad."),
+    ("approvals.management_desc", "Las aprobaciones se detectan a partir de los eventos Approval on-chain de tu dirección. Las aprobaciones ilimitadas pueden suponer un riesgo de seguridad."),
     ("approvals.evm_only", "Las aprobaciones de tokens solo están disponibles para cadenas EVM."),
     ("approvals.scanning", "Escaneando aprobaciones..."),
-    ("approvals.no_approvals", "No se encontraron aprobaciones activas para tokens conocidos."),
+    ("approvals.no_approvals", "No se encontraron aprobaciones activas."),
     ("approvals.no_rpc", "URL RPC no disponible"),
     ("approvals.revoke", "Revocar"),
     ("approvals.revoke_error", "Error al codificar la revocación:"),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "Cifrado AES-256-GCM"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600.000 rondas)"),
     ("security.local_only", "Las claves nunca salen de tu dispositivo"),
+    // EVM fees
+    ("fee.title", "Comisión de red"),
+    ("fee.slow", "Lenta"),
+    ("fee.normal", "Normal"),
+    ("fee.fast", "Rápida"),
+    ("fee.custom", "Personalizada"),
+    ("fee.max_fee", "Comisión máx. (Gwei)"),
+    ("fee.priority_fee", "Comisión de prioridad (Gwei)"),
+    ("fee.gas_limit", "Límite de gas"),
+    ("fee.estimated", "Comisión estimada"),
+    ("fee.max", "máx."),
+    ("fee.loading", "Cargando comisiones..."),
+    ("fee.unavailable", "Datos de comisión no disponibles:"),
+    ("fee.invalid", "Selecciona una comisión de red válida"),
+    ("approve.unsupported_chain", "La dApp solicitó una red no soportada"),
+    ("approve.missing_chain", "La dApp no indicó qué red usar"),
+    // Pending EVM transactions
+    ("history.pending", "Pendientes"),
+    ("history.pending_tx", "Pendiente"),
+    ("history.sped_up", "Acelerada"),
+    ("history.cancelling", "Cancelando"),
+    ("history.speed_up", "Acelerar"),
+    ("history.cancel_tx", "Cancelar"),
+    ("history.speed_up_hint", "Reenvía la misma transacción con comisiones más altas (al menos +10%)."),
+    ("history.cancel_hint", "Reemplaza la transacción por un envío de valor 0 a ti mismo. Solo funciona si no se mina antes."),
+    // Custom EVM networks
+    ("networks.title", "Redes personalizadas"),
+    ("networks.manage", "Gestionar redes"),
+    ("networks.hint", "Añade cualquier cadena compatible con EVM. Tu dirección de Ethereum se usa en todas las redes EVM."),
+    ("networks.add", "+ Añadir"),
+    ("networks.chain_id", "Chain ID"),
+    ("networks.name", "Nombre de la red"),
+    ("networks.ticker", "Símbolo de moneda"),
+    ("networks.rpc_url", "URL RPC"),
+    ("networks.explorer_url", "Explorador de bloques (opcional)"),
+    ("networks.save", "Guardar red"),
+    ("networks.delete", "Eliminar"),
+    ("networks.empty", "Aún no hay redes personalizadas"),
+    ("networks.invalid_chain_id", "El Chain ID debe ser un número positivo"),
+    ("networks.checking_rpc", "Comprobando RPC..."),
+    ("approve.add_network", "Añadir red"),
+    ("approve.switch_network", "Cambiar de red"),
+    ("approve.add_network_warning", "Una red maliciosa puede mostrar saldos y transacciones falsos. Añade redes solo de sitios de confianza."),
+    // ENS
+    ("ens.resolving", "Resolviendo nombre ENS..."),
+    ("ens.not_resolved", "Espera a que se resuelva el nombre ENS antes de enviar"),
+    ("approve.to", "Para"),
+    // Safe multisig
+    ("safe.title", "Safe multifirma"),
+    ("safe.manage", "Gestionar Safes"),
+    ("safe.add", "+ Añadir"),
+    ("safe.hint", "Sigue un Safe del que eres copropietario para firmar, ejecutar y proponer transacciones."),
+    ("safe.address", "Dirección del Safe"),
+    ("safe.name", "Nombre (opcional)"),
+    ("safe.network", "Red"),
+    ("safe.save", "Añadir Safe"),
+    ("safe.empty", "Aún no hay Safes"),
+    ("safe.checking", "Comprobando el Safe..."),
+    ("safe.already_added", "Este Safe ya está en la lista"),
+    ("safe.not_a_safe", "No es un Safe en esta red:"),
+    ("safe.no_rpc", "No hay RPC para esta red"),
+    ("safe.info_error", "No se pudo leer el Safe:"),
+    ("safe.queue_error", "No se pudo cargar la cola:"),
+    ("safe.threshold", "Umbral"),
+    ("safe.you_are_owner", "Esta cartera es propietaria"),
+    ("safe.not_owner", "Solo lectura: esta cartera no es propietaria"),
+    ("safe.queue", "Transacciones pendientes"),
+    ("safe.queue_empty", "Nada esperando firmas"),
+    ("safe.contract_call", "Llamada a contrato"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: el destino ejecuta código con control total del Safe"),
+    ("safe.confirmations", "Firmas"),
+    ("safe.sign", "Firmar"),
+    ("safe.execute", "Ejecutar"),
+    ("safe.propose", "Nueva transferencia desde el Safe"),
+    ("safe.propose_button", "Proponer y firmar"),
+    ("safe.signed", "Firma añadida"),
+    ("safe.proposed", "Propuesta con nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Permit de token (aprobación off-chain)"),
+    ("permit.kind_permit2", "Allowance de Permit2"),
+    ("permit.kind_permit2_transfer", "Transferencia de tokens Permit2"),
+    ("permit.kind_seaport", "Listado de Seaport"),
+    ("permit.spender", "Gastador"),
+    ("permit.expiry", "Caduca"),
+    ("permit.unlimited", "Ilimitado"),
+    ("permit.never", "Nunca"),
+    ("permit.expired", "Ya caducado"),
+    ("permit.in", "en"),
+    ("permit.high_risk_title", "Esta firma puede vaciar tus tokens"),
+    ("permit.review_hint", "No se envía ninguna transacción, pero tras firmar el gastador podrá mover estos tokens."),
+    ("permit.risk_unlimited", "Importe ilimitado."),
+    ("permit.risk_unknown_spender", "El gastador no es un contrato conocido."),
+    ("permit.risk_far_expiry", "Sigue siendo válida durante mucho tiempo."),
+    ("permit.risk_no_payment", "No recibes nada a cambio de los artículos listados."),
+    // Transaction simulation
+    ("sim.title", "Cambios de saldo estimados"),
+    ("sim.would_fail", "Se espera que esta transacción falle"),
+    ("sim.unavailable", "Cambios no disponibles: este RPC no admite simulación."),
+    ("sim.no_changes", "No se detectaron cambios de activos en tu cuenta."),
+    ("sim.approvals", "Aprobaciones otorgadas"),
+    ("sim.all_items", "Todos los artículos"),
+    ("sim.revoked", "Revocada"),
+    ("sim.risky_approval", "Aprobación ilimitada"),
+    ("sim.risky_approval_hint", "El gastador podrá mover estos activos en cualquier momento hasta que revoques la aprobación."),
+    ("sim.gas_excluded", "Comisión de red no incluida."),
+    // NFT transfers
+    ("nft.send", "Enviar NFT"),
+    ("nft.review", "Revisar transferencia"),
+    ("nft.simulating", "Simulando transferencia..."),
+    ("nft.simulation_ok", "Simulación correcta: el NFT saldrá de tu billetera."),
+    // Custom tokens
+    ("tokens.title", "Importar token"),
+    ("tokens.import", "+ Importar"),
+    ("tokens.address", "Dirección del contrato / mint"),
+    ("tokens.lookup", "Buscar"),
+    ("tokens.looking_up", "Leyendo metadatos del token..."),
+    ("tokens.lookup_failed", "No se pudo leer el token"),
+    ("tokens.already_listed", "Este token ya está en la lista predeterminada"),
+    ("tokens.warning_title", "Verifica la dirección"),
+    ("tokens.warning", "Cualquiera puede crear un token con cualquier nombre y símbolo. Importa solo direcciones de fuentes oficiales del proyecto."),
+    ("tokens.symbol", "Símbolo"),
+    ("tokens.name", "Nombre"),
+    ("tokens.decimals", "Decimales"),
+    ("tokens.add", "Añadir token"),
+    ("tokens.remove", "Quitar"),
+    ("tokens.empty", "No hay tokens importados en esta red"),
+    ("tokens.unsupported_chain", "Esta red no tiene un estándar de token compatible con la wallet"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Delegación de la cuenta (EIP-7702)"),
+    ("approve.delegation_unknown", "Destino de delegación desconocido"),
+    ("approve.delegation_unknown_hint", "Este contrato controlará tu cuenta y todos sus activos. No es un delegado conocido y auditado. Continúa solo si confías plenamente en esta dApp."),
+    ("approve.delegation_any_chain", "Delegación válida en todas las cadenas"),
+    ("approve.delegation_any_chain_hint", "Esta autorización usa chain id 0 y puede reutilizarse en cualquier red EVM donde exista tu cuenta."),
+    ("approve.delegation_unknown_contract", "Contrato desconocido"),
+    ("approve.delegation_revoke", "Eliminar la delegación actual"),
+    ("approve.delegation_all_chains", "Todas las cadenas"),
+    ("approve.delegation_presigned", "Firmada por otra cuenta; retransmitida por esta transacción"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Solicitud de inicio de sesión"),
+    ("siwe.address", "Cuenta"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Red"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Emitido el"),
+    ("siwe.expires", "Caduca"),
+    ("siwe.not_before", "Válido desde"),
+    ("siwe.request_id", "ID de solicitud"),
+    ("siwe.resources", "Recursos"),
+    ("siwe.domain_mismatch", "Inicio de sesión para otro sitio"),
+    ("siwe.domain_mismatch_hint", "Este mensaje inicia sesión en {domain}, pero lo solicitó {origin}. Firmarlo puede permitir que {origin} acceda a tu cuenta de {domain}. Es una técnica de phishing común."),
+    ("siwe.scheme_mismatch", "Protocolo no coincidente"),
+    ("siwe.scheme_mismatch_hint", "El mensaje indica un protocolo (http/https) distinto al de la página que lo solicitó."),
+    ("siwe.address_mismatch", "Cuenta distinta"),
+    ("siwe.address_mismatch_hint", "El mensaje es para una dirección que no es tu cuenta activa."),
+    ("siwe.chain_mismatch", "Red distinta"),
+    ("siwe.chain_mismatch_hint", "El mensaje es para la cadena {message}, pero el sitio está conectado a la cadena {active}."),
+    ("siwe.invalid_nonce", "Nonce débil"),
+    ("siwe.invalid_nonce_hint", "El nonce debería tener al menos 8 letras o dígitos aleatorios. Un nonce predecible permite reutilizar la firma."),
+    ("siwe.expired", "Solicitud de inicio de sesión caducada"),
+    ("siwe.expired_hint", "La fecha de caducidad ha pasado. Recarga el sitio e inicia sesión de nuevo."),
+    ("siwe.not_yet_valid", "Solicitud aún no válida"),
+    ("siwe.not_yet_valid_hint", "El mensaje solo será válido más tarde, así que puede usarse después de que salgas del sitio."),
+    ("siwe.invalid_time", "Fecha no válida"),
+    ("siwe.invalid_time_hint", "Una de las fechas del mensaje está mal formada, así que no se puede comprobar su validez."),
+    ("siwe.unbound", "Sitio no verificado"),
+    ("siwe.unbound_hint", "Esta solicitud llegó por WalletConnect, así que la billetera no puede confirmar que la envió el sitio indicado en el mensaje."),
+    ("siwe.chain_unknown", "Red no verificada"),
+    ("siwe.chain_unknown_hint", "La solicitud no indica a qué red está conectado el sitio, por lo que no se pudo comprobar la cadena del mensaje."),
+    ("siwe.malformed", "Mensaje de inicio de sesión mal formado"),
+    ("siwe.malformed_hint", "Parece un mensaje de Sign-In with Ethereum pero no sigue el estándar ({error}), así que no se puede comprobar el sitio, la cuenta y la red a los que da acceso. Los sitios legítimos no envían mensajes de inicio de sesión mal formados."),
+    // Signature verification tool
+    ("verify.title", "Verificar firma"),
+    ("verify.open", "Abrir herramienta de verificación"),
+    ("verify.hint", "Comprueba quién firmó un mensaje. Funciona con cuentas normales y billeteras de contrato inteligente (ERC-1271), incluso no desplegadas aún (ERC-6492)."),
+    ("verify.network", "Red"),
+    ("verify.signer", "Dirección del firmante"),
+    ("verify.message_type", "Tipo de mensaje"),
+    ("verify.kind_personal", "Mensaje (personal_sign)"),
+    ("verify.kind_typed", "Datos tipados (JSON EIP-712)"),
+    ("verify.kind_hash", "Hash (32 bytes)"),
+    ("verify.message", "Mensaje"),
+    ("verify.signature", "Firma"),
+    ("verify.check", "Verificar"),
+    ("verify.checking", "Verificando..."),
+    ("verify.valid", "Firma válida"),
+    ("verify.invalid", "Firma no válida"),
+    ("verify.method", "Verificada con"),
+    ("verify.recovered", "Firmante real"),
+    ("verify.no_rpc", "No hay endpoint RPC para esta red"),
+    // Approval discovery
+    ("approvals.all_items", "Todos los ítems"),
+    ("approvals.scanned_to", "Historial analizado hasta el bloque {block}"),
+    ("approvals.scan_more", "Analizar bloques anteriores"),
+    ("approvals.scan_error", "Análisis de logs interrumpido:"),
+    ("approvals.found", "{n} aprobaciones activas"),
+    ("approvals.select_all", "Seleccionar todo"),
+    ("approvals.unknown_spender", "Contrato desconocido"),
+    ("approvals.spender", "Gastador"),
+    ("approvals.allowance", "Asignación"),
+    ("approvals.revoke_selected", "Revocar seleccionadas ({n})"),
+    ("approvals.confirm_revoke", "¿Revocar {n} aprobaciones? Se envía una transacción por cada una."),
+    ("approvals.revoked_count", "{n} transacciones de revocación enviadas"),
+    // SPL token transfers
+    ("send.ata_rent", "incluye la renta de la nueva cuenta de token del destinatario"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Conexión de wallet Solana"),
+    ("approve.solana_sign_tx", "Firmar transacción Solana"),
+    ("approve.solana_sign_all", "Firmar transacciones Solana"),
+    ("approve.solana_send_tx", "Enviar transacción Solana"),
+    ("approve.solana_sign_message", "Firmar mensaje (Solana)"),
+    ("approve.solana_sign_in", "Iniciar sesión con Solana"),
+    ("approve.solana_disguised_tx", "Este mensaje es una transacción"),
+    ("approve.solana_disguised_tx_hint", "El sitio pidió firmar bytes que forman una transacción Solana. Firmarlos autorizaría esa transacción, por lo que Rusby se negará."),
+    ("approve.solana_tx", "Transacción"),
+    ("approve.solana_tx_n", "Transacción {n} de {count}"),
+    ("approve.solana_decode_failed", "No se pudo decodificar la transacción"),
+    ("solana_review.raw_units", "unidades (token desconocido)"),
+    ("solana_review.you", "tú"),
+    ("solana_review.sol_transfer", "Transferencia de SOL"),
+    ("solana_review.create_account", "Crear cuenta"),
+    ("solana_review.assign", "Asignar cuenta a un programa"),
+    ("solana_review.token_transfer", "Transferencia de tokens"),
+    ("solana_review.token_approve", "Aprobación de delegado de tokens"),
+    ("solana_review.set_authority", "Cambiar autoridad de la cuenta de tokens"),
+    ("solana_review.none", "ninguna"),
+    ("solana_review.close_account", "Cerrar cuenta de tokens"),
+    ("solana_review.create_token_account", "Crear cuenta de tokens"),
+    ("solana_review.memo", "Memo"),
+    ("solana_review.compute_limit", "Límite de unidades de cómputo"),
+    ("solana_review.priority_fee", "Comisión de prioridad"),
+    ("solana_review.program_call", "Llamada a programa"),
+    ("solana_review.not_signer", "No es tu transacción"),
+    ("solana_review.not_signer_hint", "Tu cuenta no es firmante de esta transacción, así que no se puede firmar."),
+    ("solana_review.risk_assign", "Cambio de propietario de la cuenta"),
+    ("solana_review.risk_assign_hint", "Tu cuenta pasaría a otro programa, que podría mover todo su SOL. Los drainers usan esta técnica."),
+    ("solana_review.risk_authority", "Cesión de autoridad de la cuenta de tokens"),
+    ("solana_review.risk_authority_hint", "El control de una de tus cuentas de tokens pasaría a otra dirección, junto con todos los tokens que contenga ahora o después."),
+    ("solana_review.risk_delegate", "Aprobación de gasto de tokens"),
+    ("solana_review.risk_delegate_hint", "Un delegado podría mover tus tokens más tarde sin volver a preguntar."),
+    ("solana_review.risk_close", "Cuenta de tokens cerrada"),
+    ("solana_review.risk_close_hint", "Una de tus cuentas de tokens se cierra y su depósito de renta va a otra dirección."),
+    ("solana_review.risk_unknown_program", "Programa desconocido"),
+    ("solana_review.sponsored", "Pagada por"),
+    ("solana_review.outgoing", "SOL enviados desde tu cuenta"),
+    ("solana_review.network_fee", "Comisión de red"),
+    // Solana priority fees
+    ("fee.solana_priority", "Comisión de prioridad"),
+    ("fee.per_compute_unit", "micro-lamports por unidad de cómputo"),
+    ("fee.compute_units", "unidades de cómputo"),
+    // Native SOL staking
+    ("dashboard.stake", "Stake"),
+    ("staking.title", "Staking de SOL"),
+    ("staking.about", "Staking nativo"),
+    ("staking.about_desc", "El stake permanece en cuentas de stake que controlas. El nuevo stake se activa en la siguiente época; el desactivado se puede retirar al terminar la época."),
+    ("staking.total_staked", "Total en stake"),
+    ("staking.epoch", "Época"),
+    ("staking.accounts", "Cuentas de stake"),
+    ("staking.loading", "Cargando cuentas de stake..."),
+    ("staking.no_accounts", "Aún no hay cuentas de stake"),
+    ("staking.load_error", "No se pudieron cargar las cuentas de stake:"),
+    ("staking.validators_error", "No se pudieron cargar los validadores:"),
+    ("staking.undelegated", "Sin delegar"),
+    ("staking.activating", "Activándose"),
+    ("staking.active", "Activo"),
+    ("staking.deactivating", "Desactivándose"),
+    ("staking.inactive", "Inactivo"),
+    ("staking.validator", "Validador"),
+    ("staking.locked", "Bloqueo vigente: retiros bloqueados"),
+    ("staking.stake", "Hacer stake"),
+    ("staking.delegate", "Delegar"),
+    ("staking.deactivate", "Desactivar"),
+    ("staking.withdraw", "Retirar"),
+    ("staking.split", "Dividir"),
+    ("staking.merge", "Fusionar"),
+    ("staking.new_stake", "Nuevo stake"),
+    ("staking.rent_note", "Unos 0,0023 SOL del importe quedan en la cuenta como reserva de rent."),
+    ("staking.search_validator", "Buscar dirección de voto o identidad"),
+    ("staking.loading_validators", "Cargando validadores..."),
+    ("staking.delinquent", "moroso"),
+    ("staking.pick_validator", "Selecciona primero un validador"),
+    ("staking.exceeds_withdrawable", "El importe supera el saldo retirable"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Memo (opcional)"),
+    ("send.memo_placeholder", "Requerido por algunos exchanges"),
+    ("send.token_transfer_fee", "comisión de transferencia del token (descontada del importe)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "Gestionar TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Atrás"),
+    ("tc.connect_dapp", "Pega un enlace TON Connect (tc://) de una dApp"),
+    ("tc.open_link", "Abrir enlace"),
+    ("tc.invalid_link", "Enlace no válido:"),
+    ("tc.loading_manifest", "Cargando manifiesto de la app..."),
+    ("tc.manifest_error", "No se puede cargar el manifiesto:"),
+    ("tc.connect_request", "quiere conectarse a tu wallet TON"),
+    ("tc.proof_requested", "Prueba de inicio de sesión solicitada para"),
+    ("tc.password", "Contraseña"),
+    ("tc.approve", "Aprobar"),
+    ("tc.reject", "Rechazar"),
+    ("tc.connected", "Conectado a"),
+    ("tc.error", "Error:"),
+    ("tc.tx_requests", "Solicitudes de transacción"),
+    ("tc.payload", "con payload"),
+    ("tc.state_init", "despliega un contrato (StateInit)"),
+    ("tc.tx_sent", "Transacción enviada"),
+    ("tc.sessions", "dApps conectadas"),
+    ("tc.no_sessions", "No hay dApps conectadas"),
+    ("tc.disconnect", "Desconectar"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "Contrato de Wallet TON"),
+    ("settings.ton_wallet_version_hint", "La misma clave posee un wallet TON por versión. Elige cuál mostrar y desde cuál enviar."),
 ];
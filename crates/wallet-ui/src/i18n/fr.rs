@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Portefeuille introuvable"),
     ("approve.active_wallet_not_found", "Portefeuille actif introuvable"),
     ("approve.seed_invalid", "Seed invalide"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction via dApp pas encore pris en charge \u{2014} utilisez le portefeuille directement"),
     ("approve.method_unsupported", "Méthode non prise en charge :"),
     ("approve.error_parsing_wallet", "Erreur d'analyse du portefeuille :"),
     ("approve.error_parsing_params", "Erreur d'analyse des paramètres :"),
     ("approve.missing_message_param", "Paramètre de message manquant"),
     ("approve.invalid_hex", "Hexadécimal invalide :"),
     ("approve.missing_typed_data", "Données typées manquantes"),
-    ("approve.missing_domain", "Champ domain manquant"),
-    ("approve.missing_message_field", "Champ message manquant"),
-    ("approve.missing_primary_type", "Champ primaryType manquant"),
-    ("approve.missing_types", "Champ types manquant"),
-    ("approve.type_not_found", "Type introuvable :"),
     ("wc.back", "Retour"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "Configuration"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "dApp inconnue"),
     ("approvals.title", "Approbations de Jetons"),
     ("approvals.management", "Gestion des Approbations"),
-    ("approvals.management_desc", "Vérifiez et révoquez les approbations ERC-20 aux contrats DEX. Les approbations illimitées peuvent représenter un risque de sécurité."),
+    ("approvals.management_desc", "Les approbations sont détectées à partir des événements Approval on-chain de votre adresse. Les approbations illimitées peuvent présenter un risque de sécurité."),
     ("approvals.evm_only", "Les approbations de jetons sont uniquement disponibles pour les chaînes EVM."),
     ("approvals.scanning", "Analyse des approbations..."),
-    ("approvals.no_approvals", "Aucune approbation active trouvée pour les jetons connus."),
+    ("approvals.no_approvals", "Aucune approbation active trouvée."),
     ("approvals.no_rpc", "URL RPC non disponible"),
     ("approvals.revoke", "Révoquer"),
     ("approvals.revoke_error", "Erreur d'encodage de la révocation :"),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "Chiffrement AES-256-GCM"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600 000 tours)"),
     ("security.local_only", "Les clés ne quittent jamais votre appareil"),
+    // EVM fees
+    ("fee.title", "Frais de réseau"),
+    ("fee.slow", "Lent"),
+    ("fee.normal", "Normal"),
+    ("fee.fast", "Rapide"),
+    ("fee.custom", "Personnalisé"),
+    ("fee.max_fee", "Frais max (Gwei)"),
+    ("fee.priority_fee", "Frais de priorité (Gwei)"),
+    ("fee.gas_limit", "Limite de gas"),
+    ("fee.estimated", "Frais estimés"),
+    ("fee.max", "max"),
+    ("fee.loading", "Chargement des frais..."),
+    ("fee.unavailable", "Données de frais indisponibles :"),
+    ("fee.invalid", "Sélectionnez des frais de réseau valides"),
+    ("approve.unsupported_chain", "La dApp a demandé un réseau non pris en charge"),
+    ("approve.missing_chain", "La dApp n'a pas indiqué quel réseau utiliser"),
+    // Pending EVM transactions
+    ("history.pending", "En attente"),
+    ("history.pending_tx", "En attente"),
+    ("history.sped_up", "Accélérée"),
+    ("history.cancelling", "Annulation"),
+    ("history.speed_up", "Accélérer"),
+    ("history.cancel_tx", "Annuler"),
+    ("history.speed_up_hint", "Renvoie la même transaction avec des frais plus élevés (au moins +10 %)."),
+    ("history.cancel_hint", "Remplace la transaction par un envoi de valeur 0 à vous-même. Ne fonctionne que si elle n'est pas minée avant."),
+    // Custom EVM networks
+    ("networks.title", "Réseaux personnalisés"),
+    ("networks.manage", "Gérer les réseaux"),
+    ("networks.hint", "Ajoutez n'importe quelle chaîne compatible EVM. Votre adresse Ethereum est utilisée sur tous les réseaux EVM."),
+    ("networks.add", "+ Ajouter"),
+    ("networks.chain_id", "Chain ID"),
+    ("networks.name", "Nom du réseau"),
+    ("networks.ticker", "Symbole de la devise"),
+    ("networks.rpc_url", "URL RPC"),
+    ("networks.explorer_url", "Explorateur de blocs (facultatif)"),
+    ("networks.save", "Enregistrer le réseau"),
+    ("networks.delete", "Supprimer"),
+    ("networks.empty", "Aucun réseau personnalisé"),
+    ("networks.invalid_chain_id", "Le Chain ID doit être un nombre positif"),
+    ("networks.checking_rpc", "Vérification du RPC..."),
+    ("approve.add_network", "Ajouter un réseau"),
+    ("approve.switch_network", "Changer de réseau"),
+    ("approve.add_network_warning", "Un réseau malveillant peut afficher de faux soldes et transactions. N'ajoutez que des réseaux de sites de confiance."),
+    // ENS
+    ("ens.resolving", "Résolution du nom ENS..."),
+    ("ens.not_resolved", "Attendez la résolution du nom ENS avant d'envoyer"),
+    ("approve.to", "À"),
+    // Safe multisig
+    ("safe.title", "Safe multisig"),
+    ("safe.manage", "Gérer les Safes"),
+    ("safe.add", "+ Ajouter"),
+    ("safe.hint", "Suivez un Safe dont vous êtes co-propriétaire pour signer, exécuter et proposer des transactions."),
+    ("safe.address", "Adresse du Safe"),
+    ("safe.name", "Nom (facultatif)"),
+    ("safe.network", "Réseau"),
+    ("safe.save", "Ajouter le Safe"),
+    ("safe.empty", "Aucun Safe"),
+    ("safe.checking", "Vérification du Safe..."),
+    ("safe.already_added", "Ce Safe est déjà dans la liste"),
+    ("safe.not_a_safe", "Pas un Safe sur ce réseau :"),
+    ("safe.no_rpc", "Aucun RPC pour ce réseau"),
+    ("safe.info_error", "Impossible de lire le Safe :"),
+    ("safe.queue_error", "Impossible de charger la file :"),
+    ("safe.threshold", "Seuil"),
+    ("safe.you_are_owner", "Ce portefeuille est propriétaire"),
+    ("safe.not_owner", "Lecture seule : ce portefeuille n'est pas propriétaire"),
+    ("safe.queue", "Transactions en attente"),
+    ("safe.queue_empty", "Rien en attente de signatures"),
+    ("safe.contract_call", "Appel de contrat"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL : la cible exécute du code avec le contrôle total du Safe"),
+    ("safe.confirmations", "Signatures"),
+    ("safe.sign", "Signer"),
+    ("safe.execute", "Exécuter"),
+    ("safe.propose", "Nouveau transfert depuis le Safe"),
+    ("safe.propose_button", "Proposer et signer"),
+    ("safe.signed", "Signature ajoutée"),
+    ("safe.proposed", "Proposée avec le nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Permit de jeton (approbation hors chaîne)"),
+    ("permit.kind_permit2", "Allowance Permit2"),
+    ("permit.kind_permit2_transfer", "Transfert de jetons Permit2"),
+    ("permit.kind_seaport", "Annonce Seaport"),
+    ("permit.spender", "Dépensier"),
+    ("permit.expiry", "Expire"),
+    ("permit.unlimited", "Illimité"),
+    ("permit.never", "Jamais"),
+    ("permit.expired", "Déjà expiré"),
+    ("permit.in", "dans"),
+    ("permit.high_risk_title", "Cette signature peut vider vos jetons"),
+    ("permit.review_hint", "Aucune transaction n'est envoyée, mais après signature le dépensier pourra déplacer ces jetons."),
+    ("permit.risk_unlimited", "Montant illimité."),
+    ("permit.risk_unknown_spender", "Le dépensier n'est pas un contrat connu."),
+    ("permit.risk_far_expiry", "Elle reste valide très longtemps."),
+    ("permit.risk_no_payment", "Vous ne recevez rien en échange des objets listés."),
+    // Transaction simulation
+    ("sim.title", "Variations de solde estimées"),
+    ("sim.would_fail", "Cette transaction devrait échouer"),
+    ("sim.unavailable", "Variations indisponibles : ce RPC ne prend pas en charge la simulation."),
+    ("sim.no_changes", "Aucune variation d'actifs détectée pour votre compte."),
+    ("sim.approvals", "Autorisations accordées"),
+    ("sim.all_items", "Tous les objets"),
+    ("sim.revoked", "Révoquée"),
+    ("sim.risky_approval", "Autorisation illimitée"),
+    ("sim.risky_approval_hint", "Le bénéficiaire pourra déplacer ces actifs à tout moment jusqu'à ce que vous révoquiez l'autorisation."),
+    ("sim.gas_excluded", "Frais de réseau non inclus."),
+    // NFT transfers
+    ("nft.send", "Envoyer le NFT"),
+    ("nft.review", "Vérifier le transfert"),
+    ("nft.simulating", "Simulation du transfert..."),
+    ("nft.simulation_ok", "Simulation réussie : le NFT quittera votre portefeuille."),
+    // Custom tokens
+    ("tokens.title", "Importer un jeton"),
+    ("tokens.import", "+ Importer"),
+    ("tokens.address", "Adresse du contrat / mint"),
+    ("tokens.lookup", "Rechercher"),
+    ("tokens.looking_up", "Lecture des métadonnées..."),
+    ("tokens.lookup_failed", "Impossible de lire le jeton"),
+    ("tokens.already_listed", "Ce jeton est déjà dans la liste par défaut"),
+    ("tokens.warning_title", "Vérifiez l'adresse"),
+    ("tokens.warning", "N'importe qui peut créer un jeton avec n'importe quel nom et symbole. N'importez que des adresses issues des sources officielles du projet."),
+    ("tokens.symbol", "Symbole"),
+    ("tokens.name", "Nom"),
+    ("tokens.decimals", "Décimales"),
+    ("tokens.add", "Ajouter le jeton"),
+    ("tokens.remove", "Retirer"),
+    ("tokens.empty", "Aucun jeton importé sur ce réseau"),
+    ("tokens.unsupported_chain", "Ce réseau n'a pas de standard de jeton pris en charge par le portefeuille"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Délégation du compte (EIP-7702)"),
+    ("approve.delegation_unknown", "Cible de délégation inconnue"),
+    ("approve.delegation_unknown_hint", "Ce contrat contrôlera votre compte et tous ses actifs. Ce n'est pas un délégué connu et audité. Ne continuez que si vous faites entièrement confiance à cette dApp."),
+    ("approve.delegation_any_chain", "Délégation valable sur toutes les chaînes"),
+    ("approve.delegation_any_chain_hint", "Cette autorisation utilise le chain id 0 et peut être rejouée sur tout réseau EVM où votre compte existe."),
+    ("approve.delegation_unknown_contract", "Contrat inconnu"),
+    ("approve.delegation_revoke", "Supprimer la délégation actuelle"),
+    ("approve.delegation_all_chains", "Toutes les chaînes"),
+    ("approve.delegation_presigned", "Signée par un autre compte ; relayée par cette transaction"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Demande de connexion"),
+    ("siwe.address", "Compte"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Réseau"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Émis le"),
+    ("siwe.expires", "Expire"),
+    ("siwe.not_before", "Valide à partir de"),
+    ("siwe.request_id", "ID de requête"),
+    ("siwe.resources", "Ressources"),
+    ("siwe.domain_mismatch", "Connexion pour un autre site"),
+    ("siwe.domain_mismatch_hint", "Ce message vous connecte à {domain}, mais il a été demandé par {origin}. Le signer peut permettre à {origin} d'accéder à votre compte {domain}. C'est une technique de phishing courante."),
+    ("siwe.scheme_mismatch", "Protocole différent"),
+    ("siwe.scheme_mismatch_hint", "Le message indique un protocole (http/https) différent de celui de la page qui l'a demandé."),
+    ("siwe.address_mismatch", "Compte différent"),
+    ("siwe.address_mismatch_hint", "Le message concerne une adresse qui n'est pas votre compte actif."),
+    ("siwe.chain_mismatch", "Réseau différent"),
+    ("siwe.chain_mismatch_hint", "Le message concerne la chaîne {message}, mais le site est connecté à la chaîne {active}."),
+    ("siwe.invalid_nonce", "Nonce faible"),
+    ("siwe.invalid_nonce_hint", "Le nonce devrait contenir au moins 8 lettres ou chiffres aléatoires. Un nonce prévisible permet de rejouer la signature."),
+    ("siwe.expired", "Demande de connexion expirée"),
+    ("siwe.expired_hint", "La date d'expiration est dépassée. Rechargez le site et reconnectez-vous."),
+    ("siwe.not_yet_valid", "Demande pas encore valide"),
+    ("siwe.not_yet_valid_hint", "Le message ne devient valide que plus tard et peut donc être utilisé après votre départ du site."),
+    ("siwe.invalid_time", "Date invalide"),
+    ("siwe.invalid_time_hint", "Une des dates du message est mal formée ; sa période de validité ne peut pas être vérifiée."),
+    ("siwe.unbound", "Site non vérifié"),
+    ("siwe.unbound_hint", "Cette demande provient de WalletConnect ; le wallet ne peut pas confirmer qu'elle a été envoyée par le site indiqué dans le message."),
+    ("siwe.chain_unknown", "Réseau non vérifié"),
+    ("siwe.chain_unknown_hint", "La demande n'indique pas à quel réseau le site est connecté, la chaîne du message n'a donc pas pu être vérifiée."),
+    ("siwe.malformed", "Message de connexion mal formé"),
+    ("siwe.malformed_hint", "Cela ressemble à un message Sign-In with Ethereum mais il ne respecte pas la norme ({error}), le site, le compte et le réseau auxquels il donne accès ne peuvent donc pas être vérifiés. Les sites légitimes n'envoient pas de messages de connexion mal formés."),
+    // Signature verification tool
+    ("verify.title", "Vérifier une signature"),
+    ("verify.open", "Ouvrir l'outil de vérification"),
+    ("verify.hint", "Vérifiez qui a signé un message. Fonctionne avec les comptes classiques et les smart wallets (ERC-1271), y compris ceux pas encore déployés (ERC-6492)."),
+    ("verify.network", "Réseau"),
+    ("verify.signer", "Adresse du signataire"),
+    ("verify.message_type", "Type de message"),
+    ("verify.kind_personal", "Message (personal_sign)"),
+    ("verify.kind_typed", "Données typées (JSON EIP-712)"),
+    ("verify.kind_hash", "Hash (32 octets)"),
+    ("verify.message", "Message"),
+    ("verify.signature", "Signature"),
+    ("verify.check", "Vérifier"),
+    ("verify.checking", "Vérification..."),
+    ("verify.valid", "Signature valide"),
+    ("verify.invalid", "Signature invalide"),
+    ("verify.method", "Vérifiée avec"),
+    ("verify.recovered", "Signataire réel"),
+    ("verify.no_rpc", "Aucun endpoint RPC pour ce réseau"),
+    // Approval discovery
+    ("approvals.all_items", "Tous les objets"),
+    ("approvals.scanned_to", "Historique analysé jusqu'au bloc {block}"),
+    ("approvals.scan_more", "Analyser les blocs plus anciens"),
+    ("approvals.scan_error", "Analyse des logs interrompue :"),
+    ("approvals.found", "{n} approbations actives"),
+    ("approvals.select_all", "Tout sélectionner"),
+    ("approvals.unknown_spender", "Contrat inconnu"),
+    ("approvals.spender", "Dépensier"),
+    ("approvals.allowance", "Allocation"),
+    ("approvals.revoke_selected", "Révoquer la sélection ({n})"),
+    ("approvals.confirm_revoke", "Révoquer {n} approbations ? Une transaction est envoyée pour chacune."),
+    ("approvals.revoked_count", "{n} transactions de révocation envoyées"),
+    // SPL token transfers
+    ("send.ata_rent", "inclut le loyer du nouveau compte de jetons du destinataire"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Connexion du wallet Solana"),
+    ("approve.solana_sign_tx", "Signer une transaction Solana"),
+    ("approve.solana_sign_all", "Signer des transactions Solana"),
+    ("approve.solana_send_tx", "Envoyer une transaction Solana"),
+    ("approve.solana_sign_message", "Signer un message (Solana)"),
+    ("approve.solana_sign_in", "Connexion avec Solana"),
+    ("approve.solana_disguised_tx", "Ce message est une transaction"),
+    ("approve.solana_disguised_tx_hint", "Le site demande de signer des octets qui forment une transaction Solana. Les signer autoriserait cette transaction, Rusby refusera donc."),
+    ("approve.solana_tx", "Transaction"),
+    ("approve.solana_tx_n", "Transaction {n} sur {count}"),
+    ("approve.solana_decode_failed", "Impossible de décoder la transaction"),
+    ("solana_review.raw_units", "unités (jeton inconnu)"),
+    ("solana_review.you", "vous"),
+    ("solana_review.sol_transfer", "Transfert de SOL"),
+    ("solana_review.create_account", "Créer un compte"),
+    ("solana_review.assign", "Attribuer le compte à un programme"),
+    ("solana_review.token_transfer", "Transfert de jetons"),
+    ("solana_review.token_approve", "Approbation d'un délégué de jetons"),
+    ("solana_review.set_authority", "Changer l'autorité du compte de jetons"),
+    ("solana_review.none", "aucune"),
+    ("solana_review.close_account", "Fermer le compte de jetons"),
+    ("solana_review.create_token_account", "Créer un compte de jetons"),
+    ("solana_review.memo", "Mémo"),
+    ("solana_review.compute_limit", "Limite d'unités de calcul"),
+    ("solana_review.priority_fee", "Frais de priorité"),
+    ("solana_review.program_call", "Appel de programme"),
+    ("solana_review.not_signer", "Pas votre transaction"),
+    ("solana_review.not_signer_hint", "Votre compte ne fait pas partie des signataires de cette transaction, elle ne peut donc pas être signée."),
+    ("solana_review.risk_assign", "Changement de propriétaire du compte"),
+    ("solana_review.risk_assign_hint", "Votre compte serait confié à un autre programme, qui pourrait ensuite déplacer tous ses SOL. Les drainers utilisent cette technique."),
+    ("solana_review.risk_authority", "Transfert d'autorité du compte de jetons"),
+    ("solana_review.risk_authority_hint", "Le contrôle d'un de vos comptes de jetons passerait à une autre adresse, avec tous les jetons qu'il contient maintenant ou plus tard."),
+    ("solana_review.risk_delegate", "Autorisation de dépense de jetons"),
+    ("solana_review.risk_delegate_hint", "Un délégué pourrait déplacer vos jetons plus tard sans redemander."),
+    ("solana_review.risk_close", "Compte de jetons fermé"),
+    ("solana_review.risk_close_hint", "Un de vos comptes de jetons est fermé et son dépôt de loyer part vers une autre adresse."),
+    ("solana_review.risk_unknown_program", "Programme inconnu"),
+    ("solana_review.sponsored", "Payés par"),
+    ("solana_review.outgoing", "SOL envoyés depuis votre compte"),
+    ("solana_review.network_fee", "Frais de réseau"),
+    // Solana priority fees
+    ("fee.solana_priority", "Frais de priorité"),
+    ("fee.per_compute_unit", "micro-lamports par unité de calcul"),
+    ("fee.compute_units", "unités de calcul"),
+    // Native SOL staking
+    ("dashboard.stake", "Staker"),
+    ("staking.title", "Staking SOL"),
+    ("staking.about", "Staking natif"),
+    ("staking.about_desc", "Le stake reste dans des comptes de stake que vous contrôlez. Le nouveau stake s'active à l'époque suivante ; le stake désactivé peut être retiré à la fin de l'époque."),
+    ("staking.total_staked", "Total staké"),
+    ("staking.epoch", "Époque"),
+    ("staking.accounts", "Comptes de stake"),
+    ("staking.loading", "Chargement des comptes de stake..."),
+    ("staking.no_accounts", "Aucun compte de stake"),
+    ("staking.load_error", "Impossible de charger les comptes de stake :"),
+    ("staking.validators_error", "Impossible de charger les validateurs :"),
+    ("staking.undelegated", "Non délégué"),
+    ("staking.activating", "En activation"),
+    ("staking.active", "Actif"),
+    ("staking.deactivating", "En désactivation"),
+    ("staking.inactive", "Inactif"),
+    ("staking.validator", "Validateur"),
+    ("staking.locked", "Verrouillage actif : retraits bloqués"),
+    ("staking.stake", "Staker"),
+    ("staking.delegate", "Déléguer"),
+    ("staking.deactivate", "Désactiver"),
+    ("staking.withdraw", "Retirer"),
+    ("staking.split", "Diviser"),
+    ("staking.merge", "Fusionner"),
+    ("staking.new_stake", "Nouveau stake"),
+    ("staking.rent_note", "Environ 0,0023 SOL du montant reste dans le compte comme réserve de rent."),
+    ("staking.search_validator", "Rechercher une adresse de vote ou d'identité"),
+    ("staking.loading_validators", "Chargement des validateurs..."),
+    ("staking.delinquent", "défaillant"),
+    ("staking.pick_validator", "Sélectionnez d'abord un validateur"),
+    ("staking.exceeds_withdrawable", "Le montant dépasse le solde retirable"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Mémo (facultatif)"),
+    ("send.memo_placeholder", "Requis par certaines plateformes d'échange"),
+    ("send.token_transfer_fee", "frais de transfert du jeton (déduits du montant)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "Gérer TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Retour"),
+    ("tc.connect_dapp", "Collez un lien TON Connect (tc://) d'une dApp"),
+    ("tc.open_link", "Ouvrir le lien"),
+    ("tc.invalid_link", "Lien invalide :"),
+    ("tc.loading_manifest", "Chargement du manifeste de l'app..."),
+    ("tc.manifest_error", "Impossible de charger le manifeste :"),
+    ("tc.connect_request", "souhaite se connecter à votre wallet TON"),
+    ("tc.proof_requested", "Preuve de connexion demandée pour"),
+    ("tc.password", "Mot de passe"),
+    ("tc.approve", "Approuver"),
+    ("tc.reject", "Refuser"),
+    ("tc.connected", "Connecté à"),
+    ("tc.error", "Erreur :"),
+    ("tc.tx_requests", "Demandes de transaction"),
+    ("tc.payload", "avec payload"),
+    ("tc.state_init", "déploie un contrat (StateInit)"),
+    ("tc.tx_sent", "Transaction envoyée"),
+    ("tc.sessions", "dApps connectées"),
+    ("tc.no_sessions", "Aucune dApp connectée"),
+    ("tc.disconnect", "Déconnecter"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "Contrat de portefeuille TON"),
+    ("settings.ton_wallet_version_hint", "La même clé possède un portefeuille TON par version. Choisissez celui à afficher et depuis lequel envoyer."),
 ];
@@ -124,18 +124,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Wallet non trovato"),
     ("approve.active_wallet_not_found", "Wallet attivo non trovato"),
     ("approve.seed_invalid", "Seed non valido"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction via dApp non ancora supportato \u{2014} usa il wallet direttamente"),
     ("approve.method_unsupported", "Metodo non supportato:"),
     ("approve.error_parsing_wallet", "Errore parsing wallet:"),
     ("approve.error_parsing_params", "Errore parsing params:"),
     ("approve.missing_message_param", "Parametro messaggio mancante"),
     ("approve.invalid_hex", "Hex non valido:"),
     ("approve.missing_typed_data", "Parametro typed data mancante"),
-    ("approve.missing_domain", "Campo domain mancante"),
-    ("approve.missing_message_field", "Campo message mancante"),
-    ("approve.missing_primary_type", "Campo primaryType mancante"),
-    ("approve.missing_types", "Campo types mancante"),
-    ("approve.type_not_found", "Tipo non trovato:"),
 
     // WalletConnect
     ("wc.back", "Indietro"),
@@ -182,10 +176,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     // Token Approvals
     ("approvals.title", "Approvazioni Token"),
     ("approvals.management", "Gestione Approvazioni"),
-    ("approvals.management_desc", "Controlla e revoca le approvazioni ERC-20 ai contratti DEX. Le approvazioni illimitate possono essere un rischio di sicurezza."),
+    ("approvals.management_desc", "Le approvazioni vengono individuate dagli eventi Approval on-chain del tuo indirizzo. Le approvazioni illimitate possono essere un rischio per la sicurezza."),
     ("approvals.evm_only", "Le approvazioni token sono disponibili solo per chain EVM."),
     ("approvals.scanning", "Scansione approvazioni in corso..."),
-    ("approvals.no_approvals", "Nessuna approvazione attiva trovata per i token conosciuti."),
+    ("approvals.no_approvals", "Nessuna approvazione attiva trovata."),
     ("approvals.no_rpc", "Nessun RPC URL disponibile"),
     ("approvals.revoke", "Revoca"),
     ("approvals.revoke_error", "Errore encoding revoke:"),
@@ -359,4 +353,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "Cifratura AES-256-GCM"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600.000 round)"),
     ("security.local_only", "Le chiavi non lasciano mai il tuo dispositivo"),
+    // EVM fees
+    ("fee.title", "Commissione di rete"),
+    ("fee.slow", "Lenta"),
+    ("fee.normal", "Normale"),
+    ("fee.fast", "Veloce"),
+    ("fee.custom", "Personalizzata"),
+    ("fee.max_fee", "Fee massima (Gwei)"),
+    ("fee.priority_fee", "Fee di priorità (Gwei)"),
+    ("fee.gas_limit", "Limite gas"),
+    ("fee.estimated", "Commissione stimata"),
+    ("fee.max", "max"),
+    ("fee.loading", "Caricamento commissioni..."),
+    ("fee.unavailable", "Dati commissioni non disponibili:"),
+    ("fee.invalid", "Seleziona una commissione di rete valida"),
+    ("approve.unsupported_chain", "La dApp ha richiesto una rete non supportata"),
+    ("approve.missing_chain", "La dApp non ha indicato quale rete usare"),
+    // Pending EVM transactions
+    ("history.pending", "In attesa"),
+    ("history.pending_tx", "In attesa"),
+    ("history.sped_up", "Accelerata"),
+    ("history.cancelling", "Annullamento"),
+    ("history.speed_up", "Accelera"),
+    ("history.cancel_tx", "Annulla"),
+    ("history.speed_up_hint", "Reinvia la stessa transazione con commissioni più alte (almeno +10%)."),
+    ("history.cancel_hint", "Sostituisce la transazione con un invio di valore 0 a te stesso. Funziona solo se non viene minata prima."),
+    // Custom EVM networks
+    ("networks.title", "Reti personalizzate"),
+    ("networks.manage", "Gestisci reti"),
+    ("networks.hint", "Aggiungi qualsiasi chain compatibile EVM. Il tuo indirizzo Ethereum è usato su tutte le reti EVM."),
+    ("networks.add", "+ Aggiungi"),
+    ("networks.chain_id", "Chain ID"),
+    ("networks.name", "Nome rete"),
+    ("networks.ticker", "Simbolo valuta"),
+    ("networks.rpc_url", "URL RPC"),
+    ("networks.explorer_url", "Block explorer (opzionale)"),
+    ("networks.save", "Salva rete"),
+    ("networks.delete", "Rimuovi"),
+    ("networks.empty", "Nessuna rete personalizzata"),
+    ("networks.invalid_chain_id", "Il Chain ID deve essere un numero positivo"),
+    ("networks.checking_rpc", "Verifica RPC..."),
+    ("approve.add_network", "Aggiungi rete"),
+    ("approve.switch_network", "Cambia rete"),
+    ("approve.add_network_warning", "Una rete malevola può mostrare saldi e transazioni falsi. Aggiungi solo reti da siti di cui ti fidi."),
+    // ENS
+    ("ens.resolving", "Risoluzione nome ENS..."),
+    ("ens.not_resolved", "Attendi la risoluzione del nome ENS prima di inviare"),
+    ("approve.to", "A"),
+    // Safe multisig
+    ("safe.title", "Safe multisig"),
+    ("safe.manage", "Gestisci Safe"),
+    ("safe.add", "+ Aggiungi"),
+    ("safe.hint", "Monitora un Safe di cui sei co-proprietario per firmare, eseguire e proporre transazioni."),
+    ("safe.address", "Indirizzo Safe"),
+    ("safe.name", "Nome (facoltativo)"),
+    ("safe.network", "Rete"),
+    ("safe.save", "Aggiungi Safe"),
+    ("safe.empty", "Nessun Safe"),
+    ("safe.checking", "Verifica del Safe..."),
+    ("safe.already_added", "Questo Safe è già nella lista"),
+    ("safe.not_a_safe", "Non è un Safe su questa rete:"),
+    ("safe.no_rpc", "Nessun RPC per questa rete"),
+    ("safe.info_error", "Impossibile leggere il Safe:"),
+    ("safe.queue_error", "Impossibile caricare la coda:"),
+    ("safe.threshold", "Soglia"),
+    ("safe.you_are_owner", "Questo wallet è un proprietario"),
+    ("safe.not_owner", "Solo visualizzazione: questo wallet non è un proprietario"),
+    ("safe.queue", "Transazioni in attesa"),
+    ("safe.queue_empty", "Nulla in attesa di firme"),
+    ("safe.contract_call", "Chiamata a contratto"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: il destinatario esegue codice con pieno controllo del Safe"),
+    ("safe.confirmations", "Firme"),
+    ("safe.sign", "Firma"),
+    ("safe.execute", "Esegui"),
+    ("safe.propose", "Nuovo trasferimento dal Safe"),
+    ("safe.propose_button", "Proponi e firma"),
+    ("safe.signed", "Firma aggiunta"),
+    ("safe.proposed", "Proposta con nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Permit token (approvazione off-chain)"),
+    ("permit.kind_permit2", "Allowance Permit2"),
+    ("permit.kind_permit2_transfer", "Trasferimento token Permit2"),
+    ("permit.kind_seaport", "Inserzione Seaport"),
+    ("permit.spender", "Spender"),
+    ("permit.expiry", "Scadenza"),
+    ("permit.unlimited", "Illimitato"),
+    ("permit.never", "Mai"),
+    ("permit.expired", "Già scaduto"),
+    ("permit.in", "tra"),
+    ("permit.high_risk_title", "Questa firma può svuotare i tuoi token"),
+    ("permit.review_hint", "Non viene inviata alcuna transazione, ma dopo la firma lo spender può spostare questi token."),
+    ("permit.risk_unlimited", "Importo illimitato."),
+    ("permit.risk_unknown_spender", "Lo spender non è un contratto noto."),
+    ("permit.risk_far_expiry", "Resta valida per moltissimo tempo."),
+    ("permit.risk_no_payment", "Non ricevi nulla in cambio degli oggetti messi in vendita."),
+    // Transaction simulation
+    ("sim.title", "Variazioni di saldo stimate"),
+    ("sim.would_fail", "Questa transazione probabilmente fallirà"),
+    ("sim.unavailable", "Variazioni non disponibili: questo RPC non supporta la simulazione."),
+    ("sim.no_changes", "Nessuna variazione di asset rilevata per il tuo account."),
+    ("sim.approvals", "Autorizzazioni concesse"),
+    ("sim.all_items", "Tutti gli oggetti"),
+    ("sim.revoked", "Revocata"),
+    ("sim.risky_approval", "Autorizzazione illimitata"),
+    ("sim.risky_approval_hint", "Lo spender potrà spostare questi asset in qualsiasi momento finché non revochi l'autorizzazione."),
+    ("sim.gas_excluded", "Commissione di rete esclusa."),
+    // NFT transfers
+    ("nft.send", "Invia NFT"),
+    ("nft.review", "Verifica trasferimento"),
+    ("nft.simulating", "Simulazione del trasferimento..."),
+    ("nft.simulation_ok", "Simulazione riuscita: l'NFT lascerà il tuo wallet."),
+    // Custom tokens
+    ("tokens.title", "Importa token"),
+    ("tokens.import", "+ Importa"),
+    ("tokens.address", "Indirizzo contratto / mint"),
+    ("tokens.lookup", "Cerca"),
+    ("tokens.looking_up", "Lettura metadati token..."),
+    ("tokens.lookup_failed", "Impossibile leggere il token"),
+    ("tokens.already_listed", "Questo token è già nella lista predefinita"),
+    ("tokens.warning_title", "Verifica l'indirizzo"),
+    ("tokens.warning", "Chiunque può creare un token con qualsiasi nome e simbolo. Importa solo indirizzi dalle fonti ufficiali del progetto."),
+    ("tokens.symbol", "Simbolo"),
+    ("tokens.name", "Nome"),
+    ("tokens.decimals", "Decimali"),
+    ("tokens.add", "Aggiungi token"),
+    ("tokens.remove", "Rimuovi"),
+    ("tokens.empty", "Nessun token importato su questa rete"),
+    ("tokens.unsupported_chain", "Questa rete non ha uno standard token supportato dal wallet"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Delega dell'account (EIP-7702)"),
+    ("approve.delegation_unknown", "Destinazione della delega sconosciuta"),
+    ("approve.delegation_unknown_hint", "Questo contratto controllerà il tuo account e tutti i suoi asset. Non è un delegato noto e verificato. Continua solo se ti fidi completamente di questa dApp."),
+    ("approve.delegation_any_chain", "Delega valida su tutte le chain"),
+    ("approve.delegation_any_chain_hint", "Questa autorizzazione usa chain id 0 e può essere riutilizzata su qualsiasi rete EVM in cui esiste il tuo account."),
+    ("approve.delegation_unknown_contract", "Contratto sconosciuto"),
+    ("approve.delegation_revoke", "Rimuovi la delega attuale"),
+    ("approve.delegation_all_chains", "Tutte le chain"),
+    ("approve.delegation_presigned", "Firmata da un altro account; inoltrata da questa transazione"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Richiesta di accesso"),
+    ("siwe.address", "Account"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Rete"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Emesso il"),
+    ("siwe.expires", "Scade"),
+    ("siwe.not_before", "Valido da"),
+    ("siwe.request_id", "ID richiesta"),
+    ("siwe.resources", "Risorse"),
+    ("siwe.domain_mismatch", "Accesso per un sito diverso"),
+    ("siwe.domain_mismatch_hint", "Questo messaggio ti fa accedere a {domain}, ma è stato richiesto da {origin}. Firmandolo {origin} può accedere al tuo account su {domain}. È una tecnica di phishing comune."),
+    ("siwe.scheme_mismatch", "Protocollo non corrispondente"),
+    ("siwe.scheme_mismatch_hint", "Il messaggio indica un protocollo (http/https) diverso da quello della pagina che lo ha richiesto."),
+    ("siwe.address_mismatch", "Account diverso"),
+    ("siwe.address_mismatch_hint", "Il messaggio è per un indirizzo che non è il tuo account attivo."),
+    ("siwe.chain_mismatch", "Rete diversa"),
+    ("siwe.chain_mismatch_hint", "Il messaggio è per la chain {message}, ma il sito è connesso alla chain {active}."),
+    ("siwe.invalid_nonce", "Nonce debole"),
+    ("siwe.invalid_nonce_hint", "Il nonce dovrebbe avere almeno 8 lettere o cifre casuali. Un nonce prevedibile permette di riutilizzare la firma."),
+    ("siwe.expired", "Richiesta di accesso scaduta"),
+    ("siwe.expired_hint", "La scadenza è passata. Ricarica il sito e accedi di nuovo."),
+    ("siwe.not_yet_valid", "Richiesta di accesso non ancora valida"),
+    ("siwe.not_yet_valid_hint", "Il messaggio diventa valido solo più tardi, quindi può essere usato dopo che hai lasciato il sito."),
+    ("siwe.invalid_time", "Data non valida"),
+    ("siwe.invalid_time_hint", "Una delle date del messaggio è malformata, quindi la validità non può essere verificata."),
+    ("siwe.unbound", "Sito non verificato"),
+    ("siwe.unbound_hint", "Questa richiesta arriva da WalletConnect, quindi il wallet non può confermare che sia stata inviata dal sito indicato nel messaggio."),
+    ("siwe.chain_unknown", "Rete non verificata"),
+    ("siwe.chain_unknown_hint", "La richiesta non indica a quale rete è connesso il sito, quindi la chain del messaggio non può essere verificata."),
+    ("siwe.malformed", "Messaggio di accesso non valido"),
+    ("siwe.malformed_hint", "Sembra un messaggio Sign-In with Ethereum ma non rispetta lo standard ({error}), quindi non è possibile verificare sito, account e rete a cui dà accesso. I siti legittimi non inviano messaggi di accesso non validi."),
+    // Signature verification tool
+    ("verify.title", "Verifica firma"),
+    ("verify.open", "Apri strumento di verifica"),
+    ("verify.hint", "Controlla chi ha firmato un messaggio. Funziona con account normali e smart wallet (ERC-1271), anche non ancora deployati (ERC-6492)."),
+    ("verify.network", "Rete"),
+    ("verify.signer", "Indirizzo del firmatario"),
+    ("verify.message_type", "Tipo di messaggio"),
+    ("verify.kind_personal", "Messaggio (personal_sign)"),
+    ("verify.kind_typed", "Dati tipizzati (JSON EIP-712)"),
+    ("verify.kind_hash", "Hash (32 byte)"),
+    ("verify.message", "Messaggio"),
+    ("verify.signature", "Firma"),
+    ("verify.check", "Verifica"),
+    ("verify.checking", "Verifica in corso..."),
+    ("verify.valid", "Firma valida"),
+    ("verify.invalid", "Firma non valida"),
+    ("verify.method", "Verificata con"),
+    ("verify.recovered", "Firmatario effettivo"),
+    ("verify.no_rpc", "Nessun endpoint RPC per questa rete"),
+    // Approval discovery
+    ("approvals.all_items", "Tutti gli oggetti"),
+    ("approvals.scanned_to", "Cronologia analizzata fino al blocco {block}"),
+    ("approvals.scan_more", "Analizza blocchi precedenti"),
+    ("approvals.scan_error", "Analisi dei log interrotta:"),
+    ("approvals.found", "{n} approvazioni attive"),
+    ("approvals.select_all", "Seleziona tutto"),
+    ("approvals.unknown_spender", "Contratto sconosciuto"),
+    ("approvals.spender", "Spender"),
+    ("approvals.allowance", "Limite"),
+    ("approvals.revoke_selected", "Revoca selezionate ({n})"),
+    ("approvals.confirm_revoke", "Revocare {n} approvazioni? Viene inviata una transazione per ciascuna."),
+    ("approvals.revoked_count", "{n} transazioni di revoca inviate"),
+    // SPL token transfers
+    ("send.ata_rent", "include l'affitto del nuovo token account del destinatario"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Connessione wallet Solana"),
+    ("approve.solana_sign_tx", "Firma transazione Solana"),
+    ("approve.solana_sign_all", "Firma transazioni Solana"),
+    ("approve.solana_send_tx", "Invia transazione Solana"),
+    ("approve.solana_sign_message", "Firma messaggio (Solana)"),
+    ("approve.solana_sign_in", "Accesso con Solana"),
+    ("approve.solana_disguised_tx", "Questo messaggio è una transazione"),
+    ("approve.solana_disguised_tx_hint", "Il sito ha chiesto di firmare byte che formano una transazione Solana. Firmarli autorizzerebbe la transazione, quindi Rusby rifiuterà."),
+    ("approve.solana_tx", "Transazione"),
+    ("approve.solana_tx_n", "Transazione {n} di {count}"),
+    ("approve.solana_decode_failed", "Impossibile decodificare la transazione"),
+    ("solana_review.raw_units", "unità (token sconosciuto)"),
+    ("solana_review.you", "tu"),
+    ("solana_review.sol_transfer", "Trasferimento SOL"),
+    ("solana_review.create_account", "Crea account"),
+    ("solana_review.assign", "Assegna account a un programma"),
+    ("solana_review.token_transfer", "Trasferimento token"),
+    ("solana_review.token_approve", "Approvazione delegato token"),
+    ("solana_review.set_authority", "Cambio autorità account token"),
+    ("solana_review.none", "nessuna"),
+    ("solana_review.close_account", "Chiudi account token"),
+    ("solana_review.create_token_account", "Crea account token"),
+    ("solana_review.memo", "Memo"),
+    ("solana_review.compute_limit", "Limite unità di calcolo"),
+    ("solana_review.priority_fee", "Commissione di priorità"),
+    ("solana_review.program_call", "Chiamata a programma"),
+    ("solana_review.not_signer", "Transazione non tua"),
+    ("solana_review.not_signer_hint", "Il tuo account non è tra i firmatari di questa transazione, quindi non può essere firmata."),
+    ("solana_review.risk_assign", "Cambio di proprietario dell'account"),
+    ("solana_review.risk_assign_hint", "Il tuo account verrebbe ceduto a un altro programma, che potrebbe poi spostare tutti i suoi SOL. I drainer usano questa tecnica."),
+    ("solana_review.risk_authority", "Cessione dell'autorità dell'account token"),
+    ("solana_review.risk_authority_hint", "Il controllo di un tuo account token passerebbe a un altro indirizzo, insieme a tutti i token che contiene ora o in futuro."),
+    ("solana_review.risk_delegate", "Autorizzazione di spesa token"),
+    ("solana_review.risk_delegate_hint", "Un delegato potrebbe spostare i tuoi token in seguito senza chiedere di nuovo."),
+    ("solana_review.risk_close", "Account token chiuso"),
+    ("solana_review.risk_close_hint", "Un tuo account token viene chiuso e il deposito per l'affitto va a un altro indirizzo."),
+    ("solana_review.risk_unknown_program", "Programma sconosciuto"),
+    ("solana_review.sponsored", "Pagata da"),
+    ("solana_review.outgoing", "SOL inviati dal tuo account"),
+    ("solana_review.network_fee", "Commissione di rete"),
+    // Solana priority fees
+    ("fee.solana_priority", "Commissione di priorità"),
+    ("fee.per_compute_unit", "micro-lamport per unità di calcolo"),
+    ("fee.compute_units", "unità di calcolo"),
+    // Native SOL staking
+    ("dashboard.stake", "Stake"),
+    ("staking.title", "Staking SOL"),
+    ("staking.about", "Staking nativo"),
+    ("staking.about_desc", "Lo stake resta in account di stake che controlli tu. Il nuovo stake si attiva all'epoca successiva; quello disattivato si può prelevare a fine epoca."),
+    ("staking.total_staked", "Totale in stake"),
+    ("staking.epoch", "Epoca"),
+    ("staking.accounts", "Account di stake"),
+    ("staking.loading", "Caricamento account di stake..."),
+    ("staking.no_accounts", "Nessun account di stake"),
+    ("staking.load_error", "Impossibile caricare gli account di stake:"),
+    ("staking.validators_error", "Impossibile caricare i validatori:"),
+    ("staking.undelegated", "Non delegato"),
+    ("staking.activating", "In attivazione"),
+    ("staking.active", "Attivo"),
+    ("staking.deactivating", "In disattivazione"),
+    ("staking.inactive", "Inattivo"),
+    ("staking.validator", "Validatore"),
+    ("staking.locked", "Lockup attivo: prelievi bloccati"),
+    ("staking.stake", "Metti in stake"),
+    ("staking.delegate", "Delega"),
+    ("staking.deactivate", "Disattiva"),
+    ("staking.withdraw", "Preleva"),
+    ("staking.split", "Dividi"),
+    ("staking.merge", "Unisci"),
+    ("staking.new_stake", "Nuovo stake"),
+    ("staking.rent_note", "Circa 0,0023 SOL dell'importo restano nell'account come riserva di rent."),
+    ("staking.search_validator", "Cerca indirizzo di voto o identità"),
+    ("staking.loading_validators", "Caricamento validatori..."),
+    ("staking.delinquent", "inattivo"),
+    ("staking.pick_validator", "Seleziona prima un validatore"),
+    ("staking.exceeds_withdrawable", "L'importo supera il saldo prelevabile"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Memo (opzionale)"),
+    ("send.memo_placeholder", "Richiesto da alcuni exchange"),
+    ("send.token_transfer_fee", "commissione di trasferimento del token (detratta dall'importo)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "Gestisci TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Indietro"),
+    ("tc.connect_dapp", "Incolla un link TON Connect (tc://) di una dApp"),
+    ("tc.open_link", "Apri link"),
+    ("tc.invalid_link", "Link non valido:"),
+    ("tc.loading_manifest", "Caricamento manifest dell'app..."),
+    ("tc.manifest_error", "Impossibile caricare il manifest dell'app:"),
+    ("tc.connect_request", "vuole connettersi al tuo wallet TON"),
+    ("tc.proof_requested", "Prova di accesso richiesta per"),
+    ("tc.password", "Password"),
+    ("tc.approve", "Approva"),
+    ("tc.reject", "Rifiuta"),
+    ("tc.connected", "Connesso a"),
+    ("tc.error", "Errore:"),
+    ("tc.tx_requests", "Richieste di transazione"),
+    ("tc.payload", "con payload"),
+    ("tc.state_init", "esegue il deploy di un contratto (StateInit)"),
+    ("tc.tx_sent", "Transazione inviata"),
+    ("tc.sessions", "dApp connesse"),
+    ("tc.no_sessions", "Nessuna dApp connessa"),
+    ("tc.disconnect", "Disconnetti"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "Contratto Wallet TON"),
+    ("settings.ton_wallet_version_hint", "La stessa chiave possiede un wallet TON per versione. Scegli quello da mostrare e da cui inviare."),
 ];
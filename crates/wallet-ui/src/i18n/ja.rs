@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "ウォレットが見つかりません"),
     ("approve.active_wallet_not_found", "アクティブなウォレットが見つかりません"),
     ("approve.seed_invalid", "無効なシード"),
-    ("approve.sendtx_unsupported", "dApp 経由の eth_sendTransaction はまだサポートされていません \u{2014} ウォレットから直接操作してください"),
     ("approve.method_unsupported", "サポートされていないメソッド："),
     ("approve.error_parsing_wallet", "ウォレットの解析エラー："),
     ("approve.error_parsing_params", "パラメータの解析エラー："),
     ("approve.missing_message_param", "メッセージパラメータが不足しています"),
     ("approve.invalid_hex", "無効な 16 進数："),
     ("approve.missing_typed_data", "型付きデータパラメータが不足しています"),
-    ("approve.missing_domain", "domain フィールドが不足しています"),
-    ("approve.missing_message_field", "message フィールドが不足しています"),
-    ("approve.missing_primary_type", "primaryType フィールドが不足しています"),
-    ("approve.missing_types", "types フィールドが不足しています"),
-    ("approve.type_not_found", "型が見つかりません："),
     ("wc.back", "戻る"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "設定"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "不明な dApp"),
     ("approvals.title", "トークン承認"),
     ("approvals.management", "承認管理"),
-    ("approvals.management_desc", "DEX コントラクトへの ERC-20 承認を確認・取り消しできます。無制限の承認はセキュリティリスクとなる可能性があります。"),
+    ("approvals.management_desc", "承認はあなたのアドレスのオンチェーン Approval イベントから検出されます。無制限の承認はセキュリティリスクとなる可能性があります。"),
     ("approvals.evm_only", "トークン承認は EVM チェーンでのみ利用可能です。"),
     ("approvals.scanning", "承認をスキャン中..."),
-    ("approvals.no_approvals", "既知のトークンに対するアクティブな承認は見つかりませんでした。"),
+    ("approvals.no_approvals", "有効な承認は見つかりませんでした。"),
     ("approvals.no_rpc", "利用可能な RPC URL がありません"),
     ("approvals.revoke", "取り消し"),
     ("approvals.revoke_error", "取り消しのエンコードエラー："),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "AES-256-GCM暗号化"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600,000ラウンド)"),
     ("security.local_only", "鍵はデバイスから外に出ません"),
+    // EVM fees
+    ("fee.title", "ネットワーク手数料"),
+    ("fee.slow", "低速"),
+    ("fee.normal", "標準"),
+    ("fee.fast", "高速"),
+    ("fee.custom", "カスタム"),
+    ("fee.max_fee", "最大手数料 (Gwei)"),
+    ("fee.priority_fee", "優先手数料 (Gwei)"),
+    ("fee.gas_limit", "ガスリミット"),
+    ("fee.estimated", "推定手数料"),
+    ("fee.max", "最大"),
+    ("fee.loading", "手数料を読み込み中..."),
+    ("fee.unavailable", "手数料データを取得できません:"),
+    ("fee.invalid", "有効なネットワーク手数料を選択してください"),
+    ("approve.unsupported_chain", "dApp がサポートされていないネットワークを要求しました"),
+    ("approve.missing_chain", "dApp が使用するネットワークを指定していません"),
+    // Pending EVM transactions
+    ("history.pending", "保留中"),
+    ("history.pending_tx", "保留中"),
+    ("history.sped_up", "高速化済み"),
+    ("history.cancelling", "キャンセル中"),
+    ("history.speed_up", "高速化"),
+    ("history.cancel_tx", "キャンセル"),
+    ("history.speed_up_hint", "同じトランザクションをより高い手数料（+10%以上）で再送信します。"),
+    ("history.cancel_hint", "トランザクションを自分宛ての 0 送金に置き換えます。先にマイニングされた場合は無効です。"),
+    // Custom EVM networks
+    ("networks.title", "カスタムネットワーク"),
+    ("networks.manage", "ネットワークを管理"),
+    ("networks.hint", "EVM 互換チェーンを追加できます。すべての EVM ネットワークで Ethereum アドレスが使われます。"),
+    ("networks.add", "+ 追加"),
+    ("networks.chain_id", "チェーン ID"),
+    ("networks.name", "ネットワーク名"),
+    ("networks.ticker", "通貨シンボル"),
+    ("networks.rpc_url", "RPC URL"),
+    ("networks.explorer_url", "ブロックエクスプローラー（任意）"),
+    ("networks.save", "ネットワークを保存"),
+    ("networks.delete", "削除"),
+    ("networks.empty", "カスタムネットワークはまだありません"),
+    ("networks.invalid_chain_id", "チェーン ID は正の数である必要があります"),
+    ("networks.checking_rpc", "RPC を確認中..."),
+    ("approve.add_network", "ネットワークを追加"),
+    ("approve.switch_network", "ネットワークを切り替え"),
+    ("approve.add_network_warning", "悪意のあるネットワークは偽の残高や取引を表示する可能性があります。信頼できるサイトのネットワークのみ追加してください。"),
+    // ENS
+    ("ens.resolving", "ENS 名を解決中..."),
+    ("ens.not_resolved", "送信前に ENS 名の解決を待ってください"),
+    ("approve.to", "宛先"),
+    // Safe multisig
+    ("safe.title", "Safe マルチシグ"),
+    ("safe.manage", "Safe を管理"),
+    ("safe.add", "+ 追加"),
+    ("safe.hint", "共同所有する Safe を登録して、トランザクションの署名・実行・提案を行います。"),
+    ("safe.address", "Safe アドレス"),
+    ("safe.name", "名前（任意）"),
+    ("safe.network", "ネットワーク"),
+    ("safe.save", "Safe を追加"),
+    ("safe.empty", "Safe はまだありません"),
+    ("safe.checking", "Safe を確認中..."),
+    ("safe.already_added", "この Safe は既に登録されています"),
+    ("safe.not_a_safe", "このネットワーク上の Safe ではありません："),
+    ("safe.no_rpc", "このネットワークの RPC がありません"),
+    ("safe.info_error", "Safe を読み込めません："),
+    ("safe.queue_error", "キューを読み込めません："),
+    ("safe.threshold", "しきい値"),
+    ("safe.you_are_owner", "このウォレットはオーナーです"),
+    ("safe.not_owner", "閲覧のみ：このウォレットはオーナーではありません"),
+    ("safe.queue", "保留中のトランザクション"),
+    ("safe.queue_empty", "署名待ちはありません"),
+    ("safe.contract_call", "コントラクト呼び出し"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL：対象のコードが Safe を完全に制御して実行されます"),
+    ("safe.confirmations", "署名"),
+    ("safe.sign", "署名"),
+    ("safe.execute", "実行"),
+    ("safe.propose", "Safe からの新しい送金"),
+    ("safe.propose_button", "提案して署名"),
+    ("safe.signed", "署名を追加しました"),
+    ("safe.proposed", "提案しました。nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "トークン Permit（オフチェーン承認）"),
+    ("permit.kind_permit2", "Permit2 許可額"),
+    ("permit.kind_permit2_transfer", "Permit2 トークン送金"),
+    ("permit.kind_seaport", "Seaport 出品"),
+    ("permit.spender", "使用者"),
+    ("permit.expiry", "有効期限"),
+    ("permit.unlimited", "無制限"),
+    ("permit.never", "無期限"),
+    ("permit.expired", "期限切れ"),
+    ("permit.in", "残り"),
+    ("permit.high_risk_title", "この署名でトークンが抜き取られる恐れがあります"),
+    ("permit.review_hint", "トランザクションは送信されませんが、署名すると使用者がこれらのトークンを移動できます。"),
+    ("permit.risk_unlimited", "金額が無制限です。"),
+    ("permit.risk_unknown_spender", "使用者は既知のコントラクトではありません。"),
+    ("permit.risk_far_expiry", "非常に長期間有効です。"),
+    ("permit.risk_no_payment", "出品したアイテムの対価を何も受け取れません。"),
+    // Transaction simulation
+    ("sim.title", "予想される残高の変化"),
+    ("sim.would_fail", "このトランザクションは失敗する見込みです"),
+    ("sim.unavailable", "資産の変化を取得できません：この RPC はシミュレーションに対応していません。"),
+    ("sim.no_changes", "アカウントの資産変化は検出されませんでした。"),
+    ("sim.approvals", "付与される承認"),
+    ("sim.all_items", "すべてのアイテム"),
+    ("sim.revoked", "取り消し"),
+    ("sim.risky_approval", "無制限の承認"),
+    ("sim.risky_approval_hint", "承認を取り消すまで、使用者はいつでもこれらの資産を移動できます。"),
+    ("sim.gas_excluded", "ネットワーク手数料は含まれません。"),
+    // NFT transfers
+    ("nft.send", "NFT を送信"),
+    ("nft.review", "送信内容を確認"),
+    ("nft.simulating", "送信をシミュレーション中..."),
+    ("nft.simulation_ok", "シミュレーション成功：NFT はウォレットから送信されます。"),
+    // Custom tokens
+    ("tokens.title", "トークンをインポート"),
+    ("tokens.import", "+ インポート"),
+    ("tokens.address", "コントラクト / ミントアドレス"),
+    ("tokens.lookup", "検索"),
+    ("tokens.looking_up", "トークン情報を読み込み中..."),
+    ("tokens.lookup_failed", "トークンを読み取れません"),
+    ("tokens.already_listed", "このトークンは既定のリストにあります"),
+    ("tokens.warning_title", "アドレスを確認してください"),
+    ("tokens.warning", "誰でも任意の名前とシンボルでトークンを作成できます。プロジェクトの公式情報源にあるアドレスだけをインポートしてください。"),
+    ("tokens.symbol", "シンボル"),
+    ("tokens.name", "名前"),
+    ("tokens.decimals", "小数桁"),
+    ("tokens.add", "トークンを追加"),
+    ("tokens.remove", "削除"),
+    ("tokens.empty", "このネットワークにインポートしたトークンはありません"),
+    ("tokens.unsupported_chain", "このネットワークにはウォレットが対応するトークン規格がありません"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "アカウント委任 (EIP-7702)"),
+    ("approve.delegation_unknown", "不明な委任先"),
+    ("approve.delegation_unknown_hint", "このコントラクトはアカウントとその全資産を制御します。既知の監査済み委任先ではありません。この dApp を完全に信頼できる場合のみ続行してください。"),
+    ("approve.delegation_any_chain", "すべてのチェーンで有効な委任"),
+    ("approve.delegation_any_chain_hint", "この承認は chain id 0 を使用しており、アカウントが存在するすべての EVM ネットワークで再利用できます。"),
+    ("approve.delegation_unknown_contract", "不明なコントラクト"),
+    ("approve.delegation_revoke", "現在の委任を解除"),
+    ("approve.delegation_all_chains", "全チェーン"),
+    ("approve.delegation_presigned", "別のアカウントが署名済み。このトランザクションで中継されます"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "サインインリクエスト"),
+    ("siwe.address", "アカウント"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "ネットワーク"),
+    ("siwe.nonce", "ノンス"),
+    ("siwe.issued_at", "発行日時"),
+    ("siwe.expires", "有効期限"),
+    ("siwe.not_before", "有効開始"),
+    ("siwe.request_id", "リクエスト ID"),
+    ("siwe.resources", "リソース"),
+    ("siwe.domain_mismatch", "別のサイトへのサインイン"),
+    ("siwe.domain_mismatch_hint", "このメッセージは {domain} へのサインイン用ですが、{origin} から要求されました。署名すると {origin} があなたの {domain} アカウントにアクセスできる可能性があります。よくあるフィッシング手口です。"),
+    ("siwe.scheme_mismatch", "プロトコルの不一致"),
+    ("siwe.scheme_mismatch_hint", "メッセージのプロトコル（http/https）が要求元ページと異なります。"),
+    ("siwe.address_mismatch", "別のアカウント"),
+    ("siwe.address_mismatch_hint", "メッセージのアドレスはアクティブなアカウントではありません。"),
+    ("siwe.chain_mismatch", "別のネットワーク"),
+    ("siwe.chain_mismatch_hint", "メッセージはチェーン {message} 用ですが、サイトはチェーン {active} に接続しています。"),
+    ("siwe.invalid_nonce", "弱いノンス"),
+    ("siwe.invalid_nonce_hint", "ノンスは8文字以上のランダムな英数字である必要があります。予測可能なノンスでは署名が再利用される恐れがあります。"),
+    ("siwe.expired", "サインインリクエストの期限切れ"),
+    ("siwe.expired_hint", "有効期限が過ぎています。サイトを再読み込みして再度サインインしてください。"),
+    ("siwe.not_yet_valid", "サインインリクエストはまだ有効ではありません"),
+    ("siwe.not_yet_valid_hint", "このメッセージは後の時刻から有効になるため、サイトを離れた後に使われる可能性があります。"),
+    ("siwe.invalid_time", "無効な日付"),
+    ("siwe.invalid_time_hint", "メッセージのタイムスタンプが不正なため、有効期間を確認できません。"),
+    ("siwe.unbound", "サイト未確認"),
+    ("siwe.unbound_hint", "このリクエストは WalletConnect 経由のため、メッセージに記載されたサイトから送信されたことを確認できません。"),
+    ("siwe.chain_unknown", "ネットワーク未確認"),
+    ("siwe.chain_unknown_hint", "リクエストにサイトの接続先ネットワークが含まれていないため、メッセージのチェーンを確認できません。"),
+    ("siwe.malformed", "不正なサインインメッセージ"),
+    ("siwe.malformed_hint", "Sign-In with Ethereum メッセージのようですが規格に従っていません（{error}）。そのため、アクセスを許可するサイト、アカウント、ネットワークを確認できません。正規のサイトは不正なサインインメッセージを送りません。"),
+    // Signature verification tool
+    ("verify.title", "署名を検証"),
+    ("verify.open", "検証ツールを開く"),
+    ("verify.hint", "メッセージの署名者を確認します。通常アカウントとスマートコントラクトウォレット（ERC-1271）、未デプロイのもの（ERC-6492）にも対応します。"),
+    ("verify.network", "ネットワーク"),
+    ("verify.signer", "署名者アドレス"),
+    ("verify.message_type", "メッセージの種類"),
+    ("verify.kind_personal", "メッセージ（personal_sign）"),
+    ("verify.kind_typed", "型付きデータ（EIP-712 JSON）"),
+    ("verify.kind_hash", "ハッシュ（32 バイト）"),
+    ("verify.message", "メッセージ"),
+    ("verify.signature", "署名"),
+    ("verify.check", "検証"),
+    ("verify.checking", "検証中..."),
+    ("verify.valid", "有効な署名"),
+    ("verify.invalid", "無効な署名"),
+    ("verify.method", "検証方法"),
+    ("verify.recovered", "実際の署名者"),
+    ("verify.no_rpc", "このネットワークの RPC エンドポイントがありません"),
+    // Approval discovery
+    ("approvals.all_items", "すべてのアイテム"),
+    ("approvals.scanned_to", "ブロック {block} まで履歴をスキャン済み"),
+    ("approvals.scan_more", "古いブロックをスキャン"),
+    ("approvals.scan_error", "ログのスキャンが中断されました:"),
+    ("approvals.found", "有効な承認 {n} 件"),
+    ("approvals.select_all", "すべて選択"),
+    ("approvals.unknown_spender", "不明なコントラクト"),
+    ("approvals.spender", "スペンダー"),
+    ("approvals.allowance", "許可額"),
+    ("approvals.revoke_selected", "選択を取り消す（{n}）"),
+    ("approvals.confirm_revoke", "{n} 件の承認を取り消しますか？承認ごとに1件のトランザクションが送信されます。"),
+    ("approvals.revoked_count", "取り消しトランザクションを {n} 件送信しました"),
+    // SPL token transfers
+    ("send.ata_rent", "受取人の新しいトークンアカウントのレントを含む"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Solana ウォレット接続"),
+    ("approve.solana_sign_tx", "Solana トランザクションに署名"),
+    ("approve.solana_sign_all", "複数の Solana トランザクションに署名"),
+    ("approve.solana_send_tx", "Solana トランザクションを送信"),
+    ("approve.solana_sign_message", "メッセージに署名 (Solana)"),
+    ("approve.solana_sign_in", "Solana でサインイン"),
+    ("approve.solana_disguised_tx", "このメッセージはトランザクションです"),
+    ("approve.solana_disguised_tx_hint", "サイトが Solana トランザクションを構成するバイト列への署名を求めています。署名するとそのトランザクションが承認されるため、Rusby は拒否します。"),
+    ("approve.solana_tx", "トランザクション"),
+    ("approve.solana_tx_n", "トランザクション {n}/{count}"),
+    ("approve.solana_decode_failed", "トランザクションをデコードできません"),
+    ("solana_review.raw_units", "単位（不明なトークン）"),
+    ("solana_review.you", "あなた"),
+    ("solana_review.sol_transfer", "SOL 送金"),
+    ("solana_review.create_account", "アカウント作成"),
+    ("solana_review.assign", "アカウントをプログラムに割り当て"),
+    ("solana_review.token_transfer", "トークン送金"),
+    ("solana_review.token_approve", "トークン委任の承認"),
+    ("solana_review.set_authority", "トークンアカウントの権限を変更"),
+    ("solana_review.none", "なし"),
+    ("solana_review.close_account", "トークンアカウントを閉鎖"),
+    ("solana_review.create_token_account", "トークンアカウント作成"),
+    ("solana_review.memo", "メモ"),
+    ("solana_review.compute_limit", "コンピュートユニット上限"),
+    ("solana_review.priority_fee", "優先手数料"),
+    ("solana_review.program_call", "プログラム呼び出し"),
+    ("solana_review.not_signer", "あなたのトランザクションではありません"),
+    ("solana_review.not_signer_hint", "あなたのアカウントはこのトランザクションの署名者ではないため、署名できません。"),
+    ("solana_review.risk_assign", "アカウント所有者の変更"),
+    ("solana_review.risk_assign_hint", "ウォレットアカウントが別のプログラムに渡され、そのプログラムがすべての SOL を移動できるようになります。ドレイナーが使う手口です。"),
+    ("solana_review.risk_authority", "トークンアカウント権限の譲渡"),
+    ("solana_review.risk_authority_hint", "トークンアカウントの管理権が別のアドレスに移り、現在および将来の保有トークンもすべて含まれます。"),
+    ("solana_review.risk_delegate", "トークン支出の承認"),
+    ("solana_review.risk_delegate_hint", "委任先が後から確認なしでトークンを移動できるようになります。"),
+    ("solana_review.risk_close", "トークンアカウントの閉鎖"),
+    ("solana_review.risk_close_hint", "トークンアカウントが閉鎖され、レント預託金が別のアドレスに送られます。"),
+    ("solana_review.risk_unknown_program", "不明なプログラム"),
+    ("solana_review.sponsored", "支払者"),
+    ("solana_review.outgoing", "あなたのアカウントから送られる SOL"),
+    ("solana_review.network_fee", "ネットワーク手数料"),
+    // Solana priority fees
+    ("fee.solana_priority", "優先手数料"),
+    ("fee.per_compute_unit", "コンピュートユニットあたりのマイクロlamport"),
+    ("fee.compute_units", "コンピュートユニット"),
+    // Native SOL staking
+    ("dashboard.stake", "ステーク"),
+    ("staking.title", "SOL ステーキング"),
+    ("staking.about", "ネイティブステーキング"),
+    ("staking.about_desc", "ステークはあなたが管理するステークアカウントに保管されます。新しいステークは次のエポックで有効になり、無効化したステークはエポック終了後に引き出せます。"),
+    ("staking.total_staked", "ステーク合計"),
+    ("staking.epoch", "エポック"),
+    ("staking.accounts", "ステークアカウント"),
+    ("staking.loading", "ステークアカウントを読み込み中..."),
+    ("staking.no_accounts", "ステークアカウントはまだありません"),
+    ("staking.load_error", "ステークアカウントを読み込めません:"),
+    ("staking.validators_error", "バリデーターを読み込めません:"),
+    ("staking.undelegated", "未委任"),
+    ("staking.activating", "有効化中"),
+    ("staking.active", "有効"),
+    ("staking.deactivating", "無効化中"),
+    ("staking.inactive", "無効"),
+    ("staking.validator", "バリデーター"),
+    ("staking.locked", "ロックアップ中:引き出しできません"),
+    ("staking.stake", "ステーク"),
+    ("staking.delegate", "委任"),
+    ("staking.deactivate", "無効化"),
+    ("staking.withdraw", "引き出し"),
+    ("staking.split", "分割"),
+    ("staking.merge", "統合"),
+    ("staking.new_stake", "新規ステーク"),
+    ("staking.rent_note", "金額のうち約 0.0023 SOL はレント準備金としてアカウントに残ります。"),
+    ("staking.search_validator", "投票またはIDアドレスを検索"),
+    ("staking.loading_validators", "バリデーターを読み込み中..."),
+    ("staking.delinquent", "停止中"),
+    ("staking.pick_validator", "先にバリデーターを選択してください"),
+    ("staking.exceeds_withdrawable", "金額が引き出し可能残高を超えています"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "メモ（任意）"),
+    ("send.memo_placeholder", "一部の取引所で必要"),
+    ("send.token_transfer_fee", "トークン送金手数料（金額から差し引き）"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "TON Connect を管理"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← 戻る"),
+    ("tc.connect_dapp", "dApp の TON Connect リンク (tc://) を貼り付け"),
+    ("tc.open_link", "リンクを開く"),
+    ("tc.invalid_link", "無効なリンク:"),
+    ("tc.loading_manifest", "アプリのマニフェストを読み込み中..."),
+    ("tc.manifest_error", "マニフェストを読み込めません:"),
+    ("tc.connect_request", "が TON ウォレットへの接続を求めています"),
+    ("tc.proof_requested", "サインイン証明を要求:"),
+    ("tc.password", "パスワード"),
+    ("tc.approve", "承認"),
+    ("tc.reject", "拒否"),
+    ("tc.connected", "接続済み:"),
+    ("tc.error", "エラー:"),
+    ("tc.tx_requests", "トランザクション要求"),
+    ("tc.payload", "ペイロードあり"),
+    ("tc.state_init", "コントラクトをデプロイ (StateInit)"),
+    ("tc.tx_sent", "トランザクションを送信しました"),
+    ("tc.sessions", "接続中の dApp"),
+    ("tc.no_sessions", "接続中の dApp はありません"),
+    ("tc.disconnect", "切断"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "TON ウォレットコントラクト"),
+    ("settings.ton_wallet_version_hint", "同じ鍵はバージョンごとに1つの TON ウォレットを所有します。表示・送信に使うものを選択してください。"),
 ];
@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "지갑을 찾을 수 없습니다"),
     ("approve.active_wallet_not_found", "활성 지갑을 찾을 수 없습니다"),
     ("approve.seed_invalid", "잘못된 시드"),
-    ("approve.sendtx_unsupported", "dApp을 통한 eth_sendTransaction은 아직 지원되지 않습니다 \u{2014} 지갑에서 직접 사용하세요"),
     ("approve.method_unsupported", "지원되지 않는 메서드:"),
     ("approve.error_parsing_wallet", "지갑 파싱 오류:"),
     ("approve.error_parsing_params", "매개변수 파싱 오류:"),
     ("approve.missing_message_param", "메시지 매개변수가 없습니다"),
     ("approve.invalid_hex", "잘못된 16진수:"),
     ("approve.missing_typed_data", "타입 데이터 매개변수가 없습니다"),
-    ("approve.missing_domain", "domain 필드가 없습니다"),
-    ("approve.missing_message_field", "message 필드가 없습니다"),
-    ("approve.missing_primary_type", "primaryType 필드가 없습니다"),
-    ("approve.missing_types", "types 필드가 없습니다"),
-    ("approve.type_not_found", "타입을 찾을 수 없습니다:"),
     ("wc.back", "뒤로"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "설정"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "알 수 없는 dApp"),
     ("approvals.title", "토큰 승인"),
     ("approvals.management", "승인 관리"),
-    ("approvals.management_desc", "DEX 컨트랙트에 대한 ERC-20 승인을 확인하고 취소하세요. 무제한 승인은 보안 위험이 될 수 있습니다."),
+    ("approvals.management_desc", "승인은 주소의 온체인 Approval 이벤트에서 찾습니다. 무제한 승인은 보안 위험이 될 수 있습니다."),
     ("approvals.evm_only", "토큰 승인은 EVM 체인에서만 사용할 수 있습니다."),
     ("approvals.scanning", "승인 스캔 중..."),
-    ("approvals.no_approvals", "알려진 토큰에 대한 활성 승인을 찾을 수 없습니다."),
+    ("approvals.no_approvals", "활성 승인이 없습니다."),
     ("approvals.no_rpc", "사용 가능한 RPC URL이 없습니다"),
     ("approvals.revoke", "취소"),
     ("approvals.revoke_error", "취소 인코딩 오류:"),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "AES-256-GCM 암호화"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600,000 라운드)"),
     ("security.local_only", "키는 기기를 벗어나지 않습니다"),
+    // EVM fees
+    ("fee.title", "네트워크 수수료"),
+    ("fee.slow", "느림"),
+    ("fee.normal", "보통"),
+    ("fee.fast", "빠름"),
+    ("fee.custom", "사용자 지정"),
+    ("fee.max_fee", "최대 수수료 (Gwei)"),
+    ("fee.priority_fee", "우선 수수료 (Gwei)"),
+    ("fee.gas_limit", "가스 한도"),
+    ("fee.estimated", "예상 수수료"),
+    ("fee.max", "최대"),
+    ("fee.loading", "수수료 불러오는 중..."),
+    ("fee.unavailable", "수수료 정보를 가져올 수 없습니다:"),
+    ("fee.invalid", "유효한 네트워크 수수료를 선택하세요"),
+    ("approve.unsupported_chain", "dApp이 지원되지 않는 네트워크를 요청했습니다"),
+    ("approve.missing_chain", "dApp이 사용할 네트워크를 지정하지 않았습니다"),
+    // Pending EVM transactions
+    ("history.pending", "대기 중"),
+    ("history.pending_tx", "대기 중"),
+    ("history.sped_up", "가속됨"),
+    ("history.cancelling", "취소 중"),
+    ("history.speed_up", "가속"),
+    ("history.cancel_tx", "취소"),
+    ("history.speed_up_hint", "더 높은 수수료(최소 +10%)로 동일한 트랜잭션을 다시 보냅니다."),
+    ("history.cancel_hint", "트랜잭션을 자신에게 보내는 0 금액 전송으로 대체합니다. 먼저 채굴되지 않은 경우에만 작동합니다."),
+    // Custom EVM networks
+    ("networks.title", "사용자 지정 네트워크"),
+    ("networks.manage", "네트워크 관리"),
+    ("networks.hint", "EVM 호환 체인을 추가하세요. 모든 EVM 네트워크에서 이더리움 주소가 사용됩니다."),
+    ("networks.add", "+ 추가"),
+    ("networks.chain_id", "체인 ID"),
+    ("networks.name", "네트워크 이름"),
+    ("networks.ticker", "통화 기호"),
+    ("networks.rpc_url", "RPC URL"),
+    ("networks.explorer_url", "블록 탐색기 (선택)"),
+    ("networks.save", "네트워크 저장"),
+    ("networks.delete", "삭제"),
+    ("networks.empty", "사용자 지정 네트워크가 없습니다"),
+    ("networks.invalid_chain_id", "체인 ID는 양수여야 합니다"),
+    ("networks.checking_rpc", "RPC 확인 중..."),
+    ("approve.add_network", "네트워크 추가"),
+    ("approve.switch_network", "네트워크 전환"),
+    ("approve.add_network_warning", "악성 네트워크는 가짜 잔액과 거래를 표시할 수 있습니다. 신뢰할 수 있는 사이트의 네트워크만 추가하세요."),
+    // ENS
+    ("ens.resolving", "ENS 이름 확인 중..."),
+    ("ens.not_resolved", "보내기 전에 ENS 이름 확인을 기다리세요"),
+    ("approve.to", "받는 주소"),
+    // Safe multisig
+    ("safe.title", "Safe 멀티시그"),
+    ("safe.manage", "Safe 관리"),
+    ("safe.add", "+ 추가"),
+    ("safe.hint", "공동 소유한 Safe를 등록해 트랜잭션을 서명, 실행, 제안하세요."),
+    ("safe.address", "Safe 주소"),
+    ("safe.name", "이름 (선택)"),
+    ("safe.network", "네트워크"),
+    ("safe.save", "Safe 추가"),
+    ("safe.empty", "등록된 Safe 없음"),
+    ("safe.checking", "Safe 확인 중..."),
+    ("safe.already_added", "이미 목록에 있는 Safe입니다"),
+    ("safe.not_a_safe", "이 네트워크의 Safe가 아닙니다:"),
+    ("safe.no_rpc", "이 네트워크의 RPC가 없습니다"),
+    ("safe.info_error", "Safe를 읽을 수 없습니다:"),
+    ("safe.queue_error", "대기열을 불러올 수 없습니다:"),
+    ("safe.threshold", "임계값"),
+    ("safe.you_are_owner", "이 지갑은 소유자입니다"),
+    ("safe.not_owner", "보기 전용: 이 지갑은 소유자가 아닙니다"),
+    ("safe.queue", "대기 중인 트랜잭션"),
+    ("safe.queue_empty", "서명 대기 중인 항목이 없습니다"),
+    ("safe.contract_call", "컨트랙트 호출"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: 대상 코드가 Safe를 완전히 제어하며 실행됩니다"),
+    ("safe.confirmations", "서명"),
+    ("safe.sign", "서명"),
+    ("safe.execute", "실행"),
+    ("safe.propose", "Safe에서 새 송금"),
+    ("safe.propose_button", "제안 및 서명"),
+    ("safe.signed", "서명이 추가되었습니다"),
+    ("safe.proposed", "제안됨, nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "토큰 Permit (오프체인 승인)"),
+    ("permit.kind_permit2", "Permit2 허용량"),
+    ("permit.kind_permit2_transfer", "Permit2 토큰 전송"),
+    ("permit.kind_seaport", "Seaport 리스팅"),
+    ("permit.spender", "사용자"),
+    ("permit.expiry", "만료"),
+    ("permit.unlimited", "무제한"),
+    ("permit.never", "없음"),
+    ("permit.expired", "이미 만료됨"),
+    ("permit.in", "남은 기간"),
+    ("permit.high_risk_title", "이 서명으로 토큰이 유출될 수 있습니다"),
+    ("permit.review_hint", "트랜잭션은 전송되지 않지만, 서명하면 사용자가 이 토큰을 옮길 수 있습니다."),
+    ("permit.risk_unlimited", "금액이 무제한입니다."),
+    ("permit.risk_unknown_spender", "사용자가 알려진 컨트랙트가 아닙니다."),
+    ("permit.risk_far_expiry", "매우 오랫동안 유효합니다."),
+    ("permit.risk_no_payment", "등록한 항목에 대해 아무것도 받지 못합니다."),
+    // Transaction simulation
+    ("sim.title", "예상 잔액 변화"),
+    ("sim.would_fail", "이 트랜잭션은 실패할 것으로 예상됩니다"),
+    ("sim.unavailable", "자산 변화를 확인할 수 없음: 이 RPC는 시뮬레이션을 지원하지 않습니다."),
+    ("sim.no_changes", "계정의 자산 변화가 감지되지 않았습니다."),
+    ("sim.approvals", "부여되는 승인"),
+    ("sim.all_items", "모든 아이템"),
+    ("sim.revoked", "취소됨"),
+    ("sim.risky_approval", "무제한 승인"),
+    ("sim.risky_approval_hint", "승인을 취소할 때까지 사용자는 언제든지 이 자산을 옮길 수 있습니다."),
+    ("sim.gas_excluded", "네트워크 수수료 제외."),
+    // NFT transfers
+    ("nft.send", "NFT 보내기"),
+    ("nft.review", "전송 검토"),
+    ("nft.simulating", "전송 시뮬레이션 중..."),
+    ("nft.simulation_ok", "시뮬레이션 성공: NFT가 지갑에서 전송됩니다."),
+    // Custom tokens
+    ("tokens.title", "토큰 가져오기"),
+    ("tokens.import", "+ 가져오기"),
+    ("tokens.address", "컨트랙트 / 민트 주소"),
+    ("tokens.lookup", "조회"),
+    ("tokens.looking_up", "토큰 메타데이터 읽는 중..."),
+    ("tokens.lookup_failed", "토큰을 읽을 수 없습니다"),
+    ("tokens.already_listed", "이 토큰은 이미 기본 목록에 있습니다"),
+    ("tokens.warning_title", "주소를 확인하세요"),
+    ("tokens.warning", "누구나 임의의 이름과 심볼로 토큰을 만들 수 있습니다. 프로젝트 공식 출처의 주소만 가져오세요."),
+    ("tokens.symbol", "심볼"),
+    ("tokens.name", "이름"),
+    ("tokens.decimals", "소수 자릿수"),
+    ("tokens.add", "토큰 추가"),
+    ("tokens.remove", "제거"),
+    ("tokens.empty", "이 네트워크에 가져온 토큰이 없습니다"),
+    ("tokens.unsupported_chain", "이 네트워크에는 지갑이 지원하는 토큰 표준이 없습니다"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "계정 위임 (EIP-7702)"),
+    ("approve.delegation_unknown", "알 수 없는 위임 대상"),
+    ("approve.delegation_unknown_hint", "이 컨트랙트가 계정과 모든 자산을 제어하게 됩니다. 알려진 감사된 위임 대상이 아닙니다. 이 dApp을 완전히 신뢰하는 경우에만 계속하세요."),
+    ("approve.delegation_any_chain", "모든 체인에서 유효한 위임"),
+    ("approve.delegation_any_chain_hint", "이 권한은 chain id 0을 사용하므로 계정이 있는 모든 EVM 네트워크에서 재사용될 수 있습니다."),
+    ("approve.delegation_unknown_contract", "알 수 없는 컨트랙트"),
+    ("approve.delegation_revoke", "현재 위임 제거"),
+    ("approve.delegation_all_chains", "모든 체인"),
+    ("approve.delegation_presigned", "다른 계정이 서명함; 이 트랜잭션으로 전달됨"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "로그인 요청"),
+    ("siwe.address", "계정"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "네트워크"),
+    ("siwe.nonce", "논스"),
+    ("siwe.issued_at", "발급 시각"),
+    ("siwe.expires", "만료"),
+    ("siwe.not_before", "유효 시작"),
+    ("siwe.request_id", "요청 ID"),
+    ("siwe.resources", "리소스"),
+    ("siwe.domain_mismatch", "다른 사이트 로그인"),
+    ("siwe.domain_mismatch_hint", "이 메시지는 {domain} 로그인용이지만 {origin}에서 요청했습니다. 서명하면 {origin}이(가) {domain} 계정에 접근할 수 있습니다. 흔한 피싱 수법입니다."),
+    ("siwe.scheme_mismatch", "프로토콜 불일치"),
+    ("siwe.scheme_mismatch_hint", "메시지의 프로토콜(http/https)이 요청한 페이지와 다릅니다."),
+    ("siwe.address_mismatch", "다른 계정"),
+    ("siwe.address_mismatch_hint", "메시지의 주소가 활성 계정이 아닙니다."),
+    ("siwe.chain_mismatch", "다른 네트워크"),
+    ("siwe.chain_mismatch_hint", "메시지는 체인 {message}용이지만 사이트는 체인 {active}에 연결되어 있습니다."),
+    ("siwe.invalid_nonce", "약한 논스"),
+    ("siwe.invalid_nonce_hint", "논스는 최소 8자의 무작위 문자 또는 숫자여야 합니다. 예측 가능한 논스는 서명 재사용을 허용합니다."),
+    ("siwe.expired", "로그인 요청 만료"),
+    ("siwe.expired_hint", "만료 시간이 지났습니다. 사이트를 새로고침하고 다시 로그인하세요."),
+    ("siwe.not_yet_valid", "로그인 요청이 아직 유효하지 않음"),
+    ("siwe.not_yet_valid_hint", "메시지가 나중에야 유효해지므로 사이트를 떠난 후에 사용될 수 있습니다."),
+    ("siwe.invalid_time", "잘못된 날짜"),
+    ("siwe.invalid_time_hint", "메시지의 타임스탬프 형식이 잘못되어 유효 기간을 확인할 수 없습니다."),
+    ("siwe.unbound", "사이트 미확인"),
+    ("siwe.unbound_hint", "이 요청은 WalletConnect를 통해 왔으므로 메시지에 명시된 사이트에서 보냈는지 확인할 수 없습니다."),
+    ("siwe.chain_unknown", "네트워크 미확인"),
+    ("siwe.chain_unknown_hint", "요청에 사이트가 연결된 네트워크가 없어 메시지의 체인을 확인할 수 없습니다."),
+    ("siwe.malformed", "잘못된 로그인 메시지"),
+    ("siwe.malformed_hint", "Sign-In with Ethereum 메시지처럼 보이지만 표준을 따르지 않아({error}) 접근을 허용하는 사이트, 계정, 네트워크를 확인할 수 없습니다. 정상적인 사이트는 잘못된 로그인 메시지를 보내지 않습니다."),
+    // Signature verification tool
+    ("verify.title", "서명 검증"),
+    ("verify.open", "검증 도구 열기"),
+    ("verify.hint", "메시지 서명자를 확인합니다. 일반 계정과 스마트 컨트랙트 지갑(ERC-1271), 아직 배포되지 않은 지갑(ERC-6492)도 지원합니다."),
+    ("verify.network", "네트워크"),
+    ("verify.signer", "서명자 주소"),
+    ("verify.message_type", "메시지 유형"),
+    ("verify.kind_personal", "메시지 (personal_sign)"),
+    ("verify.kind_typed", "타입 데이터 (EIP-712 JSON)"),
+    ("verify.kind_hash", "해시 (32바이트)"),
+    ("verify.message", "메시지"),
+    ("verify.signature", "서명"),
+    ("verify.check", "검증"),
+    ("verify.checking", "검증 중..."),
+    ("verify.valid", "유효한 서명"),
+    ("verify.invalid", "유효하지 않은 서명"),
+    ("verify.method", "검증 방법"),
+    ("verify.recovered", "실제 서명자"),
+    ("verify.no_rpc", "이 네트워크의 RPC 엔드포인트가 없습니다"),
+    // Approval discovery
+    ("approvals.all_items", "모든 항목"),
+    ("approvals.scanned_to", "블록 {block}까지 기록 스캔 완료"),
+    ("approvals.scan_more", "이전 블록 스캔"),
+    ("approvals.scan_error", "로그 스캔 중단:"),
+    ("approvals.found", "활성 승인 {n}개"),
+    ("approvals.select_all", "모두 선택"),
+    ("approvals.unknown_spender", "알 수 없는 컨트랙트"),
+    ("approvals.spender", "사용자"),
+    ("approvals.allowance", "허용량"),
+    ("approvals.revoke_selected", "선택 항목 취소 ({n})"),
+    ("approvals.confirm_revoke", "승인 {n}개를 취소할까요? 승인마다 트랜잭션이 하나씩 전송됩니다."),
+    ("approvals.revoked_count", "취소 트랜잭션 {n}개 전송됨"),
+    // SPL token transfers
+    ("send.ata_rent", "수신자의 새 토큰 계정 렌트 포함"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Solana 지갑 연결"),
+    ("approve.solana_sign_tx", "Solana 트랜잭션 서명"),
+    ("approve.solana_sign_all", "Solana 트랜잭션 일괄 서명"),
+    ("approve.solana_send_tx", "Solana 트랜잭션 전송"),
+    ("approve.solana_sign_message", "메시지 서명 (Solana)"),
+    ("approve.solana_sign_in", "Solana로 로그인"),
+    ("approve.solana_disguised_tx", "이 메시지는 트랜잭션입니다"),
+    ("approve.solana_disguised_tx_hint", "사이트가 Solana 트랜잭션을 구성하는 바이트에 서명을 요청했습니다. 서명하면 해당 트랜잭션이 승인되므로 Rusby는 거부합니다."),
+    ("approve.solana_tx", "트랜잭션"),
+    ("approve.solana_tx_n", "트랜잭션 {n}/{count}"),
+    ("approve.solana_decode_failed", "트랜잭션을 디코딩할 수 없습니다"),
+    ("solana_review.raw_units", "단위 (알 수 없는 토큰)"),
+    ("solana_review.you", "나"),
+    ("solana_review.sol_transfer", "SOL 전송"),
+    ("solana_review.create_account", "계정 생성"),
+    ("solana_review.assign", "계정을 프로그램에 할당"),
+    ("solana_review.token_transfer", "토큰 전송"),
+    ("solana_review.token_approve", "토큰 위임 승인"),
+    ("solana_review.set_authority", "토큰 계정 권한 변경"),
+    ("solana_review.none", "없음"),
+    ("solana_review.close_account", "토큰 계정 닫기"),
+    ("solana_review.create_token_account", "토큰 계정 생성"),
+    ("solana_review.memo", "메모"),
+    ("solana_review.compute_limit", "컴퓨트 유닛 한도"),
+    ("solana_review.priority_fee", "우선 수수료"),
+    ("solana_review.program_call", "프로그램 호출"),
+    ("solana_review.not_signer", "내 트랜잭션이 아님"),
+    ("solana_review.not_signer_hint", "내 계정이 이 트랜잭션의 서명자가 아니므로 서명할 수 없습니다."),
+    ("solana_review.risk_assign", "계정 소유권 변경"),
+    ("solana_review.risk_assign_hint", "지갑 계정이 다른 프로그램에 넘어가 모든 SOL을 옮길 수 있게 됩니다. 드레이너가 사용하는 수법입니다."),
+    ("solana_review.risk_authority", "토큰 계정 권한 이전"),
+    ("solana_review.risk_authority_hint", "토큰 계정 중 하나의 제어권이 현재와 향후 보유 토큰 전부와 함께 다른 주소로 넘어갑니다."),
+    ("solana_review.risk_delegate", "토큰 지출 승인"),
+    ("solana_review.risk_delegate_hint", "위임자가 나중에 다시 묻지 않고 토큰을 옮길 수 있게 됩니다."),
+    ("solana_review.risk_close", "토큰 계정 닫힘"),
+    ("solana_review.risk_close_hint", "토큰 계정 하나가 닫히고 렌트 보증금이 다른 주소로 갑니다."),
+    ("solana_review.risk_unknown_program", "알 수 없는 프로그램"),
+    ("solana_review.sponsored", "지불자"),
+    ("solana_review.outgoing", "내 계정에서 보내는 SOL"),
+    ("solana_review.network_fee", "네트워크 수수료"),
+    // Solana priority fees
+    ("fee.solana_priority", "우선 수수료"),
+    ("fee.per_compute_unit", "컴퓨트 유닛당 마이크로 램포트"),
+    ("fee.compute_units", "컴퓨트 유닛"),
+    // Native SOL staking
+    ("dashboard.stake", "스테이킹"),
+    ("staking.title", "SOL 스테이킹"),
+    ("staking.about", "네이티브 스테이킹"),
+    ("staking.about_desc", "스테이크는 직접 관리하는 스테이크 계정에 보관됩니다. 새 스테이크는 다음 에포크에 활성화되며, 비활성화된 스테이크는 에포크가 끝난 후 인출할 수 있습니다."),
+    ("staking.total_staked", "총 스테이킹"),
+    ("staking.epoch", "에포크"),
+    ("staking.accounts", "스테이크 계정"),
+    ("staking.loading", "스테이크 계정 불러오는 중..."),
+    ("staking.no_accounts", "아직 스테이크 계정이 없습니다"),
+    ("staking.load_error", "스테이크 계정을 불러올 수 없습니다:"),
+    ("staking.validators_error", "검증인을 불러올 수 없습니다:"),
+    ("staking.undelegated", "위임 안 됨"),
+    ("staking.activating", "활성화 중"),
+    ("staking.active", "활성"),
+    ("staking.deactivating", "비활성화 중"),
+    ("staking.inactive", "비활성"),
+    ("staking.validator", "검증인"),
+    ("staking.locked", "락업 기간: 인출이 차단됨"),
+    ("staking.stake", "스테이킹"),
+    ("staking.delegate", "위임"),
+    ("staking.deactivate", "비활성화"),
+    ("staking.withdraw", "인출"),
+    ("staking.split", "분할"),
+    ("staking.merge", "병합"),
+    ("staking.new_stake", "새 스테이크"),
+    ("staking.rent_note", "금액 중 약 0.0023 SOL은 렌트 준비금으로 계정에 남습니다."),
+    ("staking.search_validator", "투표 또는 식별 주소 검색"),
+    ("staking.loading_validators", "검증인 불러오는 중..."),
+    ("staking.delinquent", "비정상"),
+    ("staking.pick_validator", "먼저 검증인을 선택하세요"),
+    ("staking.exceeds_withdrawable", "금액이 인출 가능 잔액을 초과합니다"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "메모 (선택)"),
+    ("send.memo_placeholder", "일부 거래소에서 필요"),
+    ("send.token_transfer_fee", "토큰 전송 수수료 (금액에서 차감)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "TON Connect 관리"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← 뒤로"),
+    ("tc.connect_dapp", "dApp의 TON Connect 링크(tc://)를 붙여넣으세요"),
+    ("tc.open_link", "링크 열기"),
+    ("tc.invalid_link", "잘못된 링크:"),
+    ("tc.loading_manifest", "앱 매니페스트 불러오는 중..."),
+    ("tc.manifest_error", "앱 매니페스트를 불러올 수 없습니다:"),
+    ("tc.connect_request", "이(가) TON 지갑 연결을 요청합니다"),
+    ("tc.proof_requested", "로그인 증명 요청:"),
+    ("tc.password", "비밀번호"),
+    ("tc.approve", "승인"),
+    ("tc.reject", "거부"),
+    ("tc.connected", "연결됨:"),
+    ("tc.error", "오류:"),
+    ("tc.tx_requests", "트랜잭션 요청"),
+    ("tc.payload", "페이로드 포함"),
+    ("tc.state_init", "컨트랙트 배포 (StateInit)"),
+    ("tc.tx_sent", "트랜잭션 전송됨"),
+    ("tc.sessions", "연결된 dApp"),
+    ("tc.no_sessions", "연결된 dApp 없음"),
+    ("tc.disconnect", "연결 해제"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "TON 지갑 컨트랙트"),
+    ("settings.ton_wallet_version_hint", "같은 키는 버전마다 하나의 TON 지갑을 소유합니다. 표시하고 송금할 지갑을 선택하세요."),
 ];
@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "Carteira não encontrada"),
     ("approve.active_wallet_not_found", "Carteira ativa não encontrada"),
     ("approve.seed_invalid", "Seed inválida"),
-    ("approve.sendtx_unsupported", "eth_sendTransaction via dApp ainda não é suportado \u{2014} use a carteira diretamente"),
     ("approve.method_unsupported", "Método não suportado:"),
     ("approve.error_parsing_wallet", "Erro ao analisar carteira:"),
     ("approve.error_parsing_params", "Erro ao analisar parâmetros:"),
     ("approve.missing_message_param", "Parâmetro de mensagem ausente"),
     ("approve.invalid_hex", "Hex inválido:"),
     ("approve.missing_typed_data", "Parâmetro de dados tipados ausente"),
-    ("approve.missing_domain", "Campo domain ausente"),
-    ("approve.missing_message_field", "Campo message ausente"),
-    ("approve.missing_primary_type", "Campo primaryType ausente"),
-    ("approve.missing_types", "Campo types ausente"),
-    ("approve.type_not_found", "Tipo não encontrado:"),
     ("wc.back", "Voltar"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "Configuração"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "dApp desconhecida"),
     ("approvals.title", "Aprovações de Tokens"),
     ("approvals.management", "Gerenciamento de Aprovações"),
-    ("approvals.management_desc", "Verifique e revogue aprovações ERC-20 para contratos DEX. Aprovações ilimitadas podem ser um risco de segurança."),
+    ("approvals.management_desc", "As aprovações são detectadas a partir dos eventos Approval on-chain do seu endereço. Aprovações ilimitadas podem ser um risco de segurança."),
     ("approvals.evm_only", "Aprovações de tokens estão disponíveis apenas para chains EVM."),
     ("approvals.scanning", "Verificando aprovações..."),
-    ("approvals.no_approvals", "Nenhuma aprovação ativa encontrada para tokens conhecidos."),
+    ("approvals.no_approvals", "Nenhuma aprovação ativa encontrada."),
     ("approvals.no_rpc", "Nenhuma URL RPC disponível"),
     ("approvals.revoke", "Revogar"),
     ("approvals.revoke_error", "Erro ao codificar revogação:"),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "Criptografia AES-256-GCM"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600.000 rodadas)"),
     ("security.local_only", "As chaves nunca saem do seu dispositivo"),
+    // EVM fees
+    ("fee.title", "Taxa de rede"),
+    ("fee.slow", "Lenta"),
+    ("fee.normal", "Normal"),
+    ("fee.fast", "Rápida"),
+    ("fee.custom", "Personalizada"),
+    ("fee.max_fee", "Taxa máx. (Gwei)"),
+    ("fee.priority_fee", "Taxa de prioridade (Gwei)"),
+    ("fee.gas_limit", "Limite de gás"),
+    ("fee.estimated", "Taxa estimada"),
+    ("fee.max", "máx."),
+    ("fee.loading", "Carregando taxas..."),
+    ("fee.unavailable", "Dados de taxa indisponíveis:"),
+    ("fee.invalid", "Selecione uma taxa de rede válida"),
+    ("approve.unsupported_chain", "A dApp solicitou uma rede não suportada"),
+    ("approve.missing_chain", "A dApp não indicou qual rede usar"),
+    // Pending EVM transactions
+    ("history.pending", "Pendentes"),
+    ("history.pending_tx", "Pendente"),
+    ("history.sped_up", "Acelerada"),
+    ("history.cancelling", "Cancelando"),
+    ("history.speed_up", "Acelerar"),
+    ("history.cancel_tx", "Cancelar"),
+    ("history.speed_up_hint", "Reenvia a mesma transação com taxas mais altas (pelo menos +10%)."),
+    ("history.cancel_hint", "Substitui a transação por um envio de valor 0 para você mesmo. Só funciona se ela não for minerada antes."),
+    // Custom EVM networks
+    ("networks.title", "Redes personalizadas"),
+    ("networks.manage", "Gerenciar redes"),
+    ("networks.hint", "Adicione qualquer rede compatível com EVM. Seu endereço Ethereum é usado em todas as redes EVM."),
+    ("networks.add", "+ Adicionar"),
+    ("networks.chain_id", "Chain ID"),
+    ("networks.name", "Nome da rede"),
+    ("networks.ticker", "Símbolo da moeda"),
+    ("networks.rpc_url", "URLs RPC"),
+    ("networks.explorer_url", "Explorador de blocos (opcional)"),
+    ("networks.save", "Salvar rede"),
+    ("networks.delete", "Remover"),
+    ("networks.empty", "Nenhuma rede personalizada"),
+    ("networks.invalid_chain_id", "O Chain ID deve ser um número positivo"),
+    ("networks.checking_rpc", "Verificando RPC..."),
+    ("approve.add_network", "Adicionar rede"),
+    ("approve.switch_network", "Trocar de rede"),
+    ("approve.add_network_warning", "Uma rede maliciosa pode mostrar saldos e transações falsos. Adicione redes apenas de sites confiáveis."),
+    // ENS
+    ("ens.resolving", "Resolvendo nome ENS..."),
+    ("ens.not_resolved", "Aguarde a resolução do nome ENS antes de enviar"),
+    ("approve.to", "Para"),
+    // Safe multisig
+    ("safe.title", "Safe multisig"),
+    ("safe.manage", "Gerir Safes"),
+    ("safe.add", "+ Adicionar"),
+    ("safe.hint", "Acompanhe um Safe do qual é coproprietário para assinar, executar e propor transações."),
+    ("safe.address", "Endereço do Safe"),
+    ("safe.name", "Nome (opcional)"),
+    ("safe.network", "Rede"),
+    ("safe.save", "Adicionar Safe"),
+    ("safe.empty", "Ainda sem Safes"),
+    ("safe.checking", "A verificar o Safe..."),
+    ("safe.already_added", "Este Safe já está na lista"),
+    ("safe.not_a_safe", "Não é um Safe nesta rede:"),
+    ("safe.no_rpc", "Sem RPC para esta rede"),
+    ("safe.info_error", "Não foi possível ler o Safe:"),
+    ("safe.queue_error", "Não foi possível carregar a fila:"),
+    ("safe.threshold", "Limite"),
+    ("safe.you_are_owner", "Esta carteira é proprietária"),
+    ("safe.not_owner", "Só visualização: esta carteira não é proprietária"),
+    ("safe.queue", "Transações pendentes"),
+    ("safe.queue_empty", "Nada à espera de assinaturas"),
+    ("safe.contract_call", "Chamada de contrato"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL: o destino executa código com controlo total do Safe"),
+    ("safe.confirmations", "Assinaturas"),
+    ("safe.sign", "Assinar"),
+    ("safe.execute", "Executar"),
+    ("safe.propose", "Nova transferência a partir do Safe"),
+    ("safe.propose_button", "Propor e assinar"),
+    ("safe.signed", "Assinatura adicionada"),
+    ("safe.proposed", "Proposta com nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "Permit de token (aprovação off-chain)"),
+    ("permit.kind_permit2", "Allowance Permit2"),
+    ("permit.kind_permit2_transfer", "Transferência de tokens Permit2"),
+    ("permit.kind_seaport", "Listagem Seaport"),
+    ("permit.spender", "Spender"),
+    ("permit.expiry", "Expira"),
+    ("permit.unlimited", "Ilimitado"),
+    ("permit.never", "Nunca"),
+    ("permit.expired", "Já expirado"),
+    ("permit.in", "em"),
+    ("permit.high_risk_title", "Esta assinatura pode esvaziar os seus tokens"),
+    ("permit.review_hint", "Nenhuma transação é enviada, mas após assinar o spender pode mover estes tokens."),
+    ("permit.risk_unlimited", "Montante ilimitado."),
+    ("permit.risk_unknown_spender", "O spender não é um contrato conhecido."),
+    ("permit.risk_far_expiry", "Permanece válida durante muito tempo."),
+    ("permit.risk_no_payment", "Não recebe nada em troca dos itens listados."),
+    // Transaction simulation
+    ("sim.title", "Alterações de saldo estimadas"),
+    ("sim.would_fail", "Esta transação deve falhar"),
+    ("sim.unavailable", "Alterações indisponíveis: este RPC não suporta simulação."),
+    ("sim.no_changes", "Nenhuma alteração de ativos detectada na sua conta."),
+    ("sim.approvals", "Aprovações concedidas"),
+    ("sim.all_items", "Todos os itens"),
+    ("sim.revoked", "Revogada"),
+    ("sim.risky_approval", "Aprovação ilimitada"),
+    ("sim.risky_approval_hint", "O gastador poderá mover esses ativos a qualquer momento até que você revogue a aprovação."),
+    ("sim.gas_excluded", "Taxa de rede não incluída."),
+    // NFT transfers
+    ("nft.send", "Enviar NFT"),
+    ("nft.review", "Revisar transferência"),
+    ("nft.simulating", "Simulando transferência..."),
+    ("nft.simulation_ok", "Simulação bem-sucedida: o NFT sairá da sua carteira."),
+    // Custom tokens
+    ("tokens.title", "Importar token"),
+    ("tokens.import", "+ Importar"),
+    ("tokens.address", "Endereço do contrato / mint"),
+    ("tokens.lookup", "Consultar"),
+    ("tokens.looking_up", "Lendo metadados do token..."),
+    ("tokens.lookup_failed", "Não foi possível ler o token"),
+    ("tokens.already_listed", "Este token já está na lista padrão"),
+    ("tokens.warning_title", "Verifique o endereço"),
+    ("tokens.warning", "Qualquer pessoa pode criar um token com qualquer nome e símbolo. Importe apenas endereços de fontes oficiais do projeto."),
+    ("tokens.symbol", "Símbolo"),
+    ("tokens.name", "Nome"),
+    ("tokens.decimals", "Decimais"),
+    ("tokens.add", "Adicionar token"),
+    ("tokens.remove", "Remover"),
+    ("tokens.empty", "Nenhum token importado nesta rede"),
+    ("tokens.unsupported_chain", "Esta rede não tem um padrão de token suportado pela carteira"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "Delegação da conta (EIP-7702)"),
+    ("approve.delegation_unknown", "Destino de delegação desconhecido"),
+    ("approve.delegation_unknown_hint", "Este contrato controlará sua conta e todos os ativos nela. Não é um delegado conhecido e auditado. Continue apenas se confiar totalmente nesta dApp."),
+    ("approve.delegation_any_chain", "Delegação válida em todas as redes"),
+    ("approve.delegation_any_chain_hint", "Esta autorização usa chain id 0 e pode ser reutilizada em qualquer rede EVM onde sua conta exista."),
+    ("approve.delegation_unknown_contract", "Contrato desconhecido"),
+    ("approve.delegation_revoke", "Remover a delegação atual"),
+    ("approve.delegation_all_chains", "Todas as redes"),
+    ("approve.delegation_presigned", "Assinada por outra conta; retransmitida por esta transação"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "Pedido de login"),
+    ("siwe.address", "Conta"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "Rede"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "Emitido em"),
+    ("siwe.expires", "Expira"),
+    ("siwe.not_before", "Válido a partir de"),
+    ("siwe.request_id", "ID do pedido"),
+    ("siwe.resources", "Recursos"),
+    ("siwe.domain_mismatch", "Login para outro site"),
+    ("siwe.domain_mismatch_hint", "Esta mensagem faz login em {domain}, mas foi solicitada por {origin}. Assiná-la pode permitir que {origin} acesse sua conta em {domain}. É uma técnica de phishing comum."),
+    ("siwe.scheme_mismatch", "Protocolo divergente"),
+    ("siwe.scheme_mismatch_hint", "A mensagem indica um protocolo (http/https) diferente do da página que a solicitou."),
+    ("siwe.address_mismatch", "Conta diferente"),
+    ("siwe.address_mismatch_hint", "A mensagem é para um endereço que não é sua conta ativa."),
+    ("siwe.chain_mismatch", "Rede diferente"),
+    ("siwe.chain_mismatch_hint", "A mensagem é para a chain {message}, mas o site está conectado à chain {active}."),
+    ("siwe.invalid_nonce", "Nonce fraco"),
+    ("siwe.invalid_nonce_hint", "O nonce deve ter pelo menos 8 letras ou dígitos aleatórios. Um nonce previsível permite reutilizar a assinatura."),
+    ("siwe.expired", "Pedido de login expirado"),
+    ("siwe.expired_hint", "O prazo expirou. Recarregue o site e faça login novamente."),
+    ("siwe.not_yet_valid", "Pedido ainda não válido"),
+    ("siwe.not_yet_valid_hint", "A mensagem só se torna válida mais tarde, podendo ser usada depois que você sair do site."),
+    ("siwe.invalid_time", "Data inválida"),
+    ("siwe.invalid_time_hint", "Uma das datas da mensagem está malformada, então a validade não pode ser verificada."),
+    ("siwe.unbound", "Site não verificado"),
+    ("siwe.unbound_hint", "Este pedido veio pelo WalletConnect, então a carteira não pode confirmar que foi enviado pelo site indicado na mensagem."),
+    ("siwe.chain_unknown", "Rede não verificada"),
+    ("siwe.chain_unknown_hint", "A solicitação não indica a qual rede o site está conectado, então a chain da mensagem não pôde ser verificada."),
+    ("siwe.malformed", "Mensagem de login malformada"),
+    ("siwe.malformed_hint", "Parece uma mensagem Sign-In with Ethereum, mas não segue o padrão ({error}), então não é possível verificar o site, a conta e a rede a que dá acesso. Sites legítimos não enviam mensagens de login malformadas."),
+    // Signature verification tool
+    ("verify.title", "Verificar assinatura"),
+    ("verify.open", "Abrir ferramenta de verificação"),
+    ("verify.hint", "Verifique quem assinou uma mensagem. Funciona com contas comuns e carteiras de contrato inteligente (ERC-1271), inclusive ainda não implantadas (ERC-6492)."),
+    ("verify.network", "Rede"),
+    ("verify.signer", "Endereço do signatário"),
+    ("verify.message_type", "Tipo de mensagem"),
+    ("verify.kind_personal", "Mensagem (personal_sign)"),
+    ("verify.kind_typed", "Dados tipados (JSON EIP-712)"),
+    ("verify.kind_hash", "Hash (32 bytes)"),
+    ("verify.message", "Mensagem"),
+    ("verify.signature", "Assinatura"),
+    ("verify.check", "Verificar"),
+    ("verify.checking", "Verificando..."),
+    ("verify.valid", "Assinatura válida"),
+    ("verify.invalid", "Assinatura inválida"),
+    ("verify.method", "Verificada com"),
+    ("verify.recovered", "Signatário real"),
+    ("verify.no_rpc", "Nenhum endpoint RPC para esta rede"),
+    // Approval discovery
+    ("approvals.all_items", "Todos os itens"),
+    ("approvals.scanned_to", "Histórico analisado até o bloco {block}"),
+    ("approvals.scan_more", "Analisar blocos anteriores"),
+    ("approvals.scan_error", "Análise de logs interrompida:"),
+    ("approvals.found", "{n} aprovações ativas"),
+    ("approvals.select_all", "Selecionar tudo"),
+    ("approvals.unknown_spender", "Contrato desconhecido"),
+    ("approvals.spender", "Gastador"),
+    ("approvals.allowance", "Permissão"),
+    ("approvals.revoke_selected", "Revogar selecionadas ({n})"),
+    ("approvals.confirm_revoke", "Revogar {n} aprovações? Uma transação é enviada para cada uma."),
+    ("approvals.revoked_count", "{n} transações de revogação enviadas"),
+    // SPL token transfers
+    ("send.ata_rent", "inclui o aluguel da nova conta de token do destinatário"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Conexão de carteira Solana"),
+    ("approve.solana_sign_tx", "Assinar transação Solana"),
+    ("approve.solana_sign_all", "Assinar transações Solana"),
+    ("approve.solana_send_tx", "Enviar transação Solana"),
+    ("approve.solana_sign_message", "Assinar mensagem (Solana)"),
+    ("approve.solana_sign_in", "Entrar com Solana"),
+    ("approve.solana_disguised_tx", "Esta mensagem é uma transação"),
+    ("approve.solana_disguised_tx_hint", "O site pediu para assinar bytes que formam uma transação Solana. Assiná-los autorizaria essa transação, por isso o Rusby recusará."),
+    ("approve.solana_tx", "Transação"),
+    ("approve.solana_tx_n", "Transação {n} de {count}"),
+    ("approve.solana_decode_failed", "Não foi possível decodificar a transação"),
+    ("solana_review.raw_units", "unidades (token desconhecido)"),
+    ("solana_review.you", "você"),
+    ("solana_review.sol_transfer", "Transferência de SOL"),
+    ("solana_review.create_account", "Criar conta"),
+    ("solana_review.assign", "Atribuir conta a um programa"),
+    ("solana_review.token_transfer", "Transferência de tokens"),
+    ("solana_review.token_approve", "Aprovação de delegado de tokens"),
+    ("solana_review.set_authority", "Alterar autoridade da conta de tokens"),
+    ("solana_review.none", "nenhuma"),
+    ("solana_review.close_account", "Fechar conta de tokens"),
+    ("solana_review.create_token_account", "Criar conta de tokens"),
+    ("solana_review.memo", "Memo"),
+    ("solana_review.compute_limit", "Limite de unidades de computação"),
+    ("solana_review.priority_fee", "Taxa de prioridade"),
+    ("solana_review.program_call", "Chamada de programa"),
+    ("solana_review.not_signer", "Não é sua transação"),
+    ("solana_review.not_signer_hint", "Sua conta não é signatária desta transação, então ela não pode ser assinada."),
+    ("solana_review.risk_assign", "Mudança de proprietário da conta"),
+    ("solana_review.risk_assign_hint", "Sua conta seria entregue a outro programa, que poderia mover todo o SOL. Drainers usam essa técnica."),
+    ("solana_review.risk_authority", "Transferência de autoridade da conta de tokens"),
+    ("solana_review.risk_authority_hint", "O controle de uma das suas contas de tokens passaria para outro endereço, junto com todos os tokens que ela tem agora ou depois."),
+    ("solana_review.risk_delegate", "Aprovação de gasto de tokens"),
+    ("solana_review.risk_delegate_hint", "Um delegado poderia mover seus tokens depois sem pedir novamente."),
+    ("solana_review.risk_close", "Conta de tokens fechada"),
+    ("solana_review.risk_close_hint", "Uma das suas contas de tokens é fechada e o depósito de aluguel vai para outro endereço."),
+    ("solana_review.risk_unknown_program", "Programa desconhecido"),
+    ("solana_review.sponsored", "Paga por"),
+    ("solana_review.outgoing", "SOL enviados da sua conta"),
+    ("solana_review.network_fee", "Taxa de rede"),
+    // Solana priority fees
+    ("fee.solana_priority", "Taxa de prioridade"),
+    ("fee.per_compute_unit", "micro-lamports por unidade de computação"),
+    ("fee.compute_units", "unidades de computação"),
+    // Native SOL staking
+    ("dashboard.stake", "Stake"),
+    ("staking.title", "Staking de SOL"),
+    ("staking.about", "Staking nativo"),
+    ("staking.about_desc", "O stake fica em contas de stake que você controla. O novo stake ativa na próxima época; o desativado pode ser sacado ao fim da época."),
+    ("staking.total_staked", "Total em stake"),
+    ("staking.epoch", "Época"),
+    ("staking.accounts", "Contas de stake"),
+    ("staking.loading", "Carregando contas de stake..."),
+    ("staking.no_accounts", "Nenhuma conta de stake ainda"),
+    ("staking.load_error", "Não foi possível carregar as contas de stake:"),
+    ("staking.validators_error", "Não foi possível carregar os validadores:"),
+    ("staking.undelegated", "Não delegado"),
+    ("staking.activating", "Ativando"),
+    ("staking.active", "Ativo"),
+    ("staking.deactivating", "Desativando"),
+    ("staking.inactive", "Inativo"),
+    ("staking.validator", "Validador"),
+    ("staking.locked", "Bloqueio ativo: saques bloqueados"),
+    ("staking.stake", "Fazer stake"),
+    ("staking.delegate", "Delegar"),
+    ("staking.deactivate", "Desativar"),
+    ("staking.withdraw", "Sacar"),
+    ("staking.split", "Dividir"),
+    ("staking.merge", "Mesclar"),
+    ("staking.new_stake", "Novo stake"),
+    ("staking.rent_note", "Cerca de 0,0023 SOL do valor fica na conta como reserva de rent."),
+    ("staking.search_validator", "Buscar endereço de voto ou identidade"),
+    ("staking.loading_validators", "Carregando validadores..."),
+    ("staking.delinquent", "inadimplente"),
+    ("staking.pick_validator", "Selecione um validador primeiro"),
+    ("staking.exceeds_withdrawable", "O valor excede o saldo disponível para saque"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "Memo (opcional)"),
+    ("send.memo_placeholder", "Exigido por algumas corretoras"),
+    ("send.token_transfer_fee", "taxa de transferência do token (descontada do valor)"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "Gerenciar TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← Voltar"),
+    ("tc.connect_dapp", "Cole um link TON Connect (tc://) de uma dApp"),
+    ("tc.open_link", "Abrir link"),
+    ("tc.invalid_link", "Link inválido:"),
+    ("tc.loading_manifest", "Carregando manifesto do app..."),
+    ("tc.manifest_error", "Não foi possível carregar o manifesto:"),
+    ("tc.connect_request", "quer se conectar à sua carteira TON"),
+    ("tc.proof_requested", "Prova de login solicitada para"),
+    ("tc.password", "Senha"),
+    ("tc.approve", "Aprovar"),
+    ("tc.reject", "Rejeitar"),
+    ("tc.connected", "Conectado a"),
+    ("tc.error", "Erro:"),
+    ("tc.tx_requests", "Solicitações de transação"),
+    ("tc.payload", "com payload"),
+    ("tc.state_init", "implanta um contrato (StateInit)"),
+    ("tc.tx_sent", "Transação enviada"),
+    ("tc.sessions", "dApps conectadas"),
+    ("tc.no_sessions", "Nenhuma dApp conectada"),
+    ("tc.disconnect", "Desconectar"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "Contrato de Carteira TON"),
+    ("settings.ton_wallet_version_hint", "A mesma chave possui uma carteira TON por versão. Escolha a que será exibida e usada para enviar."),
 ];
@@ -109,18 +109,12 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("approve.wallet_not_found", "未找到钱包"),
     ("approve.active_wallet_not_found", "未找到活跃钱包"),
     ("approve.seed_invalid", "种子无效"),
-    ("approve.sendtx_unsupported", "暂不支持通过 dApp 发送 eth_sendTransaction \u{2014} 请直接使用钱包"),
     ("approve.method_unsupported", "不支持的方法："),
     ("approve.error_parsing_wallet", "解析钱包时出错："),
     ("approve.error_parsing_params", "解析参数时出错："),
     ("approve.missing_message_param", "缺少消息参数"),
     ("approve.invalid_hex", "无效的十六进制："),
     ("approve.missing_typed_data", "缺少类型化数据参数"),
-    ("approve.missing_domain", "缺少 domain 字段"),
-    ("approve.missing_message_field", "缺少 message 字段"),
-    ("approve.missing_primary_type", "缺少 primaryType 字段"),
-    ("approve.missing_types", "缺少 types 字段"),
-    ("approve.type_not_found", "未找到类型："),
     ("wc.back", "返回"),
     ("wc.title", "WalletConnect"),
     ("wc.config", "配置"),
@@ -161,10 +155,10 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("wc_proposal.unknown_dapp", "未知 dApp"),
     ("approvals.title", "代币授权"),
     ("approvals.management", "授权管理"),
-    ("approvals.management_desc", "检查并撤销对 DEX 合约的 ERC-20 授权。无限授权可能存在安全风险。"),
+    ("approvals.management_desc", "授权通过您地址的链上 Approval 事件发现。无限授权可能带来安全风险。"),
     ("approvals.evm_only", "代币授权仅适用于 EVM 链。"),
     ("approvals.scanning", "正在扫描授权..."),
-    ("approvals.no_approvals", "未找到已知代币的活跃授权。"),
+    ("approvals.no_approvals", "未找到有效授权。"),
     ("approvals.no_rpc", "没有可用的 RPC URL"),
     ("approvals.revoke", "撤销"),
     ("approvals.revoke_error", "编码撤销时出错："),
@@ -331,4 +325,316 @@ pub const TRANSLATIONS: &[(&str, &str)] = &[
     ("security.encryption", "AES-256-GCM 加密"),
     ("security.key_derivation", "PBKDF2-HMAC-SHA256 (600,000 轮)"),
     ("security.local_only", "密钥永远不会离开您的设备"),
+    // EVM fees
+    ("fee.title", "网络费用"),
+    ("fee.slow", "慢"),
+    ("fee.normal", "标准"),
+    ("fee.fast", "快"),
+    ("fee.custom", "自定义"),
+    ("fee.max_fee", "最高费用 (Gwei)"),
+    ("fee.priority_fee", "优先费 (Gwei)"),
+    ("fee.gas_limit", "Gas 上限"),
+    ("fee.estimated", "预估费用"),
+    ("fee.max", "最高"),
+    ("fee.loading", "正在加载费用..."),
+    ("fee.unavailable", "无法获取费用数据："),
+    ("fee.invalid", "请选择有效的网络费用"),
+    ("approve.unsupported_chain", "dApp 请求了不支持的网络"),
+    ("approve.missing_chain", "dApp 未指定要使用的网络"),
+    // Pending EVM transactions
+    ("history.pending", "待确认"),
+    ("history.pending_tx", "待确认"),
+    ("history.sped_up", "已加速"),
+    ("history.cancelling", "取消中"),
+    ("history.speed_up", "加速"),
+    ("history.cancel_tx", "取消"),
+    ("history.speed_up_hint", "以更高的费用（至少 +10%）重新发送同一笔交易。"),
+    ("history.cancel_hint", "用一笔发给自己的 0 金额交易替换原交易。仅在原交易尚未被打包时有效。"),
+    // Custom EVM networks
+    ("networks.title", "自定义网络"),
+    ("networks.manage", "管理网络"),
+    ("networks.hint", "添加任意 EVM 兼容链。所有 EVM 网络都使用您的以太坊地址。"),
+    ("networks.add", "+ 添加"),
+    ("networks.chain_id", "链 ID"),
+    ("networks.name", "网络名称"),
+    ("networks.ticker", "货币符号"),
+    ("networks.rpc_url", "RPC 地址"),
+    ("networks.explorer_url", "区块浏览器（可选）"),
+    ("networks.save", "保存网络"),
+    ("networks.delete", "移除"),
+    ("networks.empty", "暂无自定义网络"),
+    ("networks.invalid_chain_id", "链 ID 必须是正整数"),
+    ("networks.checking_rpc", "正在检查 RPC..."),
+    ("approve.add_network", "添加网络"),
+    ("approve.switch_network", "切换网络"),
+    ("approve.add_network_warning", "恶意网络可能显示虚假的余额和交易。请只添加来自可信网站的网络。"),
+    // ENS
+    ("ens.resolving", "正在解析 ENS 名称..."),
+    ("ens.not_resolved", "请等待 ENS 名称解析完成后再发送"),
+    ("approve.to", "接收方"),
+    // Safe multisig
+    ("safe.title", "Safe 多签"),
+    ("safe.manage", "管理 Safe"),
+    ("safe.add", "+ 添加"),
+    ("safe.hint", "关注你共同拥有的 Safe，以签名、执行和提议交易。"),
+    ("safe.address", "Safe 地址"),
+    ("safe.name", "名称（可选）"),
+    ("safe.network", "网络"),
+    ("safe.save", "添加 Safe"),
+    ("safe.empty", "暂无 Safe"),
+    ("safe.checking", "正在检查 Safe..."),
+    ("safe.already_added", "该 Safe 已在列表中"),
+    ("safe.not_a_safe", "该网络上不是 Safe："),
+    ("safe.no_rpc", "此网络没有 RPC"),
+    ("safe.info_error", "无法读取 Safe："),
+    ("safe.queue_error", "无法加载队列："),
+    ("safe.threshold", "阈值"),
+    ("safe.you_are_owner", "此钱包是所有者"),
+    ("safe.not_owner", "仅查看：此钱包不是所有者"),
+    ("safe.queue", "待处理交易"),
+    ("safe.queue_empty", "没有等待签名的交易"),
+    ("safe.contract_call", "合约调用"),
+    ("safe.delegatecall_warning", "⚠ DELEGATECALL：目标代码将完全控制该 Safe"),
+    ("safe.confirmations", "签名"),
+    ("safe.sign", "签名"),
+    ("safe.execute", "执行"),
+    ("safe.propose", "从 Safe 发起新转账"),
+    ("safe.propose_button", "提议并签名"),
+    ("safe.signed", "已添加签名"),
+    ("safe.proposed", "已提议，nonce"),
+    // Permit / Permit2 / Seaport signatures
+    ("permit.kind_permit", "代币 Permit（链下授权）"),
+    ("permit.kind_permit2", "Permit2 授权额度"),
+    ("permit.kind_permit2_transfer", "Permit2 代币转账"),
+    ("permit.kind_seaport", "Seaport 挂单"),
+    ("permit.spender", "授权对象"),
+    ("permit.expiry", "到期"),
+    ("permit.unlimited", "无限"),
+    ("permit.never", "永不"),
+    ("permit.expired", "已过期"),
+    ("permit.in", "剩余"),
+    ("permit.high_risk_title", "此签名可能会转走你的代币"),
+    ("permit.review_hint", "不会发送交易，但签名后授权对象即可转移这些代币。"),
+    ("permit.risk_unlimited", "金额无限。"),
+    ("permit.risk_unknown_spender", "授权对象不是已知合约。"),
+    ("permit.risk_far_expiry", "有效期非常长。"),
+    ("permit.risk_no_payment", "你出售的物品得不到任何回报。"),
+    // Transaction simulation
+    ("sim.title", "预计余额变化"),
+    ("sim.would_fail", "此交易预计会失败"),
+    ("sim.unavailable", "无法获取资产变化：此 RPC 不支持模拟。"),
+    ("sim.no_changes", "未检测到您账户的资产变化。"),
+    ("sim.approvals", "授予的授权"),
+    ("sim.all_items", "全部物品"),
+    ("sim.revoked", "已撤销"),
+    ("sim.risky_approval", "无限授权"),
+    ("sim.risky_approval_hint", "在您撤销授权之前，被授权方可以随时转移这些资产。"),
+    ("sim.gas_excluded", "不含网络费用。"),
+    // NFT transfers
+    ("nft.send", "发送 NFT"),
+    ("nft.review", "检查转账"),
+    ("nft.simulating", "正在模拟转账..."),
+    ("nft.simulation_ok", "模拟成功：该 NFT 将转出您的钱包。"),
+    // Custom tokens
+    ("tokens.title", "导入代币"),
+    ("tokens.import", "+ 导入"),
+    ("tokens.address", "合约 / 铸币地址"),
+    ("tokens.lookup", "查询"),
+    ("tokens.looking_up", "正在读取代币元数据..."),
+    ("tokens.lookup_failed", "无法读取代币"),
+    ("tokens.already_listed", "该代币已在默认列表中"),
+    ("tokens.warning_title", "请核实地址"),
+    ("tokens.warning", "任何人都可以用任意名称和符号创建代币。请只导入项目官方渠道公布的地址。"),
+    ("tokens.symbol", "符号"),
+    ("tokens.name", "名称"),
+    ("tokens.decimals", "小数位"),
+    ("tokens.add", "添加代币"),
+    ("tokens.remove", "移除"),
+    ("tokens.empty", "此网络上没有导入的代币"),
+    ("tokens.unsupported_chain", "此网络没有钱包支持的代币标准"),
+    // EIP-7702 delegation
+    ("approve.delegation_title", "账户委托 (EIP-7702)"),
+    ("approve.delegation_unknown", "未知的委托目标"),
+    ("approve.delegation_unknown_hint", "该合约将控制你的账户及其中所有资产。它不是已知的、经过审计的委托合约。仅在完全信任此 dApp 时继续。"),
+    ("approve.delegation_any_chain", "委托在所有链上有效"),
+    ("approve.delegation_any_chain_hint", "此授权使用 chain id 0，可在你账户存在的任何 EVM 网络上重放。"),
+    ("approve.delegation_unknown_contract", "未知合约"),
+    ("approve.delegation_revoke", "移除当前委托"),
+    ("approve.delegation_all_chains", "所有链"),
+    ("approve.delegation_presigned", "由其他账户签名；由此交易转发"),
+    // Sign-In with Ethereum (EIP-4361)
+    ("siwe.title", "登录请求"),
+    ("siwe.address", "账户"),
+    ("siwe.uri", "URI"),
+    ("siwe.chain", "网络"),
+    ("siwe.nonce", "Nonce"),
+    ("siwe.issued_at", "签发时间"),
+    ("siwe.expires", "过期时间"),
+    ("siwe.not_before", "生效时间"),
+    ("siwe.request_id", "请求 ID"),
+    ("siwe.resources", "资源"),
+    ("siwe.domain_mismatch", "为其他网站登录"),
+    ("siwe.domain_mismatch_hint", "此消息用于登录 {domain}，但请求来自 {origin}。签名后 {origin} 可能访问你在 {domain} 的账户。这是常见的钓鱼手段。"),
+    ("siwe.scheme_mismatch", "协议不匹配"),
+    ("siwe.scheme_mismatch_hint", "消息中的协议（http/https）与请求页面不同。"),
+    ("siwe.address_mismatch", "账户不同"),
+    ("siwe.address_mismatch_hint", "消息中的地址不是你当前的账户。"),
+    ("siwe.chain_mismatch", "网络不同"),
+    ("siwe.chain_mismatch_hint", "消息针对链 {message}，但网站连接的是链 {active}。"),
+    ("siwe.invalid_nonce", "Nonce 过弱"),
+    ("siwe.invalid_nonce_hint", "Nonce 应至少包含 8 个随机字母或数字。可预测的 nonce 会让签名被重放。"),
+    ("siwe.expired", "登录请求已过期"),
+    ("siwe.expired_hint", "已超过过期时间。请刷新网站后重新登录。"),
+    ("siwe.not_yet_valid", "登录请求尚未生效"),
+    ("siwe.not_yet_valid_hint", "该消息要到之后才生效，因此可能在你离开网站后被使用。"),
+    ("siwe.invalid_time", "日期无效"),
+    ("siwe.invalid_time_hint", "消息中的某个时间戳格式错误，无法检查有效期。"),
+    ("siwe.unbound", "网站未验证"),
+    ("siwe.unbound_hint", "此请求来自 WalletConnect，钱包无法确认它由消息中所述的网站发出。"),
+    ("siwe.chain_unknown", "网络未验证"),
+    ("siwe.chain_unknown_hint", "请求未说明网站连接的网络，因此无法检查消息中的链。"),
+    ("siwe.malformed", "登录消息格式错误"),
+    ("siwe.malformed_hint", "这看起来像 Sign-In with Ethereum 消息，但不符合标准（{error}），因此无法检查它授权访问的网站、账户和网络。正规网站不会发送格式错误的登录消息。"),
+    // Signature verification tool
+    ("verify.title", "验证签名"),
+    ("verify.open", "打开验证工具"),
+    ("verify.hint", "检查消息由谁签名。支持普通账户和智能合约钱包（ERC-1271），包括尚未部署的钱包（ERC-6492）。"),
+    ("verify.network", "网络"),
+    ("verify.signer", "签名者地址"),
+    ("verify.message_type", "消息类型"),
+    ("verify.kind_personal", "消息（personal_sign）"),
+    ("verify.kind_typed", "结构化数据（EIP-712 JSON）"),
+    ("verify.kind_hash", "哈希（32 字节）"),
+    ("verify.message", "消息"),
+    ("verify.signature", "签名"),
+    ("verify.check", "验证"),
+    ("verify.checking", "验证中..."),
+    ("verify.valid", "签名有效"),
+    ("verify.invalid", "签名无效"),
+    ("verify.method", "验证方式"),
+    ("verify.recovered", "实际签名者"),
+    ("verify.no_rpc", "该网络没有 RPC 节点"),
+    // Approval discovery
+    ("approvals.all_items", "全部物品"),
+    ("approvals.scanned_to", "已扫描至区块 {block}"),
+    ("approvals.scan_more", "扫描更早的区块"),
+    ("approvals.scan_error", "日志扫描中断："),
+    ("approvals.found", "{n} 个有效授权"),
+    ("approvals.select_all", "全选"),
+    ("approvals.unknown_spender", "未知合约"),
+    ("approvals.spender", "授权对象"),
+    ("approvals.allowance", "额度"),
+    ("approvals.revoke_selected", "撤销所选（{n}）"),
+    ("approvals.confirm_revoke", "撤销 {n} 个授权？每个授权将发送一笔交易。"),
+    ("approvals.revoked_count", "已发送 {n} 笔撤销交易"),
+    // SPL token transfers
+    ("send.ata_rent", "含为收款人新建代币账户的租金"),
+    // Solana dApp signing (Wallet Standard / WalletConnect)
+    ("approve.solana_connection", "Solana 钱包连接"),
+    ("approve.solana_sign_tx", "签署 Solana 交易"),
+    ("approve.solana_sign_all", "签署多笔 Solana 交易"),
+    ("approve.solana_send_tx", "发送 Solana 交易"),
+    ("approve.solana_sign_message", "签署消息 (Solana)"),
+    ("approve.solana_sign_in", "使用 Solana 登录"),
+    ("approve.solana_disguised_tx", "此消息实际上是一笔交易"),
+    ("approve.solana_disguised_tx_hint", "该网站请求签署的字节构成一笔 Solana 交易。签署将授权该交易，因此 Rusby 会拒绝。"),
+    ("approve.solana_tx", "交易"),
+    ("approve.solana_tx_n", "第 {n} 笔，共 {count} 笔"),
+    ("approve.solana_decode_failed", "无法解码交易"),
+    ("solana_review.raw_units", "单位（未知代币）"),
+    ("solana_review.you", "你"),
+    ("solana_review.sol_transfer", "SOL 转账"),
+    ("solana_review.create_account", "创建账户"),
+    ("solana_review.assign", "将账户分配给程序"),
+    ("solana_review.token_transfer", "代币转账"),
+    ("solana_review.token_approve", "代币委托授权"),
+    ("solana_review.set_authority", "更改代币账户权限"),
+    ("solana_review.none", "无"),
+    ("solana_review.close_account", "关闭代币账户"),
+    ("solana_review.create_token_account", "创建代币账户"),
+    ("solana_review.memo", "备注"),
+    ("solana_review.compute_limit", "计算单元上限"),
+    ("solana_review.priority_fee", "优先费"),
+    ("solana_review.program_call", "程序调用"),
+    ("solana_review.not_signer", "不是你的交易"),
+    ("solana_review.not_signer_hint", "你的账户不是该交易的签名者，因此无法签署。"),
+    ("solana_review.risk_assign", "账户所有权变更"),
+    ("solana_review.risk_assign_hint", "你的钱包账户将被转交给另一个程序，该程序随后可转走全部 SOL。盗币程序常用此手法。"),
+    ("solana_review.risk_authority", "代币账户权限转移"),
+    ("solana_review.risk_authority_hint", "你的一个代币账户的控制权将转给另一个地址，连同其现在及以后持有的所有代币。"),
+    ("solana_review.risk_delegate", "代币支出授权"),
+    ("solana_review.risk_delegate_hint", "被委托方之后可以直接转走你的代币，无需再次确认。"),
+    ("solana_review.risk_close", "代币账户将被关闭"),
+    ("solana_review.risk_close_hint", "你的一个代币账户将被关闭，其租金押金转到另一个地址。"),
+    ("solana_review.risk_unknown_program", "未知程序"),
+    ("solana_review.sponsored", "支付方"),
+    ("solana_review.outgoing", "从你的账户转出的 SOL"),
+    ("solana_review.network_fee", "网络费用"),
+    // Solana priority fees
+    ("fee.solana_priority", "优先费"),
+    ("fee.per_compute_unit", "每计算单元的微 lamport"),
+    ("fee.compute_units", "计算单元"),
+    // Native SOL staking
+    ("dashboard.stake", "质押"),
+    ("staking.title", "SOL 质押"),
+    ("staking.about", "原生质押"),
+    ("staking.about_desc", "质押资金保存在由您控制的质押账户中。新质押在下一个纪元生效;停用的质押在纪元结束后可提取。"),
+    ("staking.total_staked", "质押总额"),
+    ("staking.epoch", "纪元"),
+    ("staking.accounts", "质押账户"),
+    ("staking.loading", "正在加载质押账户..."),
+    ("staking.no_accounts", "暂无质押账户"),
+    ("staking.load_error", "无法加载质押账户:"),
+    ("staking.validators_error", "无法加载验证者:"),
+    ("staking.undelegated", "未委托"),
+    ("staking.activating", "激活中"),
+    ("staking.active", "活跃"),
+    ("staking.deactivating", "停用中"),
+    ("staking.inactive", "未激活"),
+    ("staking.validator", "验证者"),
+    ("staking.locked", "锁定期内:无法提取"),
+    ("staking.stake", "质押"),
+    ("staking.delegate", "委托"),
+    ("staking.deactivate", "停用"),
+    ("staking.withdraw", "提取"),
+    ("staking.split", "拆分"),
+    ("staking.merge", "合并"),
+    ("staking.new_stake", "新质押"),
+    ("staking.rent_note", "金额中约 0.0023 SOL 作为租金储备留在质押账户中。"),
+    ("staking.search_validator", "搜索投票或身份地址"),
+    ("staking.loading_validators", "正在加载验证者..."),
+    ("staking.delinquent", "失效"),
+    ("staking.pick_validator", "请先选择验证者"),
+    ("staking.exceeds_withdrawable", "金额超过可提取余额"),
+    // Token-2022: memo and transfer fee
+    ("send.memo", "备注（可选）"),
+    ("send.memo_placeholder", "部分交易所要求填写"),
+    ("send.token_transfer_fee", "代币转账费（从金额中扣除）"),
+    // TON Connect
+    ("settings.ton_connect", "TON Connect"),
+    ("settings.manage_ton_connect", "管理 TON Connect"),
+    ("tc.title", "TON Connect"),
+    ("tc.back", "← 返回"),
+    ("tc.connect_dapp", "粘贴 dApp 的 TON Connect 链接 (tc://)"),
+    ("tc.open_link", "打开链接"),
+    ("tc.invalid_link", "无效链接："),
+    ("tc.loading_manifest", "正在加载应用清单..."),
+    ("tc.manifest_error", "无法加载应用清单："),
+    ("tc.connect_request", "请求连接你的 TON 钱包"),
+    ("tc.proof_requested", "请求登录证明："),
+    ("tc.password", "密码"),
+    ("tc.approve", "批准"),
+    ("tc.reject", "拒绝"),
+    ("tc.connected", "已连接到"),
+    ("tc.error", "错误："),
+    ("tc.tx_requests", "交易请求"),
+    ("tc.payload", "含 payload"),
+    ("tc.state_init", "部署合约 (StateInit)"),
+    ("tc.tx_sent", "交易已发送"),
+    ("tc.sessions", "已连接的 dApp"),
+    ("tc.no_sessions", "没有已连接的 dApp"),
+    ("tc.disconnect", "断开"),
+    // TON wallet version
+    ("settings.ton_wallet_version", "TON 钱包合约"),
+    ("settings.ton_wallet_version_hint", "同一密钥在每个版本下各拥有一个 TON 钱包。请选择要显示和用于发送的钱包。"),
 ];
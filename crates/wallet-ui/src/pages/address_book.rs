@@ -20,6 +20,7 @@ pub fn AddressBookPage() -> impl IntoView {
     let (form_chain, set_form_chain) = signal(String::new());
     let (form_notes, set_form_notes) = signal(String::new());
     let (show_form, set_show_form) = signal(false);
+    let (form_error, set_form_error) = signal(String::new());
 
     let clear_form = move || {
         set_form_name.set(String::new());
@@ -27,6 +28,7 @@ pub fn AddressBookPage() -> impl IntoView {
         set_form_chain.set(String::new());
         set_form_notes.set(String::new());
         set_editing.set(None);
+        set_form_error.set(String::new());
         set_show_form.set(false);
     };
 
@@ -49,17 +51,37 @@ pub fn AddressBookPage() -> impl IntoView {
             },
         };
 
-        let mut list = contacts.get();
-        if let Some(idx) = editing.get() {
-            if idx < list.len() {
-                list[idx] = contact;
+        let editing_idx = editing.get();
+        let store = move |contact: Contact| {
+            let mut list = contacts.get_untracked();
+            if let Some(idx) = editing_idx {
+                if idx < list.len() {
+                    list[idx] = contact;
+                }
+            } else {
+                list.push(contact);
             }
-        } else {
-            list.push(contact);
+            save_address_book(&list);
+            set_contacts.set(list);
+            clear_form();
+        };
+
+        if !wallet_core::ens::looks_like_ens_name(&contact.address) {
+            store(contact);
+            return;
         }
-        save_address_book(&list);
-        set_contacts.set(list);
-        clear_form();
+        // ENS contacts are stored by name (resolved again at send time); refuse names that don't resolve
+        set_form_error.set(t("ens.resolving"));
+        wasm_bindgen_futures::spawn_local(async move {
+            let Some(rpc_url) = crate::rpc::ens::ens_rpc_url(false) else { return };
+            match wallet_core::ens::normalize(&contact.address) {
+                Ok(name) => match crate::rpc::ens::resolve_name(&name, wallet_core::ens::ETH_COIN_TYPE, &rpc_url).await {
+                    Ok(_) => store(Contact { address: name, ..contact }),
+                    Err(e) => set_form_error.set(e),
+                },
+                Err(e) => set_form_error.set(e),
+            }
+        });
     };
 
     let start_edit = move |idx: usize| {
@@ -153,6 +175,12 @@ pub fn AddressBookPage() -> impl IntoView {
                                 on:input=move |ev| set_form_notes.set(event_target_value(&ev))
                             />
                         </div>
+                        {move || {
+                            let e = form_error.get();
+                            (!e.is_empty()).then(|| view! {
+                                <p class="text-sm mb-2" style="color: var(--warning, #ff9800);">{e}</p>
+                            })
+                        }}
                         <div class="flex gap-2">
                             <button class="btn btn-primary flex-1" on:click=save_contact>
                                 {move || t("addressbook.save")}
@@ -3,113 +3,167 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use leptos::prelude::*;
+use wallet_core::tokens::approvals::{self, Allowance, ApprovalKind};
+use wallet_core::tx::evm::parse_address;
 
 use crate::state::*;
 use crate::i18n::t;
-use crate::rpc::approvals::{check_approvals_for_token, ApprovalInfo};
+use crate::rpc::approvals::ApprovalInfo;
+use crate::tx_send::evm::EvmCall;
 use crate::components::security_warning::{SecurityWarning, Severity};
 
+/// eth_getLogs requests per scan, so a first visit on a long chain stays responsive
+const LOG_REQUESTS_PER_SCAN: usize = 40;
+
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..8], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
+}
+
+/// Stable key of a row for selection
+fn row_key(a: &ApprovalInfo) -> String {
+    format!("{}:{}:{:?}", a.contract.to_lowercase(), a.spender.to_lowercase(), a.kind)
+}
+
+fn allowance_label(a: &ApprovalInfo) -> String {
+    match (a.kind, a.allowance, a.decimals) {
+        (ApprovalKind::Operator, _, _) => t("approvals.all_items"),
+        (_, Allowance::Unlimited, _) => t("permit.unlimited"),
+        (_, Allowance::Amount(v), Some(d)) => wallet_core::tokens::erc20::format_token_amount(v, d),
+        (_, Allowance::Amount(v), None) => v.to_string(),
+    }
+}
+
 #[component]
 pub fn ApprovalsPage() -> impl IntoView {
     let wallet_state: ReadSignal<WalletState> = expect_context();
     let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
 
     let (approvals, set_approvals) = signal::<Vec<ApprovalInfo>>(vec![]);
     let (loading, set_loading) = signal(false);
+    let (progress, set_progress) = signal(String::new());
+    let (scan_complete, set_scan_complete) = signal(true);
     let (error, set_error) = signal::<Option<String>>(None);
-    let (revoking, set_revoking) = signal::<Option<String>>(None); // spender being revoked
+    let (selected, set_selected) = signal::<Vec<String>>(vec![]);
+    let (confirming, set_confirming) = signal(false);
+    let (password, set_password) = signal(String::new());
+    let (revoking, set_revoking) = signal(false);
+    let (result_msg, set_result_msg) = signal(String::new());
 
-    // Scan approvals on mount
-    Effect::new(move |_| {
-        let (is_unlocked, chain, owner, tokens) = wallet_state.with(|s| (
-            s.is_unlocked,
-            s.active_chain.clone(),
-            s.current_address(),
-            s.token_balances.get(&s.active_chain).cloned().unwrap_or_default(),
-        ));
-        if !is_unlocked { return; }
-        if owner.is_empty() { return; }
-
-        // Only EVM chains support ERC-20 approvals
-        let is_evm = matches!(chain.as_str(), "ethereum" | "polygon" | "bsc" | "optimism" | "base" | "arbitrum");
-        if !is_evm { return; }
-
-        let rpc_url = wallet_core::chains::supported_chains()
-            .into_iter()
-            .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
-            .and_then(|c| c.rpc_urls.first().cloned());
-
-        let rpc_url = match rpc_url {
-            Some(u) => u,
-            None => return,
+    let chain = move || wallet_state.with(|s| s.active_chain.clone());
+    let is_evm = move || is_evm_chain(&chain());
+    let rpc_url_for = move |chain: &str| all_chains(testnet_mode.get_untracked()).into_iter()
+        .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
+        .and_then(|c| c.rpc_urls.first().cloned());
+
+    // Scan the logs (incrementally, from the saved progress), then read current state
+    let scan = move || {
+        let (chain, owner) = wallet_state.with_untracked(|s| (s.active_chain.clone(), s.current_address()));
+        if owner.is_empty() || !is_evm_chain(&chain) || loading.get_untracked() { return; }
+        let Some(rpc_url) = rpc_url_for(&chain) else {
+            set_error.set(Some(t("approvals.no_rpc")));
+            return;
         };
 
         set_loading.set(true);
         set_error.set(None);
-
+        set_progress.set(t("approvals.scanning"));
         wasm_bindgen_futures::spawn_local(async move {
-            let mut all_approvals = Vec::new();
-
-            for token in &tokens {
-                let results = check_approvals_for_token(
-                    &rpc_url,
-                    &token.token.address,
-                    &token.token.symbol,
-                    &owner,
-                    &chain,
-                ).await;
-                all_approvals.extend(results);
+            let mut state = load_approval_scan(&chain, &owner);
+            match crate::rpc::approvals::scan_logs(&rpc_url, &owner, &mut state, LOG_REQUESTS_PER_SCAN).await {
+                Ok(latest) => {
+                    set_scan_complete.set(state.is_complete(latest));
+                    set_progress.set(match state.oldest {
+                        Some(0) | None => String::new(),
+                        Some(oldest) => t("approvals.scanned_to").replace("{block}", &oldest.to_string()),
+                    });
+                }
+                Err(e) => set_error.set(Some(format!("{} {}", t("approvals.scan_error"), e))),
             }
+            save_approval_scan(&chain, &owner, &state);
 
-            set_approvals.set(all_approvals);
+            let active = crate::rpc::approvals::active_approvals(&rpc_url, &owner, &chain, &state.candidates).await;
+            set_selected.set(vec![]);
+            set_approvals.set(active);
             set_loading.set(false);
         });
+    };
+
+    Effect::new(move |_| {
+        let ready = wallet_state.with(|s| s.is_unlocked && !s.current_address().is_empty());
+        let _ = chain();
+        if ready { scan(); }
     });
 
     let go_back = move |_| set_page.set(AppPage::Settings);
 
-    let revoke_approval = move |token_addr: String, spender_addr: String| {
-        let (chain, from) = wallet_state.with(|s| (s.active_chain.clone(), s.current_address()));
+    let toggle = move |key: String| set_selected.update(|sel| {
+        if let Some(pos) = sel.iter().position(|k| *k == key) {
+            sel.remove(pos);
+        } else {
+            sel.push(key);
+        }
+    });
 
-        let rpc_url = wallet_core::chains::supported_chains()
-            .into_iter()
-            .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
-            .and_then(|c| c.rpc_urls.first().cloned());
+    let revoke_selected = move |_| {
+        if revoking.get_untracked() { return; }
+        let keys = selected.get_untracked();
+        let targets: Vec<ApprovalInfo> = approvals.get_untracked().into_iter()
+            .filter(|a| keys.contains(&row_key(a)))
+            .collect();
+        if targets.is_empty() { return; }
 
-        let _rpc_url = match rpc_url {
-            Some(u) => u,
-            None => {
-                set_error.set(Some(t("approvals.no_rpc")));
+        let calls: Result<Vec<EvmCall>, String> = targets.iter().map(|a| Ok(EvmCall {
+            to: parse_address(&a.contract)?,
+            value: 0,
+            data: approvals::encode_revoke(a.kind, &a.spender)?,
+            gas_fallback: wallet_core::tx::evm_fees::CONTRACT_CALL_GAS_FALLBACK,
+        })).collect();
+        let calls = match calls {
+            Ok(c) => c,
+            Err(e) => {
+                set_error.set(Some(format!("{} {}", t("approvals.revoke_error"), e)));
                 return;
             }
         };
 
-        set_revoking.set(Some(spender_addr.clone()));
-
+        let (chain, owner) = wallet_state.with_untracked(|s| (s.active_chain.clone(), s.current_address()));
+        let pwd = password.get_untracked();
+        let testnet = testnet_mode.get_untracked();
+        set_revoking.set(true);
+        set_error.set(None);
         wasm_bindgen_futures::spawn_local(async move {
-            // Encode approve(spender, 0)
-            match wallet_core::tokens::erc20::encode_revoke(&spender_addr) {
-                Ok(calldata) => {
-                    let hex_data = format!("0x{}", hex::encode(&calldata));
-                    // Build TX and send via background
-                    let data = serde_json::json!({
-                        "from": from,
-                        "to": token_addr,
-                        "value": "0x0",
-                        "data": hex_data,
-                        "chain": chain,
-                    });
-                    let _ = send_to_background("__rusby_send_tx", &data).await;
-                    // Remove from list optimistically
-                    set_approvals.update(|list| {
-                        list.retain(|a| !(a.token_address == token_addr && a.spender_address == spender_addr));
-                    });
-                }
-                Err(e) => {
-                    set_error.set(Some(format!("{} {}", t("approvals.revoke_error"), e)));
+            match crate::tx_send::execute_contract_calls(&chain, calls, &pwd, testnet).await {
+                Ok(results) => {
+                    let mut state = load_approval_scan(&chain, &owner);
+                    let mut revoked = Vec::new();
+                    let mut failures = Vec::new();
+                    for (target, result) in targets.iter().zip(results) {
+                        match result {
+                            Ok(_) => {
+                                state.remove(&target.contract, &target.spender, target.kind);
+                                revoked.push(row_key(target));
+                            }
+                            Err(e) => failures.push(format!("{}: {}", target.symbol, e)),
+                        }
+                    }
+                    save_approval_scan(&chain, &owner, &state);
+                    set_approvals.update(|list| list.retain(|a| !revoked.contains(&row_key(a))));
+                    set_selected.update(|sel| sel.retain(|k| !revoked.contains(k)));
+                    set_result_msg.set(t("approvals.revoked_count").replace("{n}", &revoked.len().to_string()));
+                    if !failures.is_empty() {
+                        set_error.set(Some(failures.join("\n")));
+                    }
+                    set_confirming.set(false);
+                    set_password.set(String::new());
                 }
+                Err(e) => set_error.set(Some(e)),
             }
-            set_revoking.set(None);
+            set_revoking.set(false);
         });
     };
 
@@ -129,102 +183,136 @@ pub fn ApprovalsPage() -> impl IntoView {
                 dismissable=true
             />
 
+            {move || (!is_evm()).then(|| view! {
+                <div class="card">
+                    <p class="text-sm text-muted">{t("approvals.evm_only")}</p>
+                </div>
+            })}
+
             {move || {
-                let chain = wallet_state.with(|s| s.active_chain.clone());
-                let is_evm = matches!(chain.as_str(), "ethereum" | "polygon" | "bsc" | "optimism" | "base" | "arbitrum");
-                if !is_evm {
-                    return Some(view! {
-                        <div class="card">
-                            <p class="text-sm text-muted">{t("approvals.evm_only")}</p>
-                        </div>
-                    }.into_any());
-                }
-                None
+                let msg = progress.get();
+                (is_evm() && !msg.is_empty()).then(|| view! {
+                    <div class="card" style="text-align: center;">
+                        <p class="text-sm">{msg}</p>
+                        {(!loading.get() && !scan_complete.get()).then(|| view! {
+                            <button class="btn btn-secondary btn-sm" style="margin-top: 8px;" on:click=move |_| scan()>
+                                {t("approvals.scan_more")}
+                            </button>
+                        })}
+                    </div>
+                })
             }}
 
             {move || {
-                if loading.get() {
-                    Some(view! {
-                        <div class="card" style="text-align: center;">
-                            <p>{t("approvals.scanning")}</p>
-                        </div>
-                    })
-                } else {
-                    None
-                }
+                error.get().map(|err| view! {
+                    <div class="card" style="border: 1px solid #e74c3c;">
+                        <p style="color: #e74c3c; white-space: pre-wrap;">{err}</p>
+                    </div>
+                })
             }}
 
             {move || {
-                if let Some(err) = error.get() {
-                    Some(view! {
-                        <div class="card" style="border: 1px solid #e74c3c;">
-                            <p style="color: #e74c3c;">{err}</p>
-                        </div>
-                    })
-                } else {
-                    None
-                }
+                let msg = result_msg.get();
+                (!msg.is_empty()).then(|| view! {
+                    <div class="card"><p class="text-sm" style="color: var(--success, #4caf50);">{msg}</p></div>
+                })
             }}
 
             {move || {
                 let list = approvals.get();
-                if !loading.get() && list.is_empty() {
-                    return Some(view! {
+                if !is_evm() { return None; }
+                if list.is_empty() {
+                    return (!loading.get()).then(|| view! {
                         <div class="card">
                             <p class="text-sm text-muted">{t("approvals.no_approvals")}</p>
                         </div>
                     }.into_any());
                 }
 
-                if list.is_empty() {
-                    return None;
-                }
-
+                let all_keys: Vec<String> = list.iter().map(row_key).collect();
                 Some(view! {
+                    <div class="flex justify-between" style="align-items: center; margin-bottom: 8px;">
+                        <span class="text-sm text-muted">{t("approvals.found").replace("{n}", &list.len().to_string())}</span>
+                        <button class="btn btn-secondary btn-sm" on:click=move |_| {
+                            let everything = selected.get_untracked().len() == all_keys.len();
+                            set_selected.set(if everything { vec![] } else { all_keys.clone() });
+                        }>
+                            {t("approvals.select_all")}
+                        </button>
+                    </div>
                     <div>
                         {list.into_iter().map(|approval| {
-                            let token_addr = approval.token_address.clone();
-                            let is_unlimited = approval.allowance == "Unlimited";
-                            let token_addr_c = token_addr.clone();
-                            let spender_addr_c = approval.spender_address.clone();
-                            let spender_for_btn = approval.spender_address.clone();
-                            let spender_for_label = approval.spender_address.clone();
-                            let revoke = revoke_approval.clone();
-
+                            let key = row_key(&approval);
+                            let key_checked = key.clone();
+                            let is_risky = approval.allowance == Allowance::Unlimited;
+                            let spender_name = approval.spender_name.clone().unwrap_or_else(|| t("approvals.unknown_spender"));
+                            let kind_label = match approval.kind {
+                                ApprovalKind::Allowance => "ERC-20",
+                                ApprovalKind::Operator => "NFT",
+                            };
                             view! {
-                                <div class="card" style="margin-bottom: 8px;">
-                                    <div style="display: flex; justify-content: space-between; align-items: center;">
-                                        <div>
-                                            <p style="font-weight: bold; margin: 0;">
-                                                {approval.token_symbol.clone()}
-                                            </p>
-                                            <p class="text-sm text-muted" style="margin: 2px 0;">
-                                                {format!("Spender: {}", approval.spender_name)}
-                                            </p>
-                                            <p class="text-sm" style="margin: 2px 0; font-family: monospace; font-size: 0.7rem;">
-                                                {format!("{}...{}", &approval.spender_address[..6], &approval.spender_address[approval.spender_address.len()-4..])}
-                                            </p>
-                                            <p class="text-sm" style=format!("margin: 2px 0; color: {};", if is_unlimited { "#e74c3c" } else { "inherit" })>
-                                                {format!("Allowance: {}", approval.allowance)}
-                                            </p>
-                                        </div>
-                                        <button
-                                            class="btn btn-danger"
-                                            style="min-width: 80px;"
-                                            disabled=move || revoking.get().as_deref() == Some(spender_for_btn.as_str())
-                                            on:click=move |_| {
-                                                revoke(token_addr_c.clone(), spender_addr_c.clone());
-                                            }
-                                        >
-                                            {move || if revoking.get().as_deref() == Some(spender_for_label.as_str()) { "...".to_string() } else { t("approvals.revoke") }}
-                                        </button>
+                                <label class="card" style="margin-bottom: 8px; display: flex; gap: 10px; align-items: center; cursor: pointer;">
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=move || selected.get().contains(&key_checked)
+                                        on:change=move |_| toggle(key.clone())
+                                    />
+                                    <div style="flex: 1; min-width: 0;">
+                                        <p style="font-weight: bold; margin: 0;">
+                                            {approval.symbol.clone()}
+                                            <span class="text-sm text-muted">{format!(" · {}", kind_label)}</span>
+                                        </p>
+                                        <p class="text-sm" style=format!("margin: 2px 0; {}", if approval.spender_name.is_none() { "color: var(--warning, #ff9800);" } else { "" })>
+                                            {format!("{}: {}", t("approvals.spender"), spender_name)}
+                                        </p>
+                                        <p class="text-sm" style="margin: 2px 0; font-family: monospace; font-size: 0.7rem;">
+                                            {short(&approval.spender)}
+                                        </p>
+                                        <p class="text-sm" style=format!("margin: 2px 0; color: {};", if is_risky { "#e74c3c" } else { "inherit" })>
+                                            {format!("{}: {}", t("approvals.allowance"), allowance_label(&approval))}
+                                        </p>
                                     </div>
-                                </div>
+                                </label>
                             }
                         }).collect::<Vec<_>>()}
                     </div>
                 }.into_any())
             }}
+
+            {move || {
+                let count = selected.get().len();
+                (count > 0).then(|| if confirming.get() {
+                    view! {
+                        <div class="card">
+                            <p class="text-sm mb-2">{t("approvals.confirm_revoke").replace("{n}", &count.to_string())}</p>
+                            <input
+                                type="password"
+                                placeholder=t("confirm.password_placeholder")
+                                prop:value=move || password.get()
+                                on:input=move |ev| set_password.set(event_target_value(&ev))
+                                style="width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border); margin-bottom: 8px;"
+                            />
+                            <div class="flex gap-2">
+                                <button class="btn btn-secondary flex-1" on:click=move |_| set_confirming.set(false)>
+                                    {t("common.cancel")}
+                                </button>
+                                <button class="btn btn-danger flex-1" disabled=move || revoking.get() on:click=revoke_selected>
+                                    {move || if revoking.get() { "...".to_string() } else { t("approvals.revoke") }}
+                                </button>
+                            </div>
+                        </div>
+                    }.into_any()
+                } else {
+                    view! {
+                        <button class="btn btn-danger btn-block" on:click=move |_| {
+                            set_result_msg.set(String::new());
+                            set_confirming.set(true);
+                        }>
+                            {t("approvals.revoke_selected").replace("{n}", &count.to_string())}
+                        </button>
+                    }.into_any()
+                })
+            }}
         </div>
     }
 }
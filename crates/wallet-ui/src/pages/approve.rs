@@ -1,12 +1,24 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// dApp request approval page — handles EIP-1193 and WalletConnect requests
+// dApp request approval page — handles EIP-1193, Solana Wallet Standard and
+// WalletConnect requests
 
 use leptos::prelude::*;
 use crate::state::*;
 use crate::i18n::t;
 use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::components::fee_selector::EvmFeeSelector;
+use crate::components::asset_changes::AssetChangesCard;
+use crate::components::solana_review::SolanaReviewCard;
+use wallet_core::tx::evm_fees::{self, Eip1559Fees, FeeEstimate};
+use wallet_core::tx::eip7702::{self, DelegationReview};
+use wallet_core::tx::evm_simulate::{SimCall, SimulationOutcome};
+use wallet_core::chains::custom_evm;
+use wallet_core::security::permit::{self, PermitAnalysis, PermitKind, PermitRisk};
+use wallet_core::security::siwe::{self, SiweIssue, SiweMessage};
+use wallet_core::security::siws::{self, SiwsMessage};
+use wallet_core::tx::solana_dapp::{self, TransactionReview};
 
 #[component]
 pub fn ApprovePage() -> impl IntoView {
@@ -18,6 +30,16 @@ pub fn ApprovePage() -> impl IntoView {
     let (password, set_password) = signal(String::new());
     let (needs_password, set_needs_password) = signal(false);
     let (signing, set_signing) = signal(false);
+    let (fee_estimate, set_fee_estimate) = signal::<Option<FeeEstimate>>(None);
+    let (gas_limit, set_gas_limit) = signal(evm_fees::NATIVE_TRANSFER_GAS);
+    let (evm_fees, set_evm_fees) = signal::<Option<Eip1559Fees>>(None);
+    // eth_sendTransaction target and its verified ENS primary name
+    let (tx_to, set_tx_to) = signal::<Option<(String, Option<String>)>>(None);
+    // eth_sendTransaction simulation with the chain key and ticker it was run on
+    let (sim_outcome, set_sim_outcome) = signal::<Option<(SimulationOutcome, String, String)>>(None);
+    // Solana transactions to sign, decoded (lookup tables resolved), and the first simulation failure
+    let (sol_reviews, set_sol_reviews) = signal::<Option<Result<Vec<TransactionReview>, String>>>(None);
+    let (sol_sim_error, set_sol_sim_error) = signal::<Option<String>>(None);
 
     // On mount: fetch pending request from background
     Effect::new(move |_| {
@@ -40,6 +62,8 @@ pub fn ApprovePage() -> impl IntoView {
                                 origin: req.get("origin").and_then(|o| o.as_str()).unwrap_or("").to_string(),
                                 method: req.get("method").and_then(|m| m.as_str()).unwrap_or("").to_string(),
                                 params: req.get("params").map(|p| p.to_string()).unwrap_or_default(),
+                                evm_chain_id: parse_request_chain_id(req),
+                                solana_testnet: is_solana_testnet_request(req),
                             }));
                             break;
                         }
@@ -50,6 +74,147 @@ pub fn ApprovePage() -> impl IntoView {
         });
     });
 
+    // eth_sendTransaction: estimate gas and load fee tiers so the user can edit them
+    Effect::new(move |_| {
+        let Some(req) = request.get() else { return };
+        if req.method != "eth_sendTransaction" {
+            return;
+        }
+        let Some(tx) = first_param(&req.params) else { return };
+        let config = match request_chain_config(&req) {
+            Ok(config) => config,
+            Err(e) => {
+                set_status_msg.set(e);
+                return;
+            }
+        };
+        // Flag a foreign sender or chain before the user types a password
+        let our_address = wallet_state.with_untracked(|s| s.addresses.get("ethereum").cloned().unwrap_or_default());
+        let Some(chain_id) = config.evm_chain_id else { return };
+        if let Err(e) = crate::tx_send::evm::check_dapp_tx(&tx, &our_address, chain_id) {
+            set_status_msg.set(e);
+            return;
+        }
+        let Some(rpc_url) = config.rpc_urls.first().cloned() else { return };
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::rpc::evm::get_fee_estimate(&rpc_url).await {
+                Ok(est) => set_fee_estimate.set(Some(est)),
+                Err(e) => set_status_msg.set(format!("{} {}", t("fee.unavailable"), e)),
+            }
+            let dapp_gas = tx.get("gas")
+                .and_then(|v| v.as_str())
+                .and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+                .filter(|g| *g > 0);
+            let gas = match (dapp_gas, crate::tx_send::evm::dapp_call(&tx)) {
+                (Some(g), _) => g,
+                (None, Ok(call)) => {
+                    let from = tx.get("from").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+                    let authorizations = eip7702::parse_authorization_list(&tx).map(|a| a.len()).unwrap_or(0);
+                    crate::tx_send::evm::estimate_call_gas(&from, &call, &rpc_url).await
+                        + eip7702::PER_AUTHORIZATION_GAS * authorizations as u64
+                }
+                (None, Err(e)) => {
+                    set_status_msg.set(e);
+                    return;
+                }
+            };
+            set_gas_limit.set(gas);
+        });
+    });
+
+    // eth_sendTransaction: simulate from the requesting account and show what it moves
+    Effect::new(move |_| {
+        let Some(req) = request.get() else { return };
+        if req.method != "eth_sendTransaction" {
+            return;
+        }
+        let Some(tx) = first_param(&req.params) else { return };
+        // Without the delegated code in place the simulation would describe a different transaction
+        if tx.get("authorizationList").is_some() {
+            return;
+        }
+        let Ok(config) = request_chain_config(&req) else { return };
+        let Some(rpc_url) = config.rpc_urls.first().cloned() else { return };
+        let Ok(call) = crate::tx_send::evm::dapp_call(&tx) else { return };
+        let Some(from) = tx.get("from")
+            .and_then(|v| v.as_str())
+            .and_then(|s| wallet_core::tx::evm::parse_address(s).ok()) else { return };
+        let sim_call = SimCall { from, to: call.to, value: call.value, data: call.data };
+        let chain = crate::tx_send::chain_id_to_string(&config.id);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Ok(outcome) = crate::rpc::simulate::simulate_asset_changes(&rpc_url, &sim_call).await {
+                set_sim_outcome.set(Some((outcome, chain, config.ticker)));
+            }
+        });
+    });
+
+    // Reverse-resolve the transaction target so users can recognise known counterparties
+    Effect::new(move |_| {
+        let Some(req) = request.get() else { return };
+        if req.method != "eth_sendTransaction" {
+            return;
+        }
+        let Some(to) = first_param(&req.params)
+            .and_then(|tx| tx.get("to").and_then(|v| v.as_str()).map(|s| s.to_string())) else { return };
+        set_tx_to.set(Some((to.clone(), None)));
+        let Some(rpc_url) = crate::rpc::ens::ens_rpc_url(false) else { return };
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Some(name) = crate::rpc::ens::lookup_primary_name(&to, &rpc_url).await {
+                set_tx_to.set(Some((to, Some(name))));
+            }
+        });
+    });
+
+    // Solana transactions: decode each one against its lookup tables and dry-run it
+    Effect::new(move |_| {
+        let Some(req) = request.get() else { return };
+        if !is_solana_tx_method(&req.method) {
+            return;
+        }
+        let Some(our_key) = solana_key(wallet_state) else { return };
+        let params: serde_json::Value = serde_json::from_str(&req.params).unwrap_or_default();
+        let encoded: Vec<String> = match req.method.as_str() {
+            "solana_signAllTransactions" => params.get("transactions")
+                .and_then(|t| t.as_array())
+                .map(|a| a.iter().filter_map(|e| e.as_str().map(str::to_string)).collect())
+                .unwrap_or_default(),
+            _ => params.get("transaction").and_then(|t| t.as_str()).map(|e| vec![e.to_string()]).unwrap_or_default(),
+        };
+        let Some(config) = crate::state::all_chains(req.solana_testnet).into_iter()
+            .find(|c| c.id == wallet_core::chains::ChainId::Solana) else { return };
+        let Some(rpc_url) = config.rpc_urls.first().cloned() else { return };
+        wasm_bindgen_futures::spawn_local(async move {
+            if encoded.is_empty() {
+                set_sol_reviews.set(Some(Err(t("approve.error_parsing_params"))));
+                return;
+            }
+            let mut reviews = Vec::new();
+            for tx_b64 in &encoded {
+                let review = async {
+                    let tx = solana_dapp::decode_transaction(tx_b64)?;
+                    let keys: Vec<[u8; 32]> = tx.message.address_table_lookups.iter().map(|l| l.account_key).collect();
+                    let tables = crate::rpc::solana::get_lookup_tables(&keys, &rpc_url).await?;
+                    let review = solana_dapp::review_message(&tx.message, &tables, &our_key)?;
+                    let raw_b58 = bs58::encode(tx.serialize()).into_string();
+                    if let Ok(Some(err)) = crate::rpc::solana::simulate_transaction(&raw_b58, &rpc_url).await {
+                        if sol_sim_error.get_untracked().is_none() {
+                            set_sol_sim_error.set(Some(err));
+                        }
+                    }
+                    Ok::<_, String>(review)
+                }.await;
+                match review {
+                    Ok(r) => reviews.push(r),
+                    Err(e) => {
+                        set_sol_reviews.set(Some(Err(e)));
+                        return;
+                    }
+                }
+            }
+            set_sol_reviews.set(Some(Ok(reviews)));
+        });
+    });
+
     let approve = move |_| {
         let req = request.get_untracked();
         let addresses = wallet_state.with_untracked(|s| s.addresses.clone());
@@ -66,10 +231,51 @@ pub fn ApprovePage() -> impl IntoView {
                         }
                     });
                 }
-                "personal_sign" | "eth_signTypedData_v4" | "eth_sendTransaction" => {
+                "solana_requestAccounts" => {
+                    set_status_msg.set(t("approve.approving"));
+                    let addr = addresses.get("solana").cloned().unwrap_or_default();
+                    let result = serde_json::json!({"requestId": req.request_id, "result": [addr]});
+                    wasm_bindgen_futures::spawn_local(async move {
+                        if send_to_background("__rusby_approve", &result).await.is_some() {
+                            close_after_delay();
+                        }
+                    });
+                }
+                "personal_sign" | "eth_signTypedData_v4" | "eth_sendTransaction"
+                | "solana_signMessage" | "solana_signIn" => {
                     // Needs password to decrypt seed
                     set_needs_password.set(true);
                 }
+                m if is_solana_tx_method(m) => set_needs_password.set(true),
+                "wallet_addEthereumChain" => {
+                    let network = match first_param(&req.params)
+                        .ok_or_else(|| t("approve.error_parsing_params"))
+                        .and_then(|p| custom_evm::from_add_chain_params(&p))
+                    {
+                        Ok(n) => n,
+                        Err(e) => {
+                            set_status_msg.set(e);
+                            return;
+                        }
+                    };
+                    set_signing.set(true);
+                    set_status_msg.set(t("networks.checking_rpc"));
+                    wasm_bindgen_futures::spawn_local(async move {
+                        // A dApp could pair a familiar name with a hostile RPC; make sure the id matches
+                        if let Err(e) = crate::rpc::evm::check_chain_id(&network.rpc_urls[0], network.chain_id).await {
+                            set_signing.set(false);
+                            set_status_msg.set(e);
+                            return;
+                        }
+                        let mut networks = load_custom_networks();
+                        custom_evm::upsert_network(&mut networks, network);
+                        save_custom_networks(&networks);
+                        let result = serde_json::json!({"requestId": req.request_id, "result": null});
+                        if send_to_background("__rusby_approve", &result).await.is_some() {
+                            close_after_delay();
+                        }
+                    });
+                }
                 _ => {
                     let result = serde_json::json!({"requestId": req.request_id, "result": null});
                     wasm_bindgen_futures::spawn_local(async move {
@@ -89,16 +295,37 @@ pub fn ApprovePage() -> impl IntoView {
             return;
         }
         let Some(req) = req else { return };
+        let fees = evm_fees.get_untracked();
+        if req.method == "eth_sendTransaction" && fees.is_none() {
+            set_status_msg.set(t("fee.invalid"));
+            return;
+        }
         set_signing.set(true);
         set_status_msg.set(t("approve.signing"));
 
         wasm_bindgen_futures::spawn_local(async move {
-            let sign_result = execute_sign(&req.method, &req.params, &pwd);
+            let sign_result = if req.method == "eth_sendTransaction" {
+                match first_param(&req.params) {
+                    Some(tx) => match req.evm_chain_id {
+                        Some(chain_id) => crate::tx_send::execute_dapp_transaction(&tx, chain_id, &pwd, fees).await
+                            .map(serde_json::Value::String),
+                        None => Err(t("approve.missing_chain")),
+                    },
+                    None => Err(t("approve.error_parsing_params")),
+                }
+            } else if req.method.starts_with("solana_") {
+                let params: serde_json::Value = serde_json::from_str(&req.params).unwrap_or_default();
+                crate::tx_send::execute_solana_dapp_request(
+                    &req.method, &params, &pwd, req.solana_testnet, &origin_host(&req.origin),
+                ).await
+            } else {
+                execute_sign(&req.method, &req.params, &pwd).map(serde_json::Value::String)
+            };
             match sign_result {
-                Ok(sig_hex) => {
+                Ok(value) => {
                     let result = serde_json::json!({
                         "requestId": req.request_id,
-                        "result": sig_hex
+                        "result": value
                     });
                     if send_to_background("__rusby_approve", &result).await.is_some() {
                         set_status_msg.set(t("approve.signed"));
@@ -135,20 +362,128 @@ pub fn ApprovePage() -> impl IntoView {
             "personal_sign" => t("approve.sign_message"),
             "eth_signTypedData_v4" => t("approve.sign_typed"),
             "eth_sendTransaction" => t("approve.send_tx"),
+            "wallet_addEthereumChain" => t("approve.add_network"),
+            "wallet_switchEthereumChain" => t("approve.switch_network"),
+            "solana_requestAccounts" => t("approve.solana_connection"),
+            "solana_signTransaction" => t("approve.solana_sign_tx"),
+            "solana_signAllTransactions" => t("approve.solana_sign_all"),
+            "solana_signAndSendTransaction" => t("approve.solana_send_tx"),
+            "solana_signMessage" => t("approve.solana_sign_message"),
+            "solana_signIn" => t("approve.solana_sign_in"),
             other => format!("{} {}", t("approve.request"), other),
         }).unwrap_or_default()
     };
 
-    // Extract readable message for personal_sign
+    // Extract readable message for personal_sign and solana_signMessage
     let display_message = move || {
         let req = request.get()?;
-        if req.method != "personal_sign" { return None; }
-        // personal_sign params: [message_hex, address]
+        String::from_utf8(message_bytes(&req)?).ok()
+    };
+
+    // solana_signMessage bytes that parse as a transaction: signing would authorize it
+    let disguised_tx = move || {
+        let req = request.get()?;
+        if req.method != "solana_signMessage" { return None; }
+        solana_dapp::is_transaction_bytes(&message_bytes(&req)?).then_some(())
+    };
+
+    // Sign-In with Ethereum: parsed message and the checks it fails against this request,
+    // or the parse error for text that has a SIWE header but does not follow EIP-4361
+    let siwe_info = move || {
+        let req = request.get()?;
+        let text = display_message()?;
+        if !siwe::is_siwe_message(&text) { return None; }
+        let msg = match siwe::parse_siwe(&text) {
+            Ok(msg) => msg,
+            Err(e) => return Some(Err(e)),
+        };
+        // WalletConnect requests carry a session topic, not a web origin
+        let origin = (!req.origin.starts_with("walletconnect:")).then_some(req.origin.as_str());
+        let our_address = wallet_state.with_untracked(|s| s.addresses.get("ethereum").cloned().unwrap_or_default());
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+        let issues = siwe::validate(&msg, origin, req.evm_chain_id, &our_address, now);
+        Some(Ok((msg, issues, origin.is_none())))
+    };
+
+    // Sign-In With Solana: wallet-built message for solana_signIn, or SIWS text sent to signMessage
+    let siws_info = move || {
+        let req = request.get()?;
+        let our_address = wallet_state.with_untracked(|s| s.addresses.get("solana").cloned().unwrap_or_default());
+        let origin = (!req.origin.starts_with("walletconnect:")).then_some(req.origin.as_str());
+        let msg = match req.method.as_str() {
+            "solana_signIn" => {
+                let input: serde_json::Value = serde_json::from_str(&req.params).ok()?;
+                SiwsMessage::from_sign_in_input(&input, &origin_host(&req.origin), &our_address).ok()?
+            }
+            "solana_signMessage" => {
+                let text = display_message()?;
+                if !siws::is_siws_message(&text) { return None; }
+                siws::parse_siws(&text).ok()?
+            }
+            _ => return None,
+        };
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+        let issues = siws::validate(&msg, origin, &our_address, now);
+        Some((msg, issues, origin.is_none()))
+    };
+
+    // Network being added or switched to: (is_add, label/value rows)
+    let network_rows = move || {
+        let req = request.get()?;
+        let param = first_param(&req.params)?;
+        match req.method.as_str() {
+            "wallet_addEthereumChain" => {
+                let net = custom_evm::from_add_chain_params(&param).ok()?;
+                Some((true, vec![
+                    (t("networks.name"), net.name),
+                    (t("networks.chain_id"), net.chain_id.to_string()),
+                    (t("networks.ticker"), net.ticker),
+                    (t("networks.rpc_url"), net.rpc_urls.join(", ")),
+                    (t("networks.explorer_url"), net.explorer_url.unwrap_or_default()),
+                ]))
+            }
+            "wallet_switchEthereumChain" => {
+                let chain_id = custom_evm::parse_switch_chain_params(&param).ok()?;
+                let name = crate::tx_send::evm_config_for_chain_id(chain_id)
+                    .map(|c| c.name)
+                    .unwrap_or_default();
+                Some((false, vec![
+                    (t("networks.name"), name),
+                    (t("networks.chain_id"), chain_id.to_string()),
+                ]))
+            }
+            _ => None,
+        }
+    };
+
+    // Permit / Permit2 / Seaport detection for eth_signTypedData_v4: (analysis, chain key)
+    let permit_info = move || {
+        let req = request.get()?;
+        if req.method != "eth_signTypedData_v4" { return None; }
         let params: serde_json::Value = serde_json::from_str(&req.params).ok()?;
-        let msg_hex = params.get(0)?.as_str()?;
-        let hex_str = msg_hex.strip_prefix("0x").unwrap_or(msg_hex);
-        let bytes = hex::decode(hex_str).ok()?;
-        String::from_utf8(bytes).ok()
+        let typed = permit::parse_typed_data(params.get(1)?)?;
+        let chain_id = typed.get("domain")
+            .and_then(|d| d.get("chainId"))
+            .and_then(|c| c.as_u64().or_else(|| c.as_str().and_then(|s| s.parse().ok())))
+            .or(req.evm_chain_id);
+        let chain = chain_id.and_then(crate::tx_send::evm_config_for_chain_id)
+            .map(|c| crate::rpc::chain_id_str(&c.id))
+            .unwrap_or_default();
+        let known: Vec<String> = crate::rpc::approvals::known_spenders().into_iter()
+            .filter(|k| k.chain == chain)
+            .map(|k| k.address)
+            .collect();
+        let now = (js_sys::Date::now() / 1000.0) as u64;
+        permit::analyze_typed_data(&typed, now, &known).map(|a| (a, chain))
+    };
+
+    // EIP-7702 authorizations carried by eth_sendTransaction
+    let delegations = move || {
+        let req = request.get()?;
+        if req.method != "eth_sendTransaction" { return None; }
+        let tx = first_param(&req.params)?;
+        tx.get("authorizationList")?;
+        Some(eip7702::parse_authorization_list(&tx).map(|entries| entries.iter().map(eip7702::review).collect::<Vec<_>>()))
     };
 
     view! {
@@ -173,37 +508,124 @@ pub fn ApprovePage() -> impl IntoView {
                         {
                             let origin = req.origin.clone();
                             let phishing_warning = wallet_core::security::phishing::check_suspicious_domain(&origin);
-                            if let Some(reason) = phishing_warning {
-                                Some(view! {
-                                    <SecurityWarning
-                                        severity=Severity::High
-                                        title=t("approve.suspicious_domain")
-                                        message=reason
-                                        dismissable=false
-                                    />
-                                })
-                            } else {
-                                None
-                            }
+                            phishing_warning.map(|reason| view! {
+                                <SecurityWarning
+                                    severity=Severity::High
+                                    title=t("approve.suspicious_domain")
+                                    message=reason
+                                    dismissable=false
+                                />
+                            })
                         }
                         <div class="card">
                             <p class="text-sm text-muted mb-2">{move || t("approve.request_type")}</p>
                             <p style="font-weight: bold;">{method_label()}</p>
                         </div>
-                        // Show readable message for personal_sign
+                        // Off-chain token approvals (Permit, Permit2, Seaport)
+                        {move || permit_info().map(|(analysis, chain)| permit_card(analysis, &chain))}
+                        // Structured login prompt for SIWE / SIWS, readable message otherwise
                         {move || {
+                            match siwe_info() {
+                                Some(Ok((msg, issues, unbound))) => return Some(siwe_card(msg, issues, unbound).into_any()),
+                                Some(Err(e)) => return Some(view! {
+                                    <SecurityWarning severity=Severity::High title=t("siwe.malformed") message=t("siwe.malformed_hint").replace("{error}", &e) dismissable=false />
+                                    <div class="card">
+                                        <p class="text-sm text-muted mb-2">{t("approve.message")}</p>
+                                        <pre style="font-size: 0.8rem; white-space: pre-wrap; word-break: break-all; max-height: 120px; overflow-y: auto;">{display_message()}</pre>
+                                    </div>
+                                }.into_any()),
+                                None => {}
+                            }
+                            if let Some((msg, issues, unbound)) = siws_info() {
+                                return Some(siws_card(msg, issues, unbound).into_any());
+                            }
+                            if disguised_tx().is_some() {
+                                return Some(view! {
+                                    <SecurityWarning severity=Severity::High title=t("approve.solana_disguised_tx") message=t("approve.solana_disguised_tx_hint") dismissable=false />
+                                }.into_any());
+                            }
                             display_message().map(|msg| view! {
                                 <div class="card">
                                     <p class="text-sm text-muted mb-2">{t("approve.message")}</p>
                                     <pre style="font-size: 0.8rem; white-space: pre-wrap; word-break: break-all; max-height: 120px; overflow-y: auto;">{msg}</pre>
                                 </div>
+                            }.into_any())
+                        }}
+                        // EIP-7702 delegation targets, shown before anything else about the transaction
+                        {move || delegations().map(|result| match result {
+                            Ok(reviews) => delegation_card(reviews).into_any(),
+                            Err(e) => view! {
+                                <SecurityWarning severity=Severity::High title=t("approve.delegation_title") message=e dismissable=false />
+                            }.into_any(),
+                        })}
+                        // Transaction target with its ENS primary name
+                        {move || {
+                            tx_to.get().map(|(to, name)| view! {
+                                <div class="card text-sm">
+                                    <p class="text-muted mb-2">{t("approve.to")}</p>
+                                    {name.map(|n| view! { <p style="font-weight: bold;">{n}</p> })}
+                                    <p style="font-family: monospace; word-break: break-all;">{to}</p>
+                                </div>
+                            })
+                        }}
+                        // Simulated asset changes of the transaction
+                        {move || {
+                            sim_outcome.get().map(|(outcome, chain, ticker)| view! {
+                                <AssetChangesCard outcome=outcome chain=chain ticker=ticker />
+                            })
+                        }}
+                        // Decoded Solana transactions
+                        {move || sol_sim_error.get().map(|err| view! {
+                            <SecurityWarning severity=Severity::High title=t("sim.would_fail") message=err dismissable=false />
+                        })}
+                        {move || {
+                            let our_key = solana_key(wallet_state)?;
+                            sol_reviews.get().map(|result| match result {
+                                Ok(reviews) => {
+                                    let count = reviews.len();
+                                    reviews.into_iter().enumerate().map(|(i, review)| {
+                                        let title = if count > 1 {
+                                            t("approve.solana_tx_n").replace("{n}", &(i + 1).to_string()).replace("{count}", &count.to_string())
+                                        } else {
+                                            t("approve.solana_tx")
+                                        };
+                                        view! { <SolanaReviewCard review=review our_key=our_key title=title /> }
+                                    }).collect::<Vec<_>>().into_any()
+                                }
+                                Err(e) => view! {
+                                    <SecurityWarning severity=Severity::High title=t("approve.solana_decode_failed") message=e dismissable=false />
+                                }.into_any(),
+                            })
+                        }}
+                        // Network details for wallet_addEthereumChain / wallet_switchEthereumChain
+                        {move || {
+                            network_rows().map(|(is_add, rows)| view! {
+                                {is_add.then(|| view! {
+                                    <SecurityWarning
+                                        severity=Severity::Medium
+                                        title=t("approve.add_network")
+                                        message=t("approve.add_network_warning")
+                                        dismissable=false
+                                    />
+                                })}
+                                <div class="card text-sm">
+                                    {rows.into_iter().filter(|(_, v)| !v.is_empty()).map(|(label, value)| view! {
+                                        <div class="flex justify-between mb-2" style="gap: 8px;">
+                                            <span class="text-muted">{label}</span>
+                                            <span style="word-break: break-all; text-align: right;">{value}</span>
+                                        </div>
+                                    }).collect::<Vec<_>>()}
+                                </div>
                             })
                         }}
                         // Show raw params if not personal_sign or no readable message
                         {
                             let params = req.params.clone();
                             let method = req.method.clone();
-                            if method != "personal_sign" && !params.is_empty() && params != "[]" && params != "null" {
+                            let has_details = matches!(method.as_str(), "wallet_addEthereumChain" | "wallet_switchEthereumChain" | "solana_signIn")
+                                || is_solana_tx_method(&method)
+                                || (method == "solana_signMessage" && display_message().is_some());
+                            if method != "personal_sign" && !has_details && !params.is_empty() && params != "[]" && params != "null" {
                                 Some(view! {
                                     <div class="card">
                                         <p class="text-sm text-muted mb-2">{t("approve.params")}</p>
@@ -214,6 +636,20 @@ pub fn ApprovePage() -> impl IntoView {
                                 None
                             }
                         }
+                        // Editable network fee for eth_sendTransaction
+                        {move || {
+                            let req = request.get().filter(|r| r.method == "eth_sendTransaction")?;
+                            if !needs_password.get() { return None; }
+                            let config = request_chain_config(&req).ok()?;
+                            Some(view! {
+                                <EvmFeeSelector
+                                    estimate=fee_estimate
+                                    gas_limit=gas_limit
+                                    ticker=config.ticker
+                                    set_fees=set_evm_fees
+                                />
+                            })
+                        }}
                         // Password input for signing operations
                         {move || {
                             if needs_password.get() {
@@ -251,7 +687,7 @@ pub fn ApprovePage() -> impl IntoView {
                             {move || {
                                 if !needs_password.get() {
                                     Some(view! {
-                                        <button class="btn btn-primary" style="flex: 1;" on:click=approve>
+                                        <button class="btn btn-primary" style="flex: 1;" on:click=approve disabled=move || signing.get()>
                                             {move || t("approve.approve")}
                                         </button>
                                     })
@@ -307,7 +743,7 @@ fn execute_sign(method: &str, params_json: &str, password: &str) -> Result<Strin
 
     // Get EVM private key
     let private_key = wallet_core::chains::evm::get_private_key(&seed)
-        .map_err(|e| { seed.fill(0); e })?;
+        .inspect_err(|_| seed.fill(0))?;
     seed.fill(0); // zeroize
 
     let result = match method {
@@ -317,11 +753,6 @@ fn execute_sign(method: &str, params_json: &str, password: &str) -> Result<Strin
         "eth_signTypedData_v4" => {
             sign_typed_data(params_json, &private_key)
         }
-        "eth_sendTransaction" => {
-            // For sendTransaction, we return the signed tx hash
-            // Full implementation would build, sign and broadcast
-            Err(t("approve.sendtx_unsupported"))
-        }
         _ => Err(format!("{} {}", t("approve.method_unsupported"), method)),
     };
 
@@ -352,144 +783,294 @@ fn sign_typed_data(params_json: &str, private_key: &[u8; 32]) -> Result<String,
         .map_err(|e| format!("{} {}", t("approve.error_parsing_params"), e))?;
 
     // eth_signTypedData_v4 params: [address, typed_data_json]
-    let typed_data_str = params.get(1)
-        .and_then(|v| v.as_str())
+    let typed_data = params.get(1)
+        .and_then(permit::parse_typed_data)
         .ok_or(t("approve.missing_typed_data"))?;
 
-    let typed_data: serde_json::Value = serde_json::from_str(typed_data_str)
-        .map_err(|e| format!("{} {}", t("approve.error_parsing_params"), e))?;
+    let signature = wallet_core::signing::eip712::sign_typed_data(&typed_data, private_key)?;
+    Ok(format!("0x{}", hex::encode(signature)))
+}
 
-    // Extract domain
-    let domain = typed_data.get("domain")
-        .ok_or(t("approve.missing_domain"))?;
-    let name = domain.get("name").and_then(|v| v.as_str()).unwrap_or("");
-    let version = domain.get("version").and_then(|v| v.as_str()).unwrap_or("1");
-    let chain_id = domain.get("chainId")
-        .and_then(|v| v.as_u64())
-        .unwrap_or(1);
-    let contract_hex = domain.get("verifyingContract")
-        .and_then(|v| v.as_str())
-        .unwrap_or("0x0000000000000000000000000000000000000000");
-    let contract_bytes = wallet_core::tx::evm::parse_address(contract_hex)?;
-
-    let domain_separator = wallet_core::signing::eip712::hash_eip712_domain(
-        name, version, chain_id, &contract_bytes
-    );
-
-    // For the struct hash, we need the primary type and its encoding
-    // This is a simplified implementation — full EIP-712 recursive hashing
-    // would require parsing the types field completely
-    let message = typed_data.get("message")
-        .ok_or(t("approve.missing_message_field"))?;
-    let primary_type = typed_data.get("primaryType")
-        .and_then(|v| v.as_str())
-        .ok_or(t("approve.missing_primary_type"))?;
-    let types = typed_data.get("types")
-        .ok_or(t("approve.missing_types"))?;
+/// Details and risk warning for an off-chain approval signature
+fn permit_card(analysis: PermitAnalysis, chain: &str) -> impl IntoView {
+    let tokens = crate::state::tokens_for_chain(chain);
+    let spender_name = crate::rpc::approvals::known_spenders().into_iter()
+        .find(|k| k.chain == chain && k.address.eq_ignore_ascii_case(&analysis.spender))
+        .map(|k| k.name);
+    let kind_label = match analysis.kind {
+        PermitKind::Erc2612 | PermitKind::DaiPermit => t("permit.kind_permit"),
+        PermitKind::Permit2Allowance => t("permit.kind_permit2"),
+        PermitKind::Permit2Transfer => t("permit.kind_permit2_transfer"),
+        PermitKind::SeaportOrder => t("permit.kind_seaport"),
+    };
+    let token_rows: Vec<(String, String)> = analysis.tokens.iter().map(|pt| {
+        let known = tokens.iter().find(|tk| tk.address.eq_ignore_ascii_case(&pt.token));
+        let label = known.map(|tk| tk.symbol.clone()).unwrap_or_else(|| pt.token.clone());
+        let amount = match (pt.amount, known) {
+            (None, _) => t("permit.unlimited"),
+            (Some(a), _) if pt.is_nft => format!("{} NFT", a),
+            (Some(a), Some(tk)) => wallet_core::tokens::erc20::format_token_amount(a, tk.decimals),
+            (Some(a), None) => a.to_string(),
+        };
+        (label, amount)
+    }).collect();
+    let now = (js_sys::Date::now() / 1000.0) as u64;
+    let expiry = match analysis.expiry {
+        None | Some(u64::MAX) => t("permit.never"),
+        Some(e) if e <= now => t("permit.expired"),
+        Some(e) => {
+            let secs = e - now;
+            let span = if secs >= 86_400 {
+                format!("{}d", secs / 86_400)
+            } else if secs >= 3600 {
+                format!("{}h", secs / 3600)
+            } else {
+                format!("{}m", secs.div_ceil(60))
+            };
+            format!("{} {}", t("permit.in"), span)
+        }
+    };
+    let (severity, title, message) = if analysis.is_high_risk() {
+        let reasons: Vec<String> = analysis.risks.iter().map(|r| match r {
+            PermitRisk::UnlimitedAmount => t("permit.risk_unlimited"),
+            PermitRisk::UnknownSpender => t("permit.risk_unknown_spender"),
+            PermitRisk::FarFutureExpiry => t("permit.risk_far_expiry"),
+            PermitRisk::NoPaymentToSigner => t("permit.risk_no_payment"),
+        }).collect();
+        (Severity::High, t("permit.high_risk_title"), reasons.join(" "))
+    } else {
+        (Severity::Medium, kind_label.clone(), t("permit.review_hint"))
+    };
 
-    let struct_hash = compute_struct_hash(primary_type, message, types)?;
+    view! {
+        <SecurityWarning severity=severity title=title message=message dismissable=false />
+        <div class="card text-sm">
+            <p class="text-muted mb-2">{kind_label}</p>
+            <div class="flex justify-between mb-2" style="gap: 8px;">
+                <span class="text-muted">{t("permit.spender")}</span>
+                <span style="word-break: break-all; text-align: right;">
+                    {spender_name.map(|n| format!("{} ", n))}
+                    <span style="font-family: monospace;">{analysis.spender.clone()}</span>
+                </span>
+            </div>
+            {token_rows.into_iter().map(|(token, amount)| view! {
+                <div class="flex justify-between mb-2" style="gap: 8px;">
+                    <span style="word-break: break-all;">{token}</span>
+                    <span style="font-weight: bold; text-align: right;">{amount}</span>
+                </div>
+            }).collect::<Vec<_>>()}
+            <div class="flex justify-between" style="gap: 8px;">
+                <span class="text-muted">{t("permit.expiry")}</span>
+                <span>{expiry}</span>
+            </div>
+        </div>
+    }
+}
 
-    let signature = wallet_core::signing::eip712::sign_typed_data_hash(
-        &domain_separator, &struct_hash, private_key
-    )?;
-    Ok(format!("0x{}", hex::encode(signature)))
+/// Warnings for the checks a sign-in message fails (shared by SIWE and SIWS)
+fn sign_in_warnings(issues: &[SiweIssue]) -> Vec<(Severity, String, String)> {
+    issues.iter().map(|issue| match issue {
+        SiweIssue::DomainMismatch { domain, origin } => (
+            Severity::High,
+            t("siwe.domain_mismatch"),
+            t("siwe.domain_mismatch_hint").replace("{domain}", domain).replace("{origin}", origin),
+        ),
+        SiweIssue::SchemeMismatch => (Severity::High, t("siwe.scheme_mismatch"), t("siwe.scheme_mismatch_hint")),
+        SiweIssue::SuspiciousDomain(reason) => (Severity::High, t("approve.suspicious_domain"), reason.clone()),
+        SiweIssue::AddressMismatch => (Severity::High, t("siwe.address_mismatch"), t("siwe.address_mismatch_hint")),
+        SiweIssue::ChainMismatch { message, active } => (
+            Severity::Medium,
+            t("siwe.chain_mismatch"),
+            t("siwe.chain_mismatch_hint").replace("{message}", &message.to_string()).replace("{active}", &active.to_string()),
+        ),
+        SiweIssue::ChainUnknown => (Severity::Medium, t("siwe.chain_unknown"), t("siwe.chain_unknown_hint")),
+        SiweIssue::InvalidNonce => (Severity::Medium, t("siwe.invalid_nonce"), t("siwe.invalid_nonce_hint")),
+        SiweIssue::Expired => (Severity::Medium, t("siwe.expired"), t("siwe.expired_hint")),
+        SiweIssue::NotYetValid => (Severity::Medium, t("siwe.not_yet_valid"), t("siwe.not_yet_valid_hint")),
+        SiweIssue::InvalidTimestamp => (Severity::Medium, t("siwe.invalid_time"), t("siwe.invalid_time_hint")),
+    }).collect()
 }
 
-/// Compute EIP-712 struct hash (simplified — handles common cases)
-fn compute_struct_hash(
-    primary_type: &str,
-    data: &serde_json::Value,
-    types: &serde_json::Value,
-) -> Result<[u8; 32], String> {
-    use wallet_core::signing::keccak256;
-
-    // Build type string: "PrimaryType(type1 name1,type2 name2,...)"
-    let type_fields = types.get(primary_type)
-        .and_then(|v| v.as_array())
-        .ok_or(format!("{} {}", t("approve.type_not_found"), primary_type))?;
-
-    let mut type_str = format!("{}(", primary_type);
-    for (i, field) in type_fields.iter().enumerate() {
-        if i > 0 { type_str.push(','); }
-        let ftype = field.get("type").and_then(|v| v.as_str()).unwrap_or("uint256");
-        let fname = field.get("name").and_then(|v| v.as_str()).unwrap_or("");
-        type_str.push_str(&format!("{} {}", ftype, fname));
+/// Login prompt: the site being signed in to, its statement and the message fields.
+/// `unbound` is set when the request origin is unknown and the domain could not be checked.
+fn sign_in_card(
+    site: String,
+    statement: Option<String>,
+    rows: Vec<(String, String)>,
+    resources: Vec<String>,
+    issues: Vec<SiweIssue>,
+    unbound: bool,
+) -> impl IntoView {
+    view! {
+        {unbound.then(|| view! {
+            <SecurityWarning severity=Severity::Medium title=t("siwe.unbound") message=t("siwe.unbound_hint") dismissable=false />
+        })}
+        {sign_in_warnings(&issues).into_iter().map(|(severity, title, message)| view! {
+            <SecurityWarning severity=severity title=title message=message dismissable=false />
+        }).collect::<Vec<_>>()}
+        <div class="card text-sm">
+            <p class="text-muted mb-2">{t("siwe.title")}</p>
+            <p class="mb-2" style="font-size: 1.1rem; font-weight: bold; word-break: break-all;">{site}</p>
+            {statement.map(|st| view! { <p class="mb-2">{st}</p> })}
+            {rows.into_iter().map(|(label, value)| view! {
+                <div class="flex justify-between mb-2" style="gap: 8px;">
+                    <span class="text-muted">{label}</span>
+                    <span style="word-break: break-all; text-align: right;">{value}</span>
+                </div>
+            }).collect::<Vec<_>>()}
+            {(!resources.is_empty()).then(|| view! {
+                <p class="text-muted mb-2">{t("siwe.resources")}</p>
+                {resources.into_iter().map(|r| view! {
+                    <p style="font-family: monospace; word-break: break-all;">{r}</p>
+                }).collect::<Vec<_>>()}
+            })}
+        </div>
     }
-    type_str.push(')');
+}
 
-    let type_hash = keccak256(type_str.as_bytes());
+/// Sign-In with Ethereum login prompt
+fn siwe_card(msg: SiweMessage, issues: Vec<SiweIssue>, unbound: bool) -> impl IntoView {
+    let chain_name = crate::tx_send::evm_config_for_chain_id(msg.chain_id)
+        .map(|c| format!("{} ({})", c.name, msg.chain_id))
+        .unwrap_or_else(|| msg.chain_id.to_string());
+    let mut rows = vec![
+        (t("siwe.address"), msg.address.clone()),
+        (t("siwe.uri"), msg.uri.clone()),
+        (t("siwe.chain"), chain_name),
+        (t("siwe.nonce"), msg.nonce.clone()),
+        (t("siwe.issued_at"), msg.issued_at.clone()),
+    ];
+    if let Some(exp) = &msg.expiration_time {
+        rows.push((t("siwe.expires"), exp.clone()));
+    }
+    if let Some(nbf) = &msg.not_before {
+        rows.push((t("siwe.not_before"), nbf.clone()));
+    }
+    if let Some(id) = &msg.request_id {
+        rows.push((t("siwe.request_id"), id.clone()));
+    }
+    let site = format!("{}{}", msg.scheme.as_ref().map(|s| format!("{}://", s)).unwrap_or_default(), msg.domain);
+    sign_in_card(site, msg.statement, rows, msg.resources, issues, unbound)
+}
 
-    // Encode data fields
-    let mut encoded = Vec::with_capacity(32 + type_fields.len() * 32);
-    encoded.extend_from_slice(&type_hash);
+/// Sign-In With Solana login prompt; every field after the address is optional
+fn siws_card(msg: SiwsMessage, issues: Vec<SiweIssue>, unbound: bool) -> impl IntoView {
+    let fields = [
+        (t("siwe.uri"), &msg.uri),
+        (t("siwe.chain"), &msg.chain_id),
+        (t("siwe.nonce"), &msg.nonce),
+        (t("siwe.issued_at"), &msg.issued_at),
+        (t("siwe.expires"), &msg.expiration_time),
+        (t("siwe.not_before"), &msg.not_before),
+        (t("siwe.request_id"), &msg.request_id),
+    ];
+    let mut rows = vec![(t("siwe.address"), msg.address.clone())];
+    rows.extend(fields.into_iter().filter_map(|(label, value)| value.clone().map(|v| (label, v))));
+    let site = format!("{}{}", msg.scheme.as_ref().map(|s| format!("{}://", s)).unwrap_or_default(), msg.domain);
+    sign_in_card(site, msg.statement, rows, msg.resources, issues, unbound)
+}
 
-    for field in type_fields {
-        let fname = field.get("name").and_then(|v| v.as_str()).unwrap_or("");
-        let ftype = field.get("type").and_then(|v| v.as_str()).unwrap_or("uint256");
-        let value = data.get(fname);
+/// EIP-7702 delegations: the code that would control the account after this transaction
+fn delegation_card(reviews: Vec<DelegationReview>) -> impl IntoView {
+    let any_chain = reviews.iter().any(|r| r.any_chain && !r.revokes);
+    let unknown = reviews.iter().any(|r| r.delegate_name.is_none() && !r.revokes);
+    view! {
+        {any_chain.then(|| view! {
+            <SecurityWarning severity=Severity::High title=t("approve.delegation_any_chain") message=t("approve.delegation_any_chain_hint") dismissable=false />
+        })}
+        {unknown.then(|| view! {
+            <SecurityWarning severity=Severity::High title=t("approve.delegation_unknown") message=t("approve.delegation_unknown_hint") dismissable=false />
+        })}
+        <div class="card text-sm">
+            <p class="text-muted mb-2">{t("approve.delegation_title")}</p>
+            {reviews.into_iter().map(|r| {
+                let title = if r.revokes {
+                    t("approve.delegation_revoke")
+                } else {
+                    r.delegate_name.map(str::to_string).unwrap_or_else(|| t("approve.delegation_unknown_contract"))
+                };
+                let color = if r.is_high_risk() { "var(--danger, #f44336)" } else { "inherit" };
+                let scope = if r.any_chain { t("approve.delegation_all_chains") } else { String::new() };
+                view! {
+                    <div class="mb-2">
+                        <p style=format!("font-size: 1.1rem; font-weight: bold; color: {};", color)>{title}</p>
+                        {(!r.revokes).then(|| view! {
+                            <p style="font-family: monospace; word-break: break-all;">{r.delegate.clone()}</p>
+                        })}
+                        {(!scope.is_empty()).then(|| view! { <p class="text-muted">{scope}</p> })}
+                        {r.presigned.then(|| view! { <p class="text-muted">{t("approve.delegation_presigned")}</p> })}
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
 
-        let encoded_value = encode_eip712_value(ftype, value)?;
-        encoded.extend_from_slice(&encoded_value);
+/// Chain targeted by a pending request: `chainId` (hex) from the provider or `wcChainId` (eip155:N)
+fn parse_request_chain_id(req: &serde_json::Value) -> Option<u64> {
+    if let Some(hex_id) = req.get("chainId").and_then(|v| v.as_str()) {
+        return u64::from_str_radix(hex_id.trim_start_matches("0x"), 16).ok();
     }
+    req.get("wcChainId")
+        .and_then(|v| v.as_str())
+        .and_then(|caip| caip.strip_prefix("eip155:"))
+        .and_then(|n| n.parse().ok())
+}
 
-    Ok(keccak256(&encoded))
+/// Solana cluster of a pending request: `wcChainId` from WalletConnect or the Wallet
+/// Standard `chain` the page passed along
+fn is_solana_testnet_request(req: &serde_json::Value) -> bool {
+    req.get("wcChainId")
+        .or_else(|| req.get("params").and_then(|p| p.get("chain")))
+        .and_then(|c| c.as_str())
+        .is_some_and(wallet_core::caip::is_solana_test_cluster)
 }
 
-/// Encode a single EIP-712 value to 32 bytes
-fn encode_eip712_value(type_name: &str, value: Option<&serde_json::Value>) -> Result<[u8; 32], String> {
-    use wallet_core::signing::keccak256;
+/// Solana methods that carry serialized transactions
+fn is_solana_tx_method(method: &str) -> bool {
+    matches!(method, "solana_signTransaction" | "solana_signAllTransactions" | "solana_signAndSendTransaction")
+}
 
-    let mut result = [0u8; 32];
+/// The wallet's Solana public key
+fn solana_key(wallet_state: ReadSignal<WalletState>) -> Option<[u8; 32]> {
+    wallet_state.with_untracked(|s| s.addresses.get("solana").cloned())
+        .and_then(|a| wallet_core::tx::solana::parse_pubkey(&a).ok())
+}
 
-    match type_name {
-        "address" => {
-            let addr_str = value.and_then(|v| v.as_str()).unwrap_or("0x0000000000000000000000000000000000000000");
-            let addr = wallet_core::tx::evm::parse_address(addr_str)?;
-            result[12..].copy_from_slice(&addr);
-        }
-        "string" => {
-            let s = value.and_then(|v| v.as_str()).unwrap_or("");
-            result = keccak256(s.as_bytes());
-        }
-        "bytes" => {
-            let hex_str = value.and_then(|v| v.as_str()).unwrap_or("0x");
-            let clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-            let bytes = hex::decode(clean).unwrap_or_default();
-            result = keccak256(&bytes);
-        }
-        "bool" => {
-            if value.and_then(|v| v.as_bool()).unwrap_or(false) {
-                result[31] = 1;
-            }
-        }
-        t if t.starts_with("uint") || t.starts_with("int") => {
-            if let Some(v) = value {
-                if let Some(n) = v.as_u64() {
-                    result[24..].copy_from_slice(&n.to_be_bytes());
-                } else if let Some(s) = v.as_str() {
-                    // Handle large numbers as decimal strings
-                    if let Ok(n) = s.parse::<u128>() {
-                        result[16..].copy_from_slice(&n.to_be_bytes());
-                    }
-                }
-            }
-        }
-        t if t.starts_with("bytes") && t.len() > 5 => {
-            // bytesN (fixed-size)
-            let hex_str = value.and_then(|v| v.as_str()).unwrap_or("0x");
-            let clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-            let bytes = hex::decode(clean).unwrap_or_default();
-            let len = bytes.len().min(32);
-            result[..len].copy_from_slice(&bytes[..len]);
-        }
-        _ => {
-            // Unknown type — hash the JSON representation
-            let json = value.map(|v| v.to_string()).unwrap_or_default();
-            result = keccak256(json.as_bytes());
+/// Bytes of a message-signing request: hex for personal_sign, base58 for solana_signMessage
+fn message_bytes(req: &DappRequest) -> Option<Vec<u8>> {
+    let params: serde_json::Value = serde_json::from_str(&req.params).ok()?;
+    match req.method.as_str() {
+        // personal_sign params: [message_hex, address]
+        "personal_sign" => {
+            let msg_hex = params.get(0)?.as_str()?;
+            hex::decode(msg_hex.strip_prefix("0x").unwrap_or(msg_hex)).ok()
         }
+        "solana_signMessage" => bs58::decode(params.get("message")?.as_str()?).into_vec().ok(),
+        _ => None,
+    }
+}
+
+/// Host (with port) of a web origin; empty for WalletConnect sessions
+fn origin_host(origin: &str) -> String {
+    if origin.starts_with("walletconnect:") {
+        return String::new();
     }
+    let rest = origin.split_once("://").map(|(_, r)| r).unwrap_or(origin);
+    rest.split(['/', '?', '#']).next().unwrap_or_default().to_string()
+}
+
+/// Config of the EVM chain a request targets. A request without a chain id is an
+/// error rather than an implicit mainnet.
+fn request_chain_config(req: &DappRequest) -> Result<wallet_core::chains::ChainConfig, String> {
+    let chain_id = req.evm_chain_id.ok_or_else(|| t("approve.missing_chain"))?;
+    crate::tx_send::evm_config_for_chain_id(chain_id).ok_or_else(|| t("approve.unsupported_chain"))
+}
 
-    Ok(result)
+/// First element of a JSON-RPC params array (the tx object for eth_sendTransaction)
+fn first_param(params_json: &str) -> Option<serde_json::Value> {
+    let params: serde_json::Value = serde_json::from_str(params_json).ok()?;
+    params.get(0).cloned()
 }
 
 fn close_after_delay() {
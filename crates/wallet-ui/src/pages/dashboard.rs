@@ -55,17 +55,16 @@ pub fn Dashboard() -> impl IntoView {
         });
     });
 
-    // Fetch ERC-20 token balances for EVM chains (mainnet only)
+    // Fetch ERC-20 token balances for EVM chains, custom networks included (mainnet only)
     Effect::new(move |_| {
         if !is_unlocked.get() || testnet_mode.get() { return; }
         let chain = active_chain.get();
         let address = current_address.get();
         if address.is_empty() { return; }
 
-        let evm_chains = ["ethereum", "polygon", "bsc", "optimism", "base", "arbitrum"];
-        if !evm_chains.contains(&chain.as_str()) { return; }
+        if !is_evm_chain(&chain) { return; }
 
-        let rpc_url = get_chains(false).into_iter()
+        let rpc_url = all_chains(false).into_iter()
             .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
             .and_then(|c| c.rpc_urls.first().cloned());
 
@@ -254,6 +253,12 @@ pub fn Dashboard() -> impl IntoView {
                     <span class="icon">"◆"</span>
                     {move || t("dashboard.nft")}
                 </button>
+                {move || (active_chain.get() == "solana").then(|| view! {
+                    <button class="action-btn" on:click=move |_| set_page.set(AppPage::Staking)>
+                        <span class="icon">"⬡"</span>
+                        {move || t("dashboard.stake")}
+                    </button>
+                })}
             </div>
 
             // Current address
@@ -266,12 +271,22 @@ pub fn Dashboard() -> impl IntoView {
                 let tokens = wallet_state.with(|s| {
                     s.token_balances.get(&s.active_chain).cloned().unwrap_or_default()
                 });
-                if tokens.is_empty() {
+                // Chains with a token standard always show the header so tokens can be imported
+                let can_import = !testnet_mode.get()
+                    && wallet_core::tokens::custom::TokenKind::for_chain(&active_chain.get()).is_some();
+                if tokens.is_empty() && !can_import {
                     None
                 } else {
                     Some(view! {
                         <div style="padding: 0 20px 16px;">
-                            <h3 class="text-sm text-muted mb-2">{move || t("dashboard.tokens")}</h3>
+                            <div class="flex items-center justify-between mb-2">
+                                <h3 class="text-sm text-muted" style="margin: 0;">{move || t("dashboard.tokens")}</h3>
+                                {can_import.then(|| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| set_page.set(AppPage::Tokens)>
+                                        {move || t("tokens.import")}
+                                    </button>
+                                })}
+                            </div>
                             <div class="chain-list">
                                 {tokens.into_iter().map(|tb| {
                                     view! {
@@ -6,7 +6,18 @@ use leptos::prelude::*;
 use crate::state::*;
 use crate::rpc::history::{TxRecord, TxDirection};
 use crate::i18n::t;
-use wallet_core::chains::get_chains;
+use wallet_core::tx::evm_pending::{self, PendingEvmTx, PendingKind};
+
+/// Drop mined entries and return the latest broadcast per nonce for this account
+async fn refresh_pending(chain: &str, address: &str, rpc_url: &str) -> Vec<PendingEvmTx> {
+    let mut all = load_pending_evm_txs();
+    if let Ok(mined) = crate::rpc::evm::get_nonce(address, rpc_url).await {
+        if evm_pending::prune_mined(&mut all, chain, address, mined) {
+            save_pending_evm_txs(&all);
+        }
+    }
+    evm_pending::latest_by_nonce(&all, chain, address).into_iter().cloned().collect()
+}
 
 #[component]
 pub fn HistoryPage() -> impl IntoView {
@@ -16,6 +27,14 @@ pub fn HistoryPage() -> impl IntoView {
 
     let (transactions, set_transactions) = signal(Vec::<TxRecord>::new());
     let (loading, set_loading) = signal(false);
+    let (pending, set_pending) = signal(Vec::<PendingEvmTx>::new());
+    // (hash, is_cancel) of the pending tx being replaced
+    let (replacing, set_replacing) = signal::<Option<(String, bool)>>(None);
+    let (password, set_password) = signal(String::new());
+    let (replace_status, set_replace_status) = signal(String::new());
+    let (replace_busy, set_replace_busy) = signal(false);
+    // Lowercased counterparty address -> verified ENS primary name
+    let (primary_names, set_primary_names) = signal(std::collections::HashMap::<String, String>::new());
 
     // Fetch history when page loads
     Effect::new(move |_| {
@@ -31,14 +50,14 @@ pub fn HistoryPage() -> impl IntoView {
 
         set_loading.set(true);
 
-        let rpc_url = get_chains(testnet_mode.get()).into_iter()
+        let rpc_url = all_chains(testnet_mode.get()).into_iter()
             .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
             .and_then(|c| c.rpc_urls.first().cloned())
             .unwrap_or_default();
 
         wasm_bindgen_futures::spawn_local(async move {
-            let evm_chains = ["ethereum", "polygon", "bsc", "optimism", "base", "arbitrum"];
-            let txs = if evm_chains.contains(&chain.as_str()) {
+            let txs = if crate::state::is_evm_chain(&chain) {
+                set_pending.set(refresh_pending(&chain, &address, &rpc_url).await);
                 crate::rpc::history::fetch_evm_history(&address, &chain, &rpc_url).await
             } else if chain == "solana" {
                 crate::rpc::history::fetch_solana_history(&address, &rpc_url).await
@@ -50,11 +69,58 @@ pub fn HistoryPage() -> impl IntoView {
                 Vec::new()
             };
 
+            let is_evm = crate::state::is_evm_chain(&chain);
+            let counterparties: Vec<String> = if is_evm {
+                let mut seen = std::collections::HashSet::new();
+                txs.iter()
+                    .map(|tx| if tx.direction == TxDirection::Sent { tx.to.to_lowercase() } else { tx.from.to_lowercase() })
+                    .filter(|a| !a.is_empty() && seen.insert(a.clone()))
+                    .take(10)
+                    .collect()
+            } else {
+                Vec::new()
+            };
             set_transactions.set(txs);
             set_loading.set(false);
+
+            let Some(ens_rpc) = crate::rpc::ens::ens_rpc_url(false) else { return };
+            for addr in counterparties {
+                if let Some(name) = crate::rpc::ens::lookup_primary_name(&addr, &ens_rpc).await {
+                    set_primary_names.update(|m| { m.insert(addr, name); });
+                }
+            }
         });
     });
 
+    let confirm_replace = move |_| {
+        let Some((hash, cancel)) = replacing.get_untracked() else { return };
+        let pwd = password.get_untracked();
+        if pwd.is_empty() {
+            set_replace_status.set(t("approve.enter_password"));
+            return;
+        }
+        let (chain, address) = wallet_state.with_untracked(|s| (s.active_chain.clone(), s.current_address()));
+        let rpc_url = all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| crate::rpc::chain_id_str(&c.id) == chain)
+            .and_then(|c| c.rpc_urls.first().cloned())
+            .unwrap_or_default();
+        set_replace_busy.set(true);
+        set_replace_status.set(t("send.signing"));
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = crate::tx_send::execute_replacement(&hash, cancel, &pwd).await;
+            set_replace_busy.set(false);
+            match result {
+                Ok(new_hash) => {
+                    set_replace_status.set(format!("{} {}", t("send.tx_sent"), new_hash));
+                    set_replacing.set(None);
+                    set_password.set(String::new());
+                    set_pending.set(refresh_pending(&chain, &address, &rpc_url).await);
+                }
+                Err(e) => set_replace_status.set(format!("{} {}", t("send.error"), e)),
+            }
+        });
+    };
+
     view! {
         <div class="p-4">
             <div class="flex items-center justify-between mb-4">
@@ -65,6 +131,93 @@ pub fn HistoryPage() -> impl IntoView {
                 <div style="width: 60px;" />
             </div>
 
+            // Pending EVM transactions (speed up / cancel)
+            {move || {
+                let list = pending.get();
+                if list.is_empty() {
+                    return None;
+                }
+                Some(view! {
+                    <div class="mb-4">
+                        <p class="text-sm text-muted mb-2">{t("history.pending")}</p>
+                        {list.into_iter().map(|p| {
+                            let label = match p.kind {
+                                PendingKind::Original => t("history.pending_tx"),
+                                PendingKind::SpeedUp => t("history.sped_up"),
+                                PendingKind::Cancel => t("history.cancelling"),
+                            };
+                            let hash_short = if p.hash.len() > 16 {
+                                format!("{}...{}", &p.hash[..8], &p.hash[p.hash.len()-6..])
+                            } else {
+                                p.hash.clone()
+                            };
+                            let hash_speed = p.hash.clone();
+                            let hash_cancel = p.hash.clone();
+                            let hash_active = p.hash.clone();
+                            let is_active = move || replacing.get()
+                                .map(|(h, _)| h == hash_active)
+                                .unwrap_or(false);
+                            view! {
+                                <div class="card text-sm" style="margin-bottom: 8px;">
+                                    <div class="flex justify-between items-center">
+                                        <div>
+                                            <div>{format!("⏳ {} · nonce {}", label, p.nonce)}</div>
+                                            <div class="text-muted" style="font-family: monospace;">{hash_short}</div>
+                                        </div>
+                                        <div style="display: flex; gap: 4px;">
+                                            <button class="btn btn-sm btn-secondary"
+                                                on:click=move |_| {
+                                                    set_replace_status.set(String::new());
+                                                    set_replacing.set(Some((hash_speed.clone(), false)));
+                                                }
+                                            >{t("history.speed_up")}</button>
+                                            <button class="btn btn-sm btn-danger"
+                                                on:click=move |_| {
+                                                    set_replace_status.set(String::new());
+                                                    set_replacing.set(Some((hash_cancel.clone(), true)));
+                                                }
+                                            >{t("history.cancel_tx")}</button>
+                                        </div>
+                                    </div>
+                                    {move || is_active().then(|| {
+                                        let is_cancel = replacing.get().map(|(_, c)| c).unwrap_or(false);
+                                        view! {
+                                            <div style="margin-top: 8px;">
+                                                <p class="text-muted mb-2">
+                                                    {if is_cancel { t("history.cancel_hint") } else { t("history.speed_up_hint") }}
+                                                </p>
+                                                <input
+                                                    type="password"
+                                                    placeholder=t("approve.enter_password_placeholder")
+                                                    style="width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border);"
+                                                    prop:value=move || password.get()
+                                                    on:input=move |ev| set_password.set(event_target_value(&ev))
+                                                />
+                                                <div style="display: flex; gap: 8px; margin-top: 8px;">
+                                                    <button class="btn btn-primary" style="flex: 1;"
+                                                        on:click=confirm_replace
+                                                        disabled=move || replace_busy.get()
+                                                    >{t("confirm.confirm_sign")}</button>
+                                                    <button class="btn btn-secondary" style="flex: 1;"
+                                                        on:click=move |_| set_replacing.set(None)
+                                                    >{t("confirm.cancel")}</button>
+                                                </div>
+                                            </div>
+                                        }
+                                    })}
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                        {move || {
+                            let msg = replace_status.get();
+                            (!msg.is_empty()).then(|| view! {
+                                <p class="text-sm text-muted" style="word-break: break-all;">{msg}</p>
+                            })
+                        }}
+                    </div>
+                })
+            }}
+
             {move || {
                 if loading.get() {
                     return view! { <p class="text-center text-muted">{t("history.loading")}</p> }.into_any();
@@ -87,6 +240,8 @@ pub fn HistoryPage() -> impl IntoView {
                                 tx.hash.clone()
                             };
                             let explorer_url = tx.explorer_url.clone();
+                            let counterparty = if tx.direction == TxDirection::Sent { tx.to.to_lowercase() } else { tx.from.to_lowercase() };
+                            let primary_name = move || primary_names.with(|m| m.get(&counterparty).cloned());
 
                             view! {
                                 <div class="chain-item" style="cursor: pointer;"
@@ -99,6 +254,7 @@ pub fn HistoryPage() -> impl IntoView {
                                     <div class="chain-icon" style=format!("color: {};", color)>{icon}</div>
                                     <div class="chain-info">
                                         <div class="chain-name">{label}</div>
+                                        {move || primary_name().map(|name| view! { <div class="text-sm">{name}</div> })}
                                         <div class="text-sm text-muted" style="font-family: monospace;">{hash_short}</div>
                                     </div>
                                     <div style="text-align: right;">
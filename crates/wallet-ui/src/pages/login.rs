@@ -69,6 +69,7 @@ pub fn Login() -> impl IntoView {
                                     .unwrap_or("ethereum")
                                     .to_string();
 
+                                let ton_version = load_ton_version(&name);
                                 set_wallet_state.set(WalletState {
                                     is_unlocked: true,
                                     wallet_name: name,
@@ -79,6 +80,7 @@ pub fn Login() -> impl IntoView {
                                     prices: std::collections::HashMap::new(),
                                     token_balances: std::collections::HashMap::new(),
                                     nfts: Vec::new(),
+                                    ton_version,
                                 });
                                 set_page.set(AppPage::Dashboard);
                             }
@@ -10,7 +10,13 @@ pub mod history;
 pub mod approve;
 pub mod walletconnect;
 pub mod wc_proposal;
+pub mod ton_connect;
 pub mod approvals;
 pub mod address_book;
 pub mod nft;
 pub mod swap;
+pub mod networks;
+pub mod safe;
+pub mod tokens;
+pub mod verify;
+pub mod staking;
@@ -0,0 +1,182 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::chains::custom_evm::{self, CustomEvmNetwork};
+
+use crate::state::*;
+use crate::i18n::t;
+
+#[component]
+pub fn NetworksPage() -> impl IntoView {
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let set_wallet_state: WriteSignal<WalletState> = expect_context();
+
+    let (networks, set_networks) = signal(load_custom_networks());
+    let (show_form, set_show_form) = signal(false);
+    let (saving, set_saving) = signal(false);
+    let (error, set_error) = signal(String::new());
+
+    // Form fields
+    let (form_chain_id, set_form_chain_id) = signal(String::new());
+    let (form_name, set_form_name) = signal(String::new());
+    let (form_ticker, set_form_ticker) = signal(String::new());
+    let (form_rpc, set_form_rpc) = signal(String::new());
+    let (form_explorer, set_form_explorer) = signal(String::new());
+
+    let clear_form = move || {
+        set_form_chain_id.set(String::new());
+        set_form_name.set(String::new());
+        set_form_ticker.set(String::new());
+        set_form_rpc.set(String::new());
+        set_form_explorer.set(String::new());
+        set_error.set(String::new());
+        set_show_form.set(false);
+    };
+
+    let save_network = move |_| {
+        if saving.get_untracked() { return; }
+        let Ok(chain_id) = form_chain_id.get().trim().parse::<u64>() else {
+            set_error.set(t("networks.invalid_chain_id"));
+            return;
+        };
+        let explorer = form_explorer.get().trim().trim_end_matches('/').to_string();
+        let network = CustomEvmNetwork {
+            chain_id,
+            name: form_name.get().trim().to_string(),
+            ticker: form_ticker.get().trim().to_uppercase(),
+            rpc_urls: form_rpc.get()
+                .split([',', '\n', ' '])
+                .map(|u| u.trim().trim_end_matches('/').to_string())
+                .filter(|u| !u.is_empty())
+                .collect(),
+            explorer_url: if explorer.is_empty() { None } else { Some(explorer) },
+            explorer_api_url: None,
+        };
+        if let Err(e) = network.validate() {
+            set_error.set(e);
+            return;
+        }
+
+        set_saving.set(true);
+        set_error.set(t("networks.checking_rpc"));
+        wasm_bindgen_futures::spawn_local(async move {
+            let check = crate::rpc::evm::check_chain_id(&network.rpc_urls[0], network.chain_id).await;
+            set_saving.set(false);
+            if let Err(e) = check {
+                set_error.set(e);
+                return;
+            }
+            let mut list = networks.get_untracked();
+            custom_evm::upsert_network(&mut list, network);
+            save_custom_networks(&list);
+            set_networks.set(list);
+            clear_form();
+        });
+    };
+
+    let delete_network = move |chain_id: u64| {
+        let mut list = networks.get_untracked();
+        list.retain(|n| n.chain_id != chain_id);
+        save_custom_networks(&list);
+        set_networks.set(list);
+        // Don't leave the wallet pointing at a network that no longer exists
+        let removed_key = custom_evm::chain_key(chain_id);
+        set_wallet_state.update(|s| {
+            if s.active_chain == removed_key {
+                s.active_chain = "ethereum".to_string();
+            }
+            s.balances.remove(&removed_key);
+        });
+    };
+
+    let field = move |label: &'static str, placeholder: &'static str, value: ReadSignal<String>, set_value: WriteSignal<String>| {
+        view! {
+            <div class="mb-2">
+                <label class="text-sm text-muted">{t(label)}</label>
+                <input
+                    type="text"
+                    class="input"
+                    placeholder=placeholder
+                    prop:value=move || value.get()
+                    on:input=move |ev| set_value.set(event_target_value(&ev))
+                />
+            </div>
+        }
+    };
+
+    view! {
+        <div class="p-4">
+            <div class="flex items-center justify-between mb-4">
+                <button class="btn btn-sm btn-secondary" on:click=move |_| set_page.set(AppPage::Settings)>
+                    {move || t("common.back")}
+                </button>
+                <h2>{move || t("networks.title")}</h2>
+                <button class="btn btn-sm btn-primary" on:click=move |_| {
+                    clear_form();
+                    set_show_form.set(true);
+                }>
+                    {move || t("networks.add")}
+                </button>
+            </div>
+
+            <p class="text-sm text-muted mb-3">{move || t("networks.hint")}</p>
+
+            // Add form
+            {move || {
+                if !show_form.get() {
+                    return view! { <div /> }.into_any();
+                }
+                view! {
+                    <div class="card mb-3 p-3">
+                        {field("networks.chain_id", "43114", form_chain_id, set_form_chain_id)}
+                        {field("networks.name", "Avalanche C-Chain", form_name, set_form_name)}
+                        {field("networks.ticker", "AVAX", form_ticker, set_form_ticker)}
+                        {field("networks.rpc_url", "https://...", form_rpc, set_form_rpc)}
+                        {field("networks.explorer_url", "https://...", form_explorer, set_form_explorer)}
+                        {move || {
+                            let e = error.get();
+                            if e.is_empty() { None } else { Some(view! { <p class="text-sm mb-2" style="color: var(--warning, #ff9800);">{e}</p> }) }
+                        }}
+                        <div class="flex gap-2">
+                            <button class="btn btn-primary flex-1" disabled=move || saving.get() on:click=save_network>
+                                {move || t("networks.save")}
+                            </button>
+                            <button class="btn btn-secondary flex-1" on:click=move |_| clear_form()>
+                                {move || t("common.cancel")}
+                            </button>
+                        </div>
+                    </div>
+                }.into_any()
+            }}
+
+            // Network list
+            {move || {
+                let list = networks.get();
+                if list.is_empty() {
+                    return view! { <p class="text-center text-muted">{t("networks.empty")}</p> }.into_any();
+                }
+                view! {
+                    <div class="chain-list">
+                        {list.into_iter().map(|net| {
+                            let chain_id = net.chain_id;
+                            let rpc = net.rpc_urls.first().cloned().unwrap_or_default();
+                            view! {
+                                <div class="chain-item">
+                                    <div class="chain-info" style="flex: 1;">
+                                        <div class="chain-name">{net.name.clone()}</div>
+                                        <div class="chain-ticker">{format!("{} · {} {}", net.ticker, t("networks.chain_id"), chain_id)}</div>
+                                        <div class="text-sm text-muted" style="font-family: monospace; word-break: break-all;">{rpc}</div>
+                                    </div>
+                                    <button class="btn btn-sm btn-danger" on:click=move |_| delete_network(chain_id)>
+                                        {t("networks.delete")}
+                                    </button>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -6,17 +6,144 @@ use leptos::prelude::*;
 
 use crate::state::*;
 use crate::i18n::t;
-use wallet_core::nft::NftItem;
+use crate::components::asset_changes::AssetChangesCard;
+use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::tx_send;
+use wallet_core::nft::{NftItem, NftStandard};
+use wallet_core::tx::evm_simulate::{SimCall, SimulationOutcome};
+
+/// Pre-send check result for the NFT transfer form
+#[derive(Clone)]
+enum NftReview {
+    Evm(SimulationOutcome, String, String),
+    /// Solana dry run: `Some(reason)` when it would fail
+    Solana(Option<String>),
+}
 
 #[component]
 pub fn NftPage() -> impl IntoView {
     let wallet_state: ReadSignal<WalletState> = expect_context();
     let set_wallet_state: WriteSignal<WalletState> = expect_context();
     let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
 
     let (loading, set_loading) = signal(false);
     let (selected_nft, set_selected_nft) = signal::<Option<NftItem>>(None);
 
+    // Send form
+    let (send_item, set_send_item) = signal::<Option<NftItem>>(None);
+    let (recipient, set_recipient) = signal(String::new());
+    let (amount, set_amount) = signal("1".to_string());
+    let (scam_warning, set_scam_warning) = signal::<Option<String>>(None);
+    let (review, set_review) = signal::<Option<NftReview>>(None);
+    let (password, set_password) = signal(String::new());
+    let (status, set_status) = signal(String::new());
+    let (busy, set_busy) = signal(false);
+
+    let open_send = move |nft: NftItem| {
+        set_selected_nft.set(None);
+        set_recipient.set(String::new());
+        set_amount.set("1".into());
+        set_scam_warning.set(None);
+        set_review.set(None);
+        set_password.set(String::new());
+        set_status.set(String::new());
+        set_send_item.set(Some(nft));
+    };
+
+    let chain_config = move |item: &NftItem| {
+        all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| tx_send::chain_id_to_string(&c.id) == item.chain_id)
+    };
+
+    let parsed_amount = move |item: &NftItem| -> Result<u128, String> {
+        if NftStandard::from_item(item) != Some(NftStandard::Erc1155) {
+            return Ok(1);
+        }
+        amount.get_untracked().trim().parse::<u128>().ok()
+            .filter(|a| *a > 0)
+            .ok_or_else(|| t("send.enter_amount"))
+    };
+
+    // Scam check and simulation before the password is requested
+    let on_review = move |_| {
+        let Some(item) = send_item.get_untracked() else { return };
+        let to = recipient.get_untracked().trim().to_string();
+        if to.is_empty() {
+            set_status.set(t("send.enter_recipient"));
+            return;
+        }
+        let qty = match parsed_amount(&item) {
+            Ok(q) => q,
+            Err(e) => {
+                set_status.set(e);
+                return;
+            }
+        };
+        let Some(config) = chain_config(&item) else {
+            set_status.set(t("approve.unsupported_chain"));
+            return;
+        };
+        let Some(rpc_url) = config.rpc_urls.first().cloned() else { return };
+        let from = wallet_state.with_untracked(|s| s.current_address());
+        let (_, reason) = wallet_core::security::scam_addresses::assess_address_risk(&to, &from);
+        set_scam_warning.set(reason);
+        set_review.set(None);
+        set_status.set(t("nft.simulating"));
+        set_busy.set(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = if NftStandard::from_item(&item) == Some(NftStandard::Metaplex) {
+                tx_send::nft::simulate_solana(&item, &from, &to, &rpc_url).await.map(NftReview::Solana)
+            } else {
+                async {
+                    let call = tx_send::nft::evm_call(&item, &from, &to, qty)?;
+                    let sim_call = SimCall {
+                        from: wallet_core::tx::evm::parse_address(&from)?,
+                        to: call.to,
+                        value: call.value,
+                        data: call.data,
+                    };
+                    let outcome = crate::rpc::simulate::simulate_asset_changes(&rpc_url, &sim_call).await?;
+                    Ok(NftReview::Evm(outcome, item.chain_id.clone(), config.ticker.clone()))
+                }.await
+            };
+            set_busy.set(false);
+            match result {
+                Ok(r) => {
+                    set_status.set(String::new());
+                    set_review.set(Some(r));
+                }
+                Err(e) => set_status.set(e),
+            }
+        });
+    };
+
+    let on_send = move |_| {
+        let Some(item) = send_item.get_untracked() else { return };
+        let pwd = password.get_untracked();
+        if pwd.is_empty() {
+            set_status.set(t("approve.enter_password"));
+            return;
+        }
+        let to = recipient.get_untracked().trim().to_string();
+        let Ok(qty) = parsed_amount(&item) else { return };
+        let Some(config) = chain_config(&item) else { return };
+        set_busy.set(true);
+        set_status.set(t("send.signing"));
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = tx_send::nft::send(&item, &to, qty, &pwd, &config).await;
+            set_busy.set(false);
+            set_password.set(String::new());
+            match result {
+                Ok(hash) => {
+                    set_status.set(format!("{} {}", t("send.tx_sent"), hash));
+                    set_review.set(None);
+                }
+                Err(e) => set_status.set(format!("{} {}", t("send.error"), e)),
+            }
+        });
+    };
+
     // Fetch NFTs on mount
     Effect::new(move |_| {
         let (is_unlocked, chain, address) = wallet_state.with(|s| {
@@ -207,6 +334,19 @@ pub fn NftPage() -> impl IntoView {
                                     </div>
                                 </div>
 
+                                {NftStandard::from_item(&nft).is_some().then(|| {
+                                    let nft = nft.clone();
+                                    view! {
+                                        <button
+                                            class="btn btn-primary btn-block"
+                                            style="margin-top: 12px;"
+                                            on:click=move |_| open_send(nft.clone())
+                                        >
+                                            {t("nft.send")}
+                                        </button>
+                                    }
+                                })}
+
                                 <button
                                     class="btn btn-secondary btn-block"
                                     style="margin-top: 12px;"
@@ -219,6 +359,100 @@ pub fn NftPage() -> impl IntoView {
                     }
                 })
             }}
+
+            // Send form
+            {move || {
+                send_item.get().map(|item| {
+                    let name = if item.name.is_empty() { format!("#{}", item.token_id) } else { item.name.clone() };
+                    let is_1155 = NftStandard::from_item(&item) == Some(NftStandard::Erc1155);
+                    view! {
+                        <div class="nft-detail-overlay" on:click=move |_| set_send_item.set(None)>
+                            <div class="nft-detail" on:click=move |ev| ev.stop_propagation()>
+                                <h3 style="margin-bottom: 8px;">{format!("{} — {}", t("nft.send"), name)}</h3>
+                                <div class="input-group">
+                                    <label>{t("send.recipient")}</label>
+                                    <input
+                                        type="text"
+                                        placeholder=t("send.recipient_placeholder")
+                                        prop:value=move || recipient.get()
+                                        on:input=move |ev| {
+                                            set_recipient.set(event_target_value(&ev));
+                                            set_review.set(None);
+                                        }
+                                    />
+                                </div>
+                                {is_1155.then(|| view! {
+                                    <div class="input-group">
+                                        <label>{t("send.amount")}</label>
+                                        <input
+                                            type="text"
+                                            prop:value=move || amount.get()
+                                            on:input=move |ev| {
+                                                set_amount.set(event_target_value(&ev));
+                                                set_review.set(None);
+                                            }
+                                        />
+                                    </div>
+                                })}
+                                {move || scam_warning.get().map(|msg| view! {
+                                    <SecurityWarning
+                                        severity=Severity::High
+                                        title=t("send.suspicious_address")
+                                        message=msg
+                                        dismissable=false
+                                    />
+                                })}
+                                {move || review.get().map(|r| match r {
+                                    NftReview::Evm(outcome, chain, ticker) => view! {
+                                        <AssetChangesCard outcome=outcome chain=chain ticker=ticker />
+                                    }.into_any(),
+                                    NftReview::Solana(Some(reason)) => view! {
+                                        <SecurityWarning severity=Severity::High title=t("sim.would_fail") message=reason dismissable=false />
+                                    }.into_any(),
+                                    NftReview::Solana(None) => view! {
+                                        <p class="text-sm text-muted mb-2">{t("nft.simulation_ok")}</p>
+                                    }.into_any(),
+                                })}
+                                {move || {
+                                    let s = status.get();
+                                    (!s.is_empty()).then(|| view! {
+                                        <p class="text-sm" style="word-break: break-all; margin-bottom: 8px;">{s}</p>
+                                    })
+                                }}
+                                {move || {
+                                    if review.get().is_some() {
+                                        view! {
+                                            <input
+                                                type="password"
+                                                placeholder=t("approve.enter_password_placeholder")
+                                                style="width: 100%; margin-bottom: 8px;"
+                                                prop:value=move || password.get()
+                                                on:input=move |ev| set_password.set(event_target_value(&ev))
+                                            />
+                                            <button class="btn btn-primary btn-block" disabled=move || busy.get() on:click=on_send>
+                                                {move || if busy.get() { t("send.sending") } else { t("confirm.confirm_sign") }}
+                                            </button>
+                                        }.into_any()
+                                    } else {
+                                        view! {
+                                            <button class="btn btn-primary btn-block" disabled=move || busy.get() on:click=on_review>
+                                                {t("nft.review")}
+                                            </button>
+                                        }.into_any()
+                                    }
+                                }}
+                                <button
+                                    class="btn btn-secondary btn-block"
+                                    style="margin-top: 12px;"
+                                    on:click=move |_| set_send_item.set(None)
+                                >
+                                    {t("confirm.cancel")}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                })
+            }}
         </div>
     }
 }
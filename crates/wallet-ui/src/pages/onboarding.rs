@@ -5,6 +5,7 @@ use leptos::prelude::*;
 use wallet_core::bip39_utils::{self, WordCount};
 use wallet_core::wallet::{self, WalletStore, PasswordStrength, validate_password_strength};
 use wallet_core::crypto;
+use wallet_core::chains::ton::WalletVersion;
 use zeroize::Zeroize;
 
 use crate::state::*;
@@ -134,6 +135,7 @@ pub fn Onboarding() -> impl IntoView {
         let name = wallet_name.get();
         let phrase = mnemonic.get();
         let chains = enabled_chains.get();
+        let imported = import_mode.get();
 
         // Three-phase creation to avoid blocking the main thread for >5s:
         // Phase 1 (Timeout 50ms): BIP39 mnemonic → seed (~0.5-2s)
@@ -195,6 +197,22 @@ pub fn Onboarding() -> impl IntoView {
                                 .unwrap_or("ethereum")
                                 .to_string();
 
+                            // A new wallet starts on v4r2; an imported one moves to
+                            // another version once we know only that one holds funds
+                            save_ton_version(&name, WalletVersion::default());
+                            if imported && addresses.contains_key("ton") {
+                                let (name, addresses) = (name.clone(), addresses.clone());
+                                wasm_bindgen_futures::spawn_local(async move {
+                                    // Stay on the default unless every version's balance was read
+                                    let Ok(balances) = crate::rpc::ton::get_version_balances(&addresses, false).await else { return };
+                                    let version = wallet_core::chains::ton::preferred_version(&balances);
+                                    if version != WalletVersion::default() {
+                                        save_ton_version(&name, version);
+                                        set_wallet_state.update(|s| if s.wallet_name == name { s.ton_version = version });
+                                    }
+                                });
+                            }
+
                             set_wallet_state.set(WalletState {
                                 is_unlocked: true,
                                 wallet_name: name,
@@ -205,6 +223,7 @@ pub fn Onboarding() -> impl IntoView {
                                 prices: std::collections::HashMap::new(),
                                 token_balances: std::collections::HashMap::new(),
                                 nfts: Vec::new(),
+                                ton_version: WalletVersion::default(),
                             });
                             set_page.set(AppPage::Dashboard);
                         }
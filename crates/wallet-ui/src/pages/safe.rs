@@ -0,0 +1,424 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::chains::evm::eip55_checksum;
+use wallet_core::tx::evm::parse_address;
+use wallet_core::tx::safe::{self, PendingSafeTx, WatchedSafe};
+
+use crate::rpc::safe::SafeInfo;
+use crate::state::*;
+use crate::i18n::t;
+
+/// Action waiting for the password
+#[derive(Clone, Debug, PartialEq)]
+enum SafeAction {
+    Confirm(PendingSafeTx),
+    Execute(PendingSafeTx),
+    Propose,
+}
+
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..8], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
+}
+
+#[component]
+pub fn SafePage() -> impl IntoView {
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let wallet_state: ReadSignal<WalletState> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let (safes, set_safes) = signal(load_watched_safes());
+    let (selected, set_selected) = signal(Option::<WatchedSafe>::None);
+    let (info, set_info) = signal(Option::<SafeInfo>::None);
+    let (pending, set_pending) = signal(Vec::<PendingSafeTx>::new());
+    let (loading, set_loading) = signal(false);
+    let (status, set_status) = signal(String::new());
+
+    // Add form
+    let (show_form, set_show_form) = signal(false);
+    let (form_address, set_form_address) = signal(String::new());
+    let (form_name, set_form_name) = signal(String::new());
+    let (form_chain, set_form_chain) = signal("ethereum".to_string());
+    let (adding, set_adding) = signal(false);
+
+    // Proposal form and password prompt
+    let (propose_to, set_propose_to) = signal(String::new());
+    let (propose_amount, set_propose_amount) = signal(String::new());
+    let (action, set_action) = signal(Option::<SafeAction>::None);
+    let (password, set_password) = signal(String::new());
+    let (busy, set_busy) = signal(false);
+
+    let my_address = move || wallet_state.with(|s| {
+        wallet_core::wallet::EVM_CHAIN_IDS.iter()
+            .find_map(|c| s.addresses.get(*c).cloned())
+            .and_then(|a| parse_address(&a).ok())
+    });
+
+    // Chains with a Safe Transaction Service
+    let safe_chains = move || all_chains(testnet_mode.get()).into_iter()
+        .filter(|c| c.evm_chain_id.and_then(safe::service_url).is_some())
+        .collect::<Vec<_>>();
+
+    let refresh = move |watched: WatchedSafe| {
+        let rpc_url = all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| c.evm_chain_id == Some(watched.evm_chain_id))
+            .and_then(|c| c.rpc_urls.first().cloned());
+        set_loading.set(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            let Some(rpc_url) = rpc_url else {
+                set_status.set(t("safe.no_rpc"));
+                set_loading.set(false);
+                return;
+            };
+            match crate::rpc::safe::fetch_info(&watched.address, &rpc_url).await {
+                Ok(i) => {
+                    let queue = crate::rpc::safe::fetch_pending(&watched, i.nonce).await;
+                    match queue {
+                        Ok(list) => set_pending.set(list),
+                        Err(e) => set_status.set(format!("{} {}", t("safe.queue_error"), e)),
+                    }
+                    set_info.set(Some(i));
+                }
+                Err(e) => set_status.set(format!("{} {}", t("safe.info_error"), e)),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let open_safe = move |watched: WatchedSafe| {
+        set_status.set(String::new());
+        set_info.set(None);
+        set_pending.set(Vec::new());
+        set_action.set(None);
+        set_selected.set(Some(watched.clone()));
+        refresh(watched);
+    };
+
+    let add_safe = move |_| {
+        if adding.get_untracked() { return; }
+        let chain_key = form_chain.get();
+        let Some(config) = all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| crate::rpc::chain_id_str(&c.id) == chain_key) else { return };
+        let (Some(evm_chain_id), Some(rpc_url)) = (config.evm_chain_id, config.rpc_urls.first().cloned()) else { return };
+        let watched = match WatchedSafe::new(form_address.get().trim(), &chain_key, evm_chain_id, &form_name.get()) {
+            Ok(w) => w,
+            Err(e) => {
+                set_status.set(e);
+                return;
+            }
+        };
+        if safes.get_untracked().iter().any(|s| s.address == watched.address && s.evm_chain_id == evm_chain_id) {
+            set_status.set(t("safe.already_added"));
+            return;
+        }
+        set_adding.set(true);
+        set_status.set(t("safe.checking"));
+        wasm_bindgen_futures::spawn_local(async move {
+            // Only accept addresses that answer like a Safe
+            let check = crate::rpc::safe::fetch_info(&watched.address, &rpc_url).await;
+            set_adding.set(false);
+            if let Err(e) = check {
+                set_status.set(format!("{} {}", t("safe.not_a_safe"), e));
+                return;
+            }
+            let mut list = safes.get_untracked();
+            list.push(watched);
+            save_watched_safes(&list);
+            set_safes.set(list);
+            set_form_address.set(String::new());
+            set_form_name.set(String::new());
+            set_show_form.set(false);
+            set_status.set(String::new());
+        });
+    };
+
+    let remove_safe = move |address: String, evm_chain_id: u64| {
+        let mut list = safes.get_untracked();
+        list.retain(|s| !(s.address == address && s.evm_chain_id == evm_chain_id));
+        save_watched_safes(&list);
+        set_safes.set(list);
+    };
+
+    let run_action = move |_| {
+        let (Some(act), Some(watched), Some(i)) = (action.get_untracked(), selected.get_untracked(), info.get_untracked()) else { return };
+        let pwd = password.get_untracked();
+        if pwd.is_empty() {
+            set_status.set(t("approve.enter_password"));
+            return;
+        }
+        let queue = pending.get_untracked();
+        let to = propose_to.get_untracked().trim().to_string();
+        let amount = propose_amount.get_untracked().trim().to_string();
+        set_busy.set(true);
+        set_status.set(t("send.signing"));
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = match act {
+                SafeAction::Confirm(p) => crate::tx_send::safe::confirm(&watched, &i, &p, &pwd).await
+                    .map(|_| t("safe.signed")),
+                SafeAction::Execute(p) => crate::tx_send::safe::execute(&watched, &i, &p, &pwd).await
+                    .map(|hash| format!("{} {}", t("send.tx_sent"), hash)),
+                SafeAction::Propose => crate::tx_send::safe::propose_transfer(&watched, &i, &queue, &to, &amount, &pwd).await
+                    .map(|nonce| format!("{} {}", t("safe.proposed"), nonce)),
+            };
+            set_busy.set(false);
+            match result {
+                Ok(msg) => {
+                    set_status.set(msg);
+                    set_action.set(None);
+                    set_password.set(String::new());
+                    set_propose_to.set(String::new());
+                    set_propose_amount.set(String::new());
+                    refresh(watched);
+                }
+                Err(e) => set_status.set(format!("{} {}", t("send.error"), e)),
+            }
+        });
+    };
+
+    let input_style = "width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border); margin-bottom: 8px;";
+
+    let password_prompt = move || action.get().map(|_| view! {
+        <div class="card mb-3 p-3">
+            <input
+                type="password"
+                placeholder=t("approve.enter_password_placeholder")
+                style=input_style
+                prop:value=move || password.get()
+                on:input=move |ev| set_password.set(event_target_value(&ev))
+            />
+            <div class="flex gap-2">
+                <button class="btn btn-primary flex-1" disabled=move || busy.get() on:click=run_action>
+                    {t("confirm.confirm_sign")}
+                </button>
+                <button class="btn btn-secondary flex-1" on:click=move |_| set_action.set(None)>
+                    {t("confirm.cancel")}
+                </button>
+            </div>
+        </div>
+    });
+
+    let safe_detail = move |watched: WatchedSafe| {
+        let chain_name = all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| c.evm_chain_id == Some(watched.evm_chain_id))
+            .map(|c| (c.name, c.ticker))
+            .unwrap_or_else(|| (watched.chain.clone(), "ETH".into()));
+        let ticker = chain_name.1.clone();
+        view! {
+            <div>
+                <div class="card mb-3">
+                    <div class="chain-name">{if watched.name.is_empty() { t("safe.title") } else { watched.name.clone() }}</div>
+                    <div class="text-sm text-muted" style="font-family: monospace; word-break: break-all;">{watched.address.clone()}</div>
+                    <div class="text-sm text-muted">{chain_name.0}</div>
+                    {move || info.get().map(|i| {
+                        let me = my_address();
+                        let is_owner = me.map(|m| i.owners.contains(&m)).unwrap_or(false);
+                        view! {
+                            <div class="text-sm" style="margin-top: 6px;">
+                                <div>{format!("{} {}/{} · nonce {}", t("safe.threshold"), i.threshold, i.owners.len(), i.nonce)}</div>
+                                <div class="text-muted">{if is_owner { t("safe.you_are_owner") } else { t("safe.not_owner") }}</div>
+                            </div>
+                        }
+                    })}
+                </div>
+
+                {move || loading.get().then(|| view! { <p class="text-center text-muted">{t("history.loading")}</p> })}
+
+                // Queue
+                <p class="text-sm text-muted mb-2">{move || t("safe.queue")}</p>
+                {move || {
+                    let list = pending.get();
+                    let Some(i) = info.get() else { return view! { <div /> }.into_any() };
+                    if list.is_empty() {
+                        return view! { <p class="text-center text-muted mb-3">{t("safe.queue_empty")}</p> }.into_any();
+                    }
+                    let me = my_address();
+                    let is_owner = me.map(|m| i.owners.contains(&m)).unwrap_or(false);
+                    let ticker = ticker.clone();
+                    view! {
+                        <div class="mb-3">
+                            {list.into_iter().map(|p| {
+                                let signed_by_me = me.map(|m| p.is_signed_by(&m)).unwrap_or(false);
+                                let count = p.valid_confirmations(&i.owners) as u64;
+                                // Executing adds our approval if it's missing
+                                let executable = is_owner && p.tx.nonce == i.nonce
+                                    && count + u64::from(!signed_by_me) >= i.threshold;
+                                let to = short(&eip55_checksum(&p.tx.to));
+                                let desc = if p.tx.data.is_empty() {
+                                    format!("{} {} → {}", crate::rpc::evm::format_wei(p.tx.value, 18), ticker, to)
+                                } else {
+                                    format!("{} → {} ({} bytes)", t("safe.contract_call"), to, p.tx.data.len())
+                                };
+                                let for_confirm = p.clone();
+                                let for_execute = p.clone();
+                                view! {
+                                    <div class="card text-sm" style="margin-bottom: 8px;">
+                                        <div>{format!("#{} · {}", p.tx.nonce, desc)}</div>
+                                        {(p.tx.operation == safe::SafeOperation::DelegateCall).then(|| view! {
+                                            <div style="color: var(--warning, #ff9800);">{t("safe.delegatecall_warning")}</div>
+                                        })}
+                                        <div class="text-muted">{format!("{} {}/{}", t("safe.confirmations"), count, i.threshold)}</div>
+                                        <div style="display: flex; gap: 4px; margin-top: 6px;">
+                                            {(is_owner && !signed_by_me).then(|| view! {
+                                                <button class="btn btn-sm btn-secondary"
+                                                    on:click=move |_| {
+                                                        set_status.set(String::new());
+                                                        set_action.set(Some(SafeAction::Confirm(for_confirm.clone())));
+                                                    }
+                                                >{t("safe.sign")}</button>
+                                            })}
+                                            {executable.then(|| view! {
+                                                <button class="btn btn-sm btn-primary"
+                                                    on:click=move |_| {
+                                                        set_status.set(String::new());
+                                                        set_action.set(Some(SafeAction::Execute(for_execute.clone())));
+                                                    }
+                                                >{t("safe.execute")}</button>
+                                            })}
+                                        </div>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    }.into_any()
+                }}
+
+                // New proposal (owners only)
+                {move || {
+                    let i = info.get()?;
+                    let is_owner = my_address().map(|m| i.owners.contains(&m)).unwrap_or(false);
+                    is_owner.then(|| view! {
+                        <div class="card mb-3 p-3">
+                            <p class="text-sm text-muted mb-2">{t("safe.propose")}</p>
+                            <input type="text" placeholder="0x..." style=input_style
+                                prop:value=move || propose_to.get()
+                                on:input=move |ev| set_propose_to.set(event_target_value(&ev))
+                            />
+                            <input type="text" placeholder="0.0" style=input_style
+                                prop:value=move || propose_amount.get()
+                                on:input=move |ev| set_propose_amount.set(event_target_value(&ev))
+                            />
+                            <button class="btn btn-primary btn-block"
+                                on:click=move |_| {
+                                    set_status.set(String::new());
+                                    set_action.set(Some(SafeAction::Propose));
+                                }
+                            >{t("safe.propose_button")}</button>
+                        </div>
+                    })
+                }}
+            </div>
+        }
+    };
+
+    view! {
+        <div class="p-4">
+            <div class="flex items-center justify-between mb-4">
+                <button class="btn btn-sm btn-secondary" on:click=move |_| {
+                    if selected.get_untracked().is_some() {
+                        set_selected.set(None);
+                        set_action.set(None);
+                        set_status.set(String::new());
+                    } else {
+                        set_page.set(AppPage::Settings);
+                    }
+                }>
+                    {move || t("common.back")}
+                </button>
+                <h2>{move || t("safe.title")}</h2>
+                {move || selected.get().is_none().then(|| view! {
+                    <button class="btn btn-sm btn-primary" on:click=move |_| {
+                        set_status.set(String::new());
+                        set_show_form.set(true);
+                    }>
+                        {t("safe.add")}
+                    </button>
+                })}
+            </div>
+
+            {move || password_prompt()}
+
+            {move || {
+                let msg = status.get();
+                (!msg.is_empty()).then(|| view! {
+                    <p class="text-sm text-muted mb-2" style="word-break: break-all;">{msg}</p>
+                })
+            }}
+
+            {move || match selected.get() {
+                Some(watched) => safe_detail(watched).into_any(),
+                None => view! {
+                    <div>
+                        <p class="text-sm text-muted mb-3">{t("safe.hint")}</p>
+
+                        {move || show_form.get().then(|| view! {
+                            <div class="card mb-3 p-3">
+                                <label class="text-sm text-muted">{t("safe.address")}</label>
+                                <input type="text" placeholder="0x..." style=input_style
+                                    prop:value=move || form_address.get()
+                                    on:input=move |ev| set_form_address.set(event_target_value(&ev))
+                                />
+                                <label class="text-sm text-muted">{t("safe.name")}</label>
+                                <input type="text" placeholder="Treasury" style=input_style
+                                    prop:value=move || form_name.get()
+                                    on:input=move |ev| set_form_name.set(event_target_value(&ev))
+                                />
+                                <label class="text-sm text-muted">{t("safe.network")}</label>
+                                <select style=input_style
+                                    prop:value=move || form_chain.get()
+                                    on:change=move |ev| set_form_chain.set(event_target_value(&ev))
+                                >
+                                    {safe_chains().into_iter().map(|c| {
+                                        let key = crate::rpc::chain_id_str(&c.id);
+                                        view! { <option value=key>{c.name}</option> }
+                                    }).collect::<Vec<_>>()}
+                                </select>
+                                <div class="flex gap-2">
+                                    <button class="btn btn-primary flex-1" disabled=move || adding.get() on:click=add_safe>
+                                        {t("safe.save")}
+                                    </button>
+                                    <button class="btn btn-secondary flex-1" on:click=move |_| set_show_form.set(false)>
+                                        {t("common.cancel")}
+                                    </button>
+                                </div>
+                            </div>
+                        })}
+
+                        {move || {
+                            let list = safes.get();
+                            if list.is_empty() {
+                                return view! { <p class="text-center text-muted">{t("safe.empty")}</p> }.into_any();
+                            }
+                            view! {
+                                <div class="chain-list">
+                                    {list.into_iter().map(|s| {
+                                        let for_open = s.clone();
+                                        let address = s.address.clone();
+                                        let evm_chain_id = s.evm_chain_id;
+                                        view! {
+                                            <div class="chain-item">
+                                                <div class="chain-info" style="flex: 1; cursor: pointer;"
+                                                    on:click=move |_| open_safe(for_open.clone())
+                                                >
+                                                    <div class="chain-name">{if s.name.is_empty() { short(&s.address) } else { s.name.clone() }}</div>
+                                                    <div class="chain-ticker">{format!("{} · {}", short(&s.address), s.chain)}</div>
+                                                </div>
+                                                <button class="btn btn-sm btn-danger"
+                                                    on:click=move |_| remove_safe(address.clone(), evm_chain_id)
+                                                >{t("networks.delete")}</button>
+                                            </div>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </div>
+                            }.into_any()
+                        }}
+                    </div>
+                }.into_any(),
+            }}
+        </div>
+    }
+}
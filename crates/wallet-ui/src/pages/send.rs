@@ -7,8 +7,13 @@ use leptos::prelude::*;
 use crate::state::*;
 use crate::components::confirmation_modal::ConfirmationModal;
 use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::components::fee_selector::{EvmFeeSelector, SolanaFeeSelector};
+use crate::components::asset_changes::AssetChangesCard;
 use crate::tx_send;
 use crate::i18n::t;
+use wallet_core::tx::evm_fees::{self, Eip1559Fees, FeeEstimate};
+use wallet_core::tx::evm_simulate::{SimCall, SimulationOutcome};
+use wallet_core::tx::solana_fees::PriorityFeeEstimate;
 
 #[component]
 pub fn SendPage() -> impl IntoView {
@@ -18,6 +23,7 @@ pub fn SendPage() -> impl IntoView {
 
     let (recipient, set_recipient) = signal(String::new());
     let (amount, set_amount) = signal(String::new());
+    let (memo, set_memo) = signal(String::new());
     let (status, set_status) = signal(String::new());
     let (status_type, set_status_type) = signal("warning"); // "warning" | "success" | "danger"
     let (sending, set_sending) = signal(false);
@@ -25,7 +31,15 @@ pub fn SendPage() -> impl IntoView {
     let (estimated_fee, set_estimated_fee) = signal("0.0000".to_string());
     let (selected_token, set_selected_token) = signal(String::new());
     let (scam_warning, set_scam_warning) = signal::<Option<String>>(None);
-    let (sim_warning, set_sim_warning) = signal::<Option<String>>(None);
+    let (sim_outcome, set_sim_outcome) = signal::<Option<(SimulationOutcome, String, String)>>(None);
+    let (fee_estimate, set_fee_estimate) = signal::<Option<FeeEstimate>>(None);
+    let (gas_limit, set_gas_limit) = signal(evm_fees::NATIVE_TRANSFER_GAS);
+    let (evm_fees, set_evm_fees) = signal::<Option<Eip1559Fees>>(None);
+    let (sol_fee_estimate, set_sol_fee_estimate) = signal::<Option<PriorityFeeEstimate>>(None);
+    let (sol_priority, set_sol_priority) = signal::<Option<u64>>(None);
+    // (name, address) shown in the preview when the recipient is an ENS name
+    let (ens_resolved, set_ens_resolved) = signal::<Option<(String, String)>>(None);
+    let (ens_status, set_ens_status) = signal(String::new());
 
     let active_chain = move || wallet_state.with(|s| s.active_chain.clone());
 
@@ -46,9 +60,83 @@ pub fn SendPage() -> impl IntoView {
     };
 
     let is_evm = move || {
-        matches!(active_chain().as_str(), "ethereum" | "polygon" | "bsc" | "optimism" | "base" | "arbitrum")
+        crate::state::is_evm_chain(&active_chain())
     };
 
+    // Resolve ENS recipients (debounced) on EVM chains
+    Effect::new(move |_| {
+        let input = recipient.get().trim().to_string();
+        set_ens_resolved.set(None);
+        set_ens_status.set(String::new());
+        if !is_evm() || !wallet_core::ens::looks_like_ens_name(&input) {
+            return;
+        }
+        set_ens_status.set(t("ens.resolving"));
+        let chain = active_chain();
+        let testnet = testnet_mode.get_untracked();
+        gloo_timers::callback::Timeout::new(400, move || {
+            if recipient.get_untracked().trim() != input {
+                return;
+            }
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = resolve_ens_recipient(&input, &chain, testnet).await;
+                if recipient.get_untracked().trim() != input {
+                    return;
+                }
+                match result {
+                    Ok(address) => {
+                        let from = wallet_state.with_untracked(|s| s.current_address());
+                        let (_, reason) = wallet_core::security::scam_addresses::assess_address_risk(&address, &from);
+                        set_scam_warning.set(reason);
+                        set_ens_status.set(String::new());
+                        set_ens_resolved.set(Some((input, address)));
+                    }
+                    Err(e) => set_ens_status.set(e),
+                }
+            });
+        }).forget();
+    });
+
+    // Load EIP-1559 fee tiers whenever the active EVM network changes
+    Effect::new(move |_| {
+        let chain = active_chain();
+        let testnet = testnet_mode.get();
+        set_fee_estimate.set(None);
+        if !is_evm() {
+            return;
+        }
+        wasm_bindgen_futures::spawn_local(async move {
+            let chains = crate::state::all_chains(testnet);
+            let Some(rpc_url) = chains.iter()
+                .find(|c| tx_send::chain_id_to_string(&c.id) == chain)
+                .and_then(|c| c.rpc_urls.first().cloned()) else { return };
+            match crate::rpc::evm::get_fee_estimate(&rpc_url).await {
+                Ok(est) => set_fee_estimate.set(Some(est)),
+                Err(e) => set_status.set(format!("{} {}", t("fee.unavailable"), e)),
+            }
+        });
+    });
+
+    // Load Solana priority fee tiers for transactions paid by the current account
+    Effect::new(move |_| {
+        let testnet = testnet_mode.get();
+        set_sol_fee_estimate.set(None);
+        if active_chain() != "solana" {
+            return;
+        }
+        let from = wallet_state.with_untracked(|s| s.current_address());
+        wasm_bindgen_futures::spawn_local(async move {
+            let chains = crate::state::all_chains(testnet);
+            let Some(rpc_url) = chains.iter()
+                .find(|c| tx_send::chain_id_to_string(&c.id) == "solana")
+                .and_then(|c| c.rpc_urls.first().cloned()) else { return };
+            match crate::rpc::solana::get_priority_fees(&[from], &rpc_url).await {
+                Ok(est) => set_sol_fee_estimate.set(Some(est)),
+                Err(e) => set_status.set(format!("{} {}", t("fee.unavailable"), e)),
+            }
+        });
+    });
+
     let estimate = move |_| {
         let to = recipient.get();
         let amt = amount.get();
@@ -66,33 +154,56 @@ pub fn SendPage() -> impl IntoView {
 
         set_status.set(String::new());
 
+        // ENS names must be resolved (and shown) before the preview
+        let to = if is_evm() && wallet_core::ens::looks_like_ens_name(&to) {
+            match ens_resolved.get() {
+                Some((name, address)) if name == to.trim() => address,
+                _ => {
+                    set_status.set(t("ens.not_resolved"));
+                    set_status_type.set("warning");
+                    return;
+                }
+            }
+        } else {
+            to
+        };
+
         if is_evm() {
+            let Some(fees) = evm_fees.get() else {
+                set_status.set(t("fee.invalid"));
+                set_status_type.set("warning");
+                return;
+            };
             let chain = active_chain();
+            let token_addr = selected_token.get();
             let sim_to = to.clone();
             let sim_from = wallet_state.with(|s| s.current_address());
             set_estimated_fee.set(t("send.estimating"));
-            set_sim_warning.set(None);
+            set_sim_outcome.set(None);
             let testnet = testnet_mode.get();
             wasm_bindgen_futures::spawn_local(async move {
-                let chains = wallet_core::chains::get_chains(testnet);
+                let chains = crate::state::all_chains(testnet);
                 if let Some(config) = chains.iter().find(|c| tx_send::chain_id_to_string(&c.id) == chain) {
                     if let Some(rpc_url) = config.rpc_urls.first() {
-                        match crate::rpc::evm::get_gas_price(rpc_url).await {
-                            Ok(gas_price) => {
-                                let fee_wei = gas_price * 21000;
-                                let fee_gwei = fee_wei / 1_000_000_000;
-                                set_estimated_fee.set(format!("~{} Gwei", fee_gwei));
-                            }
-                            Err(_) => {
-                                set_estimated_fee.set("~21000 Gwei".into());
-                            }
-                        }
-                        if let Ok(sim) = crate::rpc::simulate::simulate_evm_tx(
-                            rpc_url, &sim_from, &sim_to, "0x0", ""
-                        ).await {
-                            if !sim.success {
-                                let reason = sim.error.unwrap_or_else(|| t("send.tx_would_fail"));
-                                set_sim_warning.set(Some(reason));
+                        let call = tx_send::evm::transfer_call(&sim_to, &amt, &token_addr, config);
+                        let gas = match &call {
+                            Ok(call) => tx_send::evm::estimate_call_gas(&sim_from, call, rpc_url).await,
+                            Err(_) => gas_limit.get_untracked(),
+                        };
+                        set_gas_limit.set(gas);
+                        let base_fee = fee_estimate.get_untracked()
+                            .map(|e| e.base_fee_per_gas)
+                            .unwrap_or(fees.max_fee_per_gas);
+                        set_estimated_fee.set(format!(
+                            "~{} ({} {})",
+                            evm_fees::format_fee(fees.expected_cost(gas, base_fee)),
+                            t("fee.max"),
+                            evm_fees::format_fee(fees.max_cost(gas)),
+                        ));
+                        if let (Ok(call), Ok(from)) = (call, wallet_core::tx::evm::parse_address(&sim_from)) {
+                            let sim_call = SimCall { from, to: call.to, value: call.value, data: call.data };
+                            if let Ok(outcome) = crate::rpc::simulate::simulate_asset_changes(rpc_url, &sim_call).await {
+                                set_sim_outcome.set(Some((outcome, chain.clone(), config.ticker.clone())));
                             }
                         }
                     }
@@ -113,7 +224,44 @@ pub fn SendPage() -> impl IntoView {
                 }
             });
         } else if active_chain() == "solana" {
-            set_estimated_fee.set("~0.000005 SOL".into());
+            set_estimated_fee.set(t("send.estimating"));
+            let mint = selected_token.get();
+            let memo_text = memo.get();
+            let price = sol_priority.get().unwrap_or(0);
+            let from = wallet_state.with(|s| s.current_address());
+            // (symbol, decimals) of the selected token, for the transfer fee line
+            let token = wallet_state.with(|s| s.token_balances.get("solana")
+                .and_then(|list| list.iter().find(|tb| tb.token.address == mint))
+                .map(|tb| (tb.token.symbol.clone(), tb.token.decimals)));
+            let testnet = testnet_mode.get();
+            wasm_bindgen_futures::spawn_local(async move {
+                let chains = crate::state::all_chains(testnet);
+                let Some(rpc_url) = chains.iter()
+                    .find(|c| tx_send::chain_id_to_string(&c.id) == "solana")
+                    .and_then(|c| c.rpc_urls.first().cloned()) else { return };
+                let estimate = match tx_send::solana::estimate_fee(&from, &to, &amt, &mint, &memo_text, price, &rpc_url).await {
+                    Ok(estimate) => estimate,
+                    Err(e) => {
+                        set_estimated_fee.set("~0.000005 SOL".into());
+                        set_status.set(e);
+                        set_status_type.set("warning");
+                        return;
+                    }
+                };
+                let sol = |lamports: u64| wallet_core::tokens::erc20::format_token_amount(lamports as u128, 9);
+                let fee = estimate.fee_lamports;
+                let mut line = format!("~{} SOL ({} {})", sol(fee), estimate.compute_units, t("fee.compute_units"));
+                if !mint.is_empty() {
+                    if let Ok(Some(rent)) = tx_send::solana::new_token_account_rent(&to, &mint, &rpc_url).await {
+                        line = format!("~{} SOL ({})", sol(fee + rent), t("send.ata_rent"));
+                    }
+                }
+                if let (true, Some((symbol, decimals))) = (estimate.token_fee > 0, token) {
+                    let token_fee = wallet_core::tokens::erc20::format_token_amount(estimate.token_fee as u128, decimals);
+                    line = format!("{} + {} {} {}", line, token_fee, symbol, t("send.token_transfer_fee"));
+                }
+                set_estimated_fee.set(line);
+            });
         } else if active_chain() == "ton" {
             if selected_token.get().is_empty() {
                 set_estimated_fee.set("~0.01 TON".into());
@@ -165,10 +313,23 @@ pub fn SendPage() -> impl IntoView {
         let to = recipient.get();
         let amt = amount.get();
         let token_addr = selected_token.get();
+        let memo_text = if chain == "solana" || chain == "ton" { memo.get() } else { String::new() };
         let testnet = testnet_mode.get();
+        let fees = if is_evm() { evm_fees.get() } else { None };
+        let priority_fee = if chain == "solana" { sol_priority.get() } else { None };
+        let previewed = ens_resolved.get();
 
         wasm_bindgen_futures::spawn_local(async move {
-            let result = tx_send::execute_send_for_network(&chain, &to, &amt, &password, &token_addr, testnet).await;
+            let result = match previewed {
+                // Re-resolve right before signing so a record change after the preview can't redirect funds
+                Some((name, previewed_addr)) => match resolve_ens_recipient(&name, &chain, testnet).await
+                    .and_then(|current| wallet_core::ens::ensure_unchanged(&name, &previewed_addr, &current).map(|_| current))
+                {
+                    Ok(address) => tx_send::execute_send_for_network(&chain, &address, &amt, &password, &token_addr, &memo_text, testnet, fees, priority_fee).await,
+                    Err(e) => Err(e),
+                },
+                None => tx_send::execute_send_for_network(&chain, &to, &amt, &password, &token_addr, &memo_text, testnet, fees, priority_fee).await,
+            };
             set_sending.set(false);
             match result {
                 Ok(tx_hash) => {
@@ -197,9 +358,10 @@ pub fn SendPage() -> impl IntoView {
                 <div style="width: 60px;" />
             </div>
 
-            // Token selector (EVM, Cosmos, Osmosis, TON)
+            // Token selector (EVM, Solana, Cosmos, Osmosis, TON)
             {move || {
                 let supports_tokens = is_evm()
+                    || active_chain() == "solana"
                     || active_chain() == "cosmos"
                     || active_chain() == "osmosis"
                     || active_chain() == "ton";
@@ -251,6 +413,21 @@ pub fn SendPage() -> impl IntoView {
                 />
             </div>
 
+            // ENS resolution result
+            {move || {
+                let resolved = ens_resolved.get().map(|(_, address)| view! {
+                    <p class="text-sm text-muted" style="font-family: monospace; word-break: break-all; margin-top: -8px; margin-bottom: 8px;">
+                        {format!("→ {}", address)}
+                    </p>
+                }.into_any());
+                resolved.or_else(|| {
+                    let s = ens_status.get();
+                    (!s.is_empty()).then(|| view! {
+                        <p class="text-sm" style="color: var(--warning, #ff9800); margin-top: -8px; margin-bottom: 8px;">{s}</p>
+                    }.into_any())
+                })
+            }}
+
             // Scam address warning
             {move || {
                 scam_warning.get().map(|msg| view! {
@@ -263,15 +440,10 @@ pub fn SendPage() -> impl IntoView {
                 })
             }}
 
-            // TX simulation warning
+            // Simulated asset changes
             {move || {
-                sim_warning.get().map(|msg| view! {
-                    <SecurityWarning
-                        severity=Severity::Medium
-                        title=t("send.tx_simulation")
-                        message=msg
-                        dismissable=true
-                    />
+                sim_outcome.get().map(|(outcome, chain, ticker)| view! {
+                    <AssetChangesCard outcome=outcome chain=chain ticker=ticker />
                 })
             }}
 
@@ -285,6 +457,22 @@ pub fn SendPage() -> impl IntoView {
                 />
             </div>
 
+            // Optional memo: Solana Memo instruction (required by some Token-2022
+            // recipient accounts) or TON text comment (also forwarded with jettons)
+            {move || {
+                (active_chain() == "solana" || active_chain() == "ton").then(|| view! {
+                    <div class="input-group">
+                        <label>{t("send.memo")}</label>
+                        <input
+                            type="text"
+                            placeholder=t("send.memo_placeholder")
+                            prop:value=move || memo.get()
+                            on:input=move |ev| set_memo.set(event_target_value(&ev))
+                        />
+                    </div>
+                })
+            }}
+
             <div class="card text-sm">
                 <div class="flex justify-between">
                     <span class="text-muted">{move || t("send.from")}</span>
@@ -309,6 +497,25 @@ pub fn SendPage() -> impl IntoView {
                 </div>
             </div>
 
+            // EIP-1559 fee tiers (editable before signing)
+            {move || {
+                is_evm().then(|| view! {
+                    <EvmFeeSelector
+                        estimate=fee_estimate
+                        gas_limit=gas_limit
+                        ticker=active_ticker()
+                        set_fees=set_evm_fees
+                    />
+                })
+            }}
+
+            // Solana priority fee tiers (compute unit price)
+            {move || {
+                (active_chain() == "solana").then(|| view! {
+                    <SolanaFeeSelector estimate=sol_fee_estimate set_price=set_sol_priority />
+                })
+            }}
+
             {move || {
                 let s = status.get();
                 if s.is_empty() { None } else {
@@ -335,7 +542,10 @@ pub fn SendPage() -> impl IntoView {
                 if show_confirm.get() {
                     Some(view! {
                         <ConfirmationModal
-                            recipient=recipient.get()
+                            recipient=match ens_resolved.get() {
+                                Some((name, address)) => format!("{} ({})", name, address),
+                                None => recipient.get(),
+                            }
                             amount=amount.get()
                             fee=estimated_fee.get()
                             chain=active_chain_name()
@@ -351,3 +561,13 @@ pub fn SendPage() -> impl IntoView {
         </div>
     }
 }
+
+/// Resolve an ENS name for the active EVM chain (ENSIP-11 coin type, ENSIP-19 default EVM record as fallback)
+async fn resolve_ens_recipient(name: &str, chain: &str, testnet: bool) -> Result<String, String> {
+    let evm_chain_id = crate::state::all_chains(testnet).into_iter()
+        .find(|c| tx_send::chain_id_to_string(&c.id) == chain)
+        .and_then(|c| c.evm_chain_id)
+        .unwrap_or(1);
+    let rpc_url = crate::rpc::ens::ens_rpc_url(testnet).ok_or("No Ethereum RPC for ENS")?;
+    crate::rpc::ens::resolve_name(name, wallet_core::ens::evm_coin_type(evm_chain_id), &rpc_url).await
+}
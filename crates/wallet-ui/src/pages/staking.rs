@@ -0,0 +1,420 @@
+// Rusby Wallet — Native SOL staking page (stake accounts and validators)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::tokens::erc20::format_token_amount;
+use wallet_core::tx::solana::{parse_pubkey, parse_sol_to_lamports};
+use wallet_core::tx::solana_stake::{ActivationState, StakeAccount, StakeOperation, Validator};
+
+use crate::state::*;
+use crate::i18n::t;
+use crate::components::security_warning::{SecurityWarning, Severity};
+
+/// Validators listed at once; the search box reaches the rest
+const VALIDATORS_SHOWN: usize = 30;
+
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..6], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
+}
+
+fn sol(lamports: u64) -> String {
+    format!("{} SOL", format_token_amount(lamports as u128, 9))
+}
+
+fn b58(key: &[u8; 32]) -> String {
+    bs58::encode(key).into_string()
+}
+
+fn state_label(state: ActivationState) -> (String, &'static str) {
+    match state {
+        ActivationState::Undelegated => (t("staking.undelegated"), "var(--text-secondary)"),
+        ActivationState::Activating => (t("staking.activating"), "var(--warning, #ff9800)"),
+        ActivationState::Active => (t("staking.active"), "var(--success, #4caf50)"),
+        ActivationState::Deactivating => (t("staking.deactivating"), "var(--warning, #ff9800)"),
+        ActivationState::Inactive => (t("staking.inactive"), "var(--text-secondary)"),
+    }
+}
+
+/// Action waiting for the password
+#[derive(Debug, Clone, PartialEq)]
+enum Pending {
+    Stake,
+    Delegate([u8; 32]),
+    Deactivate([u8; 32]),
+    Withdraw { account: [u8; 32], max: u64 },
+    Split([u8; 32]),
+    Merge { destination: [u8; 32], source: [u8; 32] },
+}
+
+impl Pending {
+    fn label(&self) -> String {
+        match self {
+            Pending::Stake => t("staking.stake"),
+            Pending::Delegate(_) => t("staking.delegate"),
+            Pending::Deactivate(_) => t("staking.deactivate"),
+            Pending::Withdraw { .. } => t("staking.withdraw"),
+            Pending::Split(_) => t("staking.split"),
+            Pending::Merge { .. } => t("staking.merge"),
+        }
+    }
+
+    fn needs_amount(&self) -> bool {
+        matches!(self, Pending::Withdraw { .. } | Pending::Split(_))
+    }
+}
+
+#[component]
+pub fn StakingPage() -> impl IntoView {
+    let wallet_state: ReadSignal<WalletState> = expect_context();
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let (accounts, set_accounts) = signal::<Vec<StakeAccount>>(vec![]);
+    let (epoch, set_epoch) = signal::<Option<u64>>(None);
+    let (validators, set_validators) = signal::<Vec<Validator>>(vec![]);
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (result_msg, set_result_msg) = signal(String::new());
+    let (amount, set_amount) = signal(String::new());
+    let (vote_choice, set_vote_choice) = signal(String::new());
+    let (search, set_search) = signal(String::new());
+    let (pending, set_pending) = signal::<Option<Pending>>(None);
+    let (action_amount, set_action_amount) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (working, set_working) = signal(false);
+
+    let owner = move || wallet_state.with(|s| s.addresses.get("solana").cloned().unwrap_or_default());
+    let rpc_url = move || all_chains(testnet_mode.get_untracked()).into_iter()
+        .find(|c| crate::rpc::chain_id_str(&c.id) == "solana")
+        .and_then(|c| c.rpc_urls.first().cloned());
+
+    let refresh = move || {
+        let owner = owner();
+        let Some(rpc_url) = rpc_url() else { return };
+        if owner.is_empty() || loading.get_untracked() { return; }
+        set_loading.set(true);
+        set_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::rpc::solana::get_epoch(&rpc_url).await {
+                Ok(e) => set_epoch.set(Some(e)),
+                Err(e) => set_error.set(Some(e)),
+            }
+            match crate::rpc::solana::get_stake_accounts(&owner, &rpc_url).await {
+                Ok(list) => set_accounts.set(list),
+                Err(e) => set_error.set(Some(format!("{} {}", t("staking.load_error"), e))),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    Effect::new(move |_| {
+        let _ = testnet_mode.get();
+        let ready = wallet_state.with(|s| s.is_unlocked);
+        if ready { refresh(); }
+    });
+
+    // Validators change slowly: load once per page visit
+    Effect::new(move |_| {
+        let Some(rpc_url) = all_chains(testnet_mode.get()).into_iter()
+            .find(|c| crate::rpc::chain_id_str(&c.id) == "solana")
+            .and_then(|c| c.rpc_urls.first().cloned()) else { return };
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::rpc::solana::get_validators(&rpc_url).await {
+                Ok(list) => set_validators.set(list),
+                Err(e) => set_error.set(Some(format!("{} {}", t("staking.validators_error"), e))),
+            }
+        });
+    });
+
+    let ask = move |action: Pending| {
+        set_result_msg.set(String::new());
+        set_error.set(None);
+        set_action_amount.set(match &action {
+            Pending::Withdraw { max, .. } => format_token_amount(*max as u128, 9),
+            _ => String::new(),
+        });
+        set_pending.set(Some(action));
+    };
+
+    let confirm = move |_| {
+        let Some(action) = pending.get_untracked() else { return };
+        if working.get_untracked() { return; }
+        let Some(rpc_url) = rpc_url() else { return };
+        let owner = owner();
+        let vote = vote_choice.get_untracked();
+        let stake_amount = amount.get_untracked();
+        let action_amt = action_amount.get_untracked();
+        let pwd = password.get_untracked();
+        let testnet = testnet_mode.get_untracked();
+        set_working.set(true);
+        set_error.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            let operation = async {
+                Ok::<_, String>(match action {
+                    Pending::Stake => StakeOperation::Stake {
+                        seed: crate::tx_send::solana::next_stake_seed(&owner, &rpc_url).await?,
+                        lamports: parse_sol_to_lamports(stake_amount.trim())?,
+                        vote_account: parse_pubkey(&vote).map_err(|_| t("staking.pick_validator"))?,
+                    },
+                    Pending::Delegate(stake_account) => StakeOperation::Delegate {
+                        stake_account,
+                        vote_account: parse_pubkey(&vote).map_err(|_| t("staking.pick_validator"))?,
+                    },
+                    Pending::Deactivate(stake_account) => StakeOperation::Deactivate { stake_account },
+                    Pending::Withdraw { account, max } => {
+                        let lamports = parse_sol_to_lamports(action_amt.trim())?;
+                        if lamports > max {
+                            return Err(t("staking.exceeds_withdrawable"));
+                        }
+                        StakeOperation::Withdraw { stake_account: account, lamports }
+                    }
+                    Pending::Split(stake_account) => StakeOperation::Split {
+                        stake_account,
+                        seed: crate::tx_send::solana::next_stake_seed(&owner, &rpc_url).await?,
+                        lamports: parse_sol_to_lamports(action_amt.trim())?,
+                    },
+                    Pending::Merge { destination, source } => StakeOperation::Merge { destination, source },
+                })
+            }.await;
+            let result = match operation {
+                Ok(op) => crate::tx_send::execute_stake_operation(&op, &pwd, testnet).await,
+                Err(e) => Err(e),
+            };
+            set_working.set(false);
+            match result {
+                Ok(signature) => {
+                    set_result_msg.set(format!("{} {}", t("send.tx_sent"), signature));
+                    set_pending.set(None);
+                    set_password.set(String::new());
+                    set_amount.set(String::new());
+                    refresh();
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    let total_staked = move || accounts.get().iter()
+        .filter_map(|a| a.delegation.as_ref().map(|d| d.stake))
+        .fold(0u64, u64::saturating_add);
+
+    let input_style = "width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border); margin-bottom: 8px;";
+
+    view! {
+        <div>
+            <div style="display: flex; align-items: center; gap: 8px; margin-bottom: 16px;">
+                <button class="btn btn-secondary" style="padding: 4px 12px;" on:click=move |_| set_page.set(AppPage::Dashboard)>
+                    "←"
+                </button>
+                <h2 style="margin: 0;">{move || t("staking.title")}</h2>
+            </div>
+
+            <SecurityWarning
+                severity=Severity::Low
+                title=t("staking.about")
+                message=t("staking.about_desc")
+                dismissable=true
+            />
+
+            <div class="card text-sm">
+                <div class="flex justify-between">
+                    <span class="text-muted">{move || t("staking.total_staked")}</span>
+                    <span style="font-weight: bold;">{move || sol(total_staked())}</span>
+                </div>
+                <div class="flex justify-between mt-2">
+                    <span class="text-muted">{move || t("staking.epoch")}</span>
+                    <span>{move || epoch.get().map(|e| e.to_string()).unwrap_or_else(|| "…".into())}</span>
+                </div>
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="card" style="border: 1px solid #e74c3c;">
+                    <p style="color: #e74c3c; white-space: pre-wrap; word-break: break-all;">{err}</p>
+                </div>
+            })}
+
+            {move || {
+                let msg = result_msg.get();
+                (!msg.is_empty()).then(|| view! {
+                    <div class="card"><p class="text-sm" style="color: var(--success, #4caf50); word-break: break-all;">{msg}</p></div>
+                })
+            }}
+
+            // Password confirmation for the selected action
+            {move || pending.get().map(|action| {
+                let needs_amount = action.needs_amount();
+                view! {
+                    <div class="card">
+                        <p class="text-sm mb-2" style="font-weight: bold;">{action.label()}</p>
+                        {needs_amount.then(|| view! {
+                            <label class="text-sm text-muted">{t("send.amount")}</label>
+                            <input
+                                type="text"
+                                placeholder="0.0"
+                                style=input_style
+                                prop:value=move || action_amount.get()
+                                on:input=move |ev| set_action_amount.set(event_target_value(&ev))
+                            />
+                        })}
+                        <input
+                            type="password"
+                            placeholder=t("confirm.password_placeholder")
+                            style=input_style
+                            prop:value=move || password.get()
+                            on:input=move |ev| set_password.set(event_target_value(&ev))
+                        />
+                        <div class="flex gap-2">
+                            <button class="btn btn-secondary flex-1" on:click=move |_| set_pending.set(None)>
+                                {t("common.cancel")}
+                            </button>
+                            <button class="btn btn-primary flex-1" disabled=move || working.get() on:click=confirm>
+                                {move || if working.get() { "...".to_string() } else { t("common.confirm") }}
+                            </button>
+                        </div>
+                    </div>
+                }
+            })}
+
+            // Stake accounts
+            <h3 class="text-sm text-muted" style="margin: 16px 0 8px;">{move || t("staking.accounts")}</h3>
+            {move || {
+                let list = accounts.get();
+                let Some(current) = epoch.get() else {
+                    return view! { <div class="card"><p class="text-sm text-muted">{t("staking.loading")}</p></div> }.into_any();
+                };
+                if list.is_empty() {
+                    return view! { <div class="card"><p class="text-sm text-muted">{t("staking.no_accounts")}</p></div> }.into_any();
+                }
+                let now = (js_sys::Date::now() / 1000.0) as i64;
+                list.iter().map(|account| {
+                    let address = account.address;
+                    let state = account.activation(current);
+                    let (label, color) = state_label(state);
+                    let withdrawable = account.withdrawable(current);
+                    let locked = account.is_locked(current, now);
+                    let merge_into = list.iter().find(|other| other.can_merge(account, current)).map(|o| o.address);
+                    let validator = account.delegation.as_ref().map(|d| short(&b58(&d.vote_account)));
+                    let can_deactivate = matches!(state, ActivationState::Activating | ActivationState::Active);
+                    let can_delegate = matches!(state, ActivationState::Undelegated | ActivationState::Inactive);
+                    let can_split = matches!(state, ActivationState::Active | ActivationState::Undelegated | ActivationState::Inactive);
+                    view! {
+                        <div class="card text-sm" style="margin-bottom: 8px;">
+                            <div class="flex justify-between">
+                                <span style="font-family: monospace;">{short(&b58(&address))}</span>
+                                <span style=format!("color: {}; font-weight: bold;", color)>{label}</span>
+                            </div>
+                            <div class="flex justify-between mt-2">
+                                <span class="text-muted">{t("send.balance")}</span>
+                                <span>{sol(account.lamports)}</span>
+                            </div>
+                            {validator.map(|v| view! {
+                                <div class="flex justify-between mt-2">
+                                    <span class="text-muted">{t("staking.validator")}</span>
+                                    <span style="font-family: monospace;">{v}</span>
+                                </div>
+                            })}
+                            {locked.then(|| view! {
+                                <p class="text-sm mt-2" style="color: var(--warning, #ff9800);">{t("staking.locked")}</p>
+                            })}
+                            <div style="display: flex; gap: 4px; flex-wrap: wrap; margin-top: 8px;">
+                                {can_deactivate.then(|| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| ask(Pending::Deactivate(address))>
+                                        {t("staking.deactivate")}
+                                    </button>
+                                })}
+                                {can_delegate.then(|| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| ask(Pending::Delegate(address))>
+                                        {t("staking.delegate")}
+                                    </button>
+                                })}
+                                {(withdrawable > 0 && !locked).then(|| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| ask(Pending::Withdraw { account: address, max: withdrawable })>
+                                        {t("staking.withdraw")}
+                                    </button>
+                                })}
+                                {can_split.then(|| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| ask(Pending::Split(address))>
+                                        {t("staking.split")}
+                                    </button>
+                                })}
+                                {merge_into.map(|destination| view! {
+                                    <button class="btn btn-sm btn-secondary" on:click=move |_| ask(Pending::Merge { destination, source: address })>
+                                        {t("staking.merge")}
+                                    </button>
+                                })}
+                            </div>
+                        </div>
+                    }
+                }).collect::<Vec<_>>().into_any()
+            }}
+
+            // New stake
+            <h3 class="text-sm text-muted" style="margin: 16px 0 8px;">{move || t("staking.new_stake")}</h3>
+            <div class="card">
+                <label class="text-sm text-muted">{move || t("send.amount")}</label>
+                <input
+                    type="text"
+                    placeholder="0.0"
+                    style=input_style
+                    prop:value=move || amount.get()
+                    on:input=move |ev| set_amount.set(event_target_value(&ev))
+                />
+                <p class="text-sm text-muted" style="margin-bottom: 8px;">{move || t("staking.rent_note")}</p>
+                <input
+                    type="text"
+                    placeholder=t("staking.search_validator")
+                    style=input_style
+                    prop:value=move || search.get()
+                    on:input=move |ev| set_search.set(event_target_value(&ev))
+                />
+                <div style="max-height: 240px; overflow-y: auto;">
+                    {move || {
+                        let query = search.get().trim().to_string();
+                        let list = validators.get();
+                        if list.is_empty() {
+                            return view! { <p class="text-sm text-muted">{t("staking.loading_validators")}</p> }.into_any();
+                        }
+                        list.into_iter()
+                            .filter(|v| if query.is_empty() { !v.delinquent } else { v.vote_account.contains(&query) || v.node.contains(&query) })
+                            .take(VALIDATORS_SHOWN)
+                            .map(|v| {
+                                let key = v.vote_account.clone();
+                                let key_sel = key.clone();
+                                let is_selected = move || vote_choice.get() == key_sel;
+                                view! {
+                                    <div
+                                        class="flex justify-between text-sm"
+                                        style=move || format!(
+                                            "padding: 6px 8px; border-radius: 6px; cursor: pointer; {}",
+                                            if is_selected() { "background: var(--bg-secondary); font-weight: bold;" } else { "" }
+                                        )
+                                        on:click=move |_| set_vote_choice.set(key.clone())
+                                    >
+                                        <span style="font-family: monospace;">{short(&v.vote_account)}</span>
+                                        <span class="text-muted">{format!("{}% · {}", v.commission, sol(v.activated_stake))}</span>
+                                        {v.delinquent.then(|| view! {
+                                            <span style="color: var(--danger, #f44336);">{t("staking.delinquent")}</span>
+                                        })}
+                                    </div>
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .into_any()
+                    }}
+                </div>
+                <button
+                    class="btn btn-primary btn-block mt-4"
+                    disabled=move || vote_choice.get().is_empty() || amount.get().trim().is_empty()
+                    on:click=move |_| ask(Pending::Stake)
+                >
+                    {move || t("staking.stake")}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -7,6 +7,9 @@ use leptos::prelude::*;
 use crate::state::*;
 use crate::i18n::t;
 use wallet_core::swap::{self, SwapQuote, SwapParams, evm_chain_id};
+use wallet_core::chains::custom_evm;
+use wallet_core::tx::evm_simulate::{SimCall, SimulationOutcome};
+use crate::components::asset_changes::AssetChangesCard;
 
 #[component]
 pub fn SwapPage() -> impl IntoView {
@@ -26,11 +29,12 @@ pub fn SwapPage() -> impl IntoView {
     let (show_password, set_show_password) = signal(false);
     let (password, set_password) = signal(String::new());
     let (executing, set_executing) = signal(false);
+    let (sim_outcome, set_sim_outcome) = signal::<Option<(SimulationOutcome, String)>>(None);
 
     let active_chain = move || wallet_state.with(|s| s.active_chain.clone());
 
     let is_evm = move || {
-        matches!(active_chain().as_str(), "ethereum" | "polygon" | "bsc" | "optimism" | "base" | "arbitrum")
+        crate::state::is_evm_chain(&active_chain())
     };
 
     let swap_tokens = move || {
@@ -54,11 +58,12 @@ pub fn SwapPage() -> impl IntoView {
         set_buy_token.set(sell);
         set_buy_amount.set(String::new());
         set_quote.set(None);
+        set_sim_outcome.set(None);
     };
 
     let on_get_quote = move |_| {
         let chain = active_chain();
-        let chain_numeric = match evm_chain_id(&chain) {
+        let chain_numeric = match evm_chain_id(&chain).or_else(|| custom_evm::parse_chain_key(&chain)) {
             Some(id) => id,
             None => {
                 set_status.set(t("swap.evm_only"));
@@ -127,7 +132,9 @@ pub fn SwapPage() -> impl IntoView {
         set_loading.set(true);
         set_status.set(t("swap.fetching_quote"));
         set_status_type.set("warning");
+        set_sim_outcome.set(None);
 
+        let testnet = testnet_mode.get();
         wasm_bindgen_futures::spawn_local(async move {
             match crate::rpc::swap::get_swap_price(&params, &api_key).await {
                 Ok(q) => {
@@ -141,6 +148,10 @@ pub fn SwapPage() -> impl IntoView {
                     set_buy_amount.set(formatted);
                     set_quote.set(Some(q));
                     set_status.set(String::new());
+                    set_loading.set(false);
+                    if let Some(sim) = simulate_swap(&chain, &params, &api_key, testnet).await {
+                        set_sim_outcome.set(Some(sim));
+                    }
                 }
                 Err(e) => {
                     set_status.set(format!("Quote error: {}", e));
@@ -161,7 +172,7 @@ pub fn SwapPage() -> impl IntoView {
 
     let on_execute_swap = move |_| {
         let chain = active_chain();
-        let chain_numeric = match evm_chain_id(&chain) {
+        let chain_numeric = match evm_chain_id(&chain).or_else(|| custom_evm::parse_chain_key(&chain)) {
             Some(id) => id,
             None => return,
         };
@@ -392,6 +403,14 @@ pub fn SwapPage() -> impl IntoView {
                             })
                         }}
 
+                        // Simulated balance changes of the firm quote
+                        {move || {
+                            let chain = active_chain();
+                            sim_outcome.get().map(|(outcome, ticker)| view! {
+                                <AssetChangesCard outcome=outcome chain=chain ticker=ticker />
+                            })
+                        }}
+
                         // Status
                         {move || {
                             let s = status.get();
@@ -481,6 +500,25 @@ pub fn SwapPage() -> impl IntoView {
     }
 }
 
+/// Fetch the firm quote for `params` and simulate it from the taker's account.
+/// Returns the outcome with the chain's ticker, or `None` if either step is unavailable.
+async fn simulate_swap(
+    chain: &str,
+    params: &SwapParams,
+    api_key: &str,
+    testnet: bool,
+) -> Option<(SimulationOutcome, String)> {
+    let (_quote, tx_data) = crate::rpc::swap::get_swap_quote(params, api_key).await.ok()?;
+    let call = crate::tx_send::evm::swap_call(&tx_data.to, &tx_data.value, &tx_data.data, tx_data.gas_limit).ok()?;
+    let from = wallet_core::tx::evm::parse_address(&params.taker_address).ok()?;
+    let config = crate::state::all_chains(testnet).into_iter()
+        .find(|c| crate::tx_send::chain_id_to_string(&c.id) == chain)?;
+    let rpc_url = config.rpc_urls.first()?;
+    let sim_call = SimCall { from, to: call.to, value: call.value, data: call.data };
+    let outcome = crate::rpc::simulate::simulate_asset_changes(rpc_url, &sim_call).await.ok()?;
+    Some((outcome, config.ticker))
+}
+
 /// Execute the swap transaction via existing EVM TX infrastructure
 async fn execute_swap_tx(
     chain: &str,
@@ -503,7 +541,7 @@ async fn execute_swap_tx(
     let mut seed = [0u8; 64];
     seed.copy_from_slice(&seed_bytes);
 
-    let chains = wallet_core::chains::get_chains(testnet);
+    let chains = crate::state::all_chains(testnet);
     let config = chains.iter()
         .find(|c| tx_send::chain_id_to_string(&c.id) == chain)
         .ok_or("Unknown chain")?;
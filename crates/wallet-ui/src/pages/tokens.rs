@@ -0,0 +1,190 @@
+// Rusby Wallet — Import custom tokens by contract or mint address
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::tokens::custom::{self, TokenKind};
+use wallet_core::tokens::Token;
+
+use crate::components::security_warning::{SecurityWarning, Severity};
+use crate::state::*;
+use crate::i18n::t;
+
+#[component]
+pub fn TokensPage() -> impl IntoView {
+    let wallet_state: ReadSignal<WalletState> = expect_context();
+    let set_wallet_state: WriteSignal<WalletState> = expect_context();
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let chain = wallet_state.with_untracked(|s| s.active_chain.clone());
+    let chain_name = chain_list().into_iter()
+        .find(|c| c.id == chain)
+        .map(|c| c.name)
+        .unwrap_or_else(|| chain.clone());
+    let kind = TokenKind::for_chain(&chain);
+
+    let (custom_list, set_custom_list) = signal(load_custom_tokens());
+    let (address, set_address) = signal(String::new());
+    let (preview, set_preview) = signal(Option::<Token>::None);
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal(String::new());
+
+    let chain_for_lookup = chain.clone();
+    let lookup = move |_| {
+        if loading.get_untracked() { return; }
+        let input = address.get_untracked().trim().to_string();
+        if input.is_empty() { return; }
+        if custom::is_default(&chain_for_lookup, &input) {
+            set_error.set(t("tokens.already_listed"));
+            return;
+        }
+        set_error.set(String::new());
+        set_preview.set(None);
+        set_loading.set(true);
+        let chain = chain_for_lookup.clone();
+        let testnet = testnet_mode.get_untracked();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = crate::rpc::fetch_token(&chain, &input, testnet).await;
+            set_loading.set(false);
+            match result {
+                Ok(token) => set_preview.set(Some(token)),
+                Err(e) => set_error.set(format!("{}: {}", t("tokens.lookup_failed"), e)),
+            }
+        });
+    };
+
+    // Dropping the cached balances makes the dashboard refetch with the new list
+    let chain_for_refresh = chain.clone();
+    let refresh_balances = move || {
+        let chain = chain_for_refresh.clone();
+        set_wallet_state.update(|s| { s.token_balances.remove(&chain); });
+    };
+
+    let refresh_on_add = refresh_balances.clone();
+    let add_token = move |_| {
+        let Some(token) = preview.get_untracked() else { return };
+        let mut list = custom_list.get_untracked();
+        custom::upsert_token(&mut list, token);
+        save_custom_tokens(&list);
+        set_custom_list.set(list);
+        set_preview.set(None);
+        set_address.set(String::new());
+        refresh_on_add();
+    };
+
+    let chain_for_remove = chain.clone();
+    let remove_token = move |token_address: String| {
+        let mut list = custom_list.get_untracked();
+        if custom::remove_token(&mut list, &chain_for_remove, &token_address) {
+            save_custom_tokens(&list);
+            set_custom_list.set(list);
+            refresh_balances();
+        }
+    };
+
+    let chain_for_list = chain.clone();
+
+    view! {
+        <div class="p-4">
+            <div class="flex items-center justify-between mb-4">
+                <button class="btn btn-sm btn-secondary" on:click=move |_| set_page.set(AppPage::Dashboard)>
+                    {move || t("common.back")}
+                </button>
+                <h2>{move || t("tokens.title")}</h2>
+                <div style="width: 60px;"></div>
+            </div>
+
+            {match kind {
+                None => view! { <p class="text-center text-muted">{t("tokens.unsupported_chain")}</p> }.into_any(),
+                Some(kind) => view! {
+                    <p class="text-sm text-muted mb-3">
+                        {format!("{} · {}", chain_name, kind.standard())}
+                    </p>
+
+                    <div class="card mb-3 p-3">
+                        <label class="text-sm text-muted">{t("tokens.address")}</label>
+                        <input
+                            type="text"
+                            class="input mb-2"
+                            placeholder=match kind {
+                                TokenKind::Erc20 => "0x...",
+                                TokenKind::Spl => "EPjFWdd5...",
+                                TokenKind::Cw20 => "cosmos1... / osmo1...",
+                                TokenKind::Jetton => "EQ...",
+                            }
+                            prop:value=move || address.get()
+                            on:input=move |ev| {
+                                set_address.set(event_target_value(&ev));
+                                set_preview.set(None);
+                            }
+                        />
+                        <button class="btn btn-secondary" style="width: 100%;" disabled=move || loading.get() on:click=lookup>
+                            {move || if loading.get() { t("tokens.looking_up") } else { t("tokens.lookup") }}
+                        </button>
+                        {move || {
+                            let e = error.get();
+                            (!e.is_empty()).then(|| view! { <p class="text-sm mt-2" style="color: var(--warning, #ff9800);">{e}</p> })
+                        }}
+                    </div>
+
+                    {move || preview.get().map(|token| view! {
+                        <SecurityWarning
+                            severity=Severity::Medium
+                            title=t("tokens.warning_title")
+                            message=t("tokens.warning")
+                            dismissable=false
+                        />
+                        <div class="card mb-3 p-3 text-sm">
+                            <div class="flex justify-between mb-2">
+                                <span class="text-muted">{t("tokens.symbol")}</span>
+                                <span style="font-weight: bold;">{token.symbol.clone()}</span>
+                            </div>
+                            <div class="flex justify-between mb-2">
+                                <span class="text-muted">{t("tokens.name")}</span>
+                                <span>{token.name.clone()}</span>
+                            </div>
+                            <div class="flex justify-between mb-2">
+                                <span class="text-muted">{t("tokens.decimals")}</span>
+                                <span>{token.decimals}</span>
+                            </div>
+                            <div class="text-muted mb-2" style="font-family: monospace; word-break: break-all;">{token.address.clone()}</div>
+                            <button class="btn btn-primary" style="width: 100%;" on:click=add_token.clone()>
+                                {t("tokens.add")}
+                            </button>
+                        </div>
+                    })}
+
+                    {move || {
+                        let list: Vec<Token> = custom_list.get().into_iter()
+                            .filter(|tk| tk.chain_id == chain_for_list)
+                            .collect();
+                        if list.is_empty() {
+                            return view! { <p class="text-center text-muted">{t("tokens.empty")}</p> }.into_any();
+                        }
+                        view! {
+                            <div class="chain-list">
+                                {list.into_iter().map(|token| {
+                                    let remove = remove_token.clone();
+                                    let token_address = token.address.clone();
+                                    view! {
+                                        <div class="chain-item">
+                                            <div class="chain-icon">{token.symbol.chars().next().unwrap_or('?').to_string()}</div>
+                                            <div class="chain-info" style="flex: 1; min-width: 0;">
+                                                <div class="chain-name">{token.symbol.clone()}</div>
+                                                <div class="text-sm text-muted" style="font-family: monospace; word-break: break-all;">{token.address.clone()}</div>
+                                            </div>
+                                            <button class="btn btn-sm btn-danger" on:click=move |_| remove(token_address.clone())>
+                                                {t("tokens.remove")}
+                                            </button>
+                                        </div>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        }.into_any()
+                    }}
+                }.into_any(),
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,426 @@
+// Rusby Wallet — TON Connect page: connect links, sessions and transaction requests
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use leptos::prelude::*;
+use wallet_core::tonconnect::{self, AppManifest, AppRequest, ConnectLink, TransactionRequest};
+use wallet_core::tonconnect::bridge::{BridgeClient, IncomingMessage, DEFAULT_BRIDGE_URL};
+use wallet_core::tonconnect::session::Session;
+use crate::state::*;
+use crate::i18n::t;
+use crate::logging::log_error;
+
+/// `sendTransaction` waiting for the user's decision
+#[derive(Debug, Clone)]
+struct PendingTransaction {
+    app_client_id: String,
+    app_name: String,
+    id: String,
+    request: TransactionRequest,
+}
+
+/// Encrypt `payload` for the session's dApp and post it to the bridge
+async fn reply(session: &TonConnectSession, payload: serde_json::Value) -> Result<(), String> {
+    let session = Session::restore(&session.secret, &session.app_client_id)?;
+    let message = BridgeClient::new(DEFAULT_BRIDGE_URL, None).outgoing(&session, &payload, None)?;
+    crate::rpc::ton_connect::post_message(&message).await
+}
+
+/// Send a wallet event and advance the session's event id
+async fn send_event(app_client_id: &str, event: impl FnOnce(u64) -> serde_json::Value) -> Result<(), String> {
+    let mut sessions = load_ton_connect_sessions();
+    let session = sessions.iter_mut().find(|s| s.app_client_id == app_client_id).ok_or("Unknown session")?;
+    let payload = event(session.next_event_id);
+    session.next_event_id += 1;
+    let session = session.clone();
+    save_ton_connect_sessions(&sessions);
+    reply(&session, payload).await
+}
+
+#[component]
+pub fn TonConnectPage() -> impl IntoView {
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let (link, set_link) = signal(String::new());
+    let (status, set_status) = signal(String::new());
+    let (password, set_password) = signal(String::new());
+    let (busy, set_busy) = signal(false);
+    let (sessions, set_sessions) = signal(load_ton_connect_sessions());
+    let (proposal, set_proposal) = signal::<Option<(ConnectLink, AppManifest)>>(None);
+    let (pending, set_pending) = signal::<Vec<PendingTransaction>>(vec![]);
+
+    // Bridge listener: runs while the page is open and restarts with the
+    // current session list whenever `generation` changes
+    let stopped = Arc::new(AtomicBool::new(false));
+    let generation = Arc::new(AtomicU64::new(0));
+    {
+        let stopped = stopped.clone();
+        let generation = generation.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let mut client = BridgeClient::new(DEFAULT_BRIDGE_URL, load_from_storage("ton_connect_last_event_id"));
+            while !stopped.load(Ordering::Relaxed) {
+                let current = load_ton_connect_sessions();
+                if current.is_empty() {
+                    gloo_timers::future::TimeoutFuture::new(2_000).await;
+                    continue;
+                }
+                let client_ids: Vec<String> = current.iter()
+                    .filter_map(|s| Session::restore(&s.secret, &s.app_client_id).ok())
+                    .map(|s| s.client_id())
+                    .collect();
+                let listening = generation.load(Ordering::Relaxed);
+                let keep_running = || !stopped.load(Ordering::Relaxed) && generation.load(Ordering::Relaxed) == listening;
+                let on_message = |message: IncomingMessage| handle_message(message, set_pending, set_sessions);
+                crate::rpc::ton_connect::listen(&mut client, &client_ids, keep_running, on_message).await;
+                if let Some(id) = client.last_event_id() {
+                    save_to_storage("ton_connect_last_event_id", id);
+                }
+            }
+        });
+    }
+    on_cleanup(move || stopped.store(true, Ordering::Relaxed));
+
+    let open_link = move |_| {
+        let parsed = match tonconnect::parse_connect_link(&link.get_untracked()) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                set_status.set(format!("{} {}", t("tc.invalid_link"), e));
+                return;
+            }
+        };
+        set_busy.set(true);
+        set_status.set(t("tc.loading_manifest"));
+        wasm_bindgen_futures::spawn_local(async move {
+            match crate::rpc::ton_connect::fetch_manifest(&parsed.request.manifest_url).await {
+                Ok(manifest) => {
+                    set_proposal.set(Some((parsed, manifest)));
+                    set_status.set(String::new());
+                    set_link.set(String::new());
+                }
+                Err(e) => set_status.set(format!("{} {}", t("tc.manifest_error"), e)),
+            }
+            set_busy.set(false);
+        });
+    };
+
+    let approve_connect = {
+        let generation = generation.clone();
+        move |_| {
+            let Some((parsed, manifest)) = proposal.get_untracked() else { return };
+            let testnet = testnet_mode.get_untracked();
+            let items = match crate::tx_send::execute_ton_connect(&parsed.request, &manifest, &password.get_untracked(), testnet) {
+                Ok(items) => items,
+                Err(e) => {
+                    set_status.set(format!("{} {}", t("tc.error"), e));
+                    return;
+                }
+            };
+            set_password.set(String::new());
+            let session = match Session::new(&parsed.client_id) {
+                Ok(session) => session,
+                Err(e) => {
+                    set_status.set(format!("{} {}", t("tc.error"), e));
+                    return;
+                }
+            };
+            let stored = TonConnectSession {
+                app_client_id: parsed.client_id.clone(),
+                secret: session.secret_hex(),
+                name: manifest.name.clone(),
+                url: manifest.url.clone(),
+                icon_url: manifest.icon_url.clone(),
+                next_event_id: 1,
+            };
+            let mut all = load_ton_connect_sessions();
+            all.retain(|s| s.app_client_id != stored.app_client_id);
+            all.push(stored.clone());
+            save_ton_connect_sessions(&all);
+            set_sessions.set(all);
+            set_proposal.set(None);
+            generation.fetch_add(1, Ordering::Relaxed);
+
+            set_busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let event = tonconnect::connect_event(0, items, tonconnect::device_info(env!("CARGO_PKG_VERSION")));
+                match reply(&stored, event).await {
+                    Ok(()) => set_status.set(format!("{} {}", t("tc.connected"), stored.name)),
+                    Err(e) => set_status.set(format!("{} {}", t("tc.error"), e)),
+                }
+                set_busy.set(false);
+            });
+        }
+    };
+
+    let reject_connect = move |_| {
+        let Some((parsed, _)) = proposal.get_untracked() else { return };
+        set_proposal.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            let Ok(session) = Session::new(&parsed.client_id) else { return };
+            let event = tonconnect::connect_error_event(0, tonconnect::ERROR_USER_DECLINED, "User declined the connection");
+            if let Ok(message) = BridgeClient::new(DEFAULT_BRIDGE_URL, None).outgoing(&session, &event, None) {
+                let _ = crate::rpc::ton_connect::post_message(&message).await;
+            }
+        });
+    };
+
+    let disconnect = {
+        let generation = generation.clone();
+        move |app_client_id: String| {
+            generation.fetch_add(1, Ordering::Relaxed);
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Err(e) = send_event(&app_client_id, tonconnect::disconnect_event).await {
+                    log_error!("TON Connect disconnect: {}", e);
+                }
+                let mut all = load_ton_connect_sessions();
+                all.retain(|s| s.app_client_id != app_client_id);
+                save_ton_connect_sessions(&all);
+                set_sessions.set(all);
+                set_pending.update(|p| p.retain(|r| r.app_client_id != app_client_id));
+            });
+        }
+    };
+
+    let decide = move |request: PendingTransaction, approve: bool| {
+        let pass = password.get_untracked();
+        let testnet = testnet_mode.get_untracked();
+        set_busy.set(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            let now = (js_sys::Date::now() / 1000.0) as u64;
+            let response = if approve && request.request.is_expired(now) {
+                set_status.set(format!("{} {}", t("tc.error"), "Transaction request expired"));
+                tonconnect::response_error(&request.id, tonconnect::ERROR_BAD_REQUEST, "Request expired")
+            } else if approve {
+                match crate::tx_send::execute_ton_connect_transaction(&request.request, &pass, testnet).await {
+                    Ok(boc) => {
+                        set_status.set(t("tc.tx_sent"));
+                        tonconnect::response_result(&request.id, serde_json::Value::String(boc))
+                    }
+                    Err(e) => {
+                        set_status.set(format!("{} {}", t("tc.error"), e));
+                        set_busy.set(false);
+                        return;
+                    }
+                }
+            } else {
+                tonconnect::response_error(&request.id, tonconnect::ERROR_USER_DECLINED, "User declined the transaction")
+            };
+            set_password.set(String::new());
+            set_pending.update(|p| p.retain(|r| !(r.app_client_id == request.app_client_id && r.id == request.id)));
+            if let Some(session) = load_ton_connect_sessions().into_iter().find(|s| s.app_client_id == request.app_client_id) {
+                if let Err(e) = reply(&session, response).await {
+                    set_status.set(format!("{} {}", t("tc.error"), e));
+                }
+            }
+            set_busy.set(false);
+        });
+    };
+
+    let password_input = move || view! {
+        <input
+            type="password"
+            placeholder=t("tc.password")
+            style="width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border); margin-top: 8px;"
+            prop:value=move || password.get()
+            on:input=move |ev| set_password.set(event_target_value(&ev))
+        />
+    };
+
+    view! {
+        <div>
+            <div style="display: flex; align-items: center; gap: 8px; margin-bottom: 16px;">
+                <button class="btn btn-secondary" style="padding: 4px 8px;" on:click=move |_| set_page.set(AppPage::Settings)>
+                    {move || t("tc.back")}
+                </button>
+                <h2>{move || t("tc.title")}</h2>
+            </div>
+
+            // Connect link
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("tc.connect_dapp")}</p>
+                <input
+                    type="text"
+                    placeholder="tc://?v=2&id=..."
+                    style="width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border);"
+                    prop:value=move || link.get()
+                    on:input=move |ev| set_link.set(event_target_value(&ev))
+                />
+                <button class="btn btn-primary btn-block" style="margin-top: 8px;" on:click=open_link disabled=move || busy.get()>
+                    {move || t("tc.open_link")}
+                </button>
+            </div>
+
+            // Connection proposal
+            {move || proposal.get().map(|(parsed, manifest)| {
+                let approve_connect = approve_connect.clone();
+                view! {
+                    <div class="card">
+                        <div style="display: flex; align-items: center; gap: 8px;">
+                            <img src=manifest.icon_url.clone() alt="" style="width: 32px; height: 32px; border-radius: 8px;" />
+                            <div>
+                                <p style="font-weight: bold;">{manifest.name.clone()}</p>
+                                <p class="text-sm text-muted" style="word-break: break-all;">{manifest.url.clone()}</p>
+                            </div>
+                        </div>
+                        <p class="text-sm" style="margin-top: 8px;">{t("tc.connect_request")}</p>
+                        {parsed.request.proof_payload().map(|_| view! {
+                            <p class="text-sm text-muted">{format!("{} {}", t("tc.proof_requested"), manifest.domain())}</p>
+                        })}
+                        {password_input()}
+                        <div style="display: flex; gap: 8px; margin-top: 8px;">
+                            <button class="btn btn-secondary" style="flex: 1;" on:click=reject_connect>{t("tc.reject")}</button>
+                            <button class="btn btn-primary" style="flex: 1;" on:click=approve_connect disabled=move || busy.get()>{t("tc.approve")}</button>
+                        </div>
+                    </div>
+                }
+            })}
+
+            // Status
+            {move || {
+                let s = status.get();
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(view! { <p class="text-sm" style="padding: 8px; text-align: center;">{s}</p> })
+                }
+            }}
+
+            // Transaction requests
+            {move || {
+                let list = pending.get();
+                if list.is_empty() {
+                    return None;
+                }
+                Some(view! {
+                    <div class="card">
+                        <p class="text-sm text-muted mb-2">{t("tc.tx_requests")}</p>
+                        {list.into_iter().map(|request| {
+                            let rejected = request.clone();
+                            let approved = request.clone();
+                            view! {
+                                <div style="padding: 8px 0; border-bottom: 1px solid var(--border);">
+                                    <p style="font-weight: bold; font-size: 0.9rem;">{request.app_name.clone()}</p>
+                                    {request.request.messages.iter().map(|m| {
+                                        let extras = [
+                                            m.payload.as_ref().map(|_| t("tc.payload")),
+                                            m.state_init.as_ref().map(|_| t("tc.state_init")),
+                                        ].into_iter().flatten().collect::<Vec<_>>().join(", ");
+                                        view! {
+                                            <p class="text-sm" style="word-break: break-all;">
+                                                {format!("{} TON → {}", crate::rpc::ton::format_nanoton(m.amount_nanoton), m.destination.to_friendly())}
+                                            </p>
+                                            {(!extras.is_empty()).then(|| view! { <p class="text-sm text-muted">{extras}</p> })}
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                    {password_input()}
+                                    <div style="display: flex; gap: 8px; margin-top: 8px;">
+                                        <button class="btn btn-secondary" style="flex: 1;" on:click=move |_| decide(rejected.clone(), false)>{t("tc.reject")}</button>
+                                        <button class="btn btn-primary" style="flex: 1;" on:click=move |_| decide(approved.clone(), true) disabled=move || busy.get()>{t("tc.approve")}</button>
+                                    </div>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                })
+            }}
+
+            // Sessions
+            <div class="card">
+                <p class="text-sm text-muted mb-2">{move || t("tc.sessions")}</p>
+                {move || {
+                    let list = sessions.get();
+                    if list.is_empty() {
+                        return view! { <p class="text-sm">{t("tc.no_sessions")}</p> }.into_any();
+                    }
+                    let disconnect = disconnect.clone();
+                    view! {
+                        <div>
+                            {list.into_iter().map(|session| {
+                                let disconnect = disconnect.clone();
+                                let app_client_id = session.app_client_id.clone();
+                                view! {
+                                    <div style="display: flex; justify-content: space-between; align-items: center; padding: 8px 0; border-bottom: 1px solid var(--border);">
+                                        <div>
+                                            <p style="font-weight: bold; font-size: 0.9rem;">{session.name.clone()}</p>
+                                            <p class="text-sm text-muted" style="word-break: break-all;">{session.url.clone()}</p>
+                                        </div>
+                                        <button
+                                            class="btn btn-secondary"
+                                            style="font-size: 0.7rem; padding: 2px 8px; min-width: auto;"
+                                            on:click=move |_| disconnect(app_client_id.clone())
+                                        >
+                                            {t("tc.disconnect")}
+                                        </button>
+                                    </div>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+                    }.into_any()
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Route a bridge message: queue transactions for approval, end sessions on
+/// `disconnect` and answer malformed requests and unknown methods with a
+/// bad-request error
+fn handle_message(
+    message: IncomingMessage,
+    set_pending: WriteSignal<Vec<PendingTransaction>>,
+    set_sessions: WriteSignal<Vec<TonConnectSession>>,
+) {
+    let Some(session) = load_ton_connect_sessions().into_iter().find(|s| s.app_client_id == message.from) else {
+        return;
+    };
+    let value = match Session::restore(&session.secret, &session.app_client_id).and_then(|s| message.open(&s)) {
+        Ok(value) => value,
+        Err(e) => {
+            log_error!("TON Connect message from {}: {}", session.name, e);
+            return;
+        }
+    };
+    let request = match tonconnect::parse_app_request(&value) {
+        Ok(request) => request,
+        Err(e) => {
+            log_error!("TON Connect request from {}: {}", session.name, e);
+            // Answer requests we can still identify so the dApp does not wait forever
+            if let Some(id) = tonconnect::request_id(&value) {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let _ = reply(&session, tonconnect::response_error(&id, tonconnect::ERROR_BAD_REQUEST, &e)).await;
+                });
+            }
+            return;
+        }
+    };
+    match request {
+        AppRequest::SendTransaction { id, request } => {
+            set_pending.update(|p| p.push(PendingTransaction {
+                app_client_id: session.app_client_id.clone(),
+                app_name: session.name.clone(),
+                id,
+                request,
+            }));
+        }
+        AppRequest::Disconnect { id } => {
+            let mut all = load_ton_connect_sessions();
+            all.retain(|s| s.app_client_id != session.app_client_id);
+            save_ton_connect_sessions(&all);
+            set_sessions.set(all);
+            set_pending.update(|p| p.retain(|r| r.app_client_id != session.app_client_id));
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = reply(&session, tonconnect::response_result(&id, serde_json::json!({}))).await;
+            });
+        }
+        AppRequest::Unsupported { id, method } => {
+            log_error!("TON Connect: unsupported method {}", method);
+            wasm_bindgen_futures::spawn_local(async move {
+                let response = tonconnect::response_error(&id, tonconnect::ERROR_METHOD_NOT_SUPPORTED, &format!("Method not supported: {}", method));
+                let _ = reply(&session, response).await;
+            });
+        }
+    }
+}
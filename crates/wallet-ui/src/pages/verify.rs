@@ -0,0 +1,156 @@
+// Rusby Wallet — Signature verification tool (EOA, ERC-1271, ERC-6492)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use leptos::prelude::*;
+use wallet_core::chains::evm::eip55_checksum;
+use wallet_core::signing::verify::{self, MessageKind, Verification};
+use wallet_core::tx::evm::parse_address;
+
+use crate::state::*;
+use crate::i18n::t;
+
+#[component]
+pub fn VerifyPage() -> impl IntoView {
+    let set_page: WriteSignal<AppPage> = expect_context();
+    let testnet_mode: ReadSignal<bool> = expect_context();
+
+    let (chain, set_chain) = signal("ethereum".to_string());
+    let (signer, set_signer) = signal(String::new());
+    let (kind, set_kind) = signal("personal".to_string());
+    let (message, set_message) = signal(String::new());
+    let (signature, set_signature) = signal(String::new());
+    let (checking, set_checking) = signal(false);
+    let (result, set_result) = signal(Option::<Result<Verification, String>>::None);
+
+    let evm_chains = move || all_chains(testnet_mode.get()).into_iter()
+        .filter(|c| c.evm_chain_id.is_some())
+        .collect::<Vec<_>>();
+
+    let check = move |_| {
+        if checking.get_untracked() { return; }
+        let kind = match kind.get_untracked().as_str() {
+            "typed" => MessageKind::TypedData,
+            "hash" => MessageKind::Hash,
+            _ => MessageKind::Personal,
+        };
+        let inputs = parse_address(signer.get_untracked().trim())
+            .and_then(|addr| Ok((addr, verify::message_hash(kind, &message.get_untracked())?)))
+            .and_then(|(addr, hash)| Ok((addr, hash, verify::parse_signature_hex(&signature.get_untracked())?)));
+        let (address, hash, sig) = match inputs {
+            Ok(v) => v,
+            Err(e) => {
+                set_result.set(Some(Err(e)));
+                return;
+            }
+        };
+        let rpc_url = all_chains(testnet_mode.get_untracked()).into_iter()
+            .find(|c| crate::rpc::chain_id_str(&c.id) == chain.get_untracked())
+            .and_then(|c| c.rpc_urls.first().cloned());
+        let Some(rpc_url) = rpc_url else {
+            set_result.set(Some(Err(t("verify.no_rpc"))));
+            return;
+        };
+        set_checking.set(true);
+        set_result.set(None);
+        wasm_bindgen_futures::spawn_local(async move {
+            let outcome = crate::rpc::verify::verify_signature(&rpc_url, &address, &hash, &sig).await;
+            set_result.set(Some(outcome));
+            set_checking.set(false);
+        });
+    };
+
+    let input_style = "width: 100%; padding: 8px; border-radius: 8px; background: var(--bg-secondary); color: var(--text-primary); border: 1px solid var(--border); margin-bottom: 8px;";
+
+    view! {
+        <div class="p-4">
+            <div class="flex items-center justify-between mb-4">
+                <button class="btn btn-sm btn-secondary" on:click=move |_| set_page.set(AppPage::Settings)>
+                    {move || t("common.back")}
+                </button>
+                <h2>{move || t("verify.title")}</h2>
+                <div style="width: 60px;"></div>
+            </div>
+            <p class="text-sm text-muted mb-3">{move || t("verify.hint")}</p>
+
+            <div class="card mb-3 p-3">
+                <label class="text-sm text-muted">{move || t("verify.network")}</label>
+                <select style=input_style
+                    prop:value=move || chain.get()
+                    on:change=move |ev| set_chain.set(event_target_value(&ev))
+                >
+                    {move || evm_chains().into_iter().map(|c| {
+                        let key = crate::rpc::chain_id_str(&c.id);
+                        view! { <option value=key>{c.name}</option> }
+                    }).collect::<Vec<_>>()}
+                </select>
+
+                <label class="text-sm text-muted">{move || t("verify.signer")}</label>
+                <input type="text" placeholder="0x..." style=input_style
+                    prop:value=move || signer.get()
+                    on:input=move |ev| set_signer.set(event_target_value(&ev))
+                />
+
+                <label class="text-sm text-muted">{move || t("verify.message_type")}</label>
+                <select style=input_style
+                    prop:value=move || kind.get()
+                    on:change=move |ev| set_kind.set(event_target_value(&ev))
+                >
+                    <option value="personal">{move || t("verify.kind_personal")}</option>
+                    <option value="typed">{move || t("verify.kind_typed")}</option>
+                    <option value="hash">{move || t("verify.kind_hash")}</option>
+                </select>
+
+                <label class="text-sm text-muted">{move || t("verify.message")}</label>
+                <textarea rows="5" style=format!("{} font-family: monospace; font-size: 12px; resize: vertical;", input_style)
+                    placeholder=move || match kind.get().as_str() {
+                        "typed" => "{\"types\": ..., \"domain\": ..., \"primaryType\": ..., \"message\": ...}",
+                        "hash" => "0x...",
+                        _ => "Hello!",
+                    }
+                    prop:value=move || message.get()
+                    on:input=move |ev| set_message.set(event_target_value(&ev))
+                ></textarea>
+
+                <label class="text-sm text-muted">{move || t("verify.signature")}</label>
+                <textarea rows="3" style=format!("{} font-family: monospace; font-size: 12px; resize: vertical;", input_style)
+                    placeholder="0x..."
+                    prop:value=move || signature.get()
+                    on:input=move |ev| set_signature.set(event_target_value(&ev))
+                ></textarea>
+
+                <button class="btn btn-primary" style="width: 100%;" disabled=move || checking.get() on:click=check>
+                    {move || if checking.get() { t("verify.checking") } else { t("verify.check") }}
+                </button>
+            </div>
+
+            {move || result.get().map(|outcome| match outcome {
+                Err(e) => view! {
+                    <div class="card p-3 text-sm" style="color: var(--warning, #ff9800);">{e}</div>
+                }.into_any(),
+                Ok(v) => {
+                    let (label, color) = if v.valid {
+                        (t("verify.valid"), "var(--success, #4caf50)")
+                    } else {
+                        (t("verify.invalid"), "var(--danger, #f44336)")
+                    };
+                    view! {
+                        <div class="card p-3 text-sm">
+                            <p class="mb-2" style=format!("font-size: 1.1rem; font-weight: bold; color: {};", color)>{label}</p>
+                            <div class="flex justify-between mb-2" style="gap: 8px;">
+                                <span class="text-muted">{t("verify.method")}</span>
+                                <span>{v.method.label()}</span>
+                            </div>
+                            {(!v.valid).then(|| v.recovered.map(|addr| view! {
+                                <div class="flex justify-between" style="gap: 8px;">
+                                    <span class="text-muted">{t("verify.recovered")}</span>
+                                    <span style="font-family: monospace; word-break: break-all; text-align: right;">{eip55_checksum(&addr)}</span>
+                                </div>
+                            }))}
+                        </div>
+                    }.into_any()
+                }
+            })}
+        </div>
+    }
+}
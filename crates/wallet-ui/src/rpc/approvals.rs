@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::post_json;
+use super::multicall::{batch_call, Read};
+use wallet_core::tokens::approvals::{self, Allowance, ApprovalCandidate, ApprovalKind, ScanState};
 use wallet_core::tokens::erc20;
 
 /// Known spender contracts (DEX routers, etc.)
@@ -17,6 +19,7 @@ pub fn known_spenders() -> Vec<KnownSpender> {
         // Ethereum
         KnownSpender { address: "0x68b3465833fb72A70ecDF485E0e4C7bD8665Fc45".into(), name: "Uniswap V3 Router".into(), chain: "ethereum".into() },
         KnownSpender { address: "0xEf1c6E67703c7BD7107eed8303Fbe6EC2554BF6B".into(), name: "Uniswap Universal Router".into(), chain: "ethereum".into() },
+        KnownSpender { address: "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD".into(), name: "Uniswap Universal Router".into(), chain: "ethereum".into() },
         KnownSpender { address: "0x1111111254EEB25477B68fb85Ed929f73A960582".into(), name: "1inch V5 Router".into(), chain: "ethereum".into() },
         KnownSpender { address: "0xDef1C0ded9bec7F1a1670819833240f027b25EfF".into(), name: "0x Exchange Proxy".into(), chain: "ethereum".into() },
         KnownSpender { address: "0xd9e1cE17f2641f24aE83637ab66a2cca9C378B9F".into(), name: "SushiSwap Router".into(), chain: "ethereum".into() },
@@ -33,74 +36,145 @@ pub fn known_spenders() -> Vec<KnownSpender> {
     ]
 }
 
-/// Approval info for display
-#[derive(Debug, Clone)]
-pub struct ApprovalInfo {
-    pub token_address: String,
-    pub token_symbol: String,
-    pub spender_address: String,
-    pub spender_name: String,
-    pub allowance: String,
-}
-
-/// Get token allowance for a specific owner/spender pair
-pub async fn get_token_allowance(
-    rpc_url: &str,
-    token_address: &str,
-    owner: &str,
-    spender: &str,
-) -> Result<String, String> {
-    let calldata = erc20::encode_allowance(owner, spender)?;
-    let hex_data = format!("0x{}", hex::encode(&calldata));
+/// Contracts deployed at the same address on every chain
+const CROSS_CHAIN_SPENDERS: &[(&str, &str)] = &[
+    (wallet_core::security::permit::PERMIT2_ADDRESS, "Uniswap Permit2"),
+    ("0x1E0049783F008A0085193E00003D00cd54003c71", "OpenSea Conduit"),
+    ("0x00000000000000ADc04C56Bf30aC9d3c0aAF14dC", "Seaport 1.5"),
+    ("0x0000000000000068F116a894984e2DB1123eB395", "Seaport 1.6"),
+];
 
-    let body = format!(
-        r#"{{"jsonrpc":"2.0","method":"eth_call","params":[{{"to":"{}","data":"{}"}},"latest"],"id":1}}"#,
-        token_address, hex_data
-    );
+/// Name of a known spender/operator on `chain`
+pub fn spender_label(chain: &str, address: &str) -> Option<String> {
+    known_spenders().into_iter()
+        .find(|k| k.chain == chain && k.address.eq_ignore_ascii_case(address))
+        .map(|k| k.name)
+        .or_else(|| CROSS_CHAIN_SPENDERS.iter()
+            .find(|(a, _)| a.eq_ignore_ascii_case(address))
+            .map(|(_, name)| name.to_string()))
+}
 
-    let json = post_json(rpc_url, &body).await?;
+/// Active approval for display
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApprovalInfo {
+    pub contract: String,
+    pub symbol: String,
+    /// Known for ERC-20 tokens in the token list or with a decimals() getter
+    pub decimals: Option<u8>,
+    pub spender: String,
+    pub spender_name: Option<String>,
+    pub kind: ApprovalKind,
+    pub allowance: Allowance,
+}
 
-    if let Some(result) = json.get("result").and_then(|r| r.as_str()) {
-        Ok(result.to_string())
-    } else {
-        Err("Failed to get allowance".into())
+/// Query Approval / ApprovalForAll logs of `owner` for up to `max_requests` block ranges,
+/// shrinking the range whenever the provider refuses it. Returns the latest block.
+pub async fn scan_logs(rpc_url: &str, owner: &str, state: &mut ScanState, max_requests: usize) -> Result<u64, String> {
+    let latest = super::evm::get_block_number(rpc_url).await?;
+    for _ in 0..max_requests {
+        let Some(range) = state.next_range(latest) else { break };
+        let body = approvals::get_logs_body(owner, range.0, range.1)?;
+        let json = post_json(rpc_url, &body.to_string()).await;
+        let outcome = match &json {
+            Ok(j) => match (j.get("result"), j.get("error")) {
+                (Some(result), _) => Ok(result),
+                (None, Some(err)) => Err(err.to_string()),
+                (None, None) => Err("Missing result in eth_getLogs".into()),
+            },
+            Err(e) => Err(e.clone()),
+        };
+        match outcome {
+            Ok(result) => {
+                let logs = approvals::parse_logs(result)?;
+                state.record(range, &logs);
+            }
+            Err(e) if approvals::is_range_error(&e) && state.shrink() => continue,
+            Err(e) => return Err(e),
+        }
     }
+    Ok(latest)
 }
 
-/// Check all known spenders for a token and return active approvals
-pub async fn check_approvals_for_token(
+/// Read the current state of each candidate and keep the ones still active.
+/// Allowances, operator flags and missing metadata are each read in one batch.
+pub async fn active_approvals(
     rpc_url: &str,
-    token_address: &str,
-    token_symbol: &str,
     owner: &str,
     chain: &str,
+    candidates: &[ApprovalCandidate],
 ) -> Vec<ApprovalInfo> {
-    let spenders: Vec<_> = known_spenders().into_iter()
-        .filter(|s| s.chain == chain)
+    let mut reads: Vec<Read> = Vec::new();
+    let mut readable: Vec<&ApprovalCandidate> = Vec::new();
+    for candidate in candidates {
+        let data = match candidate.kind {
+            ApprovalKind::Allowance => erc20::encode_allowance(owner, &candidate.spender),
+            ApprovalKind::Operator => approvals::encode_is_approved_for_all(owner, &candidate.spender),
+        };
+        if let Ok(data) = data {
+            reads.push((candidate.contract.clone(), data));
+            readable.push(candidate);
+        }
+    }
+    let current = batch_call(&reads, rpc_url).await;
+    let live: Vec<(&ApprovalCandidate, Allowance)> = readable.into_iter().zip(current)
+        .filter_map(|(c, r)| r.ok().and_then(|hex| approvals::decode_allowance(&hex)).map(|a| (c, a)))
         .collect();
 
-    let mut approvals = Vec::new();
+    // Metadata for contracts outside the token list, each contract once
+    let tokens = crate::state::tokens_for_chain(chain);
+    let mut unknown_tokens: Vec<String> = Vec::new();
+    let mut unknown_collections: Vec<String> = Vec::new();
+    for (c, _) in &live {
+        let list = match c.kind {
+            ApprovalKind::Allowance => &mut unknown_tokens,
+            ApprovalKind::Operator => &mut unknown_collections,
+        };
+        let known = tokens.iter().any(|t| t.address.eq_ignore_ascii_case(&c.contract));
+        if !known && !list.iter().any(|a| a.eq_ignore_ascii_case(&c.contract)) {
+            list.push(c.contract.clone());
+        }
+    }
+    let token_meta = super::erc20::get_tokens_metadata(&unknown_tokens, rpc_url).await;
+    // Collections: symbol() is optional, decimals meaningless
+    let symbol_reads: Vec<Read> = unknown_collections.iter().map(|a| (a.clone(), erc20::encode_symbol())).collect();
+    let collection_symbols = batch_call(&symbol_reads, rpc_url).await;
 
-    for spender in spenders {
-        if let Ok(allowance_hex) = get_token_allowance(rpc_url, token_address, owner, &spender.address).await {
-            let hex = allowance_hex.trim_start_matches("0x");
-            let value = u128::from_str_radix(hex, 16).unwrap_or(0);
-            if value > 0 {
-                let allowance_display = if value >= u128::MAX / 2 {
-                    "Unlimited".to_string()
-                } else {
-                    format!("{}", value)
-                };
-                approvals.push(ApprovalInfo {
-                    token_address: token_address.to_string(),
-                    token_symbol: token_symbol.to_string(),
-                    spender_address: spender.address,
-                    spender_name: spender.name,
-                    allowance: allowance_display,
-                });
+    live.into_iter().map(|(candidate, allowance)| {
+        let known = tokens.iter().find(|t| t.address.eq_ignore_ascii_case(&candidate.contract));
+        let (symbol, decimals) = match (known, candidate.kind) {
+            (Some(t), _) => (t.symbol.clone(), Some(t.decimals)),
+            (None, ApprovalKind::Allowance) => unknown_tokens.iter()
+                .position(|a| a.eq_ignore_ascii_case(&candidate.contract))
+                .and_then(|i| token_meta[i].as_ref().ok())
+                .map(|meta| (meta.symbol.clone(), Some(meta.decimals)))
+                .unwrap_or_else(|| (short(&candidate.contract), None)),
+            (None, ApprovalKind::Operator) => {
+                let symbol = unknown_collections.iter()
+                    .position(|a| a.eq_ignore_ascii_case(&candidate.contract))
+                    .and_then(|i| collection_symbols[i].as_ref().ok())
+                    .and_then(|hex| erc20::decode_string_result(hex).ok())
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or_else(|| short(&candidate.contract));
+                (symbol, None)
             }
+        };
+
+        ApprovalInfo {
+            contract: candidate.contract.clone(),
+            symbol,
+            decimals,
+            spender: candidate.spender.clone(),
+            spender_name: spender_label(chain, &candidate.spender),
+            kind: candidate.kind,
+            allowance: if candidate.kind == ApprovalKind::Operator { Allowance::Unlimited } else { allowance },
         }
-    }
+    }).collect()
+}
 
-    approvals
+fn short(addr: &str) -> String {
+    if addr.len() > 14 {
+        format!("{}...{}", &addr[..8], &addr[addr.len() - 4..])
+    } else {
+        addr.to_string()
+    }
 }
@@ -123,8 +123,7 @@ pub async fn get_all_balances(address: &str, rpc_url: &str, chain_id: &str) -> R
         }
 
         // IBC tokens: show truncated denom hash
-        let symbol = if denom.starts_with("ibc/") {
-            let hash = &denom[4..];
+        let symbol = if let Some(hash) = denom.strip_prefix("ibc/") {
             format!("IBC/{}", &hash[..6.min(hash.len())])
         } else {
             denom.to_string()
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::get_json;
-use wallet_core::tokens::{Token, TokenBalance};
+use wallet_core::tokens::{Token, TokenBalance, TokenMetadata};
 use wallet_core::tokens::cw20;
 
 /// Get CW-20 token balance via CosmWasm smart query
@@ -23,10 +23,20 @@ pub async fn get_token_balance(owner: &str, token: &Token, rest_url: &str) -> Re
     Ok(cw20::format_token_amount(raw, token.decimals))
 }
 
+/// Query `token_info` on a CW-20 contract
+pub async fn get_token_metadata(contract: &str, rest_url: &str) -> Result<TokenMetadata, String> {
+    let url = format!(
+        "{}/cosmwasm/wasm/v1/contract/{}/smart/{}",
+        rest_url, contract, cw20::encode_token_info_query()
+    );
+    let json = get_json(&url).await?;
+    cw20::parse_token_info(&json["data"])
+}
+
 /// Get all CW-20 token balances for a chain
 pub async fn get_all_token_balances(owner: &str, chain_id: &str, rpc_url: &str) -> Vec<TokenBalance> {
     let rest_url = super::cosmos::rpc_url_to_rest(rpc_url);
-    let tokens = cw20::tokens_for_chain(chain_id);
+    let tokens = crate::state::tokens_for_chain(chain_id);
     let mut result = Vec::new();
 
     for token in tokens {
@@ -0,0 +1,82 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// rpc/ens: ENS forward resolution and verified reverse (primary name) lookup via eth_call
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use wallet_core::chains::ChainId;
+use wallet_core::ens;
+
+use super::evm::eth_call;
+
+thread_local! {
+    /// Lowercased address -> verified primary name (None = no name), per session
+    static PRIMARY_NAMES: RefCell<HashMap<String, Option<String>>> = RefCell::new(HashMap::new());
+}
+
+/// RPC of the Ethereum network holding the ENS registry (Sepolia in testnet mode)
+pub fn ens_rpc_url(testnet: bool) -> Option<String> {
+    wallet_core::chains::get_chains(testnet).into_iter()
+        .find(|c| c.id == ChainId::Ethereum)
+        .and_then(|c| c.rpc_urls.first().cloned())
+}
+
+async fn resolver_for(node: &[u8; 32], rpc_url: &str) -> Result<Option<String>, String> {
+    let result = eth_call(ens::ENS_REGISTRY, &ens::encode_resolver_call(node), rpc_url).await?;
+    ens::decode_address(&result)
+}
+
+async fn addr_record(resolver: &str, node: &[u8; 32], coin_type: u64, rpc_url: &str) -> Result<Option<String>, String> {
+    let result = eth_call(resolver, &ens::encode_addr_call(node, coin_type), rpc_url).await?;
+    if coin_type == ens::ETH_COIN_TYPE {
+        ens::decode_address(&result)
+    } else {
+        ens::decode_coin_address(&result, coin_type)
+    }
+}
+
+/// Resolve `name` to an address for `coin_type` (see `ens::evm_coin_type`).
+/// An L2 without its own record falls back to the ENSIP-19 default EVM record,
+/// never to the mainnet Ether record (which may be a contract that only exists on L1).
+pub async fn resolve_name(name: &str, coin_type: u64, rpc_url: &str) -> Result<String, String> {
+    let normalized = ens::normalize(name)?;
+    let node = ens::namehash(&normalized);
+    let resolver = resolver_for(&node, rpc_url).await?
+        .ok_or_else(|| format!("{} is not registered or has no resolver", normalized))?;
+
+    if let Some(addr) = addr_record(&resolver, &node, coin_type, rpc_url).await? {
+        return Ok(addr);
+    }
+    if let Some(fallback) = ens::fallback_coin_type(coin_type) {
+        if let Some(addr) = addr_record(&resolver, &node, fallback, rpc_url).await? {
+            return Ok(addr);
+        }
+    }
+    Err(format!("{} has no address set for this network", normalized))
+}
+
+/// Verified primary name of an address, cached for the session
+pub async fn lookup_primary_name(address: &str, rpc_url: &str) -> Option<String> {
+    let key = address.to_lowercase();
+    if let Some(cached) = PRIMARY_NAMES.with(|c| c.borrow().get(&key).cloned()) {
+        return cached;
+    }
+    let name = fetch_primary_name(address, rpc_url).await;
+    PRIMARY_NAMES.with(|c| c.borrow_mut().insert(key, name.clone()));
+    name
+}
+
+async fn fetch_primary_name(address: &str, rpc_url: &str) -> Option<String> {
+    let node = ens::reverse_node(address).ok()?;
+    let resolver = resolver_for(&node, rpc_url).await.ok()??;
+    let result = eth_call(&resolver, &ens::encode_name_call(&node), rpc_url).await.ok()?;
+    let name = String::from_utf8(ens::decode_bytes(&result).ok()?).ok()?;
+    if name.is_empty() {
+        return None;
+    }
+    // Anyone can set any reverse name; only trust it if it points back here
+    let forward = resolve_name(&name, ens::ETH_COIN_TYPE, rpc_url).await.ok();
+    ens::verified_primary_name(&name, forward.as_deref(), address)
+}
@@ -1,59 +1,63 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// rpc/erc20: Fetch ERC-20 token balances via eth_call
+// rpc/erc20: Fetch ERC-20 token balances and metadata via batched eth_call
 
 use wallet_core::tokens::erc20;
-use wallet_core::tokens::{Token, TokenBalance};
+use wallet_core::tokens::{TokenBalance, TokenMetadata};
 
-/// Fetch balance of a single ERC-20 token
-pub async fn get_token_balance(
-    owner: &str,
-    token: &Token,
-    rpc_url: &str,
-) -> Result<String, String> {
-    let calldata = erc20::encode_balance_of(owner)?;
-    let calldata_hex = format!("0x{}", hex::encode(&calldata));
-
-    let body = format!(
-        r#"{{"jsonrpc":"2.0","method":"eth_call","params":[{{"to":"{}","data":"{}"}},"latest"],"id":1}}"#,
-        token.address, calldata_hex
-    );
-
-    let json = super::post_json(rpc_url, &body).await?;
+use super::multicall::{batch_call, Read};
 
-    let result = json.get("result")
-        .and_then(|v| v.as_str())
-        .unwrap_or("0x0");
+/// Read name, symbol and decimals from an ERC-20 contract
+pub async fn get_token_metadata(address: &str, rpc_url: &str) -> Result<TokenMetadata, String> {
+    get_tokens_metadata(&[address.to_string()], rpc_url).await
+        .pop()
+        .unwrap_or_else(|| Err("No metadata result".into()))
+}
 
-    Ok(erc20::decode_balance(result, token.decimals))
+/// Read metadata of several contracts in one batch, in order
+pub async fn get_tokens_metadata(addresses: &[String], rpc_url: &str) -> Vec<Result<TokenMetadata, String>> {
+    let reads: Vec<Read> = addresses.iter()
+        .flat_map(|a| [
+            (a.clone(), erc20::encode_decimals()),
+            (a.clone(), erc20::encode_symbol()),
+            (a.clone(), erc20::encode_name()),
+        ])
+        .collect();
+    let results = batch_call(&reads, rpc_url).await;
+
+    results.chunks(3).map(|r| {
+        let decimals = r[0].as_ref().map_err(|e| e.clone())
+            .and_then(|hex| erc20::decode_decimals(hex).map_err(|_| "Contract does not implement decimals()".to_string()))?;
+        let symbol = r[1].as_ref().map_err(|e| e.clone())
+            .and_then(|hex| erc20::decode_string_result(hex).map_err(|_| "Contract does not implement symbol()".to_string()))?;
+        // name() is optional in the standard; fall back to the symbol
+        let name = r[2].as_ref().ok()
+            .and_then(|hex| erc20::decode_string_result(hex).ok())
+            .unwrap_or_default();
+        Ok(TokenMetadata { name, symbol, decimals })
+    }).collect()
 }
 
-/// Fetch balances for all default tokens on a chain
+/// Fetch balances for the default and imported tokens of a chain
 pub async fn get_all_token_balances(
     owner: &str,
     chain_id: &str,
     rpc_url: &str,
 ) -> Vec<TokenBalance> {
-    let tokens = erc20::tokens_for_chain(chain_id);
-    let mut results = Vec::new();
-
-    for token in tokens {
-        let balance = match get_token_balance(owner, &token, rpc_url).await {
-            Ok(b) => b,
-            Err(_) => "0.0000".to_string(),
-        };
-
-        // Skip zero balances
-        let balance_f: f64 = balance.parse().unwrap_or(0.0);
-        if balance_f > 0.0 {
-            results.push(TokenBalance {
-                token,
-                balance,
-                balance_usd: 0.0,
-            });
-        }
-    }
-
-    results
+    let Ok(calldata) = erc20::encode_balance_of(owner) else {
+        return Vec::new();
+    };
+    let tokens = crate::state::tokens_for_chain(chain_id);
+    let reads: Vec<Read> = tokens.iter().map(|t| (t.address.clone(), calldata.clone())).collect();
+    let results = batch_call(&reads, rpc_url).await;
+
+    tokens.into_iter().zip(results)
+        .filter_map(|(token, result)| {
+            let balance = erc20::decode_balance(result.as_deref().unwrap_or("0x0"), token.decimals);
+            // Skip zero balances
+            let balance_f: f64 = balance.parse().unwrap_or(0.0);
+            (balance_f > 0.0).then_some(TokenBalance { token, balance, balance_usd: 0.0 })
+        })
+        .collect()
 }
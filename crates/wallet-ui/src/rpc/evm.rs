@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::post_json;
+use wallet_core::tx::evm_fees::{self, FeeEstimate};
 
 /// Get native ETH/token balance via eth_getBalance
 /// Returns formatted balance string (e.g. "1.2345")
@@ -37,6 +38,96 @@ pub async fn get_nonce(address: &str, rpc_url: &str) -> Result<u64, String> {
     parse_hex_u64(hex_nonce)
 }
 
+/// Get next nonce including transactions still in the mempool
+pub async fn get_pending_nonce(address: &str, rpc_url: &str) -> Result<u64, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getTransactionCount",
+        "params": [address, "pending"],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    let hex_nonce = json["result"].as_str()
+        .ok_or("Missing result in eth_getTransactionCount")?;
+
+    parse_hex_u64(hex_nonce)
+}
+
+/// eth_call against the latest block; returns the raw hex result
+pub async fn eth_call(to: &str, data: &[u8], rpc_url: &str) -> Result<String, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_call",
+        "params": [{"to": to, "data": format!("0x{}", hex::encode(data))}, "latest"],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    if let Some(err) = json.get("error") {
+        return Err(format!("eth_call error: {}", err));
+    }
+    json["result"].as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Missing result in eth_call".into())
+}
+
+/// Latest block number (eth_blockNumber)
+pub async fn get_block_number(rpc_url: &str) -> Result<u64, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_blockNumber",
+        "params": [],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    let hex_block = json["result"].as_str()
+        .ok_or("Missing result in eth_blockNumber")?;
+
+    parse_hex_u64(hex_block)
+}
+
+/// Deployed bytecode at `address` ("0x" for externally owned accounts)
+pub async fn get_code(address: &str, rpc_url: &str) -> Result<String, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getCode",
+        "params": [address, "latest"],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    json["result"].as_str()
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Missing result in eth_getCode".into())
+}
+
+/// Chain id reported by an RPC endpoint (eth_chainId)
+pub async fn get_chain_id(rpc_url: &str) -> Result<u64, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_chainId",
+        "params": [],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    let hex_id = json["result"].as_str()
+        .ok_or("Missing result in eth_chainId")?;
+
+    parse_hex_u64(hex_id)
+}
+
+/// Fail unless the endpoint serves `expected` (guards against mislabeled custom networks)
+pub async fn check_chain_id(rpc_url: &str, expected: u64) -> Result<(), String> {
+    let actual = get_chain_id(rpc_url).await?;
+    if actual != expected {
+        return Err(format!("RPC returned chain ID {}, expected {}", actual, expected));
+    }
+    Ok(())
+}
+
 /// Get current gas price
 pub async fn get_gas_price(rpc_url: &str) -> Result<u128, String> {
     let body = serde_json::json!({
@@ -69,6 +160,52 @@ pub async fn get_max_priority_fee(rpc_url: &str) -> Result<u128, String> {
     parse_hex_u128(hex_fee)
 }
 
+/// Estimate a gas limit via eth_estimateGas (with safety margin).
+/// Falls back to `fallback` when the node errors or the call would revert.
+pub async fn estimate_gas_limit(
+    from: &str,
+    to: &str,
+    value: u128,
+    data: &[u8],
+    fallback: u64,
+    rpc_url: &str,
+) -> u64 {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_estimateGas",
+        "params": [{
+            "from": from,
+            "to": to,
+            "value": format!("0x{:x}", value),
+            "data": format!("0x{}", hex::encode(data)),
+        }],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await.ok();
+    evm_fees::resolve_gas_limit(json.as_ref(), fallback)
+}
+
+/// Slow/normal/fast EIP-1559 fees from eth_feeHistory, falling back to eth_gasPrice
+pub async fn get_fee_estimate(rpc_url: &str) -> Result<FeeEstimate, String> {
+    let percentiles: Vec<u8> = evm_fees::FEE_HISTORY_PERCENTILES.to_vec();
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_feeHistory",
+        "params": [format!("0x{:x}", evm_fees::FEE_HISTORY_BLOCKS), "latest", percentiles],
+        "id": 1
+    }).to_string();
+
+    let history = post_json(rpc_url, &body).await.ok();
+    if let Some(Ok(estimate)) = history.as_ref().map(evm_fees::fees_from_fee_history) {
+        return Ok(estimate);
+    }
+
+    let gas_price = get_gas_price(rpc_url).await.ok();
+    let priority_fee = get_max_priority_fee(rpc_url).await.ok();
+    evm_fees::resolve_fee_estimate(None, gas_price, priority_fee)
+}
+
 /// Broadcast a signed raw transaction
 pub async fn send_raw_transaction(signed_hex: &str, rpc_url: &str) -> Result<String, String> {
     let body = serde_json::json!({
@@ -100,7 +237,7 @@ fn parse_hex_u64(hex: &str) -> Result<u64, String> {
 }
 
 /// Format wei to human-readable (e.g. 1000000000000000000 → "1.0000")
-fn format_wei(wei: u128, decimals: u32) -> String {
+pub fn format_wei(wei: u128, decimals: u32) -> String {
     let divisor = 10u128.pow(decimals);
     let integer = wei / divisor;
     let fraction = wei % divisor;
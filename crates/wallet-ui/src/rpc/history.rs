@@ -32,13 +32,22 @@ pub async fn fetch_evm_history(
 ) -> Vec<TxRecord> {
     // Use free block explorer APIs
     let (api_url, explorer_base) = match chain_id {
-        "ethereum" => ("https://api.etherscan.io/api", "https://etherscan.io/tx/"),
-        "polygon" => ("https://api.polygonscan.com/api", "https://polygonscan.com/tx/"),
-        "bsc" => ("https://api.bscscan.com/api", "https://bscscan.com/tx/"),
-        "arbitrum" => ("https://api.arbiscan.io/api", "https://arbiscan.io/tx/"),
-        "optimism" => ("https://api-optimistic.etherscan.io/api", "https://optimistic.etherscan.io/tx/"),
-        "base" => ("https://api.basescan.org/api", "https://basescan.org/tx/"),
-        _ => return Vec::new(),
+        "ethereum" => ("https://api.etherscan.io/api".to_string(), "https://etherscan.io/tx/".to_string()),
+        "polygon" => ("https://api.polygonscan.com/api".into(), "https://polygonscan.com/tx/".into()),
+        "bsc" => ("https://api.bscscan.com/api".into(), "https://bscscan.com/tx/".into()),
+        "arbitrum" => ("https://api.arbiscan.io/api".into(), "https://arbiscan.io/tx/".into()),
+        "optimism" => ("https://api-optimistic.etherscan.io/api".into(), "https://optimistic.etherscan.io/tx/".into()),
+        "base" => ("https://api.basescan.org/api".into(), "https://basescan.org/tx/".into()),
+        key => {
+            // Custom networks: Etherscan-compatible API of the configured explorer, if any
+            let Some(id) = wallet_core::chains::custom_evm::parse_chain_key(key) else { return Vec::new() };
+            let networks = crate::state::load_custom_networks();
+            let Some(net) = networks.iter().find(|n| n.chain_id == id) else { return Vec::new() };
+            match (net.history_api_url(), net.tx_url("")) {
+                (Some(api), Some(explorer)) => (api, explorer),
+                _ => return Vec::new(),
+            }
+        }
     };
 
     let url = format!(
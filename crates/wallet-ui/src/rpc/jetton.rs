@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::get_json;
-use wallet_core::tokens::{Token, TokenBalance};
+use wallet_core::tokens::{Token, TokenBalance, TokenMetadata};
 use wallet_core::tokens::jetton;
 
 /// Get all Jetton balances using toncenter v3 REST API
@@ -16,7 +16,7 @@ pub async fn get_all_token_balances(owner: &str, rpc_url: &str) -> Vec<TokenBala
 
     if let Ok(json) = get_json(&v3_url).await {
         if let Some(wallets) = json["jetton_wallets"].as_array() {
-            let known_tokens = jetton::default_tokens();
+            let known_tokens = crate::state::tokens_for_chain("ton");
             let mut result = Vec::new();
 
             for wallet in wallets {
@@ -68,9 +68,9 @@ pub async fn get_all_token_balances(owner: &str, rpc_url: &str) -> Vec<TokenBala
     get_default_token_balances(owner, rpc_url).await
 }
 
-/// Fallback: query each default token via runGetMethod
+/// Fallback: query each known (default or imported) token via runGetMethod
 async fn get_default_token_balances(owner: &str, rpc_url: &str) -> Vec<TokenBalance> {
-    let tokens = jetton::default_tokens();
+    let tokens = crate::state::tokens_for_chain("ton");
     let mut result = Vec::new();
 
     for token in tokens {
@@ -99,8 +99,16 @@ async fn get_default_token_balances(owner: &str, rpc_url: &str) -> Vec<TokenBala
     result
 }
 
+/// Read jetton master metadata through the toncenter v3 index
+pub async fn get_token_metadata(master: &str, rpc_url: &str) -> Result<TokenMetadata, String> {
+    let base_url = rpc_url.trim_end_matches("/jsonRPC");
+    let url = format!("{}/v3/jetton/masters?address={}&limit=1", base_url, master);
+    jetton::parse_jetton_master(&get_json(&url).await?)
+}
+
 /// Resolve jetton wallet address via runGetMethod on the master contract
 pub async fn get_jetton_wallet_address(master: &str, owner: &str, rpc_url: &str) -> Result<String, String> {
+    let owner = wallet_core::chains::ton::TonAddress::parse(owner)?;
     let body = serde_json::json!({
         "id": 1,
         "jsonrpc": "2.0",
@@ -109,23 +117,13 @@ pub async fn get_jetton_wallet_address(master: &str, owner: &str, rpc_url: &str)
             "address": master,
             "method": "get_wallet_address",
             "stack": [
-                ["tvm.Slice", owner]
+                ["tvm.Slice", jetton::wallet_address_stack_arg(&owner)?]
             ]
         }
     }).to_string();
 
     let json = super::post_json(rpc_url, &body).await?;
-
-    let stack = json["result"]["stack"].as_array()
-        .ok_or("Missing stack in get_wallet_address response")?;
-
-    if let Some(first) = stack.first() {
-        if let Some(val) = first.get(1).and_then(|v| v.as_str()) {
-            return Ok(val.to_string());
-        }
-    }
-
-    Err("Could not resolve jetton wallet address".into())
+    Ok(jetton::parse_wallet_address_result(&json)?.to_friendly())
 }
 
 /// Get jetton balance from a jetton wallet contract via runGetMethod
@@ -21,10 +21,15 @@ pub mod approvals;
 pub mod simulate;
 pub mod cw20;
 pub mod jetton;
+pub mod ton_connect;
 pub mod nft;
 pub mod swap;
+pub mod ens;
+pub mod safe;
+pub mod verify;
+pub mod multicall;
 
-use wallet_core::chains::{ChainId, get_chains};
+use wallet_core::chains::ChainId;
 
 /// Fetch the native balance for any supported chain (mainnet)
 pub async fn fetch_balance(chain_id: &str, address: &str) -> Result<String, String> {
@@ -33,7 +38,7 @@ pub async fn fetch_balance(chain_id: &str, address: &str) -> Result<String, Stri
 
 /// Fetch the native balance for any supported chain with network selection
 pub async fn fetch_balance_for_network(chain_id: &str, address: &str, testnet: bool) -> Result<String, String> {
-    let chains = get_chains(testnet);
+    let chains = crate::state::all_chains(testnet);
     let config = chains.iter()
         .find(|c| chain_id_to_string(&c.id) == chain_id)
         .ok_or_else(|| format!("Unknown chain: {}", chain_id))?;
@@ -43,7 +48,8 @@ pub async fn fetch_balance_for_network(chain_id: &str, address: &str, testnet: b
 
     match &config.id {
         ChainId::Ethereum | ChainId::Polygon | ChainId::Bsc |
-        ChainId::Optimism | ChainId::Base | ChainId::Arbitrum => {
+        ChainId::Optimism | ChainId::Base | ChainId::Arbitrum |
+        ChainId::CustomEvm(_) => {
             evm::get_balance(address, rpc_url).await
         }
         ChainId::Solana => {
@@ -79,6 +85,27 @@ pub async fn fetch_balance_for_network(chain_id: &str, address: &str, testnet: b
     }
 }
 
+/// Look up a token contract/mint on `chain_id` and build an importable token
+pub async fn fetch_token(chain_id: &str, address: &str, testnet: bool) -> Result<wallet_core::tokens::Token, String> {
+    use wallet_core::tokens::custom::{self, TokenKind};
+
+    let address = custom::normalize_address(chain_id, address)?;
+    let chains = crate::state::all_chains(testnet);
+    let config = chains.iter()
+        .find(|c| chain_id_to_string(&c.id) == chain_id)
+        .ok_or_else(|| format!("Unknown chain: {}", chain_id))?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or_else(|| format!("No RPC URL for {}", chain_id))?;
+
+    let metadata = match TokenKind::for_chain(chain_id).ok_or("Tokens are not supported on this chain")? {
+        TokenKind::Erc20 => erc20::get_token_metadata(&address, rpc_url).await?,
+        TokenKind::Spl => spl::get_token_metadata(&address, rpc_url).await?,
+        TokenKind::Cw20 => cw20::get_token_metadata(&address, &cosmos::rpc_url_to_rest(rpc_url)).await?,
+        TokenKind::Jetton => jetton::get_token_metadata(&address, rpc_url).await?,
+    };
+    custom::new_token(chain_id, &address, metadata)
+}
+
 /// Helper to post JSON-RPC requests (with 30s timeout and status check)
 pub async fn post_json(url: &str, body: &str) -> Result<serde_json::Value, String> {
     use gloo_net::http::Request;
@@ -128,6 +155,11 @@ pub async fn post_json(url: &str, body: &str) -> Result<serde_json::Value, Strin
     let text = response.text().await
         .map_err(|e| format!("Response read error: {}", e))?;
 
+    // REST endpoints may answer 201/204 with no body
+    if text.trim().is_empty() {
+        return Ok(serde_json::Value::Null);
+    }
+
     serde_json::from_str(&text)
         .map_err(|e| format!("JSON parse error: {}", e))
 }
@@ -179,12 +211,12 @@ pub async fn get_json(url: &str) -> Result<serde_json::Value, String> {
         .map_err(|e| format!("JSON parse error: {}", e))
 }
 
-pub fn chain_id_str(id: &ChainId) -> &'static str {
+pub fn chain_id_str(id: &ChainId) -> String {
     chain_id_to_string(id)
 }
 
-fn chain_id_to_string(id: &ChainId) -> &'static str {
-    match id {
+fn chain_id_to_string(id: &ChainId) -> String {
+    let key = match id {
         ChainId::Ethereum => "ethereum",
         ChainId::Polygon => "polygon",
         ChainId::Bsc => "bsc",
@@ -201,5 +233,7 @@ fn chain_id_to_string(id: &ChainId) -> &'static str {
         ChainId::Ripple => "ripple",
         ChainId::Dogecoin => "dogecoin",
         ChainId::Tron => "tron",
-    }
+        ChainId::CustomEvm(n) => return wallet_core::chains::custom_evm::chain_key(*n),
+    };
+    key.to_string()
 }
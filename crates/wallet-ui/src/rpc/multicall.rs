@@ -0,0 +1,78 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// rpc/multicall: Batch eth_call reads through Multicall3, one eth_call per read as fallback
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use wallet_core::tokens::multicall::{self, Call3, MAX_BATCH, MULTICALL3_ADDRESS};
+
+use super::evm::eth_call;
+use crate::logging::log_info;
+
+thread_local! {
+    /// RPC URLs where aggregate3 failed (contract missing or call refused), per session
+    static UNSUPPORTED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// A read: target contract and calldata
+pub type Read = (String, Vec<u8>);
+
+/// `Ok(None)` when no contract answers at the Multicall3 address
+async fn aggregate(calls: &[Call3], rpc_url: &str) -> Result<Option<Vec<multicall::CallResult>>, String> {
+    let result = eth_call(MULTICALL3_ADDRESS, &multicall::encode_aggregate3(calls), rpc_url).await?;
+    if result.trim_start_matches("0x").is_empty() {
+        return Ok(None);
+    }
+    multicall::decode_aggregate3(&result, calls.len()).map(Some)
+}
+
+/// Run `reads` and return each result as eth_call-style hex, in order.
+/// Reads are grouped into Multicall3 `aggregate3` calls; chains without it
+/// get one eth_call per read.
+pub async fn batch_call(reads: &[Read], rpc_url: &str) -> Vec<Result<String, String>> {
+    let mut results: Vec<Option<Result<String, String>>> = vec![None; reads.len()];
+    let use_multicall = reads.len() > 1
+        && !UNSUPPORTED.with(|u| u.borrow().contains(rpc_url));
+
+    if use_multicall {
+        let mut pending: Vec<(usize, Call3)> = Vec::new();
+        for (i, (target, data)) in reads.iter().enumerate() {
+            match Call3::new(target, data.clone()) {
+                Ok(call) => pending.push((i, call)),
+                Err(e) => results[i] = Some(Err(e)),
+            }
+        }
+
+        for chunk in pending.chunks(MAX_BATCH) {
+            let calls: Vec<Call3> = chunk.iter().map(|(_, c)| c.clone()).collect();
+            match aggregate(&calls, rpc_url).await {
+                Ok(Some(outcome)) => {
+                    for ((i, _), r) in chunk.iter().zip(outcome) {
+                        results[*i] = Some(if r.success { Ok(r.to_hex()) } else { Err("Call reverted".into()) });
+                    }
+                }
+                Ok(None) => {
+                    log_info!("Multicall3 not deployed behind {}, using eth_call", rpc_url);
+                    UNSUPPORTED.with(|u| u.borrow_mut().insert(rpc_url.to_string()));
+                    break;
+                }
+                // Transient or size-related failure: fall back for this request only
+                Err(e) => {
+                    log_info!("Multicall3 batch failed on {}, falling back to eth_call: {}", rpc_url, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(reads.len());
+    for ((target, data), result) in reads.iter().zip(results) {
+        out.push(match result {
+            Some(r) => r,
+            None => eth_call(target, data, rpc_url).await,
+        });
+    }
+    out
+}
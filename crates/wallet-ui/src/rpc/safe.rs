@@ -0,0 +1,65 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// rpc/safe: Safe on-chain state (owners, threshold, nonce) and the Safe Transaction Service queue
+
+use wallet_core::tx::safe::{self, PendingSafeTx, SafeSignature, SafeTransaction, WatchedSafe};
+
+use super::evm::eth_call;
+use crate::logging::log_error;
+
+/// On-chain configuration of a Safe
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafeInfo {
+    pub owners: Vec<[u8; 20]>,
+    pub threshold: u64,
+    pub nonce: u64,
+}
+
+pub async fn fetch_info(safe_address: &str, rpc_url: &str) -> Result<SafeInfo, String> {
+    let owners = eth_call(safe_address, &safe::encode_get_owners_call(), rpc_url).await?;
+    let threshold = eth_call(safe_address, &safe::encode_get_threshold_call(), rpc_url).await?;
+    let nonce = eth_call(safe_address, &safe::encode_nonce_call(), rpc_url).await?;
+    Ok(SafeInfo {
+        owners: safe::decode_owners(&owners)?,
+        threshold: safe::decode_uint(&threshold)?,
+        nonce: safe::decode_uint(&nonce)?,
+    })
+}
+
+fn service(chain_id: u64) -> Result<&'static str, String> {
+    safe::service_url(chain_id)
+        .ok_or_else(|| format!("No Safe Transaction Service for chain {}", chain_id))
+}
+
+/// Queued (not yet executed) transactions at or above `onchain_nonce`
+pub async fn fetch_pending(watched: &WatchedSafe, onchain_nonce: u64) -> Result<Vec<PendingSafeTx>, String> {
+    let url = format!(
+        "{}/api/v1/safes/{}/multisig-transactions/?executed=false&nonce__gte={}&ordering=nonce&limit=50",
+        service(watched.evm_chain_id)?, watched.address, onchain_nonce,
+    );
+    let json = super::get_json(&url).await?;
+    let safe_addr = wallet_core::tx::evm::parse_address(&watched.address)?;
+    let (pending, skipped) = safe::parse_pending(&json, &safe_addr, watched.evm_chain_id, onchain_nonce)?;
+    for e in skipped {
+        log_error!("Skipping queued Safe transaction for {}: {}", watched.address, e);
+    }
+    Ok(pending)
+}
+
+/// Publish a new transaction together with the proposer's signature
+pub async fn propose(watched: &WatchedSafe, tx: &SafeTransaction, signature: &SafeSignature) -> Result<(), String> {
+    let safe_addr = wallet_core::tx::evm::parse_address(&watched.address)?;
+    let url = format!("{}/api/v1/safes/{}/multisig-transactions/", service(watched.evm_chain_id)?, watched.address);
+    let body = tx.proposal_body(&safe_addr, watched.evm_chain_id, signature);
+    super::post_json(&url, &body.to_string()).await.map(|_| ())
+}
+
+/// Add an owner's signature to a queued transaction
+pub async fn confirm(watched: &WatchedSafe, safe_tx_hash: &[u8; 32], signature: &SafeSignature) -> Result<(), String> {
+    let url = format!(
+        "{}/api/v1/multisig-transactions/0x{}/confirmations/",
+        service(watched.evm_chain_id)?, hex::encode(safe_tx_hash),
+    );
+    super::post_json(&url, &safe::confirmation_body(signature).to_string()).await.map(|_| ())
+}
@@ -1,6 +1,11 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use wallet_core::tx::solana_dapp;
+use wallet_core::tx::solana_fees::{self, PriorityFeeEstimate};
+use wallet_core::tx::solana_stake::{self, StakeAccount, Validator};
+use wallet_core::tx::solana_message::AddressLookupTable;
+
 use super::post_json;
 
 /// Get SOL balance in human-readable format
@@ -54,6 +59,128 @@ pub async fn send_transaction(signed_b58: &str, rpc_url: &str) -> Result<String,
         .ok_or("Missing result in sendTransaction".into())
 }
 
+/// Raw account (`getAccountInfo` value) with base64 data; Null if the account does not exist
+pub async fn get_account_info(address: &str, rpc_url: &str) -> Result<serde_json::Value, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "getAccountInfo",
+        "params": [address, {"encoding": "base64", "commitment": "confirmed"}],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+    Ok(json["result"]["value"].clone())
+}
+
+/// Load the address lookup tables a v0 message references
+pub async fn get_lookup_tables(keys: &[[u8; 32]], rpc_url: &str) -> Result<Vec<AddressLookupTable>, String> {
+    let mut tables = Vec::with_capacity(keys.len());
+    for key in keys {
+        let key = bs58::encode(key).into_string();
+        let account = get_account_info(&key, rpc_url).await?;
+        tables.push(solana_dapp::lookup_table_from_account_info(&key, &account)?);
+    }
+    Ok(tables)
+}
+
+/// `simulateTransaction` result value for an (unsigned) base58 transaction
+async fn simulate(tx_b58: &str, rpc_url: &str) -> Result<serde_json::Value, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "simulateTransaction",
+        "params": [tx_b58, {"encoding": "base58", "sigVerify": false, "replaceRecentBlockhash": true}],
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+    Ok(json["result"]["value"].clone())
+}
+
+/// Program error and last log lines of a failed simulation
+fn simulation_failure(value: &serde_json::Value) -> Option<String> {
+    if value["err"].is_null() {
+        return None;
+    }
+    let logs: Vec<&str> = value["logs"].as_array()
+        .map(|l| l.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    let tail = logs.iter().rev().take(2).rev().cloned().collect::<Vec<_>>().join(" | ");
+    Some(format!("{} {}", value["err"], tail).trim().to_string())
+}
+
+/// Dry-run an (unsigned) base58 transaction. Returns the program error and last
+/// log lines when it would fail, `None` when it would succeed.
+pub async fn simulate_transaction(tx_b58: &str, rpc_url: &str) -> Result<Option<String>, String> {
+    Ok(simulation_failure(&simulate(tx_b58, rpc_url).await?))
+}
+
+/// Compute units an (unsigned) base58 transaction consumes; errors if it would fail
+pub async fn simulate_compute_units(tx_b58: &str, rpc_url: &str) -> Result<u64, String> {
+    let value = simulate(tx_b58, rpc_url).await?;
+    if let Some(failure) = simulation_failure(&value) {
+        return Err(format!("Simulation failed: {}", failure));
+    }
+    value["unitsConsumed"].as_u64().ok_or("Missing unitsConsumed in simulation".into())
+}
+
+/// Slow/normal/fast priority fees paid recently by transactions writing `accounts`
+pub async fn get_priority_fees(accounts: &[String], rpc_url: &str) -> Result<PriorityFeeEstimate, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "getRecentPrioritizationFees",
+        "params": [accounts],
+        "id": 1
+    }).to_string();
+
+    solana_fees::fees_from_prioritization_fees(&post_json(rpc_url, &body).await?)
+}
+
+/// Current epoch (`getEpochInfo`)
+pub async fn get_epoch(rpc_url: &str) -> Result<u64, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "getEpochInfo",
+        "id": 1
+    }).to_string();
+
+    let json = post_json(rpc_url, &body).await?;
+    json["result"]["epoch"].as_u64().ok_or("Missing epoch in getEpochInfo".into())
+}
+
+/// Stake accounts whose withdraw authority is `owner`
+pub async fn get_stake_accounts(owner: &str, rpc_url: &str) -> Result<Vec<StakeAccount>, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "getProgramAccounts",
+        "params": [solana_stake::STAKE_PROGRAM_ID, {
+            "encoding": "base64",
+            "commitment": "confirmed",
+            "filters": [{"memcmp": {"offset": solana_stake::WITHDRAWER_OFFSET, "bytes": owner}}]
+        }],
+        "id": 1
+    }).to_string();
+
+    solana_stake::stake_accounts_from_response(&post_json(rpc_url, &body).await?)
+}
+
+/// Validators (`getVoteAccounts`), current ones first by activated stake
+pub async fn get_validators(rpc_url: &str) -> Result<Vec<Validator>, String> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "getVoteAccounts",
+        "params": [{"commitment": "confirmed"}],
+        "id": 1
+    }).to_string();
+
+    solana_stake::validators_from_response(&post_json(rpc_url, &body).await?)
+}
+
 fn format_lamports(lamports: u64) -> String {
     let sol = lamports / 1_000_000_000;
     let frac = lamports % 1_000_000_000;
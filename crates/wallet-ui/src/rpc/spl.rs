@@ -1,19 +1,54 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
-// rpc/spl: Fetch SPL token balances via Solana JSON-RPC
+// rpc/spl: Fetch SPL Token and Token-2022 balances via Solana JSON-RPC
 
-use wallet_core::tokens::{Token, TokenBalance};
+use wallet_core::tokens::{Token, TokenBalance, TokenMetadata};
 use wallet_core::tokens::spl;
 
-/// Fetch all SPL token balances for a wallet
+/// Read decimals from the mint account and name/symbol from its Token-2022 metadata
+/// extension, or else from its Metaplex metadata
+pub async fn get_token_metadata(mint: &str, rpc_url: &str) -> Result<TokenMetadata, String> {
+    let account = super::solana::get_account_info(mint, rpc_url).await?;
+    if account.is_null() {
+        return Err("Mint account not found".into());
+    }
+    let mint_info = spl::parse_mint_info(&account)?;
+    let decimals = mint_info.decimals;
+    if let Some(embedded) = mint_info.extensions.metadata {
+        return Ok(TokenMetadata { name: embedded.name, symbol: embedded.symbol, decimals });
+    }
+
+    let mint_key = wallet_core::tx::solana::parse_pubkey(mint)?;
+    let metadata_address = bs58::encode(wallet_core::nft::metadata_address(&mint_key)?).into_string();
+    let metadata = super::solana::get_account_info(&metadata_address, rpc_url).await.ok()
+        .and_then(|value| wallet_core::nft::parse_metadata_account_info(&value).ok())
+        .ok_or("Mint has no Metaplex metadata; name and symbol are unknown")?;
+    Ok(TokenMetadata { name: metadata.name, symbol: metadata.symbol, decimals })
+}
+
+/// Fetch all SPL Token and Token-2022 balances for a wallet
 pub async fn get_all_token_balances(
     owner: &str,
     rpc_url: &str,
 ) -> Vec<TokenBalance> {
+    let mut results = get_program_token_balances(owner, spl::TOKEN_PROGRAM_ID, rpc_url).await;
+    results.extend(get_program_token_balances(owner, spl::TOKEN_2022_PROGRAM_ID, rpc_url).await);
+    results
+}
+
+/// Name and symbol from a Token-2022 mint's metadata extension
+async fn token_2022_metadata(mint: &str, rpc_url: &str) -> Option<(String, String)> {
+    let account = super::solana::get_account_info(mint, rpc_url).await.ok()?;
+    let metadata = spl::parse_mint_info(&account).ok()?.extensions.metadata?;
+    Some((metadata.name, metadata.symbol))
+}
+
+/// Balances of the token accounts `owner` holds under one token program
+async fn get_program_token_balances(owner: &str, token_program: &str, rpc_url: &str) -> Vec<TokenBalance> {
     let body = format!(
         r#"{{"jsonrpc":"2.0","id":1,"method":"getTokenAccountsByOwner","params":["{}",{{"programId":"{}"}},{{"encoding":"jsonParsed"}}]}}"#,
-        owner, spl::TOKEN_PROGRAM_ID
+        owner, token_program
     );
 
     let json = match super::post_json(rpc_url, &body).await {
@@ -22,7 +57,7 @@ pub async fn get_all_token_balances(
     };
 
     let mut results = Vec::new();
-    let known_tokens = spl::default_tokens();
+    let known_tokens = crate::state::tokens_for_chain("solana");
 
     if let Some(value) = json.get("result").and_then(|r| r.get("value")).and_then(|v| v.as_array()) {
         for account in value {
@@ -53,17 +88,20 @@ pub async fn get_all_token_balances(
                 continue;
             }
 
-            // Match against known tokens, or use mint as symbol
-            let token = known_tokens.iter()
-                .find(|t| t.address == mint)
-                .cloned()
-                .unwrap_or(Token {
-                    address: mint.to_string(),
-                    symbol: format!("{}...", &mint[..6]),
-                    name: "Unknown Token".to_string(),
-                    decimals,
-                    chain_id: "solana".to_string(),
-                });
+            // Match against known tokens, then Token-2022 embedded metadata, or use mint as symbol
+            let token = match known_tokens.iter().find(|t| t.address == mint) {
+                Some(known) => known.clone(),
+                None => {
+                    let embedded = if token_program == spl::TOKEN_2022_PROGRAM_ID {
+                        token_2022_metadata(mint, rpc_url).await
+                    } else {
+                        None
+                    };
+                    let (name, symbol) = embedded
+                        .unwrap_or_else(|| ("Unknown Token".to_string(), format!("{}...", &mint[..6])));
+                    Token { address: mint.to_string(), symbol, name, decimals, chain_id: "solana".to_string() }
+                }
+            };
 
             results.push(TokenBalance {
                 token,
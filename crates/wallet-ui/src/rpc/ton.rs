@@ -1,10 +1,19 @@
 // Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use std::collections::HashMap;
+use wallet_core::chains::ChainId;
+use wallet_core::chains::ton::WalletVersion;
+
 use super::{get_json, post_json};
 
 /// Get TON balance via toncenter API
 pub async fn get_balance(address: &str, rpc_url: &str) -> Result<String, String> {
+    Ok(format_nanoton(get_balance_nanoton(address, rpc_url).await?))
+}
+
+/// Get TON balance in nanoton via toncenter API
+pub async fn get_balance_nanoton(address: &str, rpc_url: &str) -> Result<u64, String> {
     // toncenter REST endpoint
     let base_url = rpc_url.trim_end_matches("/jsonRPC");
     let url = format!("{}/getAddressBalance?address={}", base_url, address);
@@ -14,10 +23,26 @@ pub async fn get_balance(address: &str, rpc_url: &str) -> Result<String, String>
     let balance_str = json["result"].as_str()
         .ok_or("Missing result in getAddressBalance")?;
 
-    let nanoton: u64 = balance_str.parse()
-        .map_err(|_| "Invalid balance number")?;
+    balance_str.parse()
+        .map_err(|_| "Invalid balance number".into())
+}
 
-    Ok(format_nanoton(nanoton))
+/// Balance (nanoton) of each wallet version's address in `addresses`.
+/// Versions without an address are skipped; any failed balance read fails the
+/// whole call, so callers never pick a version from partial data.
+pub async fn get_version_balances(addresses: &HashMap<String, String>, testnet: bool) -> Result<Vec<(WalletVersion, u64)>, String> {
+    let chains = crate::state::all_chains(testnet);
+    let rpc_url = chains.iter()
+        .find(|c| c.id == ChainId::Ton)
+        .and_then(|c| c.rpc_urls.first())
+        .ok_or("No TON RPC configured")?;
+
+    let mut balances = Vec::new();
+    for version in WalletVersion::all() {
+        let Some(address) = addresses.get(version.address_key()) else { continue };
+        balances.push((*version, get_balance_nanoton(address, rpc_url).await?));
+    }
+    Ok(balances)
 }
 
 /// Get wallet seqno
@@ -69,7 +94,7 @@ pub async fn send_boc(boc_b64: &str, rpc_url: &str) -> Result<String, String> {
     Ok(json["result"].to_string())
 }
 
-fn format_nanoton(nanoton: u64) -> String {
+pub fn format_nanoton(nanoton: u64) -> String {
     let ton = nanoton / 1_000_000_000;
     let frac = nanoton % 1_000_000_000;
     let frac_str = format!("{:09}", frac);
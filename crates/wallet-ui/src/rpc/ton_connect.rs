@@ -0,0 +1,104 @@
+// Rusby Wallet — Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+//
+// rpc/ton_connect: TON Connect manifest fetch and HTTP bridge transport.
+// The events stream is read with fetch and handled by `BridgeClient`, which
+// also paces the reconnects.
+
+use wallet_core::tonconnect::AppManifest;
+use wallet_core::tonconnect::bridge::{BridgeClient, IncomingMessage, OutgoingMessage, StreamControl};
+
+use super::get_json;
+use crate::logging::{log_error, log_info};
+
+/// Fetch and validate a dApp's `tonconnect-manifest.json`
+pub async fn fetch_manifest(manifest_url: &str) -> Result<AppManifest, String> {
+    AppManifest::parse(&get_json(manifest_url).await?)
+}
+
+/// Deliver an encrypted message through the bridge
+pub async fn post_message(message: &OutgoingMessage) -> Result<(), String> {
+    use gloo_net::http::Request;
+
+    let response = Request::post(&message.url)
+        .header("Content-Type", "text/plain")
+        .body(message.body.clone())
+        .map_err(|e| format!("Request error: {}", e))?
+        .send()
+        .await
+        .map_err(|e| format!("Bridge error: {}", e))?;
+    if !response.ok() {
+        return Err(format!("Bridge HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+/// Listen on the bridge events stream for `client_ids`, handing each message
+/// to `on_message`. Dropped streams are reopened after a backoff, resuming from
+/// the last event id. Returns once `keep_running` turns false (checked after
+/// every chunk and during the backoff; the bridge sends heartbeats).
+pub async fn listen(
+    client: &mut BridgeClient,
+    client_ids: &[String],
+    keep_running: impl Fn() -> bool,
+    mut on_message: impl FnMut(IncomingMessage),
+) {
+    loop {
+        match read_stream(client, client_ids, &keep_running, &mut on_message).await {
+            Ok(StreamControl::Stop) => return,
+            Ok(_) => log_info!("TON Connect bridge closed the stream"),
+            Err(e) => log_error!("TON Connect bridge: {}", e),
+        }
+        let mut waited = 0;
+        let delay = client.reconnect_delay_ms();
+        while waited < delay {
+            if !keep_running() {
+                return;
+            }
+            gloo_timers::future::TimeoutFuture::new(500).await;
+            waited += 500;
+        }
+    }
+}
+
+/// Read one connection of the events stream until it ends or the listener stops
+async fn read_stream(
+    client: &mut BridgeClient,
+    client_ids: &[String],
+    keep_running: &impl Fn() -> bool,
+    on_message: &mut impl FnMut(IncomingMessage),
+) -> Result<StreamControl, String> {
+    use gloo_net::http::Request;
+    use wasm_bindgen::JsCast;
+
+    let url = client.events_url(client_ids);
+    let response = Request::get(&url)
+        .header("Accept", "text/event-stream")
+        .send()
+        .await
+        .map_err(|e| format!("Bridge error: {}", e))?;
+    if !response.ok() {
+        return Err(format!("Bridge HTTP {}", response.status()));
+    }
+    let reader: web_sys::ReadableStreamDefaultReader = response.body()
+        .ok_or("Empty bridge response")?
+        .get_reader()
+        .unchecked_into();
+
+    loop {
+        let chunk = wasm_bindgen_futures::JsFuture::from(reader.read()).await
+            .map_err(|_| "Bridge stream interrupted".to_string());
+        let bytes = chunk.as_ref().ok()
+            .filter(|c| !js_sys::Reflect::get(c, &"done".into()).map(|d| d.is_truthy()).unwrap_or(true))
+            .and_then(|c| js_sys::Reflect::get(c, &"value".into()).ok())
+            .map(|value| js_sys::Uint8Array::new(&value).to_vec());
+        match client.on_read(bytes.as_deref(), keep_running(), &mut *on_message) {
+            StreamControl::Continue => {}
+            StreamControl::Stop => {
+                let _ = reader.cancel();
+                return Ok(StreamControl::Stop);
+            }
+            StreamControl::Reconnect => return chunk.map(|_| StreamControl::Reconnect),
+        }
+    }
+}
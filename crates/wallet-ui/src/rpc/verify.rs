@@ -0,0 +1,57 @@
+// Rusby Wallet — Signature verification against the chain (ERC-1271, ERC-6492)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use wallet_core::signing::verify::{self, Verification, VerifyMethod};
+
+use super::post_json;
+
+/// Verify `signature` over `hash` for `signer`.
+/// Accounts with code are asked via `isValidSignature`; an ERC-6492 signature for an
+/// account that does not exist yet is checked by simulating its deployment first;
+/// everything else goes through ecrecover.
+pub async fn verify_signature(
+    rpc_url: &str,
+    signer: &[u8; 20],
+    hash: &[u8; 32],
+    signature: &[u8],
+) -> Result<Verification, String> {
+    let signer_hex = format!("0x{}", hex::encode(signer));
+    let code = super::evm::get_code(&signer_hex, rpc_url).await?;
+    let deployed = !code.trim_start_matches("0x").is_empty();
+    let wrapped = verify::decode_erc6492(signature)?;
+
+    match (wrapped, deployed) {
+        (Some(wrapped), false) => {
+            let body = verify::erc6492_simulate_body(signer, hash, &wrapped);
+            let json = post_json(rpc_url, &body.to_string()).await?;
+            let result = json.get("result")
+                .ok_or("This RPC does not support eth_simulateV1, needed for undeployed ERC-6492 accounts")?;
+            Ok(Verification {
+                valid: verify::parse_erc6492_simulation(result)?,
+                method: VerifyMethod::Erc6492,
+                recovered: None,
+            })
+        }
+        (wrapped, true) => {
+            // Already deployed: the wrapper is not needed
+            let inner = wrapped.map(|w| w.signature).unwrap_or_else(|| signature.to_vec());
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "eth_call",
+                "params": [{
+                    "to": signer_hex,
+                    "data": format!("0x{}", hex::encode(verify::encode_is_valid_signature(hash, &inner))),
+                }, "latest"],
+                "id": 1
+            }).to_string();
+            let json = post_json(rpc_url, &body).await?;
+            // A revert means the account rejected the signature
+            let valid = json["result"].as_str()
+                .and_then(|r| hex::decode(r.trim_start_matches("0x")).ok())
+                .is_some_and(|ret| verify::is_erc1271_success(&ret));
+            Ok(Verification { valid, method: VerifyMethod::Erc1271, recovered: None })
+        }
+        (None, false) => Ok(verify::verify_ecrecover(hash, signature, signer)),
+    }
+}
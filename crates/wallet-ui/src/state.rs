@@ -3,8 +3,14 @@
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use wallet_core::tokens::TokenBalance;
-use wallet_core::chains::get_chains;
+use wallet_core::tokens::{Token, TokenBalance};
+use wallet_core::tokens::custom as custom_tokens;
+use wallet_core::tokens::approvals::ScanState;
+use wallet_core::tx::evm_pending::PendingEvmTx;
+use wallet_core::chains::ChainConfig;
+use wallet_core::chains::ton::WalletVersion;
+use wallet_core::chains::custom_evm::{self, CustomEvmNetwork};
+use wallet_core::tx::safe::WatchedSafe;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -25,10 +31,16 @@ pub enum AppPage {
     DappApproval,
     WalletConnect,
     WcProposal,
+    TonConnect,
     Approvals,
     AddressBook,
     Nft,
     Swap,
+    Networks,
+    Safe,
+    Tokens,
+    Verify,
+    Staking,
 }
 
 /// Wallet state shared across components
@@ -43,6 +55,8 @@ pub struct WalletState {
     pub prices: HashMap<String, f64>,
     pub token_balances: HashMap<String, Vec<TokenBalance>>,
     pub nfts: Vec<wallet_core::nft::NftItem>,
+    /// TON wallet contract the app shows and signs with
+    pub ton_version: WalletVersion,
 }
 
 impl Default for WalletState {
@@ -57,16 +71,26 @@ impl Default for WalletState {
             prices: HashMap::new(),
             token_balances: HashMap::new(),
             nfts: Vec::new(),
+            ton_version: WalletVersion::default(),
         }
     }
 }
 
 impl WalletState {
     pub fn current_address(&self) -> String {
-        self.addresses
-            .get(&self.active_chain)
-            .cloned()
-            .unwrap_or_default()
+        if self.active_chain == "ton" {
+            return self.addresses.get(self.ton_version.address_key()).cloned().unwrap_or_default();
+        }
+        if let Some(addr) = self.addresses.get(&self.active_chain) {
+            return addr.clone();
+        }
+        // Custom EVM networks share the Ethereum address
+        if custom_evm::parse_chain_key(&self.active_chain).is_some() {
+            return wallet_core::wallet::EVM_CHAIN_IDS.iter()
+                .find_map(|c| self.addresses.get(*c).cloned())
+                .unwrap_or_default();
+        }
+        String::new()
     }
 
     pub fn current_balance(&self) -> String {
@@ -87,41 +111,116 @@ pub struct ChainDisplay {
 
 /// Get the icon image path for a given chain id
 fn chain_icon_path(chain_id: &str) -> String {
+    if custom_evm::parse_chain_key(chain_id).is_some() {
+        return "chain-icons/ethereum.png".into();
+    }
     format!("chain-icons/{}.png", chain_id)
 }
 
+/// True for built-in EVM chains and user-defined EVM networks
+pub fn is_evm_chain(chain: &str) -> bool {
+    wallet_core::wallet::EVM_CHAIN_IDS.contains(&chain) || custom_evm::parse_chain_key(chain).is_some()
+}
+
+/// Built-in chain configs plus the user's custom EVM networks
+pub fn all_chains(testnet: bool) -> Vec<ChainConfig> {
+    custom_evm::merge_chains(testnet, &load_custom_networks())
+}
+
+/// Save user-defined EVM networks to storage
+pub fn save_custom_networks(networks: &[CustomEvmNetwork]) {
+    if let Ok(json) = serde_json::to_string(networks) {
+        save_to_storage("custom_evm_networks", &json);
+    }
+}
+
+/// Load user-defined EVM networks from storage
+pub fn load_custom_networks() -> Vec<CustomEvmNetwork> {
+    load_from_storage("custom_evm_networks")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save tokens imported by contract/mint address (all chains)
+pub fn save_custom_tokens(tokens: &[Token]) {
+    if let Ok(json) = serde_json::to_string(tokens) {
+        save_to_storage("custom_tokens", &json);
+    }
+}
+
+/// Load tokens imported by contract/mint address (all chains)
+pub fn load_custom_tokens() -> Vec<Token> {
+    load_from_storage("custom_tokens")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Default tokens of `chain` plus the ones the user imported
+pub fn tokens_for_chain(chain: &str) -> Vec<Token> {
+    custom_tokens::tokens_for_chain(chain, &load_custom_tokens())
+}
+
+fn approval_scan_key(chain: &str, owner: &str) -> String {
+    format!("approval_scan_{}_{}", chain, owner.to_lowercase())
+}
+
+/// Save the approval log scan progress of `owner` on `chain`
+pub fn save_approval_scan(chain: &str, owner: &str, scan: &ScanState) {
+    if let Ok(json) = serde_json::to_string(scan) {
+        save_to_storage(&approval_scan_key(chain, owner), &json);
+    }
+}
+
+/// Load the approval log scan progress of `owner` on `chain`
+pub fn load_approval_scan(chain: &str, owner: &str) -> ScanState {
+    load_from_storage(&approval_scan_key(chain, owner))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn ton_version_key(wallet_name: &str) -> String {
+    format!("ton_wallet_version_{}", wallet_name)
+}
+
+/// Save the TON wallet contract chosen for `wallet_name`
+pub fn save_ton_version(wallet_name: &str, version: WalletVersion) {
+    save_to_storage(&ton_version_key(wallet_name), version.id());
+}
+
+/// Load the TON wallet contract chosen for `wallet_name` (v4r2 if never chosen)
+pub fn load_ton_version(wallet_name: &str) -> WalletVersion {
+    load_from_storage(&ton_version_key(wallet_name))
+        .and_then(|id| WalletVersion::from_id(&id))
+        .unwrap_or_default()
+}
+
+/// Save the Safes tracked for co-signing
+pub fn save_watched_safes(safes: &[WatchedSafe]) {
+    if let Ok(json) = serde_json::to_string(safes) {
+        save_to_storage("watched_safes", &json);
+    }
+}
+
+/// Load the Safes tracked for co-signing
+pub fn load_watched_safes() -> Vec<WatchedSafe> {
+    load_from_storage("watched_safes")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
 pub fn chain_list() -> Vec<ChainDisplay> {
     chain_list_for(false)
 }
 
 /// Get chain display list for mainnet or testnet (reads from wallet-core config)
 pub fn chain_list_for(testnet: bool) -> Vec<ChainDisplay> {
-    use wallet_core::chains::ChainId;
-    let chains = get_chains(testnet);
-    chains.iter().map(|c| {
-        let id = match &c.id {
-            ChainId::Ethereum => "ethereum",
-            ChainId::Polygon => "polygon",
-            ChainId::Bsc => "bsc",
-            ChainId::Optimism => "optimism",
-            ChainId::Base => "base",
-            ChainId::Arbitrum => "arbitrum",
-            ChainId::Solana => "solana",
-            ChainId::Ton => "ton",
-            ChainId::CosmosHub => "cosmos",
-            ChainId::Osmosis => "osmosis",
-            ChainId::Bitcoin => "bitcoin",
-            ChainId::Litecoin => "litecoin",
-            ChainId::Stellar => "stellar",
-            ChainId::Ripple => "ripple",
-            ChainId::Dogecoin => "dogecoin",
-            ChainId::Tron => "tron",
-        };
+    all_chains(testnet).iter().map(|c| {
+        let id = crate::rpc::chain_id_str(&c.id);
         ChainDisplay {
-            id: id.into(),
+            icon_path: chain_icon_path(&id),
+            id,
             name: c.name.clone(),
             ticker: c.ticker.clone(),
-            icon_path: chain_icon_path(id),
         }
     }).collect()
 }
@@ -149,6 +248,47 @@ pub fn load_address_book() -> Vec<Contact> {
         .unwrap_or_default()
 }
 
+/// Save broadcast-but-unmined EVM transactions to storage
+pub fn save_pending_evm_txs(txs: &[PendingEvmTx]) {
+    if let Ok(json) = serde_json::to_string(txs) {
+        save_to_storage("pending_evm_txs", &json);
+    }
+}
+
+/// Load pending EVM transactions from storage
+pub fn load_pending_evm_txs() -> Vec<PendingEvmTx> {
+    load_from_storage("pending_evm_txs")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// A TON Connect session with a dApp
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TonConnectSession {
+    pub app_client_id: String,
+    /// Session secret key (hex), only used to encrypt bridge messages
+    pub secret: String,
+    pub name: String,
+    pub url: String,
+    pub icon_url: String,
+    /// Id of the next event the wallet sends to this dApp
+    pub next_event_id: u64,
+}
+
+/// Save TON Connect sessions to storage
+pub fn save_ton_connect_sessions(sessions: &[TonConnectSession]) {
+    if let Ok(json) = serde_json::to_string(sessions) {
+        save_to_storage("ton_connect_sessions", &json);
+    }
+}
+
+/// Load TON Connect sessions from storage
+pub fn load_ton_connect_sessions() -> Vec<TonConnectSession> {
+    load_from_storage("ton_connect_sessions")
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
 /// Detect if chrome.storage.local is available (extension context)
 pub fn has_chrome_storage() -> bool {
     if let Some(window) = web_sys::window() {
@@ -263,6 +403,10 @@ pub struct DappRequest {
     pub origin: String,
     pub method: String,
     pub params: String,
+    /// EVM chain the dApp targets (provider chainId or WalletConnect eip155:N)
+    pub evm_chain_id: Option<u64>,
+    /// Solana request aimed at devnet/testnet instead of mainnet
+    pub solana_testnet: bool,
 }
 
 /// Send a message to the background service worker via chrome.runtime.sendMessage
@@ -247,6 +247,7 @@ fn is_dark_color(hex: &str) -> bool {
 }
 
 /// Build full 17-var theme from 8 user-selected colors.
+#[allow(clippy::too_many_arguments)]
 pub fn build_full_custom_theme(
     bg_primary: &str,
     bg_secondary: &str,
@@ -49,6 +49,7 @@ pub async fn send(
 }
 
 /// Send CW-20 token via MsgExecuteContract
+#[allow(clippy::too_many_arguments)]
 pub async fn send_cw20(
     seed: &[u8; 64],
     to: &str,
@@ -68,9 +69,9 @@ pub async fn send_cw20(
     let prefix = if denom == "uatom" { "cosmos" } else { "osmo" };
     let from_address = wallet_core::chains::cosmos::derive_cosmos_address(seed, prefix, 118)?;
 
-    // Find token decimals from default list
+    // Find token decimals from the default and imported tokens
     let chain_str = super::chain_id_to_string(&chain_id);
-    let tokens = cw20::tokens_for_chain(&chain_str);
+    let tokens = crate::state::tokens_for_chain(&chain_str);
     let decimals = tokens.iter()
         .find(|t| t.address == token_address)
         .map(|t| t.decimals)
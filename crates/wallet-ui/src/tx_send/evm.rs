@@ -3,46 +3,69 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use wallet_core::tx::evm::*;
+use wallet_core::tx::eip7702::{self, AuthorizationEntry};
+use wallet_core::tx::evm_fees::{self, Eip1559Fees, FeeTier};
+use wallet_core::tx::evm_pending::{PendingEvmTx, PendingKind};
 use wallet_core::chains::evm;
 use super::chain_id_to_string;
 use zeroize::Zeroize;
 
-pub async fn send_native(
-    seed: &[u8; 64],
+/// Target, value and calldata of an outgoing EVM call
+pub struct EvmCall {
+    pub to: [u8; 20],
+    pub value: u128,
+    pub data: Vec<u8>,
+    /// Gas limit used when eth_estimateGas fails
+    pub gas_fallback: u64,
+}
+
+/// Build the call for a native transfer (empty `token_address`) or an ERC-20 transfer
+pub fn transfer_call(
     to: &str,
     amount: &str,
-    rpc_url: &str,
+    token_address: &str,
     config: &wallet_core::chains::ChainConfig,
-) -> Result<String, String> {
-    let mut private_key = evm::get_private_key(seed)?;
-    let from_address = evm::derive_evm_address(seed)?;
-
-    let to_bytes = parse_address(to)?;
-    let value = parse_ether_to_wei(amount)?;
+) -> Result<EvmCall, String> {
+    use wallet_core::tokens::erc20;
 
-    let nonce = crate::rpc::evm::get_nonce(&from_address, rpc_url).await?;
-    let gas_price = crate::rpc::evm::get_gas_price(rpc_url).await?;
-    let priority_fee = crate::rpc::evm::get_max_priority_fee(rpc_url).await
-        .unwrap_or(1_500_000_000);
+    if token_address.is_empty() {
+        return Ok(EvmCall {
+            to: parse_address(to)?,
+            value: parse_ether_to_wei(amount)?,
+            data: vec![],
+            gas_fallback: evm_fees::NATIVE_TRANSFER_GAS,
+        });
+    }
 
-    let evm_chain_id = config.evm_chain_id.ok_or("Missing EVM chain ID")?;
+    let tokens = crate::state::tokens_for_chain(&chain_id_to_string(&config.id));
+    let token = tokens.iter()
+        .find(|t| t.address.to_lowercase() == token_address.to_lowercase())
+        .ok_or("Token not found")?;
 
-    let tx = EvmTransaction {
-        chain_id_num: evm_chain_id,
-        nonce,
-        max_priority_fee_per_gas: priority_fee,
-        max_fee_per_gas: gas_price.saturating_mul(2),
-        gas_limit: 21000,
-        to: to_bytes,
-        value,
-        data: vec![],
-    };
+    Ok(EvmCall {
+        to: parse_address(token_address)?,
+        value: 0,
+        data: erc20::encode_transfer(to, amount, token.decimals)?,
+        gas_fallback: evm_fees::ERC20_TRANSFER_GAS_FALLBACK,
+    })
+}
 
-    let signed = tx.sign(&private_key, config.id.clone())?;
-    private_key.zeroize();
-    let raw_hex = format!("0x{}", hex::encode(&signed.raw_bytes));
+/// Estimate the gas limit of `call` sent from `from`
+pub async fn estimate_call_gas(from: &str, call: &EvmCall, rpc_url: &str) -> u64 {
+    let to = format!("0x{}", hex::encode(call.to));
+    crate::rpc::evm::estimate_gas_limit(from, &to, call.value, &call.data, call.gas_fallback, rpc_url).await
+}
 
-    crate::rpc::evm::send_raw_transaction(&raw_hex, rpc_url).await
+pub async fn send_native(
+    seed: &[u8; 64],
+    to: &str,
+    amount: &str,
+    fees: Option<Eip1559Fees>,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    let call = transfer_call(to, amount, "", config)?;
+    sign_and_send(seed, call, None, &[], fees, rpc_url, config).await
 }
 
 pub async fn send_erc20(
@@ -50,45 +73,12 @@ pub async fn send_erc20(
     to: &str,
     amount: &str,
     token_address: &str,
+    fees: Option<Eip1559Fees>,
     rpc_url: &str,
     config: &wallet_core::chains::ChainConfig,
 ) -> Result<String, String> {
-    use wallet_core::tokens::erc20;
-
-    let mut private_key = evm::get_private_key(seed)?;
-    let from_address = evm::derive_evm_address(seed)?;
-
-    let tokens = erc20::tokens_for_chain(&chain_id_to_string(&config.id));
-    let token = tokens.iter()
-        .find(|t| t.address.to_lowercase() == token_address.to_lowercase())
-        .ok_or("Token not found")?;
-
-    let data = erc20::encode_transfer(to, amount, token.decimals)?;
-    let contract_address = parse_address(token_address)?;
-
-    let nonce = crate::rpc::evm::get_nonce(&from_address, rpc_url).await?;
-    let gas_price = crate::rpc::evm::get_gas_price(rpc_url).await?;
-    let priority_fee = crate::rpc::evm::get_max_priority_fee(rpc_url).await
-        .unwrap_or(1_500_000_000);
-
-    let evm_chain_id = config.evm_chain_id.ok_or("Missing EVM chain ID")?;
-
-    let tx = EvmTransaction {
-        chain_id_num: evm_chain_id,
-        nonce,
-        max_priority_fee_per_gas: priority_fee,
-        max_fee_per_gas: gas_price.saturating_mul(2),
-        gas_limit: 65000,
-        to: contract_address,
-        value: 0,
-        data,
-    };
-
-    let signed = tx.sign(&private_key, config.id.clone())?;
-    private_key.zeroize();
-    let raw_hex = format!("0x{}", hex::encode(&signed.raw_bytes));
-
-    crate::rpc::evm::send_raw_transaction(&raw_hex, rpc_url).await
+    let call = transfer_call(to, amount, token_address, config)?;
+    sign_and_send(seed, call, None, &[], fees, rpc_url, config).await
 }
 
 /// Send a swap transaction with arbitrary calldata (from 0x API quote)
@@ -101,9 +91,13 @@ pub async fn send_swap_tx(
     rpc_url: &str,
     config: &wallet_core::chains::ChainConfig,
 ) -> Result<String, String> {
-    let mut private_key = evm::get_private_key(seed)?;
-    let from_address = evm::derive_evm_address(seed)?;
+    let call = swap_call(to, value, data, gas_limit)?;
+    sign_and_send(seed, call, None, &[], None, rpc_url, config).await
+}
 
+/// Build the call for an aggregator swap transaction.
+/// The quote's gas figure is only a fallback; the node estimate is authoritative.
+pub fn swap_call(to: &str, value: &str, data: &str, gas_limit: u64) -> Result<EvmCall, String> {
     let to_bytes = parse_address(to)?;
 
     // Parse value (decimal string or hex)
@@ -124,27 +118,240 @@ pub async fn send_swap_tx(
         vec![]
     };
 
-    let nonce = crate::rpc::evm::get_nonce(&from_address, rpc_url).await?;
-    let gas_price = crate::rpc::evm::get_gas_price(rpc_url).await?;
-    let priority_fee = crate::rpc::evm::get_max_priority_fee(rpc_url).await
-        .unwrap_or(1_500_000_000);
+    Ok(EvmCall {
+        to: to_bytes,
+        value: value_u128,
+        data: data_bytes,
+        gas_fallback: gas_limit,
+    })
+}
 
+/// Sign and broadcast an eth_sendTransaction request from a dApp.
+/// Honors the dApp's `gas` field; fees come from the user's selection.
+/// An `authorizationList` turns it into an EIP-7702 (type 4) transaction.
+pub async fn send_dapp_tx(
+    seed: &[u8; 64],
+    tx: &serde_json::Value,
+    fees: Option<Eip1559Fees>,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
     let evm_chain_id = config.evm_chain_id.ok_or("Missing EVM chain ID")?;
+    check_dapp_tx(tx, &evm::derive_evm_address(seed)?, evm_chain_id)?;
+    let call = dapp_call(tx)?;
+    let authorizations = eip7702::parse_authorization_list(tx)?;
+    let gas_limit = tx.get("gas")
+        .and_then(|v| v.as_str())
+        .and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+        .filter(|g| *g > 0);
+    sign_and_send(seed, call, gas_limit, &authorizations, fees, rpc_url, config).await
+}
+
+/// Reject an eth_sendTransaction object whose `from` is not our account or whose
+/// `chainId` (when given) is not the chain it would be signed for
+pub fn check_dapp_tx(tx: &serde_json::Value, from_address: &str, evm_chain_id: u64) -> Result<(), String> {
+    let from = tx.get("from")
+        .and_then(|v| v.as_str())
+        .ok_or("Missing from address")?;
+    if !from.eq_ignore_ascii_case(from_address) {
+        return Err(format!("Transaction sender {} is not the active account", from));
+    }
+    if let Some(chain_id) = tx.get("chainId") {
+        let chain_id = chain_id.as_u64()
+            .or_else(|| chain_id.as_str().and_then(|s| match s.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16).ok(),
+                None => s.parse().ok(),
+            }))
+            .ok_or("Invalid chainId")?;
+        if chain_id != evm_chain_id {
+            return Err(format!("Transaction chainId {} does not match the requested network {}", chain_id, evm_chain_id));
+        }
+    }
+    Ok(())
+}
+
+/// Parse the `to`, `value` and `data` (or `input`) fields of an eth_sendTransaction object
+pub fn dapp_call(tx: &serde_json::Value) -> Result<EvmCall, String> {
+    let to = tx.get("to")
+        .and_then(|v| v.as_str())
+        .ok_or("Contract deployment is not supported")?;
+    let value = match tx.get("value").and_then(|v| v.as_str()) {
+        Some(v) => u128::from_str_radix(v.trim_start_matches("0x"), 16)
+            .map_err(|_| "Invalid hex value")?,
+        None => 0,
+    };
+    let data_hex = tx.get("data")
+        .or_else(|| tx.get("input"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("0x");
+    let data = hex::decode(data_hex.trim_start_matches("0x"))
+        .map_err(|_| "Invalid calldata hex")?;
+    let gas_fallback = if data.is_empty() {
+        evm_fees::NATIVE_TRANSFER_GAS
+    } else {
+        evm_fees::CONTRACT_CALL_GAS_FALLBACK
+    };
+
+    Ok(EvmCall { to: parse_address(to)?, value, data, gas_fallback })
+}
+
+/// Sign and broadcast an arbitrary contract call with estimated gas and normal-tier fees
+pub async fn send_call(
+    seed: &[u8; 64],
+    call: EvmCall,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    sign_and_send(seed, call, None, &[], None, rpc_url, config).await
+}
+
+/// Sign and broadcast several calls back to back with consecutive nonces.
+/// A failed call does not consume its nonce, so later calls still go through.
+pub async fn send_calls(
+    seed: &[u8; 64],
+    calls: Vec<EvmCall>,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Vec<Result<String, String>> {
+    let prepared = async {
+        let from_address = evm::derive_evm_address(seed)?;
+        let evm_chain_id = config.evm_chain_id.ok_or("Missing EVM chain ID")?;
+        let fees = crate::rpc::evm::get_fee_estimate(rpc_url).await?.tier(FeeTier::Normal);
+        let nonce = crate::rpc::evm::get_pending_nonce(&from_address, rpc_url).await?;
+        Ok::<_, String>((from_address, evm_chain_id, fees, nonce))
+    }.await;
+    let (from_address, evm_chain_id, fees, mut nonce) = match prepared {
+        Ok(p) => p,
+        Err(e) => return calls.iter().map(|_| Err(e.clone())).collect(),
+    };
+
+    let mut results = Vec::with_capacity(calls.len());
+    for call in calls {
+        let gas_limit = estimate_call_gas(&from_address, &call, rpc_url).await;
+        let tx = EvmTransaction {
+            chain_id_num: evm_chain_id,
+            nonce,
+            max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+            max_fee_per_gas: fees.max_fee_per_gas,
+            gas_limit,
+            to: call.to,
+            value: call.value,
+            data: call.data,
+            authorization_list: Vec::new(),
+        };
+        let result = broadcast(seed, &tx, &from_address, PendingKind::Original, rpc_url, config).await;
+        if result.is_ok() {
+            nonce += 1;
+        }
+        results.push(result);
+    }
+    results
+}
+
+/// Resolve nonce, gas and fees, then sign and broadcast.
+/// `fees: None` uses the normal tier of the current fee estimate.
+async fn sign_and_send(
+    seed: &[u8; 64],
+    call: EvmCall,
+    gas_limit: Option<u64>,
+    authorizations: &[AuthorizationEntry],
+    fees: Option<Eip1559Fees>,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    let from_address = evm::derive_evm_address(seed)?;
+    let evm_chain_id = config.evm_chain_id.ok_or("Missing EVM chain ID")?;
+
+    let fees = match fees {
+        Some(f) => {
+            f.validate()?;
+            f
+        }
+        None => crate::rpc::evm::get_fee_estimate(rpc_url).await?.tier(FeeTier::Normal),
+    };
+    let gas_limit = match gas_limit {
+        Some(g) => g,
+        None => estimate_call_gas(&from_address, &call, rpc_url).await
+            + eip7702::PER_AUTHORIZATION_GAS * authorizations.len() as u64,
+    };
+    // "pending" so back-to-back sends don't collide with our own unmined txs
+    let nonce = crate::rpc::evm::get_pending_nonce(&from_address, rpc_url).await?;
+
+    let authorization_list = if authorizations.is_empty() {
+        Vec::new()
+    } else {
+        let mut private_key = evm::get_private_key(seed)?;
+        let signed = eip7702::sign_entries(authorizations, evm_chain_id, nonce, &private_key);
+        private_key.zeroize();
+        signed?
+    };
 
     let tx = EvmTransaction {
         chain_id_num: evm_chain_id,
         nonce,
-        max_priority_fee_per_gas: priority_fee,
-        max_fee_per_gas: gas_price.saturating_mul(2),
+        max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+        max_fee_per_gas: fees.max_fee_per_gas,
         gas_limit,
-        to: to_bytes,
-        value: value_u128,
-        data: data_bytes,
+        to: call.to,
+        value: call.value,
+        data: call.data,
+        authorization_list,
     };
 
-    let signed = tx.sign(&private_key, config.id.clone())?;
+    broadcast(seed, &tx, &from_address, PendingKind::Original, rpc_url, config).await
+}
+
+/// Re-sign a pending transaction with bumped fees (same nonce and payload)
+pub async fn speed_up(
+    seed: &[u8; 64],
+    pending: &PendingEvmTx,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    let market = market_fees(rpc_url).await;
+    let tx = pending.speed_up(market)?;
+    broadcast(seed, &tx, &pending.from, PendingKind::SpeedUp, rpc_url, config).await
+}
+
+/// Replace a pending transaction with a zero-value self-send at the same nonce
+pub async fn cancel(
+    seed: &[u8; 64],
+    pending: &PendingEvmTx,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    let market = market_fees(rpc_url).await;
+    let tx = pending.cancel(market)?;
+    broadcast(seed, &tx, &pending.from, PendingKind::Cancel, rpc_url, config).await
+}
+
+/// Current fast-tier fees, used as a floor for replacements when the market moved up
+async fn market_fees(rpc_url: &str) -> Option<Eip1559Fees> {
+    crate::rpc::evm::get_fee_estimate(rpc_url).await.ok().map(|e| e.tier(FeeTier::Fast))
+}
+
+/// Sign, broadcast and remember the transaction until it is mined
+async fn broadcast(
+    seed: &[u8; 64],
+    tx: &EvmTransaction,
+    from_address: &str,
+    kind: PendingKind,
+    rpc_url: &str,
+    config: &wallet_core::chains::ChainConfig,
+) -> Result<String, String> {
+    let mut private_key = evm::get_private_key(seed)?;
+    let signed = tx.sign(&private_key, config.id.clone());
     private_key.zeroize();
-    let raw_hex = format!("0x{}", hex::encode(&signed.raw_bytes));
+    let raw_hex = format!("0x{}", hex::encode(&signed?.raw_bytes));
+
+    let hash = crate::rpc::evm::send_raw_transaction(&raw_hex, rpc_url).await?;
+
+    let now = (js_sys::Date::now() / 1000.0) as u64;
+    let mut pending = crate::state::load_pending_evm_txs();
+    pending.push(PendingEvmTx::from_transaction(
+        tx, &hash, &chain_id_to_string(&config.id), from_address, kind, now,
+    ));
+    crate::state::save_pending_evm_txs(&pending);
 
-    crate::rpc::evm::send_raw_transaction(&raw_hex, rpc_url).await
+    Ok(hash)
 }
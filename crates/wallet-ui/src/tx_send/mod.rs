@@ -12,8 +12,12 @@ pub mod stellar;
 pub mod ripple;
 pub mod dogecoin;
 pub mod tron;
+pub mod safe;
+pub mod nft;
 
-use wallet_core::chains::ChainId;
+use wallet_core::chains::{ChainConfig, ChainId};
+use wallet_core::chains::ton::WalletVersion;
+use wallet_core::tx::evm_fees::Eip1559Fees;
 use crate::logging::{log_info, log_error};
 
 pub fn chain_id_to_string(id: &ChainId) -> String {
@@ -34,6 +38,7 @@ pub fn chain_id_to_string(id: &ChainId) -> String {
         ChainId::Ripple => "ripple",
         ChainId::Dogecoin => "dogecoin",
         ChainId::Tron => "tron",
+        ChainId::CustomEvm(n) => return wallet_core::chains::custom_evm::chain_key(*n),
     }.to_string()
 }
 
@@ -58,18 +63,40 @@ fn decrypt_seed(password: &str) -> Result<[u8; 64], String> {
     Ok(seed)
 }
 
+/// TON wallet contract chosen for the active wallet
+fn active_ton_version() -> WalletVersion {
+    crate::state::load_from_storage("wallet_store")
+        .and_then(|json| serde_json::from_str::<wallet_core::wallet::WalletStore>(&json).ok())
+        .and_then(|store| store.wallets.get(store.active_index).map(|w| crate::state::load_ton_version(&w.name)))
+        .unwrap_or_default()
+}
+
 /// Execute send for any chain — main dispatch
 pub async fn execute_send(chain: &str, to: &str, amount: &str, password: &str, token_address: &str) -> Result<String, String> {
-    execute_send_for_network(chain, to, amount, password, token_address, false).await
+    execute_send_for_network(chain, to, amount, password, token_address, "", false, None, None).await
 }
 
-/// Execute send with network selection (mainnet/testnet)
-pub async fn execute_send_for_network(chain: &str, to: &str, amount: &str, password: &str, token_address: &str, testnet: bool) -> Result<String, String> {
+/// Execute send with network selection (mainnet/testnet).
+/// `evm_fees` overrides the estimated EIP-1559 fees on EVM chains, `solana_priority_fee`
+/// the compute unit price (micro-lamports) on Solana. `memo` is attached as a Memo
+/// instruction on Solana and as a text comment on TON.
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_send_for_network(
+    chain: &str,
+    to: &str,
+    amount: &str,
+    password: &str,
+    token_address: &str,
+    memo: &str,
+    testnet: bool,
+    evm_fees: Option<Eip1559Fees>,
+    solana_priority_fee: Option<u64>,
+) -> Result<String, String> {
     use zeroize::Zeroize;
     log_info!("TX send: chain={}, to={}...{}, amount={}, testnet={}", chain, &to[..6.min(to.len())], &to[to.len().saturating_sub(4)..], amount, testnet);
     let mut seed = decrypt_seed(password)?;
 
-    let chains = wallet_core::chains::get_chains(testnet);
+    let chains = crate::state::all_chains(testnet);
     let config = chains.iter()
         .find(|c| chain_id_to_string(&c.id) == chain)
         .ok_or("Unknown chain")?;
@@ -77,19 +104,19 @@ pub async fn execute_send_for_network(chain: &str, to: &str, amount: &str, passw
         .ok_or("No RPC URL")?;
 
     let result = match chain {
-        "ethereum" | "polygon" | "bsc" | "optimism" | "base" | "arbitrum" => {
+        c if crate::state::is_evm_chain(c) => {
             if token_address.is_empty() {
-                evm::send_native(&seed, to, amount, rpc_url, config).await
+                evm::send_native(&seed, to, amount, evm_fees, rpc_url, config).await
             } else {
-                evm::send_erc20(&seed, to, amount, token_address, rpc_url, config).await
+                evm::send_erc20(&seed, to, amount, token_address, evm_fees, rpc_url, config).await
             }
         }
-        "solana" => solana::send(&seed, to, amount, rpc_url).await,
+        "solana" => solana::send(&seed, to, amount, token_address, memo, solana_priority_fee, rpc_url).await,
         "ton" => {
             if token_address.is_empty() {
-                ton::send(&seed, to, amount, rpc_url).await
+                ton::send(&seed, active_ton_version(), testnet, to, amount, memo, rpc_url).await
             } else {
-                ton::send_jetton(&seed, to, amount, token_address, rpc_url).await
+                ton::send_jetton(&seed, active_ton_version(), testnet, to, amount, memo, token_address, rpc_url).await
             }
         }
         "cosmos" => {
@@ -124,6 +151,180 @@ pub async fn execute_send_for_network(chain: &str, to: &str, amount: &str, passw
     result
 }
 
+/// Decrypt the seed once and send a batch of contract calls on an EVM chain
+/// (e.g. bulk approval revocation). One result per call, in order.
+pub async fn execute_contract_calls(
+    chain: &str,
+    calls: Vec<evm::EvmCall>,
+    password: &str,
+    testnet: bool,
+) -> Result<Vec<Result<String, String>>, String> {
+    use zeroize::Zeroize;
+    let chains = crate::state::all_chains(testnet);
+    let config = chains.iter()
+        .find(|c| chain_id_to_string(&c.id) == chain)
+        .ok_or("Unknown chain")?;
+    let rpc_url = config.rpc_urls.first().ok_or("No RPC URL")?;
+
+    let mut seed = decrypt_seed(password)?;
+    let count = calls.len();
+    let results = evm::send_calls(&seed, calls, rpc_url, config).await;
+    seed.zeroize();
+    let sent = results.iter().filter(|r| r.is_ok()).count();
+    log_info!("Batch on {}: {}/{} calls sent", chain, sent, count);
+    for e in results.iter().filter_map(|r| r.as_ref().err()) {
+        log_error!("Batch call failed: {}", e);
+    }
+    Ok(results)
+}
+
+/// Find the EVM chain config for a numeric chain id (mainnet or testnet)
+pub fn evm_config_for_chain_id(evm_chain_id: u64) -> Option<ChainConfig> {
+    [false, true].into_iter()
+        .flat_map(crate::state::all_chains)
+        .find(|c| c.evm_chain_id == Some(evm_chain_id))
+}
+
+/// Sign and broadcast a dApp eth_sendTransaction request on the given EVM chain
+pub async fn execute_dapp_transaction(
+    tx: &serde_json::Value,
+    evm_chain_id: u64,
+    password: &str,
+    fees: Option<Eip1559Fees>,
+) -> Result<String, String> {
+    use zeroize::Zeroize;
+    let config = evm_config_for_chain_id(evm_chain_id)
+        .ok_or_else(|| format!("Unsupported EVM chain id {}", evm_chain_id))?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or("No RPC URL")?;
+    log_info!("dApp TX: chain={}, evm_chain_id={}", chain_id_to_string(&config.id), evm_chain_id);
+
+    let mut seed = decrypt_seed(password)?;
+    let result = evm::send_dapp_tx(&seed, tx, fees, rpc_url, &config).await;
+    seed.zeroize();
+    match &result {
+        Ok(hash) => log_info!("dApp TX success: {}", hash),
+        Err(e) => log_error!("dApp TX failed: {}", e),
+    }
+    result
+}
+
+/// Sign a Solana dApp request (`solana_*` method) with the active wallet.
+/// `origin_host` is the requesting site, used as the default Sign-In With Solana domain.
+pub async fn execute_solana_dapp_request(
+    method: &str,
+    params: &serde_json::Value,
+    password: &str,
+    testnet: bool,
+    origin_host: &str,
+) -> Result<serde_json::Value, String> {
+    use zeroize::Zeroize;
+    let chains = crate::state::all_chains(testnet);
+    let config = chains.iter()
+        .find(|c| c.id == ChainId::Solana)
+        .ok_or("Unknown chain")?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or("No RPC URL")?;
+    log_info!("dApp Solana request: method={}, testnet={}", method, testnet);
+
+    let mut seed = decrypt_seed(password)?;
+    let result = solana::dapp_request(&seed, method, params, origin_host, rpc_url).await;
+    seed.zeroize();
+    if let Err(e) = &result {
+        log_error!("dApp Solana request failed: {}", e);
+    }
+    result
+}
+
+/// Build the TON Connect reply items for a connect request with the active wallet
+pub fn execute_ton_connect(
+    request: &wallet_core::tonconnect::ConnectRequest,
+    manifest: &wallet_core::tonconnect::AppManifest,
+    password: &str,
+    testnet: bool,
+) -> Result<Vec<serde_json::Value>, String> {
+    use zeroize::Zeroize;
+    let mut seed = decrypt_seed(password)?;
+    let result = ton::connect_items(&seed, active_ton_version(), request, manifest, testnet);
+    seed.zeroize();
+    result
+}
+
+/// Sign and broadcast a TON Connect `sendTransaction` request with the active wallet
+pub async fn execute_ton_connect_transaction(
+    request: &wallet_core::tonconnect::TransactionRequest,
+    password: &str,
+    testnet: bool,
+) -> Result<String, String> {
+    use zeroize::Zeroize;
+    let chains = crate::state::all_chains(testnet);
+    let config = chains.iter()
+        .find(|c| c.id == ChainId::Ton)
+        .ok_or("Unknown chain")?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or("No RPC URL")?;
+    log_info!("TON Connect transaction: {} messages, testnet={}", request.messages.len(), testnet);
+
+    let mut seed = decrypt_seed(password)?;
+    let result = ton::send_connect_transaction(&seed, active_ton_version(), request, testnet, rpc_url).await;
+    seed.zeroize();
+    if let Err(e) = &result {
+        log_error!("TON Connect transaction failed: {}", e);
+    }
+    result
+}
+
+/// Run a native staking operation with the active wallet's Solana account
+pub async fn execute_stake_operation(
+    operation: &wallet_core::tx::solana_stake::StakeOperation,
+    password: &str,
+    testnet: bool,
+) -> Result<String, String> {
+    use zeroize::Zeroize;
+    let chains = crate::state::all_chains(testnet);
+    let config = chains.iter()
+        .find(|c| c.id == ChainId::Solana)
+        .ok_or("Unknown chain")?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or("No RPC URL")?;
+    log_info!("Stake operation: {:?}, testnet={}", operation, testnet);
+
+    let mut seed = decrypt_seed(password)?;
+    let result = solana::stake(&seed, operation, rpc_url).await;
+    seed.zeroize();
+    match &result {
+        Ok(signature) => log_info!("Stake operation sent: {}", signature),
+        Err(e) => log_error!("Stake operation failed: {}", e),
+    }
+    result
+}
+
+/// Speed up (`cancel = false`) or cancel a tracked pending EVM transaction
+pub async fn execute_replacement(hash: &str, cancel: bool, password: &str) -> Result<String, String> {
+    use zeroize::Zeroize;
+    let pending = crate::state::load_pending_evm_txs().into_iter()
+        .find(|p| p.hash.eq_ignore_ascii_case(hash))
+        .ok_or("Pending transaction not found")?;
+    let config = evm_config_for_chain_id(pending.evm_chain_id)
+        .ok_or_else(|| format!("Unsupported EVM chain id {}", pending.evm_chain_id))?;
+    let rpc_url = config.rpc_urls.first()
+        .ok_or("No RPC URL")?;
+    log_info!("TX {}: nonce={}, replacing {}", if cancel { "cancel" } else { "speed-up" }, pending.nonce, hash);
+
+    let mut seed = decrypt_seed(password)?;
+    let result = if cancel {
+        evm::cancel(&seed, &pending, rpc_url, &config).await
+    } else {
+        evm::speed_up(&seed, &pending, rpc_url, &config).await
+    };
+    seed.zeroize();
+    match &result {
+        Ok(new_hash) => log_info!("Replacement sent: {}", new_hash),
+        Err(e) => log_error!("Replacement failed: {}", e),
+    }
+    result
+}
+
 pub fn base64_simple_encode(data: &[u8]) -> String {
     const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
     let mut result = String::new();
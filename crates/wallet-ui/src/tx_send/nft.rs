@@ -0,0 +1,88 @@
+// Rusby Wallet — NFT transfers (ERC-721, ERC-1155, Solana Metaplex)
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use wallet_core::chains::solana as sol_chain;
+use wallet_core::chains::ChainConfig;
+use wallet_core::nft::{self, NftItem, NftStandard};
+use wallet_core::tx::evm::parse_address;
+use wallet_core::tx::evm_fees;
+use wallet_core::tx::solana::{compile_legacy_message, parse_pubkey, sign_message, unsigned_transaction};
+use zeroize::Zeroize;
+
+use super::decrypt_seed;
+use super::evm::EvmCall;
+use crate::logging::{log_info, log_error};
+
+/// Contract call moving `amount` of `item` (always 1 for ERC-721)
+pub fn evm_call(item: &NftItem, from: &str, to: &str, amount: u128) -> Result<EvmCall, String> {
+    let data = match NftStandard::from_item(item) {
+        Some(NftStandard::Erc721) => nft::encode_erc721_safe_transfer_from(from, to, &item.token_id)?,
+        Some(NftStandard::Erc1155) => nft::encode_erc1155_safe_transfer_from(from, to, &item.token_id, amount)?,
+        _ => return Err(format!("Unsupported token standard: {}", item.token_standard)),
+    };
+    Ok(EvmCall {
+        to: parse_address(&item.contract_address)?,
+        value: 0,
+        data,
+        gas_fallback: evm_fees::CONTRACT_CALL_GAS_FALLBACK,
+    })
+}
+
+/// Legacy message transferring a Solana NFT (mint = `item.token_id`).
+/// Reads the metadata account to pick SPL or programmable (Token Metadata) transfer.
+pub async fn solana_message(item: &NftItem, owner: &str, to: &str, rpc_url: &str) -> Result<Vec<u8>, String> {
+    let owner = parse_pubkey(owner)?;
+    let mint = parse_pubkey(&item.token_id)?;
+    let recipient = parse_pubkey(to)?;
+    let metadata_address = bs58::encode(nft::metadata_address(&mint)?).into_string();
+    let account = crate::rpc::solana::get_account_info(&metadata_address, rpc_url).await?;
+    let metadata = nft::parse_metadata_account_info(&account)?;
+    let instructions = nft::solana_nft_transfer_instructions(&owner, &mint, &recipient, &metadata)?;
+
+    let blockhash = crate::rpc::solana::get_latest_blockhash(rpc_url).await?;
+    let blockhash: [u8; 32] = bs58::decode(&blockhash).into_vec()
+        .map_err(|e| format!("Invalid blockhash: {}", e))?
+        .try_into()
+        .map_err(|_| "Invalid blockhash length".to_string())?;
+    compile_legacy_message(&owner, &instructions, &blockhash)
+}
+
+/// Simulate a Solana NFT transfer; `Some(reason)` if it would fail
+pub async fn simulate_solana(item: &NftItem, owner: &str, to: &str, rpc_url: &str) -> Result<Option<String>, String> {
+    let message = solana_message(item, owner, to, rpc_url).await?;
+    let tx = bs58::encode(unsigned_transaction(&message)).into_string();
+    crate::rpc::solana::simulate_transaction(&tx, rpc_url).await
+}
+
+/// Decrypt the seed and send `amount` of `item` to `to`
+pub async fn send(item: &NftItem, to: &str, amount: u128, password: &str, config: &ChainConfig) -> Result<String, String> {
+    let rpc_url = config.rpc_urls.first().ok_or("No RPC URL")?.clone();
+    let mut seed = decrypt_seed(password)?;
+    let result = async {
+        match NftStandard::from_item(item) {
+            Some(NftStandard::Metaplex) => {
+                let mut keypair = sol_chain::get_keypair(&seed)?;
+                let mut private_key: [u8; 32] = keypair[..32].try_into().unwrap();
+                let owner = bs58::encode(&keypair[32..]).into_string();
+                keypair.zeroize();
+                let message = solana_message(item, &owner, to, &rpc_url).await;
+                let signed = message.map(|m| sign_message(&m, &private_key));
+                private_key.zeroize();
+                let signed_b58 = bs58::encode(&signed?.raw_bytes).into_string();
+                crate::rpc::solana::send_transaction(&signed_b58, &rpc_url).await
+            }
+            _ => {
+                let from = wallet_core::chains::evm::derive_evm_address(&seed)?;
+                let call = evm_call(item, &from, to, amount)?;
+                super::evm::send_call(&seed, call, &rpc_url, config).await
+            }
+        }
+    }.await;
+    seed.zeroize();
+    match &result {
+        Ok(hash) => log_info!("NFT sent: {} #{} → {}, tx={}", item.contract_address, item.token_id, to, hash),
+        Err(e) => log_error!("NFT send failed: {}", e),
+    }
+    result
+}
@@ -0,0 +1,97 @@
+// Rusby Wallet — Safe multisig proposal, co-signing and execution
+// Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use wallet_core::chains::evm;
+use wallet_core::tx::evm::parse_address;
+use wallet_core::tx::evm_fees;
+use wallet_core::tx::safe::{self, PendingSafeTx, SafeSignature, SafeTransaction, WatchedSafe};
+use zeroize::Zeroize;
+
+use super::evm::{transfer_call, EvmCall};
+use super::{decrypt_seed, evm_config_for_chain_id};
+use crate::logging::{log_info, log_error};
+use crate::rpc::safe::SafeInfo;
+
+/// Propose a native-coin transfer out of the Safe, signed by us as first owner
+pub async fn propose_transfer(
+    watched: &WatchedSafe,
+    info: &SafeInfo,
+    pending: &[PendingSafeTx],
+    to: &str,
+    amount: &str,
+    password: &str,
+) -> Result<u64, String> {
+    let config = evm_config_for_chain_id(watched.evm_chain_id)
+        .ok_or_else(|| format!("Unsupported EVM chain id {}", watched.evm_chain_id))?;
+    let call = transfer_call(to, amount, "", &config)?;
+    let nonce = safe::next_nonce(info.nonce, pending);
+    let tx = SafeTransaction::call(call.to, call.value, call.data, nonce);
+
+    let signature = sign(watched, &tx, info, password)?;
+    crate::rpc::safe::propose(watched, &tx, &signature).await?;
+    log_info!("Safe proposal: safe={}, nonce={}", watched.address, nonce);
+    Ok(nonce)
+}
+
+/// Add our signature to a queued transaction
+pub async fn confirm(watched: &WatchedSafe, info: &SafeInfo, pending: &PendingSafeTx, password: &str) -> Result<(), String> {
+    let signature = sign(watched, &pending.tx, info, password)?;
+    crate::rpc::safe::confirm(watched, &pending.safe_tx_hash, &signature).await?;
+    log_info!("Safe confirmation: safe={}, nonce={}", watched.address, pending.tx.nonce);
+    Ok(())
+}
+
+/// Submit execTransaction from our EOA. If we haven't signed yet, our approval
+/// is included as a pre-validated signature since we are msg.sender.
+pub async fn execute(watched: &WatchedSafe, info: &SafeInfo, pending: &PendingSafeTx, password: &str) -> Result<String, String> {
+    if pending.tx.nonce != info.nonce {
+        return Err(format!("Execute nonce {} first", info.nonce));
+    }
+    let config = evm_config_for_chain_id(watched.evm_chain_id)
+        .ok_or_else(|| format!("Unsupported EVM chain id {}", watched.evm_chain_id))?;
+    let rpc_url = config.rpc_urls.first().ok_or("No RPC URL")?.clone();
+
+    let mut seed = decrypt_seed(password)?;
+    let result = async {
+        let me = parse_address(&evm::derive_evm_address(&seed)?)?;
+        if !info.owners.contains(&me) {
+            return Err("This wallet is not an owner of the Safe".to_string());
+        }
+        let mut signatures: Vec<SafeSignature> = pending.confirmations.iter()
+            .filter(|c| info.owners.contains(&c.owner))
+            .cloned()
+            .collect();
+        if !pending.is_signed_by(&me) {
+            signatures.push(SafeSignature::approved_hash(me));
+        }
+        if (signatures.len() as u64) < info.threshold {
+            return Err(format!("{} of {} signatures", signatures.len(), info.threshold));
+        }
+        let call = EvmCall {
+            to: parse_address(&watched.address)?,
+            value: 0,
+            data: pending.tx.encode_exec_transaction(&safe::pack_signatures(&signatures)),
+            gas_fallback: evm_fees::CONTRACT_CALL_GAS_FALLBACK,
+        };
+        super::evm::send_call(&seed, call, &rpc_url, &config).await
+    }.await;
+    seed.zeroize();
+    match &result {
+        Ok(hash) => log_info!("Safe executed: nonce={}, tx={}", pending.tx.nonce, hash),
+        Err(e) => log_error!("Safe execution failed: {}", e),
+    }
+    result
+}
+
+fn sign(watched: &WatchedSafe, tx: &SafeTransaction, info: &SafeInfo, password: &str) -> Result<SafeSignature, String> {
+    let safe_addr = parse_address(&watched.address)?;
+    let mut seed = decrypt_seed(password)?;
+    let signature = tx.sign_with_seed(&safe_addr, watched.evm_chain_id, &seed);
+    seed.zeroize();
+    let signature = signature?;
+    if !info.owners.contains(&signature.owner) {
+        return Err("This wallet is not an owner of the Safe".into());
+    }
+    Ok(signature)
+}
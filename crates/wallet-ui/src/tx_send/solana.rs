@@ -3,41 +3,314 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use wallet_core::tx::solana::*;
+use wallet_core::tx::evm_fees::FeeTier;
+use wallet_core::tx::solana_dapp;
+use wallet_core::tx::solana_fees;
+use wallet_core::tx::solana_message::Transaction;
+use wallet_core::tx::solana_stake::{self, StakeOperation};
 use wallet_core::chains::solana as sol_chain;
+use wallet_core::security::siws::SiwsMessage;
+use wallet_core::tokens::{spl, token_2022};
 use zeroize::Zeroize;
 
-pub async fn send(
-    seed: &[u8; 64],
+use crate::logging::log_info;
+
+/// Seeds probed for a new stake account before giving up
+const MAX_STAKE_SEEDS: u32 = 256;
+
+async fn recent_blockhash(rpc_url: &str) -> Result<[u8; 32], String> {
+    let blockhash_b58 = crate::rpc::solana::get_latest_blockhash(rpc_url).await?;
+    bs58::decode(&blockhash_b58).into_vec()
+        .map_err(|e| format!("Invalid blockhash: {}", e))?
+        .try_into()
+        .map_err(|_| "Invalid blockhash length".to_string())
+}
+
+/// Rent of `owner`'s associated token account for `mint` when it doesn't exist yet
+/// (the sender pays it when the transfer creates the account). Token-2022 accounts
+/// are larger than 165 bytes, depending on the mint's extensions.
+pub async fn new_token_account_rent(owner: &str, mint: &str, rpc_url: &str) -> Result<Option<u64>, String> {
+    let mint_info = spl::parse_mint_info(&crate::rpc::solana::get_account_info(mint, rpc_url).await?)?;
+    let ata = spl::associated_token_address(&parse_pubkey(owner)?, &parse_pubkey(mint)?, &mint_info.token_program)?;
+    let account = crate::rpc::solana::get_account_info(&bs58::encode(ata).into_string(), rpc_url).await?;
+    Ok(account.is_null().then(|| mint_info.extensions.token_account_rent()))
+}
+
+/// Instructions for a SOL transfer, or for an SPL `TransferChecked` of `mint`
+/// that creates the recipient's associated token account if needed. Decimals,
+/// token program and Token-2022 extensions are read from the mint, so the amount
+/// can't be mis-scaled. Also returns the token transfer fee withheld by the mint.
+async fn transfer_instructions(
+    owner: &[u8; 32],
+    to: &str,
+    amount: &str,
+    mint: &str,
+    memo: &str,
+    rpc_url: &str,
+) -> Result<(Vec<Instruction>, u64), String> {
+    let recipient = parse_pubkey(to)?;
+    let memo = Some(memo.trim()).filter(|m| !m.is_empty());
+    if mint.is_empty() {
+        let lamports = parse_sol_to_lamports(amount)?;
+        let mut instructions: Vec<Instruction> = memo.map(|m| spl::memo_instruction(owner, m)).into_iter().collect();
+        instructions.push(system_transfer(*owner, recipient, lamports));
+        return Ok((instructions, 0));
+    }
+
+    let mint_key = parse_pubkey(mint)?;
+    let mint_info = spl::parse_mint_info(&crate::rpc::solana::get_account_info(mint, rpc_url).await?)?;
+    // A pasted token account would get its own ATA, which nobody controls
+    let recipient_account = crate::rpc::solana::get_account_info(to, rpc_url).await?;
+    if spl::is_token_program_account(&recipient_account) {
+        return Err("Recipient is a token account: enter the owner's wallet address".into());
+    }
+    let destination = spl::associated_token_address(&recipient, &mint_key, &mint_info.token_program)?;
+    let destination_account = crate::rpc::solana::get_account_info(&bs58::encode(destination).into_string(), rpc_url).await?;
+    token_2022::check_memo_requirement(&destination_account, memo)?;
+
+    let raw_amount = spl::parse_amount(amount, mint_info.decimals)?;
+    let (epoch, token_fee) = match &mint_info.extensions.transfer_fee {
+        Some(config) => {
+            let epoch = crate::rpc::solana::get_epoch(rpc_url).await?;
+            (epoch, config.calculate_fee(epoch, raw_amount))
+        }
+        None => (0, 0),
+    };
+    let instructions = spl::mint_transfer_instructions(
+        owner, &mint_key, &recipient, raw_amount, &mint_info, epoch, memo, !destination_account.is_null(),
+    )?;
+    Ok((instructions, token_fee))
+}
+
+fn system_transfer(from_pubkey: [u8; 32], to_pubkey: [u8; 32], lamports: u64) -> Instruction {
+    SolanaTransfer { from_pubkey, to_pubkey, lamports, recent_blockhash: [0u8; 32] }.instruction()
+}
+
+/// Compile `instructions` behind a compute budget: the unit limit comes from
+/// simulating them, the price is `micro_lamports` per CU. Returns the message and limit.
+async fn budgeted_message(
+    payer: &[u8; 32],
+    instructions: &[Instruction],
+    micro_lamports: u64,
+    recent_blockhash: &[u8; 32],
+    rpc_url: &str,
+) -> Result<(Vec<u8>, u32), String> {
+    // Simulate with the same instruction list so the budget instructions are counted too
+    let probe = solana_fees::with_compute_budget(instructions, solana_fees::MAX_COMPUTE_UNITS, micro_lamports);
+    let probe_tx = unsigned_transaction(&compile_legacy_message(payer, &probe, recent_blockhash)?);
+    let units = crate::rpc::solana::simulate_compute_units(&bs58::encode(probe_tx).into_string(), rpc_url).await?;
+    let limit = solana_fees::compute_unit_limit(units);
+    let budgeted = solana_fees::with_compute_budget(instructions, limit, micro_lamports);
+    Ok((compile_legacy_message(payer, &budgeted, recent_blockhash)?, limit))
+}
+
+/// Simulated cost of a send
+pub struct SendEstimate {
+    /// Network fee in lamports
+    pub fee_lamports: u64,
+    pub compute_units: u32,
+    /// Token-2022 transfer fee withheld from the amount, in token base units
+    pub token_fee: u64,
+}
+
+/// Network fee and compute unit limit of a send, from simulation
+pub async fn estimate_fee(
+    from: &str,
     to: &str,
     amount: &str,
+    mint: &str,
+    memo: &str,
+    micro_lamports: u64,
+    rpc_url: &str,
+) -> Result<SendEstimate, String> {
+    let owner = parse_pubkey(from)?;
+    let (instructions, token_fee) = transfer_instructions(&owner, to, amount, mint, memo, rpc_url).await?;
+    // The simulation replaces the blockhash, so none is fetched
+    let (_, limit) = budgeted_message(&owner, &instructions, micro_lamports, &[0u8; 32], rpc_url).await?;
+    Ok(SendEstimate {
+        fee_lamports: solana_fees::total_fee_lamports(1, limit as u64, micro_lamports),
+        compute_units: limit,
+        token_fee,
+    })
+}
+
+/// The wallet's Solana public key
+fn wallet_pubkey(seed: &[u8; 64]) -> Result<[u8; 32], String> {
+    let mut keypair = sol_chain::get_keypair(seed)?;
+    let pubkey: [u8; 32] = keypair[32..].try_into().unwrap();
+    keypair.zeroize();
+    Ok(pubkey)
+}
+
+/// Sign `instructions` paid by the wallet behind a simulated compute budget and
+/// broadcast them. `micro_lamports: None` uses the normal tier of recent fees.
+async fn sign_and_send(
+    seed: &[u8; 64],
+    instructions: &[Instruction],
+    micro_lamports: Option<u64>,
     rpc_url: &str,
 ) -> Result<String, String> {
+    let owner = wallet_pubkey(seed)?;
+    let price = match micro_lamports {
+        Some(price) => price,
+        None => normal_priority_fee(&owner, rpc_url).await,
+    };
+    let (message, _) = budgeted_message(&owner, instructions, price, &recent_blockhash(rpc_url).await?, rpc_url).await?;
+
     let mut keypair = sol_chain::get_keypair(seed)?;
     let mut private_key: [u8; 32] = keypair[..32].try_into().unwrap();
-    let from_pubkey: [u8; 32] = keypair[32..].try_into().unwrap();
     keypair.zeroize();
-    let to_pubkey = parse_pubkey(to)?;
-    let lamports = parse_sol_to_lamports(amount)?;
+    let signed = sign_message(&message, &private_key);
+    private_key.zeroize();
+    let signed_b58 = bs58::encode(&signed.raw_bytes).into_string();
 
-    let blockhash_b58 = crate::rpc::solana::get_latest_blockhash(rpc_url).await?;
-    let blockhash_bytes = bs58::decode(&blockhash_b58).into_vec()
-        .map_err(|e| format!("Invalid blockhash: {}", e))?;
-    let mut recent_blockhash = [0u8; 32];
-    if blockhash_bytes.len() != 32 {
-        return Err("Invalid blockhash length".into());
+    crate::rpc::solana::send_transaction(&signed_b58, rpc_url).await
+}
+
+/// Send SOL, or the SPL token `mint` when set, with an optional memo, a compute unit
+/// limit and priority fee. `micro_lamports: None` uses the normal tier of recent fees.
+pub async fn send(
+    seed: &[u8; 64],
+    to: &str,
+    amount: &str,
+    mint: &str,
+    memo: &str,
+    micro_lamports: Option<u64>,
+    rpc_url: &str,
+) -> Result<String, String> {
+    let owner = wallet_pubkey(seed)?;
+    let (instructions, _) = transfer_instructions(&owner, to, amount, mint, memo, rpc_url).await?;
+    sign_and_send(seed, &instructions, micro_lamports, rpc_url).await
+}
+
+/// Run a staking operation on the wallet's stake accounts
+pub async fn stake(seed: &[u8; 64], operation: &StakeOperation, rpc_url: &str) -> Result<String, String> {
+    let instructions = operation.instructions(&wallet_pubkey(seed)?)?;
+    sign_and_send(seed, &instructions, None, rpc_url).await
+}
+
+/// First `stake:N` seed of `owner` whose account does not exist yet
+pub async fn next_stake_seed(owner: &str, rpc_url: &str) -> Result<String, String> {
+    let base = parse_pubkey(owner)?;
+    for index in 0..MAX_STAKE_SEEDS {
+        let seed = solana_stake::stake_seed(index);
+        let address = solana_stake::stake_account_address(&base, &seed)?;
+        if crate::rpc::solana::get_account_info(&bs58::encode(address).into_string(), rpc_url).await?.is_null() {
+            return Ok(seed);
+        }
     }
-    recent_blockhash.copy_from_slice(&blockhash_bytes);
+    Err("No free stake account seed".into())
+}
 
-    let transfer = SolanaTransfer {
-        from_pubkey,
-        to_pubkey,
-        lamports,
-        recent_blockhash,
-    };
+/// Normal-tier price for transactions paid by `payer`; zero if the node has no data
+async fn normal_priority_fee(payer: &[u8; 32], rpc_url: &str) -> u64 {
+    let accounts = [bs58::encode(payer).into_string()];
+    match crate::rpc::solana::get_priority_fees(&accounts, rpc_url).await {
+        Ok(estimate) => estimate.tier(FeeTier::Normal),
+        Err(e) => {
+            log_info!("Solana priority fees unavailable, sending without: {}", e);
+            0
+        }
+    }
+}
+
+/// `params.transaction` (base64) of signTransaction / signAndSendTransaction
+fn transaction_param(params: &serde_json::Value) -> Result<&str, String> {
+    params.get("transaction").and_then(|t| t.as_str()).ok_or_else(|| "Missing transaction".to_string())
+}
+
+/// Add our signature to a dApp transaction and return it with that signature
+fn sign_transaction(encoded: &str, private_key: &[u8; 32], our_key: &[u8; 32]) -> Result<(Transaction, [u8; 64]), String> {
+    let mut tx = solana_dapp::decode_transaction(encoded)?;
+    tx.sign(private_key)?;
+    let slot = tx.message.signer_keys().iter().position(|k| k == our_key).unwrap_or_default();
+    let signature = tx.signatures[slot];
+    Ok((tx, signature))
+}
+
+/// Sign a `solana_*` dApp request and build the result in the WalletConnect shape.
+/// Returns the signed transaction separately when it still has to be broadcast.
+fn sign_request(
+    private_key: &[u8; 32],
+    our_key: &[u8; 32],
+    method: &str,
+    params: &serde_json::Value,
+    origin_host: &str,
+) -> Result<(serde_json::Value, Option<Transaction>), String> {
+    let b58 = |bytes: &[u8]| bs58::encode(bytes).into_string();
+    match method {
+        "solana_signTransaction" => {
+            let (tx, signature) = sign_transaction(transaction_param(params)?, private_key, our_key)?;
+            Ok((serde_json::json!({
+                "signature": b58(&signature),
+                "transaction": solana_dapp::encode_transaction(&tx),
+            }), None))
+        }
+        "solana_signAllTransactions" => {
+            let encoded = params.get("transactions").and_then(|t| t.as_array()).ok_or("Missing transactions")?;
+            let signed = encoded.iter()
+                .map(|e| {
+                    let e = e.as_str().ok_or("Invalid transaction")?;
+                    sign_transaction(e, private_key, our_key).map(|(tx, _)| solana_dapp::encode_transaction(&tx))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            Ok((serde_json::json!({ "transactions": signed }), None))
+        }
+        "solana_signAndSendTransaction" => {
+            let (tx, _) = sign_transaction(transaction_param(params)?, private_key, our_key)?;
+            if !tx.is_fully_signed() {
+                return Err("Transaction is missing signatures from other signers".into());
+            }
+            Ok((serde_json::Value::Null, Some(tx)))
+        }
+        "solana_signMessage" => {
+            if let Some(pubkey) = params.get("pubkey").and_then(|p| p.as_str()) {
+                if pubkey != b58(our_key) {
+                    return Err("Message is addressed to another account".into());
+                }
+            }
+            let message = params.get("message").and_then(|m| m.as_str())
+                .and_then(|m| bs58::decode(m).into_vec().ok())
+                .ok_or("Missing or invalid message")?;
+            let signature = solana_dapp::sign_off_chain_message(&message, private_key)?;
+            Ok((serde_json::json!({ "signature": b58(&signature) }), None))
+        }
+        "solana_signIn" => {
+            let address = b58(our_key);
+            let text = SiwsMessage::from_sign_in_input(params, origin_host, &address)?.to_text();
+            let signature = solana_dapp::sign_off_chain_message(text.as_bytes(), private_key)?;
+            Ok((serde_json::json!({
+                "address": address,
+                "signedMessage": b58(text.as_bytes()),
+                "signature": b58(&signature),
+            }), None))
+        }
+        other => Err(format!("Unsupported Solana method: {}", other)),
+    }
+}
 
-    let signed = transfer.sign(&private_key)?;
+/// Handle a Solana dApp signing request with the wallet key; signAndSendTransaction
+/// is also broadcast through `rpc_url`
+pub async fn dapp_request(
+    seed: &[u8; 64],
+    method: &str,
+    params: &serde_json::Value,
+    origin_host: &str,
+    rpc_url: &str,
+) -> Result<serde_json::Value, String> {
+    let mut keypair = sol_chain::get_keypair(seed)?;
+    let mut private_key: [u8; 32] = keypair[..32].try_into().unwrap();
+    let our_key: [u8; 32] = keypair[32..].try_into().unwrap();
+    keypair.zeroize();
+    let signed = sign_request(&private_key, &our_key, method, params, origin_host);
     private_key.zeroize();
-    let signed_b58 = bs58::encode(&signed.raw_bytes).into_string();
 
-    crate::rpc::solana::send_transaction(&signed_b58, rpc_url).await
+    match signed? {
+        (_, Some(tx)) => {
+            let raw_b58 = bs58::encode(tx.serialize()).into_string();
+            let signature = crate::rpc::solana::send_transaction(&raw_b58, rpc_url).await?;
+            Ok(serde_json::json!({ "signature": signature }))
+        }
+        (result, None) => Ok(result),
+    }
 }
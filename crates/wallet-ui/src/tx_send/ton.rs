@@ -4,78 +4,146 @@
 
 use wallet_core::tx::ton::*;
 use wallet_core::bip32_utils::{self, DerivationPath};
+use wallet_core::chains::ton::{self as ton_chain, TonAddress, WalletVersion};
+use wallet_core::tokens::jetton::{self, JettonTransfer};
+use wallet_core::tonconnect::{self, AppManifest, ConnectRequest, TransactionRequest};
 use zeroize::Zeroize;
 
-pub async fn send(
+/// Seconds an external message stays valid after signing
+const MESSAGE_TTL_SECS: u32 = 60;
+
+/// `valid_until` for a message signed now
+fn valid_until() -> u32 {
+    (js_sys::Date::now() / 1000.0) as u32 + MESSAGE_TTL_SECS
+}
+
+/// Text comment body, or none for an empty comment
+fn comment_body(comment: &str) -> Result<Option<wallet_core::chains::ton_cell::Cell>, String> {
+    let comment = comment.trim();
+    if comment.is_empty() { Ok(None) } else { text_comment(comment).map(Some) }
+}
+
+/// Sign `messages` from the wallet's `version` contract, valid until the given
+/// unix time; returns the external message BOC (base64)
+async fn sign_messages(
     seed: &[u8; 64],
-    to: &str,
-    amount: &str,
+    version: WalletVersion,
+    testnet: bool,
+    messages: &[TonMessage],
+    valid_until: u32,
     rpc_url: &str,
 ) -> Result<String, String> {
-    let path = DerivationPath::bip44(607);
-    let (mut private_key, _) = bip32_utils::derive_ed25519_key_from_seed(seed, &path)?;
-
-    let from_address = wallet_core::chains::ton::derive_ton_address(seed)?;
-    let nanoton = parse_ton_to_nanoton(amount)?;
-
+    let from_address = ton_chain::derive_ton_address_for_version(seed, version, testnet)?;
     let seqno = crate::rpc::ton::get_seqno(&from_address, rpc_url).await
         .unwrap_or(0);
 
-    // Decode TON friendly address (base64url) to raw 34 bytes (tag + workchain + hash)
-    let to_raw = wallet_core::chains::ton::decode_ton_friendly_address(to)?;
-
-    let transfer = TonTransfer {
-        to_address_raw: to_raw,
-        amount_nanoton: nanoton,
-        seqno,
-        valid_until: u32::MAX,
-    };
-
-    let signed = transfer.sign(&private_key)?;
+    let path = DerivationPath::bip44(607);
+    let (mut private_key, _) = bip32_utils::derive_ed25519_key_from_seed(seed, &path)?;
+    let signed = sign_for_version(version, &private_key, testnet, seqno, valid_until, messages, SEND_MODE_PAY_FEES_SEPARATELY);
     private_key.zeroize();
 
-    let boc_b64 = super::base64_simple_encode(&signed.raw_bytes);
+    Ok(super::base64_simple_encode(&signed?.raw_bytes))
+}
+
+/// Sign `messages` from the wallet's `version` contract and broadcast the external message
+async fn sign_and_send(seed: &[u8; 64], version: WalletVersion, testnet: bool, messages: &[TonMessage], rpc_url: &str) -> Result<String, String> {
+    let boc_b64 = sign_messages(seed, version, testnet, messages, valid_until(), rpc_url).await?;
     crate::rpc::ton::send_boc(&boc_b64, rpc_url).await
 }
 
-/// Send Jetton token transfer
-/// Note: Jetton transfers are complex (require BoC serialization for internal message).
-/// This MVP uses a simplified approach with the standard TonTransfer + jetton gas amount.
-pub async fn send_jetton(
+/// Send TON with an optional text comment. Non-bounceable recipient
+/// addresses get a non-bouncing message.
+pub async fn send(
     seed: &[u8; 64],
-    _to: &str,
-    _amount: &str,
-    token_address: &str,
+    version: WalletVersion,
+    testnet: bool,
+    to: &str,
+    amount: &str,
+    comment: &str,
     rpc_url: &str,
 ) -> Result<String, String> {
-    use wallet_core::tokens::jetton;
+    let destination = TonAddress::parse(to)?;
+    let message = TonMessage {
+        destination,
+        amount_nanoton: parse_ton_to_nanoton(amount)?,
+        bounce: destination.bounceable,
+        state_init: None,
+        body: comment_body(comment)?,
+    };
+    sign_and_send(seed, version, testnet, &[message], rpc_url).await
+}
 
-    let path = DerivationPath::bip44(607);
-    let (mut private_key, _) = bip32_utils::derive_ed25519_key_from_seed(seed, &path)?;
+/// Decimals of a known or imported jetton, else read from its master
+async fn jetton_decimals(master: &str, rpc_url: &str) -> Result<u8, String> {
+    if let Some(token) = crate::state::tokens_for_chain("ton").into_iter().find(|t| t.address == master) {
+        return Ok(token.decimals);
+    }
+    crate::rpc::jetton::get_token_metadata(master, rpc_url).await.map(|m| m.decimals)
+}
 
-    let from_address = wallet_core::chains::ton::derive_ton_address(seed)?;
+/// Send a jetton: a TEP-74 `transfer` to our jetton wallet for `master`, moving
+/// `amount` to the wallet owned by `to`. Unspent TON comes back to us.
+#[allow(clippy::too_many_arguments)]
+pub async fn send_jetton(
+    seed: &[u8; 64],
+    version: WalletVersion,
+    testnet: bool,
+    to: &str,
+    amount: &str,
+    comment: &str,
+    master: &str,
+    rpc_url: &str,
+) -> Result<String, String> {
+    let from_address = ton_chain::derive_ton_address_for_version(seed, version, testnet)?;
+    let decimals = jetton_decimals(master, rpc_url).await?;
 
     // Resolve jetton wallet address for the sender
-    let jetton_wallet = crate::rpc::jetton::get_jetton_wallet_address(token_address, &from_address, rpc_url).await
+    let jetton_wallet = crate::rpc::jetton::get_jetton_wallet_address(master, &from_address, rpc_url).await
         .map_err(|e| format!("Cannot resolve jetton wallet: {}", e))?;
 
-    let seqno = crate::rpc::ton::get_seqno(&from_address, rpc_url).await
-        .unwrap_or(0);
-
-    // Decode jetton wallet address to raw bytes
-    let jetton_wallet_raw = wallet_core::chains::ton::decode_ton_friendly_address(&jetton_wallet)?;
-
-    // Send gas amount to the jetton wallet contract
-    let transfer = TonTransfer {
-        to_address_raw: jetton_wallet_raw,
-        amount_nanoton: jetton::JETTON_GAS_AMOUNT,
-        seqno,
-        valid_until: u32::MAX,
+    let transfer = JettonTransfer {
+        query_id: js_sys::Date::now() as u64,
+        amount: wallet_core::tokens::erc20::parse_token_amount(amount.trim(), decimals)?,
+        destination: TonAddress::parse(to)?,
+        response_destination: TonAddress::parse(&from_address)?,
+        forward_ton_amount: jetton::FORWARD_TON_AMOUNT,
+        comment: Some(comment.trim()).filter(|c| !c.is_empty()).map(str::to_string),
     };
+    let message = transfer.message(TonAddress::parse(&jetton_wallet)?)?;
+    sign_and_send(seed, version, testnet, &[message], rpc_url).await
+}
 
-    let signed = transfer.sign(&private_key)?;
+/// TON Connect reply items for the wallet's `version` contract, with a `ton_proof` signed now
+pub fn connect_items(
+    seed: &[u8; 64],
+    version: WalletVersion,
+    request: &ConnectRequest,
+    manifest: &AppManifest,
+    testnet: bool,
+) -> Result<Vec<serde_json::Value>, String> {
+    let path = DerivationPath::bip44(607);
+    let (mut private_key, _) = bip32_utils::derive_ed25519_key_from_seed(seed, &path)?;
+    let now = (js_sys::Date::now() / 1000.0) as u64;
+    let items = tonconnect::connect_items(&private_key, version, request, manifest, testnet, now);
     private_key.zeroize();
+    items
+}
 
-    let boc_b64 = super::base64_simple_encode(&signed.raw_bytes);
-    crate::rpc::ton::send_boc(&boc_b64, rpc_url).await
+/// Sign and broadcast a TON Connect `sendTransaction` request. Returns the
+/// external message BOC (base64), the result the dApp expects.
+pub async fn send_connect_transaction(
+    seed: &[u8; 64],
+    version: WalletVersion,
+    request: &TransactionRequest,
+    testnet: bool,
+    rpc_url: &str,
+) -> Result<String, String> {
+    let from_address = ton_chain::derive_ton_address_for_version(seed, version, testnet)?;
+    let now = (js_sys::Date::now() / 1000.0) as u64;
+    request.validate(&TonAddress::parse(&from_address)?, testnet, now)?;
+    let valid_until = request.signing_valid_until(now, MESSAGE_TTL_SECS);
+    let messages = request.ton_messages()?;
+    let boc_b64 = sign_messages(seed, version, testnet, &messages, valid_until, rpc_url).await?;
+    crate::rpc::ton::send_boc(&boc_b64, rpc_url).await?;
+    Ok(boc_b64)
 }
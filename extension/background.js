@@ -12,6 +12,7 @@ import { rusbyWC } from './wc-bundle.js';
 let walletLocked = true;
 let pendingRequests = new Map(); // requestId -> { id, method, params, origin, tabId }
 let approvedOrigins = {};        // origin -> [address]
+let solanaOrigins = {};          // origin -> [base58 address] (Wallet Standard)
 let activeChainId = '0x1';       // Ethereum mainnet default
 let activeAccounts = [];         // Current unlocked accounts
 let wcInitialized = false;
@@ -30,10 +31,11 @@ self.addEventListener('activate', () => {
 async function restoreState() {
   try {
     const data = await chrome.storage.local.get([
-      'approvedOrigins', 'activeChainId', 'walletLocked',
+      'approvedOrigins', 'solanaOrigins', 'activeChainId', 'walletLocked',
       'pendingRequests', 'activeAccounts'
     ]);
     approvedOrigins = data.approvedOrigins || {};
+    solanaOrigins = data.solanaOrigins || {};
     activeChainId = data.activeChainId || '0x1';
     walletLocked = data.walletLocked !== false;
     activeAccounts = data.activeAccounts || [];
@@ -49,6 +51,7 @@ async function persistState() {
   try {
     await chrome.storage.local.set({
       approvedOrigins,
+      solanaOrigins,
       activeChainId,
       walletLocked,
       activeAccounts,
@@ -211,14 +214,53 @@ async function handleDappMessage(msg, origin, tabId) {
       return enqueueForApproval(id, method, params, origin, tabId);
 
     case 'wallet_switchEthereumChain':
+    case 'wallet_addEthereumChain':
+      if (walletLocked) {
+        return { error: { code: 4100, message: 'Wallet locked' } };
+      }
       if (!approvedOrigins[origin]) {
         return { error: { code: 4100, message: 'Not connected' } };
       }
-      return handleSwitchChain(id, params);
+      return method === 'wallet_addEthereumChain'
+        ? handleAddChain(id, params, origin, tabId)
+        : handleSwitchChain(id, params, origin, tabId);
+
+    // --- Solana (Wallet Standard) ---
+    case 'solana_requestAccounts':
+      if (!walletLocked && solanaOrigins[origin]) {
+        return { result: solanaOrigins[origin] };
+      }
+      return enqueueForApproval(id, method, params, origin, tabId);
 
-    case 'wallet_addEthereumChain':
-      // For now, reject — custom chains not supported yet
-      return { error: { code: 4200, message: 'Custom chains not supported' } };
+    case 'solana_accounts':
+      if (walletLocked || !solanaOrigins[origin]) {
+        return { result: [] };
+      }
+      return { result: solanaOrigins[origin] };
+
+    case 'solana_disconnect':
+      delete solanaOrigins[origin];
+      await persistState();
+      return { result: null };
+
+    case 'solana_signTransaction':
+    case 'solana_signAllTransactions':
+    case 'solana_signAndSendTransaction':
+    case 'solana_signMessage':
+      if (walletLocked) {
+        return { error: { code: 4100, message: 'Wallet locked' } };
+      }
+      if (!solanaOrigins[origin]) {
+        return { error: { code: 4100, message: 'Not connected' } };
+      }
+      return enqueueForApproval(id, method, params, origin, tabId);
+
+    // Sign-in connects as well, so no prior connection is needed
+    case 'solana_signIn':
+      if (walletLocked) {
+        return { error: { code: 4100, message: 'Wallet locked' } };
+      }
+      return enqueueForApproval(id, method, params, origin, tabId);
 
     default:
       return { error: { code: 4200, message: `Method not supported: ${method}` } };
@@ -239,6 +281,7 @@ async function enqueueForApproval(id, method, params, origin, tabId) {
   const requestId = crypto.randomUUID();
   pendingRequests.set(requestId, {
     id, method, params, origin, tabId, requestId,
+    chainId: activeChainId,
     timestamp: Date.now(),
   });
   await persistState();
@@ -260,21 +303,59 @@ async function enqueueForApproval(id, method, params, origin, tabId) {
   return null;
 }
 
-function handleSwitchChain(id, params) {
-  if (!params || !params[0] || !params[0].chainId) {
+const BUILTIN_CHAIN_IDS = ['0x1', '0x89', '0x38', '0xa', '0x2105', '0xa4b1'];
+
+// Normalize a hex chain id ("0x0A" -> "0xa"); null when malformed
+function normalizeChainId(chainId) {
+  if (typeof chainId !== 'string' || !/^0x[0-9a-fA-F]+$/.test(chainId)) return null;
+  const n = BigInt(chainId);
+  return n > 0n ? '0x' + n.toString(16) : null;
+}
+
+// Built-in chains plus networks the user added in the popup (stored as JSON by the wallet UI)
+async function isKnownChain(chainId) {
+  if (BUILTIN_CHAIN_IDS.includes(chainId)) return true;
+  const data = await chrome.storage.local.get('custom_evm_networks');
+  try {
+    const networks = JSON.parse(data.custom_evm_networks || '[]');
+    return networks.some(n => '0x' + BigInt(n.chain_id).toString(16) === chainId);
+  } catch {
+    return false;
+  }
+}
+
+// EIP-3326: switching to another known chain needs user confirmation
+async function handleSwitchChain(id, params, origin, tabId) {
+  const newChainId = normalizeChainId(params?.[0]?.chainId);
+  if (!newChainId) {
     return { error: { code: -32602, message: 'Invalid params' } };
   }
-  const newChainId = params[0].chainId;
-  // Validate known chain IDs
-  const knownChains = ['0x1', '0x89', '0x38', '0xa', '0x2105', '0xa4b1'];
-  if (!knownChains.includes(newChainId)) {
+  if (newChainId === activeChainId) {
+    return { result: null };
+  }
+  if (!(await isKnownChain(newChainId))) {
     return { error: { code: 4902, message: 'Unrecognized chain ID' } };
   }
+  return enqueueForApproval(id, 'wallet_switchEthereumChain', [{ chainId: newChainId }], origin, tabId);
+}
+
+// EIP-3085: the popup validates the parameters, checks the RPC and stores the network
+async function handleAddChain(id, params, origin, tabId) {
+  const newChainId = normalizeChainId(params?.[0]?.chainId);
+  if (!newChainId) {
+    return { error: { code: -32602, message: 'Invalid params' } };
+  }
+  if (await isKnownChain(newChainId)) {
+    // Already available: treat as a switch request
+    return handleSwitchChain(id, [{ chainId: newChainId }], origin, tabId);
+  }
+  return enqueueForApproval(id, 'wallet_addEthereumChain', params, origin, tabId);
+}
+
+function setActiveChain(newChainId) {
   activeChainId = newChainId;
-  persistState();
   // Notify all content scripts of chain change
   broadcastEvent('chainChanged', newChainId);
-  return { result: null };
 }
 
 // --- Popup Message Handler ---
@@ -289,6 +370,18 @@ async function handlePopupMessage(msg) {
       if (req.method === 'eth_requestAccounts' && result) {
         approvedOrigins[req.origin] = result;
       }
+      if (req.method === 'solana_requestAccounts' && result) {
+        solanaOrigins[req.origin] = result;
+      }
+      if (req.method === 'solana_signIn' && result?.address) {
+        solanaOrigins[req.origin] = [result.address];
+      }
+
+      // Approved network add/switch: the dApp now talks to the new chain
+      if (req.method === 'wallet_switchEthereumChain' || req.method === 'wallet_addEthereumChain') {
+        const newChainId = normalizeChainId(req.params?.[0]?.chainId);
+        if (newChainId) setActiveChain(newChainId);
+      }
 
       if (req.source === 'walletconnect' && wcInitialized) {
         // WC request — respond via WC protocol
@@ -344,6 +437,7 @@ async function handlePopupMessage(msg) {
       await persistState();
       if (walletLocked) {
         broadcastEvent('accountsChanged', []);
+        broadcastEvent('solanaAccountsChanged', []);
       }
       return { ok: true };
     }
@@ -361,14 +455,17 @@ async function handlePopupMessage(msg) {
     case '__rusby_revoke_origin': {
       const { origin } = msg;
       delete approvedOrigins[origin];
+      delete solanaOrigins[origin];
       await persistState();
       // Notify that origin's tabs
       broadcastEvent('accountsChanged', []);
+      broadcastEvent('solanaAccountsChanged', []);
       return { ok: true };
     }
 
     case '__rusby_get_approved_origins': {
-      return { origins: approvedOrigins };
+      // Solana-only connections are listed too, with their Solana address
+      return { origins: { ...solanaOrigins, ...approvedOrigins } };
     }
 
     // --- WalletConnect methods ---
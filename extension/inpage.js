@@ -1,9 +1,10 @@
-// Rusby Wallet — Injected Provider (EIP-1193 + EIP-6963)
+// Rusby Wallet — Injected Provider (EIP-1193 + EIP-6963 + Solana Wallet Standard)
 // Copyright (C) 2025 axpnet & Claude Opus (Anthropic)
 // SPDX-License-Identifier: GPL-3.0-or-later
 //
 // Injected into the page context by content-script.js.
-// Exposes window.rusby as an EIP-1193 compatible provider.
+// Exposes window.rusby as an EIP-1193 compatible provider and registers a
+// Wallet Standard wallet for Solana dApps.
 
 'use strict';
 
@@ -186,4 +187,211 @@
 
   // Re-announce when requested
   window.addEventListener('eip6963:requestProvider', announceProvider);
+
+  // --- Solana: Wallet Standard ---
+  // Requests use the WalletConnect solana_* methods and encodings (base64
+  // transactions, base58 messages and signatures); the popup signs them.
+  const B58_ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+
+  function b58encode(bytes) {
+    const digits = [];
+    for (const byte of bytes) {
+      let carry = byte;
+      for (let i = 0; i < digits.length; i++) {
+        carry += digits[i] << 8;
+        digits[i] = carry % 58;
+        carry = (carry / 58) | 0;
+      }
+      while (carry > 0) {
+        digits.push(carry % 58);
+        carry = (carry / 58) | 0;
+      }
+    }
+    let out = '';
+    for (let i = 0; i < bytes.length && bytes[i] === 0; i++) out += '1';
+    for (let i = digits.length - 1; i >= 0; i--) out += B58_ALPHABET[digits[i]];
+    return out;
+  }
+
+  function b58decode(str) {
+    const bytes = [0];
+    for (const char of str) {
+      const value = B58_ALPHABET.indexOf(char);
+      if (value < 0) throw new Error('Invalid base58 string');
+      let carry = value;
+      for (let i = 0; i < bytes.length; i++) {
+        carry += bytes[i] * 58;
+        bytes[i] = carry & 0xff;
+        carry >>= 8;
+      }
+      while (carry > 0) {
+        bytes.push(carry & 0xff);
+        carry >>= 8;
+      }
+    }
+    let zeros = 0;
+    while (zeros < str.length && str[zeros] === '1') zeros++;
+    const body = bytes.reverse();
+    const start = body.findIndex(b => b !== 0);
+    return new Uint8Array([...new Array(zeros).fill(0), ...(start < 0 ? [] : body.slice(start))]);
+  }
+
+  function b64encode(bytes) {
+    let binary = '';
+    bytes.forEach(b => { binary += String.fromCharCode(b); });
+    return btoa(binary);
+  }
+
+  function b64decode(str) {
+    return Uint8Array.from(atob(str), c => c.charCodeAt(0));
+  }
+
+  const SOLANA_CHAINS = Object.freeze(['solana:mainnet', 'solana:devnet']);
+  const SOLANA_ACCOUNT_FEATURES = Object.freeze([
+    'solana:signTransaction', 'solana:signAndSendTransaction', 'solana:signMessage', 'solana:signIn',
+  ]);
+
+  class RusbySolanaWallet {
+    constructor() {
+      this._accounts = [];
+      this._listeners = { change: [] };
+
+      // Lock, revoke and disconnect from the background
+      window.addEventListener('message', (event) => {
+        if (event.source !== window) return;
+        if (event.data?.target !== 'rusby-inpage' || event.data.type !== 'event') return;
+        if (event.data.event === 'solanaAccountsChanged') this._setAccounts(event.data.data || []);
+      });
+    }
+
+    get version() { return '1.0.0'; }
+    get name() { return providerInfo.name; }
+    get icon() { return providerInfo.icon; }
+    get chains() { return SOLANA_CHAINS; }
+    get accounts() { return this._accounts; }
+
+    get features() {
+      return {
+        'standard:connect': { version: '1.0.0', connect: this._connect.bind(this) },
+        'standard:disconnect': { version: '1.0.0', disconnect: this._disconnect.bind(this) },
+        'standard:events': { version: '1.0.0', on: this._on.bind(this) },
+        'solana:signTransaction': {
+          version: '1.0.0',
+          supportedTransactionVersions: ['legacy', 0],
+          signTransaction: this._signTransaction.bind(this),
+        },
+        'solana:signAndSendTransaction': {
+          version: '1.0.0',
+          supportedTransactionVersions: ['legacy', 0],
+          signAndSendTransaction: this._signAndSendTransaction.bind(this),
+        },
+        'solana:signMessage': { version: '1.0.0', signMessage: this._signMessage.bind(this) },
+        'solana:signIn': { version: '1.0.0', signIn: this._signIn.bind(this) },
+      };
+    }
+
+    _account(address) {
+      return Object.freeze({
+        address,
+        publicKey: b58decode(address),
+        chains: SOLANA_CHAINS,
+        features: SOLANA_ACCOUNT_FEATURES,
+      });
+    }
+
+    _setAccounts(addresses) {
+      const same = addresses.length === this._accounts.length &&
+        addresses.every((a, i) => a === this._accounts[i].address);
+      if (same) return;
+      this._accounts = addresses.map(a => this._account(a));
+      this._listeners.change.forEach(cb => {
+        try { cb({ accounts: this._accounts }); } catch (_) {}
+      });
+    }
+
+    _on(event, listener) {
+      if (!this._listeners[event]) this._listeners[event] = [];
+      this._listeners[event].push(listener);
+      return () => {
+        this._listeners[event] = this._listeners[event].filter(cb => cb !== listener);
+      };
+    }
+
+    async _connect({ silent } = {}) {
+      const method = silent ? 'solana_accounts' : 'solana_requestAccounts';
+      this._setAccounts((await provider.request({ method })) || []);
+      return { accounts: this._accounts };
+    }
+
+    async _disconnect() {
+      await provider.request({ method: 'solana_disconnect' });
+      this._setAccounts([]);
+    }
+
+    async _signTransaction(...inputs) {
+      if (inputs.length === 1) {
+        const { transaction, chain } = inputs[0];
+        const result = await provider.request({
+          method: 'solana_signTransaction',
+          params: { transaction: b64encode(transaction), chain },
+        });
+        return [{ signedTransaction: b64decode(result.transaction) }];
+      }
+      const result = await provider.request({
+        method: 'solana_signAllTransactions',
+        params: { transactions: inputs.map(i => b64encode(i.transaction)), chain: inputs[0]?.chain },
+      });
+      return result.transactions.map(t => ({ signedTransaction: b64decode(t) }));
+    }
+
+    async _signAndSendTransaction(...inputs) {
+      const outputs = [];
+      for (const { transaction, chain, options } of inputs) {
+        const result = await provider.request({
+          method: 'solana_signAndSendTransaction',
+          params: { transaction: b64encode(transaction), chain, sendOptions: options },
+        });
+        outputs.push({ signature: b58decode(result.signature) });
+      }
+      return outputs;
+    }
+
+    async _signMessage(...inputs) {
+      const outputs = [];
+      for (const { account, message } of inputs) {
+        const result = await provider.request({
+          method: 'solana_signMessage',
+          params: { message: b58encode(message), pubkey: account.address },
+        });
+        outputs.push({ signedMessage: message, signature: b58decode(result.signature) });
+      }
+      return outputs;
+    }
+
+    async _signIn(...inputs) {
+      const outputs = [];
+      for (const input of inputs) {
+        const result = await provider.request({ method: 'solana_signIn', params: { ...input } });
+        this._setAccounts([result.address]);
+        outputs.push({
+          account: this._accounts[0],
+          signedMessage: b58decode(result.signedMessage),
+          signature: b58decode(result.signature),
+          signatureType: 'ed25519',
+        });
+      }
+      return outputs;
+    }
+  }
+
+  const solanaWallet = new RusbySolanaWallet();
+  const registerSolanaWallet = ({ register }) => register(solanaWallet);
+
+  // Wallet Standard handshake: announce now, and again for apps that load later
+  try {
+    window.dispatchEvent(new CustomEvent('wallet-standard:register-wallet', { detail: registerSolanaWallet }));
+  } catch (e) {
+    console.error('[Rusby] Wallet Standard registration failed:', e);
+  }
+  window.addEventListener('wallet-standard:app-ready', ({ detail: api }) => registerSolanaWallet(api));
 })();